@@ -32,6 +32,9 @@ abi StorageVecContract {
     #[storage(read)]
     fn iterate_over_a_storage_vec();
 
+    #[storage(read)]
+    fn iterate_over_a_storage_vec_with_iter();
+
     #[storage(read, write)]
     fn push_to_multiple_types_storage_vec();
 
@@ -69,6 +72,14 @@ impl StorageVecContract for Contract {
         }
     }
     // ANCHOR_END: storage_vec_iterate
+    // ANCHOR: storage_vec_iter
+    #[storage(read)]
+    fn iterate_over_a_storage_vec_with_iter() {
+        for value in storage.v.iter(100) {
+            log(value);
+        }
+    }
+    // ANCHOR_END: storage_vec_iter
     // ANCHOR: storage_vec_multiple_types_fn
     #[storage(read, write)]
     fn push_to_multiple_types_storage_vec() {
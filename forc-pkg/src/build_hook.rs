@@ -0,0 +1,190 @@
+//! Running the code generation hooks declared in the `[build]` section of package manifests.
+//!
+//! A hook is an arbitrary program, e.g. a Rust binary or a forc plugin, generating Sway sources
+//! into a directory within the `src` directory of the package before the package is compiled.
+//! The generated modules are then included into the module tree like any other module.
+
+use crate::manifest::{BuildHook, GenericManifestFile, PackageManifestFile};
+use anyhow::{bail, Context, Result};
+use forc_tracing::println_action_green;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+use sway_core::fuel_prelude::fuel_crypto::Hasher;
+
+/// The file within the output directory of a hook recording the fingerprint of the inputs the hook
+/// was last run with.
+pub const BUILD_HOOK_FINGERPRINT_FILE_NAME: &str = ".forc-build-hook";
+
+/// Run the `[build]` hook of the package, if it declares one whose inputs changed since it was
+/// last run.
+///
+/// Returns whether the hook was run.
+pub fn run_build_hook(manifest: &PackageManifestFile) -> Result<bool> {
+    let (Some(hook), Some(out_dir)) = (manifest.build.as_ref(), manifest.build_hook_out_dir())
+    else {
+        return Ok(false);
+    };
+
+    let fingerprint_path = out_dir.join(BUILD_HOOK_FINGERPRINT_FILE_NAME);
+    let fingerprint = if hook.inputs.is_empty() {
+        None
+    } else {
+        let fingerprint = fingerprint(manifest.dir(), hook)?;
+        if fs::read_to_string(&fingerprint_path).is_ok_and(|last| last == fingerprint) {
+            return Ok(false);
+        }
+        Some(fingerprint)
+    };
+
+    let command = hook.command.join(" ");
+    println_action_green(
+        "Generating",
+        &format!("{} ({command})", manifest.project_name()),
+    );
+    fs::create_dir_all(&out_dir)
+        .with_context(|| format!("failed to create the output directory {out_dir:?}"))?;
+    let (program, args) = hook
+        .command
+        .split_first()
+        .expect("the command of a build hook is validated to be non-empty");
+    let status = Command::new(program)
+        .args(args)
+        .current_dir(manifest.dir())
+        .env("OUT_DIR", &out_dir)
+        .env("FORC_MANIFEST_DIR", manifest.dir())
+        .env("FORC_PKG_NAME", manifest.project_name())
+        .status()
+        .with_context(|| {
+            format!(
+                "failed to run the build hook `{command}` of package `{}`",
+                manifest.project_name()
+            )
+        })?;
+    if !status.success() {
+        bail!(
+            "the build hook `{command}` of package `{}` failed: {status}",
+            manifest.project_name()
+        );
+    }
+
+    match fingerprint {
+        Some(fingerprint) => fs::write(&fingerprint_path, fingerprint)?,
+        None if fingerprint_path.exists() => fs::remove_file(&fingerprint_path)?,
+        None => (),
+    }
+    Ok(true)
+}
+
+/// Hash the command of the hook along with the paths and the contents of all its input files.
+fn fingerprint(manifest_dir: &Path, hook: &BuildHook) -> Result<String> {
+    let mut hasher = Hasher::default();
+    for arg in &hook.command {
+        hasher.input(arg.as_bytes());
+        hasher.input([0]);
+    }
+    for input in &hook.inputs {
+        let input_path = manifest_dir.join(input);
+        if !input_path.exists() {
+            bail!("the input {input:?} of the build hook does not exist");
+        }
+        let files: Vec<PathBuf> = walkdir::WalkDir::new(&input_path)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_file())
+            .map(|entry| entry.into_path())
+            .collect();
+        for file in files {
+            let relative_path = file.strip_prefix(manifest_dir).unwrap_or(&file);
+            hasher.input(relative_path.to_string_lossy().as_bytes());
+            hasher.input([0]);
+            hasher.input(
+                fs::read(&file)
+                    .with_context(|| format!("failed to read the build hook input {file:?}"))?,
+            );
+        }
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates a package in a fresh directory with a hook generating `src/generated/idl.sw` from
+    /// `idl.txt`, which records each of its runs in `runs.txt`.
+    fn package_with_hook(name: &str, inputs: &str) -> PackageManifestFile {
+        let dir = std::env::temp_dir().join(format!("forc_pkg_{name}"));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src").join("main.sw"), "script;\n\nfn main() {}\n").unwrap();
+        fs::write(dir.join("idl.txt"), "library;\n").unwrap();
+        let manifest = format!(
+            r#"[project]
+authors = ["Fuel Labs <contact@fuel.sh>"]
+entry = "main.sw"
+license = "Apache-2.0"
+name = "{name}"
+implicit-std = false
+
+[build]
+command = ["sh", "-c", "echo run >> runs.txt && cp idl.txt \"$OUT_DIR/idl.sw\""]
+inputs = {inputs}
+"#
+        );
+        fs::write(dir.join("Forc.toml"), manifest).unwrap();
+        PackageManifestFile::from_dir(&dir).unwrap()
+    }
+
+    fn runs(manifest: &PackageManifestFile) -> usize {
+        fs::read_to_string(manifest.dir().join("runs.txt")).map_or(0, |runs| runs.lines().count())
+    }
+
+    #[test]
+    fn test_build_hook_skipped_while_inputs_unchanged() {
+        let manifest = package_with_hook("build_hook_inputs", r#"["idl.txt"]"#);
+        let out_dir = manifest.build_hook_out_dir().unwrap();
+
+        assert!(run_build_hook(&manifest).unwrap());
+        assert_eq!(
+            fs::read_to_string(out_dir.join("idl.sw")).unwrap(),
+            "library;\n"
+        );
+        assert!(out_dir.join(BUILD_HOOK_FINGERPRINT_FILE_NAME).exists());
+
+        assert!(!run_build_hook(&manifest).unwrap());
+        assert_eq!(runs(&manifest), 1);
+
+        fs::write(
+            manifest.dir().join("idl.txt"),
+            "library;\n\npub struct Token {}\n",
+        )
+        .unwrap();
+        assert!(run_build_hook(&manifest).unwrap());
+        assert_eq!(runs(&manifest), 2);
+        assert_eq!(
+            fs::read_to_string(out_dir.join("idl.sw")).unwrap(),
+            "library;\n\npub struct Token {}\n"
+        );
+    }
+
+    #[test]
+    fn test_build_hook_without_inputs_always_run() {
+        let manifest = package_with_hook("build_hook_no_inputs", "[]");
+        assert!(run_build_hook(&manifest).unwrap());
+        assert!(run_build_hook(&manifest).unwrap());
+        assert_eq!(runs(&manifest), 2);
+        let out_dir = manifest.build_hook_out_dir().unwrap();
+        assert!(!out_dir.join(BUILD_HOOK_FINGERPRINT_FILE_NAME).exists());
+    }
+
+    #[test]
+    fn test_build_hook_missing_input() {
+        let manifest = package_with_hook("build_hook_missing_input", r#"["missing.txt"]"#);
+        assert!(run_build_hook(&manifest).is_err());
+        assert_eq!(runs(&manifest), 0);
+    }
+}
@@ -4,8 +4,10 @@
 //! The project should consist of one or more Sway modules under a `src` directory. It may also
 //! declare a set of forc package dependencies within its manifest.
 
+pub mod build_hook;
 pub mod lock;
 pub mod manifest;
+pub mod message;
 mod pkg;
 pub mod source;
 
@@ -1,5 +1,11 @@
-use serde::{Deserialize, Serialize};
-use sway_core::{OptLevel, PrintAsm, PrintIr};
+use crate::message::MessageFormat;
+use forc_tracing::println_warning;
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use sway_core::{
+    analysis::AnalysisPasses, InlineThresholds, IrPassOverrides, LoopUnrollThresholds, OptLevel,
+    PrintAsm, PrintIr, VerifyIr,
+};
 
 /// Parameters to pass through to the `sway_core::BuildConfig` during compilation.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -7,6 +13,9 @@ use sway_core::{OptLevel, PrintAsm, PrintIr};
 pub struct BuildProfile {
     #[serde(default)]
     pub name: String,
+    /// The profile whose settings are used for the settings not specified in this profile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherits: Option<String>,
     #[serde(default)]
     pub print_ast: bool,
     pub print_dca_graph: Option<String>,
@@ -35,6 +44,36 @@ pub struct BuildProfile {
     pub reverse_results: bool,
     #[serde(default)]
     pub optimization_level: OptLevel,
+    /// Experimental features to enable or disable, applied on top of the ones in `[project]`.
+    #[serde(default)]
+    pub experimental: HashMap<String, bool>,
+    #[serde(default)]
+    pub ir_passes: IrPassOverrides,
+    /// Thresholds of the cost model deciding which functions get inlined.
+    #[serde(default)]
+    pub inline: InlineThresholds,
+    /// Thresholds of the loop unrolling pass, which only runs if enabled in `ir_passes`.
+    #[serde(default)]
+    pub loop_unroll: LoopUnrollThresholds,
+    /// The number of generic functions with the most monomorphized copies to report.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monomorphization_report: Option<usize>,
+    /// The maximum total number of monomorphized copies of generic functions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monomorphization_budget: Option<usize>,
+    /// Report the external inputs of contracts that reach storage writes or asset operations
+    /// without being validated.
+    #[serde(default)]
+    pub taint_analysis: bool,
+    /// Set from the CLI options, not configurable in the manifest.
+    #[serde(skip)]
+    pub verify_ir: VerifyIr,
+    /// Set from the CLI options, not configurable in the manifest.
+    #[serde(skip)]
+    pub message_format: MessageFormat,
+    /// Set from the build options, not configurable in the manifest.
+    #[serde(skip)]
+    pub analysis_passes: AnalysisPasses,
 }
 
 impl BuildProfile {
@@ -45,6 +84,7 @@ impl BuildProfile {
     pub fn debug() -> Self {
         Self {
             name: Self::DEBUG.into(),
+            inherits: None,
             print_ast: false,
             print_dca_graph: None,
             print_dca_graph_url_format: None,
@@ -60,12 +100,23 @@ impl BuildProfile {
             error_on_warnings: false,
             reverse_results: false,
             optimization_level: OptLevel::Opt0,
+            experimental: HashMap::new(),
+            ir_passes: IrPassOverrides::default(),
+            inline: InlineThresholds::default(),
+            loop_unroll: LoopUnrollThresholds::default(),
+            monomorphization_report: None,
+            monomorphization_budget: None,
+            taint_analysis: false,
+            verify_ir: VerifyIr::default(),
+            message_format: MessageFormat::Human,
+            analysis_passes: AnalysisPasses::default(),
         }
     }
 
     pub fn release() -> Self {
         Self {
             name: Self::RELEASE.to_string(),
+            inherits: None,
             print_ast: false,
             print_dca_graph: None,
             print_dca_graph_url_format: None,
@@ -81,6 +132,16 @@ impl BuildProfile {
             error_on_warnings: false,
             reverse_results: false,
             optimization_level: OptLevel::Opt1,
+            experimental: HashMap::new(),
+            ir_passes: IrPassOverrides::default(),
+            inline: InlineThresholds::default(),
+            loop_unroll: LoopUnrollThresholds::default(),
+            monomorphization_report: None,
+            monomorphization_budget: None,
+            taint_analysis: false,
+            verify_ir: VerifyIr::default(),
+            message_format: MessageFormat::Human,
+            analysis_passes: AnalysisPasses::default(),
         }
     }
 }
@@ -91,16 +152,110 @@ impl Default for BuildProfile {
     }
 }
 
+/// Deserializes the `[build-profile.*]` tables, resolving the `inherits` key of each profile.
+///
+/// A profile that inherits from another one starts out with all the settings of its parent, which
+/// may also be one of the built-in `debug` and `release` profiles, and overrides the settings it
+/// specifies itself. Nested tables like `print-ir` or `experimental` are merged key by key.
+pub(crate) fn deserialize_build_profiles<'de, D>(
+    deserializer: D,
+) -> Result<Option<BTreeMap<String, BuildProfile>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(tables) = Option::<BTreeMap<String, toml::Table>>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+    let mut profiles = BTreeMap::new();
+    for (name, table) in &tables {
+        let resolved =
+            resolve_inherited_settings(name, &tables, &mut vec![]).map_err(D::Error::custom)?;
+        let profile: BuildProfile =
+            serde_ignored::deserialize(toml::Value::Table(resolved), |path| {
+                // Only warn about the keys of this profile, the inherited ones get reported
+                // with the profile they come from.
+                let path = path.to_string();
+                let key = path.split('.').next().unwrap_or_default();
+                if table.contains_key(key) {
+                    println_warning(&format!("unused manifest key: build-profile.{name}.{path}"));
+                }
+            })
+            .map_err(|err| D::Error::custom(format!("invalid build profile `{name}`: {err}")))?;
+        profiles.insert(name.clone(), profile);
+    }
+    Ok(Some(profiles))
+}
+
+/// Returns the settings of the profile `name`, including the ones inherited from its ancestors.
+fn resolve_inherited_settings(
+    name: &str,
+    tables: &BTreeMap<String, toml::Table>,
+    visiting: &mut Vec<String>,
+) -> Result<toml::Table, String> {
+    if visiting.iter().any(|visited| visited == name) {
+        visiting.push(name.to_string());
+        return Err(format!(
+            "cyclic build profile inheritance: {}",
+            visiting.join(" -> ")
+        ));
+    }
+    let Some(table) = tables.get(name) else {
+        let builtin = match name {
+            BuildProfile::DEBUG => BuildProfile::debug(),
+            BuildProfile::RELEASE => BuildProfile::release(),
+            _ => return Err(format!("build profile `{name}` does not exist")),
+        };
+        return toml::Table::try_from(builtin).map_err(|err| err.to_string());
+    };
+    let Some(parent) = table.get("inherits") else {
+        return Ok(table.clone());
+    };
+    let parent = parent
+        .as_str()
+        .ok_or_else(|| format!("`inherits` of build profile `{name}` must be a string"))?;
+    // A profile named like a built-in profile can inherit from the built-in one.
+    let mut inherited = if parent == name {
+        resolve_inherited_settings(parent, &BTreeMap::new(), visiting)?
+    } else {
+        visiting.push(name.to_string());
+        let inherited = resolve_inherited_settings(parent, tables, visiting)?;
+        visiting.pop();
+        inherited
+    };
+    // The `name` of the parent is not inherited.
+    inherited.remove("name");
+    merge_settings(&mut inherited, table);
+    Ok(inherited)
+}
+
+/// Overrides the settings in `base` with the ones in `overrides`, merging nested tables.
+fn merge_settings(base: &mut toml::Table, overrides: &toml::Table) {
+    for (key, value) in overrides {
+        match (base.get_mut(key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(overrides)) => {
+                merge_settings(base, overrides)
+            }
+            _ => {
+                base.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{BuildProfile, PackageManifest};
-    use sway_core::{OptLevel, PrintAsm, PrintIr};
+    use crate::{message::MessageFormat, BuildProfile, PackageManifest};
+    use std::collections::HashMap;
+    use sway_core::{
+        analysis::AnalysisPasses, InlineThresholds, IrPassOverrides, LoopUnrollThresholds,
+        OptLevel, PrintAsm, PrintIr, VerifyIr,
+    };
 
     #[test]
     fn test_build_profiles() {
         let manifest = PackageManifest::from_dir("./tests/sections").expect("manifest");
         let build_profiles = manifest.build_profile.expect("build profile");
-        assert_eq!(build_profiles.len(), 4);
+        assert_eq!(build_profiles.len(), 5);
 
         // Standard debug profile without adaptations.
         let expected = BuildProfile::debug();
@@ -150,8 +305,110 @@ mod tests {
             error_on_warnings: true,
             reverse_results: true,
             optimization_level: OptLevel::Opt0,
+            experimental: HashMap::from([("storage_domains".to_string(), true)]),
+            ir_passes: IrPassOverrides {
+                enable: vec!["loop-unroll".to_string()],
+                disable: vec!["sroa".to_string()],
+            },
+            inline: InlineThresholds {
+                call_gas_cost: Some(20),
+                ..Default::default()
+            },
+            loop_unroll: LoopUnrollThresholds {
+                max_full_unroll_trip_count: Some(8),
+                ..Default::default()
+            },
+            monomorphization_report: Some(10),
+            monomorphization_budget: Some(500),
+            taint_analysis: false,
+            verify_ir: VerifyIr::default(),
+            message_format: MessageFormat::Human,
+            analysis_passes: AnalysisPasses::default(),
         };
         let profile = build_profiles.get("release").expect("release profile");
         assert_eq!(*profile, expected);
+
+        // Profile inheriting the adapted release profile, overriding some of its settings.
+        let expected = BuildProfile {
+            inherits: Some("release".into()),
+            print_ir: PrintIr {
+                initial: true,
+                ..PrintIr::r#final()
+            },
+            terse: false,
+            optimization_level: OptLevel::Opt1,
+            experimental: HashMap::from([
+                ("storage_domains".to_string(), true),
+                ("infer_storage_purity".to_string(), true),
+            ]),
+            inline: InlineThresholds {
+                call_gas_cost: Some(20),
+                arg_gas_cost: Some(3),
+                ..Default::default()
+            },
+            ..expected
+        };
+        let profile = build_profiles
+            .get("release_inherited")
+            .expect("inherited profile");
+        assert_eq!(*profile, expected);
+    }
+
+    #[test]
+    fn test_build_profile_inheriting_builtin_profile() {
+        let manifest: PackageManifest = toml::from_str(
+            r#"
+            [project]
+            authors = ["Fuel Labs <contact@fuel.sh>"]
+            license = "Apache-2.0"
+            name = "builtin_inherited"
+
+            [build-profile.release-ir]
+            inherits = "release"
+            print-ir = { initial = true, final = true, modified = false, passes = [] }
+
+            [build-profile.debug-fast]
+            inherits = "debug"
+            optimization-level = 1
+            "#,
+        )
+        .expect("manifest");
+        let build_profiles = manifest.build_profile.expect("build profile");
+
+        let expected = BuildProfile {
+            name: "".into(),
+            inherits: Some("release".into()),
+            print_ir: PrintIr {
+                initial: true,
+                r#final: true,
+                modified_only: false,
+                passes: vec![],
+            },
+            ..BuildProfile::release()
+        };
+        let profile = build_profiles
+            .get("release-ir")
+            .expect("release-ir profile");
+        assert_eq!(*profile, expected);
+
+        let expected = BuildProfile {
+            name: "".into(),
+            inherits: Some("debug".into()),
+            optimization_level: OptLevel::Opt1,
+            ..BuildProfile::debug()
+        };
+        let profile = build_profiles
+            .get("debug-fast")
+            .expect("debug-fast profile");
+        assert_eq!(*profile, expected);
+    }
+
+    #[test]
+    fn test_builtin_build_profiles_roundtrip() {
+        for profile in [BuildProfile::debug(), BuildProfile::release()] {
+            let serialized = toml::to_string(&profile).expect("serialized profile");
+            let deserialized: BuildProfile = toml::from_str(&serialized).expect("deserialized");
+            assert_eq!(deserialized, profile);
+        }
     }
 }
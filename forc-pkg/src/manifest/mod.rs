@@ -14,7 +14,7 @@ use std::{
     sync::Arc,
 };
 use sway_core::{fuel_prelude::fuel_tx, language::parsed::TreeType, parse_tree_type, BuildTarget};
-use sway_error::handler::Handler;
+use sway_error::handler::{Handler, WarningPolicy};
 use sway_utils::{
     constants, find_nested_manifest_dir, find_parent_manifest_dir,
     find_parent_manifest_dir_with_check,
@@ -184,9 +184,15 @@ pub struct PackageManifest {
     pub patch: Option<BTreeMap<String, PatchMap>>,
     /// A list of [configuration-time constants](https://github.com/FuelLabs/sway/issues/1498).
     pub build_target: Option<BTreeMap<String, BuildTarget>>,
+    #[serde(
+        default,
+        deserialize_with = "build_profile::deserialize_build_profiles"
+    )]
     build_profile: Option<BTreeMap<String, BuildProfile>>,
     pub contract_dependencies: Option<BTreeMap<String, ContractDependency>>,
     pub proxy: Option<Proxy>,
+    pub build: Option<BuildHook>,
+    pub warnings: Option<Warnings>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
@@ -288,6 +294,90 @@ pub struct Proxy {
     pub address: Option<String>,
 }
 
+/// Describes a code generation hook which is run before the package is compiled.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct BuildHook {
+    /// The program to run followed by its arguments, e.g. `["cargo", "run", "--bin", "gen"]` or
+    /// `["forc", "gen-abi"]` for a forc plugin.
+    pub command: Vec<String>,
+    /// The files and directories, relative to the manifest directory, the generated sources are
+    /// derived from. The hook is only rerun once the contents of any of them change. If empty, the
+    /// hook is run on every build.
+    #[serde(default)]
+    pub inputs: Vec<String>,
+    /// The directory, relative to the `src` directory, the hook generates its sources into. The
+    /// hook receives its absolute path in the `OUT_DIR` environment variable.
+    #[serde(default = "default_build_hook_out_dir")]
+    pub out_dir: String,
+}
+
+impl BuildHook {
+    /// Checks that the hook has a command and that its output directory is within `src`.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.command.is_empty() {
+            bail!("The `command` of the `[build]` hook must not be empty");
+        }
+        use std::path::Component;
+        let components: Vec<_> = Path::new(&self.out_dir).components().collect();
+        if !components
+            .iter()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+            || !components
+                .iter()
+                .any(|component| matches!(component, Component::Normal(_)))
+        {
+            bail!(
+                "The `out-dir` of the `[build]` hook must be a relative path within the `src` directory, found {:?}",
+                self.out_dir
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Describes how the warnings of the package are treated.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Warnings {
+    /// Treat the warnings of the package as errors, like `--error-on-warnings` does.
+    #[serde(default)]
+    pub error_on_warnings: bool,
+    /// The warnings, given by their code, e.g. `W0009`, or their name, e.g. `DeadDeclaration`,
+    /// which are neither treated as errors nor counted against `max-warnings`.
+    #[serde(default)]
+    pub allow: Vec<String>,
+    /// The number of warnings tolerated before they fail the build, allowing to gradually lower
+    /// the number of warnings of a package.
+    pub max_warnings: Option<usize>,
+}
+
+impl Warnings {
+    /// Checks that all the allowed warnings exist.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        self.allowed_codes().map(|_| ())
+    }
+
+    /// Returns the codes of the allowed warnings, resolving the ones given by name.
+    pub fn allowed_codes(&self) -> anyhow::Result<Vec<String>> {
+        let warnings = sway_error::warning::Warning::all_codes();
+        self.allow
+            .iter()
+            .map(|allowed| {
+                warnings
+                    .iter()
+                    .find(|(code, name)| {
+                        code.as_str().eq_ignore_ascii_case(allowed) || name == allowed
+                    })
+                    .map(|(code, _)| code.as_str().to_string())
+                    .ok_or_else(|| {
+                        anyhow!("The allowed warning {allowed:?} of the `[warnings]` section is neither a warning code nor a warning name")
+                    })
+            })
+            .collect()
+    }
+}
+
 impl DependencyDetails {
     /// Checks if dependency details reserved for a specific dependency type used without the main
     /// detail for that type.
@@ -327,7 +417,7 @@ impl PackageManifestFile {
     /// If this package is a member of a workspace, patches are fetched from
     /// the workspace manifest file, ignoring any patch defined in the package
     /// manifest file, even if a patch section is not defined in the namespace.
-    fn resolve_patches(&self) -> Result<impl Iterator<Item = (String, PatchMap)>> {
+    pub fn resolve_patches(&self) -> Result<impl Iterator<Item = (String, PatchMap)>> {
         if let Some(workspace) = self.workspace().ok().flatten() {
             // If workspace is defined, passing a local patch is a warning, but the global patch is used
             if self.patch.is_some() {
@@ -411,6 +501,26 @@ impl PackageManifestFile {
             .and_then(|profiles| profiles.get(profile_name))
     }
 
+    /// The directory the `[build]` hook of the package generates its sources into, if it declares
+    /// one.
+    pub fn build_hook_out_dir(&self) -> Option<PathBuf> {
+        self.build
+            .as_ref()
+            .map(|hook| self.dir().join(constants::SRC_DIR).join(&hook.out_dir))
+    }
+
+    /// The policy deciding when the warnings of the package fail its build, combining its
+    /// `[warnings]` section with the `error_on_warnings` setting of the build profile.
+    pub fn warning_policy(&self, error_on_warnings: bool) -> WarningPolicy {
+        let warnings = self.warnings.clone().unwrap_or_default();
+        WarningPolicy {
+            error_on_warnings: error_on_warnings || warnings.error_on_warnings,
+            // The allowed warnings are validated when the manifest is loaded.
+            allowed: warnings.allowed_codes().unwrap_or_default(),
+            max_warnings: warnings.max_warnings,
+        }
+    }
+
     /// Given the name of a `path` dependency, returns the full canonical `Path` to the dependency.
     pub fn dep_path(&self, dep_name: &str) -> Option<PathBuf> {
         let dir = self.dir();
@@ -595,6 +705,8 @@ impl PackageManifest {
     /// 1. The project and organization names against a set of reserved/restricted keywords and patterns.
     /// 2. The validity of the details provided. Makes sure that there are no mismatching detail
     ///    declarations (to prevent mixing details specific to certain types).
+    /// 3. The validity of the `[build]` hook, if there is one.
+    /// 4. The existence of the allowed warnings of the `[warnings]` section, if there is one.
     pub fn validate(&self) -> Result<()> {
         validate_project_name(&self.project.name)?;
         if let Some(ref org) = self.project.organization {
@@ -603,6 +715,12 @@ impl PackageManifest {
         for (_, dependency_details) in self.deps_detailed() {
             dependency_details.validate()?;
         }
+        if let Some(build_hook) = &self.build {
+            build_hook.validate()?;
+        }
+        if let Some(warnings) = &self.warnings {
+            warnings.validate()?;
+        }
         Ok(())
     }
 
@@ -826,6 +944,10 @@ fn default_entry() -> String {
     PackageManifest::DEFAULT_ENTRY_FILE_NAME.to_string()
 }
 
+fn default_build_hook_out_dir() -> String {
+    "generated".to_string()
+}
+
 fn default_url() -> String {
     constants::DEFAULT_NODE_URL.into()
 }
@@ -1615,4 +1737,69 @@ mod tests {
         assert_eq!(original.workspace.members, deserialized.workspace.members);
         assert_eq!(original.workspace.metadata, deserialized.workspace.metadata);
     }
+
+    #[test]
+    fn test_build_hook_from_toml() {
+        let toml_str = r#"
+            command = ["cargo", "run", "--bin", "gen"]
+            inputs = ["idl/token.json"]
+        "#;
+
+        let hook: BuildHook = toml::from_str(toml_str).unwrap();
+        assert_eq!(hook.command, ["cargo", "run", "--bin", "gen"]);
+        assert_eq!(hook.inputs, ["idl/token.json"]);
+        assert_eq!(hook.out_dir, "generated");
+        assert!(hook.validate().is_ok());
+    }
+
+    #[test]
+    fn test_invalid_build_hook() {
+        let hook = BuildHook {
+            command: vec!["gen".to_string()],
+            inputs: vec![],
+            out_dir: "generated".to_string(),
+        };
+        assert!(hook.validate().is_ok());
+
+        let no_command = BuildHook {
+            command: vec![],
+            ..hook.clone()
+        };
+        assert!(no_command.validate().is_err());
+
+        for out_dir in ["", ".", "../generated", "/tmp/generated"] {
+            let invalid_out_dir = BuildHook {
+                out_dir: out_dir.to_string(),
+                ..hook.clone()
+            };
+            assert!(invalid_out_dir.validate().is_err(), "{out_dir:?}");
+        }
+    }
+
+    #[test]
+    fn test_warnings_from_toml() {
+        let (code, name) = sway_error::warning::Warning::all_codes().remove(0);
+        let toml_str = format!(
+            r#"
+            error-on-warnings = true
+            allow = ["{}", "{name}"]
+            max-warnings = 10
+        "#,
+            code.as_str().to_lowercase()
+        );
+
+        let warnings: Warnings = toml::from_str(&toml_str).unwrap();
+        assert!(warnings.error_on_warnings);
+        assert_eq!(warnings.max_warnings, Some(10));
+        assert_eq!(
+            warnings.allowed_codes().unwrap(),
+            [code.as_str(), code.as_str()]
+        );
+
+        let unknown = Warnings {
+            allow: vec!["NotAWarning".to_string()],
+            ..Default::default()
+        };
+        assert!(unknown.validate().is_err());
+    }
 }
@@ -0,0 +1,218 @@
+//! Structured messages describing the progress and results of a build.
+//!
+//! With [MessageFormat::Json], each [Message] is printed to stdout as a single line of JSON, so
+//! that external tools like IDEs or CI scripts can drive `forc build` without having to parse
+//! its human readable output.
+
+use serde::Serialize;
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use sway_core::language::parsed::TreeType;
+use sway_error::{error::CompileError, warning::CompileWarning};
+use sway_types::{LineCol, SourceEngine, Spanned};
+
+use crate::SWAY_BIN_ROOT_SUFFIX;
+
+/// The format in which the progress and results of a build are reported.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MessageFormat {
+    /// Human readable output only.
+    #[default]
+    Human,
+    /// A JSON [Message] per line on stdout, in addition to the human readable output on stderr.
+    Json,
+}
+
+impl MessageFormat {
+    pub fn is_json(&self) -> bool {
+        matches!(self, MessageFormat::Json)
+    }
+}
+
+impl FromStr for MessageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(MessageFormat::Human),
+            "json" => Ok(MessageFormat::Json),
+            _ => Err(format!(
+                "invalid message format `{s}`, expected `human` or `json`"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for MessageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MessageFormat::Human => write!(f, "human"),
+            MessageFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+/// A single message emitted during a build.
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "reason", rename_all = "kebab-case")]
+pub enum Message {
+    /// A package started compiling.
+    CompilingPackage {
+        package: String,
+        program_type: Option<String>,
+        source: String,
+    },
+    /// A warning or an error emitted while compiling a package.
+    Diagnostic(DiagnosticMessage),
+    /// The artifacts written for a built workspace member.
+    Artifacts(ArtifactsMessage),
+    /// The build finished, successfully or not.
+    BuildFinished {
+        success: bool,
+        profile: String,
+        /// The total size of the bytecode of all built members, in bytes.
+        bytecode_size: usize,
+        duration_secs: f64,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DiagnosticLevel {
+    Warning,
+    Error,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct DiagnosticMessage {
+    pub package: String,
+    pub level: DiagnosticLevel,
+    pub message: String,
+    /// The diagnostic code, if the diagnostic has one.
+    pub code: Option<String>,
+    pub span: Option<DiagnosticSpan>,
+}
+
+/// The location of a diagnostic, with 1-based lines and columns.
+#[derive(Clone, Debug, Serialize)]
+pub struct DiagnosticSpan {
+    pub path: PathBuf,
+    pub start: LineCol,
+    pub end: LineCol,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ArtifactsMessage {
+    pub package: String,
+    pub program_type: String,
+    pub bytecode: PathBuf,
+    pub bytecode_size: usize,
+    pub abi: PathBuf,
+    /// The initialized storage slots, only written for contracts.
+    pub storage_slots: Option<PathBuf>,
+    /// The storage layout, only written for contracts.
+    pub storage_layout: Option<PathBuf>,
+    /// The layout of the configurables, only written for programs with configurables.
+    pub configurables_layout: Option<PathBuf>,
+    /// The file containing the predicate root, only written for predicates.
+    pub predicate_root: Option<PathBuf>,
+}
+
+impl Message {
+    /// Prints the message to stdout as a single line of JSON.
+    pub fn emit(&self) {
+        match serde_json::to_string(self) {
+            Ok(json) => println!("{json}"),
+            Err(err) => tracing::error!("failed to serialize build message: {err}"),
+        }
+    }
+
+    /// Creates the messages for the given warnings and errors of `package`.
+    pub fn diagnostics(
+        source_engine: &SourceEngine,
+        package: &str,
+        warnings: &[CompileWarning],
+        errors: &[CompileError],
+    ) -> Vec<Message> {
+        let warnings = warnings.iter().map(|warning| {
+            diagnostic_message(
+                source_engine,
+                package,
+                DiagnosticLevel::Warning,
+                warning.to_friendly_warning_string(),
+                Some(warning.code().as_str()),
+                warning,
+            )
+        });
+        let errors = errors.iter().map(|error| {
+            diagnostic_message(
+                source_engine,
+                package,
+                DiagnosticLevel::Error,
+                error.to_string(),
+                Some(error.code().as_str()),
+                error,
+            )
+        });
+        warnings.chain(errors).map(Message::Diagnostic).collect()
+    }
+}
+
+fn diagnostic_message(
+    source_engine: &SourceEngine,
+    package: &str,
+    level: DiagnosticLevel,
+    message: String,
+    code: Option<&str>,
+    spanned: &impl Spanned,
+) -> DiagnosticMessage {
+    let span = spanned.span();
+    let span = span.source_id().map(|source_id| {
+        let line_col = span.line_col();
+        DiagnosticSpan {
+            path: source_engine.get_path(source_id),
+            start: line_col.start,
+            end: line_col.end,
+        }
+    });
+    DiagnosticMessage {
+        package: package.to_string(),
+        level,
+        message,
+        code: code.map(str::to_string),
+        span,
+    }
+}
+
+impl ArtifactsMessage {
+    /// Describes the artifacts that `BuiltPackage::write_output` writes to `output_dir`.
+    pub fn new(
+        tree_type: &TreeType,
+        pkg_name: &str,
+        output_dir: &Path,
+        bytecode_size: usize,
+        has_configurables: bool,
+    ) -> Self {
+        let is_contract = matches!(tree_type, TreeType::Contract);
+        let json_artifact = |suffix: &str| {
+            output_dir
+                .join(format!("{pkg_name}-{suffix}"))
+                .with_extension("json")
+        };
+        ArtifactsMessage {
+            package: pkg_name.to_string(),
+            program_type: forc_util::program_type_str(tree_type).to_string(),
+            bytecode: output_dir.join(pkg_name).with_extension("bin"),
+            bytecode_size,
+            abi: json_artifact("abi"),
+            storage_slots: is_contract.then(|| json_artifact("storage_slots")),
+            storage_layout: is_contract.then(|| json_artifact("storage_layout")),
+            configurables_layout: has_configurables.then(|| json_artifact("configurables_layout")),
+            predicate_root: matches!(tree_type, TreeType::Predicate)
+                .then(|| output_dir.join(format!("{pkg_name}{SWAY_BIN_ROOT_SUFFIX}"))),
+        }
+    }
+}
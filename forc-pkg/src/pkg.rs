@@ -1,13 +1,15 @@
 use crate::manifest::GenericManifestFile;
 use crate::{
+    build_hook::run_build_hook,
     lock::Lock,
     manifest::{Dependency, ManifestFile, MemberManifestFiles, PackageManifestFile},
+    message::{ArtifactsMessage, Message, MessageFormat},
     source::{self, IPFSNode, Source},
     BuildProfile,
 };
 use anyhow::{anyhow, bail, Context, Error, Result};
 use byte_unit::{Byte, UnitType};
-use forc_tracing::{println_action_green, println_warning};
+use forc_tracing::{println_action_green, println_red_err, println_warning};
 use forc_util::{
     default_output_directory, find_file_name, kebab_to_snake_case, print_compiling,
     print_on_failure, print_warnings,
@@ -34,22 +36,37 @@ use sway_core::{
         evm_abi,
         fuel_abi::{self, AbiContext},
     },
-    asm_generation::ProgramABI,
+    analysis::AnalysisPasses,
+    asm_generation::{DataSectionSize, ProgramABI},
     decl_engine::DeclRefFunction,
     fuel_prelude::{
         fuel_crypto,
         fuel_tx::{self, Contract, ContractId, StorageSlot},
     },
-    language::{parsed::TreeType, Visibility},
+    language::{
+        parsed::TreeType,
+        ty::{
+            ConfigurablesLayout, ContractCallDescriptor, ErrorSelector, EventDescriptor,
+            StorageLayout,
+        },
+        Visibility,
+    },
     semantic_analysis::namespace,
     source_map::SourceMap,
-    transform::AttributeKind,
+    transform::{Attribute, AttributeKind},
     write_dwarf, BuildTarget, Engines, FinalizedEntry, LspConfig,
 };
-use sway_core::{set_bytecode_configurables_offset, PrintAsm, PrintIr};
-use sway_error::{error::CompileError, handler::Handler, warning::CompileWarning};
+use sway_core::{set_bytecode_configurables_offset, PrintAsm, PrintIr, VerifyIr, VerifyIrLevel};
+use sway_error::{
+    error::CompileError,
+    handler::{Handler, WarningPolicyViolation},
+    warning::CompileWarning,
+};
 use sway_features::ExperimentalFeatures;
-use sway_types::constants::{CORE, PRELUDE, STD};
+use sway_types::constants::{
+    CORE, MOCK_ATTRIBUTE_NAME, MOCK_CONTRACT_ARG_NAME, MOCK_METHOD_ARG_NAME, MOCK_RETURNS_ARG_NAME,
+    PRELUDE, STD,
+};
 use sway_types::{Ident, Span, Spanned};
 use sway_utils::{constants, time_expr, PerformanceData, PerformanceMetric};
 use tracing::{debug, info};
@@ -105,6 +122,16 @@ pub struct BuiltPackage {
     pub descriptor: PackageDescriptor,
     pub program_abi: ProgramABI,
     pub storage_slots: Vec<StorageSlot>,
+    /// The layout of the contract storage. Empty for non-contract packages.
+    pub storage_layout: StorageLayout,
+    /// The offsets, sizes and encodings of the configurables in the bytecode.
+    pub configurables_layout: ConfigurablesLayout,
+    /// The error codes of the variants of all `#[error_type]` enums in the package.
+    pub error_selectors: Vec<ErrorSelector>,
+    /// The indexed fields of all logged `#[event]` structs in the package.
+    pub events: Vec<EventDescriptor>,
+    /// The external contract calls the package can make.
+    pub contract_calls: Vec<ContractCallDescriptor>,
     pub warnings: Vec<CompileWarning>,
     pub source_map: SourceMap,
     pub tree_type: TreeType,
@@ -132,6 +159,8 @@ pub struct PackageDescriptor {
 pub struct BuiltPackageBytecode {
     pub bytes: Vec<u8>,
     pub entries: Vec<PkgEntry>,
+    /// The size of the data section, broken down by the origin of its entries.
+    pub data_section_size: DataSectionSize,
 }
 
 /// Represents a package entry point.
@@ -152,17 +181,56 @@ pub enum PkgEntryKind {
 #[derive(Debug, Clone)]
 pub enum TestPassCondition {
     ShouldRevert(Option<u64>),
+    /// The test must revert after logging a value containing the given message.
+    ShouldPanic(String),
     ShouldNotRevert,
 }
 
+/// The role of a `#[test]` function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestKind {
+    /// A unit test, executed on its own.
+    Unit,
+    /// A step of an invariant test, declared with `#[test(action)]`. Actions are executed in
+    /// random sequences, and the invariants are checked after each of them.
+    Action,
+    /// A property that must hold after any sequence of actions, declared with
+    /// `#[test(invariant)]`. The invariant is broken if the function reverts.
+    Invariant,
+}
+
 /// Data specific to the test entry point.
 #[derive(Debug, Clone)]
 pub struct PkgTestEntry {
+    pub kind: TestKind,
     pub pass_condition: TestPassCondition,
+    /// The wall-clock time limit for the test, set with `#[test(timeout_ms = ...)]`.
+    pub timeout_ms: Option<u64>,
+    /// The limit of the gas used by the test, set with `#[test(gas_limit = ...)]`.
+    pub gas_limit: Option<u64>,
+    /// The external contract calls mocked by the test, set with `#[mock(...)]`.
+    pub mocks: Vec<PkgTestMock>,
+    /// The user-defined tags of the test, set with `#[test(tag = "...")]`.
+    pub tags: Vec<String>,
+    /// The path of the module declaring the test, relative to the root module of the package,
+    /// e.g. `["module", "submodule"]`. Empty for tests declared in the root module.
+    pub module_path: Vec<String>,
     pub span: Span,
     pub file_path: Arc<PathBuf>,
 }
 
+/// An external contract call mocked by a test, e.g.
+/// `#[mock(contract = "0x...", method = "balance", returns = "0x000000000000002a")]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PkgTestMock {
+    /// The id of the called contract.
+    pub contract_id: [u8; 32],
+    /// The name of the called ABI method, or `None` to mock all methods of the contract.
+    pub method: Option<String>,
+    /// The encoded value returned by the mocked call.
+    pub return_data: Vec<u8>,
+}
+
 /// The result of successfully compiling a workspace.
 pub type BuiltWorkspace = Vec<Arc<BuiltPackage>>;
 
@@ -180,6 +248,11 @@ pub struct CompiledPackage {
     pub tree_type: TreeType,
     pub program_abi: ProgramABI,
     pub storage_slots: Vec<StorageSlot>,
+    pub storage_layout: StorageLayout,
+    pub configurables_layout: ConfigurablesLayout,
+    pub error_selectors: Vec<ErrorSelector>,
+    pub events: Vec<EventDescriptor>,
+    pub contract_calls: Vec<ContractCallDescriptor>,
     pub bytecode: BuiltPackageBytecode,
     pub root_module: namespace::Module,
     pub warnings: Vec<CompileWarning>,
@@ -262,6 +335,8 @@ pub struct PrintOpts {
     pub bytecode_spans: bool,
     /// Print the generated Sway IR (Intermediate Representation).
     pub ir: PrintIr,
+    /// Verify the generated Sway IR while running the optimization passes.
+    pub verify_ir: VerifyIr,
     /// Output build errors and warnings in reverse order.
     pub reverse_order: bool,
 }
@@ -314,6 +389,10 @@ pub struct BuildOpts {
     pub experimental: Vec<sway_features::Feature>,
     /// Set of disabled experimental flags
     pub no_experimental: Vec<sway_features::Feature>,
+    /// The format in which the progress and results of the build are reported.
+    pub message_format: MessageFormat,
+    /// Third-party analysis passes to run over every compiled package.
+    pub analysis_passes: AnalysisPasses,
 }
 
 /// The set of options to filter type of projects to build in a workspace.
@@ -500,11 +579,85 @@ impl BuiltPackage {
         let json_abi_path = output_dir.join(program_abi_stem).with_extension("json");
         self.write_json_abi(&json_abi_path, minify)?;
 
+        // Publish the error codes next to the ABI, so that reverts can be decoded to typed errors.
+        if !self.error_selectors.is_empty() {
+            let error_codes_stem = format!("{pkg_name}-error_codes");
+            let error_codes_path = output_dir.join(error_codes_stem).with_extension("json");
+            let error_codes_file = File::create(error_codes_path)?;
+            let res = if minify.json_abi {
+                serde_json::to_writer(&error_codes_file, &self.error_selectors)
+            } else {
+                serde_json::to_writer_pretty(&error_codes_file, &self.error_selectors)
+            };
+
+            res?;
+        }
+
+        // Publish the layout of the configurables, so that SDKs can set their values in the
+        // bytecode.
+        if !self.configurables_layout.configurables.is_empty() {
+            let configurables_layout_stem = format!("{pkg_name}-configurables_layout");
+            let configurables_layout_path = output_dir
+                .join(configurables_layout_stem)
+                .with_extension("json");
+            let configurables_layout_file = File::create(configurables_layout_path)?;
+            let res = if minify.json_abi {
+                serde_json::to_writer(&configurables_layout_file, &self.configurables_layout)
+            } else {
+                serde_json::to_writer_pretty(&configurables_layout_file, &self.configurables_layout)
+            };
+
+            res?;
+        }
+
+        // Publish the indexed fields of the events next to the ABI, so that indexers can filter
+        // logs without decoding them.
+        if !self.events.is_empty() {
+            let events_stem = format!("{pkg_name}-events");
+            let events_path = output_dir.join(events_stem).with_extension("json");
+            let events_file = File::create(events_path)?;
+            let res = if minify.json_abi {
+                serde_json::to_writer(&events_file, &self.events)
+            } else {
+                serde_json::to_writer_pretty(&events_file, &self.events)
+            };
+
+            res?;
+        }
+
+        // Publish the external contract calls next to the ABI, so that the call graph of a whole
+        // dapp can be built from the artifacts of its contracts and scripts.
+        if !self.contract_calls.is_empty() {
+            let contract_calls_stem = format!("{pkg_name}-contract_calls");
+            let contract_calls_path = output_dir.join(contract_calls_stem).with_extension("json");
+            let contract_calls_file = File::create(contract_calls_path)?;
+            let res = if minify.json_abi {
+                serde_json::to_writer(&contract_calls_file, &self.contract_calls)
+            } else {
+                serde_json::to_writer_pretty(&contract_calls_file, &self.contract_calls)
+            };
+
+            res?;
+        }
+
         debug!(
             "      Bytecode size: {} bytes ({})",
             self.bytecode.bytes.len(),
             format_bytecode_size(self.bytecode.bytes.len())
         );
+        let data_section_size = &self.bytecode.data_section_size;
+        if data_section_size.total() > 0 {
+            info!(
+                "  Data section size of {pkg_name}: {} bytes ({} configurables, {} words, {} aggregates, {} pointers, {} padding; {} deduplicated)",
+                data_section_size.total(),
+                data_section_size.configurables,
+                data_section_size.words,
+                data_section_size.aggregates,
+                data_section_size.pointers,
+                data_section_size.padding,
+                data_section_size.deduplicated,
+            );
+        }
         // Additional ops required depending on the program type
         match self.tree_type {
             TreeType::Contract => {
@@ -519,6 +672,20 @@ impl BuiltPackage {
                 };
 
                 res?;
+
+                // Also emit the storage layout, describing how the key of each field is
+                // derived and which slots it occupies.
+                let storage_layout_stem = format!("{pkg_name}-storage_layout");
+                let storage_layout_path =
+                    output_dir.join(storage_layout_stem).with_extension("json");
+                let storage_layout_file = File::create(storage_layout_path)?;
+                let res = if minify.json_storage_slots {
+                    serde_json::to_writer(&storage_layout_file, &self.storage_layout)
+                } else {
+                    serde_json::to_writer_pretty(&storage_layout_file, &self.storage_layout)
+                };
+
+                res?;
             }
             TreeType::Predicate => {
                 // Get the root hash of the bytecode for predicates and store the result in a file in the output directory
@@ -938,18 +1105,19 @@ fn validate_graph(graph: &Graph, manifests: &MemberManifestFiles) -> Result<BTre
     let mut visited = HashSet::new();
     let edges = member_nodes
         .into_iter()
-        .flat_map(move |(n, _)| validate_deps(graph, n, manifests, &mut visited))
+        .flat_map(move |(n, member)| validate_deps(graph, n, member, manifests, &mut visited))
         .collect();
 
     Ok(edges)
 }
 
-/// Recursively validate all dependencies of the given `node`.
+/// Recursively validate all dependencies of the given `node` within the graph of `member`.
 ///
 /// Returns the set of invalid dependency edges.
 fn validate_deps(
     graph: &Graph,
     node: NodeIx,
+    member: &PackageManifestFile,
     manifests: &MemberManifestFiles,
     visited: &mut HashSet<NodeIx>,
 ) -> BTreeSet<EdgeIx> {
@@ -957,13 +1125,13 @@ fn validate_deps(
     for edge in graph.edges_directed(node, Direction::Outgoing) {
         let dep_name = edge.weight();
         let dep_node = edge.target();
-        match validate_dep(graph, manifests, dep_name, dep_node) {
+        match validate_dep(graph, member, manifests, dep_name, dep_node) {
             Err(_) => {
                 remove.insert(edge.id());
             }
             Ok(_) => {
                 if visited.insert(dep_node) {
-                    let rm = validate_deps(graph, dep_node, manifests, visited);
+                    let rm = validate_deps(graph, dep_node, member, manifests, visited);
                     remove.extend(rm);
                 }
                 continue;
@@ -978,6 +1146,7 @@ fn validate_deps(
 /// Returns the `ManifestFile` in the case that the dependency is valid.
 fn validate_dep(
     graph: &Graph,
+    member: &PackageManifestFile,
     manifests: &MemberManifestFiles,
     dep_edge: &Edge,
     dep_node: NodeIx,
@@ -987,7 +1156,7 @@ fn validate_dep(
         .get(dep_name)
         .ok_or_else(|| anyhow!("Couldn't find manifest file for {}", dep_name))?;
     // Check the validity of the dependency path, including its path root.
-    let dep_path = dep_path(graph, node_manifest, dep_node, manifests).map_err(|e| {
+    let dep_path = dep_path(graph, member, node_manifest, dep_node, manifests).map_err(|e| {
         anyhow!(
             "failed to construct path for dependency {:?}: {}",
             dep_name,
@@ -1003,7 +1172,7 @@ fn validate_dep(
         .dep(dep_name)
         .ok_or_else(|| anyhow!("no entry in parent manifest"))?;
     let dep_source =
-        Source::from_manifest_dep_patched(node_manifest, dep_name, dep_entry, manifests)?;
+        Source::from_manifest_dep_patched(member, node_manifest, dep_name, dep_entry, manifests)?;
     let dep_pkg = graph[dep_node].unpinned(&dep_path);
     if dep_pkg.source != dep_source {
         bail!("dependency node's source does not match manifest entry");
@@ -1051,6 +1220,7 @@ fn validate_dep_manifest(
 /// invalid.
 fn dep_path(
     graph: &Graph,
+    member: &PackageManifestFile,
     node_manifest: &PackageManifestFile,
     dep_node: NodeIx,
     manifests: &MemberManifestFiles,
@@ -1069,11 +1239,18 @@ fn dep_path(
                 }
             }
 
-            // Otherwise, check if it comes from a patch.
-            for (_, patch_map) in node_manifest.patches() {
+            // Otherwise, check if it comes from a patch, either of the member whose graph the
+            // dependency belongs to or of the node itself.
+            let member_patches = member
+                .resolve_patches()?
+                .map(|(_, patch_map)| (member.dir(), patch_map));
+            let node_patches = node_manifest
+                .patches()
+                .map(|(_, patch_map)| (node_manifest.dir(), patch_map.clone()));
+            for (patch_dir, patch_map) in member_patches.chain(node_patches) {
                 if let Some(Dependency::Detailed(details)) = patch_map.get(&dep_name.to_string()) {
                     if let Some(ref rel_path) = details.path {
-                        if let Ok(path) = node_manifest.dir().join(rel_path).canonicalize() {
+                        if let Ok(path) = patch_dir.join(rel_path).canonicalize() {
                             if path.exists() {
                                 return Ok(path);
                             }
@@ -1277,13 +1454,14 @@ fn pkg_graph_to_manifest_map(
                 Some((parent_manifest, dep_name))
             })
             .ok_or_else(|| anyhow!("more than one root package detected in graph"))?;
-        let dep_path = dep_path(graph, parent_manifest, dep_node, manifests).map_err(|e| {
-            anyhow!(
-                "failed to construct path for dependency {:?}: {}",
-                dep_name,
-                e
-            )
-        })?;
+        let dep_path = dep_path(graph, proj_manifest, parent_manifest, dep_node, manifests)
+            .map_err(|e| {
+                anyhow!(
+                    "failed to construct path for dependency {:?}: {}",
+                    dep_name,
+                    e
+                )
+            })?;
         let dep_manifest = PackageManifestFile::from_dir(&dep_path)?;
         let dep = &graph[dep_node];
         manifest_map.insert(dep.id(), dep_manifest);
@@ -1415,6 +1593,7 @@ fn fetch_pkg_graph(
         fetch_id,
         offline,
         ipfs_node,
+        proj_manifest,
         proj_node,
         path_root,
         graph,
@@ -1427,12 +1606,14 @@ fn fetch_pkg_graph(
 
 /// Visit the unvisited dependencies of the given node and fetch missing nodes as necessary.
 ///
-/// Assumes the `node`'s manifest already exists within the `manifest_map`.
+/// Assumes the `node`'s manifest already exists within the `manifest_map`. The patches of
+/// `member` are applied to all dependencies within its graph.
 #[allow(clippy::too_many_arguments)]
 fn fetch_deps(
     fetch_id: u64,
     offline: bool,
     ipfs_node: &IPFSNode,
+    member: &PackageManifestFile,
     node: NodeIx,
     path_root: PinnedId,
     graph: &mut Graph,
@@ -1463,9 +1644,14 @@ fn fetch_deps(
     for (dep_name, dep, dep_kind) in deps {
         let name = dep.package().unwrap_or(&dep_name);
         let parent_manifest = &manifest_map[&parent_id];
-        let source =
-            Source::from_manifest_dep_patched(parent_manifest, name, &dep, member_manifests)
-                .context(format!("Failed to source dependency: {dep_name}"))?;
+        let source = Source::from_manifest_dep_patched(
+            member,
+            parent_manifest,
+            name,
+            &dep,
+            member_manifests,
+        )
+        .context(format!("Failed to source dependency: {dep_name}"))?;
 
         // If we haven't yet fetched this dependency, fetch it, pin it and add it to the graph.
         let dep_pkg = Pkg {
@@ -1526,6 +1712,7 @@ fn fetch_deps(
             fetch_id,
             offline,
             ipfs_node,
+            member,
             dep_node,
             path_root,
             graph,
@@ -1546,6 +1733,13 @@ pub fn sway_build_config(
     build_target: BuildTarget,
     build_profile: &BuildProfile,
 ) -> Result<sway_core::BuildConfig> {
+    if let Some(pass) = build_profile.ir_passes.unknown_pass() {
+        bail!(
+            "unknown IR pass `{pass}` in the `ir-passes` of build profile `{}`",
+            build_profile.name
+        );
+    }
+
     // Prepare the build config to pass through to the compiler.
     let file_name = find_file_name(manifest_dir, entry_path)?;
     let build_config = sway_core::BuildConfig::root_from_file_name_and_manifest_path(
@@ -1561,11 +1755,21 @@ pub fn sway_build_config(
         build_profile.print_bytecode_spans,
     )
     .with_print_ir(build_profile.print_ir.clone())
+    .with_verify_ir(build_profile.verify_ir.clone())
     .with_include_tests(build_profile.include_tests)
     .with_time_phases(build_profile.time_phases)
     .with_profile(build_profile.profile)
     .with_metrics(build_profile.metrics_outfile.clone())
-    .with_optimization_level(build_profile.optimization_level);
+    .with_optimization_level(build_profile.optimization_level)
+    .with_ir_passes(build_profile.ir_passes.clone())
+    .with_inline_heuristics(build_profile.inline.heuristics())
+    .with_loop_unroll_heuristics(build_profile.loop_unroll.heuristics())
+    .with_monomorphization_limits(
+        build_profile.monomorphization_report,
+        build_profile.monomorphization_budget,
+    )
+    .with_taint_analysis(build_profile.taint_analysis)
+    .with_analysis_passes(build_profile.analysis_passes.clone());
     Ok(build_config)
 }
 
@@ -1767,7 +1971,12 @@ pub fn compile(
     let terse_mode = profile.terse;
     let reverse_results = profile.reverse_results;
     let fail = |handler: Handler| {
-        let (errors, warnings) = handler.consume();
+        let (errors, warnings) = handler.consume_sorted(engines.se());
+        if profile.message_format.is_json() {
+            Message::diagnostics(engines.se(), &pkg.name, &warnings, &errors)
+                .iter()
+                .for_each(Message::emit);
+        }
         print_on_failure(
             engines.se(),
             terse_mode,
@@ -1804,6 +2013,7 @@ pub fn compile(
         Err(_) => return fail(handler),
         Ok(programs) => programs,
     };
+    metrics.reused_programs = programs.metrics.reused_programs;
     let typed_program = match programs.typed.as_ref() {
         Err(_) => return fail(handler),
         Ok(typed_program) => typed_program,
@@ -1814,6 +2024,10 @@ pub fn compile(
     }
 
     let storage_slots = typed_program.storage_slots.clone();
+    let storage_layout = typed_program.storage_layout.clone();
+    let error_selectors = typed_program.error_selectors.clone();
+    let events = typed_program.events.clone();
+    let contract_calls = typed_program.contract_calls.clone();
     let tree_type = typed_program.kind.tree_type();
 
     let namespace = typed_program.root.namespace.clone();
@@ -1879,7 +2093,9 @@ pub fn compile(
                     Some(ProgramABI::Evm(ops)) => ops.clone(),
                     _ => vec![],
                 },
-                _ => vec![],
+                // The types of the entry functions are only known to be ABI encodable once
+                // the ASM backend accepted them.
+                _ => return fail(handler),
             };
 
             let abi = time_expr!(
@@ -1926,15 +2142,33 @@ pub fn compile(
         metrics
     );
 
-    let errored = handler.has_errors() || (handler.has_warnings() && profile.error_on_warnings);
+    let warning_policy = pkg.manifest_file.warning_policy(profile.error_on_warnings);
+    let warning_violation = handler.check_warnings(&warning_policy);
+    let errored = handler.has_errors() || warning_violation.is_some();
 
     let mut compiled = match bc_res {
         Ok(compiled) if !errored => compiled,
-        _ => return fail(handler),
+        _ => {
+            let budget_exceeded = !handler.has_errors()
+                && matches!(
+                    warning_violation,
+                    Some(WarningPolicyViolation::BudgetExceeded { .. })
+                );
+            let res = fail(handler);
+            if budget_exceeded {
+                println_red_err(&format!("  {}", warning_violation.unwrap()));
+            }
+            return res;
+        }
     };
 
-    let (_, warnings) = handler.consume();
+    let (_, warnings) = handler.consume_sorted(engines.se());
 
+    if profile.message_format.is_json() {
+        Message::diagnostics(engines.se(), &pkg.name, &warnings, &[])
+            .iter()
+            .for_each(Message::emit);
+    }
     print_warnings(engines.se(), terse_mode, &pkg.name, &warnings, &tree_type);
 
     // Metadata to be placed into the binary.
@@ -1969,15 +2203,28 @@ pub fn compile(
         set_bytecode_configurables_offset(&mut compiled, &md);
     }
 
+    let configurables_layout = ConfigurablesLayout::new(
+        engines,
+        &typed_program.configurables,
+        &compiled,
+        experimental.new_encoding,
+    );
+
     metrics.bytecode_size = compiled.bytecode.len();
     let bytecode = BuiltPackageBytecode {
         bytes: compiled.bytecode,
         entries,
+        data_section_size: compiled.data_section_size,
     };
     let compiled_package = CompiledPackage {
         source_map: source_map.clone(),
         program_abi,
         storage_slots,
+        storage_layout,
+        configurables_layout,
+        error_selectors,
+        events,
+        contract_calls,
         tree_type,
         bytecode,
         root_module: namespace.root_module().clone(),
@@ -2099,13 +2346,44 @@ impl PkgTestEntry {
         let test_function_decl = engines.de().get_function(decl_ref);
 
         const FAILING_TEST_KEYWORD: &str = "should_revert";
+        const PANICKING_TEST_KEYWORD: &str = "should_panic";
+        const TIMEOUT_KEYWORD: &str = "timeout_ms";
+        const GAS_LIMIT_KEYWORD: &str = "gas_limit";
+        const ACTION_KEYWORD: &str = "action";
+        const INVARIANT_KEYWORD: &str = "invariant";
+        const TAG_KEYWORD: &str = "tag";
+
+        let test_attr_args = || {
+            test_function_decl
+                .attributes
+                .get(&AttributeKind::Test)
+                .expect("test declaration is missing test attribute")
+                .iter()
+                .flat_map(|attr| attr.args.iter())
+        };
 
-        let test_args: HashMap<String, Option<String>> = test_function_decl
-            .attributes
-            .get(&AttributeKind::Test)
-            .expect("test declaration is missing test attribute")
-            .iter()
-            .flat_map(|attr| attr.args.iter())
+        // A test can have any number of tags, so they are collected before the other arguments,
+        // which can only be given once.
+        let tags = test_attr_args()
+            .filter(|arg| arg.name.as_str() == TAG_KEYWORD)
+            .map(|arg| {
+                let test_name = &test_function_decl.name;
+                arg.value
+                    .as_ref()
+                    .and_then(|val| {
+                        let tag = val.span().as_str().strip_prefix('"')?.strip_suffix('"')?;
+                        (!tag.is_empty()).then(|| tag.to_string())
+                    })
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Expected a non-empty string literal for `{TAG_KEYWORD}` in test: {test_name}."
+                        )
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut test_args: HashMap<String, Option<String>> = test_attr_args()
+            .filter(|arg| arg.name.as_str() != TAG_KEYWORD)
             .map(|arg| {
                 (
                     arg.name.to_string(),
@@ -2116,17 +2394,75 @@ impl PkgTestEntry {
             })
             .collect();
 
+        let mut numeric_arg = |keyword: &str, expected: &str| {
+            test_args
+                .remove(keyword)
+                .map(|arg| {
+                    let test_name = &test_function_decl.name;
+                    arg.as_deref()
+                        .and_then(parse_numeric_test_arg)
+                        .ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "Expected {expected} for `{keyword}` in test: {test_name}."
+                            )
+                        })
+                })
+                .transpose()
+        };
+        let timeout_ms = numeric_arg(TIMEOUT_KEYWORD, "a number of milliseconds")?;
+        let gas_limit = numeric_arg(GAS_LIMIT_KEYWORD, "an amount of gas")?;
+
+        let kind = match (
+            test_args.remove(ACTION_KEYWORD).is_some(),
+            test_args.remove(INVARIANT_KEYWORD).is_some(),
+        ) {
+            (false, false) => TestKind::Unit,
+            (true, false) => TestKind::Action,
+            (false, true) => TestKind::Invariant,
+            (true, true) => {
+                let test_name = &test_function_decl.name;
+                bail!("A test cannot be both an `{ACTION_KEYWORD}` and an `{INVARIANT_KEYWORD}`: {test_name}.")
+            }
+        };
+        if kind != TestKind::Unit && !test_args.is_empty() {
+            let test_name = &test_function_decl.name;
+            bail!("Actions and invariants cannot have an expected revert or panic: {test_name}.")
+        }
+
+        let mocks = test_function_decl
+            .attributes
+            .get(&AttributeKind::Mock)
+            .into_iter()
+            .flatten()
+            .map(|attr| PkgTestMock::from_attribute(attr, &test_function_decl.name))
+            .collect::<Result<Vec<_>>>()?;
+
         let pass_condition = if test_args.is_empty() {
             anyhow::Ok(TestPassCondition::ShouldNotRevert)
         } else if let Some(args) = test_args.get(FAILING_TEST_KEYWORD) {
             let expected_revert_code = args
-                .as_ref()
+                .as_deref()
                 .map(|arg| {
-                    let arg_str = arg.replace('"', "");
-                    arg_str.parse::<u64>()
+                    let test_name = &test_function_decl.name;
+                    parse_numeric_test_arg(arg).ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Expected a revert code for `{FAILING_TEST_KEYWORD}` in test: {test_name}."
+                        )
+                    })
                 })
                 .transpose()?;
             anyhow::Ok(TestPassCondition::ShouldRevert(expected_revert_code))
+        } else if let Some(args) = test_args.get(PANICKING_TEST_KEYWORD) {
+            let test_name = &test_function_decl.name;
+            let expected_message = args
+                .as_ref()
+                .and_then(|arg| arg.strip_prefix('"')?.strip_suffix('"'))
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Expected a string literal message for `{PANICKING_TEST_KEYWORD}` in test: {test_name}."
+                    )
+                })?;
+            anyhow::Ok(TestPassCondition::ShouldPanic(expected_message.to_string()))
         } else {
             let test_name = &test_function_decl.name;
             bail!("Invalid test argument(s) for test: {test_name}.")
@@ -2138,14 +2474,99 @@ impl PkgTestEntry {
                     .ok_or_else(|| anyhow::anyhow!("Missing span for test function"))?,
             ),
         );
+        // The first prefix of the full path of the test is the name of the package.
+        let module_path = test_function_decl
+            .call_path
+            .prefixes
+            .iter()
+            .skip(1)
+            .map(|prefix| prefix.to_string())
+            .collect();
+
         Ok(Self {
+            kind,
             pass_condition,
+            timeout_ms,
+            gas_limit,
+            mocks,
+            tags,
+            module_path,
             span,
             file_path,
         })
     }
 }
 
+/// Parses the value of a numeric `#[test]` argument, given either as an integer literal, e.g.
+/// `1_000` or `0x3e8`, or as a string literal containing one, e.g. `"1000"`.
+fn parse_numeric_test_arg(arg: &str) -> Option<u64> {
+    let arg = arg
+        .strip_prefix('"')
+        .and_then(|arg| arg.strip_suffix('"'))
+        .unwrap_or(arg);
+    let (digits, radix) = match arg.get(..2) {
+        Some("0x") => (&arg[2..], 16),
+        Some("0o") => (&arg[2..], 8),
+        Some("0b") => (&arg[2..], 2),
+        _ => (arg, 10),
+    };
+    // Underscores can separate the digits, but not precede them.
+    if digits.is_empty() || digits.starts_with('_') {
+        return None;
+    }
+    u64::from_str_radix(&digits.replace('_', ""), radix).ok()
+}
+
+impl PkgTestMock {
+    fn from_attribute(attr: &Attribute, test_name: &Ident) -> Result<Self> {
+        let mut contract_id = None;
+        let mut method = None;
+        let mut return_data = None;
+        for arg in &attr.args {
+            let value = arg
+                .value
+                .as_ref()
+                .and_then(|val| {
+                    let val = val.span();
+                    let val = val.as_str().strip_prefix('"')?.strip_suffix('"')?;
+                    Some(val.to_string())
+                })
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Expected a string literal for `{}` of `{MOCK_ATTRIBUTE_NAME}` in test: {test_name}.",
+                        arg.name
+                    )
+                })?;
+            match arg.name.as_str() {
+                MOCK_CONTRACT_ARG_NAME => {
+                    let bytes = decode_hex(&value)?;
+                    contract_id = Some(<[u8; 32]>::try_from(bytes).map_err(|_| {
+                        anyhow!("Expected a 32 byte contract id for `{MOCK_CONTRACT_ARG_NAME}` of `{MOCK_ATTRIBUTE_NAME}` in test: {test_name}.")
+                    })?);
+                }
+                MOCK_METHOD_ARG_NAME => method = Some(value),
+                MOCK_RETURNS_ARG_NAME => return_data = Some(decode_hex(&value)?),
+                name => bail!(
+                    "Unknown argument `{name}` of `{MOCK_ATTRIBUTE_NAME}` in test: {test_name}."
+                ),
+            }
+        }
+        let missing =
+            |arg: &str| anyhow!("Missing `{arg}` of `{MOCK_ATTRIBUTE_NAME}` in test: {test_name}.");
+        Ok(Self {
+            contract_id: contract_id.ok_or_else(|| missing(MOCK_CONTRACT_ARG_NAME))?,
+            method,
+            return_data: return_data.ok_or_else(|| missing(MOCK_RETURNS_ARG_NAME))?,
+        })
+    }
+}
+
+/// Decodes a hex string, with or without the `0x` prefix.
+fn decode_hex(value: &str) -> Result<Vec<u8>> {
+    let value = value.strip_prefix("0x").unwrap_or(value);
+    hex::decode(value).map_err(|err| anyhow!("Invalid hex string \"{value}\": {err}"))
+}
+
 /// The suffix that helps identify the file which contains the hash of the binary file created when
 /// scripts are built_package.
 pub const SWAY_BIN_HASH_SUFFIX: &str = "-bin-hash";
@@ -2169,6 +2590,8 @@ fn build_profile_from_opts(
         metrics_outfile,
         tests,
         error_on_warnings,
+        message_format,
+        analysis_passes,
         ..
     } = build_options;
 
@@ -2199,6 +2622,9 @@ fn build_profile_from_opts(
             .clone_from(&print.dca_graph_url_format);
     }
     profile.print_ir |= print.ir.clone();
+    if print.verify_ir.level != VerifyIrLevel::Initial {
+        profile.verify_ir.clone_from(&print.verify_ir);
+    }
     profile.print_asm |= print.asm;
     profile.print_bytecode |= print.bytecode;
     profile.print_bytecode_spans |= print.bytecode_spans;
@@ -2210,6 +2636,8 @@ fn build_profile_from_opts(
     }
     profile.include_tests |= tests;
     profile.error_on_warnings |= error_on_warnings;
+    profile.message_format = *message_format;
+    profile.analysis_passes.clone_from(analysis_passes);
     // profile.experimental = *experimental;
 
     Ok(profile)
@@ -2227,7 +2655,12 @@ fn profile_target_string(profile_name: &str, build_target: &BuildTarget) -> Stri
 }
 /// Returns the size of the bytecode in a human-readable format.
 pub fn format_bytecode_size(bytes_len: usize) -> String {
-    let size = Byte::from_u64(bytes_len as u64);
+    format_size(bytes_len as u64)
+}
+
+/// Returns the given number of bytes in a human-readable format.
+pub fn format_size(bytes: u64) -> String {
+    let size = Byte::from_u64(bytes);
     let adjusted_byte = size.get_appropriate_unit(UnitType::Decimal);
     adjusted_byte.to_string()
 }
@@ -2241,28 +2674,247 @@ fn is_contract_dependency(graph: &Graph, node: NodeIx) -> bool {
 
 /// Builds a project with given BuildOptions.
 pub fn build_with_options(build_options: &BuildOpts) -> Result<Built> {
+    let path = build_path(build_options)?;
+    println_action_green("Building", &path.display().to_string());
+
+    let build_plan = BuildPlan::from_pkg_opts(&build_options.pkg)?;
+    let build_profiles: HashMap<String, BuildProfile> = build_plan.build_profiles().collect();
+    // Get the selected build profile using build options
+    let build_profile = build_profile_from_opts(&build_profiles, build_options)?;
+
+    let output_dir = build_options
+        .pkg
+        .output_directory
+        .as_ref()
+        .map(PathBuf::from);
+    build_with_plan(
+        build_options,
+        &build_plan,
+        &path,
+        build_options.build_target,
+        &build_profile,
+        &Engines::default(),
+        &|pkg_manifest| {
+            output_dir.clone().unwrap_or_else(|| {
+                default_output_directory(pkg_manifest.dir()).join(&build_profile.name)
+            })
+        },
+    )
+}
+
+/// Builds the textual IR module at `ir_path`, e.g. as printed with `--ir`, by running the IR passes
+/// of the selected build profile followed by the ASM generation, instead of compiling a package.
+///
+/// The bytecode is written to the binary outfile of the build options if given, or else to
+/// `<output-directory>/<ir-file-stem>.bin`, where the output directory defaults to the
+/// `out/<profile>` directory next to the IR file. Returns the path of the written bytecode.
+pub fn build_ir_with_options(ir_path: &Path, build_options: &BuildOpts) -> Result<PathBuf> {
+    let ir_path = ir_path
+        .canonicalize()
+        .with_context(|| format!("failed to find the IR file {}", ir_path.display()))?;
+    println_action_green("Building", &ir_path.display().to_string());
+    let ir_input = fs::read_to_string(&ir_path)
+        .with_context(|| format!("failed to read the IR file {}", ir_path.display()))?;
+    let ir_dir = ir_path.parent().unwrap_or(&ir_path);
+
+    // There is no manifest, so only the built-in build profiles are available.
+    let build_profiles = HashMap::from([
+        (BuildProfile::DEBUG.to_string(), BuildProfile::debug()),
+        (BuildProfile::RELEASE.to_string(), BuildProfile::release()),
+    ]);
+    let profile = build_profile_from_opts(&build_profiles, build_options)?;
+    let experimental = ExperimentalFeatures::new(
+        &profile.experimental,
+        &build_options.experimental,
+        &build_options.no_experimental,
+    )
+    .map_err(|err| anyhow!("{err}"))?;
+
+    let engines = Engines::default();
+    let sway_build_config =
+        sway_build_config(ir_dir, &ir_path, build_options.build_target, &profile)?;
+    let handler = Handler::default();
+    let compiled = sway_core::compile_ir_to_asm(
+        &handler,
+        &engines,
+        &ir_input,
+        &sway_build_config,
+        experimental,
+    )
+    .and_then(|mut asm| {
+        sway_core::asm_to_bytecode(
+            &handler,
+            &mut asm,
+            &mut SourceMap::new(),
+            engines.se(),
+            &sway_build_config,
+        )
+    });
+    let (errors, warnings) = handler.consume_sorted(engines.se());
+    let bytecode = match compiled {
+        Ok(compiled) if errors.is_empty() => compiled.bytecode,
+        _ => {
+            print_on_failure(
+                engines.se(),
+                profile.terse,
+                &warnings,
+                &errors,
+                build_options.print.reverse_order,
+            );
+            bail!("Failed to compile {}", ir_path.display());
+        }
+    };
+
+    let bin_path = match &build_options.binary_outfile {
+        Some(bin_path) => PathBuf::from(bin_path),
+        None => {
+            let output_dir = build_options
+                .pkg
+                .output_directory
+                .as_ref()
+                .map(PathBuf::from)
+                .unwrap_or_else(|| default_output_directory(ir_dir).join(&profile.name));
+            fs::create_dir_all(&output_dir)?;
+            let file_stem = ir_path.file_stem().unwrap_or(ir_path.as_os_str());
+            output_dir.join(file_stem).with_extension("bin")
+        }
+    };
+    fs::write(&bin_path, &bytecode)
+        .with_context(|| format!("failed to write the bytecode to {}", bin_path.display()))?;
+    info!(
+        "      Bytecode size: {} bytes ({})",
+        bytecode.len(),
+        format_bytecode_size(bytecode.len())
+    );
+
+    Ok(bin_path)
+}
+
+/// The result of building a project for one combination of build target and build profile
+/// within [build_matrix_with_options].
+#[derive(Debug, Clone)]
+pub struct BuiltMatrixEntry {
+    pub target: BuildTarget,
+    pub profile: String,
+    pub built: Built,
+}
+
+/// Builds a project for every combination of the given build targets and build profiles.
+///
+/// The build plan, i.e. the resolved and fetched dependency graph, is shared by all the builds, as
+/// are the engines, so that the typed programs are reused across the targets and profiles instead
+/// of being type checked again for each of them.
+/// The artifacts of each build are written to `<output-directory>/<target>/<profile>`, where the
+/// output directory defaults to the `out` directory of each package.
+///
+/// If `targets` or `profiles` is empty, the build target or build profile selected by the
+/// `build_options` is used.
+pub fn build_matrix_with_options(
+    build_options: &BuildOpts,
+    targets: &[BuildTarget],
+    profiles: &[String],
+) -> Result<Vec<BuiltMatrixEntry>> {
+    fn unique<T: Clone + PartialEq>(items: &[T]) -> Vec<T> {
+        items.iter().fold(Vec::new(), |mut unique, item| {
+            if !unique.contains(item) {
+                unique.push(item.clone());
+            }
+            unique
+        })
+    }
+    let targets = match targets {
+        [] => vec![build_options.build_target],
+        targets => unique(targets),
+    };
+    let profile_names = match profiles {
+        [] if build_options.release => vec![BuildProfile::RELEASE.to_string()],
+        [] => vec![build_options.build_profile.clone()],
+        profiles => unique(profiles),
+    };
+    if targets.len() * profile_names.len() > 1
+        && (build_options.binary_outfile.is_some() || build_options.debug_outfile.is_some())
+    {
+        bail!("Output files cannot be specified when building for multiple targets or profiles");
+    }
+
+    let path = build_path(build_options)?;
+    println_action_green("Building", &path.display().to_string());
+
+    let build_plan = BuildPlan::from_pkg_opts(&build_options.pkg)?;
+    let build_profiles: HashMap<String, BuildProfile> = build_plan.build_profiles().collect();
+    let engines = Engines::default();
+
+    let mut built_matrix = Vec::with_capacity(targets.len() * profile_names.len());
+    for target in targets {
+        for profile_name in &profile_names {
+            let profile_options = BuildOpts {
+                build_profile: profile_name.clone(),
+                release: false,
+                build_target: target,
+                ..build_options.clone()
+            };
+            let build_profile = build_profile_from_opts(&build_profiles, &profile_options)?;
+            let built = build_with_plan(
+                &profile_options,
+                &build_plan,
+                &path,
+                target,
+                &build_profile,
+                &engines,
+                &|pkg_manifest| {
+                    build_options
+                        .pkg
+                        .output_directory
+                        .as_ref()
+                        .map_or_else(
+                            || default_output_directory(pkg_manifest.dir()),
+                            PathBuf::from,
+                        )
+                        .join(target.to_string())
+                        .join(&build_profile.name)
+                },
+            )?;
+            built_matrix.push(BuiltMatrixEntry {
+                target,
+                profile: build_profile.name.clone(),
+                built,
+            });
+        }
+    }
+
+    Ok(built_matrix)
+}
+
+/// The path of the package or workspace to build, defaulting to the current directory.
+fn build_path(build_options: &BuildOpts) -> Result<PathBuf> {
+    match &build_options.pkg.path {
+        Some(path) => Ok(PathBuf::from(path)),
+        None => Ok(std::env::current_dir()?),
+    }
+}
+
+/// Builds the packages of the `build_plan` found at `path` for the given build target and build
+/// profile with the given `engines`, writing the artifacts of each package to the directory
+/// returned by `output_dir_of`.
+fn build_with_plan(
+    build_options: &BuildOpts,
+    build_plan: &BuildPlan,
+    path: &Path,
+    build_target: BuildTarget,
+    build_profile: &BuildProfile,
+    engines: &Engines,
+    output_dir_of: &dyn Fn(&PackageManifestFile) -> PathBuf,
+) -> Result<Built> {
     let BuildOpts {
         minify,
         binary_outfile,
         debug_outfile,
-        pkg,
-        build_target,
         member_filter,
         experimental,
         no_experimental,
         ..
     } = &build_options;
 
-    let current_dir = std::env::current_dir()?;
-    let path = &build_options
-        .pkg
-        .path
-        .as_ref()
-        .map_or_else(|| current_dir, PathBuf::from);
-
-    println_action_green("Building", &path.display().to_string());
-
-    let build_plan = BuildPlan::from_pkg_opts(&build_options.pkg)?;
     let graph = build_plan.graph();
     let manifest_map = build_plan.manifest_map();
 
@@ -2271,9 +2923,6 @@ pub fn build_with_options(build_options: &BuildOpts) -> Result<Built> {
     let curr_manifest = manifest_map
         .values()
         .find(|&pkg_manifest| pkg_manifest.dir() == path);
-    let build_profiles: HashMap<String, BuildProfile> = build_plan.build_profiles().collect();
-    // Get the selected build profile using build options
-    let build_profile = build_profile_from_opts(&build_profiles, build_options)?;
     // If this is a workspace we want to have all members in the output.
     let outputs = match curr_manifest {
         Some(pkg_manifest) => std::iter::once(
@@ -2285,20 +2934,31 @@ pub fn build_with_options(build_options: &BuildOpts) -> Result<Built> {
         None => build_plan.member_nodes().collect(),
     };
 
-    let outputs = member_filter.filter_outputs(&build_plan, outputs);
+    let outputs = member_filter.filter_outputs(build_plan, outputs);
 
     // Build it!
     let mut built_workspace = Vec::new();
     let build_start = std::time::Instant::now();
-    let built_packages = build(
-        &build_plan,
-        *build_target,
-        &build_profile,
+    let built_packages = build_with_engines(
+        build_plan,
+        build_target,
+        build_profile,
         &outputs,
         experimental,
         no_experimental,
-    )?;
-    let output_dir = pkg.output_directory.as_ref().map(PathBuf::from);
+        engines,
+    )
+    .inspect_err(|_| {
+        if build_profile.message_format.is_json() {
+            Message::BuildFinished {
+                success: false,
+                profile: build_profile.name.clone(),
+                bytecode_size: 0,
+                duration_secs: build_start.elapsed().as_secs_f64(),
+            }
+            .emit();
+        }
+    })?;
     let total_size = built_packages
         .iter()
         .map(|(_, pkg)| pkg.bytecode.bytes.len())
@@ -2308,7 +2968,7 @@ pub fn build_with_options(build_options: &BuildOpts) -> Result<Built> {
         "Finished",
         &format!(
             "{} [{}] in {:.2}s",
-            profile_target_string(&build_profile.name, build_target),
+            profile_target_string(&build_profile.name, &build_target),
             format_bytecode_size(total_size),
             build_start.elapsed().as_secs_f32()
         ),
@@ -2319,9 +2979,7 @@ pub fn build_with_options(build_options: &BuildOpts) -> Result<Built> {
         let pkg_manifest = manifest_map
             .get(&pinned.id())
             .ok_or_else(|| anyhow!("Couldn't find member manifest for {}", pinned.name))?;
-        let output_dir = output_dir.clone().unwrap_or_else(|| {
-            default_output_directory(pkg_manifest.dir()).join(&build_profile.name)
-        });
+        let output_dir = output_dir_of(pkg_manifest);
         // Output artifacts for the built package
         if let Some(outfile) = &binary_outfile {
             built_package.write_bytecode(outfile.as_ref())?;
@@ -2330,8 +2988,27 @@ pub fn build_with_options(build_options: &BuildOpts) -> Result<Built> {
             built_package.write_debug_info(outfile.as_ref())?;
         }
         built_package.write_output(minify, &pkg_manifest.project.name, &output_dir)?;
+        if build_profile.message_format.is_json() {
+            Message::Artifacts(ArtifactsMessage::new(
+                &built_package.tree_type,
+                &pkg_manifest.project.name,
+                &output_dir,
+                built_package.bytecode.bytes.len(),
+                !built_package.configurables_layout.configurables.is_empty(),
+            ))
+            .emit();
+        }
         built_workspace.push(Arc::new(built_package));
     }
+    if build_profile.message_format.is_json() {
+        Message::BuildFinished {
+            success: true,
+            profile: build_profile.name.clone(),
+            bytecode_size: total_size,
+            duration_secs: build_start.elapsed().as_secs_f64(),
+        }
+        .emit();
+    }
 
     match curr_manifest {
         Some(pkg_manifest) => {
@@ -2409,6 +3086,30 @@ pub fn build(
     outputs: &HashSet<NodeIx>,
     experimental: &[sway_features::Feature],
     no_experimental: &[sway_features::Feature],
+) -> anyhow::Result<Vec<(NodeIx, BuiltPackage)>> {
+    build_with_engines(
+        plan,
+        target,
+        profile,
+        outputs,
+        experimental,
+        no_experimental,
+        &Engines::default(),
+    )
+}
+
+/// Like [build], but with the given `engines`.
+///
+/// The programs compiled by earlier builds with the same `engines` are reused if their sources
+/// did not change since.
+pub fn build_with_engines(
+    plan: &BuildPlan,
+    target: BuildTarget,
+    profile: &BuildProfile,
+    outputs: &HashSet<NodeIx>,
+    experimental: &[sway_features::Feature],
+    no_experimental: &[sway_features::Feature],
+    engines: &Engines,
 ) -> anyhow::Result<Vec<(NodeIx, BuiltPackage)>> {
     let mut built_packages = Vec::new();
 
@@ -2417,7 +3118,6 @@ pub fn build(
         .flat_map(|output_node| plan.node_deps(*output_node))
         .collect();
 
-    let engines = Engines::default();
     let include_tests = profile.include_tests;
 
     // This is the Contract ID of the current contract being compiled.
@@ -2435,6 +3135,10 @@ pub fn build(
         let mut source_map = SourceMap::new();
         let pkg = &plan.graph()[node];
         let manifest = &plan.manifest_map()[&pkg.id()];
+        // Hooks run arbitrary commands, so only those of the workspace members are run.
+        if matches!(pkg.source, source::Pinned::Member(_)) {
+            run_build_hook(manifest)?;
+        }
         let program_ty = manifest.program_type().ok();
 
         print_compiling(
@@ -2442,13 +3146,23 @@ pub fn build(
             &pkg.name,
             &pkg.source.display_compiling(manifest.dir()),
         );
+        if profile.message_format.is_json() {
+            Message::CompilingPackage {
+                package: pkg.name.clone(),
+                program_type: program_ty
+                    .as_ref()
+                    .map(|ty| forc_util::program_type_str(ty).to_string()),
+                source: pkg.source.display_compiling(manifest.dir()).to_string(),
+            }
+            .emit();
+        }
 
-        let experimental = ExperimentalFeatures::new(
-            &manifest.project.experimental,
-            experimental,
-            no_experimental,
-        )
-        .map_err(|err| anyhow!("{err}"))?;
+        // The features set in the build profile take precedence over the ones of the project.
+        let mut manifest_experimental = manifest.project.experimental.clone();
+        manifest_experimental.extend(profile.experimental.clone());
+        let experimental =
+            ExperimentalFeatures::new(&manifest_experimental, experimental, no_experimental)
+                .map_err(|err| anyhow!("{err}"))?;
 
         let descriptor = PackageDescriptor {
             name: pkg.name.clone(),
@@ -2457,7 +3171,12 @@ pub fn build(
             manifest_file: manifest.clone(),
         };
 
-        let fail = |warnings, errors| {
+        let fail = |warnings: &[CompileWarning], errors: &[CompileError]| {
+            if profile.message_format.is_json() {
+                Message::diagnostics(engines.se(), &pkg.name, warnings, errors)
+                    .iter()
+                    .for_each(Message::emit);
+            }
             print_on_failure(
                 engines.se(),
                 profile.terse,
@@ -2493,7 +3212,7 @@ pub fn build(
                 &compiled_contract_deps,
                 plan.graph(),
                 node,
-                &engines,
+                engines,
                 None,
                 experimental,
             ) {
@@ -2504,7 +3223,7 @@ pub fn build(
             let compiled_without_tests = compile(
                 &descriptor,
                 &profile,
-                &engines,
+                engines,
                 &mut dep_namespace,
                 &mut source_map,
                 experimental,
@@ -2558,27 +3277,18 @@ pub fn build(
             &compiled_contract_deps,
             plan.graph(),
             node,
-            &engines,
+            engines,
             contract_id_value.clone(),
             experimental,
         ) {
             Ok(o) => o,
-            Err(errs) => {
-                print_on_failure(
-                    engines.se(),
-                    profile.terse,
-                    &[],
-                    &errs,
-                    profile.reverse_results,
-                );
-                bail!("Failed to compile {}", pkg.name);
-            }
+            Err(errs) => return fail(&[], &errs),
         };
 
         let compiled = compile(
             &descriptor,
             &profile,
-            &engines,
+            engines,
             &mut dep_namespace,
             &mut source_map,
             experimental,
@@ -2600,6 +3310,11 @@ pub fn build(
             descriptor,
             program_abi: compiled.program_abi,
             storage_slots: compiled.storage_slots,
+            storage_layout: compiled.storage_layout,
+            configurables_layout: compiled.configurables_layout,
+            error_selectors: compiled.error_selectors,
+            events: compiled.events,
+            contract_calls: compiled.contract_calls,
             source_map: compiled.source_map,
             tree_type: compiled.tree_type,
             bytecode: compiled.bytecode,
@@ -2640,6 +3355,12 @@ pub fn check(
         let pkg = &plan.graph[node];
         let manifest = &plan.manifest_map()[&pkg.id()];
 
+        // Generating sources on every change in the language server would be too costly, so the
+        // hooks are only run by `forc check`, and like in `build` only for the workspace members.
+        if lsp_mode.is_none() && matches!(pkg.source, source::Pinned::Member(_)) {
+            run_build_hook(manifest)?;
+        }
+
         let experimental = ExperimentalFeatures::new(
             &manifest.project.experimental,
             experimental,
@@ -2855,4 +3576,199 @@ mod test {
 "#;
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn test_member_patch_applies_to_transitive_deps() {
+        let tests_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/patch_transitive");
+        let member = PackageManifestFile::from_dir(tests_dir.join("project")).unwrap();
+        let lib_a = PackageManifestFile::from_dir(tests_dir.join("lib_a")).unwrap();
+        let members = MemberManifestFiles::from([(member.project.name.clone(), member.clone())]);
+        let dep = lib_a.dep("lib_b").unwrap();
+
+        // Without the member's patch, `lib_b` is fetched from git.
+        let unpatched =
+            Source::from_manifest_dep_patched(&lib_a, &lib_a, "lib_b", dep, &members).unwrap();
+        assert!(matches!(unpatched, Source::Git(_)));
+
+        // The member's patch replaces `lib_b` even though `lib_a` declares it.
+        let patched =
+            Source::from_manifest_dep_patched(&member, &lib_a, "lib_b", dep, &members).unwrap();
+        let lib_b_dir = tests_dir.join("lib_b").canonicalize().unwrap();
+        assert_eq!(patched, Source::Path(lib_b_dir));
+    }
+
+    #[test]
+    fn test_parse_numeric_test_arg() {
+        assert_eq!(parse_numeric_test_arg("500"), Some(500));
+        assert_eq!(parse_numeric_test_arg("1_000"), Some(1_000));
+        assert_eq!(parse_numeric_test_arg("0x2a"), Some(42));
+        assert_eq!(parse_numeric_test_arg("0b101"), Some(5));
+        assert_eq!(parse_numeric_test_arg("\"500\""), Some(500));
+        assert_eq!(parse_numeric_test_arg("\"0x2a\""), Some(42));
+
+        // Quotes are not stripped from the inside of an argument.
+        assert_eq!(parse_numeric_test_arg("5\"0\"0"), None);
+        assert_eq!(parse_numeric_test_arg("\"500"), None);
+        assert_eq!(parse_numeric_test_arg("\"\""), None);
+        assert_eq!(parse_numeric_test_arg("0x"), None);
+        assert_eq!(parse_numeric_test_arg("_1"), None);
+        assert_eq!(parse_numeric_test_arg("-1"), None);
+        assert_eq!(parse_numeric_test_arg("18446744073709551616"), None);
+    }
+
+    /// Records the functions of the typed program and of the IR, and rejects them if `fail` is set.
+    #[derive(Default)]
+    struct FunctionNames {
+        typed: std::sync::Mutex<Vec<String>>,
+        ir: std::sync::Mutex<Vec<String>>,
+        fail: bool,
+    }
+
+    impl sway_core::analysis::AnalysisPass for FunctionNames {
+        fn name(&self) -> &str {
+            "function_names"
+        }
+
+        fn analyze_typed_program(
+            &self,
+            ctx: &sway_core::analysis::AnalysisContext,
+            program: &sway_core::language::ty::TyProgram,
+        ) {
+            use sway_core::{decl_engine::DeclEngineGet, language::ty};
+            for node in &program.root.all_nodes {
+                if let ty::TyAstNodeContent::Declaration(ty::TyDecl::FunctionDecl(decl)) =
+                    &node.content
+                {
+                    let func = ctx.engines.de().get_function(&decl.decl_id);
+                    self.typed.lock().unwrap().push(func.name.to_string());
+                    if self.fail {
+                        ctx.error(func.name.span(), "functions are not allowed");
+                    }
+                }
+            }
+        }
+
+        fn analyze_ir(&self, _ctx: &sway_core::analysis::AnalysisContext, ir: &sway_ir::Context) {
+            for module in ir.module_iter() {
+                for function in module.function_iter(ir) {
+                    let name = function.get_name(ir).to_string();
+                    self.ir.lock().unwrap().push(name);
+                }
+            }
+        }
+    }
+
+    fn build_with_analysis_pass(pass: Arc<FunctionNames>) -> Result<Built> {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/analysis_pass");
+        let mut analysis_passes = AnalysisPasses::default();
+        analysis_passes.push(pass);
+        build_with_options(&BuildOpts {
+            pkg: PkgOpts {
+                path: Some(path.to_string_lossy().to_string()),
+                offline: true,
+                locked: true,
+                ..Default::default()
+            },
+            analysis_passes,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_analysis_passes_run_on_typed_program_and_ir() {
+        let pass = Arc::new(FunctionNames::default());
+        build_with_analysis_pass(pass.clone()).unwrap();
+
+        let typed = pass.typed.lock().unwrap();
+        assert!(typed.contains(&"helper".to_string()));
+        assert!(typed.contains(&"main".to_string()));
+        let ir = pass.ir.lock().unwrap();
+        assert!(ir.contains(&"main".to_string()));
+    }
+
+    #[test]
+    fn test_analysis_pass_errors_fail_the_build() {
+        let pass = Arc::new(FunctionNames {
+            fail: true,
+            ..Default::default()
+        });
+        assert!(build_with_analysis_pass(pass.clone()).is_err());
+        assert!(!pass.typed.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_build_matrix_reuses_typed_program() {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/analysis_pass");
+        let out_dir = std::env::temp_dir().join("forc_pkg_build_matrix");
+        fs::create_dir_all(&out_dir).unwrap();
+        let metrics_path = out_dir.join("metrics.json");
+        let built_matrix = build_matrix_with_options(
+            &BuildOpts {
+                pkg: PkgOpts {
+                    path: Some(path.to_string_lossy().to_string()),
+                    offline: true,
+                    locked: true,
+                    output_directory: Some(out_dir.to_string_lossy().to_string()),
+                    ..Default::default()
+                },
+                metrics_outfile: Some(metrics_path.to_string_lossy().to_string()),
+                ..Default::default()
+            },
+            &[BuildTarget::Fuel],
+            &[
+                BuildProfile::DEBUG.to_string(),
+                BuildProfile::RELEASE.to_string(),
+            ],
+        )
+        .unwrap();
+
+        let profiles: Vec<_> = built_matrix
+            .iter()
+            .map(|entry| entry.profile.as_str())
+            .collect();
+        assert_eq!(profiles, [BuildProfile::DEBUG, BuildProfile::RELEASE]);
+        assert!(out_dir.join("fuel").join("release").exists());
+
+        // The metrics are those of the last build, which reused the typed program of the first.
+        let metrics: PerformanceData =
+            serde_json::from_str(&fs::read_to_string(metrics_path).unwrap()).unwrap();
+        assert_eq!(metrics.reused_programs, 1);
+    }
+
+    fn build_ir_fixture(file_name: &str, profile: &str) -> Result<PathBuf> {
+        let ir_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/ir_input")
+            .join(file_name);
+        let out_dir = std::env::temp_dir().join("forc_pkg_build_ir").join(profile);
+        build_ir_with_options(
+            &ir_path,
+            &BuildOpts {
+                pkg: PkgOpts {
+                    output_directory: Some(out_dir.to_string_lossy().to_string()),
+                    ..Default::default()
+                },
+                build_profile: profile.to_string(),
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn test_build_ir_with_options() {
+        for profile in [BuildProfile::DEBUG, BuildProfile::RELEASE] {
+            let bin_path = build_ir_fixture("main.ir", profile).unwrap();
+            assert_eq!(bin_path.file_name().unwrap(), "main.bin");
+            assert!(bin_path.starts_with(std::env::temp_dir().join("forc_pkg_build_ir")));
+
+            // The bytecode is made of whole instructions, followed by the word aligned data section.
+            let bytecode = fs::read(&bin_path).unwrap();
+            assert!(!bytecode.is_empty());
+            assert_eq!(bytecode.len() % 4, 0);
+        }
+    }
+
+    #[test]
+    fn test_build_ir_with_options_fails_on_invalid_ir() {
+        assert!(build_ir_fixture("invalid.ir", BuildProfile::DEBUG).is_err());
+    }
 }
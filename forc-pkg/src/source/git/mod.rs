@@ -557,6 +557,63 @@ pub fn fetch(fetch_id: u64, name: &str, pinned: &Pinned) -> Result<PathBuf> {
     Ok(path)
 }
 
+/// A checkout of a git package within the checkouts directory.
+#[derive(Clone, Debug)]
+pub struct Checkout {
+    /// The name of the package the checkout was fetched for.
+    pub name: String,
+    pub git_reference: Reference,
+    pub commit_hash: String,
+    /// The directory containing the checked out commit.
+    pub path: PathBuf,
+}
+
+/// Collect all git checkouts within the checkouts directory, sorted by name and commit.
+///
+/// The checkouts are keyed by commit and shared by all projects fetching the same package.
+pub fn checkouts() -> Result<Vec<Checkout>> {
+    let checkouts_dir = git_checkouts_directory();
+    if !checkouts_dir.exists() {
+        return Ok(vec![]);
+    }
+    let mut checkouts = vec![];
+    with_search_checkouts(checkouts_dir, "", |repo_index, repo_dir_path| {
+        // The parent directory is named `name-<repo_url_hash>`.
+        let name = repo_dir_path
+            .parent()
+            .and_then(|dir| dir.file_name())
+            .and_then(|dir_name| dir_name.to_str())
+            .and_then(|dir_name| dir_name.rsplit_once('-'))
+            .map(|(name, _)| name.to_string())
+            .ok_or_else(|| anyhow!("invalid checkout path {}", repo_dir_path.display()))?;
+        checkouts.push(Checkout {
+            name,
+            git_reference: repo_index.git_reference,
+            commit_hash: repo_index.head_with_time.0,
+            path: repo_dir_path,
+        });
+        Ok(())
+    })?;
+    checkouts.sort_by(|a, b| (&a.name, &a.commit_hash).cmp(&(&b.name, &b.commit_hash)));
+    Ok(checkouts)
+}
+
+/// Remove the given checkout, so that it is fetched again the next time it is required.
+pub fn remove_checkout(checkout: &Checkout) -> Result<()> {
+    // Co-ordinate access to the git checkout directory using an advisory file lock.
+    let mut lock = forc_util::path_lock(&checkout.path)?;
+    let _guard = lock.write()?;
+    fs::remove_dir_all(&checkout.path)
+        .with_context(|| format!("failed to remove {}", checkout.path.display()))?;
+    // Remove the repository directory once its last checkout is gone.
+    if let Some(repo_dir) = checkout.path.parent() {
+        if fs::read_dir(repo_dir)?.next().is_none() {
+            fs::remove_dir(repo_dir)?;
+        }
+    }
+    Ok(())
+}
+
 /// Search local checkout dir for git sources, for non-branch git references tries to find the
 /// exact match. For branch references, tries to find the most recent repo present locally with the given repo
 pub(crate) fn search_source_locally(
@@ -207,16 +207,24 @@ impl Source {
     }
 
     /// Convert the given manifest `Dependency` declaration to a source,
-    /// applying any relevant patches from within the given `manifest` as
-    /// necessary.
+    /// applying any relevant patches as necessary.
+    ///
+    /// The dependency is declared within `manifest`, while `member` is the
+    /// workspace member whose dependency graph is being resolved. Patches of
+    /// the `member` apply to dependencies anywhere in its graph and take
+    /// precedence over the patches within `manifest`.
     pub fn from_manifest_dep_patched(
+        member: &PackageManifestFile,
         manifest: &PackageManifestFile,
         dep_name: &str,
         dep: &manifest::Dependency,
         members: &MemberManifestFiles,
     ) -> Result<Self> {
         let unpatched = Self::from_manifest_dep(manifest.dir(), dep, members)?;
-        unpatched.apply_patch(dep_name, manifest, members)
+        match unpatched.dep_patch(dep_name, member)? {
+            Some(patch) => Self::from_manifest_dep(member.dir(), &patch, members),
+            None => unpatched.apply_patch(dep_name, manifest, members),
+        }
     }
 
     /// If a patch exists for this dependency source within the given project
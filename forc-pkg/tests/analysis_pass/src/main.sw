@@ -0,0 +1,9 @@
+script;
+
+fn helper() -> u64 {
+    42
+}
+
+fn main() -> u64 {
+    helper()
+}
@@ -3,6 +3,7 @@ use forc_tracing::{init_tracing_subscriber, println_error};
 
 #[tokio::main]
 async fn main() {
+    forc_util::completion::handle_completion_metadata_request::<forc_client::cmd::Submit>();
     init_tracing_subscriber(Default::default());
     let command = forc_client::cmd::Submit::parse();
     if let Err(err) = forc_client::op::submit(command).await {
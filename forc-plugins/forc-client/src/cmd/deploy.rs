@@ -1,4 +1,4 @@
-use crate::NodeTarget;
+use crate::{FeeBump, NodeTarget};
 use clap::Parser;
 pub use forc::cli::shared::{BuildOutput, Minify, Pkg, Print};
 use forc_pkg::BuildProfile;
@@ -11,6 +11,8 @@ forc_util::cli_examples! {
         [ Deploy a single contract => "forc deploy bc09bfa7a11a04ce42b0a5abf04fd437387ee49bf4561d575177e2946468b408" ]
         [ Deploy a single contract from a different path => "forc deploy bc09bfa7a11a04ce42b0a5abf04fd437387ee49bf4561d575177e2946468b408 --path {path}" ]
         [ Deploy to a custom network => "forc deploy --node-url https://testnet.fuel.network/graphql" ]
+        [ Deploy a single contract and call its initializer => "forc deploy --init \"initialize(42, true)\"" ]
+        [ Deploy a single contract with a different value for a configurable => "forc deploy --set-configurable MAX_SUPPLY=1000" ]
     }
 }
 
@@ -29,6 +31,8 @@ pub struct Command {
     pub maturity: Maturity,
     #[clap(flatten)]
     pub node: NodeTarget,
+    #[clap(flatten)]
+    pub fee_bump: FeeBump,
     /// Optional 256-bit hexadecimal literal(s) to redeploy contracts.
     ///
     /// For a single contract, use `--salt <SALT>`, eg.: forc deploy --salt 0x0000000000000000000000000000000000000000000000000000000000000001
@@ -83,6 +87,53 @@ pub struct Command {
     /// ]
     #[clap(long, verbatim_doc_comment, name = "JSON_FILE_PATH")]
     pub override_storage_slots: Option<String>,
+    /// Call a method of the contract right after it is deployed, eg. to initialize its storage.
+    ///
+    /// The method must not return a value and its arguments are given in the same format as
+    /// script arguments of `forc run --args`.
+    ///
+    /// For a single contract, use `--init <METHOD>(<ARGS>)`, eg.: forc deploy --init "initialize(42, true)"
+    ///
+    /// For a workspace with multiple contracts, use `--init <CONTRACT_NAME>:<METHOD>(<ARGS>)`
+    /// to specify the call for each contract, eg.:
+    ///
+    /// forc deploy --init "contract_a:initialize(42)" --init "contract_b:initialize()"
+    ///
+    /// If the contract is deployed behind a proxy, the method is called through the proxy.
+    #[clap(long, verbatim_doc_comment)]
+    pub init: Option<Vec<String>>,
+    /// Set the value of a configurable constant in the bytecode before deploying it.
+    ///
+    /// The value is parsed according to the type of the configurable in the ABI of the contract,
+    /// and the contract id is computed from the resulting bytecode. Values of structs, tuples and
+    /// arrays are given as JSON objects and arrays, and values of enums as {"<VARIANT>": <VALUE>}.
+    /// Structs with a single field, like `ContractId` or `Address`, can be given as the value of
+    /// their field, eg.: forc deploy --set-configurable OWNER=0x<64 hex digits>
+    ///
+    /// For a single contract, use `--set-configurable <NAME>=<VALUE>`, eg.: forc deploy --set-configurable MAX_SUPPLY=1000
+    ///
+    /// For a workspace with multiple contracts, use `--set-configurable <CONTRACT_NAME>:<NAME>=<VALUE>`
+    /// to specify the contract of each configurable, eg.:
+    ///
+    /// forc deploy --set-configurable contract_a:MAX_SUPPLY=1000 --set-configurable contract_b:PAUSED=true
+    #[clap(long, verbatim_doc_comment)]
+    pub set_configurable: Option<Vec<String>>,
+    /// Set the values of configurable constants from a JSON file before deploying the bytecode.
+    ///
+    /// The file contains an object mapping the names of the configurables to their values, in
+    /// the same format as `--set-configurable`. Values given with `--set-configurable` take
+    /// precedence over the values in the file.
+    ///
+    /// Example: `forc deploy --override-configurables my_configurables.json`
+    ///
+    /// my_configurables.json:
+    /// {
+    ///   "MAX_SUPPLY": 1000,
+    ///   "PAUSED": true,
+    ///   "CONFIG": { "limit": 10, "enabled": false }
+    /// }
+    #[clap(long, verbatim_doc_comment, name = "CONFIGURABLES_JSON_FILE_PATH")]
+    pub override_configurables: Option<String>,
 
     #[clap(flatten)]
     pub experimental: sway_features::CliFields,
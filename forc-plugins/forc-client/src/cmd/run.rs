@@ -1,4 +1,4 @@
-use crate::NodeTarget;
+use crate::{FeeBump, NodeTarget};
 use clap::Parser;
 use fuel_crypto::SecretKey;
 
@@ -27,6 +27,8 @@ pub struct Command {
     pub build_profile: BuildProfile,
     #[clap(flatten)]
     pub node: NodeTarget,
+    #[clap(flatten)]
+    pub fee_bump: FeeBump,
     /// Hex string of data to input to script.
     #[clap(short, long)]
     pub data: Option<String>,
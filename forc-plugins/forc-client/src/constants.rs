@@ -13,3 +13,6 @@ pub const DEFAULT_PRIVATE_KEY: &str =
     "0xde97d8624a438121b86a1956544bd72ed68cd69f2c99555b08b1e8c51ffd511c";
 /// The maximum time to wait for a transaction to be included in a block by the node
 pub const TX_SUBMIT_TIMEOUT_MS: u64 = 30_000u64;
+/// The number of blocks ahead for which the gas price is estimated when bumping the tip of a
+/// transaction
+pub const GAS_PRICE_ESTIMATION_BLOCK_HORIZON: u32 = 1;
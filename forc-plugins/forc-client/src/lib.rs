@@ -38,3 +38,26 @@ pub struct NodeTarget {
     #[clap(long)]
     pub mainnet: bool,
 }
+
+/// Flags for capping transaction fees and re-submitting transactions that aren't included in a
+/// block in time with a higher tip.
+#[derive(Debug, Default, Parser, Deserialize, Serialize)]
+pub struct FeeBump {
+    /// The maximum fee a transaction may pay, including its tip.
+    ///
+    /// A transaction whose max fee exceeds the cap is not submitted, which also stops fee bumping.
+    #[clap(long)]
+    pub max_fee_cap: Option<u64>,
+
+    /// The number of times a transaction that isn't included in a block in time is re-submitted
+    /// with a higher tip.
+    #[clap(long, default_value_t = 0)]
+    pub fee_bump_attempts: u32,
+
+    /// The percentage by which the tip is increased on each re-submission.
+    ///
+    /// The tip is increased by at least the gas price estimated by the node, so that a zero tip
+    /// is bumped as well.
+    #[clap(long, default_value_t = 20)]
+    pub fee_bump_percent: u64,
+}
@@ -0,0 +1,492 @@
+use anyhow::{anyhow, bail, Context};
+use fuel_abi_types::abi::full_program::{FullProgramABI, FullTypeApplication};
+use std::{path::Path, str::FromStr};
+use sway_core::language::ty::{ConfigurableEncoding, ConfigurablesLayout};
+use sway_types::u256::U256;
+
+/// A new value for a configurable constant, given as `NAME=VALUE`.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct ConfigurableOverride {
+    pub(crate) name: String,
+    pub(crate) value: String,
+}
+
+impl FromStr for ConfigurableOverride {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, value) = s
+            .split_once('=')
+            .with_context(|| format!("Invalid configurable value `{s}`, expected `NAME=VALUE`"))?;
+
+        let name = name.trim();
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            bail!("Invalid configurable name `{name}` in `{s}`");
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            value: value.trim().to_string(),
+        })
+    }
+}
+
+impl ConfigurableOverride {
+    /// Encode the value the way it is stored in the bytecode, using the type of the configurable
+    /// in the provided json abi and its encoding in the configurables layout of the contract.
+    pub(crate) fn encoded_value(
+        &self,
+        json_abi_str: &str,
+        layout: &ConfigurablesLayout,
+    ) -> anyhow::Result<Vec<u8>> {
+        let full_abi = FullProgramABI::from_json_abi(json_abi_str)?;
+        let configurable = full_abi
+            .configurables
+            .iter()
+            .find(|configurable| configurable.name == self.name)
+            .ok_or_else(|| anyhow!("The contract has no configurable named `{}`", self.name))?;
+        let encoding = layout
+            .configurable(&self.name)
+            .map(|configurable| configurable.encoding)
+            .ok_or_else(|| {
+                anyhow!(
+                    "The configurable `{}` is never used, so it was removed from the bytecode",
+                    self.name
+                )
+            })?;
+
+        encode_value(&configurable.application, &self.value, encoding)
+            .with_context(|| format!("Invalid value for configurable `{}`", self.name))
+    }
+}
+
+/// Encode `value`, parsed as a value of the type of `application`.
+///
+/// Values of structs, tuples and arrays are given as JSON objects and arrays, and values of enums
+/// as `{"<VARIANT>": <VALUE>}`, or as the name of the variant if it holds no value. Other values
+/// are given as is, e.g., `0x` prefixed hex strings for `b256`s.
+fn encode_value(
+    application: &FullTypeApplication,
+    value: &str,
+    encoding: ConfigurableEncoding,
+) -> anyhow::Result<Vec<u8>> {
+    let value = serde_json::from_str(value)
+        .unwrap_or_else(|_| serde_json::Value::String(value.to_string()));
+    let mut bytes = vec![];
+    encode_json_value(application, &value, encoding, &mut bytes)?;
+    Ok(bytes)
+}
+
+/// Append the encoding of `value` to `bytes`.
+///
+/// ABI encoded values are laid out the way `abi_encode` lays them out, while values stored in
+/// their in-memory representation pad every primitive to at least a word.
+fn encode_json_value(
+    application: &FullTypeApplication,
+    value: &serde_json::Value,
+    encoding: ConfigurableEncoding,
+    bytes: &mut Vec<u8>,
+) -> anyhow::Result<()> {
+    let type_decl = &application.type_decl;
+    let type_field = type_decl.type_field.as_str();
+    match type_field {
+        "()" => bail!("Values of type `()` cannot be set"),
+        "u8" => push_primitive(bytes, &parse_scalar::<u8>(value)?.to_be_bytes(), encoding),
+        "u16" => push_primitive(bytes, &parse_scalar::<u16>(value)?.to_be_bytes(), encoding),
+        "u32" => push_primitive(bytes, &parse_scalar::<u32>(value)?.to_be_bytes(), encoding),
+        "u64" => push_primitive(bytes, &parse_scalar::<u64>(value)?.to_be_bytes(), encoding),
+        "u256" => bytes.extend(
+            parse_scalar::<U256>(value)
+                .context("u256 literal out of range")?
+                .to_be_bytes(),
+        ),
+        "bool" => push_primitive(bytes, &[u8::from(parse_scalar::<bool>(value)?)], encoding),
+        "b256" => {
+            let value = scalar_str(value)?;
+            let hex = value.strip_prefix("0x").unwrap_or(&value);
+            let b256: [u8; 32] = hex::decode(hex)
+                .ok()
+                .and_then(|b256| b256.try_into().ok())
+                .ok_or_else(|| anyhow!("Expected a `b256` as 64 hex digits, found `{value}`"))?;
+            bytes.extend(b256);
+        }
+        "str" => {
+            if encoding == ConfigurableEncoding::Memory {
+                bail!("Values of type `str` can only be set in ABI encoded configurables");
+            }
+            let value = scalar_str(value)?;
+            bytes.extend((value.len() as u64).to_be_bytes());
+            bytes.extend(value.as_bytes());
+        }
+        _ if type_field.starts_with("str[") => {
+            let len = array_len(type_field)?;
+            let value = scalar_str(value)?;
+            if value.len() != len {
+                bail!("Expected a string of {len} bytes, found `{value}`");
+            }
+            bytes.extend(value.as_bytes());
+            if encoding == ConfigurableEncoding::Memory {
+                bytes.resize(bytes.len() + (8 - len % 8) % 8, 0);
+            }
+        }
+        _ if type_field.starts_with("struct ") => match value {
+            serde_json::Value::Object(fields) => {
+                if let Some(unknown) = fields
+                    .keys()
+                    .find(|name| !type_decl.components.iter().any(|c| &c.name == *name))
+                {
+                    bail!("`{type_field}` has no field named `{unknown}`");
+                }
+                for field in &type_decl.components {
+                    let field_value = fields.get(&field.name).ok_or_else(|| {
+                        anyhow!(
+                            "Missing the value of field `{}` of `{type_field}`",
+                            field.name
+                        )
+                    })?;
+                    let field = resolve_generic(application, field)?;
+                    encode_json_value(field, field_value, encoding, bytes)?;
+                }
+            }
+            // Structs with a single field, e.g., `ContractId` and `Address`, can also be given
+            // as the value of the field.
+            _ => match type_decl.components.as_slice() {
+                [field] => {
+                    let field = resolve_generic(application, field)?;
+                    encode_json_value(field, value, encoding, bytes)?;
+                }
+                _ => bail!("Expected an object with the fields of `{type_field}`"),
+            },
+        },
+        _ if type_field.starts_with("enum ") => {
+            if encoding == ConfigurableEncoding::Memory {
+                bail!("Values of enums can only be set in ABI encoded configurables");
+            }
+            let (variant_name, variant_value) = match value {
+                serde_json::Value::String(variant_name) => {
+                    (variant_name.as_str(), &serde_json::Value::Null)
+                }
+                serde_json::Value::Object(variant) if variant.len() == 1 => variant
+                    .iter()
+                    .next()
+                    .map(|(name, value)| (name.as_str(), value))
+                    .expect("the object has one entry"),
+                _ => {
+                    bail!("Expected a variant of `{type_field}`, e.g., {{\"<VARIANT>\": <VALUE>}}")
+                }
+            };
+            let (tag, variant) = type_decl
+                .components
+                .iter()
+                .enumerate()
+                .find(|(_, variant)| variant.name == variant_name)
+                .ok_or_else(|| anyhow!("`{type_field}` has no variant named `{variant_name}`"))?;
+            bytes.extend((tag as u64).to_be_bytes());
+            let variant = resolve_generic(application, variant)?;
+            if variant.type_decl.type_field == "()" {
+                if !variant_value.is_null() {
+                    bail!("The variant `{variant_name}` of `{type_field}` holds no value");
+                }
+            } else {
+                encode_json_value(variant, variant_value, encoding, bytes)?;
+            }
+        }
+        _ if type_field.starts_with('(') => {
+            let elements = match value {
+                serde_json::Value::Array(elements)
+                    if elements.len() == type_decl.components.len() =>
+                {
+                    elements
+                }
+                _ => bail!(
+                    "Expected an array of the {} elements of `{type_field}`",
+                    type_decl.components.len()
+                ),
+            };
+            for (element, element_value) in type_decl.components.iter().zip(elements) {
+                let element = resolve_generic(application, element)?;
+                encode_json_value(element, element_value, encoding, bytes)?;
+            }
+        }
+        _ if type_field.starts_with('[') => {
+            let len = array_len(type_field)?;
+            let (element, elements) = match (type_decl.components.as_slice(), value) {
+                ([element], serde_json::Value::Array(elements)) if elements.len() == len => {
+                    (element, elements)
+                }
+                _ => bail!("Expected an array of {len} elements"),
+            };
+            let element = resolve_generic(application, element)?;
+            for element_value in elements {
+                encode_json_value(element, element_value, encoding, bytes)?;
+            }
+        }
+        _ => bail!("Values of type `{type_field}` cannot be set"),
+    }
+    Ok(())
+}
+
+/// Append the big-endian `primitive` to `bytes`, padded to a word in the in-memory representation.
+fn push_primitive(bytes: &mut Vec<u8>, primitive: &[u8], encoding: ConfigurableEncoding) {
+    if encoding == ConfigurableEncoding::Memory {
+        bytes.resize(bytes.len() + 8 - primitive.len(), 0);
+    }
+    bytes.extend(primitive);
+}
+
+/// Get the string form of a string, number or boolean JSON value.
+fn scalar_str(value: &serde_json::Value) -> anyhow::Result<String> {
+    match value {
+        serde_json::Value::String(value) => Ok(value.clone()),
+        serde_json::Value::Number(_) | serde_json::Value::Bool(_) => Ok(value.to_string()),
+        _ => bail!("Expected a string, a number or a boolean, found `{value}`"),
+    }
+}
+
+fn parse_scalar<T>(value: &serde_json::Value) -> anyhow::Result<T>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    Ok(scalar_str(value)?.parse::<T>()?)
+}
+
+/// Get the length of an array or string array type, e.g., `3` for `[u8; 3]` or `str[3]`.
+fn array_len(type_field: &str) -> anyhow::Result<usize> {
+    type_field
+        .strip_suffix(']')
+        .and_then(|type_field| type_field.rsplit([';', '[']).next())
+        .and_then(|len| len.trim().parse().ok())
+        .ok_or_else(|| anyhow!("Invalid array type `{type_field}`"))
+}
+
+/// Get the type argument `component` stands for if its type is a type parameter of the type of
+/// `application`, e.g., `u64` for the `T` of `Some` in `Option<u64>`.
+fn resolve_generic<'a>(
+    application: &'a FullTypeApplication,
+    component: &'a FullTypeApplication,
+) -> anyhow::Result<&'a FullTypeApplication> {
+    let type_field = &component.type_decl.type_field;
+    if !type_field.starts_with("generic ") {
+        return Ok(component);
+    }
+    application
+        .type_decl
+        .type_parameters
+        .iter()
+        .position(|type_parameter| &type_parameter.type_field == type_field)
+        .and_then(|index| application.type_arguments.get(index))
+        .ok_or_else(|| {
+            anyhow!(
+                "Cannot resolve `{type_field}` in `{}`",
+                application.type_decl.type_field
+            )
+        })
+}
+
+/// Read the configurable values from a JSON file containing an object which maps the names of
+/// the configurables, optionally prefixed with `<CONTRACT_NAME>:`, to their values.
+///
+/// The values are returned in the `[<CONTRACT_NAME>:]NAME=VALUE` form of `--set-configurable`,
+/// with the values of structs, tuples, arrays and enums in their JSON form.
+pub(crate) fn read_configurable_overrides_file(path: &Path) -> anyhow::Result<Vec<String>> {
+    let file = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read configurables file {}", path.display()))?;
+    let values: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&file)
+        .with_context(|| format!("Invalid configurables file {}", path.display()))?;
+    values
+        .into_iter()
+        .map(|(name, value)| match value {
+            serde_json::Value::String(value) => Ok(format!("{name}={value}")),
+            serde_json::Value::Null => bail!("The value of configurable `{name}` is missing"),
+            value => Ok(format!("{name}={value}")),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_configurable_override_parse() {
+        let configurable = ConfigurableOverride::from_str(" MAX_SUPPLY = 42 ").unwrap();
+        let expected_configurable = ConfigurableOverride {
+            name: "MAX_SUPPLY".to_string(),
+            value: "42".to_string(),
+        };
+        assert_eq!(configurable, expected_configurable);
+
+        assert!(ConfigurableOverride::from_str("MAX_SUPPLY").is_err());
+        assert!(ConfigurableOverride::from_str("=42").is_err());
+        assert!(ConfigurableOverride::from_str("MAX SUPPLY=42").is_err());
+    }
+
+    const TEST_ABI: &str = r#"{
+        "programType": "contract",
+        "specVersion": "1",
+        "encodingVersion": "1",
+        "concreteTypes": [
+            { "type": "u8", "concreteTypeId": "c89951a24c6ca28c13fd1cfdc646b2b656d69e61a92b91023be7eb58eb914b6b" },
+            { "type": "bool", "concreteTypeId": "b760f44fa5965c2474a3b471467a22c43185152129295af588b022ae50b50903" },
+            { "type": "b256", "concreteTypeId": "7c5ee1cecf5f8eacd1284feb5f0bf2bdea533a51e2f0c9aabe9236d335989f3b" },
+            { "type": "u64", "concreteTypeId": "1506e6f44c1d6291cdf46395a8e573276a4fa79e8ace3fc891e092ef32d1b0a0" },
+            { "type": "str[4]", "concreteTypeId": "94f0fa95c830be5e4f711963e83259fe7e8bc723278ab6ec34449e791a99b53a" },
+            {
+                "type": "struct std::contract_id::ContractId",
+                "concreteTypeId": "29c10735d33b5159f0c71ee1dbd17b36a3e69e41f00fab0d42e1bd9f428d8a54",
+                "metadataTypeId": 0
+            },
+            {
+                "type": "struct Config",
+                "concreteTypeId": "b39d8a7ad0970c30f679e27aaa69ef021b4f72d2b3d054a86a8e5c0ef4aea7f2",
+                "metadataTypeId": 1
+            },
+            {
+                "type": "enum Mode",
+                "concreteTypeId": "3d534bc63b05c0a8f890704a365886da4fcca1bacc207edcf91ae56f1249c582",
+                "metadataTypeId": 2
+            }
+        ],
+        "metadataTypes": [
+            {
+                "type": "struct std::contract_id::ContractId",
+                "metadataTypeId": 0,
+                "components": [
+                    { "name": "bits", "typeId": "7c5ee1cecf5f8eacd1284feb5f0bf2bdea533a51e2f0c9aabe9236d335989f3b" }
+                ]
+            },
+            {
+                "type": "struct Config",
+                "metadataTypeId": 1,
+                "components": [
+                    { "name": "limit", "typeId": "1506e6f44c1d6291cdf46395a8e573276a4fa79e8ace3fc891e092ef32d1b0a0" },
+                    { "name": "enabled", "typeId": "b760f44fa5965c2474a3b471467a22c43185152129295af588b022ae50b50903" }
+                ]
+            },
+            {
+                "type": "enum Mode",
+                "metadataTypeId": 2,
+                "components": [
+                    { "name": "Off", "typeId": "2e38e77b22c314a449e91fafed92a43826ac6aa403ae6a8acb6cf58239fbaf5d" },
+                    { "name": "Limit", "typeId": "c89951a24c6ca28c13fd1cfdc646b2b656d69e61a92b91023be7eb58eb914b6b" }
+                ]
+            }
+        ],
+        "functions": [],
+        "loggedTypes": [],
+        "messagesTypes": [],
+        "configurables": [
+            { "name": "SMALL", "concreteTypeId": "c89951a24c6ca28c13fd1cfdc646b2b656d69e61a92b91023be7eb58eb914b6b", "offset": 0 },
+            { "name": "FLAG", "concreteTypeId": "b760f44fa5965c2474a3b471467a22c43185152129295af588b022ae50b50903", "offset": 0 },
+            { "name": "HASH", "concreteTypeId": "7c5ee1cecf5f8eacd1284feb5f0bf2bdea533a51e2f0c9aabe9236d335989f3b", "offset": 0 },
+            { "name": "NAME", "concreteTypeId": "94f0fa95c830be5e4f711963e83259fe7e8bc723278ab6ec34449e791a99b53a", "offset": 0 },
+            { "name": "OWNER", "concreteTypeId": "29c10735d33b5159f0c71ee1dbd17b36a3e69e41f00fab0d42e1bd9f428d8a54", "offset": 0 },
+            { "name": "CONFIG", "concreteTypeId": "b39d8a7ad0970c30f679e27aaa69ef021b4f72d2b3d054a86a8e5c0ef4aea7f2", "offset": 0 },
+            { "name": "MODE", "concreteTypeId": "3d534bc63b05c0a8f890704a365886da4fcca1bacc207edcf91ae56f1249c582", "offset": 0 }
+        ]
+    }"#;
+
+    fn encode(name: &str, value: &str, encoding: ConfigurableEncoding) -> anyhow::Result<Vec<u8>> {
+        let full_abi = FullProgramABI::from_json_abi(TEST_ABI).unwrap();
+        let configurable = full_abi
+            .configurables
+            .iter()
+            .find(|configurable| configurable.name == name)
+            .unwrap();
+        encode_value(&configurable.application, value, encoding)
+    }
+
+    #[test]
+    fn test_encode_value() {
+        assert_eq!(
+            encode("SMALL", "1", ConfigurableEncoding::Abi).unwrap(),
+            vec![1]
+        );
+        assert_eq!(
+            encode("SMALL", "1", ConfigurableEncoding::Memory).unwrap(),
+            vec![0, 0, 0, 0, 0, 0, 0, 1]
+        );
+        assert_eq!(
+            encode("FLAG", "true", ConfigurableEncoding::Abi).unwrap(),
+            vec![1]
+        );
+        assert!(encode("SMALL", "256", ConfigurableEncoding::Abi).is_err());
+        assert!(encode("FLAG", "1", ConfigurableEncoding::Abi).is_err());
+    }
+
+    #[test]
+    fn test_encode_b256_and_single_field_struct_values() {
+        let hash = format!("0x{}", "11".repeat(32));
+        assert_eq!(
+            encode("HASH", &hash, ConfigurableEncoding::Abi).unwrap(),
+            vec![0x11; 32]
+        );
+        // `ContractId`s are given as their `b256`, or as an object with the `bits` field.
+        assert_eq!(
+            encode("OWNER", &hash, ConfigurableEncoding::Abi).unwrap(),
+            vec![0x11; 32]
+        );
+        assert_eq!(
+            encode(
+                "OWNER",
+                &format!("{{\"bits\": \"{hash}\"}}"),
+                ConfigurableEncoding::Memory
+            )
+            .unwrap(),
+            vec![0x11; 32]
+        );
+        assert!(encode("HASH", "0x11", ConfigurableEncoding::Abi).is_err());
+    }
+
+    #[test]
+    fn test_encode_str_array_value() {
+        assert_eq!(
+            encode("NAME", "sway", ConfigurableEncoding::Abi).unwrap(),
+            b"sway".to_vec()
+        );
+        assert_eq!(
+            encode("NAME", "sway", ConfigurableEncoding::Memory).unwrap(),
+            b"sway\0\0\0\0".to_vec()
+        );
+        assert!(encode("NAME", "fuel labs", ConfigurableEncoding::Abi).is_err());
+    }
+
+    #[test]
+    fn test_encode_struct_value() {
+        let config = r#"{"limit": 42, "enabled": true}"#;
+        let mut expected = 42u64.to_be_bytes().to_vec();
+        expected.push(1);
+        assert_eq!(
+            encode("CONFIG", config, ConfigurableEncoding::Abi).unwrap(),
+            expected
+        );
+        assert_eq!(
+            encode("CONFIG", config, ConfigurableEncoding::Memory).unwrap(),
+            [42u64.to_be_bytes(), 1u64.to_be_bytes()].concat()
+        );
+        assert!(encode("CONFIG", r#"{"limit": 42}"#, ConfigurableEncoding::Abi).is_err());
+        assert!(encode(
+            "CONFIG",
+            r#"{"limit": 42, "enabled": true, "paused": false}"#,
+            ConfigurableEncoding::Abi
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_encode_enum_value() {
+        let mut expected = 1u64.to_be_bytes().to_vec();
+        expected.push(3);
+        assert_eq!(
+            encode("MODE", "Off", ConfigurableEncoding::Abi).unwrap(),
+            0u64.to_be_bytes().to_vec()
+        );
+        assert_eq!(
+            encode("MODE", r#"{"Limit": 3}"#, ConfigurableEncoding::Abi).unwrap(),
+            expected
+        );
+        assert!(encode("MODE", "On", ConfigurableEncoding::Abi).is_err());
+        assert!(encode("MODE", "Off", ConfigurableEncoding::Memory).is_err());
+    }
+}
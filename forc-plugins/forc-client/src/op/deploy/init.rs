@@ -0,0 +1,208 @@
+use crate::util::encode::{Token, Type};
+use anyhow::{anyhow, bail, Context};
+use fuel_abi_types::abi::full_program::FullProgramABI;
+use std::str::FromStr;
+
+/// A contract method called right after the contract is deployed, given as `method(arg, ...)`.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct InitCall {
+    pub(crate) method: String,
+    pub(crate) args: Vec<String>,
+}
+
+impl FromStr for InitCall {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (method, args) = s
+            .strip_suffix(')')
+            .and_then(|call| call.split_once('('))
+            .with_context(|| format!("Invalid init call `{s}`, expected `method(arg, ...)`"))?;
+
+        let method = method.trim();
+        if method.is_empty() || !method.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            bail!("Invalid method name `{method}` in init call `{s}`");
+        }
+
+        let args =
+            split_args(args).with_context(|| format!("Invalid arguments in init call `{s}`"))?;
+
+        Ok(Self {
+            method: method.to_string(),
+            args,
+        })
+    }
+}
+
+/// Splits the comma separated `args` of an init call, ignoring the commas nested in tuples, arrays,
+/// structs and string literals, eg. `(1, 2), "a, b"` is split into `(1, 2)` and `"a, b"`.
+fn split_args(args: &str) -> anyhow::Result<Vec<String>> {
+    if args.trim().is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut split_args = vec![];
+    let mut closing_brackets = vec![];
+    let mut in_string = false;
+    let mut arg_start = 0;
+    for (idx, c) in args.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            _ if in_string => {}
+            '(' => closing_brackets.push(')'),
+            '[' => closing_brackets.push(']'),
+            '{' => closing_brackets.push('}'),
+            ')' | ']' | '}' => {
+                if closing_brackets.pop() != Some(c) {
+                    bail!("Unexpected `{c}` in `{args}`");
+                }
+            }
+            ',' if closing_brackets.is_empty() => {
+                split_args.push(args[arg_start..idx].trim().to_string());
+                arg_start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    if in_string {
+        bail!("Unterminated string literal in `{args}`");
+    }
+    if let Some(closing_bracket) = closing_brackets.last() {
+        bail!("Missing `{closing_bracket}` in `{args}`");
+    }
+    split_args.push(args[arg_start..].trim().to_string());
+
+    if split_args.iter().any(|arg| arg.is_empty()) {
+        bail!("Empty argument in `{args}`");
+    }
+    Ok(split_args)
+}
+
+impl InitCall {
+    /// Generate the tokens of the call arguments, using the argument types of the method in the
+    /// provided json abi.
+    ///
+    /// The method must not return a value, as the result of the call is not decoded.
+    pub(crate) fn arg_tokens(
+        &self,
+        json_abi_str: &str,
+    ) -> anyhow::Result<Vec<fuels_core::types::Token>> {
+        let full_abi = FullProgramABI::from_json_abi(json_abi_str)?;
+        let function = full_abi
+            .functions
+            .iter()
+            .find(|abi_func| abi_func.name() == self.method)
+            .ok_or_else(|| anyhow!("The contract has no method named `{}`", self.method))?;
+
+        if function.output().type_decl.type_field != "()" {
+            bail!("The init method `{}` must not return a value", self.method);
+        }
+
+        let arg_types = function
+            .inputs()
+            .iter()
+            .map(Type::try_from)
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let expected_arg_count = arg_types.len();
+        let provided_arg_count = self.args.len();
+        if expected_arg_count != provided_arg_count {
+            bail!(
+                "`{}` takes {expected_arg_count} arguments, {provided_arg_count} provided",
+                self.method
+            );
+        }
+
+        arg_types
+            .iter()
+            .zip(self.args.iter())
+            .map(|(ty, val)| Token::from_type_and_value(ty, val).map(|token| token.0))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InitCall;
+    use std::str::FromStr;
+
+    const TEST_JSON_ABI: &str = r#"{"programType": "contract","specVersion": "1","encodingVersion": "1","metadataTypes":[],
+    "concreteTypes":[{"concreteTypeId":"2e38e77b22c314a449e91fafed92a43826ac6aa403ae6a8acb6cf58239fbaf5d",
+    "type":"()"},{"concreteTypeId":"b760f44fa5965c2474a3b471467a22c43185152129295af588b022ae50b50903","type":"bool"},
+    {"concreteTypeId":"c89951a24c6ca28c13fd1cfdc646b2b656d69e61a92b91023be7eb58eb914b6b","type":"u8"}],
+    "functions":[{"inputs":[{"name":"test_u8","concreteTypeId":"c89951a24c6ca28c13fd1cfdc646b2b656d69e61a92b91023be7eb58eb914b6b"},
+    {"name":"test_bool","concreteTypeId":"b760f44fa5965c2474a3b471467a22c43185152129295af588b022ae50b50903"}],"name":"initialize",
+    "output":"2e38e77b22c314a449e91fafed92a43826ac6aa403ae6a8acb6cf58239fbaf5d"},
+    {"inputs":[],"name":"get_u8","output":"c89951a24c6ca28c13fd1cfdc646b2b656d69e61a92b91023be7eb58eb914b6b"}],"loggedTypes":[],
+    "messagesTypes":[],"configurables":[]}"#;
+
+    #[test]
+    fn test_init_call_parse_success() {
+        let init_call = InitCall::from_str(" initialize(1, true) ").unwrap();
+        let expected_init_call = InitCall {
+            method: "initialize".to_string(),
+            args: vec!["1".to_string(), "true".to_string()],
+        };
+        assert_eq!(init_call, expected_init_call);
+
+        let init_call = InitCall::from_str("initialize()").unwrap();
+        assert!(init_call.args.is_empty());
+
+        let init_call =
+            InitCall::from_str(r#"initialize((1, [2, 3]), { a: 4, b: "5, 6" }, "(")"#).unwrap();
+        let expected_init_call = InitCall {
+            method: "initialize".to_string(),
+            args: vec![
+                "(1, [2, 3])".to_string(),
+                r#"{ a: 4, b: "5, 6" }"#.to_string(),
+                r#""(""#.to_string(),
+            ],
+        };
+        assert_eq!(init_call, expected_init_call);
+    }
+
+    #[test]
+    fn test_init_call_parse_fail() {
+        assert!(InitCall::from_str("initialize").is_err());
+        assert!(InitCall::from_str("initialize(1").is_err());
+        assert!(InitCall::from_str("(1)").is_err());
+        assert!(InitCall::from_str("init ialize(1)").is_err());
+        assert!(InitCall::from_str("initialize(1,)").is_err());
+        assert!(InitCall::from_str("initialize((1, 2)").is_err());
+        assert!(InitCall::from_str("initialize([1, 2))").is_err());
+        assert!(InitCall::from_str(r#"initialize("1)"#).is_err());
+    }
+
+    #[test]
+    fn test_init_call_arg_tokens_success() {
+        let init_call = InitCall::from_str("initialize(1, true)").unwrap();
+        let tokens = init_call.arg_tokens(TEST_JSON_ABI).unwrap();
+        let expected_tokens = vec![
+            fuels_core::types::Token::U8(1),
+            fuels_core::types::Token::Bool(true),
+        ];
+        assert_eq!(tokens, expected_tokens);
+    }
+
+    #[test]
+    #[should_panic(expected = "`initialize` takes 2 arguments, 1 provided")]
+    fn test_init_call_arg_tokens_fail_arg_count() {
+        let init_call = InitCall::from_str("initialize(1)").unwrap();
+        init_call.arg_tokens(TEST_JSON_ABI).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "The init method `get_u8` must not return a value")]
+    fn test_init_call_arg_tokens_fail_return_value() {
+        let init_call = InitCall::from_str("get_u8()").unwrap();
+        init_call.arg_tokens(TEST_JSON_ABI).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "The contract has no method named `set_u8`")]
+    fn test_init_call_arg_tokens_fail_unknown_method() {
+        let init_call = InitCall::from_str("set_u8(1)").unwrap();
+        init_call.arg_tokens(TEST_JSON_ABI).unwrap();
+    }
+}
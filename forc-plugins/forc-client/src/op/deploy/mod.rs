@@ -1,8 +1,11 @@
+mod configurables;
+mod init;
 use crate::{
     cmd,
-    constants::TX_SUBMIT_TIMEOUT_MS,
+    constants::GAS_PRICE_ESTIMATION_BLOCK_HORIZON,
     util::{
         account::ForcClientAccount,
+        fee::{check_max_fee_cap, submit_with_fee_bump},
         node_url::get_node_url,
         pkg::{built_pkgs, create_proxy_contract, update_proxy_address_in_manifest},
         target::Target,
@@ -25,8 +28,10 @@ use fuel_crypto::fuel_types::ChainId;
 use fuel_tx::{Salt, Transaction};
 use fuel_vm::{consts::WORD_SIZE, fuel_asm::op, prelude::*};
 use fuels::{
+    core::codec::{encode_fn_selector, EncoderConfig, LogDecoder},
     macros::abigen,
     programs::{
+        calls::{CallHandler, ContractCall},
         contract::{LoadConfiguration, StorageConfiguration},
         executable::Executable,
     },
@@ -36,8 +41,10 @@ use fuels::{
     },
 };
 use fuels_accounts::{provider::Provider, Account, ViewOnlyAccount};
-use fuels_core::types::{transaction::TxPolicies, transaction_builders::CreateTransactionBuilder};
-use futures::FutureExt;
+use fuels_core::types::{
+    transaction::TxPolicies,
+    transaction_builders::{CreateTransactionBuilder, TransactionBuilder as _},
+};
 use pkg::{BuildProfile, BuiltPackage};
 use serde::{Deserialize, Serialize};
 use std::{
@@ -45,13 +52,18 @@ use std::{
     path::{Path, PathBuf},
     str::FromStr,
     sync::Arc,
-    time::Duration,
 };
 use sway_core::{asm_generation::ProgramABI, language::parsed::TreeType, BuildTarget};
 
+use self::{
+    configurables::{read_configurable_overrides_file, ConfigurableOverride},
+    init::InitCall,
+};
+
 /// Default maximum contract size allowed for a single contract. If the target
-/// contract size is bigger than this amount, forc-deploy will automatically
-/// starts dividing the contract and deploy them in chunks automatically.
+/// contract size is bigger than this amount, or than the maximum contract size
+/// of the target network, forc-deploy will automatically starts dividing the
+/// contract and deploy them in chunks automatically.
 /// The value is in bytes
 const MAX_CONTRACT_SIZE: usize = 100_000;
 
@@ -81,13 +93,20 @@ pub struct DeployedExecutable {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeploymentArtifact {
-    transaction_id: String,
+    /// The ID of the deployment transaction. Not known for chunked deployments, which consist of
+    /// a transaction per blob and one for the loader contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    transaction_id: Option<String>,
     salt: String,
     network_endpoint: String,
     chain_id: ChainId,
     contract_id: String,
     deployment_size: usize,
     deployed_block_height: Option<u32>,
+    /// For chunked deployments, the IDs of the blobs the contract was split into, in the order in
+    /// which the loader contract `contract_id` loads them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    blob_ids: Vec<String>,
 }
 
 impl DeploymentArtifact {
@@ -157,6 +176,197 @@ fn validate_and_parse_salts<'a>(
     Ok(contract_salt_map)
 }
 
+type ContractInitMap = BTreeMap<String, InitCall>;
+
+/// Takes the contract init calls passed via the --init option, validates them against the ABIs
+/// of the contracts and returns a ContractInitMap (BTreeMap of contract names to init calls).
+///
+/// Validating the calls up front reports invalid calls before anything is deployed.
+fn validate_and_parse_init_calls(
+    init_args: &[String],
+    contracts_to_deploy: &[Arc<BuiltPackage>],
+) -> Result<ContractInitMap> {
+    let mut contract_init_map = ContractInitMap::default();
+
+    if let [pkg] = contracts_to_deploy {
+        if init_args.len() > 1 {
+            bail!("More than 1 init call was specified when deploying a single contract");
+        }
+        // The contract name is optional when deploying a single contract.
+        let pkg_name = pkg.descriptor.manifest_file.project_name();
+        let init_call = init_args[0]
+            .split_once(':')
+            .filter(|(given_contract_name, _)| *given_contract_name == pkg_name)
+            .map_or(init_args[0].as_str(), |(_, init_call)| init_call);
+        contract_init_map.insert(pkg_name.to_string(), init_call.parse()?);
+    } else {
+        for init_arg in init_args {
+            let Some((given_contract_name, init_call)) = init_arg.split_once(':') else {
+                bail!("Invalid init call provided - init call must be in the form <CONTRACT_NAME>:<METHOD>(<ARGS>) when deploying a workspace");
+            };
+            if contract_init_map
+                .insert(given_contract_name.to_string(), init_call.parse()?)
+                .is_some()
+            {
+                bail!("2 init calls provided for contract '{given_contract_name}'");
+            }
+        }
+    }
+
+    for (contract_name, init_call) in &contract_init_map {
+        let Some(pkg) = contracts_to_deploy
+            .iter()
+            .find(|pkg| pkg.descriptor.manifest_file.project_name() == contract_name)
+        else {
+            bail!("Init call provided for contract '{contract_name}', which is not being deployed");
+        };
+        init_call
+            .arg_tokens(&pkg_json_abi(pkg)?)
+            .with_context(|| format!("Invalid init call for contract '{contract_name}'"))?;
+    }
+
+    Ok(contract_init_map)
+}
+
+type ContractConfigurablesMap = BTreeMap<String, Vec<ConfigurableOverride>>;
+
+/// Takes the configurable values passed via the --override-configurables and --set-configurable
+/// options and returns a ContractConfigurablesMap (BTreeMap of contract names to configurable
+/// values).
+fn parse_configurable_overrides(
+    command: &cmd::Deploy,
+    contracts_to_deploy: &[Arc<BuiltPackage>],
+) -> Result<ContractConfigurablesMap> {
+    let mut configurable_args = match &command.override_configurables {
+        Some(path) => read_configurable_overrides_file(Path::new(path))?,
+        None => vec![],
+    };
+    configurable_args.extend(command.set_configurable.iter().flatten().cloned());
+
+    let mut contract_configurables_map = ContractConfigurablesMap::default();
+    for configurable_arg in &configurable_args {
+        let (contract_name, configurable) = match contracts_to_deploy {
+            // The contract name is optional when deploying a single contract.
+            [pkg] => {
+                let pkg_name = pkg.descriptor.manifest_file.project_name();
+                let configurable = configurable_arg
+                    .split_once(':')
+                    .filter(|(given_contract_name, _)| *given_contract_name == pkg_name)
+                    .map_or(configurable_arg.as_str(), |(_, configurable)| configurable);
+                (pkg_name, configurable)
+            }
+            _ => configurable_arg.split_once(':').ok_or_else(|| {
+                anyhow::anyhow!("Invalid configurable value provided - configurable values must be in the form <CONTRACT_NAME>:<NAME>=<VALUE> when deploying a workspace")
+            })?,
+        };
+        let configurable: ConfigurableOverride = configurable.parse()?;
+        let configurables = contract_configurables_map
+            .entry(contract_name.to_string())
+            .or_default();
+        // Later values, e.g., from the command line, replace earlier ones.
+        configurables.retain(|other| other.name != configurable.name);
+        configurables.push(configurable);
+    }
+
+    Ok(contract_configurables_map)
+}
+
+/// Sets the configurables of the contracts to the values in `contract_configurables_map` by
+/// patching their bytecode, using the configurables layout of each contract. Returns the
+/// contracts to deploy, the ids of which are computed from the patched bytecode.
+fn apply_configurable_overrides(
+    contract_configurables_map: &ContractConfigurablesMap,
+    contracts_to_deploy: &[Arc<BuiltPackage>],
+) -> Result<Vec<Arc<BuiltPackage>>> {
+    for contract_name in contract_configurables_map.keys() {
+        if !contracts_to_deploy
+            .iter()
+            .any(|pkg| pkg.descriptor.manifest_file.project_name() == contract_name)
+        {
+            bail!("Configurable values provided for contract '{contract_name}', which is not being deployed");
+        }
+    }
+
+    contracts_to_deploy
+        .iter()
+        .map(|pkg| {
+            let contract_name = pkg.descriptor.manifest_file.project_name();
+            let Some(configurables) = contract_configurables_map.get(contract_name) else {
+                return Ok(pkg.clone());
+            };
+            let json_abi = pkg_json_abi(pkg)?;
+            let mut patched_pkg = (**pkg).clone();
+            for configurable in configurables {
+                let encoded_value = configurable
+                    .encoded_value(&json_abi, &pkg.configurables_layout)
+                    .and_then(|encoded_value| {
+                        pkg.configurables_layout
+                            .patch(
+                                &mut patched_pkg.bytecode.bytes,
+                                &configurable.name,
+                                &encoded_value,
+                            )
+                            .map_err(anyhow::Error::from)
+                    });
+                encoded_value.with_context(|| {
+                    format!("Failed to set configurable for contract '{contract_name}'")
+                })?;
+            }
+            Ok(Arc::new(patched_pkg))
+        })
+        .collect()
+}
+
+fn pkg_json_abi(pkg: &BuiltPackage) -> Result<String> {
+    let minify_json_abi = true;
+    pkg.json_abi_string(minify_json_abi)?
+        .ok_or_else(|| anyhow::anyhow!("Missing json abi string"))
+}
+
+/// Calls the init method of the freshly deployed contract `contract_id`.
+///
+/// If the contract is behind a proxy, the call is made through the proxy, so that the storage of
+/// the proxy is initialized.
+async fn call_init_method(
+    command: &cmd::Deploy,
+    pkg: &BuiltPackage,
+    init_call: &InitCall,
+    contract_id: ContractId,
+    proxy_id: Option<ContractId>,
+    account: &ForcClientAccount,
+) -> Result<()> {
+    let arg_tokens = init_call.arg_tokens(&pkg_json_abi(pkg)?)?;
+    let call_target = proxy_id.unwrap_or(contract_id);
+
+    let tx_policies = tx_policies_from_cmd(command);
+    let call_handler: CallHandler<_, ContractCall, ()> = CallHandler::new_contract_call(
+        call_target.into(),
+        account.clone(),
+        encode_fn_selector(&init_call.method),
+        &arg_tokens,
+        LogDecoder::new(Default::default()),
+        false,
+        EncoderConfig::default(),
+    );
+    let call_handler = call_handler.with_tx_policies(tx_policies);
+    let call_handler = if proxy_id.is_some() {
+        call_handler.with_contract_ids(&[contract_id.into()])
+    } else {
+        call_handler
+    };
+    call_handler.call().await?;
+
+    println_action_green(
+        "Initialized",
+        &format!(
+            "contract {} with `{}` at 0x{call_target}",
+            pkg.descriptor.manifest_file.project_name(),
+            init_call.method
+        ),
+    );
+    Ok(())
+}
+
 /// Depending on the cli options user passed, either returns storage slots from
 /// compiled package, or the ones user provided as overrides.
 fn resolve_storage_slots(
@@ -175,46 +385,76 @@ fn resolve_storage_slots(
     Ok(storage_slots)
 }
 
+/// Returns the size above which contracts are deployed in chunks: the smaller of
+/// [MAX_CONTRACT_SIZE] and the maximum contract size of the network.
+async fn max_contract_size(node_url: &str) -> Result<usize> {
+    let client = FuelClient::new(node_url)?;
+    let chain_info = client.chain_info().await?;
+    let network_max_size = chain_info
+        .consensus_parameters
+        .contract_params()
+        .contract_max_size();
+    Ok(usize::try_from(network_max_size)
+        .unwrap_or(usize::MAX)
+        .min(MAX_CONTRACT_SIZE))
+}
+
 /// Creates blobs from the contract to deploy contracts that are larger than
 /// maximum contract size. Created blobs are deployed, and a loader contract is
 /// generated such that it loads all the deployed blobs, and provides the user
 /// a single contract (loader contract that loads the blobs) to call into.
+///
+/// The blobs making up the loader contract are recorded in its deployment artifact.
 async fn deploy_chunked(
     command: &cmd::Deploy,
     compiled: &BuiltPackage,
     salt: Salt,
     account: &ForcClientAccount,
     provider: &Provider,
-    pkg_name: &str,
+    max_contract_size: usize,
 ) -> anyhow::Result<ContractId> {
+    let manifest = &compiled.descriptor.manifest_file;
+    let pkg_name = manifest.project_name();
     println_action_green("Deploying", &format!("contract {pkg_name} chunks"));
 
     let storage_slots = resolve_storage_slots(command, compiled)?;
-    let chain_info = provider.chain_info().await?;
-    let target = Target::from_str(&chain_info.name).unwrap_or_default();
-    let contract_url = match target.explorer_url() {
-        Some(explorer_url) => format!("{explorer_url}/contract/0x"),
-        None => "".to_string(),
-    };
+    let node_url = provider.url();
+    let client = FuelClient::new(node_url)?;
+    let chain_info = client.chain_info().await?;
+    let chain_id = chain_info.consensus_parameters.chain_id();
 
-    let blobs = compiled
-        .bytecode
-        .bytes
-        .chunks(MAX_CONTRACT_SIZE)
+    let bytecode = &compiled.bytecode.bytes;
+    let blobs: Vec<_> = bytecode
+        .chunks(max_contract_size)
         .map(|chunk| Blob::new(chunk.to_vec()))
         .collect();
+    let blob_ids = blobs
+        .iter()
+        .map(|blob| format!("0x{}", hex::encode(blob.id())))
+        .collect();
 
     let tx_policies = tx_policies_from_cmd(command);
-    let contract_id =
+    let contract_id: ContractId =
         fuels::programs::contract::Contract::loader_from_blobs(blobs, salt, storage_slots)?
             .deploy(account, tx_policies)
             .await?
             .into();
 
-    println_action_green(
-        "Finished",
-        &format!("deploying loader contract for {pkg_name} {contract_url}{contract_id}"),
-    );
+    create_deployment_artifact(
+        DeploymentArtifact {
+            transaction_id: None,
+            salt: format!("0x{}", salt),
+            network_endpoint: node_url.to_string(),
+            chain_id,
+            contract_id: format!("0x{}", contract_id),
+            deployment_size: bytecode.len(),
+            deployed_block_height: None,
+            blob_ids,
+        },
+        command,
+        manifest,
+        chain_info,
+    )?;
 
     Ok(contract_id)
 }
@@ -605,15 +845,26 @@ pub async fn deploy_contracts(
         None
     };
 
+    let contract_configurables_map = parse_configurable_overrides(command, contracts_to_deploy)?;
+    let contracts_to_deploy =
+        &apply_configurable_overrides(&contract_configurables_map, contracts_to_deploy)?;
+
+    let contract_init_map = match &command.init {
+        Some(init_args) => validate_and_parse_init_calls(init_args, contracts_to_deploy)?,
+        None => ContractInitMap::default(),
+    };
+
     let node_url = validate_and_get_node_url(command, contracts_to_deploy).await?;
     let provider = Provider::connect(node_url.clone()).await?;
+    let max_contract_size = max_contract_size(&node_url).await?;
 
     // Confirmation step. Summarize the transaction(s) for the deployment.
     let account = confirm_transaction_details(
         contracts_to_deploy,
+        &contract_init_map,
         command,
         node_url.clone(),
-        MAX_CONTRACT_SIZE,
+        max_contract_size,
     )
     .await?;
 
@@ -633,20 +884,9 @@ pub async fn deploy_contracts(
             }
         };
         let bytecode_size = pkg.bytecode.bytes.len();
-        let deployed_contract_id = if bytecode_size > MAX_CONTRACT_SIZE {
-            // Deploy chunked
-            let node_url = get_node_url(&command.node, &pkg.descriptor.manifest_file.network)?;
-            let provider = Provider::connect(node_url).await?;
-
-            deploy_chunked(
-                command,
-                pkg,
-                salt,
-                &account,
-                &provider,
-                &pkg.descriptor.name,
-            )
-            .await?
+        let chunked = bytecode_size > max_contract_size;
+        let deployed_contract_id = if chunked {
+            deploy_chunked(command, pkg, salt, &account, &provider, max_contract_size).await?
         } else {
             deploy_pkg(command, pkg, salt, &provider, &account).await?
         };
@@ -695,10 +935,23 @@ pub async fn deploy_contracts(
             _ => None,
         };
 
+        if let Some(init_call) = contract_init_map.get(pkg.descriptor.manifest_file.project_name())
+        {
+            call_init_method(
+                command,
+                pkg,
+                init_call,
+                deployed_contract_id,
+                proxy_id,
+                &account,
+            )
+            .await?;
+        }
+
         let deployed_contract = DeployedContract {
             id: deployed_contract_id,
             proxy: proxy_id,
-            chunked: bytecode_size > MAX_CONTRACT_SIZE,
+            chunked,
         };
         deployed_contracts.push(deployed_contract);
     }
@@ -708,6 +961,7 @@ pub async fn deploy_contracts(
 /// Prompt the user to confirm the transactions required for deployment, as well as the signing key.
 async fn confirm_transaction_details(
     pkgs_to_deploy: &[Arc<BuiltPackage>],
+    contract_init_map: &ContractInitMap,
     command: &cmd::Deploy,
     node_url: String,
     max_contract_size: usize,
@@ -742,10 +996,16 @@ async fn confirm_transaction_details(
                 ""
             };
 
-            format!(
-                "deploy {}{blob_text}{proxy_text}",
-                pkg.descriptor.manifest_file.project_name()
-            )
+            let pkg_name = pkg.descriptor.manifest_file.project_name();
+            let init_text = match contract_init_map.get(pkg_name) {
+                Some(init_call) => {
+                    tx_count += 1;
+                    &format!(" + call {}", init_call.method)
+                }
+                None => "",
+            };
+
+            format!("deploy {pkg_name}{blob_text}{proxy_text}{init_text}")
         })
         .collect::<Vec<_>>()
         .join(" + ");
@@ -781,6 +1041,7 @@ pub async fn deploy_pkg(
     let state_root = Contract::initial_state_root(storage_slots.iter());
     let contract_id = contract.id(&salt, &root, &state_root);
     let tx_policies = tx_policies_from_cmd(command);
+    let initial_tip = tx_policies.tip().unwrap_or_default();
 
     let mut tb = CreateTransactionBuilder::prepare_contract_deployment(
         bytecode.clone(),
@@ -789,31 +1050,38 @@ pub async fn deploy_pkg(
         salt,
         storage_slots.clone(),
         tx_policies,
-    );
-
+    )
+    .with_estimation_horizon(GAS_PRICE_ESTIMATION_BLOCK_HORIZON);
     account.add_witnesses(&mut tb)?;
     account.adjust_for_fee(&mut tb, 0).await?;
 
-    let tx = tb.build(provider).await?;
-    let tx = Transaction::from(tx);
+    // The inputs are selected only once, so that a re-submission with a bumped tip spends the same
+    // coins and replaces the pending transaction.
+    let build_tx = |tip: u64| {
+        let tb = tb.clone().with_tx_policies(tx_policies.with_tip(tip));
+        async move { Ok::<_, anyhow::Error>(Transaction::from(tb.build(provider).await?)) }
+    };
 
     let chain_info = client.chain_info().await?;
     let chain_id = chain_info.consensus_parameters.chain_id();
 
     // If only submitting the transaction, don't wait for the deployment to complete
     let contract_id: ContractId = if command.submit_only {
+        let tx = build_tx(initial_tip).await?;
+        check_max_fee_cap(&tx, command.fee_bump.max_fee_cap)?;
         match client.submit(&tx).await {
             Ok(transaction_id) => {
                 // Create a deployment artifact.
                 create_deployment_artifact(
                     DeploymentArtifact {
-                        transaction_id: format!("0x{}", transaction_id),
+                        transaction_id: Some(format!("0x{}", transaction_id)),
                         salt: format!("0x{}", salt),
                         network_endpoint: node_url.to_string(),
                         chain_id,
                         contract_id: format!("0x{}", contract_id),
                         deployment_size: bytecode.len(),
                         deployed_block_height: None,
+                        blob_ids: vec![],
                     },
                     command,
                     manifest,
@@ -831,51 +1099,47 @@ pub async fn deploy_pkg(
             }
         }
     } else {
-        let deployment_request = client.submit_and_await_commit(&tx).map(|res| match res {
-            Ok(logs) => match logs {
-                TransactionStatus::Submitted { .. } => {
-                    bail!("contract {} deployment timed out", &contract_id);
-                }
-                TransactionStatus::Success { block_height, .. } => {
-                    // Create a deployment artifact.
-                    create_deployment_artifact(
-                        DeploymentArtifact {
-                            transaction_id: format!("0x{}", tx.id(&chain_id)),
-                            salt: format!("0x{}", salt),
-                            network_endpoint: node_url.to_string(),
-                            chain_id,
-                            contract_id: format!("0x{}", contract_id),
-                            deployment_size: bytecode.len(),
-                            deployed_block_height: Some(*block_height),
-                        },
-                        command,
-                        manifest,
-                        chain_info,
-                    )?;
-
-                    Ok(contract_id)
-                }
-                e => {
-                    bail!(
-                        "contract {} failed to deploy due to an error: {:?}",
-                        &contract_id,
-                        e
+        let (tx, status) =
+            submit_with_fee_bump(&client, provider, &command.fee_bump, initial_tip, build_tx)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed waiting for contract {contract_id} to deploy. \
+                        The transaction may have been dropped."
                     )
-                }
-            },
-            Err(e) => bail!("{e}"),
-        });
-        tokio::time::timeout(
-            Duration::from_millis(TX_SUBMIT_TIMEOUT_MS),
-            deployment_request,
-        )
-            .await
-            .with_context(|| {
-                format!(
-                    "Timed out waiting for contract {} to deploy. The transaction may have been dropped.",
-                    &contract_id
+                })?;
+        match status {
+            TransactionStatus::Submitted { .. } => {
+                bail!("contract {} deployment timed out", &contract_id);
+            }
+            TransactionStatus::Success { block_height, .. } => {
+                // Create a deployment artifact.
+                create_deployment_artifact(
+                    DeploymentArtifact {
+                        transaction_id: Some(format!("0x{}", tx.id(&chain_id))),
+                        salt: format!("0x{}", salt),
+                        network_endpoint: node_url.to_string(),
+                        chain_id,
+                        contract_id: format!("0x{}", contract_id),
+                        deployment_size: bytecode.len(),
+                        deployed_block_height: Some(block_height),
+                        blob_ids: vec![],
+                    },
+                    command,
+                    manifest,
+                    chain_info,
+                )?;
+
+                contract_id
+            }
+            e => {
+                bail!(
+                    "contract {} failed to deploy due to an error: {:?}",
+                    &contract_id,
+                    e
                 )
-            })??
+            }
+        }
     };
 
     Ok(contract_id)
@@ -910,6 +1174,7 @@ fn build_opts_from_cmd(cmd: &cmd::Deploy, member_filter: pkg::MemberFilter) -> p
             bytecode: cmd.print.bytecode,
             bytecode_spans: false,
             ir: cmd.print.ir(),
+            verify_ir: cmd.print.verify_ir(),
             reverse_order: cmd.print.reverse_order,
         },
         time_phases: cmd.print.time_phases,
@@ -929,6 +1194,8 @@ fn build_opts_from_cmd(cmd: &cmd::Deploy, member_filter: pkg::MemberFilter) -> p
         member_filter,
         experimental: cmd.experimental.experimental.clone(),
         no_experimental: cmd.experimental.no_experimental.clone(),
+        message_format: Default::default(),
+        analysis_passes: Default::default(),
     }
 }
 
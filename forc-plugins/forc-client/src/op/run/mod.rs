@@ -1,8 +1,9 @@
 mod encode;
 use crate::{
     cmd,
-    constants::TX_SUBMIT_TIMEOUT_MS,
+    constants::{GAS_PRICE_ESTIMATION_BLOCK_HORIZON, TX_SUBMIT_TIMEOUT_MS},
     util::{
+        fee::submit_with_fee_bump,
         node_url::get_node_url,
         pkg::built_pkgs,
         tx::{prompt_forc_wallet_password, select_account, SignerSelectionMode},
@@ -19,12 +20,12 @@ use fuels::{
     types::{
         bech32::Bech32ContractId,
         transaction::TxPolicies,
-        transaction_builders::{BuildableTransaction, VariableOutputPolicy},
+        transaction_builders::{BuildableTransaction, TransactionBuilder, VariableOutputPolicy},
     },
 };
 use fuels_accounts::{provider::Provider, Account};
 use pkg::BuiltPackage;
-use std::time::Duration;
+use std::{future::Future, time::Duration};
 use std::{path::PathBuf, str::FromStr};
 use sway_core::language::parsed::TreeType;
 use sway_core::BuildTarget;
@@ -149,6 +150,8 @@ pub async fn run_pkg(
         .into_iter()
         .map(Bech32ContractId::from)
         .collect::<Vec<_>>();
+    let tx_policies = tx_policies_from_cmd(command);
+    let initial_tip = tx_policies.tip().unwrap_or_default();
     let call = ScriptCall {
         script_binary,
         encoded_args: Ok(script_data),
@@ -156,59 +159,68 @@ pub async fn run_pkg(
         outputs: vec![],
         external_contracts,
     };
-    let tx_policies = tx_policies_from_cmd(command);
     let mut tb = call
         .transaction_builder(tx_policies, VariableOutputPolicy::EstimateMinimum, &account)
-        .await?;
+        .await?
+        .with_estimation_horizon(GAS_PRICE_ESTIMATION_BLOCK_HORIZON);
 
     account.add_witnesses(&mut tb)?;
     account.adjust_for_fee(&mut tb, 0).await?;
 
-    let tx = tb.build(provider).await?;
+    // The inputs are selected only once, so that a re-submission with a bumped tip spends the same
+    // coins and replaces the pending transaction.
+    let provider = &provider;
+    let build_tx = |tip: u64| {
+        let tb = tb.clone().with_tx_policies(tx_policies.with_tip(tip));
+        async move { Ok::<_, anyhow::Error>(Transaction::from(tb.build(provider).await?)) }
+    };
 
     if command.dry_run {
+        let tx = build_tx(initial_tip).await?;
         info!("{:?}", tx);
         Ok(RanScript { receipts: vec![] })
     } else {
-        let receipts = try_send_tx(
-            node_url.as_str(),
-            &tx.into(),
-            command.pretty_print,
-            command.simulate,
-        )
-        .await?;
+        let receipts =
+            try_send_tx(node_url.as_str(), provider, command, initial_tip, build_tx).await?;
         Ok(RanScript { receipts })
     }
 }
 
-async fn try_send_tx(
+async fn try_send_tx<F, Fut>(
     node_url: &str,
-    tx: &Transaction,
-    pretty_print: bool,
-    simulate: bool,
-) -> Result<Vec<fuel_tx::Receipt>> {
+    provider: &Provider,
+    command: &cmd::Run,
+    initial_tip: u64,
+    build_tx: F,
+) -> Result<Vec<fuel_tx::Receipt>>
+where
+    F: FnMut(u64) -> Fut,
+    Fut: Future<Output = Result<Transaction>>,
+{
     let client = FuelClient::new(node_url)?;
 
     match client.health().await {
-        Ok(_) => timeout(
-            Duration::from_millis(TX_SUBMIT_TIMEOUT_MS),
-            send_tx(&client, tx, pretty_print, simulate),
-        )
-        .await
-        .with_context(|| format!("timeout waiting for {:?} to be included in a block", tx))?,
+        Ok(_) => send_tx(&client, provider, command, initial_tip, build_tx).await,
         Err(_) => Err(fuel_core_not_running(node_url)),
     }
 }
 
-async fn send_tx(
+async fn send_tx<F, Fut>(
     client: &FuelClient,
-    tx: &Transaction,
-    pretty_print: bool,
-    simulate: bool,
-) -> Result<Vec<fuel_tx::Receipt>> {
+    provider: &Provider,
+    command: &cmd::Run,
+    initial_tip: u64,
+    mut build_tx: F,
+) -> Result<Vec<fuel_tx::Receipt>>
+where
+    F: FnMut(u64) -> Fut,
+    Fut: Future<Output = Result<Transaction>>,
+{
     let outputs = {
-        if !simulate {
-            let status = client.submit_and_await_commit(tx).await?;
+        if !command.simulate {
+            let (_, status) =
+                submit_with_fee_bump(client, provider, &command.fee_bump, initial_tip, build_tx)
+                    .await?;
 
             match status {
                 fuel_core_client::client::types::TransactionStatus::Success {
@@ -220,8 +232,14 @@ async fn send_tx(
                 _ => vec![],
             }
         } else {
+            let tx = build_tx(initial_tip).await?;
             let txs = vec![tx.clone()];
-            let receipts = client.dry_run(txs.as_slice()).await?;
+            let receipts = timeout(
+                Duration::from_millis(TX_SUBMIT_TIMEOUT_MS),
+                client.dry_run(txs.as_slice()),
+            )
+            .await
+            .with_context(|| format!("timeout waiting for {:?} to be executed", tx))??;
             let receipts = receipts
                 .first()
                 .map(|tx| &tx.result)
@@ -233,7 +251,7 @@ async fn send_tx(
         }
     };
     if !outputs.is_empty() {
-        info!("{}", format_log_receipts(&outputs, pretty_print)?);
+        info!("{}", format_log_receipts(&outputs, command.pretty_print)?);
     }
     Ok(outputs)
 }
@@ -256,6 +274,7 @@ fn build_opts_from_cmd(cmd: &cmd::Run) -> pkg::BuildOpts {
             bytecode: cmd.print.bytecode,
             bytecode_spans: false,
             ir: cmd.print.ir(),
+            verify_ir: cmd.print.verify_ir(),
             reverse_order: cmd.print.reverse_order,
         },
         minify: pkg::MinifyOpts {
@@ -275,5 +294,7 @@ fn build_opts_from_cmd(cmd: &cmd::Run) -> pkg::BuildOpts {
         member_filter: pkg::MemberFilter::only_scripts(),
         experimental: cmd.experimental.experimental.clone(),
         no_experimental: cmd.experimental.no_experimental.clone(),
+        message_format: Default::default(),
+        analysis_passes: Default::default(),
     }
 }
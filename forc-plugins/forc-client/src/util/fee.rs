@@ -0,0 +1,218 @@
+use crate::{
+    constants::{GAS_PRICE_ESTIMATION_BLOCK_HORIZON, TX_SUBMIT_TIMEOUT_MS},
+    FeeBump,
+};
+use anyhow::{bail, Result};
+use forc_tracing::println_warning;
+use fuel_core_client::client::{types::TransactionStatus, FuelClient};
+use fuel_tx::{field::Policies as _, policies::PolicyType, Transaction};
+use fuels_accounts::provider::Provider;
+use std::{future::Future, time::Duration};
+use tokio::time::timeout;
+
+/// Returns the max fee set in the policies of the transaction.
+fn max_fee(tx: &Transaction) -> Option<u64> {
+    match tx {
+        Transaction::Script(tx) => tx.policies().get(PolicyType::MaxFee),
+        Transaction::Create(tx) => tx.policies().get(PolicyType::MaxFee),
+        Transaction::Upgrade(tx) => tx.policies().get(PolicyType::MaxFee),
+        Transaction::Upload(tx) => tx.policies().get(PolicyType::MaxFee),
+        Transaction::Blob(tx) => tx.policies().get(PolicyType::MaxFee),
+        Transaction::Mint(_) => None,
+    }
+}
+
+/// Fails if the max fee of the transaction exceeds the `max_fee_cap`.
+pub(crate) fn check_max_fee_cap(tx: &Transaction, max_fee_cap: Option<u64>) -> Result<()> {
+    match (max_fee(tx), max_fee_cap) {
+        (Some(max_fee), Some(max_fee_cap)) if max_fee > max_fee_cap => {
+            bail!("The max fee of the transaction ({max_fee}) exceeds the max fee cap ({max_fee_cap})")
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns the tip of the next submission of a transaction whose current tip is `tip`.
+///
+/// The tip is increased by `percent`, and by at least the estimated `gas_price`.
+pub(crate) fn bumped_tip(tip: u64, percent: u64, gas_price: u64) -> u64 {
+    let increase = (tip.saturating_mul(percent) / 100).max(gas_price).max(1);
+    tip.saturating_add(increase)
+}
+
+/// Submits the transaction built by `build_tx` for the given tip and waits for it to be committed.
+///
+/// If the transaction isn't included in a block in time, it is rebuilt with a tip bumped by at
+/// least the gas price estimated by the node, and re-submitted, up to the number of attempts given
+/// in `fee_bump`. Every transaction is checked against the max fee cap before being submitted.
+///
+/// `build_tx` must sign the same inputs for every tip, so that a re-submitted transaction replaces
+/// the pending one instead of being included along with it.
+///
+/// Returns the committed transaction along with its status.
+pub(crate) async fn submit_with_fee_bump<F, Fut>(
+    client: &FuelClient,
+    provider: &Provider,
+    fee_bump: &FeeBump,
+    initial_tip: u64,
+    build_tx: F,
+) -> Result<(Transaction, TransactionStatus)>
+where
+    F: FnMut(u64) -> Fut,
+    Fut: Future<Output = Result<Transaction>>,
+{
+    resubmit_with_bumped_tip(
+        fee_bump,
+        Duration::from_millis(TX_SUBMIT_TIMEOUT_MS),
+        initial_tip,
+        build_tx,
+        |tx| async move { Ok(client.submit_and_await_commit(&tx).await?) },
+        || async move {
+            Ok(provider
+                .estimate_gas_price(GAS_PRICE_ESTIMATION_BLOCK_HORIZON)
+                .await?
+                .gas_price)
+        },
+    )
+    .await
+}
+
+/// The submission loop of [submit_with_fee_bump], with the node interactions passed as closures.
+async fn resubmit_with_bumped_tip<S, B, BFut, Sub, SubFut, G, GFut>(
+    fee_bump: &FeeBump,
+    submit_timeout: Duration,
+    initial_tip: u64,
+    mut build_tx: B,
+    mut submit_tx: Sub,
+    mut estimate_gas_price: G,
+) -> Result<(Transaction, S)>
+where
+    B: FnMut(u64) -> BFut,
+    BFut: Future<Output = Result<Transaction>>,
+    Sub: FnMut(Transaction) -> SubFut,
+    SubFut: Future<Output = Result<S>>,
+    G: FnMut() -> GFut,
+    GFut: Future<Output = Result<u64>>,
+{
+    let mut tip = initial_tip;
+    let mut attempt = 0;
+    loop {
+        let tx = build_tx(tip).await?;
+        check_max_fee_cap(&tx, fee_bump.max_fee_cap)?;
+
+        match timeout(submit_timeout, submit_tx(tx.clone())).await {
+            Ok(status) => return Ok((tx, status?)),
+            Err(_) if attempt < fee_bump.fee_bump_attempts => {
+                tip = bumped_tip(tip, fee_bump.fee_bump_percent, estimate_gas_price().await?);
+                attempt += 1;
+                println_warning(&format!(
+                    "Transaction was not included in a block within {}s, re-submitting it with a tip of {tip} (attempt {attempt} of {})",
+                    submit_timeout.as_secs(),
+                    fee_bump.fee_bump_attempts
+                ));
+            }
+            Err(_) => bail!(
+                "Transaction was not included in a block within {}s",
+                submit_timeout.as_secs()
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_tx::Script;
+    use std::{cell::RefCell, future::pending};
+
+    /// Returns a transaction whose max fee is the `base_fee` plus the `tip`.
+    fn tx_with_tip(base_fee: u64, tip: u64) -> Transaction {
+        let mut tx = Script::default();
+        tx.policies_mut().set(PolicyType::Tip, Some(tip));
+        tx.policies_mut()
+            .set(PolicyType::MaxFee, Some(base_fee + tip));
+        tx.into()
+    }
+
+    /// Submits the transactions built with `tx_with_tip`, the first `timed_out_submissions` of
+    /// which are never included in a block, and returns the tips of all the submissions.
+    async fn submit(fee_bump: FeeBump, timed_out_submissions: usize) -> (Result<()>, Vec<u64>) {
+        let tips = RefCell::new(vec![]);
+        let result = resubmit_with_bumped_tip(
+            &fee_bump,
+            Duration::from_millis(10),
+            0,
+            |tip| {
+                tips.borrow_mut().push(tip);
+                async move { Ok(tx_with_tip(100, tip)) }
+            },
+            |_| {
+                let submissions = tips.borrow().len();
+                async move {
+                    if submissions <= timed_out_submissions {
+                        pending::<()>().await;
+                    }
+                    Ok(())
+                }
+            },
+            || async { Ok(10) },
+        )
+        .await
+        .map(|_| ());
+        (result, tips.into_inner())
+    }
+
+    #[test]
+    fn test_bumped_tip() {
+        // The tip grows by the percentage when it's above the gas price.
+        assert_eq!(bumped_tip(1_000, 20, 10), 1_200);
+        // The tip grows by at least the gas price.
+        assert_eq!(bumped_tip(0, 20, 10), 10);
+        assert_eq!(bumped_tip(100, 20, 50), 150);
+        // The tip always grows.
+        assert_eq!(bumped_tip(0, 20, 0), 1);
+        assert_eq!(bumped_tip(u64::MAX, 20, 0), u64::MAX);
+    }
+
+    #[tokio::test]
+    async fn test_resubmit_with_bumped_tip() {
+        let fee_bump = FeeBump {
+            max_fee_cap: None,
+            fee_bump_attempts: 3,
+            fee_bump_percent: 50,
+        };
+        let (result, tips) = submit(fee_bump, 2).await;
+        assert!(result.is_ok());
+        assert_eq!(tips, vec![0, 10, 20]);
+    }
+
+    #[tokio::test]
+    async fn test_resubmit_with_bumped_tip_out_of_attempts() {
+        let fee_bump = FeeBump {
+            max_fee_cap: None,
+            fee_bump_attempts: 1,
+            fee_bump_percent: 50,
+        };
+        let (result, tips) = submit(fee_bump, 2).await;
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Transaction was not included in a block within 0s"
+        );
+        assert_eq!(tips, vec![0, 10]);
+    }
+
+    #[tokio::test]
+    async fn test_resubmit_with_bumped_tip_over_max_fee_cap() {
+        let fee_bump = FeeBump {
+            max_fee_cap: Some(125),
+            fee_bump_attempts: 5,
+            fee_bump_percent: 50,
+        };
+        let (result, tips) = submit(fee_bump, 5).await;
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The max fee of the transaction (130) exceeds the max fee cap (125)"
+        );
+        assert_eq!(tips, vec![0, 10, 20, 30]);
+    }
+}
@@ -1,6 +1,7 @@
 pub mod account;
 pub mod aws;
 pub(crate) mod encode;
+pub(crate) mod fee;
 pub(crate) mod node_url;
 pub(crate) mod pkg;
 pub(crate) mod target;
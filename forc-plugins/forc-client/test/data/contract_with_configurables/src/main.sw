@@ -0,0 +1,52 @@
+contract;
+
+struct Config {
+    limit: u64,
+    enabled: bool,
+}
+
+configurable {
+    SMALL: u8 = 1,
+    HASH: b256 = b256::zero(),
+    OWNER: ContractId = ContractId::zero(),
+    NAME: str[4] = __to_str_array("fuel"),
+    CONFIG: Config = Config {
+        limit: 10,
+        enabled: false,
+    },
+}
+
+abi ConfigurablesContract {
+    fn small() -> u8;
+    fn hash() -> b256;
+    fn owner() -> b256;
+    fn name() -> str[4];
+    fn limit() -> u64;
+    fn enabled() -> bool;
+}
+
+impl ConfigurablesContract for Contract {
+    fn small() -> u8 {
+        SMALL
+    }
+
+    fn hash() -> b256 {
+        HASH
+    }
+
+    fn owner() -> b256 {
+        OWNER.bits()
+    }
+
+    fn name() -> str[4] {
+        NAME
+    }
+
+    fn limit() -> u64 {
+        CONFIG.limit
+    }
+
+    fn enabled() -> bool {
+        CONFIG.enabled
+    }
+}
@@ -0,0 +1,35 @@
+contract;
+
+storage {
+    value: u8 = 0,
+    flag: bool = false,
+}
+
+abi InitContract {
+    #[storage(write)]
+    fn initialize(value: u8, flag: bool);
+
+    #[storage(read)]
+    fn value() -> u8;
+
+    #[storage(read)]
+    fn flag() -> bool;
+}
+
+impl InitContract for Contract {
+    #[storage(write)]
+    fn initialize(value: u8, flag: bool) {
+        storage.value.write(value);
+        storage.flag.write(flag);
+    }
+
+    #[storage(read)]
+    fn value() -> u8 {
+        storage.value.read()
+    }
+
+    #[storage(read)]
+    fn flag() -> bool {
+        storage.flag.read()
+    }
+}
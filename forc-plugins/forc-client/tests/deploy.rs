@@ -426,6 +426,134 @@ async fn test_deploy_submit_only() {
     assert_eq!(contract_ids, expected)
 }
 
+#[tokio::test]
+async fn test_deploy_with_init_call() {
+    let (mut node, port) = run_node();
+    let tmp_dir = tempdir().unwrap();
+    let project_dir = test_data_path().join("contract_with_init");
+    copy_dir(&project_dir, tmp_dir.path()).unwrap();
+    patch_manifest_file_with_path_std(tmp_dir.path()).unwrap();
+
+    let pkg = Pkg {
+        path: Some(tmp_dir.path().display().to_string()),
+        ..Default::default()
+    };
+
+    let node_url = format!("http://127.0.0.1:{}/v1/graphql", port);
+    let target = NodeTarget {
+        node_url: Some(node_url.clone()),
+        target: None,
+        testnet: false,
+        mainnet: false,
+    };
+    let cmd = cmd::Deploy {
+        pkg,
+        salt: Some(vec![format!("{}", Salt::default())]),
+        node: target,
+        default_signer: true,
+        init: Some(vec!["initialize(42, true)".to_string()]),
+        ..Default::default()
+    };
+    let deployed_contract = expect_deployed_contract(deploy(cmd).await.unwrap().remove(0));
+
+    let provider = Provider::connect(&node_url).await.unwrap();
+    let secret_key = SecretKey::from_str(forc_client::constants::DEFAULT_PRIVATE_KEY).unwrap();
+    let wallet_unlocked = WalletUnlocked::new_from_private_key(secret_key, Some(provider));
+
+    abigen!(Contract(
+        name = "InitContract",
+        abi = "forc-plugins/forc-client/test/data/contract_with_init/contract_with_init-abi.json"
+    ));
+
+    // The storage was written by the init call made right after the deployment.
+    let init_contract = InitContract::new(deployed_contract.id, wallet_unlocked);
+    let value = init_contract.methods().value().call().await.unwrap().value;
+    assert_eq!(value, 42);
+    let flag = init_contract.methods().flag().call().await.unwrap().value;
+    assert!(flag);
+
+    node.kill().unwrap();
+}
+
+#[tokio::test]
+async fn test_deploy_with_configurables() {
+    let (mut node, port) = run_node();
+    let tmp_dir = tempdir().unwrap();
+    let project_dir = test_data_path().join("contract_with_configurables");
+    copy_dir(&project_dir, tmp_dir.path()).unwrap();
+    patch_manifest_file_with_path_std(tmp_dir.path()).unwrap();
+    let configurables_path = tmp_dir.path().join("configurables.json");
+    fs::write(
+        &configurables_path,
+        r#"{ "CONFIG": { "limit": 42, "enabled": true } }"#,
+    )
+    .unwrap();
+
+    let node_url = format!("http://127.0.0.1:{}/v1/graphql", port);
+    let deploy_cmd = || cmd::Deploy {
+        pkg: Pkg {
+            path: Some(tmp_dir.path().display().to_string()),
+            ..Default::default()
+        },
+        salt: Some(vec![format!("{}", Salt::default())]),
+        node: NodeTarget {
+            node_url: Some(node_url.clone()),
+            target: None,
+            testnet: false,
+            mainnet: false,
+        },
+        default_signer: true,
+        ..Default::default()
+    };
+    let default_contract = expect_deployed_contract(deploy(deploy_cmd()).await.unwrap().remove(0));
+
+    let hash = format!("0x{}", "11".repeat(32));
+    let owner = format!("0x{}", "22".repeat(32));
+    let cmd = cmd::Deploy {
+        set_configurable: Some(vec![
+            "SMALL=7".to_string(),
+            format!("HASH={hash}"),
+            format!("OWNER={owner}"),
+            "NAME=sway".to_string(),
+        ]),
+        override_configurables: Some(configurables_path.display().to_string()),
+        ..deploy_cmd()
+    };
+    let patched_contract = expect_deployed_contract(deploy(cmd).await.unwrap().remove(0));
+    // The id is computed from the patched bytecode, which differs from the default one.
+    assert_ne!(patched_contract.id, default_contract.id);
+
+    let provider = Provider::connect(&node_url).await.unwrap();
+    let secret_key = SecretKey::from_str(forc_client::constants::DEFAULT_PRIVATE_KEY).unwrap();
+    let wallet_unlocked = WalletUnlocked::new_from_private_key(secret_key, Some(provider));
+
+    abigen!(Contract(
+        name = "ConfigurablesContract",
+        abi = "forc-plugins/forc-client/test/data/contract_with_configurables/contract_with_configurables-abi.json"
+    ));
+
+    // The contract deployed with the patched id holds the new values.
+    let contract = ConfigurablesContract::new(patched_contract.id, wallet_unlocked);
+    let methods = contract.methods();
+    assert_eq!(methods.small().call().await.unwrap().value, 7);
+    assert_eq!(
+        methods.hash().call().await.unwrap().value,
+        Bits256::from_hex_str(&hash).unwrap()
+    );
+    assert_eq!(
+        methods.owner().call().await.unwrap().value,
+        Bits256::from_hex_str(&owner).unwrap()
+    );
+    assert_eq!(
+        methods.name().call().await.unwrap().value,
+        SizedAsciiString::<4>::new("sway".to_string()).unwrap()
+    );
+    assert_eq!(methods.limit().call().await.unwrap().value, 42);
+    assert!(methods.enabled().call().await.unwrap().value);
+
+    node.kill().unwrap();
+}
+
 #[tokio::test]
 async fn test_deploy_fresh_proxy() {
     let (mut node, port) = run_node();
@@ -750,6 +878,16 @@ async fn chunked_deploy() {
     node.kill().unwrap();
 
     assert!(deployed_contract.chunked);
+
+    // The blobs loaded by the loader contract are recorded in the deployment artifact.
+    let artifact_path = tmp_dir.path().join(format!(
+        "out/deployments/big_contract-deployment-0x{}.json",
+        deployed_contract.id
+    ));
+    let artifact: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(artifact_path).unwrap()).unwrap();
+    let blob_ids = artifact["blob_ids"].as_array().unwrap();
+    assert!(blob_ids.len() > 1);
 }
 
 #[tokio::test]
@@ -11,6 +11,7 @@ use std::{
 use termion::screen::IntoAlternateScreen;
 
 fn main() {
+    forc_util::completion::handle_completion_metadata_request::<Command>();
     init_tracing_subscriber(Default::default());
     if let Err(err) = run() {
         println_error(&format!("{}", err));
@@ -42,6 +42,7 @@ impl DapServer {
         if let Some(additional_data) = &args.additional_data {
             if let Ok(data) = serde_json::from_value::<AdditionalData>(additional_data.clone()) {
                 self.state.program_path = PathBuf::from(data.program);
+                self.state.test_name = data.test_name;
                 return HandlerResult::ok(ResponseBody::Launch);
             }
         }
@@ -37,8 +37,12 @@ pub const REGISTERS_VARIABLE_REF: i64 = 1;
 pub const INSTRUCTIONS_VARIABLE_REF: i64 = 2;
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AdditionalData {
     pub program: String,
+    /// Only debug the test with this name, rather than all the tests in `program`.
+    #[serde(default)]
+    pub test_name: Option<String>,
 }
 
 /// This struct is a stateful representation of a Debug Adapter Protocol (DAP) server. It holds everything
@@ -243,6 +247,14 @@ impl DapServer {
                 if test_entry.file_path.as_path() != self.state.program_path.as_path() {
                     return None;
                 }
+                if self
+                    .state
+                    .test_name
+                    .as_ref()
+                    .is_some_and(|test_name| *test_name != name)
+                {
+                    return None;
+                }
 
                 TestExecutor::build(
                     &pkg_to_debug.bytecode.bytes,
@@ -12,6 +12,8 @@ use std::path::PathBuf;
 pub struct ServerState {
     // DAP state
     pub program_path: PathBuf,
+    /// The name of the only test to debug, if any.
+    pub test_name: Option<String>,
     pub mode: Option<StartDebuggingRequestKind>,
     pub initialized_event_sent: bool,
     pub started_debugging: bool,
@@ -137,6 +139,10 @@ impl ServerState {
     }
 
     pub(crate) fn test_complete(&mut self, result: TestResult) {
+        let result = match &self.built_package {
+            Some(pkg) => result.with_revert_message(&pkg.program_abi),
+            None => result,
+        };
         self.test_results.push(result);
         self.executors.remove(0);
     }
@@ -9,6 +9,7 @@ forc_util::cli_examples! {
         [ Build the docs for a project located in another path => "forc doc --manifest-path {path}" ]
         [ Build the docs for the current project exporting private types => "forc doc --document-private-items" ]
         [ Build the docs offline without downloading any dependency from the network => "forc doc --offline" ]
+        [ Build the docs and a JSON description of all documented items => "forc doc --json" ]
     }
 }
 
@@ -44,6 +45,10 @@ pub struct Command {
     /// Do not build documentation for dependencies.
     #[clap(long)]
     pub no_deps: bool,
+    /// Also write a JSON description of all documented items to `doc.json`,
+    /// for tools that consume the documentation programmatically.
+    #[clap(long)]
+    pub json: bool,
     /// The IPFS Node to use for fetching IPFS sources.
     ///
     /// Possible values: PUBLIC, LOCAL, <GATEWAY_URL>
@@ -4,6 +4,7 @@ use crate::{
     render::{
         item::{components::*, context::DocImplTrait, documentable_type::DocumentableType},
         link::DocLink,
+        source::SourceLink,
         util::format::docstring::{create_preview, DocStrings},
     },
 };
@@ -16,7 +17,7 @@ use std::{
 use sway_core::{
     decl_engine::DeclEngine,
     language::ty::{TyAstNodeContent, TyDecl, TyImplSelfOrTrait, TyModule, TyProgram, TySubmodule},
-    Engines,
+    Engines, TypeInfo,
 };
 use sway_types::BaseIdent;
 
@@ -28,11 +29,17 @@ pub struct Documentation(pub Vec<Document>);
 
 impl Documentation {
     /// Gather [Documentation] from the [TyProgram].
+    ///
+    /// `blanket_impls` holds the blanket implementations of the dependencies of the program,
+    /// i.e. `impl<T> Trait for T`, which are listed on the pages of all types that satisfy
+    /// their constraints. The blanket implementations of the program are appended to it, so that
+    /// they can be listed for the packages that depend on it.
     pub fn from_ty_program(
         engines: &Engines,
         project_name: &str,
         typed_program: &TyProgram,
         document_private_items: bool,
+        blanket_impls: &mut Vec<(TyImplSelfOrTrait, ModuleInfo)>,
     ) -> Result<Documentation> {
         // the first module prefix will always be the project name
         let mut docs = Documentation::default();
@@ -72,6 +79,17 @@ impl Documentation {
             })
             .collect::<HashMap<BaseIdent, ModuleInfo>>();
 
+        // Blanket implementations are not implementations for a single type, set them aside.
+        let (mut package_blanket_impls, mut impl_traits): (Vec<_>, Vec<_>) = impl_traits
+            .into_iter()
+            .partition(|(impl_trait, _)| is_blanket_impl(engines, impl_trait));
+        for (impl_trait, _) in package_blanket_impls.iter_mut() {
+            impl_trait.trait_name = impl_trait
+                .trait_name
+                .to_fullpath(engines, &typed_program.root.namespace);
+        }
+        blanket_impls.append(&mut package_blanket_impls);
+
         // Add one documentation page for each primitive type that has an implementation.
         for (impl_trait, module_info) in impl_traits.iter() {
             let impl_for_type = engines.te().get(impl_trait.implementing_for.type_id);
@@ -97,7 +115,7 @@ impl Documentation {
                 | DocumentableType::Declared(TyDecl::EnumDecl(_))
                 | DocumentableType::Primitive(_) => {
                     let item_name = doc.item_header.item_name.as_str().to_string();
-                    for (impl_trait, _) in impl_traits.iter_mut() {
+                    for (impl_trait, impl_module_info) in impl_traits.iter_mut() {
                         // Check if this implementation is for this struct/enum.
                         if item_name.as_str() == impl_trait.implementing_for.span.as_str() {
                             let module_info_override = if let Some(decl_module_info) =
@@ -115,6 +133,11 @@ impl Documentation {
                                 impl_for_module: doc.module_info.clone(),
                                 impl_trait: impl_trait.clone(),
                                 module_info_override,
+                                source_link: SourceLink::from_span(
+                                    engines,
+                                    impl_module_info.project_name(),
+                                    &impl_trait.span,
+                                ),
                             };
 
                             if doc_impl_trait.is_inherent() {
@@ -128,6 +151,37 @@ impl Documentation {
                 _ => {}
             }
 
+            // Blanket implementations only apply to declared types, for which the implemented
+            // traits are known.
+            if let DocumentableType::Declared(TyDecl::StructDecl(_) | TyDecl::EnumDecl(_)) =
+                doc.item_body.ty
+            {
+                let blanket_impl_vec = blanket_impls
+                    .iter()
+                    .filter(|(impl_trait, _)| {
+                        blanket_impl_applies(engines, impl_trait, &impl_trait_vec)
+                    })
+                    .map(|(impl_trait, impl_module_info)| {
+                        let module_info_override = trait_decls
+                            .get(&impl_trait.trait_name.suffix)
+                            .map(|decl_module_info| decl_module_info.module_prefixes.clone());
+                        DocImplTrait {
+                            impl_for_module: doc.module_info.clone(),
+                            impl_trait: impl_trait.clone(),
+                            module_info_override,
+                            source_link: SourceLink::from_span(
+                                engines,
+                                impl_module_info.project_name(),
+                                &impl_trait.span,
+                            ),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                if !blanket_impl_vec.is_empty() {
+                    doc.item_body.item_context.blanket_impls = Some(blanket_impl_vec);
+                }
+            }
+
             if !impl_trait_vec.is_empty() {
                 doc.item_body.item_context.impl_traits = Some(impl_trait_vec);
             }
@@ -207,6 +261,37 @@ impl Documentation {
     }
 }
 
+/// Returns true if `impl_trait` implements a trait for a generic type parameter, e.g. `impl<T> Trait for T`.
+fn is_blanket_impl(engines: &Engines, impl_trait: &TyImplSelfOrTrait) -> bool {
+    impl_trait.trait_decl_ref.is_some()
+        && matches!(
+            *engines.te().get(impl_trait.implementing_for.type_id),
+            TypeInfo::UnknownGeneric { .. }
+        )
+}
+
+/// Returns true if a type implementing the traits in `impl_traits` satisfies the constraints
+/// of the type parameter of the blanket implementation `blanket_impl`.
+///
+/// Traits are matched by name, the same way implementations are matched to their types.
+fn blanket_impl_applies(
+    engines: &Engines,
+    blanket_impl: &TyImplSelfOrTrait,
+    impl_traits: &[DocImplTrait],
+) -> bool {
+    let TypeInfo::UnknownGeneric {
+        trait_constraints, ..
+    } = &*engines.te().get(blanket_impl.implementing_for.type_id)
+    else {
+        return false;
+    };
+    trait_constraints.iter().all(|constraint| {
+        impl_traits.iter().any(|impl_trait| {
+            impl_trait.impl_trait.trait_name.suffix == constraint.trait_name.suffix
+        })
+    })
+}
+
 /// A finalized Document ready to be rendered. We want to retain all
 /// information including spans, fields on structs, variants on enums etc.
 #[derive(Clone, Debug)]
@@ -0,0 +1,198 @@
+//! A machine readable description of the documented items, for tools that consume Sway docs programmatically.
+use crate::{
+    doc::{Document, Documentation},
+    render::item::{context::DocImplTrait, documentable_type::DocumentableType},
+};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+use sway_core::{
+    language::ty::{self, TyTraitItem},
+    Engines, TypeParameter,
+};
+use sway_types::{LineCol, Span};
+
+const JSON_DOC_FILE_NAME: &str = "doc.json";
+
+/// The version of the format of the JSON documentation.
+/// Bumped whenever the format changes in a backwards incompatible way.
+pub const JSON_FORMAT_VERSION: u32 = 1;
+
+/// Writes the JSON documentation of all documented packages to `doc.json`.
+pub fn write_json_doc(doc_path: &Path, engines: &Engines, docs: &Documentation) -> Result<()> {
+    let json_doc = JsonDocumentation::new(engines, docs);
+    let contents = serde_json::to_string_pretty(&json_doc)?;
+    Ok(fs::write(doc_path.join(JSON_DOC_FILE_NAME), contents)?)
+}
+
+/// The documentation of all packages, keyed by package name.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JsonDocumentation {
+    pub format_version: u32,
+    pub packages: BTreeMap<String, Vec<JsonItem>>,
+}
+
+/// A documented item.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JsonItem {
+    pub name: String,
+    /// The path of the module the item is declared in, starting with the package name.
+    pub path: Vec<String>,
+    /// The kind of the item, e.g. `struct` or `function`.
+    pub kind: String,
+    /// The formatted declaration of the item.
+    pub signature: String,
+    /// The names of the type parameters of the item.
+    pub generics: Vec<String>,
+    /// The doc comments of the item, as markdown.
+    pub docs: Option<String>,
+    /// The location of the declaration, `None` for primitive types.
+    pub span: Option<JsonSpan>,
+    /// The page of the item, relative to the directory of its module.
+    pub html_filename: String,
+    pub implementations: Vec<JsonImpl>,
+}
+
+/// The location of an item in its source file, with 1-based lines and columns.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JsonSpan {
+    pub path: PathBuf,
+    pub start: LineCol,
+    pub end: LineCol,
+}
+
+/// An implementation of methods or of a trait for a documented type.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JsonImpl {
+    /// The implemented trait, `None` for inherent implementations.
+    pub trait_name: Option<String>,
+    pub for_type: String,
+    pub methods: Vec<String>,
+    /// True for implementations for all types satisfying some constraints, e.g. `impl<T> Trait for T`.
+    pub is_blanket: bool,
+}
+
+impl JsonDocumentation {
+    pub fn new(engines: &Engines, docs: &Documentation) -> Self {
+        let mut packages: BTreeMap<String, Vec<JsonItem>> = BTreeMap::new();
+        for doc in docs.iter() {
+            packages
+                .entry(doc.module_info.project_name().to_string())
+                .or_default()
+                .push(JsonItem::new(engines, doc));
+        }
+        Self {
+            format_version: JSON_FORMAT_VERSION,
+            packages,
+        }
+    }
+}
+
+impl JsonItem {
+    fn new(engines: &Engines, doc: &Document) -> Self {
+        let (type_parameters, span) = match &doc.item_body.ty {
+            DocumentableType::Declared(decl) => declaration_details(engines, decl),
+            DocumentableType::Primitive(_) => (vec![], None),
+        };
+        let item_context = &doc.item_body.item_context;
+        let implementations = [
+            (&item_context.inherent_impls, false),
+            (&item_context.impl_traits, false),
+            (&item_context.blanket_impls, true),
+        ]
+        .into_iter()
+        .flat_map(|(impls, is_blanket)| {
+            impls
+                .iter()
+                .flatten()
+                .map(move |impl_trait| JsonImpl::new(impl_trait, is_blanket))
+        })
+        .collect();
+
+        Self {
+            name: doc.item_header.item_name.as_str().to_string(),
+            path: doc.module_info.module_prefixes.clone(),
+            kind: doc.item_body.ty.friendly_type_name().to_string(),
+            signature: doc.item_body.code_str.clone(),
+            generics: type_parameters
+                .iter()
+                .map(|type_param| type_param.name.as_str().to_string())
+                .collect(),
+            docs: doc.raw_attributes.clone().filter(|docs| !docs.is_empty()),
+            span: span.and_then(|span| JsonSpan::new(engines, &span)),
+            html_filename: doc.html_filename(),
+            implementations,
+        }
+    }
+}
+
+impl JsonSpan {
+    fn new(engines: &Engines, span: &Span) -> Option<Self> {
+        let line_col = span.line_col();
+        Some(Self {
+            path: engines.se().get_path(span.source_id()?),
+            start: line_col.start,
+            end: line_col.end,
+        })
+    }
+}
+
+impl JsonImpl {
+    fn new(doc_impl_trait: &DocImplTrait, is_blanket: bool) -> Self {
+        let impl_trait = &doc_impl_trait.impl_trait;
+        Self {
+            trait_name: (!doc_impl_trait.is_inherent())
+                .then(|| doc_impl_trait.name_with_type_args()),
+            for_type: impl_trait.implementing_for.span.as_str().to_string(),
+            methods: impl_trait
+                .items
+                .iter()
+                .filter_map(|item| match item {
+                    TyTraitItem::Fn(item_fn) => Some(item_fn.name().to_string()),
+                    _ => None,
+                })
+                .collect(),
+            is_blanket,
+        }
+    }
+}
+
+/// Returns the type parameters and span of a documented declaration.
+fn declaration_details(engines: &Engines, decl: &ty::TyDecl) -> (Vec<TypeParameter>, Option<Span>) {
+    let decl_engine = engines.de();
+    match decl {
+        ty::TyDecl::StructDecl(ty::StructDecl { decl_id, .. }) => {
+            let decl = decl_engine.get_struct(decl_id);
+            (decl.type_parameters.clone(), Some(decl.span.clone()))
+        }
+        ty::TyDecl::EnumDecl(ty::EnumDecl { decl_id, .. }) => {
+            let decl = decl_engine.get_enum(decl_id);
+            (decl.type_parameters.clone(), Some(decl.span.clone()))
+        }
+        ty::TyDecl::TraitDecl(ty::TraitDecl { decl_id, .. }) => {
+            let decl = decl_engine.get_trait(decl_id);
+            (decl.type_parameters.clone(), Some(decl.span.clone()))
+        }
+        ty::TyDecl::AbiDecl(ty::AbiDecl { decl_id, .. }) => {
+            let decl = decl_engine.get_abi(decl_id);
+            (vec![], Some(decl.span.clone()))
+        }
+        ty::TyDecl::StorageDecl(ty::StorageDecl { decl_id, .. }) => {
+            let decl = decl_engine.get_storage(decl_id);
+            (vec![], Some(decl.span.clone()))
+        }
+        ty::TyDecl::FunctionDecl(ty::FunctionDecl { decl_id, .. }) => {
+            let decl = decl_engine.get_function(decl_id);
+            (decl.type_parameters.clone(), Some(decl.span.clone()))
+        }
+        ty::TyDecl::ConstantDecl(ty::ConstantDecl { decl_id, .. }) => {
+            let decl = decl_engine.get_constant(decl_id);
+            (vec![], Some(decl.span.clone()))
+        }
+        _ => (vec![], None),
+    }
+}
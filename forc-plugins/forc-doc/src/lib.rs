@@ -1,5 +1,6 @@
 pub mod cli;
 pub mod doc;
+pub mod json;
 pub mod render;
 pub mod search;
 pub mod tests;
@@ -14,12 +15,18 @@ use forc_pkg::{
 };
 use forc_tracing::println_action_green;
 use forc_util::default_output_directory;
-use render::RenderedDocumentation;
+use render::{
+    source::{render_package_sources, RenderedSource},
+    RenderedDocumentation,
+};
 use std::{
     fs,
     path::{Path, PathBuf},
 };
-use sway_core::{language::ty::TyProgram, BuildTarget, Engines};
+use sway_core::{
+    language::ty::{TyImplSelfOrTrait, TyProgram},
+    BuildTarget, Engines,
+};
 
 pub const ASSETS_DIR_NAME: &str = "static.files";
 
@@ -128,12 +135,15 @@ pub fn compile_html(
             manifest: &manifest,
             pkg_manifest,
         };
-        build_docs(program_info, &doc_path, build_instructions)?
+        build_docs(program_info, &doc_path, build_instructions, &mut vec![])?
     } else {
         let order = plan.compilation_order();
         let graph = plan.graph();
         let manifest_map = plan.manifest_map();
         let mut raw_docs = Documentation(Vec::new());
+        // Dependencies are documented first, collect their blanket implementations for the
+        // packages that depend on them.
+        let mut blanket_impls = Vec::new();
 
         for (node, (compile_result, _handler)) in order.iter().zip(compile_results) {
             let id = &graph[*node].id();
@@ -152,14 +162,23 @@ pub fn compile_html(
                     manifest: &manifest_file,
                     pkg_manifest: pkg_manifest_file,
                 };
-                raw_docs
-                    .0
-                    .extend(build_docs(program_info, &doc_path, build_instructions)?.0);
+                raw_docs.0.extend(
+                    build_docs(
+                        program_info,
+                        &doc_path,
+                        build_instructions,
+                        &mut blanket_impls,
+                    )?
+                    .0,
+                );
             }
         }
         raw_docs
     };
     search::write_search_index(&doc_path, &raw_docs)?;
+    if build_instructions.json {
+        json::write_json_doc(&doc_path, &engines, &raw_docs)?;
+    }
 
     Ok((doc_path, pkg_manifest.to_owned()))
 }
@@ -168,6 +187,7 @@ fn build_docs(
     program_info: ProgramInfo,
     doc_path: &Path,
     build_instructions: &Command,
+    blanket_impls: &mut Vec<(TyImplSelfOrTrait, doc::module::ModuleInfo)>,
 ) -> Result<Documentation> {
     let Command {
         document_private_items,
@@ -195,6 +215,7 @@ fn build_docs(
         pkg_manifest.project_name(),
         &ty_program,
         document_private_items,
+        blanket_impls,
     )?;
     let root_attributes =
         (!ty_program.root.attributes.is_empty()).then_some(ty_program.root.attributes);
@@ -214,6 +235,8 @@ fn build_docs(
 
     // write file contents to doc folder
     write_content(rendered_docs, doc_path)?;
+    let rendered_sources = render_package_sources(pkg_manifest.project_name(), manifest.dir())?;
+    write_sources(rendered_sources, doc_path)?;
     println_action_green("Finished", pkg_manifest.project_name());
 
     Ok(raw_docs)
@@ -232,6 +255,17 @@ fn write_content(rendered_docs: RenderedDocumentation, doc_path: &Path) -> Resul
     Ok(())
 }
 
+fn write_sources(rendered_sources: Vec<RenderedSource>, doc_path: &Path) -> Result<()> {
+    for source in rendered_sources {
+        let path = doc_path.join(source.html_path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, source.file_contents.0.as_bytes())?;
+    }
+    Ok(())
+}
+
 const DOC_DIR_NAME: &str = "doc";
 pub fn get_doc_dir(_build_instructions: &Command) -> String {
     DOC_DIR_NAME.into()
@@ -10,6 +10,7 @@ use std::{
 };
 
 pub fn main() -> Result<()> {
+    forc_util::completion::handle_completion_metadata_request::<Command>();
     let build_instructions = Command::parse();
 
     let (doc_path, pkg_manifest) = compile_html(&build_instructions, &get_doc_dir)?;
@@ -1,3 +1,4 @@
 pub const ALL_DOC_FILENAME: &str = "all.html";
 pub const INDEX_FILENAME: &str = "index.html";
 pub const IDENTITY: &str = "#";
+pub const SOURCE_DIR_NAME: &str = "src";
@@ -6,6 +6,7 @@ use crate::{
         item::context::ItemContext,
         search::generate_searchbar,
         sidebar::{Sidebar, SidebarNav},
+        source::SourceLink,
         DocStyle, Renderable,
     },
     RenderPlan, ASSETS_DIR_NAME,
@@ -13,6 +14,7 @@ use crate::{
 use anyhow::Result;
 use horrorshow::{box_html, Raw, RenderBox};
 
+use sway_core::engine_threading::SpannedWithEngines;
 use sway_types::BaseIdent;
 
 use super::documentable_type::DocumentableType;
@@ -114,8 +116,19 @@ impl Renderable for ItemBody {
         let doc_name = ty.doc_name().to_string();
         let block_title = ty.as_block_title();
         let sidebar = sidebar.render(render_plan.clone())?;
+        let source_href = match &ty {
+            DocumentableType::Declared(decl) => SourceLink::from_span(
+                render_plan.engines,
+                module_info.project_name(),
+                &decl.span(render_plan.engines),
+            )
+            .map(|link| link.href(&module_info)),
+            DocumentableType::Primitive(_) => None,
+        };
         let item_context = (item_context.context_opt.is_some()
-            || item_context.impl_traits.is_some())
+            || item_context.inherent_impls.is_some()
+            || item_context.impl_traits.is_some()
+            || item_context.blanket_impls.is_some())
         .then(|| -> Result<Box<dyn RenderBox>> { item_context.render(render_plan.clone()) });
         let sway_hjs =
             module_info.to_html_shorthand_path_string(&format!("{ASSETS_DIR_NAME}/highlight.js"));
@@ -141,6 +154,13 @@ impl Renderable for ItemBody {
                                         }
                                     }
                                 }
+                                @ if let Some(source_href) = source_href {
+                                    span(class="out-of-band") {
+                                        a(class="srclink", href=source_href) {
+                                            : "source";
+                                        }
+                                    }
+                                }
                             }
                             div(class="docblock item-decl") {
                                 pre(class=format!("sway {}", &doc_name)) {
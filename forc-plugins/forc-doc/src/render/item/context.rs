@@ -5,6 +5,7 @@ use crate::{
         constant::IDENTITY,
         item::type_anchor::render_type_anchor,
         link::{DocLink, DocLinks},
+        source::SourceLink,
         title::BlockTitle,
         title::DocBlock,
         util::format::docstring::DocStrings,
@@ -270,6 +271,8 @@ pub struct DocImplTrait {
     pub impl_for_module: ModuleInfo,
     pub impl_trait: TyImplSelfOrTrait,
     pub module_info_override: Option<Vec<String>>,
+    /// The location of the implementation in the rendered sources.
+    pub source_link: Option<SourceLink>,
 }
 
 impl DocImplTrait {
@@ -311,6 +314,8 @@ pub struct ItemContext {
     pub inherent_impls: Option<Vec<DocImplTrait>>,
     /// The traits implemented for this type.
     pub impl_traits: Option<Vec<DocImplTrait>>,
+    /// The blanket implementations whose constraints this type satisfies.
+    pub blanket_impls: Option<Vec<DocImplTrait>>,
 }
 
 impl ItemContext {
@@ -412,6 +417,23 @@ impl ItemContext {
             links.insert(BlockTitle::ImplTraits, doc_links);
         }
 
+        if let Some(blanket_impls) = &self.blanket_impls {
+            let doc_links = blanket_impls
+                .iter()
+                .map(|blanket_impl| DocLink {
+                    name: blanket_impl.name_with_type_args(),
+                    module_info: blanket_impl.impl_for_module.clone(),
+                    html_filename: format!(
+                        "{}impl-{}",
+                        IDENTITY,
+                        blanket_impl.name_with_type_args()
+                    ),
+                    preview_opt: None,
+                })
+                .collect();
+            links.insert(BlockTitle::BlanketImpls, doc_links);
+        }
+
         DocLinks {
             style: DocStyle::Item {
                 title: None,
@@ -453,6 +475,17 @@ impl Renderable for ItemContext {
             None => vec![],
         };
 
+        let blanket_impls = match self.blanket_impls {
+            Some(blanket_impls) => {
+                let mut blanket_impl_vec: Vec<_> = Vec::with_capacity(blanket_impls.len());
+                for blanket_impl in blanket_impls {
+                    blanket_impl_vec.push(blanket_impl.render(render_plan.clone())?);
+                }
+                blanket_impl_vec
+            }
+            None => vec![],
+        };
+
         let inherent_impls = match self.inherent_impls {
             Some(inherent_impls) => {
                 let mut inherent_impl_vec: Vec<_> = Vec::with_capacity(inherent_impls.len());
@@ -490,6 +523,17 @@ impl Renderable for ItemContext {
                     }
                 }
             }
+            @ if !blanket_impls.is_empty() {
+                h2(id="blanket-implementations", class="small-section-header") {
+                    : "Blanket Implementations";
+                    a(href=format!("{IDENTITY}blanket-implementations"), class="anchor");
+                }
+                div(id="blanket-implementations-list") {
+                    @ for blanket_impl in blanket_impls {
+                        : blanket_impl;
+                    }
+                }
+            }
         })
     }
 }
@@ -512,6 +556,12 @@ impl Renderable for DocImplTrait {
         } else {
             false
         };
+        // The sources of dependencies are not rendered with `--no-deps`.
+        let source_href = self
+            .source_link
+            .as_ref()
+            .filter(|link| !no_deps || link.project_name == impl_for_module.project_name())
+            .map(|link| link.href(impl_for_module));
 
         let trait_link = if let Some(module_prefixes) = &self.module_info_override {
             ModuleInfo::from_vec_str(module_prefixes).file_path_from_location(
@@ -534,6 +584,11 @@ impl Renderable for DocImplTrait {
 
         let impl_for = box_html! {
                 div(id=format!("impl-{}", name_with_type_args), class="impl has-srclink") {
+                @ if let Some(source_href) = source_href {
+                    a(class="srclink rightside", href=source_href) {
+                        : "source";
+                    }
+                }
                 a(href=format!("{IDENTITY}impl-{}", name_with_type_args), class="anchor");
                 h3(class="code-header in-band") {
                     : "impl ";
@@ -28,6 +28,7 @@ pub mod item;
 pub mod link;
 mod search;
 mod sidebar;
+pub mod source;
 mod title;
 pub mod util;
 
@@ -0,0 +1,154 @@
+//! Renders the Sway source files of a package to HTML, so that documented items can link to their definitions.
+use crate::{
+    doc::module::ModuleInfo,
+    render::{constant::SOURCE_DIR_NAME, HTMLString},
+    ASSETS_DIR_NAME,
+};
+use anyhow::Result;
+use horrorshow::{box_html, RenderBox};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use sway_core::Engines;
+use sway_types::Span;
+use sway_utils::constants::SRC_DIR;
+
+/// The location of a span within the rendered source files of its package.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceLink {
+    /// The name of the package the source file belongs to.
+    pub project_name: String,
+    /// The path of the source file, relative to the package directory.
+    pub file: PathBuf,
+    /// The line the span starts at, starting from 1.
+    pub line: usize,
+}
+
+impl SourceLink {
+    /// Creates a [SourceLink] to the start of `span`, if it lies within a package named `project_name`.
+    pub(crate) fn from_span(engines: &Engines, project_name: &str, span: &Span) -> Option<Self> {
+        let source_id = span.source_id()?;
+        if engines.se().is_source_id_autogenerated(source_id) {
+            return None;
+        }
+        let path = engines.se().get_path(source_id);
+        let package_dir = sway_utils::find_parent_manifest_dir(&path)?;
+        let file = path.strip_prefix(&package_dir).ok()?.to_path_buf();
+        Some(Self {
+            project_name: project_name.to_owned(),
+            file,
+            line: span.line_col().start.line,
+        })
+    }
+
+    /// The link to the line of the span, relative to a page of the module `module_info`.
+    pub(crate) fn href(&self, module_info: &ModuleInfo) -> String {
+        let html_path = source_html_path(&self.project_name, &self.file);
+        module_info.to_html_shorthand_path_string(&format!("{html_path}#L{}", self.line))
+    }
+}
+
+/// A source file rendered to HTML.
+#[derive(Debug)]
+pub struct RenderedSource {
+    /// The path of the HTML file, relative to the documentation root.
+    pub html_path: PathBuf,
+    pub file_contents: HTMLString,
+}
+
+/// Renders all Sway files in the `src` directory of the package at `package_dir`.
+pub(crate) fn render_package_sources(
+    project_name: &str,
+    package_dir: &Path,
+) -> Result<Vec<RenderedSource>> {
+    let mut files = sway_utils::get_sway_files(package_dir.join(SRC_DIR));
+    files.sort();
+    let mut rendered_sources = Vec::with_capacity(files.len());
+    for path in files {
+        let file = path.strip_prefix(package_dir)?;
+        let source = fs::read_to_string(&path)?;
+        rendered_sources.push(RenderedSource {
+            html_path: PathBuf::from(source_html_path(project_name, file)),
+            file_contents: HTMLString::from_rendered_content(render_source(file, source)?)?,
+        });
+    }
+    Ok(rendered_sources)
+}
+
+/// The path of the rendered source `file` of a package, relative to the documentation root.
+///
+/// Example: `src/project_name/src/module.sw.html`
+fn source_html_path(project_name: &str, file: &Path) -> String {
+    let file = file
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+    format!("{SOURCE_DIR_NAME}/{project_name}/{file}.html")
+}
+
+fn render_source(file: &Path, source: String) -> Result<Box<dyn RenderBox>> {
+    // The page lives in `src/<project_name>/<file>.html`.
+    let path_to_root = "../".repeat(file.components().count() + 1);
+    let asset = |name: &str| format!("{path_to_root}{ASSETS_DIR_NAME}/{name}");
+    let page_title = format!("{} - source", file.display());
+    let line_count = source.lines().count().max(1);
+
+    Ok(box_html! {
+        head {
+            meta(charset="utf-8");
+            meta(name="viewport", content="width=device-width, initial-scale=1.0");
+            meta(name="generator", content="swaydoc");
+            link(rel="icon", href=asset("sway-logo.svg"));
+            title: page_title;
+            link(rel="stylesheet", type="text/css", href=asset("normalize.css"));
+            link(rel="stylesheet", type="text/css", href=asset("swaydoc.css"), id="mainThemeStyle");
+            link(rel="stylesheet", type="text/css", href=asset("ayu.css"));
+            link(rel="stylesheet", href=asset("ayu.min.css"));
+        }
+        body(class="swaydoc source") {
+            main {
+                div(class="width-limiter") {
+                    section(id="main-content", class="content") {
+                        div(class="example-wrap") {
+                            pre(class="line-numbers") {
+                                @ for line in 1..=line_count {
+                                    span(id=format!("L{line}")) { : line }
+                                    : "\n";
+                                }
+                            }
+                            pre(class="sway") {
+                                code { : source }
+                            }
+                        }
+                    }
+                }
+            }
+            script(src=asset("highlight.js"));
+            script {
+                : "hljs.highlightAll();";
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_link_href() {
+        let link = SourceLink {
+            project_name: "project_name".to_owned(),
+            file: PathBuf::from("src").join("module.sw"),
+            line: 12,
+        };
+        let module_info =
+            ModuleInfo::from_ty_module(vec!["project_name".to_owned(), "module".to_owned()], None);
+        assert_eq!(
+            link.href(&module_info),
+            "../../src/project_name/src/module.sw.html#L12"
+        );
+    }
+}
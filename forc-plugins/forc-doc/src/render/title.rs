@@ -23,6 +23,7 @@ pub enum BlockTitle {
     RequiredMethods,
     ImplMethods,
     ImplTraits,
+    BlanketImpls,
     Primitives,
 }
 
@@ -42,6 +43,7 @@ impl BlockTitle {
             Self::RequiredMethods => "Required Methods",
             Self::ImplMethods => "Methods",
             Self::ImplTraits => "Trait Implementations",
+            Self::BlanketImpls => "Blanket Implementations",
             Self::Primitives => "Primitives",
         }
     }
@@ -60,6 +62,7 @@ impl BlockTitle {
             Self::RequiredMethods => "Required Methods",
             Self::ImplMethods => "Methods",
             Self::ImplTraits => "Trait Implementations",
+            Self::BlanketImpls => "Blanket Implementations",
             Self::Primitives => "Primitive",
         }
     }
@@ -9,7 +9,9 @@ use forc_pkg::{
 use forc_tracing::{init_tracing_subscriber, println_error, println_green, println_red};
 use forc_util::fs_locking::PidFileLocking;
 use prettydiff::{basic::DiffOp, diff_lines};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use std::{
+    collections::{BTreeSet, HashMap},
     default::Default,
     fs,
     path::{Path, PathBuf},
@@ -43,7 +45,8 @@ pub struct App {
     /// Run in 'check' mode.
     ///
     /// - Exits with `0` if input is formatted correctly.
-    /// - Exits with `1` and prints a diff if formatting is required.
+    /// - Exits with `1` and prints a diff if formatting is required, followed by the list of
+    ///   files that need formatting.
     #[clap(short, long)]
     pub check: bool,
     /// Path to the project, if not specified, current working directory will be used.
@@ -57,6 +60,7 @@ pub struct App {
 }
 
 fn main() {
+    forc_util::completion::handle_completion_metadata_request::<App>();
     init_tracing_subscriber(Default::default());
     if let Err(err) = run() {
         println_error("Formatting skipped due to error.");
@@ -73,7 +77,6 @@ fn run() -> Result<()> {
         None => std::env::current_dir()?,
     };
 
-    let mut formatter = Formatter::from_dir(&dir)?;
     if let Some(f) = app.file.as_ref() {
         let file_path = &PathBuf::from(f);
 
@@ -83,8 +86,10 @@ fn run() -> Result<()> {
             .map(|path| path.join(constants::MANIFEST_FILE_NAME));
 
         if is_sway_file(file_path) {
-            format_file(&app, file_path.to_path_buf(), manifest_file, &mut formatter)?;
-            return Ok(());
+            let mut formatter = Formatter::from_dir(&dir)?;
+            let formatted_file =
+                format_file(&app, file_path.to_path_buf(), manifest_file, &mut formatter);
+            return report(&app, vec![formatted_file], false);
         }
 
         bail!(
@@ -99,7 +104,7 @@ fn run() -> Result<()> {
             format_workspace_at_dir(&app, &ws, &dir)?;
         }
         ManifestFile::Package(_) => {
-            format_pkg_at_dir(&app, &dir, &mut formatter)?;
+            format_pkg_at_dir(&app, &dir)?;
         }
     }
     Ok(())
@@ -137,17 +142,22 @@ fn get_sway_dirs(workspace_dir: PathBuf) -> Vec<PathBuf> {
     dirs_to_format
 }
 
+/// A formatted Sway file.
+struct FormattedFile {
+    path: PathBuf,
+    /// The original and the formatted content of the file, if they differ.
+    change: Option<(String, String)>,
+}
+
 /// Format a file, given its path.
-/// Returns:
-/// - Ok(true) if executed successfully and formatted,
-/// - Ok(false) if executed successfully and not formatted,
-/// - Err if it fails to execute at all.
+///
+/// Unless in check mode, the formatted content is written back to the file.
 fn format_file(
     app: &App,
     file: PathBuf,
     manifest_file: Option<PathBuf>,
     formatter: &mut Formatter,
-) -> Result<bool> {
+) -> Result<FormattedFile> {
     let file = file.canonicalize()?;
     if is_file_dirty(&file) {
         bail!(
@@ -158,7 +168,6 @@ fn format_file(
         );
     }
     if let Ok(file_content) = fs::read_to_string(&file) {
-        let mut edited = false;
         let file_content: Arc<str> = Arc::from(file_content);
         let build_config = manifest_file.map(|f| {
             BuildConfig::root_from_file_name_and_manifest_path(
@@ -169,17 +178,13 @@ fn format_file(
         });
         match Formatter::format(formatter, file_content.clone(), build_config.as_ref()) {
             Ok(formatted_content) => {
-                if app.check {
-                    if *file_content != formatted_content {
-                        info!("File was edited by formatter: \n{:?}\n", file);
-                        display_file_diff(&file_content, &formatted_content)?;
-                        edited = true;
-                    }
-                } else {
+                if !app.check && *file_content != formatted_content {
                     write_file_formatted(&file, &formatted_content)?;
                 }
+                let change = (*file_content != formatted_content)
+                    .then(|| (file_content.to_string(), formatted_content));
 
-                return Ok(edited);
+                return Ok(FormattedFile { path: file, change });
             }
             Err(err) => {
                 // TODO: Support formatting for incomplete/invalid sway code.
@@ -197,28 +202,113 @@ fn format_file(
     bail!("Could not read file: {:?}", file)
 }
 
-/// Format the workspace at the given directory.
-fn format_workspace_at_dir(app: &App, workspace: &WorkspaceManifestFile, dir: &Path) -> Result<()> {
-    let mut contains_edits = false;
-    let mut formatter = Formatter::from_dir(dir)?;
-    let mut members = vec![];
+/// Format the given files in parallel.
+///
+/// Every file is formatted with the config of its package, i.e., the nearest `swayfmt.toml`
+/// found by going up from the directory of the package's manifest.
+fn format_files(app: &App, files: BTreeSet<PathBuf>) -> Result<Vec<Result<FormattedFile>>> {
+    let mut configs = HashMap::<PathBuf, swayfmt::config::manifest::Config>::new();
+    let mut jobs = vec![];
+    for file in files {
+        let manifest_dir = find_parent_manifest_dir(&file);
+        let config = match &manifest_dir {
+            Some(manifest_dir) => match configs.get(manifest_dir) {
+                Some(config) => config.clone(),
+                None => {
+                    let config = Formatter::from_dir(manifest_dir)?.config;
+                    configs.insert(manifest_dir.clone(), config.clone());
+                    config
+                }
+            },
+            None => Formatter::default().config,
+        };
+        let manifest_file = manifest_dir.map(|dir| dir.join(constants::MANIFEST_FILE_NAME));
+        jobs.push((file, manifest_file, config));
+    }
+
+    Ok(jobs
+        .into_par_iter()
+        .map(|(file, manifest_file, config)| {
+            let mut formatter = Formatter {
+                config,
+                ..Default::default()
+            };
+            format_file(app, file, manifest_file, &mut formatter)
+        })
+        .collect())
+}
 
-    for member_path in workspace.member_paths()? {
-        members.push(member_path)
+/// Prints the outcome of formatting the `formatted_files`, and fails if any of them could not be
+/// formatted or, in check mode, contains formatting violations.
+fn report(
+    app: &App,
+    formatted_files: Vec<Result<FormattedFile>>,
+    manifests_edited: bool,
+) -> Result<()> {
+    let mut unformatted = vec![];
+    let mut failed = 0;
+    for formatted_file in &formatted_files {
+        match formatted_file {
+            Ok(FormattedFile {
+                path,
+                change: Some((file_content, formatted_content)),
+            }) => {
+                if app.check {
+                    info!("File was edited by formatter: \n{:?}\n", path);
+                    display_file_diff(file_content, formatted_content)?;
+                }
+                unformatted.push(path);
+            }
+            Ok(FormattedFile { path, change: None }) => {
+                debug!("File formatted correctly: {}", path.display());
+            }
+            Err(err) => {
+                failed += 1;
+                println_error(&format!("{err}"));
+            }
+        }
     }
 
-    // Format files at the root - we do not want to start calling format_pkg_at_dir() here,
-    // since this would mean we format twice on each subdirectory.
+    let checked = formatted_files.len() - failed;
+    if app.check {
+        if !unformatted.is_empty() {
+            println_error(&format!(
+                "{} of {checked} file(s) need formatting:",
+                unformatted.len()
+            ));
+            for path in &unformatted {
+                println_error(&format!("  {}", path.display()));
+            }
+        }
+    } else {
+        info!("Formatted {} of {checked} file(s).", unformatted.len());
+    }
+
+    if failed > 0 {
+        bail!(
+            "Failed to format {failed} file(s). \
+            Fix the errors above and run `forc fmt` again."
+        );
+    }
+    if app.check && (!unformatted.is_empty() || manifests_edited) {
+        // One or more files are not formatted, exit with error
+        bail!("Files contain formatting violations. Run `forc fmt` to format them.");
+    }
+
+    Ok(())
+}
+
+/// Format the workspace at the given directory.
+fn format_workspace_at_dir(app: &App, workspace: &WorkspaceManifestFile, dir: &Path) -> Result<()> {
+    let mut files = BTreeSet::new();
+
+    // Format files at the root - we do not want to collect all the files of the workspace
+    // directory here, since the files of the subdirectories are collected per package below.
     if let Ok(read_dir) = fs::read_dir(dir) {
         for entry in read_dir.filter_map(|res| res.ok()) {
             let path = entry.path();
             if is_sway_file(&path) {
-                format_file(
-                    app,
-                    path,
-                    Some(workspace.dir().to_path_buf()),
-                    &mut formatter,
-                )?;
+                files.insert(path);
             }
         }
     }
@@ -226,30 +316,19 @@ fn format_workspace_at_dir(app: &App, workspace: &WorkspaceManifestFile, dir: &P
     // Format subdirectories. We do not call format on members directly here, since
     // in workspaces, it is perfectly valid to have subdirectories containing Sway files,
     // yet not be a member of the workspace.
-    for sub_dir in get_sway_dirs(dir.to_path_buf()) {
-        if sub_dir.join(constants::MANIFEST_FILE_NAME).exists() {
-            // Here, we cannot simply call Formatter::from_dir() and rely on defaults
-            // if there is no swayfmt.toml in the sub directory because we still want
-            // to use the swayfmt.toml at the workspace root (if any).
-            // In order of priority: member > workspace > default.
-            formatter = Formatter::from_dir(&sub_dir)?;
-        }
-        format_pkg_at_dir(app, &sub_dir, &mut formatter)?;
+    let mut manifests_edited = false;
+    for sub_dir in get_sway_dirs(workspace.dir().to_path_buf()) {
+        files.extend(get_sway_files(sub_dir.clone()));
+        // format manifest using taplo formatter
+        manifests_edited |= format_manifest(app, sub_dir.join(constants::MANIFEST_FILE_NAME))?;
     }
 
-    let manifest_file = dir.join(constants::MANIFEST_FILE_NAME);
-
     // Finally, format the root manifest using taplo formatter
-    contains_edits |= format_manifest(app, manifest_file)?;
-
-    if app.check && contains_edits {
-        // One or more files are not formatted, exit with error
-        bail!("Files contain formatting violations.");
-    }
+    manifests_edited |= format_manifest(app, dir.join(constants::MANIFEST_FILE_NAME))?;
 
-    Ok(())
+    let formatted_files = format_files(app, files)?;
+    report(app, formatted_files, manifests_edited)
 }
-
 /// Format the given manifest at a path.
 /// Returns:
 /// - Ok(true) if executed successfully and formatted,
@@ -286,26 +365,15 @@ fn format_manifest(app: &App, manifest_file: PathBuf) -> Result<bool> {
 }
 
 /// Format the package at the given directory.
-fn format_pkg_at_dir(app: &App, dir: &Path, formatter: &mut Formatter) -> Result<()> {
+fn format_pkg_at_dir(app: &App, dir: &Path) -> Result<()> {
     match find_parent_manifest_dir(dir) {
         Some(path) => {
-            let manifest_path = path.clone();
-            let manifest_file = manifest_path.join(constants::MANIFEST_FILE_NAME);
-            let files = get_sway_files(path);
-            let mut contains_edits = false;
-
-            for file in files {
-                contains_edits |= format_file(app, file, Some(manifest_file.clone()), formatter)?;
-            }
+            let manifest_file = path.join(constants::MANIFEST_FILE_NAME);
+            let files = get_sway_files(path).into_iter().collect();
+            let formatted_files = format_files(app, files)?;
             // format manifest using taplo formatter
-            contains_edits |= format_manifest(app, manifest_file)?;
-
-            if app.check && contains_edits {
-                // One or more files are not formatted, exit with error
-                bail!("Files contain formatting violations.");
-            }
-
-            Ok(())
+            let manifest_edited = format_manifest(app, manifest_file)?;
+            report(app, formatted_files, manifest_edited)
         }
         _ => bail!("Manifest file does not exist"),
     }
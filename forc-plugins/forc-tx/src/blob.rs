@@ -0,0 +1,214 @@
+//! Deploying contracts larger than the maximum contract size.
+//!
+//! The bytecode of the contract is uploaded in blobs with `Blob` transactions, and a loader
+//! contract is deployed in its place. When called, the loader copies the blobs onto the stack in
+//! order and jumps to the beginning of the loaded code, which then runs as if it was the deployed
+//! contract.
+
+use fuel_asm::{op, Imm18, Instruction, RegId};
+use fuel_tx::{
+    BlobBody, BlobId, Contract, ContractId, Create, Salt, StorageSlot, TransactionBuilder,
+};
+use thiserror::Error;
+
+/// The size of a word in bytes. Blobs are loaded a word at a time, so all the blobs but the last
+/// one must be a whole number of words long.
+const WORD_SIZE: usize = 8;
+
+/// The size of a blob ID in bytes.
+const BLOB_ID_SIZE: u16 = 32;
+
+/// Errors that can occur while splitting a contract into blobs.
+#[derive(Debug, Error)]
+pub enum SplitContractError {
+    #[error("the contract bytecode is empty")]
+    EmptyBytecode,
+    #[error("the maximum blob size ({max_blob_size} bytes) must be at least one word")]
+    BlobSizeTooSmall { max_blob_size: usize },
+    #[error(
+        "the contract bytecode needs {num_of_blobs} blobs, but the loader supports at most {}",
+        Imm18::MAX.to_u32()
+    )]
+    TooManyBlobs { num_of_blobs: usize },
+}
+
+/// The bytecode of a contract split into blobs, to be deployed behind a loader contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkedContract {
+    /// The blobs, in the order in which they are loaded.
+    pub blobs: Vec<Vec<u8>>,
+}
+
+impl ChunkedContract {
+    /// Splits `bytecode` into blobs of at most `max_blob_size` bytes, rounded down to whole words.
+    pub fn new(bytecode: &[u8], max_blob_size: usize) -> Result<Self, SplitContractError> {
+        if bytecode.is_empty() {
+            return Err(SplitContractError::EmptyBytecode);
+        }
+        let blob_size = max_blob_size - max_blob_size % WORD_SIZE;
+        if blob_size == 0 {
+            return Err(SplitContractError::BlobSizeTooSmall { max_blob_size });
+        }
+        let blobs: Vec<_> = bytecode.chunks(blob_size).map(<[u8]>::to_vec).collect();
+        if blobs.len() > Imm18::MAX.to_u32() as usize {
+            return Err(SplitContractError::TooManyBlobs {
+                num_of_blobs: blobs.len(),
+            });
+        }
+        Ok(Self { blobs })
+    }
+
+    /// The IDs of the blobs, in the order in which they are loaded.
+    pub fn blob_ids(&self) -> Vec<BlobId> {
+        self.blobs
+            .iter()
+            .map(|blob| BlobId::compute(blob))
+            .collect()
+    }
+
+    /// Builders of the `Blob` transactions uploading the blobs, with the blob as the only witness.
+    ///
+    /// Blobs which are already uploaded don't need to be uploaded again, and uploading them fails.
+    pub fn blob_tx_builders(&self) -> Vec<TransactionBuilder<fuel_tx::Blob>> {
+        self.blobs
+            .iter()
+            .map(|blob| {
+                let body = BlobBody {
+                    id: BlobId::compute(blob),
+                    witness_index: 0,
+                };
+                let mut builder = TransactionBuilder::blob(body);
+                builder.add_witness(blob.clone().into());
+                builder
+            })
+            .collect()
+    }
+
+    /// The bytecode of the loader contract, which loads the blobs and jumps into them.
+    pub fn loader_bytecode(&self) -> Vec<u8> {
+        loader_bytecode(&self.blob_ids())
+    }
+
+    /// The ID of the loader contract, when deployed with `salt` and `storage_slots`.
+    pub fn loader_contract_id(&self, salt: &Salt, storage_slots: &[StorageSlot]) -> ContractId {
+        let contract = Contract::from(self.loader_bytecode());
+        let state_root = Contract::initial_state_root(storage_slots.iter());
+        contract.id(salt, &contract.root(), &state_root)
+    }
+
+    /// The builder of the `Create` transaction deploying the loader contract, with the loader
+    /// bytecode as the only witness.
+    ///
+    /// The storage slots of the contract are the storage slots of the loader, as the loaded code
+    /// runs in the context of the loader contract.
+    pub fn loader_create_tx_builder(
+        &self,
+        salt: Salt,
+        mut storage_slots: Vec<StorageSlot>,
+    ) -> TransactionBuilder<Create> {
+        storage_slots.sort();
+        let contract_id = self.loader_contract_id(&salt, &storage_slots);
+        let state_root = Contract::initial_state_root(storage_slots.iter());
+        let mut builder =
+            TransactionBuilder::create(self.loader_bytecode().into(), salt, storage_slots);
+        builder.add_output(fuel_tx::Output::contract_created(contract_id, state_root));
+        builder
+    }
+}
+
+/// The bytecode of a loader contract loading the blobs with `blob_ids`.
+///
+/// The blob IDs follow the instructions of the loader.
+fn loader_bytecode(blob_ids: &[BlobId]) -> Vec<u8> {
+    const REG_ADDRESS_OF_BLOB_ID: u8 = 0x10;
+    const REG_BLOB_SIZE: u8 = 0x11;
+    const REG_REMAINING_BLOBS: u8 = 0x12;
+    const REG_START_OF_LOADED_CODE: u8 = 0x13;
+
+    let get_instructions = |num_of_instructions: u16, num_of_blobs: u32| {
+        [
+            // The blob IDs are located right after the instructions.
+            op::move_(REG_ADDRESS_OF_BLOB_ID, RegId::PC),
+            op::addi(
+                REG_ADDRESS_OF_BLOB_ID,
+                REG_ADDRESS_OF_BLOB_ID,
+                num_of_instructions * Instruction::SIZE as u16,
+            ),
+            // The blobs are loaded from the current stack pointer onwards.
+            op::move_(REG_START_OF_LOADED_CODE, RegId::SP),
+            op::movi(REG_REMAINING_BLOBS, num_of_blobs),
+            // Load the current blob onto the stack.
+            op::bsiz(REG_BLOB_SIZE, REG_ADDRESS_OF_BLOB_ID),
+            op::ldc(REG_ADDRESS_OF_BLOB_ID, 0, REG_BLOB_SIZE, 1),
+            // Move on to the next blob, and loop back to loading it if there is one.
+            op::addi(REG_ADDRESS_OF_BLOB_ID, REG_ADDRESS_OF_BLOB_ID, BLOB_ID_SIZE),
+            op::subi(REG_REMAINING_BLOBS, REG_REMAINING_BLOBS, 1),
+            op::jnzb(REG_REMAINING_BLOBS, RegId::ZERO, 3),
+            // Jump to the beginning of the loaded code. `jmp` adds `$is` to the target and
+            // multiplies it by the instruction size, so both are undone first.
+            op::sub(
+                REG_START_OF_LOADED_CODE,
+                REG_START_OF_LOADED_CODE,
+                RegId::IS,
+            ),
+            op::divi(
+                REG_START_OF_LOADED_CODE,
+                REG_START_OF_LOADED_CODE,
+                Instruction::SIZE as u16,
+            ),
+            op::jmp(REG_START_OF_LOADED_CODE),
+        ]
+    };
+
+    let num_of_instructions = get_instructions(0, 0).len() as u16;
+    let num_of_blobs = blob_ids.len() as u32;
+    get_instructions(num_of_instructions, num_of_blobs)
+        .into_iter()
+        .flat_map(|instruction| instruction.to_bytes())
+        .chain(blob_ids.iter().flat_map(|blob_id| blob_id.iter().copied()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_into_whole_words() {
+        let bytecode: Vec<u8> = (0..20).collect();
+        let contract = ChunkedContract::new(&bytecode, 12).unwrap();
+        assert_eq!(
+            contract.blobs,
+            vec![
+                (0..8).collect::<Vec<u8>>(),
+                (8..16).collect(),
+                (16..20).collect(),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_errors() {
+        assert!(matches!(
+            ChunkedContract::new(&[], 8),
+            Err(SplitContractError::EmptyBytecode)
+        ));
+        assert!(matches!(
+            ChunkedContract::new(&[0; 8], 7),
+            Err(SplitContractError::BlobSizeTooSmall { max_blob_size: 7 })
+        ));
+    }
+
+    #[test]
+    fn loader_ends_with_blob_ids() {
+        let contract = ChunkedContract::new(&[1; 24], 16).unwrap();
+        let loader = contract.loader_bytecode();
+        let blob_ids: Vec<u8> = contract
+            .blob_ids()
+            .iter()
+            .flat_map(|blob_id| blob_id.iter().copied())
+            .collect();
+        assert_eq!(loader.len() % Instruction::SIZE, 0);
+        assert!(loader.ends_with(&blob_ids));
+    }
+}
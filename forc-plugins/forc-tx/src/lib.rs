@@ -12,6 +12,8 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use thiserror::Error;
 
+pub mod blob;
+
 forc_util::cli_examples! {
     {
         // This parser has a custom parser
@@ -115,6 +117,25 @@ forc_util::cli_examples! {
             --contract-id 0xCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCC \
             --state-root 0x0000000000000000000000000000000000000000000000000000000000000000"#
     ]
+    [ An example constructing a blob transaction => r#"forc tx blob \
+        --bytecode {path}/out/debug/name.bin \
+        --gas-price 0 \
+        --maturity 0 \
+        --witness ADFD \
+        input coin \
+            --utxo-id 0 \
+            --output-ix 0 \
+            --owner 0x0000000000000000000000000000000000000000000000000000000000000000 \
+            --amount 100 \
+            --asset-id 0x0000000000000000000000000000000000000000000000000000000000000000 \
+            --tx-ptr 89ACBDEFBDEF \
+            --witness-ix 1 \
+            --maturity 0 \
+        output change \
+            --to 0x2222222222222222222222222222222222222222222222222222222222222222 \
+            --amount 100 \
+            --asset-id 0x0000000000000000000000000000000000000000000000000000000000000000"#
+    ]
     }
 }
 
@@ -134,6 +155,7 @@ pub struct Command {
 pub enum Transaction {
     Create(Create),
     Script(Script),
+    Blob(Blob),
 }
 
 /// Construct a `Create` transaction for deploying a contract.
@@ -196,6 +218,33 @@ pub struct Script {
     pub outputs: Vec<Output>,
 }
 
+/// Construct a `Blob` transaction for uploading bytecode to be loaded by other contracts, scripts
+/// and predicates.
+#[derive(Debug, Parser, Deserialize, Serialize)]
+pub struct Blob {
+    #[clap(flatten)]
+    pub gas: Gas,
+    #[clap(flatten)]
+    pub maturity: Maturity,
+    /// Path to the bytecode to upload.
+    #[clap(long)]
+    pub bytecode: PathBuf,
+    /// Witness index at which the bytecode is inserted among the `--witness`es.
+    #[clap(long, default_value_t = 0)]
+    pub bytecode_witness_index: u16,
+    /// An arbitrary length string of hex-encoded bytes (e.g. "1F2E3D4C5B6A")
+    ///
+    /// Can be specified multiple times.
+    #[clap(long = "witness", num_args(0..=255))]
+    pub witnesses: Vec<String>,
+    // Inputs and outputs must follow all other arguments and are parsed separately.
+    #[clap(skip)]
+    pub inputs: Vec<Input>,
+    // Inputs and outputs must follow all other arguments and are parsed separately.
+    #[clap(skip)]
+    pub outputs: Vec<Output>,
+}
+
 /// Flag set for specifying gas price and limit.
 #[derive(Debug, Devault, Parser, Deserialize, Serialize)]
 pub struct Gas {
@@ -436,6 +485,8 @@ pub enum ConvertTxError {
     Create(#[from] ConvertCreateTxError),
     #[error("failed to convert script transaction")]
     Script(#[from] ConvertScriptTxError),
+    #[error("failed to convert blob transaction")]
+    Blob(#[from] ConvertBlobTxError),
 }
 
 /// Errors that can occur during "create" transaction conversion.
@@ -472,6 +523,21 @@ pub enum ConvertScriptTxError {
     Input(#[from] ConvertInputError),
 }
 
+/// Errors that can occur during "blob" transaction conversion.
+#[derive(Debug, Error)]
+pub enum ConvertBlobTxError {
+    #[error("failed to read `--bytecode` from {path:?}")]
+    BytecodeRead {
+        path: PathBuf,
+        #[source]
+        err: std::io::Error,
+    },
+    #[error("`--bytecode-witness-index` {index} is out of range for {witnesses} `--witness`es")]
+    BytecodeWitnessIndex { index: u16, witnesses: usize },
+    #[error("failed to convert an input")]
+    Input(#[from] ConvertInputError),
+}
+
 /// Errors that can occur during transaction input conversion.
 #[derive(Debug, Error)]
 pub enum ConvertInputError {
@@ -566,6 +632,7 @@ impl Command {
             match cmd {
                 Transaction::Create(ref mut create) => create.inputs.push(input),
                 Transaction::Script(ref mut script) => script.inputs.push(input),
+                Transaction::Blob(ref mut blob) => blob.inputs.push(input),
             }
             Ok(())
         }
@@ -574,6 +641,7 @@ impl Command {
             match cmd {
                 Transaction::Create(ref mut create) => create.outputs.push(output),
                 Transaction::Script(ref mut script) => script.outputs.push(output),
+                Transaction::Blob(ref mut blob) => blob.outputs.push(output),
             }
         }
 
@@ -628,6 +696,7 @@ impl TryFrom<Transaction> for fuel_tx::Transaction {
         let tx = match tx {
             Transaction::Create(create) => Self::Create(<_>::try_from(create)?),
             Transaction::Script(script) => Self::Script(<_>::try_from(script)?),
+            Transaction::Blob(blob) => Self::Blob(<_>::try_from(blob)?),
         };
         Ok(tx)
     }
@@ -739,6 +808,58 @@ impl TryFrom<Script> for fuel_tx::Script {
     }
 }
 
+impl TryFrom<Blob> for fuel_tx::Blob {
+    type Error = ConvertBlobTxError;
+    fn try_from(blob: Blob) -> Result<Self, Self::Error> {
+        let bytecode =
+            std::fs::read(&blob.bytecode).map_err(|err| ConvertBlobTxError::BytecodeRead {
+                path: blob.bytecode,
+                err,
+            })?;
+        let inputs = blob
+            .inputs
+            .into_iter()
+            .map(fuel_tx::Input::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let outputs = blob
+            .outputs
+            .into_iter()
+            .map(fuel_tx::Output::from)
+            .collect();
+        let mut witnesses: Vec<_> = blob
+            .witnesses
+            .into_iter()
+            .map(|s| fuel_tx::Witness::from(s.as_bytes()))
+            .collect();
+
+        // The bytecode is carried by a witness, inserted among the given ones.
+        let witness_index = blob.bytecode_witness_index;
+        if usize::from(witness_index) > witnesses.len() {
+            return Err(ConvertBlobTxError::BytecodeWitnessIndex {
+                index: witness_index,
+                witnesses: witnesses.len(),
+            });
+        }
+        let id = fuel_tx::BlobId::compute(&bytecode);
+        witnesses.insert(usize::from(witness_index), bytecode.into());
+
+        let maturity = (blob.maturity.maturity != 0).then_some(blob.maturity.maturity.into());
+        let mut policies = Policies::default();
+        policies.set(PolicyType::Tip, blob.gas.price);
+        policies.set(PolicyType::Maturity, maturity);
+
+        let blob = fuel_tx::Transaction::blob(
+            fuel_tx::BlobBody { id, witness_index },
+            policies,
+            inputs,
+            outputs,
+            witnesses,
+        );
+
+        Ok(blob)
+    }
+}
+
 impl TryFrom<Input> for fuel_tx::Input {
     type Error = ConvertInputError;
     fn try_from(input: Input) -> Result<Self, Self::Error> {
@@ -947,6 +1068,19 @@ fn test_parse_script() {
     dbg!(Command::try_parse_from_args(cmd.split_whitespace().map(|s| s.to_string())).unwrap());
 }
 
+#[test]
+fn test_parse_blob() {
+    let cmd = r#"
+        forc-tx blob
+            --bytecode ./my-contract/out/debug/my-contract.bin
+            --bytecode-witness-index 1
+            --gas-price 0
+            --maturity 0
+            --witness ADFD
+    "#;
+    dbg!(Command::try_parse_from_args(cmd.split_whitespace().map(|s| s.to_string())).unwrap());
+}
+
 #[test]
 fn test_parse_create_inputs_outputs() {
     let address = fuel_tx::Address::default();
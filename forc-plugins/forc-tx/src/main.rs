@@ -1,4 +1,5 @@
 fn main() -> anyhow::Result<()> {
+    forc_util::completion::handle_completion_metadata_request::<forc_tx::Command>();
     let cmd = forc_tx::Command::parse();
     let tx = fuel_tx::Transaction::try_from(cmd.tx)?;
     match cmd.output_path {
@@ -0,0 +1,142 @@
+//! Deploys a contract split into blobs behind a loader contract, and calls it.
+
+use forc_tx::blob::ChunkedContract;
+use fuel_asm::{op, GTFArgs, Instruction, RegId};
+use fuel_tx::{
+    consensus_parameters::ConsensusParametersV1, AssetId, Bytes32, ConsensusParameters, ContractId,
+    ContractParameters, Input, Output, Receipt, Salt, ScriptParameters, TxParameters, TxPointer,
+};
+use fuel_vm::{
+    checked_transaction::builder::TransactionBuilderExt,
+    interpreter::{Interpreter, InterpreterParams, MemoryInstance, NotSupportedEcal},
+    prelude::SecretKey,
+    storage::MemoryStorage,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const GAS_PRICE: u64 = 0;
+
+fn consensus_params() -> ConsensusParameters {
+    ConsensusParameters::V1(ConsensusParametersV1 {
+        script_params: ScriptParameters::DEFAULT.with_max_script_data_length(u64::MAX),
+        tx_params: TxParameters::DEFAULT.with_max_size(u64::MAX),
+        contract_params: ContractParameters::DEFAULT.with_contract_max_size(u64::MAX),
+        ..Default::default()
+    })
+}
+
+/// A contract which returns `1`, padded so that it is split into several blobs.
+fn contract_bytecode() -> Vec<u8> {
+    [op::noop(), op::noop(), op::noop(), op::noop(), op::noop()]
+        .into_iter()
+        .chain([op::ret(RegId::ONE)])
+        .flat_map(Instruction::to_bytes)
+        .collect()
+}
+
+/// A script calling the contract whose ID is at the beginning of the script data.
+fn call_script(contract_id: ContractId) -> (Vec<u8>, Vec<u8>) {
+    let script = [
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .flat_map(Instruction::to_bytes)
+    .collect();
+    // The call frame is the contract ID followed by the two call parameters.
+    let script_data = contract_id.iter().copied().chain([0; 16]).collect();
+    (script, script_data)
+}
+
+#[test]
+fn deploy_and_call_chunked_contract() {
+    let rng = &mut StdRng::seed_from_u64(0);
+    let params = consensus_params();
+    let mut storage = MemoryStorage::default();
+
+    let contract = ChunkedContract::new(&contract_bytecode(), 8).unwrap();
+    assert_eq!(contract.blobs.len(), 3);
+
+    // Upload the blobs.
+    for mut builder in contract.blob_tx_builders() {
+        let tx = builder
+            .with_params(params.clone())
+            .add_unsigned_coin_input(
+                SecretKey::random(rng),
+                rng.gen(),
+                1,
+                AssetId::BASE,
+                rng.gen(),
+            )
+            .finalize_checked(0.into())
+            .into_ready(GAS_PRICE, params.gas_costs(), params.fee_params())
+            .unwrap();
+        let mut interpreter: Interpreter<_, _, _, NotSupportedEcal> = Interpreter::with_storage(
+            MemoryInstance::new(),
+            storage,
+            InterpreterParams::new(GAS_PRICE, params.clone()),
+        );
+        interpreter.transact(tx).unwrap();
+        storage = interpreter.as_ref().clone();
+    }
+
+    // Deploy the loader.
+    let salt = Salt::zeroed();
+    let contract_id = contract.loader_contract_id(&salt, &[]);
+    let tx = contract
+        .loader_create_tx_builder(salt, vec![])
+        .with_params(params.clone())
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            1,
+            AssetId::BASE,
+            rng.gen(),
+        )
+        .finalize_checked(0.into())
+        .into_ready(GAS_PRICE, params.gas_costs(), params.fee_params())
+        .unwrap();
+    let mut interpreter: Interpreter<_, _, _, NotSupportedEcal> = Interpreter::with_storage(
+        MemoryInstance::new(),
+        storage,
+        InterpreterParams::new(GAS_PRICE, params.clone()),
+    );
+    interpreter.transact(tx).unwrap();
+    storage = interpreter.as_ref().clone();
+
+    // Call the loader, which runs the contract loaded from the blobs.
+    let (script, script_data) = call_script(contract_id);
+    let tx = fuel_tx::TransactionBuilder::script(script, script_data)
+        .with_params(params.clone())
+        .script_gas_limit(1_000_000)
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            1,
+            AssetId::BASE,
+            rng.gen(),
+        )
+        .add_input(Input::contract(
+            rng.gen(),
+            Bytes32::zeroed(),
+            Bytes32::zeroed(),
+            TxPointer::default(),
+            contract_id,
+        ))
+        .add_output(Output::contract(1, Bytes32::zeroed(), Bytes32::zeroed()))
+        .finalize_checked(0.into())
+        .into_ready(GAS_PRICE, params.gas_costs(), params.fee_params())
+        .unwrap();
+    let mut interpreter: Interpreter<_, _, _, NotSupportedEcal> = Interpreter::with_storage(
+        MemoryInstance::new(),
+        storage,
+        InterpreterParams::new(GAS_PRICE, params.clone()),
+    );
+    let transition = interpreter.transact(tx).unwrap();
+
+    assert!(transition.receipts().iter().any(|receipt| matches!(
+        receipt,
+        Receipt::Return { id, val: 1, .. } if *id == contract_id
+    )));
+}
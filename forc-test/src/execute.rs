@@ -1,12 +1,14 @@
 use crate::maxed_consensus_params;
 use crate::setup::TestSetup;
-use crate::TestResult;
+use crate::state::TestStateDiff;
 use crate::TEST_METADATA_SEED;
-use forc_pkg::PkgTestEntry;
+use crate::{TestLimit, TestLimitExceeded, TestLimits, TestResult};
+use forc_pkg::{PkgTestEntry, PkgTestMock};
 use fuel_tx::{self as tx, output::contract::Contract, Chargeable, Finalizable};
 use fuel_vm::error::InterpreterError;
 use fuel_vm::fuel_asm;
 use fuel_vm::prelude::Instruction;
+use fuel_vm::prelude::PanicReason;
 use fuel_vm::prelude::RegId;
 use fuel_vm::{
     self as vm,
@@ -32,6 +34,61 @@ pub struct TestExecutor {
     pub name: String,
     pub jump_instruction_index: usize,
     pub relative_jump_in_bytes: u32,
+    /// The time and gas limits after which the test is interrupted and reported as failed.
+    pub limits: TestLimits,
+    /// The external contract calls answered with a mocked return value instead of being executed.
+    pub mocks: Vec<ContractCallMock>,
+    /// The storage before the execution of the test, to compute the changes the test made.
+    initial_storage: MemoryStorage,
+}
+
+/// A mocked external contract call.
+///
+/// Calls to the contract, or only to one of its methods, are skipped and immediately return the
+/// mocked data. Mocks apply to contract calls using the new encoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractCallMock {
+    pub contract_id: tx::ContractId,
+    pub method: Option<String>,
+    pub return_data: Vec<u8>,
+}
+
+impl ContractCallMock {
+    /// Mocks all calls to `contract_id`, returning no data.
+    pub fn new(contract_id: tx::ContractId) -> Self {
+        Self {
+            contract_id,
+            method: None,
+            return_data: vec![],
+        }
+    }
+
+    /// Only mocks calls to the ABI method `method`.
+    pub fn method(mut self, method: impl Into<String>) -> Self {
+        self.method = Some(method.into());
+        self
+    }
+
+    /// Returns the already encoded `return_data` from the mocked calls.
+    pub fn returns(mut self, return_data: Vec<u8>) -> Self {
+        self.return_data = return_data;
+        self
+    }
+
+    fn matches(&self, contract_id: &tx::ContractId, method: &str) -> bool {
+        self.contract_id == *contract_id
+            && self.method.as_ref().map_or(true, |m| m.as_str() == method)
+    }
+}
+
+impl From<&PkgTestMock> for ContractCallMock {
+    fn from(mock: &PkgTestMock) -> Self {
+        Self {
+            contract_id: tx::ContractId::new(mock.contract_id),
+            method: mock.method.clone(),
+            return_data: mock.return_data.clone(),
+        }
+    }
 }
 
 /// The result of executing a test with breakpoints enabled.
@@ -125,7 +182,8 @@ impl TestExecutor {
 
         let interpreter_params = InterpreterParams::new(gas_price, &consensus_params);
         let memory_instance = MemoryInstance::new();
-        let interpreter = Interpreter::with_storage(memory_instance, storage, interpreter_params);
+        let interpreter =
+            Interpreter::with_storage(memory_instance, storage.clone(), interpreter_params);
 
         Ok(TestExecutor {
             interpreter,
@@ -135,9 +193,25 @@ impl TestExecutor {
             jump_instruction_index,
             relative_jump_in_bytes: (test_instruction_index - jump_instruction_index as u32)
                 * Instruction::SIZE as u32,
+            limits: TestLimits {
+                timeout: test_entry.timeout_ms.map(std::time::Duration::from_millis),
+                gas: test_entry.gas_limit,
+            },
+            mocks: test_entry
+                .mocks
+                .iter()
+                .map(ContractCallMock::from)
+                .collect(),
+            initial_storage: storage,
         })
     }
 
+    /// Adds `mocks` to the external contract calls mocked by the test.
+    pub fn with_mocks(mut self, mocks: impl IntoIterator<Item = ContractCallMock>) -> Self {
+        self.mocks.extend(mocks);
+        self
+    }
+
     // single-step until the jump-to-test instruction, then
     // jump into the first instruction of the test
     fn single_step_until_test(&mut self) -> ProgramState {
@@ -197,6 +271,7 @@ impl TestExecutor {
         }
 
         let duration = start.elapsed();
+        let state_diff = self.state_diff();
         let (gas_used, logs) = Self::get_gas_and_receipts(self.interpreter.receipts().to_vec())?;
         let span = self.test_entry.span.clone();
         let file_path = self.test_entry.file_path.clone();
@@ -211,6 +286,10 @@ impl TestExecutor {
             condition,
             logs,
             gas_used,
+            revert_message: None,
+            state_diff,
+            limit_exceeded: None,
+            failing_sequence: None,
         }))
     }
 
@@ -228,6 +307,7 @@ impl TestExecutor {
             return Ok(DebugResult::Breakpoint(breakpoint.pc()));
         }
         let duration = start.elapsed();
+        let state_diff = self.state_diff();
         let (gas_used, logs) = Self::get_gas_and_receipts(self.interpreter.receipts().to_vec())?; // TODO: calculate culumlative
         let span = self.test_entry.span.clone();
         let file_path = self.test_entry.file_path.clone();
@@ -242,6 +322,10 @@ impl TestExecutor {
             condition,
             logs,
             gas_used,
+            revert_message: None,
+            state_diff,
+            limit_exceeded: None,
+            failing_sequence: None,
         }))
     }
 
@@ -249,6 +333,16 @@ impl TestExecutor {
         let start = std::time::Instant::now();
 
         let mut state = Ok(self.single_step_until_test());
+        let mut limit_exceeded = None;
+        // The gas used before the test starts, by the jump to the test function, is not counted.
+        let initial_gas = self.interpreter.registers()[RegId::GGAS];
+
+        // With limits the test is single stepped, so that the elapsed time and the used gas can
+        // be checked between instructions and a hanging test interrupted. With mocks, so that
+        // mocked contract calls can be skipped before they are executed.
+        if self.limits.is_set() || !self.mocks.is_empty() {
+            self.interpreter.set_single_stepping(true);
+        }
 
         // Run test until its end
         loop {
@@ -261,12 +355,25 @@ impl TestExecutor {
                     ProgramState::Return(_) | ProgramState::ReturnData(_) | ProgramState::Revert(_),
                 ) => break,
                 Ok(ProgramState::RunProgram(_) | ProgramState::VerifyPredicate(_)) => {
+                    if let Some(limit) = self.exceeded_limit(start, initial_gas) {
+                        limit_exceeded = Some(TestLimitExceeded {
+                            limit,
+                            instruction_index: self.current_instruction_index(),
+                        });
+                        break;
+                    }
+                    // A mocked call which can't be read from memory fails like the actual call.
+                    if self.skip_mocked_calls().is_err() {
+                        state = Ok(ProgramState::Revert(0));
+                        break;
+                    }
                     state = self.interpreter.resume();
                 }
             }
         }
 
         let duration = start.elapsed();
+        let state_diff = self.state_diff();
         let (gas_used, logs) = Self::get_gas_and_receipts(self.interpreter.receipts().to_vec())?;
         let span = self.test_entry.span.clone();
         let file_path = self.test_entry.file_path.clone();
@@ -281,9 +388,91 @@ impl TestExecutor {
             condition,
             logs,
             gas_used,
+            revert_message: None,
+            state_diff,
+            limit_exceeded,
+            failing_sequence: None,
         })
     }
 
+    /// The changes the test made to the chain state so far.
+    pub fn state_diff(&self) -> TestStateDiff {
+        TestStateDiff::new(
+            &self.initial_storage,
+            self.interpreter.as_ref(),
+            self.interpreter.receipts(),
+        )
+    }
+
+    /// Skips the mocked contract calls the interpreter is about to execute, as if the called
+    /// contract returned the mocked data.
+    fn skip_mocked_calls(&mut self) -> Result<(), PanicReason> {
+        while let Some(return_data) = self.next_mocked_call()? {
+            let sp = self.interpreter.registers()[RegId::SP];
+            let len = return_data.len() as u64;
+            let memory = self.interpreter.memory_mut();
+            memory.grow_stack(sp + len)?;
+            memory
+                .write_noownerchecks(sp, len)?
+                .copy_from_slice(&return_data);
+
+            let registers = self.interpreter.registers_mut();
+            registers[RegId::SP] = sp + len;
+            registers[RegId::RET] = sp;
+            registers[RegId::RETL] = len;
+            registers[RegId::PC] += Instruction::SIZE as u64;
+        }
+        Ok(())
+    }
+
+    /// Returns the data of the mock matching the instruction the interpreter is about to execute,
+    /// if it is a mocked contract call.
+    fn next_mocked_call(&self) -> Result<Option<Vec<u8>>, PanicReason> {
+        let registers = self.interpreter.registers();
+        let memory = self.interpreter.memory();
+        let instruction = memory.read_bytes::<{ Instruction::SIZE }>(registers[RegId::PC])?;
+        let Ok(Instruction::CALL(call)) = Instruction::try_from(instruction) else {
+            return Ok(None);
+        };
+
+        // The call parameters hold the contract id, followed by pointers to the encoded method
+        // name and the encoded arguments.
+        let (params, _, _, _) = call.unpack();
+        let params = registers[params];
+        let contract_id = tx::ContractId::new(memory.read_bytes::<32>(params)?);
+        let method_ptr = u64::from_be_bytes(memory.read_bytes::<8>(params + 32)?);
+        let method_len = u64::from_be_bytes(memory.read_bytes::<8>(method_ptr)?);
+        let method = memory.read(method_ptr + 8, method_len)?.to_vec();
+        let method = String::from_utf8_lossy(&method);
+
+        Ok(self
+            .mocks
+            .iter()
+            .find(|mock| mock.matches(&contract_id, &method))
+            .map(|mock| mock.return_data.clone()))
+    }
+
+    /// Returns the limit the test exceeded, if any, given the time the test `start`ed at and the
+    /// global gas left before the test started.
+    fn exceeded_limit(&self, start: std::time::Instant, initial_gas: u64) -> Option<TestLimit> {
+        if let Some(limit) = self.limits.timeout.filter(|limit| start.elapsed() > *limit) {
+            return Some(TestLimit::Time(limit));
+        }
+        let gas_used = initial_gas.saturating_sub(self.interpreter.registers()[RegId::GGAS]);
+        self.limits
+            .gas
+            .filter(|limit| gas_used > *limit)
+            .map(TestLimit::Gas)
+    }
+
+    /// The index of the instruction the interpreter is about to execute, relative to the start of
+    /// the program bytecode.
+    fn current_instruction_index(&self) -> usize {
+        let registers = self.interpreter.registers();
+        let offset = registers[RegId::PC].saturating_sub(registers[RegId::IS]);
+        offset as usize / Instruction::SIZE
+    }
+
     fn get_gas_and_receipts(receipts: Vec<Receipt>) -> anyhow::Result<(u64, Vec<Receipt>)> {
         let gas_used = *receipts
             .iter()
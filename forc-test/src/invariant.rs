@@ -0,0 +1,201 @@
+//! Invariant testing, also known as stateful or model-based testing.
+//!
+//! The actions of a package, its `#[test(action)]` functions, are executed in random sequences,
+//! each one on the chain state left by the previous one. After every action, the invariants, the
+//! `#[test(invariant)]` functions, are executed, and an invariant that reverts is broken. The
+//! sequence that broke it is then shrunk to a shortest one that still breaks it, which is reported
+//! along with the failure.
+
+use crate::execute::TestExecutor;
+use crate::setup::TestSetup;
+use crate::{TestLimits, TestResult};
+use forc_pkg::{BuiltPackage, PkgEntry, PkgTestEntry, TestKind};
+use fuel_vm::storage::MemoryStorage;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::time::Instant;
+
+/// The seed of the random sequences of actions, fixed so that failures are reproducible.
+const INVARIANT_SEED: u64 = 0x1A7A;
+
+/// The options for running the invariant tests of a package.
+#[derive(Debug, Clone)]
+pub struct InvariantOpts {
+    /// The number of random sequences of actions executed.
+    pub runs: usize,
+    /// The number of actions in each sequence.
+    pub depth: usize,
+}
+
+impl Default for InvariantOpts {
+    fn default() -> Self {
+        Self {
+            runs: 64,
+            depth: 16,
+        }
+    }
+}
+
+/// A `#[test]` function of a package.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TestFunction<'a> {
+    pub entry: &'a PkgEntry,
+    pub test_entry: &'a PkgTestEntry,
+}
+
+impl TestFunction<'_> {
+    fn name(&self) -> &str {
+        &self.entry.finalized.fn_name
+    }
+}
+
+/// Returns the `#[test]` functions of the package of the given `kind`.
+pub(crate) fn test_functions(
+    pkg: &BuiltPackage,
+    kind: TestKind,
+) -> impl Iterator<Item = TestFunction> + '_ {
+    pkg.bytecode.entries.iter().filter_map(move |entry| {
+        let test_entry = entry.kind.test()?;
+        (test_entry.kind == kind).then_some(TestFunction { entry, test_entry })
+    })
+}
+
+/// Runs the invariant tests of a single package.
+pub(crate) struct InvariantRunner<'a> {
+    pub bytecode: &'a [u8],
+    /// The chain state every sequence of actions starts from.
+    pub setup: TestSetup,
+    pub actions: Vec<TestFunction<'a>>,
+    pub invariants: Vec<TestFunction<'a>>,
+    /// The limits of each action and invariant, unless they set their own.
+    pub limits: TestLimits,
+}
+
+impl InvariantRunner<'_> {
+    /// Executes the random sequences of actions, and returns the result of each invariant.
+    ///
+    /// Broken invariants are reported with the shrunk sequence of actions that broke them, and
+    /// are not checked by the following sequences.
+    pub fn run(&self, opts: &InvariantOpts) -> anyhow::Result<Vec<TestResult>> {
+        let start = Instant::now();
+
+        // Invariants that don't even hold after the deployment are broken by an empty sequence.
+        let mut results = self
+            .invariants
+            .iter()
+            .map(|invariant| {
+                let (mut result, _) = self.execute(invariant, &self.setup)?;
+                if !result.passed() {
+                    result.failing_sequence = Some(vec![]);
+                }
+                Ok(result)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let mut unbroken = (0..results.len())
+            .filter(|&invariant| results[invariant].passed())
+            .collect::<Vec<_>>();
+
+        let mut rng = StdRng::seed_from_u64(INVARIANT_SEED);
+        for _ in 0..opts.runs {
+            if unbroken.is_empty() || self.actions.is_empty() {
+                break;
+            }
+            let sequence = (0..opts.depth)
+                .map(|_| rng.gen_range(0..self.actions.len()))
+                .collect::<Vec<_>>();
+            let Some((invariant, len)) = self.find_violation(&sequence, &unbroken)? else {
+                continue;
+            };
+            let sequence = self.shrink(sequence[..len].to_vec(), invariant)?;
+            results[invariant] = self.replay(&sequence, invariant)?;
+            unbroken.retain(|&unbroken| unbroken != invariant);
+        }
+
+        for invariant in unbroken {
+            results[invariant].duration = start.elapsed();
+        }
+        Ok(results)
+    }
+
+    /// Executes the `sequence` of actions and checks the `invariants` after each one.
+    ///
+    /// Returns the first broken invariant, along with the number of actions executed before it
+    /// broke.
+    fn find_violation(
+        &self,
+        sequence: &[usize],
+        invariants: &[usize],
+    ) -> anyhow::Result<Option<(usize, usize)>> {
+        let mut setup = self.setup.clone();
+        for (executed, &action) in sequence.iter().enumerate() {
+            let (result, storage) = self.execute(&self.actions[action], &setup)?;
+            // A reverted action, e.g. one whose preconditions don't hold, changes nothing.
+            if !result.passed() {
+                continue;
+            }
+            setup = setup.with_storage(storage);
+            for &invariant in invariants {
+                let (result, _) = self.execute(&self.invariants[invariant], &setup)?;
+                if !result.passed() {
+                    return Ok(Some((invariant, executed + 1)));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Removes actions from the `sequence`, one at a time, as long as it still breaks the
+    /// `invariant`.
+    fn shrink(&self, mut sequence: Vec<usize>, invariant: usize) -> anyhow::Result<Vec<usize>> {
+        'shrink: loop {
+            for removed in 0..sequence.len() {
+                let mut candidate = sequence.clone();
+                candidate.remove(removed);
+                if let Some((_, len)) = self.find_violation(&candidate, &[invariant])? {
+                    candidate.truncate(len);
+                    sequence = candidate;
+                    continue 'shrink;
+                }
+            }
+            return Ok(sequence);
+        }
+    }
+
+    /// Executes the `sequence` of actions, and returns the result of the `invariant` after it.
+    fn replay(&self, sequence: &[usize], invariant: usize) -> anyhow::Result<TestResult> {
+        let mut setup = self.setup.clone();
+        for &action in sequence {
+            let (result, storage) = self.execute(&self.actions[action], &setup)?;
+            if result.passed() {
+                setup = setup.with_storage(storage);
+            }
+        }
+        let (mut result, _) = self.execute(&self.invariants[invariant], &setup)?;
+        result.failing_sequence = Some(
+            sequence
+                .iter()
+                .map(|&action| self.actions[action].name().to_string())
+                .collect(),
+        );
+        Ok(result)
+    }
+
+    /// Executes the test `function` and returns its result, along with the storage it left.
+    fn execute(
+        &self,
+        function: &TestFunction,
+        setup: &TestSetup,
+    ) -> anyhow::Result<(TestResult, MemoryStorage)> {
+        let offset = u32::try_from(function.entry.finalized.imm)
+            .expect("test instruction offset out of range");
+        let mut executor = TestExecutor::build(
+            self.bytecode,
+            offset,
+            setup.clone(),
+            function.test_entry,
+            function.name().to_string(),
+        )?;
+        executor.limits = executor.limits.or(self.limits);
+        let result = executor.execute()?;
+        Ok((result, executor.interpreter.as_ref().clone()))
+    }
+}
@@ -1,10 +1,14 @@
 pub mod execute;
+pub mod invariant;
 pub mod setup;
+pub mod state;
 
 use crate::execute::TestExecutor;
+use crate::invariant::{test_functions, InvariantOpts, InvariantRunner};
 use crate::setup::{
     ContractDeploymentSetup, ContractTestSetup, DeploymentSetup, ScriptTestSetup, TestSetup,
 };
+use crate::state::TestStateDiff;
 use forc_pkg::{self as pkg, BuildOpts};
 use fuel_abi_types::error_codes::ErrorSignal;
 use fuel_tx as tx;
@@ -12,12 +16,18 @@ use fuel_vm::checked_transaction::builder::TransactionBuilderExt;
 use fuel_vm::{self as vm};
 use fuels_core::codec::ABIDecoder;
 use fuels_core::types::param_types::ParamType;
-use pkg::TestPassCondition;
 use pkg::{Built, BuiltPackage};
+use pkg::{PkgTestEntry, TestKind, TestPassCondition};
 use rand::{Rng, SeedableRng};
 use rayon::prelude::*;
-use std::{collections::HashMap, fs, path::PathBuf, sync::Arc};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
 use sway_core::asm_generation::ProgramABI;
+use sway_core::source_map::{LocationRange, SourceMap};
 use sway_core::BuildTarget;
 use sway_types::Span;
 use tx::consensus_parameters::ConsensusParametersV1;
@@ -49,13 +59,25 @@ pub struct TestDetails {
 }
 
 /// The filter to be used to only run matching tests.
-#[derive(Debug, Clone)]
+///
+/// A test is only executed if it matches all the criteria of the filter.
+#[derive(Debug, Clone, Default)]
 pub struct TestFilter<'a> {
     /// The phrase used for filtering, a `&str` searched/matched with test name.
     pub filter_phrase: &'a str,
     /// If set `true`, a complete "match" is required with test name for the test to be executed,
     /// otherwise a test_name should "contain" the `filter_phrase`.
     pub exact_match: bool,
+    /// If set, only the tests whose path within the package starts with the given path are
+    /// executed, e.g. `module::submodule::` for the tests declared in `module::submodule` and its
+    /// submodules, or `module::test_name` for a single test.
+    pub test_path: Option<&'a str>,
+    /// If set, only the tests declared in the given file are executed, e.g. `src/module.sw`.
+    pub file: Option<&'a Path>,
+    /// If not empty, only the tests having at least one of the given tags are executed.
+    pub include_tags: &'a [String],
+    /// The tests having any of the given tags are not executed.
+    pub exclude_tags: &'a [String],
 }
 
 /// The result of executing a single test within a single package.
@@ -77,6 +99,69 @@ pub struct TestResult {
     pub logs: Vec<fuel_tx::Receipt>,
     /// Gas used while executing this test.
     pub gas_used: u64,
+    /// The decoded value logged last before the test reverted, if any.
+    ///
+    /// This is only available once the result is decoded with [TestResult::with_revert_message].
+    pub revert_message: Option<String>,
+    /// Set if the test was interrupted for exceeding its time or gas limit.
+    pub limit_exceeded: Option<TestLimitExceeded>,
+    /// The storage slots, contract balances and coin outputs changed by the test.
+    pub state_diff: TestStateDiff,
+    /// For a broken invariant, the names of the actions of the shortest sequence found to break
+    /// it. Empty if the invariant doesn't hold before any action.
+    pub failing_sequence: Option<Vec<String>>,
+}
+
+/// The limits a test is interrupted and reported as failed for exceeding.
+///
+/// Limits set in the `#[test]` attribute of a test, with `timeout_ms` and `gas_limit`, take
+/// precedence over the ones passed to [BuiltTests::run].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TestLimits {
+    /// The wall-clock time limit.
+    pub timeout: Option<std::time::Duration>,
+    /// The limit of the gas used by the test.
+    pub gas: Option<u64>,
+}
+
+impl TestLimits {
+    /// Returns the limits of `self`, falling back to the ones of `other` for those not set.
+    pub fn or(self, other: TestLimits) -> TestLimits {
+        TestLimits {
+            timeout: self.timeout.or(other.timeout),
+            gas: self.gas.or(other.gas),
+        }
+    }
+
+    /// Whether any limit is set.
+    pub fn is_set(&self) -> bool {
+        self.timeout.is_some() || self.gas.is_some()
+    }
+}
+
+/// A single limit of a test.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestLimit {
+    Time(std::time::Duration),
+    Gas(u64),
+}
+
+impl std::fmt::Display for TestLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TestLimit::Time(limit) => write!(f, "time limit of {limit:?}"),
+            TestLimit::Gas(limit) => write!(f, "gas limit of {limit}"),
+        }
+    }
+}
+
+/// Details of a test interrupted for exceeding one of its limits.
+#[derive(Debug, Clone)]
+pub struct TestLimitExceeded {
+    /// The limit the test exceeded.
+    pub limit: TestLimit,
+    /// The index of the instruction the test was about to execute when it was interrupted.
+    pub instruction_index: usize,
 }
 
 const TEST_METADATA_SEED: u64 = 0x7E57u64;
@@ -376,20 +461,23 @@ impl<'a> PackageTests {
         &self,
         test_runners: &rayon::ThreadPool,
         test_filter: Option<&TestFilter>,
+        test_limits: TestLimits,
+        invariant_opts: &InvariantOpts,
     ) -> anyhow::Result<TestedPackage> {
         let pkg_with_tests = self.built_pkg_with_tests();
-        let tests = test_runners.install(|| {
+        let mut tests: Vec<TestResult> = test_runners.install(|| {
             pkg_with_tests
                 .bytecode
                 .entries
                 .par_iter()
                 .filter_map(|entry| {
-                    if let Some(test_entry) = entry.kind.test() {
+                    let test_entry = entry.kind.test()?;
+                    if test_entry.kind == TestKind::Unit {
                         // If a test filter is specified, only the tests containing the filter phrase in
                         // their name are going to be executed.
                         let name = entry.finalized.fn_name.clone();
                         if let Some(filter) = test_filter {
-                            if !filter.filter(&name) {
+                            if !filter.filter(&name, test_entry) {
                                 return None;
                             }
                         }
@@ -403,17 +491,22 @@ impl<'a> PackageTests {
                         .expect("test instruction offset out of range");
                     let name = entry.finalized.fn_name.clone();
                     let test_setup = self.setup()?;
-                    TestExecutor::build(
+                    let mut executor = TestExecutor::build(
                         &pkg_with_tests.bytecode.bytes,
                         offset,
                         test_setup,
                         test_entry,
                         name,
-                    )?
-                    .execute()
+                    )?;
+                    // A limit set on the test itself takes precedence over the global one.
+                    executor.limits = executor.limits.or(test_limits);
+                    executor
+                        .execute()
+                        .map(|result| result.with_revert_message(&pkg_with_tests.program_abi))
                 })
                 .collect::<anyhow::Result<_>>()
         })?;
+        tests.extend(self.run_invariants(test_filter, test_limits, invariant_opts)?);
 
         Ok(TestedPackage {
             built: Box::new(pkg_with_tests.clone()),
@@ -421,6 +514,40 @@ impl<'a> PackageTests {
         })
     }
 
+    /// Run the invariant tests after applying the provided filter to the names of the invariants,
+    /// and collect the result of each invariant.
+    fn run_invariants(
+        &self,
+        test_filter: Option<&TestFilter>,
+        test_limits: TestLimits,
+        invariant_opts: &InvariantOpts,
+    ) -> anyhow::Result<Vec<TestResult>> {
+        let pkg_with_tests = self.built_pkg_with_tests();
+        let invariants = test_functions(pkg_with_tests, TestKind::Invariant)
+            .filter(|invariant| {
+                test_filter.map_or(true, |filter| {
+                    filter.filter(&invariant.entry.finalized.fn_name, invariant.test_entry)
+                })
+            })
+            .collect::<Vec<_>>();
+        if invariants.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let runner = InvariantRunner {
+            bytecode: &pkg_with_tests.bytecode.bytes,
+            setup: self.setup()?,
+            actions: test_functions(pkg_with_tests, TestKind::Action).collect(),
+            invariants,
+            limits: test_limits,
+        };
+        Ok(runner
+            .run(invariant_opts)?
+            .into_iter()
+            .map(|result| result.with_revert_message(&pkg_with_tests.program_abi))
+            .collect())
+    }
+
     /// Setup the storage for a test and returns a contract id for testing contracts.
     ///
     /// For testing contracts, storage returned from this function contains the deployed contract.
@@ -461,6 +588,8 @@ impl From<TestOpts> for pkg::BuildOpts {
             member_filter: Default::default(),
             experimental: val.experimental,
             no_experimental: val.no_experimental,
+            message_format: Default::default(),
+            analysis_passes: Default::default(),
         }
     }
 }
@@ -485,6 +614,8 @@ impl TestOpts {
             member_filter: Default::default(),
             experimental: self.experimental,
             no_experimental: self.no_experimental,
+            message_format: Default::default(),
+            analysis_passes: Default::default(),
         }
     }
 }
@@ -492,17 +623,64 @@ impl TestOpts {
 impl TestResult {
     /// Whether or not the test passed.
     pub fn passed(&self) -> bool {
+        if self.limit_exceeded.is_some() {
+            return false;
+        }
         match &self.condition {
             TestPassCondition::ShouldRevert(revert_code) => match revert_code {
                 Some(revert_code) => self.state == vm::state::ProgramState::Revert(*revert_code),
                 None => matches!(self.state, vm::state::ProgramState::Revert(_)),
             },
+            TestPassCondition::ShouldPanic(expected_message) => {
+                matches!(self.state, vm::state::ProgramState::Revert(_))
+                    && self
+                        .revert_message
+                        .as_ref()
+                        .is_some_and(|message| message.contains(expected_message.as_str()))
+            }
             TestPassCondition::ShouldNotRevert => {
                 !matches!(self.state, vm::state::ProgramState::Revert(_))
             }
         }
     }
 
+    /// Decode the value logged last before the test reverted and record it as the
+    /// [TestResult::revert_message]. This is where `require` and `panic` put their messages.
+    pub fn with_revert_message(mut self, program_abi: &ProgramABI) -> Self {
+        if self.revert_code().is_some() {
+            self.revert_message = self
+                .logs
+                .iter()
+                .rev()
+                .find_map(|receipt| match receipt {
+                    fuel_tx::Receipt::LogData {
+                        rb,
+                        data: Some(data),
+                        ..
+                    } => Some(decode_log_data(&rb.to_string(), data, program_abi)),
+                    _ => None,
+                })
+                .and_then(|decoded| decoded.ok())
+                .map(|decoded| decoded.value);
+        }
+        self
+    }
+
+    /// Return the last known source location of a test interrupted for exceeding one of its
+    /// limits, using the source map of the package it was built from.
+    pub fn limit_exceeded_location(
+        &self,
+        source_map: &SourceMap,
+    ) -> Option<(PathBuf, LocationRange)> {
+        let limit_exceeded = self.limit_exceeded.as_ref()?;
+        // Not every instruction is mapped, so fall back to the closest preceding one.
+        let (_, span) = source_map
+            .map
+            .range(..=limit_exceeded.instruction_index)
+            .next_back()?;
+        Some(span.to_span(&source_map.paths, &source_map.dependency_paths))
+    }
+
     /// Return the revert code for this [TestResult] if the test is reverted.
     pub fn revert_code(&self) -> Option<u64> {
         match self.state {
@@ -548,12 +726,33 @@ pub struct TestCount {
 }
 
 impl<'a> TestFilter<'a> {
-    fn filter(&self, fn_name: &str) -> bool {
-        if self.exact_match {
+    fn filter(&self, fn_name: &str, test: &PkgTestEntry) -> bool {
+        let name_matches = if self.exact_match {
             fn_name == self.filter_phrase
         } else {
             fn_name.contains(self.filter_phrase)
-        }
+        };
+        let path_matches = self.test_path.map_or(true, |test_path| {
+            let test_path = test_path.trim_end_matches("::");
+            let mut full_path = test.module_path.iter().map(String::as_str).chain([fn_name]);
+            test_path.is_empty()
+                || test_path
+                    .split("::")
+                    .all(|segment| full_path.next() == Some(segment))
+        });
+        let file_matches = self.file.map_or(true, |file| {
+            // Allow paths like `./src/module.sw`, as `Path::ends_with` compares components.
+            let file = file
+                .components()
+                .filter(|component| *component != Component::CurDir)
+                .collect::<PathBuf>();
+            test.file_path.ends_with(file)
+        });
+        let has_tag = |tags: &[String]| tags.iter().any(|tag| test.tags.contains(tag));
+        let tags_match = (self.include_tags.is_empty() || has_tag(self.include_tags))
+            && !has_tag(self.exclude_tags);
+
+        name_matches && path_matches && file_matches && tags_match
     }
 }
 
@@ -571,11 +770,13 @@ impl BuiltTests {
                     .entries
                     .iter()
                     .filter_map(|entry| entry.kind.test().map(|test| (entry, test)))
+                    // Actions are only executed as part of invariant tests.
+                    .filter(|(_, test)| test.kind != TestKind::Action)
             })
-            .fold(TestCount::default(), |acc, (pkg_entry, _)| {
+            .fold(TestCount::default(), |acc, (pkg_entry, test_entry)| {
                 let num_ignored = match &test_filter {
                     Some(filter) => {
-                        if filter.filter(&pkg_entry.finalized.fn_name) {
+                        if filter.filter(&pkg_entry.finalized.fn_name, test_entry) {
                             acc.ignored
                         } else {
                             acc.ignored + 1
@@ -591,10 +792,17 @@ impl BuiltTests {
     }
 
     /// Run all built tests, return the result.
+    ///
+    /// Tests exceeding `test_limits`, or the limits set in their own `#[test]` attribute, are
+    /// interrupted and reported as failed.
+    ///
+    /// Invariant tests execute sequences of actions as configured by `invariant_opts`.
     pub fn run(
         self,
         test_runner_count: TestRunnerCount,
         test_filter: Option<TestFilter>,
+        test_limits: TestLimits,
+        invariant_opts: &InvariantOpts,
     ) -> anyhow::Result<Tested> {
         let test_runners = match test_runner_count {
             TestRunnerCount::Manual(runner_count) => rayon::ThreadPoolBuilder::new()
@@ -602,7 +810,13 @@ impl BuiltTests {
                 .build(),
             TestRunnerCount::Auto => rayon::ThreadPoolBuilder::new().build(),
         }?;
-        run_tests(self, &test_runners, test_filter)
+        run_tests(
+            self,
+            &test_runners,
+            test_filter,
+            test_limits,
+            invariant_opts,
+        )
     }
 }
 
@@ -718,16 +932,30 @@ fn run_tests(
     built: BuiltTests,
     test_runners: &rayon::ThreadPool,
     test_filter: Option<TestFilter>,
+    test_limits: TestLimits,
+    invariant_opts: &InvariantOpts,
 ) -> anyhow::Result<Tested> {
     match built {
         BuiltTests::Package(pkg) => {
-            let tested_pkg = pkg.run_tests(test_runners, test_filter.as_ref())?;
+            let tested_pkg = pkg.run_tests(
+                test_runners,
+                test_filter.as_ref(),
+                test_limits,
+                invariant_opts,
+            )?;
             Ok(Tested::Package(Box::new(tested_pkg)))
         }
         BuiltTests::Workspace(workspace) => {
             let tested_pkgs = workspace
                 .into_iter()
-                .map(|pkg| pkg.run_tests(test_runners, test_filter.as_ref()))
+                .map(|pkg| {
+                    pkg.run_tests(
+                        test_runners,
+                        test_filter.as_ref(),
+                        test_limits,
+                        invariant_opts,
+                    )
+                })
                 .collect::<anyhow::Result<Vec<TestedPackage>>>()?;
             Ok(Tested::Workspace(tested_pkgs))
         }
@@ -736,9 +964,11 @@ fn run_tests(
 
 #[cfg(test)]
 mod tests {
-    use std::path::PathBuf;
+    use std::{path::PathBuf, time::Duration};
 
-    use crate::{build, BuiltTests, TestFilter, TestOpts, TestResult};
+    use crate::{
+        build, BuiltTests, InvariantOpts, TestFilter, TestLimit, TestLimits, TestOpts, TestResult,
+    };
 
     /// Name of the folder containing required data for tests to run, such as an example forc
     /// project.
@@ -751,6 +981,16 @@ mod tests {
     const TEST_PREDICATE_PACKAGE_NAME: &str = "test_predicate";
     /// Name of the script package in the "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME".
     const TEST_SCRIPT_PACKAGE_NAME: &str = "test_script";
+    /// Name of the contract package with invariant tests in the "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME".
+    const TEST_INVARIANTS_PACKAGE_NAME: &str = "test_invariants";
+    /// Name of the library package with tagged tests in submodules in the "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME".
+    const TEST_FILTERS_PACKAGE_NAME: &str = "test_filters";
+    /// Name of the library package with time and gas limited tests in the "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME".
+    const TEST_LIMITS_PACKAGE_NAME: &str = "test_limits";
+    /// Name of the library package with mocked contract calls in the "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME".
+    const TEST_MOCKS_PACKAGE_NAME: &str = "test_mocks";
+    /// Name of the contract package with tests writing storage in the "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME".
+    const TEST_STATE_DIFF_PACKAGE_NAME: &str = "test_state_diff";
 
     /// Build the tests in the test package with the given name located at
     /// "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME/TEST_LIBRARY_PACKAGE_NAME".
@@ -773,10 +1013,23 @@ mod tests {
     fn test_package_test_results(
         package_name: &str,
         test_filter: Option<TestFilter>,
+    ) -> anyhow::Result<Vec<TestResult>> {
+        test_package_test_results_with_limits(package_name, test_filter, TestLimits::default())
+    }
+
+    fn test_package_test_results_with_limits(
+        package_name: &str,
+        test_filter: Option<TestFilter>,
+        test_limits: TestLimits,
     ) -> anyhow::Result<Vec<TestResult>> {
         let built_tests = test_package_built_tests(package_name)?;
         let test_runner_count = crate::TestRunnerCount::Auto;
-        let tested = built_tests.run(test_runner_count, test_filter)?;
+        let tested = built_tests.run(
+            test_runner_count,
+            test_filter,
+            test_limits,
+            &InvariantOpts::default(),
+        )?;
         match tested {
             crate::Tested::Package(tested_pkg) => Ok(tested_pkg.tests),
             crate::Tested::Workspace(_) => {
@@ -791,6 +1044,7 @@ mod tests {
         let test_filter = TestFilter {
             filter_phrase,
             exact_match: true,
+            ..Default::default()
         };
 
         let test_library_results =
@@ -824,6 +1078,7 @@ mod tests {
         let test_filter = TestFilter {
             filter_phrase,
             exact_match: true,
+            ..Default::default()
         };
 
         let test_library_results =
@@ -857,6 +1112,7 @@ mod tests {
         let test_filter = TestFilter {
             filter_phrase,
             exact_match: false,
+            ..Default::default()
         };
 
         let test_library_results =
@@ -890,6 +1146,7 @@ mod tests {
         let test_filter = TestFilter {
             filter_phrase,
             exact_match: false,
+            ..Default::default()
         };
 
         let test_library_results =
@@ -923,6 +1180,7 @@ mod tests {
         let test_filter = TestFilter {
             filter_phrase,
             exact_match: false,
+            ..Default::default()
         };
 
         let test_library_results =
@@ -975,4 +1233,226 @@ mod tests {
         assert_eq!(tested_predicate_test_count, 2);
         assert_eq!(tested_script_test_count, 2);
     }
+
+    #[test]
+    fn test_invariant_broken_by_shrunk_sequence() {
+        let results = test_package_test_results(TEST_INVARIANTS_PACKAGE_NAME, None).unwrap();
+
+        // Actions are not reported as tests on their own.
+        let names = results.iter().map(|r| r.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["test_count_starts_at_zero", "count_below_three"]);
+        assert!(results[0].passed());
+
+        // Reverting decrements of a zero count are shrunk away, along with all other
+        // actions that are not needed to break the invariant.
+        let invariant = &results[1];
+        assert!(!invariant.passed());
+        assert_eq!(
+            invariant.failing_sequence.as_deref(),
+            Some(
+                &[
+                    "increment_count".to_string(),
+                    "increment_count".to_string(),
+                    "increment_count".to_string()
+                ][..]
+            )
+        );
+    }
+
+    fn filtered_test_names(test_filter: TestFilter) -> Vec<String> {
+        let mut names = test_package_test_results(TEST_FILTERS_PACKAGE_NAME, Some(test_filter))
+            .unwrap()
+            .into_iter()
+            .map(|result| result.name)
+            .collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_filter_test_path() {
+        let in_module = filtered_test_names(TestFilter {
+            test_path: Some("module::"),
+            ..Default::default()
+        });
+        assert_eq!(
+            in_module,
+            ["test_module", "test_submodule", "test_untagged"]
+        );
+
+        let in_submodule = filtered_test_names(TestFilter {
+            test_path: Some("module::submodule"),
+            ..Default::default()
+        });
+        assert_eq!(in_submodule, ["test_submodule", "test_untagged"]);
+
+        let single_test = filtered_test_names(TestFilter {
+            test_path: Some("module::test_module"),
+            ..Default::default()
+        });
+        assert_eq!(single_test, ["test_module"]);
+
+        let in_root = filtered_test_names(TestFilter {
+            test_path: Some("test_root"),
+            ..Default::default()
+        });
+        assert_eq!(in_root, ["test_root"]);
+    }
+
+    #[test]
+    fn test_filter_file() {
+        let file = PathBuf::from("src/module/submodule.sw");
+        let in_file = filtered_test_names(TestFilter {
+            file: Some(&file),
+            ..Default::default()
+        });
+        assert_eq!(in_file, ["test_submodule", "test_untagged"]);
+    }
+
+    #[test]
+    fn test_filter_tags() {
+        let fast = ["fast".to_string()];
+        let slow = ["slow".to_string()];
+
+        let included = filtered_test_names(TestFilter {
+            include_tags: &fast,
+            ..Default::default()
+        });
+        assert_eq!(included, ["test_root", "test_submodule"]);
+
+        let excluded = filtered_test_names(TestFilter {
+            exclude_tags: &slow,
+            ..Default::default()
+        });
+        assert_eq!(excluded, ["test_root", "test_untagged"]);
+
+        let both = filtered_test_names(TestFilter {
+            include_tags: &fast,
+            exclude_tags: &slow,
+            ..Default::default()
+        });
+        assert_eq!(both, ["test_root"]);
+    }
+
+    fn test_result<'a>(results: &'a [TestResult], name: &str) -> &'a TestResult {
+        results
+            .iter()
+            .find(|result| result.name == name)
+            .unwrap_or_else(|| panic!("missing test result for `{name}`"))
+    }
+
+    #[test]
+    fn test_limits_interrupt_tests() {
+        let results = test_package_test_results(TEST_LIMITS_PACKAGE_NAME, None).unwrap();
+
+        let hangs = test_result(&results, "test_hangs");
+        assert!(!hangs.passed());
+        assert_eq!(
+            hangs
+                .limit_exceeded
+                .as_ref()
+                .map(|exceeded| &exceeded.limit),
+            Some(&TestLimit::Time(Duration::from_millis(100)))
+        );
+
+        let too_much_gas = test_result(&results, "test_uses_too_much_gas");
+        assert!(!too_much_gas.passed());
+        assert_eq!(
+            too_much_gas
+                .limit_exceeded
+                .as_ref()
+                .map(|exceeded| &exceeded.limit),
+            Some(&TestLimit::Gas(1_000))
+        );
+
+        let within_gas_limit = test_result(&results, "test_within_gas_limit");
+        assert!(within_gas_limit.passed());
+        assert!(within_gas_limit.limit_exceeded.is_none());
+
+        let reverts = test_result(&results, "test_reverts_with_hex_code");
+        assert!(reverts.passed());
+    }
+
+    #[test]
+    fn test_global_limits_apply_to_unlimited_tests() {
+        let filter = TestFilter {
+            filter_phrase: "test_within_gas_limit",
+            exact_match: true,
+            ..Default::default()
+        };
+        let limits = TestLimits {
+            gas: Some(1),
+            ..Default::default()
+        };
+        let results =
+            test_package_test_results_with_limits(TEST_LIMITS_PACKAGE_NAME, Some(filter), limits)
+                .unwrap();
+        // The test's own `gas_limit` takes precedence over the global one.
+        assert!(test_result(&results, "test_within_gas_limit").passed());
+
+        let filter = TestFilter {
+            filter_phrase: "test_reverts_with_hex_code",
+            exact_match: true,
+            ..Default::default()
+        };
+        let results =
+            test_package_test_results_with_limits(TEST_LIMITS_PACKAGE_NAME, Some(filter), limits)
+                .unwrap();
+        let reverts = test_result(&results, "test_reverts_with_hex_code");
+        assert!(!reverts.passed());
+        assert_eq!(
+            reverts
+                .limit_exceeded
+                .as_ref()
+                .map(|exceeded| &exceeded.limit),
+            Some(&TestLimit::Gas(1))
+        );
+    }
+
+    #[test]
+    fn test_mocked_contract_calls() {
+        let results = test_package_test_results(TEST_MOCKS_PACKAGE_NAME, None).unwrap();
+        assert_eq!(results.len(), 4);
+        for result in &results {
+            assert!(result.passed(), "test `{}` failed", result.name);
+        }
+
+        // The tests revert if the mocked call does not return the expected value, or if a call to
+        // a method without a mock is executed, as there is no deployed contract to call.
+        let mocked_method = test_result(&results, "test_mocked_method");
+        let mocked_contract = test_result(&results, "test_mocked_contract");
+        assert!(mocked_method.gas_used > 0);
+        // Mocked calls are skipped without charging gas, so the way the mock matches the call
+        // does not change the gas used.
+        assert_eq!(mocked_method.gas_used, mocked_contract.gas_used);
+    }
+
+    #[test]
+    fn test_state_diff_of_storage_writes() {
+        let results = test_package_test_results(TEST_STATE_DIFF_PACKAGE_NAME, None).unwrap();
+
+        let increment = test_result(&results, "test_increment");
+        assert!(increment.passed());
+        let [slot] = increment.state_diff.storage.as_slice() else {
+            panic!("expected a single written slot: {:?}", increment.state_diff);
+        };
+        // The slot is initialized when the contract is deployed, before the test runs.
+        let mut value = [0u8; 32];
+        assert_eq!(slot.before.as_deref(), Some(value.as_slice()));
+        value[..8].copy_from_slice(&1u64.to_be_bytes());
+        assert_eq!(slot.after.as_deref(), Some(value.as_slice()));
+        assert_eq!(
+            increment
+                .state_diff
+                .storage_slot(&slot.contract_id, &slot.key),
+            Some(value.as_slice())
+        );
+        assert!(increment.state_diff.balances.is_empty());
+        assert!(increment.state_diff.coin_outputs.is_empty());
+
+        // Each test runs on its own copy of the storage, so the other test doesn't see the write.
+        let count = test_result(&results, "test_count");
+        assert!(count.passed());
+        assert!(count.state_diff.is_empty());
+    }
 }
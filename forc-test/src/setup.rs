@@ -45,6 +45,25 @@ impl TestSetup {
         }
     }
 
+    /// Replaces the storage of this test setup, e.g. with the storage left by a previous test.
+    pub fn with_storage(self, storage: vm::storage::MemoryStorage) -> Self {
+        match self {
+            TestSetup::WithDeployment(DeploymentSetup::Script(script_setup)) => {
+                TestSetup::WithDeployment(DeploymentSetup::Script(ScriptTestSetup {
+                    storage,
+                    ..script_setup
+                }))
+            }
+            TestSetup::WithDeployment(DeploymentSetup::Contract(contract_setup)) => {
+                TestSetup::WithDeployment(DeploymentSetup::Contract(ContractTestSetup {
+                    storage,
+                    ..contract_setup
+                }))
+            }
+            TestSetup::WithoutDeployment(_) => TestSetup::WithoutDeployment(storage),
+        }
+    }
+
     /// Produces an iterator yielding contract ids of contract dependencies for this test setup.
     pub fn contract_dependency_ids(&self) -> impl Iterator<Item = &tx::ContractId> + '_ {
         match self {
@@ -0,0 +1,196 @@
+//! The chain state effects of executing a test, as the difference between the storage before and
+//! after its execution.
+
+use fuel_tx::{self as tx, Receipt};
+use fuel_vm::{interpreter::InterpreterStorage, storage::MemoryStorage};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A contract storage slot written by a test.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageSlotDiff {
+    pub contract_id: tx::ContractId,
+    pub key: tx::Bytes32,
+    /// The value of the slot before the test, or `None` if it was unset.
+    pub before: Option<Vec<u8>>,
+    /// The value of the slot after the test, or `None` if it was cleared.
+    pub after: Option<Vec<u8>>,
+}
+
+/// A contract balance changed by a test.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalanceDiff {
+    pub contract_id: tx::ContractId,
+    pub asset_id: tx::AssetId,
+    pub before: u64,
+    pub after: u64,
+}
+
+/// Coins transferred by a test to an address, becoming an output of the test transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoinOutput {
+    pub to: tx::Address,
+    pub asset_id: tx::AssetId,
+    pub amount: u64,
+}
+
+/// The changes a test made to the chain state.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TestStateDiff {
+    /// The storage slots whose value changed, ordered by contract and key.
+    pub storage: Vec<StorageSlotDiff>,
+    /// The contract balances that changed, ordered by contract and asset.
+    pub balances: Vec<BalanceDiff>,
+    /// The coins transferred to addresses, in the order of the transfers.
+    pub coin_outputs: Vec<CoinOutput>,
+}
+
+impl TestStateDiff {
+    /// Compares the storage `before` and `after` the execution of a test which emitted `receipts`.
+    ///
+    /// Only the balances of the assets that appear in the receipts are compared, as those are the
+    /// only ones a test can change.
+    pub fn new(before: &MemoryStorage, after: &MemoryStorage, receipts: &[Receipt]) -> Self {
+        let before_slots = before
+            .all_contract_state()
+            .map(|(key, value)| {
+                (
+                    (*key.contract_id(), *key.state_key()),
+                    value.as_ref().to_vec(),
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+        let mut after_slots = after
+            .all_contract_state()
+            .map(|(key, value)| {
+                (
+                    (*key.contract_id(), *key.state_key()),
+                    value.as_ref().to_vec(),
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let mut storage = vec![];
+        for ((contract_id, key), before) in before_slots {
+            let after = after_slots.remove(&(contract_id, key));
+            if after.as_ref() != Some(&before) {
+                storage.push(StorageSlotDiff {
+                    contract_id,
+                    key,
+                    before: Some(before),
+                    after,
+                });
+            }
+        }
+        storage.extend(after_slots.into_iter().map(|((contract_id, key), after)| {
+            StorageSlotDiff {
+                contract_id,
+                key,
+                before: None,
+                after: Some(after),
+            }
+        }));
+        storage.sort_by_key(|slot| (slot.contract_id, slot.key));
+
+        let balances = touched_balances(receipts)
+            .into_iter()
+            .filter_map(|(contract_id, asset_id)| {
+                let balance = |storage: &MemoryStorage| {
+                    storage
+                        .contract_asset_id_balance(&contract_id, &asset_id)
+                        .ok()
+                        .flatten()
+                        .unwrap_or_default()
+                };
+                let (before, after) = (balance(before), balance(after));
+                (before != after).then_some(BalanceDiff {
+                    contract_id,
+                    asset_id,
+                    before,
+                    after,
+                })
+            })
+            .collect();
+
+        let coin_outputs = receipts
+            .iter()
+            .filter_map(|receipt| match receipt {
+                Receipt::TransferOut {
+                    to,
+                    amount,
+                    asset_id,
+                    ..
+                } => Some(CoinOutput {
+                    to: *to,
+                    asset_id: *asset_id,
+                    amount: *amount,
+                }),
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            storage,
+            balances,
+            coin_outputs,
+        }
+    }
+
+    /// Whether the test left the chain state unchanged.
+    pub fn is_empty(&self) -> bool {
+        self.storage.is_empty() && self.balances.is_empty() && self.coin_outputs.is_empty()
+    }
+
+    /// The value of the storage slot `key` of `contract_id` after the test, if the test changed it.
+    pub fn storage_slot(&self, contract_id: &tx::ContractId, key: &tx::Bytes32) -> Option<&[u8]> {
+        self.storage
+            .iter()
+            .find(|slot| slot.contract_id == *contract_id && slot.key == *key)
+            .and_then(|slot| slot.after.as_deref())
+    }
+
+    /// The balance of `asset_id` of `contract_id` after the test, if the test changed it.
+    pub fn balance(&self, contract_id: &tx::ContractId, asset_id: &tx::AssetId) -> Option<u64> {
+        self.balances
+            .iter()
+            .find(|balance| balance.contract_id == *contract_id && balance.asset_id == *asset_id)
+            .map(|balance| balance.after)
+    }
+}
+
+/// The contract balances the `receipts` may have changed.
+fn touched_balances(receipts: &[Receipt]) -> BTreeSet<(tx::ContractId, tx::AssetId)> {
+    let mut balances = BTreeSet::new();
+    for receipt in receipts {
+        match receipt {
+            Receipt::Call {
+                id, to, asset_id, ..
+            } => {
+                balances.insert((*id, *asset_id));
+                balances.insert((*to, *asset_id));
+            }
+            Receipt::Transfer {
+                id, to, asset_id, ..
+            } => {
+                balances.insert((*id, *asset_id));
+                balances.insert((*to, *asset_id));
+            }
+            Receipt::TransferOut { id, asset_id, .. } => {
+                balances.insert((*id, *asset_id));
+            }
+            Receipt::Mint {
+                sub_id,
+                contract_id,
+                ..
+            }
+            | Receipt::Burn {
+                sub_id,
+                contract_id,
+                ..
+            } => {
+                balances.insert((*contract_id, contract_id.asset_id(sub_id)));
+            }
+            _ => {}
+        }
+    }
+    balances
+}
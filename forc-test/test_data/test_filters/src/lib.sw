@@ -0,0 +1,8 @@
+library;
+
+mod module;
+
+#[test(tag = "fast")]
+fn test_root() {
+  assert(1 == 1)
+}
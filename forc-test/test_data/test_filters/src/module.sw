@@ -0,0 +1,8 @@
+library;
+
+mod submodule;
+
+#[test(tag = "slow")]
+fn test_module() {
+  assert(1 == 1)
+}
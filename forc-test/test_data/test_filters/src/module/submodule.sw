@@ -0,0 +1,11 @@
+library;
+
+#[test(tag = "slow", tag = "fast")]
+fn test_submodule() {
+  assert(1 == 1)
+}
+
+#[test]
+fn test_untagged() {
+  assert(1 == 1)
+}
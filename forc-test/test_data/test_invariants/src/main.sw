@@ -0,0 +1,53 @@
+contract;
+
+abi Counter {
+    #[storage(read, write)]
+    fn increment();
+    #[storage(read, write)]
+    fn decrement();
+    #[storage(read)]
+    fn count() -> u64;
+}
+
+storage {
+    count: u64 = 0,
+}
+
+impl Counter for Contract {
+    #[storage(read, write)]
+    fn increment() {
+        storage.count.write(storage.count.read() + 1);
+    }
+
+    #[storage(read, write)]
+    fn decrement() {
+        let count = storage.count.read();
+        require(count > 0, "count is zero");
+        storage.count.write(count - 1);
+    }
+
+    #[storage(read)]
+    fn count() -> u64 {
+        storage.count.read()
+    }
+}
+
+#[test]
+fn test_count_starts_at_zero() {
+    assert(abi(Counter, CONTRACT_ID).count() == 0);
+}
+
+#[test(action)]
+fn increment_count() {
+    abi(Counter, CONTRACT_ID).increment();
+}
+
+#[test(action)]
+fn decrement_count() {
+    abi(Counter, CONTRACT_ID).decrement();
+}
+
+#[test(invariant)]
+fn count_below_three() {
+    assert(abi(Counter, CONTRACT_ID).count() < 3);
+}
@@ -0,0 +1,28 @@
+library;
+
+fn spin(iterations: u64) {
+    let mut i = 0;
+    while i < iterations {
+        i += 1;
+    }
+}
+
+#[test(timeout_ms = 100)]
+fn test_hangs() {
+    while true {}
+}
+
+#[test(gas_limit = 1_000)]
+fn test_uses_too_much_gas() {
+    spin(1_000_000);
+}
+
+#[test(gas_limit = "1_000_000")]
+fn test_within_gas_limit() {
+    spin(10);
+}
+
+#[test(should_revert = "0x2a")]
+fn test_reverts_with_hex_code() {
+    revert(42);
+}
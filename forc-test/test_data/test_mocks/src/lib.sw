@@ -0,0 +1,35 @@
+library;
+
+abi Token {
+    fn balance() -> u64;
+}
+
+const TOKEN_ID: b256 = 0x0000000000000000000000000000000000000000000000000000000000000001;
+
+#[test]
+#[mock(contract = "0x0000000000000000000000000000000000000000000000000000000000000001", method = "balance", returns = "0x000000000000002a")]
+fn test_mocked_method() {
+    let token = abi(Token, TOKEN_ID);
+    assert_eq(token.balance(), 42);
+}
+
+#[test]
+#[mock(contract = "0x0000000000000000000000000000000000000000000000000000000000000001", returns = "0x000000000000002a")]
+fn test_mocked_contract() {
+    let token = abi(Token, TOKEN_ID);
+    assert_eq(token.balance(), 42);
+}
+
+#[test(should_revert)]
+#[mock(contract = "0x0000000000000000000000000000000000000000000000000000000000000001", method = "balance", returns = "0x0000000000000029")]
+fn test_mocked_value_is_returned() {
+    let token = abi(Token, TOKEN_ID);
+    assert_eq(token.balance(), 42);
+}
+
+#[test(should_revert)]
+#[mock(contract = "0x0000000000000000000000000000000000000000000000000000000000000001", method = "total_supply", returns = "0x000000000000002a")]
+fn test_other_method_is_called() {
+    let token = abi(Token, TOKEN_ID);
+    assert_eq(token.balance(), 42);
+}
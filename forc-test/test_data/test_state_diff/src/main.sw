@@ -0,0 +1,34 @@
+contract;
+
+abi Counter {
+    #[storage(read, write)]
+    fn increment();
+    #[storage(read)]
+    fn count() -> u64;
+}
+
+storage {
+    count: u64 = 0,
+}
+
+impl Counter for Contract {
+    #[storage(read, write)]
+    fn increment() {
+        storage.count.write(storage.count.read() + 1);
+    }
+
+    #[storage(read)]
+    fn count() -> u64 {
+        storage.count.read()
+    }
+}
+
+#[test]
+fn test_increment() {
+    abi(Counter, CONTRACT_ID).increment();
+}
+
+#[test]
+fn test_count() {
+    assert(abi(Counter, CONTRACT_ID).count() == 0);
+}
@@ -0,0 +1,184 @@
+//! Shell completion support shared between `forc` and its plugins.
+//!
+//! `forc completions` discovers `forc-*` plugins on `PATH` and asks each of them to describe its
+//! command line interface by invoking it with [COMPLETION_METADATA_FLAG]. A plugin that supports
+//! the handshake prints its [CommandInfo] as JSON to stdout and exits, which `forc` then merges
+//! into its own command tree before generating the completion script.
+
+use clap::{Arg, ArgAction, Command, CommandFactory};
+use serde::{Deserialize, Serialize};
+
+/// The flag `forc` passes to a plugin to request its completion metadata.
+pub const COMPLETION_METADATA_FLAG: &str = "--completion-metadata";
+
+/// A serializable description of a command, its arguments and its subcommands.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CommandInfo {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub about: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<ArgInfo>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subcommands: Vec<CommandInfo>,
+}
+
+/// A serializable description of a single command line argument.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ArgInfo {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub long: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short: Option<char>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+    /// Whether the argument expects a value, as opposed to being a flag.
+    #[serde(default)]
+    pub takes_value: bool,
+    /// The finite set of values the argument accepts, if any.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub possible_values: Vec<String>,
+}
+
+impl CommandInfo {
+    /// Describe the given `clap` command, recursively including its subcommands.
+    pub fn new(cmd: &Command) -> Self {
+        CommandInfo {
+            name: cmd.get_name().to_string(),
+            about: cmd.get_about().map(|about| about.to_string()),
+            args: cmd
+                .get_arguments()
+                .filter(|arg| !arg.is_hide_set())
+                .map(ArgInfo::new)
+                .collect(),
+            subcommands: cmd
+                .get_subcommands()
+                .filter(|subcmd| !subcmd.is_hide_set())
+                .map(CommandInfo::new)
+                .collect(),
+        }
+    }
+
+    /// Rebuild a `clap` command from this description, suitable for completion generation.
+    pub fn to_clap(&self) -> Command {
+        let mut cmd = Command::new(self.name.clone());
+        if let Some(about) = &self.about {
+            cmd = cmd.about(about.clone());
+        }
+        for arg in &self.args {
+            cmd = cmd.arg(arg.to_clap());
+        }
+        for subcmd in &self.subcommands {
+            cmd = cmd.subcommand(subcmd.to_clap());
+        }
+        cmd
+    }
+}
+
+impl ArgInfo {
+    fn new(arg: &Arg) -> Self {
+        ArgInfo {
+            name: arg.get_id().to_string(),
+            long: arg.get_long().map(str::to_string),
+            short: arg.get_short(),
+            help: arg.get_help().map(|help| help.to_string()),
+            takes_value: arg.get_action().takes_values(),
+            possible_values: arg
+                .get_possible_values()
+                .into_iter()
+                .filter(|value| !value.is_hide_set())
+                .map(|value| value.get_name().to_string())
+                .collect(),
+        }
+    }
+
+    fn to_clap(&self) -> Arg {
+        let mut arg = Arg::new(self.name.clone());
+        if let Some(long) = &self.long {
+            arg = arg.long(long.clone());
+        }
+        if let Some(short) = self.short {
+            arg = arg.short(short);
+        }
+        if let Some(help) = &self.help {
+            arg = arg.help(help.clone());
+        }
+        if !self.takes_value {
+            arg = arg.action(ArgAction::SetTrue);
+        } else if !self.possible_values.is_empty() {
+            arg = arg.value_parser(self.possible_values.clone());
+        }
+        arg
+    }
+}
+
+/// Print the completion metadata of `C` and exit if the process was invoked with
+/// [COMPLETION_METADATA_FLAG] as its only argument.
+///
+/// Plugins should call this at the very start of `main`, before parsing their arguments.
+pub fn handle_completion_metadata_request<C: CommandFactory>() {
+    let mut args = std::env::args().skip(1);
+    if args.next().as_deref() == Some(COMPLETION_METADATA_FLAG) && args.next().is_none() {
+        let info = CommandInfo::new(&C::command());
+        match serde_json::to_string(&info) {
+            Ok(json) => {
+                println!("{json}");
+                std::process::exit(0);
+            }
+            Err(_) => std::process::exit(1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, clap::Parser)]
+    #[clap(name = "forc-example", about = "An example plugin")]
+    struct Example {
+        /// Path to the project.
+        #[clap(short, long)]
+        path: Option<String>,
+        /// Run in check mode.
+        #[clap(long)]
+        check: bool,
+        #[clap(subcommand)]
+        command: Option<ExampleCommand>,
+    }
+
+    #[derive(Debug, clap::Subcommand)]
+    enum ExampleCommand {
+        /// Do the thing.
+        Run {
+            #[clap(long, value_parser = ["fast", "slow"])]
+            mode: String,
+        },
+    }
+
+    #[test]
+    fn command_info_round_trips_through_json() {
+        let info = CommandInfo::new(&Example::command());
+        assert_eq!(info.name, "forc-example");
+        assert_eq!(info.about.as_deref(), Some("An example plugin"));
+
+        let path = info.args.iter().find(|arg| arg.name == "path").unwrap();
+        assert_eq!(path.short, Some('p'));
+        assert!(path.takes_value);
+        let check = info.args.iter().find(|arg| arg.name == "check").unwrap();
+        assert!(!check.takes_value);
+
+        let run = &info.subcommands[0];
+        assert_eq!(run.name, "run");
+        assert_eq!(run.args[0].possible_values, vec!["fast", "slow"]);
+
+        let json = serde_json::to_string(&info).unwrap();
+        let parsed: CommandInfo = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, info);
+        assert_eq!(
+            CommandInfo::new(&parsed.to_clap()).subcommands,
+            info.subcommands
+        );
+    }
+}
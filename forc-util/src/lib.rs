@@ -24,6 +24,7 @@ use sway_types::{LineCol, LineColRange, SourceEngine, Span};
 use sway_utils::constants;
 
 pub mod bytecode;
+pub mod completion;
 pub mod fs_locking;
 pub mod restricted;
 
@@ -279,9 +280,16 @@ pub fn user_forc_directory() -> PathBuf {
         .join(constants::USER_FORC_DIRECTORY)
 }
 
-/// The location at which `forc` will checkout git repositories.
+/// The location at which `forc` will checkout git repositories, `$HOME/.forc/git/checkouts` by
+/// default.
+///
+/// The checkouts are shared by all projects. The location can be overridden with the
+/// `FORC_GIT_CHECKOUTS_DIR` environment variable, e.g. to cache the checkouts between CI runs.
 pub fn git_checkouts_directory() -> PathBuf {
-    user_forc_directory().join("git").join("checkouts")
+    match std::env::var_os(constants::FORC_GIT_CHECKOUTS_DIR) {
+        Some(dir) => PathBuf::from(dir),
+        None => user_forc_directory().join("git").join("checkouts"),
+    }
 }
 
 /// Given a path to a directory we wish to lock, produce a path for an associated lock file.
@@ -367,7 +375,7 @@ pub fn print_warnings(
     if !terse_mode {
         warnings
             .iter()
-            .for_each(|w| format_diagnostic(&w.to_diagnostic(source_engine)));
+            .for_each(|w| format_diagnostic(&w.to_diagnostic(source_engine), w.code().as_str()));
     }
 
     println_yellow_err(&format!(
@@ -395,21 +403,19 @@ pub fn print_on_failure(
 
     if !terse_mode {
         if reverse_results {
-            warnings
-                .iter()
-                .rev()
-                .for_each(|w| format_diagnostic(&w.to_diagnostic(source_engine)));
-            errors
-                .iter()
-                .rev()
-                .for_each(|e| format_diagnostic(&e.to_diagnostic(source_engine)));
+            warnings.iter().rev().for_each(|w| {
+                format_diagnostic(&w.to_diagnostic(source_engine), w.code().as_str())
+            });
+            errors.iter().rev().for_each(|e| {
+                format_diagnostic(&e.to_diagnostic(source_engine), e.code().as_str())
+            });
         } else {
-            warnings
-                .iter()
-                .for_each(|w| format_diagnostic(&w.to_diagnostic(source_engine)));
-            errors
-                .iter()
-                .for_each(|e| format_diagnostic(&e.to_diagnostic(source_engine)));
+            warnings.iter().for_each(|w| {
+                format_diagnostic(&w.to_diagnostic(source_engine), w.code().as_str())
+            });
+            errors.iter().for_each(|e| {
+                format_diagnostic(&e.to_diagnostic(source_engine), e.code().as_str())
+            });
         }
     }
 
@@ -448,13 +454,12 @@ pub fn create_diagnostics_renderer() -> Renderer {
         )
 }
 
-fn format_diagnostic(diagnostic: &Diagnostic) {
-    /// Temporary switch for testing the feature.
-    /// Keep it false until we decide to fully support the diagnostic codes.
-    const SHOW_DIAGNOSTIC_CODE: bool = false;
-
+/// Prints the `diagnostic` along with its stable `code`, e.g. `E4001`.
+///
+/// The extended explanation of the `code` can be printed with `forc explain`.
+fn format_diagnostic(diagnostic: &Diagnostic, code: &str) {
     if diagnostic.is_old_style() {
-        format_old_style_diagnostic(diagnostic.issue());
+        format_old_style_diagnostic(diagnostic.issue(), code);
         return;
     }
 
@@ -463,11 +468,7 @@ fn format_diagnostic(diagnostic: &Diagnostic) {
 
     let snippet_title = Some(Annotation {
         label: Some(label.as_str()),
-        id: if SHOW_DIAGNOSTIC_CODE {
-            diagnostic.reason().map(|reason| reason.code())
-        } else {
-            None
-        },
+        id: Some(code),
         annotation_type: diagnostic_level_to_annotation_type(diagnostic.level()),
     });
 
@@ -504,7 +505,7 @@ fn format_diagnostic(diagnostic: &Diagnostic) {
         Level::Error => tracing::error!("{}\n____\n", renderer.render(snippet)),
     }
 
-    fn format_old_style_diagnostic(issue: &Issue) {
+    fn format_old_style_diagnostic(issue: &Issue, code: &str) {
         let annotation_type = label_type_to_annotation_type(issue.label_type());
 
         let snippet_title = Some(Annotation {
@@ -513,7 +514,7 @@ fn format_diagnostic(diagnostic: &Diagnostic) {
             } else {
                 Some(issue.text())
             },
-            id: None,
+            id: Some(code),
             annotation_type,
         });
 
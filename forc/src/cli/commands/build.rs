@@ -1,12 +1,18 @@
 use crate::{cli, ops::forc_build};
 use clap::Parser;
+use forc_pkg::message::MessageFormat;
 use forc_util::ForcResult;
+use sway_core::BuildTarget;
 
 forc_util::cli_examples! {
    crate::cli::Opt {
         [ Compile the current projectx => "forc build" ]
         [ Compile the current project from a different path => "forc build --path <PATH>" ]
         [ Compile the current project without updating dependencies => "forc build --path <PATH> --locked" ]
+        [ Compile the current project and report the progress and artifacts as JSON => "forc build --message-format json" ]
+        [ Compile the current project for multiple targets and build profiles => "forc build --targets fuel,evm --profiles debug,release" ]
+        [ Compile a module from its textual IR instead of the project => "forc build --ir-input <PATH>" ]
+        [ Compile the current project and generate an upgradeable proxy for each contract => "forc build --proxy" ]
     }
 }
 
@@ -24,6 +30,10 @@ forc_util::cli_examples! {
 ///
 /// - `contract` and `library` projects will also produce the public ABI in JSON format
 ///   `<project-name>-abi.json`.
+///
+/// - projects calling other contracts will also produce a description of each external contract
+///   call, with its target, selector, payability and storage effects, in JSON format
+///   `<project-name>-contract_calls.json`.
 #[derive(Debug, Default, Parser)]
 #[clap(bin_name = "forc build", version, after_help = help())]
 pub struct Command {
@@ -33,11 +43,61 @@ pub struct Command {
     #[clap(long)]
     pub tests: bool,
 
+    /// Build for each of the given targets in one invocation, e.g. `fuel,evm`.
+    ///
+    /// The artifacts of each combination of target and build profile are written to
+    /// `<output-directory>/<target>/<profile>`.
+    #[clap(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        conflicts_with = "build_target"
+    )]
+    pub targets: Vec<BuildTarget>,
+
+    /// Build with each of the given build profiles in one invocation, e.g. `debug,release`.
+    ///
+    /// The artifacts of each combination of target and build profile are written to
+    /// `<output-directory>/<target>/<profile>`.
+    #[clap(long, value_delimiter = ',', conflicts_with_all = ["build_profile", "release"])]
+    pub profiles: Vec<String>,
+
+    /// The format of the build messages, `human` or `json`.
+    ///
+    /// With `json`, a JSON object per line describing the compiled packages, diagnostics,
+    /// written artifacts and the build result is printed to stdout, while the human readable
+    /// output is printed to stderr.
+    #[clap(long, default_value_t = MessageFormat::Human)]
+    pub message_format: MessageFormat,
+
+    /// Build the textual IR module at the given path, e.g. as printed with `--ir final`, instead
+    /// of the package.
+    ///
+    /// The IR passes of the selected build profile and the ASM generation are run on the module,
+    /// and its bytecode is written to `<output-directory>/<ir-file-stem>.bin`.
+    #[clap(long, value_name = "PATH", conflicts_with_all = ["targets", "profiles", "tests"])]
+    pub ir_input: Option<String>,
+
+    /// Also generate and build a minimal upgradeable proxy contract for each contract.
+    ///
+    /// The proxy forwards the calls to the methods it doesn't implement itself to its target
+    /// implementation contract, which only the owner of the proxy can update. Its artifacts are
+    /// written next to the ones of the contract, along with `<project-name>-proxy-wiring.json`,
+    /// which lists the artifacts of both contracts and the order in which to deploy them.
+    #[clap(long, conflicts_with_all = ["targets", "profiles", "ir_input"])]
+    pub proxy: bool,
+
     #[clap(flatten)]
     pub experimental: sway_features::CliFields,
 }
 
 pub(crate) fn exec(command: Command) -> ForcResult<()> {
-    forc_build::build(command)?;
+    if command.ir_input.is_some() {
+        forc_build::build_ir(command)?;
+    } else if command.targets.is_empty() && command.profiles.is_empty() {
+        forc_build::build(command)?;
+    } else {
+        forc_build::build_matrix(command)?;
+    }
     Ok(())
 }
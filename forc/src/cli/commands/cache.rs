@@ -0,0 +1,49 @@
+use crate::ops::forc_cache;
+use clap::{Parser, Subcommand};
+use forc_util::ForcResult;
+
+forc_util::cli_examples! {
+    crate::cli::Opt {
+        [List the cached git dependencies => "forc cache list"]
+        [Print the disk space used by the cache => "forc cache size"]
+        [Remove the cached checkouts of a single package => "forc cache clear std"]
+        [Remove all cached checkouts => "forc cache clear"]
+    }
+}
+
+/// Inspect and clear the cache of git dependencies.
+///
+/// Git dependencies are checked out once per commit into the cache and shared by all projects.
+/// The cache is located at `~/.forc/git/checkouts` by default, and can be moved by setting the
+/// `FORC_GIT_CHECKOUTS_DIR` environment variable, e.g. to keep it between CI runs.
+#[derive(Debug, Parser)]
+#[clap(name = "forc cache", version, after_help = help())]
+pub struct Command {
+    #[clap(subcommand)]
+    pub command: CacheSubcommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CacheSubcommand {
+    /// List the cached checkouts of git dependencies.
+    List,
+    /// Print the disk space used by the cached checkouts.
+    Size,
+    /// Remove cached checkouts, so that they are fetched again the next time they are required.
+    Clear(ClearCommand),
+}
+
+#[derive(Debug, Parser)]
+pub struct ClearCommand {
+    /// Only remove the checkouts of the package with this name.
+    pub package: Option<String>,
+}
+
+pub fn exec(command: Command) -> ForcResult<()> {
+    match command.command {
+        CacheSubcommand::List => forc_cache::list()?,
+        CacheSubcommand::Size => forc_cache::size()?,
+        CacheSubcommand::Clear(clear) => forc_cache::clear(clear)?,
+    }
+    Ok(())
+}
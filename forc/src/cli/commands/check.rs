@@ -1,7 +1,7 @@
 use crate::ops::forc_check;
 use clap::Parser;
 use forc_pkg::source::IPFSNode;
-use forc_util::{forc_result_bail, ForcResult};
+use forc_util::{forc_result_bail, print_on_failure, print_warnings, ForcResult};
 use sway_core::{BuildTarget, Engines};
 
 forc_util::cli_examples! {
@@ -9,6 +9,7 @@ forc_util::cli_examples! {
         [ Check the current project => "forc check" ]
         [ Check the current project with a different path => "forc check --path <PATH>" ]
         [ Check the current project without updating dependencies => "forc check --locked" ]
+        [ Check all the members of the workspace, including their tests => "forc check --all-targets --tests" ]
     }
 }
 
@@ -37,8 +38,16 @@ pub struct Command {
     #[clap(long = "terse", short = 't')]
     pub terse_mode: bool,
     /// Disable checking unit tests.
-    #[clap(long = "disable-tests")]
+    #[clap(long = "disable-tests", overrides_with = "tests")]
     pub disable_tests: bool,
+    /// Check unit tests. This is the default, and overrides an earlier `--disable-tests`.
+    #[clap(long, overrides_with = "disable_tests")]
+    pub tests: bool,
+    /// Check all the members of the workspace and report the diagnostics of every member instead
+    /// of only the last one in compilation order. The tests of the members are checked unless
+    /// `--disable-tests` is given.
+    #[clap(long)]
+    pub all_targets: bool,
     /// The IPFS Node to use for fetching IPFS sources.
     ///
     /// Possible values: PUBLIC, LOCAL, <GATEWAY_URL>
@@ -51,9 +60,38 @@ pub struct Command {
 
 pub(crate) fn exec(command: Command) -> ForcResult<()> {
     let engines = Engines::default();
+    if command.all_targets {
+        return check_all_targets(command, &engines);
+    }
     let res = forc_check::check(command, &engines)?;
     if res.0.is_none() {
         forc_result_bail!("unable to type check");
     }
     Ok(())
 }
+
+fn check_all_targets(command: Command, engines: &Engines) -> ForcResult<()> {
+    let terse_mode = command.terse_mode;
+    let members = forc_check::check_all(command, engines)?;
+    let mut failed = vec![];
+    for member in members {
+        let (errors, warnings) = member.handler.consume();
+        match member.program {
+            Some(program) if errors.is_empty() => print_warnings(
+                engines.se(),
+                terse_mode,
+                &member.name,
+                &warnings,
+                &program.kind.tree_type(),
+            ),
+            _ => {
+                print_on_failure(engines.se(), terse_mode, &warnings, &errors, false);
+                failed.push(member.name);
+            }
+        }
+    }
+    if !failed.is_empty() {
+        forc_result_bail!("unable to type check: {}", failed.join(", "));
+    }
+    Ok(())
+}
@@ -2,7 +2,29 @@ use std::fmt::Display;
 
 use clap::{Command as ClapCommand, CommandFactory, Parser};
 use clap_complete::{generate, Generator, Shell};
-use forc_util::ForcResult;
+use forc_util::{
+    completion::{CommandInfo, COMPLETION_METADATA_FLAG},
+    ForcResult,
+};
+use std::{
+    collections::HashSet,
+    io::Read,
+    path::Path,
+    process, thread,
+    time::{Duration, Instant},
+};
+use tracing::debug;
+
+/// How long a plugin is given to describe its command line interface before it is killed and only
+/// completed by name.
+const PLUGIN_COMPLETION_METADATA_TIMEOUT: Duration = Duration::from_secs(2);
+
+forc_util::cli_examples! {
+    crate::cli::Opt {
+        [ Generate completions for bash => "forc completions bash" ]
+        [ Generate completions for zsh using the target flag => "forc completions --target zsh" ]
+    }
+}
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, clap::ValueEnum)]
 enum Target {
@@ -38,20 +60,43 @@ impl Display for Target {
 }
 
 /// Generate tab-completion scripts for your shell
+///
+/// Completions cover the built-in subcommands as well as any `forc-*` plugins found on `PATH`
+/// or in `~/.forc/bin`.
+/// Plugins that answer the `--completion-metadata` handshake get completions for their own
+/// arguments and subcommands, all others are completed by name only.
 #[derive(Debug, Parser)]
+#[clap(after_help = help())]
 pub struct Command {
     /// Specify shell to enable tab-completion for
     ///
-    /// [possible values: zsh, bash, fish, powershell, elvish]
+    /// [possible values: zsh, bash, fish, powershell, elvish, fig]
     ///
     /// For more info: https://fuellabs.github.io/sway/latest/forc/commands/forc_completions.html
+    #[clap(
+        value_enum,
+        required_unless_present = "target",
+        conflicts_with = "target"
+    )]
+    shell: Option<Target>,
+    /// Specify shell to enable tab-completion for. Equivalent to the positional argument.
     #[clap(short = 'T', long, value_enum)]
-    target: Target,
+    target: Option<Target>,
+    /// Only generate completions for the built-in subcommands, skipping plugin discovery.
+    #[clap(long)]
+    no_plugins: bool,
 }
 
 pub(crate) fn exec(command: Command) -> ForcResult<()> {
     let mut cmd = super::super::Opt::command();
-    match command.target {
+    if !command.no_plugins {
+        cmd = with_plugin_subcommands(cmd);
+    }
+    let target = command
+        .shell
+        .or(command.target)
+        .expect("clap requires either `shell` or `--target`");
+    match target {
         Target::Fig => print_completions(clap_complete_fig::Fig, &mut cmd),
         Target::Bash => print_completions(Shell::Bash, &mut cmd),
         Target::Elvish => print_completions(Shell::Elvish, &mut cmd),
@@ -66,10 +111,142 @@ fn print_completions<G: Generator>(gen: G, cmd: &mut ClapCommand) {
     generate(gen, cmd, cmd.get_name().to_string(), &mut std::io::stdout());
 }
 
+/// Add a subcommand to `cmd` for every `forc-*` plugin available on `PATH`.
+///
+/// Plugins shadowed by a built-in subcommand or by a plugin found earlier on `PATH` are skipped,
+/// mirroring the resolution `forc` uses when executing them.
+fn with_plugin_subcommands(mut cmd: ClapCommand) -> ClapCommand {
+    let mut seen: HashSet<String> = cmd
+        .get_subcommands()
+        .map(|subcmd| subcmd.get_name().to_string())
+        .collect();
+    for path in crate::cli::plugin::find_all() {
+        let Some(name) = plugin_subcommand_name(&path) else {
+            continue;
+        };
+        if !seen.insert(name.clone()) {
+            continue;
+        }
+        let subcmd = match plugin_completion_metadata(&path) {
+            Some(info) => CommandInfo { name, ..info }.to_clap(),
+            None => ClapCommand::new(name).about(format!("Run the `{}` plugin", path.display())),
+        };
+        cmd = cmd.subcommand(subcmd);
+    }
+    cmd
+}
+
+/// The name of the subcommand a plugin is invoked through, e.g. `fmt` for `forc-fmt`.
+fn plugin_subcommand_name(path: &Path) -> Option<String> {
+    let stem = path.file_stem()?.to_str()?;
+    stem.strip_prefix("forc-")
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+}
+
+/// Ask a plugin to describe its command line interface.
+///
+/// Returns `None` if the plugin does not support the handshake, or doesn't answer within
+/// [PLUGIN_COMPLETION_METADATA_TIMEOUT], in which case it is only completed by name.
+fn plugin_completion_metadata(path: &Path) -> Option<CommandInfo> {
+    let mut child = process::Command::new(path)
+        .arg(COMPLETION_METADATA_FLAG)
+        .stdin(process::Stdio::null())
+        .stdout(process::Stdio::piped())
+        .stderr(process::Stdio::null())
+        .spawn()
+        .ok()?;
+    // Read the output on a separate thread, so that a plugin filling the pipe can't block.
+    let mut stdout = child.stdout.take()?;
+    let reader = thread::spawn(move || {
+        let mut buf = vec![];
+        stdout.read_to_end(&mut buf).map(|_| buf)
+    });
+    let start = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if start.elapsed() < PLUGIN_COMPLETION_METADATA_TIMEOUT => {
+                thread::sleep(Duration::from_millis(10));
+            }
+            Ok(None) => {
+                debug!(
+                    "Plugin {} did not describe its interface within {:?}",
+                    path.display(),
+                    PLUGIN_COMPLETION_METADATA_TIMEOUT
+                );
+                let _ = child.kill();
+                let _ = child.wait();
+                return None;
+            }
+            Err(_) => return None,
+        }
+    };
+    let stdout = reader.join().ok()?.ok()?;
+    if !status.success() {
+        return None;
+    }
+    match serde_json::from_slice(&stdout) {
+        Ok(info) => Some(info),
+        Err(err) => {
+            debug!(
+                "Ignoring completion metadata of {}: {}",
+                path.display(),
+                err
+            );
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::cli::{Forc, Opt};
+    use std::path::PathBuf;
+
+    #[test]
+    fn plugin_names_strip_prefix() {
+        let name = |path: &str| plugin_subcommand_name(&PathBuf::from(path));
+        assert_eq!(name("/usr/bin/forc-fmt"), Some("fmt".to_string()));
+        assert_eq!(name("/usr/bin/forc-deploy.exe"), Some("deploy".to_string()));
+        assert_eq!(name("/usr/bin/forc-"), None);
+        assert_eq!(name("/usr/bin/cargo"), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unresponsive_plugin_is_killed() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!("forc-completions-{}", process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let plugin = dir.join("forc-unresponsive");
+        std::fs::write(&plugin, "#!/bin/sh\nsleep 60\n").unwrap();
+        std::fs::set_permissions(&plugin, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let start = Instant::now();
+        assert!(plugin_completion_metadata(&plugin).is_none());
+        assert!(start.elapsed() < PLUGIN_COMPLETION_METADATA_TIMEOUT * 5);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn plugin_metadata_is_merged_into_command() {
+        let info = CommandInfo {
+            name: "forc-example".to_string(),
+            about: Some("An example plugin".to_string()),
+            ..Default::default()
+        };
+        let cmd = Opt::command().subcommand(
+            CommandInfo {
+                name: "example".to_string(),
+                ..info
+            }
+            .to_clap(),
+        );
+        assert!(cmd.find_subcommand("example").is_some());
+    }
 
     #[test]
     fn bash() {
@@ -0,0 +1,30 @@
+use crate::ops::forc_explain;
+use clap::Parser;
+use forc_util::ForcResult;
+
+forc_util::cli_examples! {
+    crate::cli::Opt {
+        [Explain an error code => "forc explain E3001"]
+        [List all the diagnostic codes => "forc explain --list"]
+    }
+}
+
+/// Print the extended explanation of a diagnostic code.
+///
+/// Every error and warning reported by the compiler has a stable code, shown next to its
+/// severity, e.g. `error[E3001]`. The explanation describes the diagnostic in more detail, along
+/// with examples of the erroneous code and how to fix it.
+#[derive(Debug, Parser)]
+#[clap(bin_name = "forc explain", version, after_help = help())]
+pub struct Command {
+    /// The diagnostic code to explain, e.g. `E3001`.
+    #[clap(required_unless_present = "list")]
+    pub code: Option<String>,
+    /// List all the diagnostic codes, along with the names of their diagnostics.
+    #[clap(long, conflicts_with = "code")]
+    pub list: bool,
+}
+
+pub(crate) fn exec(cmd: Command) -> ForcResult<()> {
+    forc_explain::explain(cmd).map_err(|e| e.into())
+}
@@ -1,4 +1,4 @@
-use crate::ops::forc_init;
+use crate::ops::{forc_init, forc_template};
 use clap::Parser;
 use forc_util::ForcResult;
 
@@ -8,6 +8,7 @@ forc_util::cli_examples! {
         [Initialize a new Forc project as workspace => "forc init --path <PATH> --workspace"]
         [Initialize a new Forc project with a predicate => "forc init --path <PATH> --predicate"]
         [Initialize a new Forc library project => "forc init --path <PATH> --library"]
+        [Initialize a new Forc project from a git template => "forc init --path <PATH> --template <URL>"]
     }
 }
 
@@ -36,6 +37,20 @@ pub struct Command {
     /// Set the package name. Defaults to the directory name
     #[clap(long)]
     pub name: Option<String>,
+    /// Initialize the project from a template in the git repository at this URL.
+    #[clap(long, conflicts_with_all = ["contract", "script", "predicate", "library", "workspace"])]
+    pub template: Option<String>,
+    /// The name of the template within the repository, if it contains more than one.
+    #[clap(long, requires = "template")]
+    pub template_name: Option<String>,
+    /// Set the value of a placeholder of the template, as `NAME=VALUE`, instead of prompting for it.
+    #[clap(
+        long = "define",
+        short = 'd',
+        requires = "template",
+        value_parser = forc_template::parse_placeholder_definition
+    )]
+    pub defines: Vec<(String, String)>,
 }
 
 pub(crate) fn exec(command: Command) -> ForcResult<()> {
@@ -0,0 +1,35 @@
+use crate::ops::forc_migrate;
+use clap::Parser;
+use forc_util::ForcResult;
+
+forc_util::cli_examples! {
+    crate::cli::Opt {
+        [Migrate the project in the current directory => "forc migrate"]
+        [Show the changes the migrations would make, without applying them => "forc migrate --dry-run"]
+        [Apply only some of the migrations => "forc migrate --only slice-type-syntax"]
+        [List the available migrations => "forc migrate --list"]
+    }
+}
+
+/// Rewrite the code of a project for the breaking changes of the latest Sway release.
+#[derive(Debug, Parser)]
+#[clap(bin_name = "forc migrate", version, after_help = help())]
+pub struct Command {
+    /// Path to the project, if not specified, current working directory will be used.
+    #[clap(short, long)]
+    pub path: Option<String>,
+    /// Print the changes that would be made, without writing them.
+    #[clap(long)]
+    pub dry_run: bool,
+    /// Only apply the migrations with these identifiers. All migrations are applied by default.
+    #[clap(long, value_delimiter = ',')]
+    pub only: Option<Vec<String>>,
+    /// List the available migrations, without applying them.
+    #[clap(long)]
+    pub list: bool,
+}
+
+pub(crate) fn exec(command: Command) -> ForcResult<()> {
+    forc_migrate::migrate(command)?;
+    Ok(())
+}
@@ -1,14 +1,18 @@
 pub mod addr2line;
 pub mod build;
+pub mod cache;
 pub mod check;
 pub mod clean;
 pub mod completions;
 pub mod contract_id;
+pub mod explain;
 pub mod init;
+pub mod migrate;
 pub mod new;
 pub mod parse_bytecode;
 pub mod plugins;
 pub mod predicate_root;
+pub mod storage_diff;
 pub mod template;
 pub mod test;
 pub mod update;
@@ -1,6 +1,6 @@
-use crate::cli::PluginsCommand;
+use crate::{cli::PluginsCommand, ops::forc_plugins};
 use anyhow::anyhow;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use forc_tracing::println_warning;
 use forc_util::ForcResult;
 use std::{
@@ -15,15 +15,41 @@ forc_util::cli_examples! {
         [ List all plugins with their paths => "forc plugins --paths" ]
         [ List all plugins with their descriptions => "forc plugins --describe" ]
         [ List all plugins with their paths and descriptions => "forc plugins --paths --describe" ]
+        [ List all plugins with their paths descriptions and sources => "forc plugins list --verbose" ]
+        [ Install a plugin from a git repository => "forc plugins install https://github.com/FuelLabs/example-plugin" ]
+        [ Install a plugin from a specific tag => "forc plugins install https://github.com/FuelLabs/example-plugin --tag v0.1.0" ]
+        [ Update all plugins installed from git => "forc plugins update" ]
+        [ Update a single plugin => "forc plugins update example" ]
     }
 }
 
-/// Find all forc plugins available via `PATH`.
+/// Find, install and update forc plugins.
 ///
-/// Prints information about each discovered plugin.
+/// Without a subcommand, prints information about each plugin available via `PATH` or
+/// installed into `~/.forc/bin`.
 #[derive(Debug, Parser)]
-#[clap(name = "forc plugins", about = "List all forc plugins", version, after_help = help())]
+#[clap(name = "forc plugins", about = "List and manage forc plugins", version, after_help = help())]
 pub struct Command {
+    #[clap(subcommand)]
+    command: Option<PluginsSubcommand>,
+    #[clap(flatten)]
+    list: ListCommand,
+}
+
+#[derive(Debug, Subcommand)]
+enum PluginsSubcommand {
+    /// List all forc plugins available via `PATH` or installed into `~/.forc/bin`.
+    ///
+    /// With `--verbose`, also prints the path, description and installation source of each plugin.
+    List(ListCommand),
+    /// Build a plugin from a git repository and install it into the forc bin directory.
+    Install(InstallCommand),
+    /// Rebuild plugins previously installed with `forc plugins install` from their source.
+    Update(UpdateCommand),
+}
+
+#[derive(Debug, Default, Parser)]
+pub struct ListCommand {
     /// Prints the absolute path to each discovered plugin.
     #[clap(long = "paths", short = 'p')]
     print_full_path: bool,
@@ -32,6 +58,30 @@ pub struct Command {
     describe: bool,
 }
 
+#[derive(Debug, Parser)]
+pub struct InstallCommand {
+    /// URL of the git repository containing the plugin.
+    pub git: String,
+    /// The cargo package to install, required if the repository contains more than one.
+    pub package: Option<String>,
+    /// Branch to install from.
+    #[clap(long, conflicts_with_all = ["tag", "rev"])]
+    pub branch: Option<String>,
+    /// Tag to install from.
+    #[clap(long, conflicts_with = "rev")]
+    pub tag: Option<String>,
+    /// Specific commit to install from.
+    #[clap(long)]
+    pub rev: Option<String>,
+}
+
+#[derive(Debug, Parser)]
+pub struct UpdateCommand {
+    /// The plugin to update, e.g. `fmt` or `forc-fmt`. If not set, all plugins installed with
+    /// `forc plugins install` are updated.
+    pub plugin: Option<String>,
+}
+
 fn get_file_name(path: &Path) -> String {
     if let Some(path_str) = path.file_name().and_then(|path_str| path_str.to_str()) {
         path_str.to_owned()
@@ -40,11 +90,28 @@ fn get_file_name(path: &Path) -> String {
     }
 }
 
-pub(crate) fn exec(command: PluginsCommand) -> ForcResult<()> {
-    let PluginsCommand {
+pub(crate) fn exec(command: PluginsCommand, verbosity: u8) -> ForcResult<()> {
+    match command.command {
+        Some(PluginsSubcommand::List(list)) => exec_list(list, verbosity),
+        Some(PluginsSubcommand::Install(install)) => Ok(forc_plugins::install(install)?),
+        Some(PluginsSubcommand::Update(update)) => Ok(forc_plugins::update(update)?),
+        None => exec_list(command.list, verbosity),
+    }
+}
+
+fn exec_list(command: ListCommand, verbosity: u8) -> ForcResult<()> {
+    let verbose = verbosity > 0;
+    let ListCommand {
         print_full_path,
         describe,
     } = command;
+    let print_full_path = print_full_path || verbose;
+    let describe = describe || verbose;
+    let installed = if verbose {
+        forc_plugins::PluginsManifest::from_file(&forc_plugins::PluginsManifest::path())?
+    } else {
+        forc_plugins::PluginsManifest::default()
+    };
 
     let mut plugins = crate::cli::plugin::find_all()
         .map(|path| {
@@ -72,6 +139,9 @@ pub(crate) fn exec(command: PluginsCommand) -> ForcResult<()> {
     info!("Installed Plugins:");
     for plugin in plugins {
         info!("{}", plugin.2);
+        if let Some(installed) = installed.find_by_binary(&plugin.0) {
+            info!("    installed from {}", installed.source_description());
+        }
         if plugin.1.len() > 1 {
             println_warning(&format!("Multiple paths found for {}", plugin.0));
             for path in plugin.1 {
@@ -0,0 +1,42 @@
+use crate::{
+    cli::shared::{BuildProfile, Pkg},
+    ops::forc_storage_diff,
+};
+use clap::Parser;
+use forc_util::ForcResult;
+use std::path::PathBuf;
+
+forc_util::cli_examples! {
+    crate::cli::Opt {
+        [Compare the storage of the current contract with a previous version => "forc storage-diff out/old/my-contract-storage_layout.json"]
+        [Compare two storage layouts => "forc storage-diff old-storage_layout.json --new new-storage_layout.json"]
+    }
+}
+
+/// Check that an upgraded contract is compatible with the storage of a previous version.
+///
+/// Compares the storage layout of the previous version, as emitted by `forc build` into the
+/// `<contract-name>-storage_layout.json` file, with the layout of the contract at the given
+/// path, or with another layout file. Fails if any storage field changed its type, key or size,
+/// or if a field occupies a slot previously occupied by a different field.
+#[derive(Debug, Parser)]
+#[clap(bin_name = "forc storage-diff", version, after_help = help())]
+pub struct Command {
+    /// Path to the storage layout of the previous version of the contract.
+    pub old: PathBuf,
+    /// Path to the storage layout to compare with. If not provided, the contract is built and
+    /// its storage layout is used.
+    #[clap(long)]
+    pub new: Option<PathBuf>,
+    #[clap(flatten)]
+    pub pkg: Pkg,
+    #[clap(flatten)]
+    pub build_profile: BuildProfile,
+
+    #[clap(flatten)]
+    pub experimental: sway_features::CliFields,
+}
+
+pub(crate) fn exec(cmd: Command) -> ForcResult<()> {
+    forc_storage_diff::storage_diff(cmd).map_err(|e| e.into())
+}
@@ -5,6 +5,7 @@ use forc_util::ForcResult;
 forc_util::cli_examples! {
     crate::cli::Opt {
         [Create a new Forc project from an option template => "forc template new-path --template-name option"]
+        [Create a new Forc project from a template, setting one of its placeholders => "forc template new-path --url <URL> --define description=\"My contract\""]
     }
 }
 
@@ -20,6 +21,10 @@ pub struct Command {
     #[clap(long, short)]
     pub template_name: Option<String>,
 
+    /// Set the value of a placeholder of the template, as `NAME=VALUE`, instead of prompting for it.
+    #[clap(long = "define", short = 'd', value_parser = forc_template::parse_placeholder_definition)]
+    pub defines: Vec<(String, String)>,
+
     /// The name of the project that will be created
     pub project_name: String,
 }
@@ -2,9 +2,13 @@ use crate::cli;
 use ansiterm::Colour;
 use clap::Parser;
 use forc_pkg as pkg;
-use forc_test::{decode_log_data, TestFilter, TestRunnerCount, TestedPackage};
+use forc_test::{
+    decode_log_data, invariant::InvariantOpts, state::TestStateDiff, TestFilter, TestLimit,
+    TestLimits, TestRunnerCount, TestedPackage,
+};
 use forc_tracing::println_action_green;
 use forc_util::{tx_utils::format_log_receipts, ForcError, ForcResult};
+use pkg::TestPassCondition;
 use sway_core::fuel_prelude::fuel_tx::Receipt;
 use tracing::info;
 
@@ -12,8 +16,12 @@ forc_util::cli_examples! {
     crate::cli::Opt {
         [ Run test => "forc test" ]
         [ Run test with a filter => "forc test $filter" ]
+        [ Run the tests of a module and its submodules => "forc test --test-path module::submodule::" ]
+        [ Run the tests tagged as slow => "forc test --include-tags slow" ]
         [ Run test without any output => "forc test --silent" ]
         [ Run test without creating or update the lock file  => "forc test --locked" ]
+        [ Run test and print the chain state changes of each test => "forc test --show-state-diff" ]
+        [ Run invariant tests with more and longer sequences of actions => "forc test --invariant-runs 256 --invariant-depth 32" ]
     }
 }
 
@@ -34,6 +42,11 @@ forc_util::cli_examples! {
 /// Upon successful compilation, test scripts are executed to their completion. A test is
 /// considered a failure in the case that a revert (`rvrt`) instruction is encountered during
 /// execution. Otherwise, it is considered a success.
+///
+/// Invariant tests are declared with `#[test(action)]` and `#[test(invariant)]`. Random sequences
+/// of actions are executed on the same chain state, and each invariant is checked after every
+/// action. A broken invariant is reported along with the shortest sequence of actions found to
+/// break it.
 #[derive(Debug, Parser)]
 #[clap(bin_name = "forc test", version, after_help = help())]
 pub struct Command {
@@ -47,9 +60,38 @@ pub struct Command {
     /// When specified, only the test exactly matching the given string will be executed.
     pub filter_exact: bool,
     #[clap(long)]
+    /// When specified, only the tests whose path starts with the given path are executed, e.g.
+    /// `module::submodule::` for the tests of a module and its submodules.
+    pub test_path: Option<String>,
+    #[clap(long)]
+    /// When specified, only the tests declared in the given file are executed, e.g.
+    /// `src/module.sw`.
+    pub file: Option<std::path::PathBuf>,
+    #[clap(long, value_delimiter = ',')]
+    /// When specified, only the tests with any of the given tags, set with
+    /// `#[test(tag = "...")]`, are executed.
+    pub include_tags: Vec<String>,
+    #[clap(long, value_delimiter = ',')]
+    /// The tests with any of the given tags are not executed.
+    pub exclude_tags: Vec<String>,
+    #[clap(long)]
     /// Number of threads to utilize when running the tests. By default, this is the number of
     /// threads available in your system.
     pub test_threads: Option<usize>,
+    #[clap(long)]
+    /// Interrupt and fail tests running longer than the given number of milliseconds. Tests with
+    /// a `timeout_ms` in their `#[test]` attribute use that limit instead.
+    pub test_timeout: Option<u64>,
+    #[clap(long)]
+    /// Interrupt and fail tests using more than the given amount of gas. Tests with a
+    /// `gas_limit` in their `#[test]` attribute use that limit instead.
+    pub test_gas_limit: Option<u64>,
+    #[clap(long, default_value_t = InvariantOpts::default().runs)]
+    /// Number of random sequences of actions executed by invariant tests.
+    pub invariant_runs: usize,
+    #[clap(long, default_value_t = InvariantOpts::default().depth)]
+    /// Number of actions in each sequence executed by invariant tests.
+    pub invariant_depth: usize,
 
     #[clap(flatten)]
     pub experimental: sway_features::CliFields,
@@ -68,6 +110,9 @@ pub struct TestPrintOpts {
     /// Print the raw logs for tests.
     #[clap(long)]
     pub raw_logs: bool,
+    /// Print the storage slots, contract balances and coin outputs changed by each test.
+    #[clap(long)]
+    pub show_state_diff: bool,
 }
 
 pub(crate) fn exec(cmd: Command) -> ForcResult<()> {
@@ -77,10 +122,31 @@ pub(crate) fn exec(cmd: Command) -> ForcResult<()> {
     };
 
     let test_print_opts = cmd.test_print.clone();
+    let test_limits = TestLimits {
+        timeout: cmd.test_timeout.map(std::time::Duration::from_millis),
+        gas: cmd.test_gas_limit,
+    };
+    let invariant_opts = InvariantOpts {
+        runs: cmd.invariant_runs,
+        depth: cmd.invariant_depth,
+    };
     let test_filter_phrase = cmd.filter.clone();
-    let test_filter = test_filter_phrase.as_ref().map(|filter_phrase| TestFilter {
-        filter_phrase,
-        exact_match: cmd.filter_exact,
+    let test_path = cmd.test_path.clone();
+    let file = cmd.file.clone();
+    let include_tags = cmd.include_tags.clone();
+    let exclude_tags = cmd.exclude_tags.clone();
+    let is_filtered = test_filter_phrase.is_some()
+        || test_path.is_some()
+        || file.is_some()
+        || !include_tags.is_empty()
+        || !exclude_tags.is_empty();
+    let test_filter = is_filtered.then(|| TestFilter {
+        filter_phrase: test_filter_phrase.as_deref().unwrap_or_default(),
+        exact_match: cmd.filter_exact && test_filter_phrase.is_some(),
+        test_path: test_path.as_deref(),
+        file: file.as_deref(),
+        include_tags: &include_tags,
+        exclude_tags: &exclude_tags,
     });
     let opts = opts_from_cmd(cmd);
     let built_tests = forc_test::build(opts)?;
@@ -98,7 +164,7 @@ pub(crate) fn exec(cmd: Command) -> ForcResult<()> {
             formatted_test_count_string(&num_tests_ignored)
         ),
     );
-    let tested = built_tests.run(test_runner_count, test_filter)?;
+    let tested = built_tests.run(test_runner_count, test_filter, test_limits, &invariant_opts)?;
     let duration = start.elapsed();
 
     // Eventually we'll print this in a fancy manner, but this will do for testing.
@@ -136,7 +202,11 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
         let test_passed = test.passed();
         let (state, color) = match test_passed {
             true => ("ok", Colour::Green),
-            false => ("FAILED", Colour::Red),
+            false => match test.limit_exceeded.as_ref().map(|exceeded| exceeded.limit) {
+                Some(TestLimit::Time(_)) => ("TIMED OUT", Colour::Red),
+                Some(TestLimit::Gas(_)) => ("OUT OF GAS", Colour::Red),
+                None => ("FAILED", Colour::Red),
+            },
         };
         info!(
             "      test {} ... {} ({:?}, {} gas)",
@@ -169,6 +239,10 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
             info!("Raw logs:\n{}", formatted_logs);
         }
 
+        if test_print_opts.show_state_diff {
+            print_state_diff(&test.state_diff);
+        }
+
         // If the test is failing, save the test result for printing the details later on.
         if !test_passed {
             failed_tests.push(test);
@@ -201,6 +275,33 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
                 }
                 info!("{failed_info_str}");
             }
+            if let Some(limit_exceeded) = &failed_test.limit_exceeded {
+                info!("        exceeded the {}", limit_exceeded.limit);
+                if let Some((path, range)) =
+                    failed_test.limit_exceeded_location(&pkg.built.source_map)
+                {
+                    info!(
+                        "        last known location: {:?}:{}:{}",
+                        path, range.start.line, range.start.col
+                    );
+                }
+            }
+            match failed_test.failing_sequence.as_deref() {
+                Some([]) => info!("        invariant broken before any action"),
+                Some(sequence) => {
+                    info!(
+                        "        invariant broken after the actions: {}",
+                        sequence.join(", ")
+                    );
+                }
+                None => {}
+            }
+            if let TestPassCondition::ShouldPanic(expected_message) = &failed_test.condition {
+                info!("        expected panic message containing: {expected_message:?}");
+                if let Some(revert_message) = &failed_test.revert_message {
+                    info!("        found: {revert_message}");
+                }
+            }
             info!("        Logs: {}", formatted_logs);
         }
         info!("\n");
@@ -222,6 +323,39 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
     Ok(())
 }
 
+fn print_state_diff(state_diff: &TestStateDiff) {
+    if state_diff.is_empty() {
+        info!("        state diff: none");
+        return;
+    }
+    info!("        state diff:");
+    let hex_or_unset = |value: &Option<Vec<u8>>| match value {
+        Some(value) => format!("0x{}", hex::encode(value)),
+        None => "unset".to_string(),
+    };
+    for slot in &state_diff.storage {
+        info!(
+            "          storage {} {}: {} -> {}",
+            slot.contract_id,
+            slot.key,
+            hex_or_unset(&slot.before),
+            hex_or_unset(&slot.after)
+        );
+    }
+    for balance in &state_diff.balances {
+        info!(
+            "          balance {} {}: {} -> {}",
+            balance.contract_id, balance.asset_id, balance.before, balance.after
+        );
+    }
+    for coin in &state_diff.coin_outputs {
+        info!(
+            "          coin output {} {}: {}",
+            coin.to, coin.asset_id, coin.amount
+        );
+    }
+}
+
 fn opts_from_cmd(cmd: Command) -> forc_test::TestOpts {
     forc_test::TestOpts {
         pkg: pkg::PkgOpts {
@@ -240,6 +374,7 @@ fn opts_from_cmd(cmd: Command) -> forc_test::TestOpts {
             bytecode: cmd.build.print.bytecode,
             bytecode_spans: false,
             ir: cmd.build.print.ir(),
+            verify_ir: cmd.build.print.verify_ir(),
             reverse_order: cmd.build.print.reverse_order,
         },
         time_phases: cmd.build.print.time_phases,
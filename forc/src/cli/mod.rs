@@ -1,23 +1,30 @@
 use self::commands::{
-    addr2line, build, check, clean, completions, contract_id, init, new, parse_bytecode, plugins,
-    predicate_root, template, test, update,
+    addr2line, build, cache, check, clean, completions, contract_id, explain, init, migrate, new,
+    parse_bytecode, plugins, predicate_root, storage_diff, template, test, update,
 };
 use addr2line::Command as Addr2LineCommand;
 use anyhow::anyhow;
 pub use build::Command as BuildCommand;
+pub use cache::ClearCommand as CacheClearCommand;
+pub use cache::Command as CacheCommand;
 pub use check::Command as CheckCommand;
 use clap::{Parser, Subcommand};
 pub use clean::Command as CleanCommand;
 pub use completions::Command as CompletionsCommand;
 pub(crate) use contract_id::Command as ContractIdCommand;
-use forc_tracing::{init_tracing_subscriber, TracingSubscriberOptions};
+pub use explain::Command as ExplainCommand;
+use forc_tracing::{init_tracing_subscriber, TracingSubscriberOptions, TracingWriterMode};
 use forc_util::ForcResult;
 pub use init::Command as InitCommand;
+pub use migrate::Command as MigrateCommand;
 pub use new::Command as NewCommand;
 use parse_bytecode::Command as ParseBytecodeCommand;
 pub use plugins::Command as PluginsCommand;
+pub use plugins::InstallCommand as PluginsInstallCommand;
+pub use plugins::UpdateCommand as PluginsUpdateCommand;
 pub(crate) use predicate_root::Command as PredicateRootCommand;
 use std::str::FromStr;
+pub use storage_diff::Command as StorageDiffCommand;
 pub use template::Command as TemplateCommand;
 pub use test::Command as TestCommand;
 use tracing::metadata::LevelFilter;
@@ -68,11 +75,15 @@ enum Forc {
     Addr2Line(Addr2LineCommand),
     #[clap(visible_alias = "b")]
     Build(BuildCommand),
+    Cache(CacheCommand),
     Check(CheckCommand),
     Clean(CleanCommand),
     Completions(CompletionsCommand),
+    Explain(ExplainCommand),
     New(NewCommand),
     Init(InitCommand),
+    #[clap(visible_alias = "upgrade")]
+    Migrate(MigrateCommand),
     ParseBytecode(ParseBytecodeCommand),
     #[clap(visible_alias = "t")]
     Test(TestCommand),
@@ -81,6 +92,7 @@ enum Forc {
     Template(TemplateCommand),
     ContractId(ContractIdCommand),
     PredicateRoot(PredicateRootCommand),
+    StorageDiff(StorageDiffCommand),
     /// This is a catch-all for unknown subcommands and their arguments.
     ///
     /// When we receive an unknown subcommand, we check for a plugin exe named
@@ -99,10 +111,13 @@ impl Forc {
         vec![
             "addr2line",
             "build",
+            "cache",
             "check",
             "clean",
             "completions",
+            "explain",
             "init",
+            "migrate",
             "new",
             "parse-bytecode",
             "plugins",
@@ -111,16 +126,23 @@ impl Forc {
             "template",
             "contract-id",
             "predicate-root",
+            "storage-diff",
         ]
     }
 }
 
 pub async fn run_cli() -> ForcResult<()> {
     let opt = Opt::parse();
+    // Keep stdout free for the JSON messages, if requested.
+    let writer_mode = match &opt.command {
+        Forc::Build(command) if command.message_format.is_json() => Some(TracingWriterMode::Stderr),
+        _ => None,
+    };
     let tracing_options = TracingSubscriberOptions {
         verbosity: Some(opt.verbose),
         silent: Some(opt.silent),
         log_level: opt.log_level,
+        writer_mode,
         ..Default::default()
     };
 
@@ -129,18 +151,22 @@ pub async fn run_cli() -> ForcResult<()> {
     match opt.command {
         Forc::Addr2Line(command) => addr2line::exec(command),
         Forc::Build(command) => build::exec(command),
+        Forc::Cache(command) => cache::exec(command),
         Forc::Check(command) => check::exec(command),
         Forc::Clean(command) => clean::exec(command),
         Forc::Completions(command) => completions::exec(command),
+        Forc::Explain(command) => explain::exec(command),
         Forc::Init(command) => init::exec(command),
+        Forc::Migrate(command) => migrate::exec(command),
         Forc::New(command) => new::exec(command),
         Forc::ParseBytecode(command) => parse_bytecode::exec(command),
-        Forc::Plugins(command) => plugins::exec(command),
+        Forc::Plugins(command) => plugins::exec(command, opt.verbose),
         Forc::Test(command) => test::exec(command),
         Forc::Update(command) => update::exec(command),
         Forc::Template(command) => template::exec(command),
         Forc::ContractId(command) => contract_id::exec(command),
         Forc::PredicateRoot(command) => predicate_root::exec(command),
+        Forc::StorageDiff(command) => storage_diff::exec(command),
         Forc::Plugin(args) => {
             let output = plugin::execute_external_subcommand(&args)?;
             let code = output
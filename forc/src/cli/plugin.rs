@@ -1,5 +1,6 @@
 //! Items related to plugin support for `forc`.
 
+use crate::ops::forc_plugins;
 use anyhow::{bail, Result};
 use forc_tracing::println_warning_verbose;
 use std::{
@@ -53,12 +54,17 @@ fn find_external_subcommand(cmd: &str) -> Option<PathBuf> {
         .find(|file| is_executable(file))
 }
 
-/// Search the user's `PATH` for `forc-*` exes.
+/// Search the user's `PATH` for `forc-*` exes, followed by the directory `forc plugins install`
+/// installs plugins into, if it is not on the `PATH` already.
 fn search_directories() -> Vec<PathBuf> {
-    if let Some(val) = env::var_os("PATH") {
-        return env::split_paths(&val).collect();
+    let mut dirs: Vec<PathBuf> = env::var_os("PATH")
+        .map(|val| env::split_paths(&val).collect())
+        .unwrap_or_default();
+    let plugins_bin_dir = forc_plugins::plugins_bin_directory();
+    if !dirs.contains(&plugins_bin_dir) {
+        dirs.push(plugins_bin_dir);
     }
-    vec![]
+    dirs
 }
 
 #[cfg(unix)]
@@ -84,7 +90,7 @@ fn is_plugin(path: &Path) -> bool {
     false
 }
 
-/// Find all forc plugins available via `PATH`.
+/// Find all forc plugins available via `PATH` or installed with `forc plugins install`.
 pub(crate) fn find_all() -> impl Iterator<Item = PathBuf> {
     search_directories()
         .into_iter()
@@ -1,7 +1,8 @@
 //! Sets of arguments that are shared between commands.
 use clap::{Args, Parser};
 use forc_pkg::source::IPFSNode;
-use sway_core::{BuildTarget, PrintAsm, PrintIr};
+use std::path::PathBuf;
+use sway_core::{BuildTarget, PrintAsm, PrintIr, VerifyIr, VerifyIrLevel};
 use sway_ir::PassManager;
 
 /// Args that can be shared between all commands that `build` a package. E.g. `build`, `test`,
@@ -96,6 +97,20 @@ pub struct Print {
     ///  - modified:    print a requested optimization pass only if it has modified the IR.
     #[arg(long, verbatim_doc_comment, num_args(1..=18), value_parser = clap::builder::PossibleValuesParser::new(PrintIrCliOpt::cli_options()))]
     pub ir: Option<Vec<String>>,
+    /// Verify the generated Sway IR while running the optimization passes.
+    ///
+    /// The initial IR is always verified. Possible values:
+    ///  - final:           verify the final IR. If it is invalid, find the pass producing it by
+    ///                     rerunning the passes with each of them disabled in turn.
+    ///  - after-each-pass: verify the IR after each pass that modifies it.
+    #[arg(long, verbatim_doc_comment, value_parser = clap::builder::PossibleValuesParser::new(&VerifyIrCliOpt::CLI_OPTIONS))]
+    pub verify_ir: Option<String>,
+    /// If the optimization passes produce invalid IR, write a reproducer to the specified file.
+    ///
+    /// The reproducer is the IR the passes were run on, along with the passes that produce the
+    /// invalid IR, which can be rerun on it with the `opt` tool of `sway-ir`.
+    #[clap(long, requires = "verify_ir")]
+    pub verify_ir_reproducer: Option<String>,
     /// Output the time elapsed over each part of the compilation process.
     #[clap(long)]
     pub time_phases: bool,
@@ -122,6 +137,16 @@ impl Print {
             .as_ref()
             .map_or(PrintIr::default(), |opts| PrintIrCliOpt::from(opts).0)
     }
+
+    pub fn verify_ir(&self) -> VerifyIr {
+        VerifyIr {
+            level: self
+                .verify_ir
+                .as_deref()
+                .map_or(VerifyIrLevel::default(), |opt| VerifyIrCliOpt::from(opt).0),
+            reproducer: self.verify_ir_reproducer.as_ref().map(PathBuf::from),
+        }
+    }
 }
 
 /// Package-related options.
@@ -253,3 +278,20 @@ impl From<&Vec<String>> for PrintIrCliOpt {
         Self(print_ir)
     }
 }
+
+pub struct VerifyIrCliOpt(pub VerifyIrLevel);
+
+impl VerifyIrCliOpt {
+    const FINAL: &'static str = "final";
+    const AFTER_EACH_PASS: &'static str = "after-each-pass";
+    pub const CLI_OPTIONS: [&'static str; 2] = [Self::FINAL, Self::AFTER_EACH_PASS];
+}
+
+impl From<&str> for VerifyIrCliOpt {
+    fn from(value: &str) -> Self {
+        match value {
+            Self::AFTER_EACH_PASS => Self(VerifyIrLevel::AfterEachPass),
+            _ => Self(VerifyIrLevel::Final),
+        }
+    }
+}
@@ -1,14 +1,36 @@
-use crate::cli::BuildCommand;
+use crate::{cli::BuildCommand, ops::forc_proxy};
 use forc_pkg as pkg;
 use forc_util::ForcResult;
 use pkg::MemberFilter;
+use std::path::PathBuf;
 
 pub fn build(cmd: BuildCommand) -> ForcResult<pkg::Built> {
+    let proxy = cmd.proxy;
     let opts = opts_from_cmd(cmd);
     let built = pkg::build_with_options(&opts)?;
+    if proxy {
+        forc_proxy::build_proxies(&opts, &built)?;
+    }
+    Ok(built)
+}
+
+/// Build for every combination of the `--targets` and `--profiles` of the command.
+pub fn build_matrix(cmd: BuildCommand) -> ForcResult<Vec<pkg::BuiltMatrixEntry>> {
+    let targets = cmd.targets.clone();
+    let profiles = cmd.profiles.clone();
+    let opts = opts_from_cmd(cmd);
+    let built = pkg::build_matrix_with_options(&opts, &targets, &profiles)?;
     Ok(built)
 }
 
+/// Build the textual IR module given with `--ir-input` instead of the package.
+pub fn build_ir(cmd: BuildCommand) -> ForcResult<PathBuf> {
+    let ir_path = PathBuf::from(cmd.ir_input.clone().unwrap_or_default());
+    let opts = opts_from_cmd(cmd);
+    let bin_path = pkg::build_ir_with_options(&ir_path, &opts)?;
+    Ok(bin_path)
+}
+
 fn opts_from_cmd(cmd: BuildCommand) -> pkg::BuildOpts {
     pkg::BuildOpts {
         pkg: pkg::PkgOpts {
@@ -27,6 +49,7 @@ fn opts_from_cmd(cmd: BuildCommand) -> pkg::BuildOpts {
             bytecode: cmd.build.print.bytecode,
             bytecode_spans: false,
             ir: cmd.build.print.ir(),
+            verify_ir: cmd.build.print.verify_ir(),
             reverse_order: cmd.build.print.reverse_order,
         },
         time_phases: cmd.build.print.time_phases,
@@ -46,5 +69,7 @@ fn opts_from_cmd(cmd: BuildCommand) -> pkg::BuildOpts {
         member_filter: MemberFilter::default(),
         experimental: cmd.experimental.experimental,
         no_experimental: cmd.experimental.no_experimental,
+        message_format: cmd.message_format,
+        analysis_passes: Default::default(),
     }
 }
@@ -0,0 +1,84 @@
+//! Inspection and removal of the git checkouts shared by all projects.
+
+use crate::cli::CacheClearCommand;
+use anyhow::Result;
+use forc_pkg::{
+    format_size,
+    source::git::{self, Checkout},
+};
+use forc_tracing::println_action_green;
+use forc_util::git_checkouts_directory;
+use std::path::Path;
+use tracing::info;
+
+pub fn list() -> Result<()> {
+    let checkouts = git::checkouts()?;
+    if checkouts.is_empty() {
+        info!(
+            "No cached git checkouts in {}",
+            git_checkouts_directory().display()
+        );
+        return Ok(());
+    }
+    info!(
+        "Cached git checkouts in {}:",
+        git_checkouts_directory().display()
+    );
+    for checkout in &checkouts {
+        info!(
+            "  {} {} ({}) {}",
+            checkout.name,
+            checkout.commit_hash,
+            checkout.git_reference,
+            format_size(dir_size(&checkout.path))
+        );
+    }
+    Ok(())
+}
+
+pub fn size() -> Result<()> {
+    let checkouts = git::checkouts()?;
+    let total: u64 = checkouts
+        .iter()
+        .map(|checkout| dir_size(&checkout.path))
+        .sum();
+    info!(
+        "{} in {} checkouts at {}",
+        format_size(total),
+        checkouts.len(),
+        git_checkouts_directory().display()
+    );
+    Ok(())
+}
+
+pub fn clear(command: CacheClearCommand) -> Result<()> {
+    let CacheClearCommand { package } = command;
+    let checkouts: Vec<Checkout> = git::checkouts()?
+        .into_iter()
+        .filter(|checkout| match &package {
+            Some(name) => checkout.name == *name,
+            None => true,
+        })
+        .collect();
+    let mut freed = 0;
+    for checkout in &checkouts {
+        freed += dir_size(&checkout.path);
+        git::remove_checkout(checkout)?;
+    }
+    println_action_green(
+        "Removed",
+        &format!("{} checkouts, {}", checkouts.len(), format_size(freed)),
+    );
+    Ok(())
+}
+
+/// The total size of all files within `dir`, in bytes.
+fn dir_size(dir: &Path) -> u64 {
+    walkdir::WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.metadata().ok())
+        .filter(|metadata| metadata.is_file())
+        .map(|metadata| metadata.len())
+        .sum()
+}
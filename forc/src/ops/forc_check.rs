@@ -7,20 +7,51 @@ use std::path::PathBuf;
 use sway_core::{language::ty, Engines};
 use sway_error::handler::Handler;
 
+/// The result of checking a single workspace member.
+pub struct CheckedMember {
+    /// The name of the member package.
+    pub name: String,
+    /// The typed program, if the member type checked.
+    pub program: Option<ty::TyProgram>,
+    /// The diagnostics produced while checking the member.
+    pub handler: Handler,
+}
+
 pub fn check(command: CheckCommand, engines: &Engines) -> Result<(Option<ty::TyProgram>, Handler)> {
-    let CheckCommand {
-        build_target,
-        path,
-        offline_mode: offline,
-        terse_mode,
-        locked,
-        disable_tests,
-        ipfs_node,
-        experimental,
-        ..
-    } = command;
+    let plan = build_plan(&command)?;
+    let mut v = check_plan(&plan, command, engines)?;
+    let (res, handler) = v
+        .pop()
+        .expect("there is guaranteed to be at least one elem in the vector");
+    let res = res.and_then(|programs| programs.typed.ok());
+    Ok((res, handler))
+}
 
-    let this_dir = if let Some(ref path) = path {
+/// Type check every workspace member, including its test code unless the tests are disabled,
+/// without generating any IR or bytecode, and return the result of each member in compilation
+/// order.
+///
+/// Checking stops at the first member which fails to type check, as the members depending on it
+/// can't be checked. The failing member is the last one returned.
+pub fn check_all(command: CheckCommand, engines: &Engines) -> Result<Vec<CheckedMember>> {
+    let plan = build_plan(&command)?;
+    let results = check_plan(&plan, command, engines)?;
+    let members = plan
+        .compilation_order()
+        .iter()
+        .zip(results)
+        .filter(|(&node, _)| matches!(plan.graph()[node].source, pkg::source::Pinned::Member(_)))
+        .map(|(&node, (res, handler))| CheckedMember {
+            name: plan.graph()[node].name.clone(),
+            program: res.and_then(|programs| programs.typed.ok()),
+            handler,
+        })
+        .collect();
+    Ok(members)
+}
+
+fn build_plan(command: &CheckCommand) -> Result<pkg::BuildPlan> {
+    let this_dir = if let Some(ref path) = command.path {
         PathBuf::from(path)
     } else {
         std::env::current_dir()?
@@ -28,17 +59,31 @@ pub fn check(command: CheckCommand, engines: &Engines) -> Result<(Option<ty::TyP
     let manifest_file = ManifestFile::from_dir(this_dir)?;
     let member_manifests = manifest_file.member_manifests()?;
     let lock_path = manifest_file.lock_path()?;
-    let plan = pkg::BuildPlan::from_lock_and_manifests(
+    pkg::BuildPlan::from_lock_and_manifests(
         &lock_path,
         &member_manifests,
-        locked,
-        offline,
-        &ipfs_node.unwrap_or_default(),
-    )?;
+        command.locked,
+        command.offline_mode,
+        &command.ipfs_node.clone().unwrap_or_default(),
+    )
+}
+
+fn check_plan(
+    plan: &pkg::BuildPlan,
+    command: CheckCommand,
+    engines: &Engines,
+) -> Result<Vec<(Option<pkg::Programs>, Handler)>> {
+    let CheckCommand {
+        build_target,
+        terse_mode,
+        disable_tests,
+        experimental,
+        ..
+    } = command;
     let tests_enabled = !disable_tests;
 
-    let mut v = pkg::check(
-        &plan,
+    pkg::check(
+        plan,
         build_target,
         terse_mode,
         None,
@@ -47,10 +92,5 @@ pub fn check(command: CheckCommand, engines: &Engines) -> Result<(Option<ty::TyP
         None,
         &experimental.experimental,
         &experimental.no_experimental,
-    )?;
-    let (res, handler) = v
-        .pop()
-        .expect("there is guaranteed to be at least one elem in the vector");
-    let res = res.and_then(|programs| programs.typed.ok());
-    Ok((res, handler))
+    )
 }
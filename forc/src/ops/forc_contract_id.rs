@@ -61,6 +61,7 @@ fn build_opts_from_cmd(cmd: &ContractIdCommand) -> pkg::BuildOpts {
             bytecode: cmd.print.bytecode,
             bytecode_spans: false,
             ir: cmd.print.ir(),
+            verify_ir: cmd.print.verify_ir(),
             reverse_order: cmd.print.reverse_order,
         },
         time_phases: cmd.print.time_phases,
@@ -80,5 +81,7 @@ fn build_opts_from_cmd(cmd: &ContractIdCommand) -> pkg::BuildOpts {
         member_filter: pkg::MemberFilter::only_contracts(),
         experimental: cmd.experimental.experimental.clone(),
         no_experimental: cmd.experimental.no_experimental.clone(),
+        message_format: Default::default(),
+        analysis_passes: Default::default(),
     }
 }
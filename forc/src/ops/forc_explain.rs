@@ -0,0 +1,32 @@
+use crate::cli::ExplainCommand;
+use anyhow::{bail, Result};
+use sway_error::codes;
+
+pub fn explain(command: ExplainCommand) -> Result<()> {
+    if command.list {
+        for info in codes::all_codes() {
+            let marker = if info.explanation.is_some() { "*" } else { " " };
+            println!("{}{marker} {}", info.code.as_str(), info.name);
+        }
+        println!("\nCodes marked with `*` have an extended explanation.");
+        return Ok(());
+    }
+
+    // Safe unwrap, because clap requires the code if `--list` is not present.
+    let code = command.code.unwrap();
+    let Some(info) = codes::lookup(&code) else {
+        bail!(
+            "`{code}` is not a valid diagnostic code. \
+            Run `forc explain --list` to see all the codes."
+        );
+    };
+    match info.explanation {
+        Some(explanation) => print!("{explanation}"),
+        None => println!(
+            "# {}\n\n{}\n\nThis diagnostic does not have an extended explanation yet.",
+            info.code.as_str(),
+            info.name
+        ),
+    }
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use crate::cli::InitCommand;
+use crate::ops::forc_template;
 use crate::utils::{defaults, program_type::ProgramType};
 use anyhow::Context;
 use forc_util::{forc_result_bail, validate_project_name, ForcResult};
@@ -80,6 +81,20 @@ pub fn init(command: InitCommand) -> ForcResult<()> {
 
     validate_project_name(&project_name)?;
 
+    if let Some(url) = &command.template {
+        let template_dir =
+            forc_template::fetch_template(url, command.template_name.as_deref(), &project_name)?;
+        forc_template::instantiate_template(
+            &template_dir,
+            &project_dir,
+            &project_name,
+            &command.defines,
+        )?;
+        debug!("\nSuccessfully created {project_name} from template {url}");
+        print_welcome_message();
+        return Ok(());
+    }
+
     let init_type = match (
         command.contract,
         command.script,
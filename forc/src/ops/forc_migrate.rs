@@ -0,0 +1,244 @@
+//! Automated rewrites of Sway code for breaking changes of the language.
+//!
+//! Every breaking change that can be migrated automatically registers a [Migration] in
+//! [MIGRATIONS]. A migration inspects the parsed module and its tokens and returns the edits to
+//! apply to the source. Edits only replace the spans they target, so the formatting and comments
+//! of the rest of the file are preserved.
+
+use crate::cli::MigrateCommand;
+use anyhow::{anyhow, bail, Context, Result};
+use forc_pkg::manifest::{GenericManifestFile, ManifestFile};
+use forc_tracing::{println_action_green, println_green, println_red};
+use prettydiff::{basic::DiffOp, diff_lines};
+use std::{fs, path::PathBuf, sync::Arc};
+use sway_ast::{
+    token::{GenericTokenTree, TokenStream},
+    Module,
+};
+use sway_error::handler::Handler;
+use sway_types::{ast::Delimiter, Span, Spanned};
+use sway_utils::{find_parent_manifest_dir, get_sway_files, MANIFEST_FILE_NAME};
+use tracing::info;
+
+/// An automated rewrite for a breaking change of the language.
+pub struct Migration {
+    /// The identifier of the migration, used to select it on the command line.
+    pub id: &'static str,
+    /// The `forc` version that introduced the breaking change.
+    pub since: &'static str,
+    /// What the migration rewrites, shown when listing the migrations.
+    pub description: &'static str,
+    /// Returns the edits migrating the module.
+    pub migrate: fn(&MigrationContext) -> Vec<Edit>,
+}
+
+/// The parsed module a [Migration] is applied to.
+pub struct MigrationContext<'a> {
+    pub module: &'a Module,
+    pub tokens: &'a TokenStream,
+}
+
+/// A replacement of the source code within `span`.
+#[derive(Debug, Clone)]
+pub struct Edit {
+    pub span: Span,
+    pub replacement: String,
+}
+
+/// All migrations, in the order of the releases that introduced them.
+pub const MIGRATIONS: &[Migration] = &[Migration {
+    id: "slice-type-syntax",
+    since: "0.60.0",
+    description: "Replaces the `__slice[T]` slice type syntax with `[T]`.",
+    migrate: migrate_slice_type_syntax,
+}];
+
+pub fn migrate(command: MigrateCommand) -> Result<()> {
+    if command.list {
+        for migration in MIGRATIONS {
+            info!(
+                "{} (since v{}): {}",
+                migration.id, migration.since, migration.description
+            );
+        }
+        return Ok(());
+    }
+
+    let migrations = match &command.only {
+        Some(ids) => ids
+            .iter()
+            .map(|id| {
+                MIGRATIONS
+                    .iter()
+                    .find(|migration| migration.id == id.as_str())
+                    .ok_or_else(|| anyhow!("unknown migration `{id}`"))
+            })
+            .collect::<Result<Vec<_>>>()?,
+        None => MIGRATIONS.iter().collect(),
+    };
+
+    let this_dir = match &command.path {
+        Some(path) => PathBuf::from(path),
+        None => std::env::current_dir()?,
+    };
+    let Some(manifest_dir) = find_parent_manifest_dir(&this_dir) else {
+        bail!(
+            "could not find `{}` in `{}` or any parent directory",
+            MANIFEST_FILE_NAME,
+            this_dir.display(),
+        )
+    };
+    let member_paths: Vec<PathBuf> = match ManifestFile::from_dir(&manifest_dir)? {
+        ManifestFile::Package(_) => vec![manifest_dir],
+        ManifestFile::Workspace(workspace) => workspace.member_paths()?.collect(),
+    };
+
+    let mut migrated_files = 0;
+    for member_path in member_paths {
+        let mut files = get_sway_files(member_path.join("src"));
+        files.sort();
+        for file in files {
+            let src = fs::read_to_string(&file)?;
+            let migrated = migrate_source(&src, &migrations)
+                .with_context(|| format!("failed to migrate `{}`", file.display()))?;
+            if migrated == src {
+                continue;
+            }
+            migrated_files += 1;
+            if command.dry_run {
+                println_action_green("Would migrate", &file.display().to_string());
+                display_file_diff(&src, &migrated);
+            } else {
+                fs::write(&file, migrated)?;
+                println_action_green("Migrated", &file.display().to_string());
+            }
+        }
+    }
+
+    if migrated_files == 0 {
+        info!("No code to migrate.");
+    }
+    Ok(())
+}
+
+/// Applies the `migrations` to the Sway source code `src`, and returns the migrated code.
+///
+/// Fails if the code cannot be parsed, or if the migrations edit overlapping spans.
+pub fn migrate_source(src: &str, migrations: &[&Migration]) -> Result<String> {
+    let handler = Handler::default();
+    let src: Arc<str> = Arc::from(src);
+    let (tokens, module) = sway_parse::lex(&handler, &src, 0, src.len(), None)
+        .and_then(|tokens| Ok((tokens, sway_parse::parse_file(&handler, src.clone(), None)?)))
+        .map_err(|_| anyhow!("the code contains syntax errors"))?;
+
+    let ctx = MigrationContext {
+        module: &module.value,
+        tokens: &tokens,
+    };
+    let mut edits = migrations
+        .iter()
+        .flat_map(|migration| (migration.migrate)(&ctx))
+        .collect::<Vec<_>>();
+    edits.sort_by_key(|edit| edit.span.start());
+
+    let mut migrated = String::with_capacity(src.len());
+    let mut position = 0;
+    for edit in edits {
+        if edit.span.start() < position {
+            bail!(
+                "migrations edit overlapping code at line {}",
+                edit.span.start_pos().line_col().line
+            );
+        }
+        migrated.push_str(&src[position..edit.span.start()]);
+        migrated.push_str(&edit.replacement);
+        position = edit.span.end();
+    }
+    migrated.push_str(&src[position..]);
+    Ok(migrated)
+}
+
+fn display_file_diff(src: &str, migrated: &str) {
+    for diff in diff_lines(src, migrated).diff() {
+        match diff {
+            DiffOp::Insert(new) => new.iter().for_each(|n| println_green(&format!("+{n}"))),
+            DiffOp::Remove(old) => old.iter().for_each(|o| println_red(&format!("-{o}"))),
+            DiffOp::Replace(old, new) => {
+                old.iter().for_each(|o| println_red(&format!("-{o}")));
+                new.iter().for_each(|n| println_green(&format!("+{n}")));
+            }
+            DiffOp::Equal(_) => {}
+        }
+    }
+}
+
+/// Removes the `__slice` keyword of `__slice[T]` slice types.
+fn migrate_slice_type_syntax(ctx: &MigrationContext) -> Vec<Edit> {
+    fn visit(tokens: &TokenStream, edits: &mut Vec<Edit>) {
+        let token_trees = tokens.token_trees();
+        for (i, token_tree) in token_trees.iter().enumerate() {
+            match token_tree {
+                GenericTokenTree::Ident(ident) if ident.as_str() == "__slice" => {
+                    if let Some(GenericTokenTree::Group(group)) = token_trees.get(i + 1) {
+                        // Whitespace between the keyword and the brackets is removed as well.
+                        let span = Span::new(
+                            ident.span().src().clone(),
+                            ident.span().start(),
+                            group.span.start(),
+                            None,
+                        );
+                        if let (Delimiter::Bracket, Some(span)) = (group.delimiter, span) {
+                            edits.push(Edit {
+                                span,
+                                replacement: String::new(),
+                            });
+                        }
+                    }
+                }
+                GenericTokenTree::Group(group) => visit(&group.token_stream, edits),
+                _ => {}
+            }
+        }
+    }
+
+    let mut edits = vec![];
+    visit(ctx.tokens, &mut edits);
+    edits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn migrate_all(src: &str) -> String {
+        migrate_source(src, &MIGRATIONS.iter().collect::<Vec<_>>()).unwrap()
+    }
+
+    #[test]
+    fn slice_type_syntax() {
+        let src = r#"library;
+
+// The slice of `__slice[u64]` is kept in comments.
+fn first(s: __slice[u64]) -> u64 {
+    let t: &__slice [u8] = __slice_bytes();
+    0
+}
+"#;
+        assert_eq!(
+            migrate_all(src),
+            r#"library;
+
+// The slice of `__slice[u64]` is kept in comments.
+fn first(s: [u64]) -> u64 {
+    let t: &[u8] = __slice_bytes();
+    0
+}
+"#
+        );
+    }
+
+    #[test]
+    fn unparsable_code_is_not_migrated() {
+        assert!(migrate_source("library; fn (", &[]).is_err());
+    }
+}
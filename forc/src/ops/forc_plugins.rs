@@ -0,0 +1,285 @@
+//! Installation and tracking of `forc` plugins built from git sources.
+//!
+//! Plugins are built with `cargo install` into the user's `.forc` directory, placing their
+//! binaries in `~/.forc/bin`. The source each plugin was installed from is recorded in
+//! `~/.forc/plugins.toml` so that `forc plugins update` can rebuild it later.
+
+use crate::cli::{PluginsInstallCommand, PluginsUpdateCommand};
+use anyhow::{anyhow, bail, Context, Result};
+use forc_tracing::{println_action_green, println_warning};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    process,
+    time::SystemTime,
+};
+
+/// The name of the file recording the provenance of installed plugins.
+pub const PLUGINS_MANIFEST_FILE_NAME: &str = "plugins.toml";
+
+/// The provenance of all plugins installed via `forc plugins install`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PluginsManifest {
+    #[serde(default, rename = "plugin")]
+    pub plugins: Vec<InstalledPlugin>,
+}
+
+/// A plugin package installed from a git repository.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct InstalledPlugin {
+    /// The git repository the plugin was built from.
+    pub git: String,
+    /// The cargo package within the repository, if one had to be specified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+    /// The branch, tag or revision the plugin was installed from. The repository's default
+    /// branch is used if none of these are set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rev: Option<String>,
+    /// The plugin binaries the package installed into the forc bin directory.
+    #[serde(default)]
+    pub binaries: Vec<String>,
+}
+
+impl PluginsManifest {
+    /// The location of the manifest, `~/.forc/plugins.toml` by default.
+    pub fn path() -> PathBuf {
+        forc_util::user_forc_directory().join(PLUGINS_MANIFEST_FILE_NAME)
+    }
+
+    /// Load the manifest from the given path, or an empty manifest if it doesn't exist yet.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        toml::from_str(&contents).with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn to_file(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let contents = toml::to_string_pretty(self)?;
+        fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    /// Find the installed plugin that provides the binary with the given file name.
+    pub fn find_by_binary(&self, binary: &str) -> Option<&InstalledPlugin> {
+        self.plugins
+            .iter()
+            .find(|plugin| plugin.binaries.iter().any(|bin| bin == binary))
+    }
+
+    /// Record `plugin`, replacing any previous installation from the same source or providing
+    /// any of the same binaries.
+    fn insert(&mut self, plugin: InstalledPlugin) {
+        self.plugins.retain(|existing| {
+            !(existing.git == plugin.git && existing.package == plugin.package)
+                && !existing
+                    .binaries
+                    .iter()
+                    .any(|bin| plugin.binaries.contains(bin))
+        });
+        self.plugins.push(plugin);
+    }
+}
+
+impl InstalledPlugin {
+    /// A short human readable description of where the plugin was installed from.
+    pub fn source_description(&self) -> String {
+        let reference = [("branch", &self.branch), ("tag", &self.tag), ("rev", &self.rev)]
+            .into_iter()
+            .find_map(|(kind, value)| value.as_ref().map(|value| format!("?{kind}={value}")))
+            .unwrap_or_default();
+        match &self.package {
+            Some(package) => format!("{}{reference} ({package})", self.git),
+            None => format!("{}{reference}", self.git),
+        }
+    }
+}
+
+/// The directory plugins are installed into, `~/.forc/bin` by default.
+pub fn plugins_bin_directory() -> PathBuf {
+    forc_util::user_forc_directory().join("bin")
+}
+
+pub fn install(command: PluginsInstallCommand) -> Result<()> {
+    let PluginsInstallCommand {
+        git,
+        package,
+        branch,
+        tag,
+        rev,
+    } = command;
+    let plugin = InstalledPlugin {
+        git,
+        package,
+        branch,
+        tag,
+        rev,
+        binaries: vec![],
+    };
+
+    let manifest_path = PluginsManifest::path();
+    let mut manifest = PluginsManifest::from_file(&manifest_path)?;
+    let plugin = cargo_install(plugin)?;
+    for bin in &plugin.binaries {
+        println_action_green("Installed", bin);
+    }
+    manifest.insert(plugin);
+    manifest.to_file(&manifest_path)
+}
+
+pub fn update(command: PluginsUpdateCommand) -> Result<()> {
+    let manifest_path = PluginsManifest::path();
+    let mut manifest = PluginsManifest::from_file(&manifest_path)?;
+    let to_update: Vec<InstalledPlugin> = match &command.plugin {
+        Some(name) => {
+            let bin = if name.starts_with("forc-") {
+                name.clone()
+            } else {
+                format!("forc-{name}")
+            };
+            let plugin = manifest.find_by_binary(&bin).ok_or_else(|| {
+                anyhow!(
+                    "plugin `{bin}` was not installed via `forc plugins install` and cannot be updated"
+                )
+            })?;
+            vec![plugin.clone()]
+        }
+        None => manifest.plugins.clone(),
+    };
+    if to_update.is_empty() {
+        println_warning("No plugins installed via `forc plugins install`, nothing to update.");
+        return Ok(());
+    }
+    for plugin in to_update {
+        println_action_green("Updating", &plugin.source_description());
+        let plugin = cargo_install(plugin)?;
+        manifest.insert(plugin);
+        // Persist after each plugin so an error part way through doesn't lose earlier updates.
+        manifest.to_file(&manifest_path)?;
+    }
+    Ok(())
+}
+
+/// Build and install `plugin` with `cargo install`, returning it with the binaries it installed.
+fn cargo_install(mut plugin: InstalledPlugin) -> Result<InstalledPlugin> {
+    let root = forc_util::user_forc_directory();
+    let bin_dir = plugins_bin_directory();
+    let before = plugin_binaries(&bin_dir);
+
+    let mut cmd = process::Command::new("cargo");
+    cmd.arg("install")
+        .arg("--locked")
+        .arg("--force")
+        .arg("--root")
+        .arg(&root)
+        .arg("--git")
+        .arg(&plugin.git);
+    if let Some(branch) = &plugin.branch {
+        cmd.arg("--branch").arg(branch);
+    }
+    if let Some(tag) = &plugin.tag {
+        cmd.arg("--tag").arg(tag);
+    }
+    if let Some(rev) = &plugin.rev {
+        cmd.arg("--rev").arg(rev);
+    }
+    if let Some(package) = &plugin.package {
+        cmd.arg(package);
+    }
+    let status = cmd
+        .status()
+        .context("failed to run `cargo install`, is cargo installed and on your `PATH`?")?;
+    if !status.success() {
+        bail!("failed to install plugin from {}", plugin.source_description());
+    }
+
+    let after = plugin_binaries(&bin_dir);
+    let installed: Vec<String> = after
+        .into_iter()
+        .filter(|(name, modified)| before.get(name) != Some(modified))
+        .map(|(name, _)| name)
+        .collect();
+    if installed.is_empty() {
+        // `cargo install --force` rewrites existing binaries, so this only happens for packages
+        // that don't contain any `forc-*` binaries.
+        bail!(
+            "{} did not install any `forc-*` binaries",
+            plugin.source_description()
+        );
+    }
+    plugin.binaries = installed;
+    Ok(plugin)
+}
+
+/// The `forc-*` binaries in `dir` along with their last modification time.
+fn plugin_binaries(dir: &Path) -> BTreeMap<String, Option<SystemTime>> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return BTreeMap::new();
+    };
+    entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().to_str()?.to_string();
+            let modified = entry.metadata().and_then(|m| m.modified()).ok();
+            name.starts_with("forc-").then_some((name, modified))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plugin(git: &str, binaries: &[&str]) -> InstalledPlugin {
+        InstalledPlugin {
+            git: git.to_string(),
+            package: None,
+            branch: None,
+            tag: None,
+            rev: None,
+            binaries: binaries.iter().map(|bin| bin.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn manifest_round_trips() {
+        let mut manifest = PluginsManifest::default();
+        manifest.insert(plugin("https://github.com/a/forc-a", &["forc-a"]));
+        manifest.insert(InstalledPlugin {
+            package: Some("forc-b".to_string()),
+            tag: Some("v1.0.0".to_string()),
+            ..plugin("https://github.com/b/plugins", &["forc-b", "forc-b2"])
+        });
+        let serialized = toml::to_string_pretty(&manifest).unwrap();
+        let deserialized: PluginsManifest = toml::from_str(&serialized).unwrap();
+        assert_eq!(manifest, deserialized);
+        assert_eq!(
+            deserialized
+                .find_by_binary("forc-b2")
+                .unwrap()
+                .source_description(),
+            "https://github.com/b/plugins?tag=v1.0.0 (forc-b)"
+        );
+    }
+
+    #[test]
+    fn reinstall_replaces_previous_entry() {
+        let mut manifest = PluginsManifest::default();
+        manifest.insert(plugin("https://github.com/a/forc-a", &["forc-a"]));
+        manifest.insert(plugin("https://github.com/fork/forc-a", &["forc-a"]));
+        assert_eq!(manifest.plugins.len(), 1);
+        assert_eq!(manifest.plugins[0].git, "https://github.com/fork/forc-a");
+    }
+}
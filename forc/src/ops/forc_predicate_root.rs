@@ -30,6 +30,7 @@ fn build_opts_from_cmd(cmd: PredicateRootCommand) -> pkg::BuildOpts {
             bytecode: cmd.print.bytecode,
             bytecode_spans: false,
             ir: cmd.print.ir(),
+            verify_ir: cmd.print.verify_ir(),
             reverse_order: cmd.print.reverse_order,
         },
         time_phases: cmd.print.time_phases,
@@ -49,5 +50,7 @@ fn build_opts_from_cmd(cmd: PredicateRootCommand) -> pkg::BuildOpts {
         member_filter: pkg::MemberFilter::only_predicates(),
         experimental: cmd.experimental.experimental,
         no_experimental: cmd.experimental.no_experimental,
+        message_format: Default::default(),
+        analysis_passes: Default::default(),
     }
 }
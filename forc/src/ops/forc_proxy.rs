@@ -0,0 +1,270 @@
+//! Generation of upgradeable proxy contracts for the contracts of a build, i.e. `forc build --proxy`.
+//!
+//! For every contract member, a minimal proxy contract forwarding all calls it doesn't implement
+//! itself to a target implementation contract is generated into the output directory of the
+//! member, and built with the same options. The target can only be updated by the owner of the
+//! proxy. A `<name>-proxy-wiring.json` file lists the artifacts of both contracts and the order
+//! in which they have to be deployed.
+
+use anyhow::Result;
+use forc_pkg::{self as pkg, manifest::Dependency, BuiltPackage};
+use forc_tracing::println_action_green;
+use forc_util::default_output_directory;
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+use sway_core::language::parsed::TreeType;
+
+/// The source of the generated proxy contract.
+///
+/// The proxy storage fields are namespaced, so that they can't clash with the storage fields of
+/// the implementation contract, whose code runs in the context of the proxy. Until they are set,
+/// the target and the owner are the values of the `INITIAL_TARGET` and `INITIAL_OWNER`
+/// configurables.
+const PROXY_CONTRACT: &str = r#"contract;
+
+use std::{auth::msg_sender, execution::run_external};
+
+configurable {
+    INITIAL_TARGET: ContractId = ContractId::zero(),
+    INITIAL_OWNER: Identity = Identity::Address(Address::zero()),
+}
+
+storage {
+    proxy {
+        target: Option<ContractId> = None,
+        owner: Option<Identity> = None,
+    }
+}
+
+enum ProxyError {
+    NotOwner: (),
+}
+
+abi Proxy {
+    #[storage(read)]
+    fn proxy_target() -> ContractId;
+    #[storage(read, write)]
+    fn set_proxy_target(new_target: ContractId);
+    #[storage(read)]
+    fn proxy_owner() -> Identity;
+    #[storage(read, write)]
+    fn set_proxy_owner(new_owner: Identity);
+}
+
+impl Proxy for Contract {
+    #[storage(read)]
+    fn proxy_target() -> ContractId {
+        target()
+    }
+
+    #[storage(read, write)]
+    fn set_proxy_target(new_target: ContractId) {
+        only_owner();
+        storage::proxy.target.write(Some(new_target));
+    }
+
+    #[storage(read)]
+    fn proxy_owner() -> Identity {
+        owner()
+    }
+
+    #[storage(read, write)]
+    fn set_proxy_owner(new_owner: Identity) {
+        only_owner();
+        storage::proxy.owner.write(Some(new_owner));
+    }
+}
+
+#[fallback]
+#[storage(read)]
+fn fallback() {
+    run_external(target())
+}
+
+#[storage(read)]
+fn target() -> ContractId {
+    storage::proxy.target.read().unwrap_or(INITIAL_TARGET)
+}
+
+#[storage(read)]
+fn owner() -> Identity {
+    storage::proxy.owner.read().unwrap_or(INITIAL_OWNER)
+}
+
+#[storage(read)]
+fn only_owner() {
+    require(msg_sender().unwrap() == owner(), ProxyError::NotOwner);
+}
+"#;
+
+/// The artifacts of a contract needed to deploy it.
+#[derive(Debug, Serialize)]
+struct ContractArtifacts {
+    name: String,
+    bytecode: PathBuf,
+    abi: PathBuf,
+    storage_slots: PathBuf,
+}
+
+/// A deployment, in the order in which the contracts have to be deployed.
+#[derive(Debug, Serialize)]
+struct DeploymentStep {
+    contract: String,
+    /// The configurables to set before deploying the contract, with a description of their value.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    configurables: BTreeMap<&'static str, &'static str>,
+    /// The storage slots files to initialize the storage of the contract with.
+    storage_slots: Vec<PathBuf>,
+}
+
+/// The wiring between an implementation contract and its proxy.
+#[derive(Debug, Serialize)]
+struct ProxyWiring {
+    implementation: ContractArtifacts,
+    proxy: ContractArtifacts,
+    deployment_order: Vec<DeploymentStep>,
+}
+
+impl ContractArtifacts {
+    fn new(name: &str, output_dir: &Path) -> Self {
+        Self {
+            name: name.to_string(),
+            bytecode: output_dir.join(format!("{name}.bin")),
+            abi: output_dir.join(format!("{name}-abi.json")),
+            storage_slots: output_dir.join(format!("{name}-storage_slots.json")),
+        }
+    }
+}
+
+/// Generates and builds a proxy contract for every contract member of `built`.
+pub fn build_proxies(opts: &pkg::BuildOpts, built: &pkg::Built) -> Result<()> {
+    let profile_name = if opts.release {
+        pkg::BuildProfile::RELEASE
+    } else {
+        opts.build_profile.as_str()
+    };
+    for (_, built_pkg) in built.into_members() {
+        if built_pkg.tree_type != TreeType::Contract {
+            continue;
+        }
+        let manifest = &built_pkg.descriptor.manifest_file;
+        let output_dir = opts
+            .pkg
+            .output_directory
+            .as_ref()
+            .map(PathBuf::from)
+            .unwrap_or_else(|| default_output_directory(manifest.dir()).join(profile_name));
+        build_proxy(opts, &built_pkg, &output_dir)?;
+    }
+    Ok(())
+}
+
+fn build_proxy(opts: &pkg::BuildOpts, built_pkg: &BuiltPackage, output_dir: &Path) -> Result<()> {
+    let manifest = &built_pkg.descriptor.manifest_file;
+    let pkg_name = manifest.project_name();
+    let proxy_name = format!("{pkg_name}-proxy");
+
+    // Generate the proxy project, depending on the same standard library as the contract.
+    let proxy_dir = output_dir.join(&proxy_name);
+    fs::create_dir_all(proxy_dir.join("src"))?;
+    let std_dependency = match manifest.dep("std") {
+        Some(dep) => format!("std = {}\n", dependency_to_toml(dep, manifest.dir())?),
+        None => String::new(),
+    };
+    let proxy_manifest = format!(
+        r#"[project]
+authors = []
+entry = "main.sw"
+license = "Apache-2.0"
+name = "{proxy_name}"
+
+[dependencies]
+{std_dependency}"#
+    );
+    fs::write(proxy_dir.join("Forc.toml"), proxy_manifest)?;
+    fs::write(proxy_dir.join("src").join("main.sw"), PROXY_CONTRACT)?;
+
+    println_action_green("Generating", &format!("proxy contract for {pkg_name}"));
+    let proxy_opts = pkg::BuildOpts {
+        pkg: pkg::PkgOpts {
+            path: Some(proxy_dir.display().to_string()),
+            offline: opts.pkg.offline,
+            terse: opts.pkg.terse,
+            locked: false,
+            output_directory: Some(output_dir.display().to_string()),
+            ipfs_node: opts.pkg.ipfs_node.clone(),
+        },
+        minify: opts.minify.clone(),
+        build_target: opts.build_target,
+        build_profile: opts.build_profile.clone(),
+        release: opts.release,
+        experimental: opts.experimental.clone(),
+        no_experimental: opts.no_experimental.clone(),
+        ..Default::default()
+    };
+    pkg::build_with_options(&proxy_opts)?;
+
+    let implementation = ContractArtifacts::new(pkg_name, output_dir);
+    let proxy = ContractArtifacts::new(&proxy_name, output_dir);
+    let deployment_order = vec![
+        DeploymentStep {
+            contract: implementation.name.clone(),
+            configurables: BTreeMap::new(),
+            storage_slots: vec![implementation.storage_slots.clone()],
+        },
+        DeploymentStep {
+            contract: proxy.name.clone(),
+            configurables: BTreeMap::from([
+                ("INITIAL_TARGET", "the contract ID of the implementation"),
+                ("INITIAL_OWNER", "the identity allowed to update the target"),
+            ]),
+            // The implementation runs in the context of the proxy, so the proxy storage must also
+            // be initialized with the storage slots of the implementation.
+            storage_slots: vec![
+                implementation.storage_slots.clone(),
+                proxy.storage_slots.clone(),
+            ],
+        },
+    ];
+    let wiring = ProxyWiring {
+        implementation,
+        proxy,
+        deployment_order,
+    };
+    let wiring_path = output_dir.join(format!("{proxy_name}-wiring.json"));
+    let wiring_file = fs::File::create(&wiring_path)?;
+    serde_json::to_writer_pretty(wiring_file, &wiring)?;
+    println_action_green(
+        "Generated",
+        &format!("proxy wiring at {}", wiring_path.display()),
+    );
+    Ok(())
+}
+
+/// Formats `dep` as an inline TOML table, with its path, if any, made absolute, as the proxy
+/// project is not located next to the manifest in `manifest_dir`.
+fn dependency_to_toml(dep: &Dependency, manifest_dir: &Path) -> Result<String> {
+    let mut dep = dep.clone();
+    if let Dependency::Detailed(details) = &mut dep {
+        if let Some(path) = &details.path {
+            let path = manifest_dir.join(path);
+            let path = path.canonicalize().unwrap_or(path);
+            details.path = Some(path.display().to_string());
+        }
+    }
+    let toml = match toml::Value::try_from(&dep)? {
+        toml::Value::Table(table) => {
+            let fields: Vec<_> = table
+                .iter()
+                .map(|(key, value)| format!("{key} = {value}"))
+                .collect();
+            format!("{{ {} }}", fields.join(", "))
+        }
+        value => value.to_string(),
+    };
+    Ok(toml)
+}
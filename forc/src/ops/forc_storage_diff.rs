@@ -0,0 +1,78 @@
+use crate::cli::StorageDiffCommand;
+use anyhow::{bail, Context, Result};
+use forc_pkg::{self as pkg, build_with_options};
+use forc_tracing::{println_green, println_red_err, println_warning};
+use std::{fs, path::Path};
+use sway_core::{language::ty::StorageLayout, BuildTarget};
+use tracing::info;
+
+pub fn storage_diff(command: StorageDiffCommand) -> Result<()> {
+    let old = read_storage_layout(&command.old)?;
+    let new = match &command.new {
+        Some(path) => read_storage_layout(path)?,
+        None => {
+            let build_options = build_opts_from_cmd(&command);
+            let mut members = build_with_options(&build_options)?.into_members();
+            let Some((_, built_contract)) = members.next() else {
+                bail!("No contract found to compare the storage layout with.");
+            };
+            if members.next().is_some() {
+                bail!(
+                    "Found more than one contract to compare the storage layout with. \
+                    Run this command for a single contract member, or provide its layout with `--new`."
+                );
+            }
+            built_contract.storage_layout.clone()
+        }
+    };
+
+    let changes = old.diff(&new);
+    if changes.is_empty() {
+        println_green("Storage layouts are identical.");
+        return Ok(());
+    }
+    for change in &changes {
+        if change.is_incompatible() {
+            println_red_err(&format!("  error: {change}"));
+        } else {
+            println_warning(&format!("  {change}"));
+        }
+    }
+    let incompatible = changes.iter().filter(|c| c.is_incompatible()).count();
+    if incompatible > 0 {
+        bail!(
+            "Found {incompatible} incompatible storage change(s). \
+            The new version of the contract would not read the storage of the old one correctly."
+        );
+    }
+    info!("Storage layouts are compatible.");
+    Ok(())
+}
+
+fn read_storage_layout(path: &Path) -> Result<StorageLayout> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("failed to read storage layout {}", path.display()))?;
+    serde_json::from_str(&contents)
+        .with_context(|| format!("failed to parse storage layout {}", path.display()))
+}
+
+fn build_opts_from_cmd(cmd: &StorageDiffCommand) -> pkg::BuildOpts {
+    pkg::BuildOpts {
+        pkg: pkg::PkgOpts {
+            path: cmd.pkg.path.clone(),
+            offline: cmd.pkg.offline,
+            terse: cmd.pkg.terse,
+            locked: cmd.pkg.locked,
+            output_directory: cmd.pkg.output_directory.clone(),
+            ipfs_node: cmd.pkg.ipfs_node.clone().unwrap_or_default(),
+        },
+        build_profile: cmd.build_profile.build_profile.clone(),
+        release: cmd.build_profile.release,
+        error_on_warnings: cmd.build_profile.error_on_warnings,
+        build_target: BuildTarget::default(),
+        member_filter: pkg::MemberFilter::only_contracts(),
+        experimental: cmd.experimental.experimental.clone(),
+        no_experimental: cmd.experimental.no_experimental.clone(),
+        ..Default::default()
+    }
+}
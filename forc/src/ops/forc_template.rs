@@ -1,28 +1,115 @@
+//! Creation of projects from templates fetched from git repositories.
+//!
+//! A template is a forc project that can contain `{{name}}` placeholders in its files and file
+//! names. `{{project-name}}` and `{{authors}}` are always available. Other placeholders are
+//! declared in a `template.toml` file at the root of the template, which is not copied:
+//!
+//! ```toml
+//! [placeholders.description]
+//! prompt = "What does the contract do?"
+//! default = "A Sway contract"
+//! ```
+//!
+//! The value of each declared placeholder is either set with `--define NAME=VALUE`, or prompted
+//! for if the standard input is a terminal. Otherwise its default is used.
+
 use crate::cli::TemplateCommand;
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use forc_pkg::{
     manifest::{self, PackageManifest},
     source::{self, git::Url},
 };
 use forc_tracing::println_action_green;
 use forc_util::validate_project_name;
-use fs_extra::dir::{copy, CopyOptions};
-use std::fs::File;
-use std::io::{Read, Write};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, BufRead, IsTerminal, Read, Write};
 use std::path::{Path, PathBuf};
 use std::{env, str::FromStr};
 use sway_utils::constants;
 
+/// The name of the file declaring the placeholders of a template.
+pub const TEMPLATE_MANIFEST_FILE_NAME: &str = "template.toml";
+
+/// The placeholders declared by a template in its `template.toml`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct TemplateManifest {
+    #[serde(default)]
+    pub placeholders: BTreeMap<String, Placeholder>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Placeholder {
+    /// The question asked to the user. Defaults to the name of the placeholder.
+    pub prompt: Option<String>,
+    /// The value used if the user doesn't provide one.
+    pub default: Option<String>,
+}
+
+impl TemplateManifest {
+    /// Load the manifest of the template at `template_dir`, or an empty manifest if it has none.
+    pub fn from_dir(template_dir: &Path) -> Result<Self> {
+        let path = template_dir.join(TEMPLATE_MANIFEST_FILE_NAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(&path)?;
+        toml::from_str(&contents).with_context(|| format!("failed to parse {}", path.display()))
+    }
+}
+
+/// Parses a `NAME=VALUE` placeholder definition passed on the command line.
+pub fn parse_placeholder_definition(definition: &str) -> Result<(String, String)> {
+    let (name, value) = definition
+        .split_once('=')
+        .ok_or_else(|| anyhow!("expected `NAME=VALUE`, found `{definition}`"))?;
+    Ok((name.trim().to_string(), value.to_string()))
+}
+
 pub fn init(command: TemplateCommand) -> Result<()> {
     validate_project_name(&command.project_name)?;
+    let template_dir = fetch_template(
+        &command.url,
+        command.template_name.as_deref(),
+        &command.project_name,
+    )?;
+
+    // Create the target dir
+    let target_dir = env::current_dir()?.join(&command.project_name);
+    if target_dir.exists() {
+        bail!("`{}` already exists", target_dir.display());
+    }
+
+    println_action_green(
+        "Creating",
+        &format!("{} from template", &command.project_name),
+    );
+    instantiate_template(
+        &template_dir,
+        &target_dir,
+        &command.project_name,
+        &command.defines,
+    )
+}
+
+/// Fetches the git repository at `url` and returns the directory of the template within it.
+///
+/// Without a `template_name`, the root of the repository is the template.
+pub(crate) fn fetch_template(
+    url: &str,
+    template_name: Option<&str>,
+    project_name: &str,
+) -> Result<PathBuf> {
     // The name used for the temporary local repo directory used for fetching the template.
-    let local_repo_name = command
-        .template_name
-        .clone()
-        .unwrap_or_else(|| format!("{}-template-source", command.project_name));
+    let local_repo_name = template_name
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("{}-template-source", project_name));
 
     let source = source::git::Source {
-        repo: Url::from_str(&command.url)?,
+        repo: Url::from_str(url)?,
         reference: source::git::Reference::DefaultBranch,
     };
 
@@ -44,42 +131,144 @@ pub fn init(command: TemplateCommand) -> Result<()> {
         source::git::fetch(fetch_id, &local_repo_name, &git_source)?;
     }
 
-    let from_path = match command.template_name {
-        Some(ref template_name) => manifest::find_dir_within(&repo_path, template_name)
-            .ok_or_else(|| {
-                anyhow!(
-                    "failed to find a template `{}` in {}",
-                    template_name,
-                    command.url
-                )
-            })?,
+    match template_name {
+        Some(template_name) => find_template_dir(&repo_path, template_name)
+            .ok_or_else(|| anyhow!("failed to find a template `{}` in {}", template_name, url)),
         None => {
-            let manifest_path = repo_path.join(constants::MANIFEST_FILE_NAME);
-            if PackageManifest::from_file(manifest_path).is_err() {
-                anyhow::bail!("failed to find a template in {}", command.url);
+            let is_template = repo_path.join(TEMPLATE_MANIFEST_FILE_NAME).exists()
+                || PackageManifest::from_file(repo_path.join(constants::MANIFEST_FILE_NAME))
+                    .is_ok();
+            if !is_template {
+                bail!("failed to find a template in {}", url);
             }
-            repo_path
+            Ok(repo_path)
         }
-    };
+    }
+}
 
-    // Create the target dir
-    let target_dir = current_dir.join(&command.project_name);
+/// Finds a template by the name of its directory, if it has a `template.toml`, or by the name
+/// of its package otherwise. The package name of templates with a `template.toml` is usually a
+/// placeholder.
+fn find_template_dir(repo_path: &Path, template_name: &str) -> Option<PathBuf> {
+    walkdir::WalkDir::new(repo_path)
+        .into_iter()
+        .filter_map(Result::ok)
+        .find(|entry| {
+            entry.file_name() == TEMPLATE_MANIFEST_FILE_NAME
+                && entry
+                    .path()
+                    .parent()
+                    .and_then(Path::file_name)
+                    .is_some_and(|dir_name| dir_name == template_name)
+        })
+        .and_then(|entry| entry.path().parent().map(Path::to_path_buf))
+        .or_else(|| manifest::find_dir_within(repo_path, template_name))
+}
 
-    println_action_green(
-        "Creating",
-        &format!("{} from template", &command.project_name),
-    );
-    // Copy contents from template to target dir
-    copy_template_to_target(&from_path, &target_dir)?;
+/// Creates the project `project_name` at `target_dir` out of the template at `template_dir`,
+/// substituting its placeholders. `target_dir` may already exist, but none of the template's
+/// files may exist in it.
+pub(crate) fn instantiate_template(
+    template_dir: &Path,
+    target_dir: &Path,
+    project_name: &str,
+    defines: &[(String, String)],
+) -> Result<()> {
+    let template_manifest = TemplateManifest::from_dir(template_dir)?;
+    let real_name = whoami::realname();
+    let interactive = io::stdin().is_terminal();
+    let values = placeholder_values(
+        &template_manifest,
+        project_name,
+        &real_name,
+        defines,
+        interactive,
+    )?;
+
+    copy_template_to_target(template_dir, target_dir, &values)?;
 
     // Edit forc.toml
-    edit_forc_toml(&target_dir, &command.project_name, &whoami::realname())?;
+    edit_forc_toml(target_dir, project_name, &real_name)?;
     if target_dir.join("test").exists() {
-        edit_cargo_toml(&target_dir, &command.project_name, &whoami::realname())?;
+        edit_cargo_toml(target_dir, project_name, &real_name)?;
     }
     Ok(())
 }
 
+/// Resolves the value of every placeholder, from the command line definitions, the answers of
+/// the user, or the defaults of the template, in that order.
+fn placeholder_values(
+    template_manifest: &TemplateManifest,
+    project_name: &str,
+    real_name: &str,
+    defines: &[(String, String)],
+    interactive: bool,
+) -> Result<BTreeMap<String, String>> {
+    let mut values = BTreeMap::from([
+        ("project-name".to_string(), project_name.to_string()),
+        ("authors".to_string(), real_name.to_string()),
+    ]);
+    let defines = defines.iter().cloned().collect::<BTreeMap<_, _>>();
+    for (name, placeholder) in &template_manifest.placeholders {
+        let value = match defines.get(name) {
+            Some(value) => value.clone(),
+            None if interactive => prompt(name, placeholder)?,
+            None => placeholder.default.clone().unwrap_or_default(),
+        };
+        values.insert(name.clone(), value);
+    }
+    for (name, value) in defines {
+        if !values.contains_key(&name) {
+            bail!("the template has no placeholder `{name}`");
+        }
+        // Built-in placeholders can be overridden as well.
+        values.insert(name, value);
+    }
+    Ok(values)
+}
+
+fn prompt(name: &str, placeholder: &Placeholder) -> Result<String> {
+    let question = placeholder.prompt.as_deref().unwrap_or(name);
+    let mut stdout = io::stdout();
+    match &placeholder.default {
+        Some(default) => write!(stdout, "{question} [{default}]: ")?,
+        None => write!(stdout, "{question}: ")?,
+    }
+    stdout.flush()?;
+
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    let answer = answer.trim();
+    Ok(match &placeholder.default {
+        Some(default) if answer.is_empty() => default.clone(),
+        _ => answer.to_string(),
+    })
+}
+
+/// Replaces every `{{name}}` in `text` with the value of the placeholder `name`. Whitespace
+/// around the name is allowed. Unknown placeholders are left as they are.
+fn substitute_placeholders(text: &str, values: &BTreeMap<String, String>) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        let name = rest[start + 2..start + 2 + len].trim();
+        let end = start + 2 + len + 2;
+        match values.get(name) {
+            Some(value) => {
+                result.push_str(&rest[..start]);
+                result.push_str(value);
+            }
+            None => result.push_str(&rest[..end]),
+        }
+        rest = &rest[end..];
+    }
+    result.push_str(rest);
+    result
+}
+
 fn edit_forc_toml(out_dir: &Path, project_name: &str, real_name: &str) -> Result<()> {
     let mut file = File::open(out_dir.join(constants::MANIFEST_FILE_NAME))?;
     let mut toml = String::new();
@@ -153,9 +342,88 @@ fn edit_cargo_toml(out_dir: &Path, project_name: &str, real_name: &str) -> Resul
     Ok(())
 }
 
-fn copy_template_to_target(from: &PathBuf, to: &PathBuf) -> Result<()> {
-    let mut copy_options = CopyOptions::new();
-    copy_options.copy_inside = true;
-    copy(from, to, &copy_options)?;
+/// Copies the files of the template to the target directory, substituting the placeholders in
+/// their contents and paths. Files which are not valid UTF-8 are copied as they are.
+fn copy_template_to_target(
+    from: &Path,
+    to: &Path,
+    values: &BTreeMap<String, String>,
+) -> Result<()> {
+    let entries = walkdir::WalkDir::new(from)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|entry| entry.file_name() != ".git");
+    for entry in entries {
+        let entry = entry?;
+        let relative_path = entry.path().strip_prefix(from)?;
+        if relative_path == Path::new(TEMPLATE_MANIFEST_FILE_NAME) {
+            continue;
+        }
+        let target_path = to.join(substitute_placeholders(
+            &relative_path.to_string_lossy(),
+            values,
+        ));
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&target_path)?;
+            continue;
+        }
+        if target_path.exists() {
+            bail!("`{}` already exists", target_path.display());
+        }
+        if let Some(parent) = target_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let contents = fs::read(entry.path())?;
+        match String::from_utf8(contents) {
+            Ok(text) => fs::write(&target_path, substitute_placeholders(&text, values))?,
+            Err(err) => fs::write(&target_path, err.into_bytes())?,
+        }
+    }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substitutes_known_placeholders() {
+        let values = BTreeMap::from([
+            ("project-name".to_string(), "counter".to_string()),
+            ("authors".to_string(), "Jane Doe".to_string()),
+        ]);
+        assert_eq!(
+            substitute_placeholders(
+                "name = \"{{project-name}}\"\nauthors = [\"{{ authors }}\"]\n{{unknown}} {{",
+                &values
+            ),
+            "name = \"counter\"\nauthors = [\"Jane Doe\"]\n{{unknown}} {{"
+        );
+    }
+
+    #[test]
+    fn defines_override_defaults() {
+        let template_manifest: TemplateManifest = toml::from_str(
+            r#"
+            [placeholders.description]
+            prompt = "What does the contract do?"
+            default = "A Sway contract"
+
+            [placeholders.license]
+            default = "Apache-2.0"
+            "#,
+        )
+        .unwrap();
+        let defines = [("license".to_string(), "MIT".to_string())];
+        let values =
+            placeholder_values(&template_manifest, "counter", "Jane Doe", &defines, false).unwrap();
+        assert_eq!(values["project-name"], "counter");
+        assert_eq!(values["description"], "A Sway contract");
+        assert_eq!(values["license"], "MIT");
+
+        let defines = [("unknown".to_string(), "value".to_string())];
+        assert!(
+            placeholder_values(&template_manifest, "counter", "Jane Doe", &defines, false).is_err()
+        );
+    }
+}
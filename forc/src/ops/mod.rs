@@ -1,8 +1,14 @@
 pub mod forc_build;
+pub mod forc_cache;
 pub mod forc_check;
 pub mod forc_clean;
 pub mod forc_contract_id;
+pub mod forc_explain;
 pub mod forc_init;
+pub mod forc_migrate;
+pub mod forc_plugins;
 pub mod forc_predicate_root;
+pub mod forc_proxy;
+pub mod forc_storage_diff;
 pub mod forc_template;
 pub mod forc_update;
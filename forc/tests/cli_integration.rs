@@ -82,3 +82,187 @@ fn test_forc_test_both_logs() -> Result<(), rexpect::error::Error> {
     process.process.exit()?;
     Ok(())
 }
+
+#[test]
+fn test_forc_build_contract_calls() -> Result<(), rexpect::error::Error> {
+    let project_dir = test_fixtures_path().join("contract_calls");
+    let mut process = spawn(
+        &format!(
+            "cargo run --bin forc -- build --path {}",
+            project_dir.to_string_lossy()
+        ),
+        Some(TIMEOUT_MS),
+    )?;
+    process.exp_string("Finished")?;
+    // The `VAULT_ID` configurable lives in the data section.
+    process.exp_string("Data section size")?;
+    process.exp_eof()?;
+
+    let contract_calls_path = project_dir
+        .join("out")
+        .join("debug")
+        .join("contract_calls-contract_calls.json");
+    let contract_calls: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(contract_calls_path).unwrap()).unwrap();
+    let contract_calls = contract_calls.as_array().unwrap();
+    assert_eq!(contract_calls.len(), 2);
+
+    let deposit = &contract_calls[0];
+    assert_eq!(deposit["caller"], "Contract::deposit");
+    assert_eq!(deposit["abi"], "Vault");
+    assert_eq!(deposit["method"], "deposit");
+    assert_eq!(
+        deposit["target"],
+        serde_json::json!({ "kind": "configurable", "name": "VAULT_ID" })
+    );
+    assert_eq!(deposit["payable"], true);
+    assert_eq!(deposit["reads_storage"], true);
+    assert_eq!(deposit["writes_storage"], true);
+    assert_eq!(deposit["forwards_coins"], true);
+    assert_eq!(deposit["forwards_asset_id"], true);
+    assert_eq!(deposit["limits_gas"], false);
+
+    let balance = &contract_calls[1];
+    assert_eq!(balance["caller"], "Contract::balance_of");
+    assert_eq!(balance["method"], "balance");
+    assert_eq!(
+        balance["target"],
+        serde_json::json!({ "kind": "parameter", "name": "vault" })
+    );
+    assert_eq!(balance["payable"], false);
+    assert_eq!(balance["reads_storage"], true);
+    assert_eq!(balance["writes_storage"], false);
+    assert_eq!(balance["forwards_coins"], false);
+    Ok(())
+}
+
+#[test]
+fn test_forc_build_ir_input() -> Result<(), rexpect::error::Error> {
+    let ir_path = test_fixtures_path().join("ir_input").join("main.ir");
+    let mut process = spawn(
+        &format!(
+            "cargo run --bin forc -- build --ir-input {}",
+            ir_path.to_string_lossy()
+        ),
+        Some(TIMEOUT_MS),
+    )?;
+    process.exp_string("Building")?;
+    process.exp_string("Bytecode size:")?;
+    process.exp_eof()?;
+
+    let bin_path = test_fixtures_path()
+        .join("ir_input")
+        .join("out")
+        .join("debug")
+        .join("main.bin");
+    let bytecode = std::fs::read(bin_path).unwrap();
+    assert!(!bytecode.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_forc_check_all_targets() -> Result<(), rexpect::error::Error> {
+    let project_dir = test_fixtures_path().join("check_workspace");
+    let mut process = spawn(
+        &format!(
+            "cargo run --bin forc -- check --all-targets --path {}",
+            project_dir.to_string_lossy()
+        ),
+        Some(TIMEOUT_MS),
+    )?;
+    // The test of `check_script` doesn't type check.
+    process.exp_string("Mismatched types")?;
+    process.exp_string("unable to type check: check_script")?;
+    process.exp_eof()?;
+
+    // Without the tests, every member type checks.
+    let mut process = spawn(
+        &format!(
+            "cargo run --bin forc -- check --all-targets --disable-tests --path {}",
+            project_dir.to_string_lossy()
+        ),
+        Some(TIMEOUT_MS),
+    )?;
+    process.exp_eof()?;
+    assert!(matches!(
+        process.process.wait()?,
+        rexpect::process::wait::WaitStatus::Exited(_, 0)
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_forc_build_proxy() -> Result<(), rexpect::error::Error> {
+    let project_dir = test_fixtures_path().join("test_contract");
+    let mut process = spawn(
+        &format!(
+            "cargo run --bin forc -- build --proxy --path {}",
+            project_dir.to_string_lossy()
+        ),
+        Some(TIMEOUT_MS),
+    )?;
+    process.exp_string("proxy contract for test_contract")?;
+    process.exp_string("proxy wiring at")?;
+    process.exp_eof()?;
+
+    let output_dir = project_dir.join("out").join("debug");
+    let wiring_path = output_dir.join("test_contract-proxy-wiring.json");
+    let wiring: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(wiring_path).unwrap()).unwrap();
+
+    for (artifacts, name) in [
+        (&wiring["implementation"], "test_contract"),
+        (&wiring["proxy"], "test_contract-proxy"),
+    ] {
+        assert_eq!(artifacts["name"], name);
+        for (artifact, file_name) in [
+            ("bytecode", format!("{name}.bin")),
+            ("abi", format!("{name}-abi.json")),
+            ("storage_slots", format!("{name}-storage_slots.json")),
+        ] {
+            let path = output_dir.join(file_name);
+            assert_eq!(artifacts[artifact], path.to_str().unwrap());
+            assert!(path.exists());
+        }
+    }
+
+    // The implementation is deployed first, so that the proxy can be configured to target it.
+    let implementation_storage_slots = &wiring["implementation"]["storage_slots"];
+    let proxy_storage_slots = &wiring["proxy"]["storage_slots"];
+    let deployment_order = wiring["deployment_order"].as_array().unwrap();
+    assert_eq!(deployment_order.len(), 2);
+    assert_eq!(deployment_order[0]["contract"], "test_contract");
+    assert!(deployment_order[0].get("configurables").is_none());
+    assert_eq!(
+        deployment_order[0]["storage_slots"],
+        serde_json::json!([implementation_storage_slots])
+    );
+    assert_eq!(deployment_order[1]["contract"], "test_contract-proxy");
+    let configurables = deployment_order[1]["configurables"].as_object().unwrap();
+    let configurable_names: Vec<_> = configurables.keys().collect();
+    assert_eq!(configurable_names, ["INITIAL_OWNER", "INITIAL_TARGET"]);
+    assert_eq!(
+        deployment_order[1]["storage_slots"],
+        serde_json::json!([implementation_storage_slots, proxy_storage_slots])
+    );
+    Ok(())
+}
+
+#[test]
+fn test_forc_build_monomorphization_report() -> Result<(), rexpect::error::Error> {
+    let project_dir = test_fixtures_path().join("monomorphization");
+    let mut process = spawn(
+        &format!(
+            "cargo run --bin forc -- build --path {}",
+            project_dir.to_string_lossy()
+        ),
+        Some(TIMEOUT_MS),
+    )?;
+    process.exp_string(";; Monomorphization report:")?;
+    process.exp_string(";;   copies    IR size  function")?;
+    process.exp_string(";;        4")?;
+    process.exp_string("first")?;
+    process.exp_string("Finished")?;
+    process.exp_eof()?;
+    Ok(())
+}
@@ -0,0 +1,5 @@
+library;
+
+pub fn id(value: u64) -> u64 {
+    value
+}
@@ -0,0 +1,13 @@
+script;
+
+use check_lib::id;
+
+fn main() -> u64 {
+    id(42)
+}
+
+// Only type checked when the tests are.
+#[test]
+fn test_id() {
+    let _value: u64 = true;
+}
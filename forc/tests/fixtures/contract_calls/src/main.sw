@@ -0,0 +1,35 @@
+contract;
+
+abi Vault {
+    #[payable, storage(read, write)]
+    fn deposit();
+
+    #[storage(read)]
+    fn balance() -> u64;
+}
+
+abi Router {
+    #[payable]
+    fn deposit();
+
+    fn balance_of(vault: ContractId) -> u64;
+}
+
+configurable {
+    VAULT_ID: ContractId = ContractId::zero(),
+}
+
+impl Router for Contract {
+    #[payable]
+    fn deposit() {
+        let vault = abi(Vault, VAULT_ID.bits());
+        vault.deposit {
+            coins: std::context::msg_amount(),
+            asset_id: AssetId::base().bits(),
+        }();
+    }
+
+    fn balance_of(vault: ContractId) -> u64 {
+        abi(Vault, vault.bits()).balance()
+    }
+}
@@ -0,0 +1,12 @@
+script;
+
+fn first<T>(a: T, _b: T) -> T {
+    a
+}
+
+fn main() -> u64 {
+    let _ = first(true, false);
+    let _ = first(1u8, 2u8);
+    let _ = first(3u32, 4u32);
+    first(5u64, 6u64)
+}
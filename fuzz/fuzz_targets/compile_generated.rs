@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sway_fuzz::{assert_no_internal_error, generator::Program};
+
+fuzz_target!(|program: Program| {
+    assert_no_internal_error(&program.to_string());
+});
@@ -0,0 +1,54 @@
+//! Shrink a program making the compiler fail with an internal error and save it as a regression
+//! test in the `regressions` directory.
+//!
+//! Usage: `cargo run --bin minimize -- <PATH>`, where `<PATH>` is either a Sway program, or a crash
+//! artifact of the `compile_generated` fuzz target, i.e. the input the program was generated from.
+
+use arbitrary::{Arbitrary, Unstructured};
+use std::{path::Path, process::ExitCode};
+use sway_fuzz::{
+    generator::Program,
+    minimize::{minimize, save_regression},
+};
+
+fn main() -> ExitCode {
+    let Some(path) = std::env::args().nth(1) else {
+        eprintln!("Usage: minimize <PATH>");
+        return ExitCode::FAILURE;
+    };
+    let bytes = match std::fs::read(&path) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            eprintln!("Failed to read {path}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let source = if path.ends_with(".sw") {
+        String::from_utf8_lossy(&bytes).into_owned()
+    } else {
+        match Program::arbitrary_take_rest(Unstructured::new(&bytes)) {
+            Ok(program) => program.to_string(),
+            Err(err) => {
+                eprintln!("Failed to generate a program from {path}: {err}");
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+
+    let Some(minimized) = minimize(&source) else {
+        eprintln!("The program doesn't make the compiler fail with an internal error:\n{source}");
+        return ExitCode::FAILURE;
+    };
+    let regressions_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("regressions");
+    match save_regression(&regressions_dir, &minimized) {
+        Ok(test_path) => {
+            println!("{minimized}");
+            println!("Saved the minimized program to {}", test_path.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Failed to save the minimized program: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
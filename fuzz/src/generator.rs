@@ -0,0 +1,535 @@
+//! A grammar-aware generator of Sway programs.
+//!
+//! The programs are generated from the bytes provided by the fuzzer through [arbitrary], in a way
+//! which keeps them well typed, so that they get past type checking and reach the IR and ASM
+//! generation. As the programs are compiled without `core`, the arithmetic is done by intrinsics.
+//!
+//! Once the bytes run out, the generator picks the first, simplest, choice everywhere, so any
+//! input produces a complete program.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+use std::fmt::{self, Write};
+
+/// The maximum nesting depth of the generated types, expressions and blocks.
+const MAX_DEPTH: u32 = 4;
+/// The maximum number of statements in a block.
+const MAX_STMTS: u32 = 5;
+/// The maximum number of functions besides `main`.
+const MAX_FNS: u32 = 4;
+/// The maximum number of parameters of a function.
+const MAX_PARAMS: u32 = 3;
+
+const ARITH_INTRINSICS: &[&str] = &[
+    "__add", "__sub", "__mul", "__div", "__mod", "__and", "__or", "__xor", "__lsh", "__rsh",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ty {
+    U64,
+    Bool,
+    Tuple(Vec<Ty>),
+    Array(Box<Ty>, u64),
+}
+
+#[derive(Debug, Clone)]
+pub enum Expr {
+    U64(u64),
+    Bool(bool),
+    Var(String),
+    /// A call to an intrinsic, e.g. `__add(a, b)`.
+    Intrinsic(&'static str, Vec<Expr>),
+    /// `a && b` or `a || b`.
+    Lazy(&'static str, Box<Expr>, Box<Expr>),
+    If(Box<Expr>, Box<Block>, Box<Block>),
+    Block(Box<Block>),
+    Call(String, Vec<Expr>),
+    Tuple(Vec<Expr>),
+    TupleField(Box<Expr>, usize),
+    Array(Vec<Expr>),
+    Index(Box<Expr>, Box<Expr>),
+}
+
+#[derive(Debug, Clone)]
+pub enum Stmt {
+    Let {
+        name: String,
+        mutable: bool,
+        ty: Ty,
+        value: Expr,
+    },
+    Assign {
+        name: String,
+        value: Expr,
+    },
+    While {
+        cond: Expr,
+        body: Vec<Stmt>,
+    },
+    Expr(Expr),
+}
+
+#[derive(Debug, Clone)]
+pub struct Block {
+    pub stmts: Vec<Stmt>,
+    pub tail: Expr,
+}
+
+#[derive(Debug, Clone)]
+pub struct FnDecl {
+    pub name: String,
+    pub params: Vec<(String, Ty)>,
+    pub ret_ty: Ty,
+    pub body: Block,
+}
+
+/// A script whose `main` function returns a `u64` and which may call the other functions.
+#[derive(Debug, Clone)]
+pub struct Program {
+    pub fns: Vec<FnDecl>,
+}
+
+impl<'a> Arbitrary<'a> for Program {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Generator {
+            u,
+            fns: vec![],
+            vars: vec![],
+            next_name: 0,
+        }
+        .program()
+    }
+}
+
+struct Var {
+    name: String,
+    ty: Ty,
+    mutable: bool,
+}
+
+struct Generator<'a, 'b> {
+    u: &'b mut Unstructured<'a>,
+    /// The functions generated so far, which can be called without introducing recursion.
+    fns: Vec<(String, Vec<Ty>, Ty)>,
+    /// The variables in scope.
+    vars: Vec<Var>,
+    next_name: usize,
+}
+
+impl Generator<'_, '_> {
+    fn program(mut self) -> Result<Program> {
+        let mut fns = vec![];
+        for _ in 0..self.u.int_in_range(0..=MAX_FNS)? {
+            let name = self.fresh_name("f");
+            let params = (0..self.u.int_in_range(0..=MAX_PARAMS)?)
+                .map(|_| Ok((self.fresh_name("p"), self.ty(1)?)))
+                .collect::<Result<Vec<_>>>()?;
+            let ret_ty = self.ty(1)?;
+            self.vars = params
+                .iter()
+                .map(|(name, ty)| Var {
+                    name: name.clone(),
+                    ty: ty.clone(),
+                    mutable: false,
+                })
+                .collect();
+            let body = self.block(&ret_ty, 0)?;
+            self.fns.push((
+                name.clone(),
+                params.iter().map(|(_, ty)| ty.clone()).collect(),
+                ret_ty.clone(),
+            ));
+            fns.push(FnDecl {
+                name,
+                params,
+                ret_ty,
+                body,
+            });
+        }
+
+        self.vars.clear();
+        let body = self.block(&Ty::U64, 0)?;
+        fns.push(FnDecl {
+            name: "main".into(),
+            params: vec![],
+            ret_ty: Ty::U64,
+            body,
+        });
+        Ok(Program { fns })
+    }
+
+    fn fresh_name(&mut self, prefix: &str) -> String {
+        self.next_name += 1;
+        format!("{prefix}{}", self.next_name)
+    }
+
+    fn ty(&mut self, depth: u32) -> Result<Ty> {
+        let choices = if depth < MAX_DEPTH { 4 } else { 2 };
+        Ok(match self.u.choose_index(choices)? {
+            0 => Ty::U64,
+            1 => Ty::Bool,
+            2 => Ty::Tuple(
+                (0..self.u.int_in_range(2..=3)?)
+                    .map(|_| self.ty(depth + 1))
+                    .collect::<Result<_>>()?,
+            ),
+            _ => Ty::Array(Box::new(self.ty(depth + 1)?), self.u.int_in_range(1..=4)?),
+        })
+    }
+
+    /// A literal of type `ty`, possibly made of variables.
+    fn leaf(&mut self, ty: &Ty) -> Result<Expr> {
+        let vars: Vec<String> = self
+            .vars
+            .iter()
+            .filter(|var| var.ty == *ty)
+            .map(|var| var.name.clone())
+            .collect();
+        if !vars.is_empty() && self.u.arbitrary()? {
+            return Ok(Expr::Var(self.u.choose(&vars)?.clone()));
+        }
+        Ok(match ty {
+            Ty::U64 => match self.u.choose_index(3)? {
+                0 => Expr::U64(self.u.int_in_range(0..=16)?),
+                1 => Expr::U64(self.u.arbitrary()?),
+                _ => Expr::U64(u64::MAX),
+            },
+            Ty::Bool => Expr::Bool(self.u.arbitrary()?),
+            Ty::Tuple(tys) => {
+                Expr::Tuple(tys.iter().map(|ty| self.leaf(ty)).collect::<Result<_>>()?)
+            }
+            Ty::Array(ty, len) => {
+                Expr::Array((0..*len).map(|_| self.leaf(ty)).collect::<Result<_>>()?)
+            }
+        })
+    }
+
+    fn expr(&mut self, ty: &Ty, depth: u32) -> Result<Expr> {
+        if depth >= MAX_DEPTH {
+            return self.leaf(ty);
+        }
+        let callees: Vec<(String, Vec<Ty>)> = self
+            .fns
+            .iter()
+            .filter(|(_, _, ret_ty)| ret_ty == ty)
+            .map(|(name, param_tys, _)| (name.clone(), param_tys.clone()))
+            .collect();
+
+        Ok(match self.u.choose_index(7)? {
+            0 => self.leaf(ty)?,
+            1 => match ty {
+                Ty::U64 => {
+                    let intrinsic = *self.u.choose(ARITH_INTRINSICS)?;
+                    Expr::Intrinsic(
+                        intrinsic,
+                        vec![self.expr(ty, depth + 1)?, self.expr(ty, depth + 1)?],
+                    )
+                }
+                Ty::Bool => match self.u.choose_index(3)? {
+                    0 => {
+                        let intrinsic = *self.u.choose(&["__eq", "__gt", "__lt"])?;
+                        Expr::Intrinsic(
+                            intrinsic,
+                            vec![
+                                self.expr(&Ty::U64, depth + 1)?,
+                                self.expr(&Ty::U64, depth + 1)?,
+                            ],
+                        )
+                    }
+                    1 => Expr::Intrinsic(
+                        "__eq",
+                        vec![self.expr(ty, depth + 1)?, self.expr(ty, depth + 1)?],
+                    ),
+                    _ => Expr::Lazy(
+                        *self.u.choose(&["&&", "||"])?,
+                        Box::new(self.expr(ty, depth + 1)?),
+                        Box::new(self.expr(ty, depth + 1)?),
+                    ),
+                },
+                Ty::Tuple(tys) => Expr::Tuple(
+                    tys.iter()
+                        .map(|ty| self.expr(ty, depth + 1))
+                        .collect::<Result<_>>()?,
+                ),
+                Ty::Array(ty, len) => Expr::Array(
+                    (0..*len)
+                        .map(|_| self.expr(ty, depth + 1))
+                        .collect::<Result<_>>()?,
+                ),
+            },
+            2 => Expr::If(
+                Box::new(self.expr(&Ty::Bool, depth + 1)?),
+                Box::new(self.block(ty, depth + 1)?),
+                Box::new(self.block(ty, depth + 1)?),
+            ),
+            3 => Expr::Block(Box::new(self.block(ty, depth + 1)?)),
+            4 if !callees.is_empty() => {
+                let (name, param_tys) = self.u.choose(&callees)?.clone();
+                let args = param_tys
+                    .iter()
+                    .map(|ty| self.expr(ty, depth + 1))
+                    .collect::<Result<_>>()?;
+                Expr::Call(name, args)
+            }
+            5 => {
+                let mut tys = vec![self.ty(depth + 1)?, self.ty(depth + 1)?];
+                let idx = self.u.choose_index(tys.len() + 1)?;
+                tys.insert(idx, ty.clone());
+                let tuple = self.expr(&Ty::Tuple(tys), depth + 1)?;
+                Expr::TupleField(Box::new(tuple), idx)
+            }
+            6 => {
+                let len = self.u.int_in_range(1..=4)?;
+                let array = self.expr(&Ty::Array(Box::new(ty.clone()), len), depth + 1)?;
+                // Keep the index in bounds, even if it isn't known at compile time.
+                let index = Expr::Intrinsic(
+                    "__mod",
+                    vec![self.expr(&Ty::U64, depth + 1)?, Expr::U64(len)],
+                );
+                Expr::Index(Box::new(array), Box::new(index))
+            }
+            _ => self.leaf(ty)?,
+        })
+    }
+
+    fn block(&mut self, ty: &Ty, depth: u32) -> Result<Block> {
+        let scope = self.vars.len();
+        let stmts = self.stmts(depth)?;
+        let tail = self.expr(ty, depth + 1)?;
+        self.vars.truncate(scope);
+        Ok(Block { stmts, tail })
+    }
+
+    /// The statements of a block, which may declare variables in the scope of the block.
+    fn stmts(&mut self, depth: u32) -> Result<Vec<Stmt>> {
+        let max_stmts = MAX_STMTS.saturating_sub(depth);
+        let mut stmts = vec![];
+        for _ in 0..self.u.int_in_range(0..=max_stmts)? {
+            let assignable: Vec<(String, Ty)> = self
+                .vars
+                .iter()
+                .filter(|var| var.mutable)
+                .map(|var| (var.name.clone(), var.ty.clone()))
+                .collect();
+            let stmt = match self.u.choose_index(4)? {
+                1 if !assignable.is_empty() => {
+                    let (name, ty) = self.u.choose(&assignable)?.clone();
+                    Stmt::Assign {
+                        name,
+                        value: self.expr(&ty, depth + 1)?,
+                    }
+                }
+                2 if depth < MAX_DEPTH => {
+                    let cond = self.expr(&Ty::Bool, depth + 1)?;
+                    let scope = self.vars.len();
+                    let body = self.stmts(depth + 1)?;
+                    self.vars.truncate(scope);
+                    Stmt::While { cond, body }
+                }
+                3 if !self.fns.is_empty() => {
+                    let (name, param_tys, _) = self.u.choose(&self.fns)?.clone();
+                    let args = param_tys
+                        .iter()
+                        .map(|ty| self.expr(ty, depth + 1))
+                        .collect::<Result<_>>()?;
+                    Stmt::Expr(Expr::Call(name, args))
+                }
+                _ => {
+                    let ty = self.ty(depth + 1)?;
+                    let value = self.expr(&ty, depth + 1)?;
+                    let name = self.fresh_name("v");
+                    let mutable = self.u.arbitrary()?;
+                    self.vars.push(Var {
+                        name: name.clone(),
+                        ty: ty.clone(),
+                        mutable,
+                    });
+                    Stmt::Let {
+                        name,
+                        mutable,
+                        ty,
+                        value,
+                    }
+                }
+            };
+            stmts.push(stmt);
+        }
+        Ok(stmts)
+    }
+}
+
+// Every statement is printed on its own line, so that the programs can be shrunk line by line.
+
+impl fmt::Display for Ty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Ty::U64 => write!(f, "u64"),
+            Ty::Bool => write!(f, "bool"),
+            Ty::Tuple(tys) => write!(f, "({})", join(tys, ", ")),
+            Ty::Array(ty, len) => write!(f, "[{ty}; {len}]"),
+        }
+    }
+}
+
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "script;")?;
+        for decl in &self.fns {
+            let params = decl
+                .params
+                .iter()
+                .map(|(name, ty)| format!("{name}: {ty}"))
+                .collect::<Vec<_>>();
+            writeln!(f)?;
+            writeln!(
+                f,
+                "fn {}({}) -> {} {{",
+                decl.name,
+                params.join(", "),
+                decl.ret_ty
+            )?;
+            write_block_contents(f, &decl.body, 1)?;
+            writeln!(f, "}}")?;
+        }
+        Ok(())
+    }
+}
+
+fn join(items: &[impl fmt::Display], sep: &str) -> String {
+    items
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<_>>()
+        .join(sep)
+}
+
+fn indentation(indent: usize) -> String {
+    "    ".repeat(indent)
+}
+
+fn write_block_contents(f: &mut impl Write, block: &Block, indent: usize) -> fmt::Result {
+    write_stmts(f, &block.stmts, indent)?;
+    write!(f, "{}", indentation(indent))?;
+    write_expr(f, &block.tail, indent)?;
+    writeln!(f)
+}
+
+fn write_stmts(f: &mut impl Write, stmts: &[Stmt], indent: usize) -> fmt::Result {
+    for stmt in stmts {
+        write!(f, "{}", indentation(indent))?;
+        match stmt {
+            Stmt::Let {
+                name,
+                mutable,
+                ty,
+                value,
+            } => {
+                let mutable = if *mutable { "mut " } else { "" };
+                write!(f, "let {mutable}{name}: {ty} = ")?;
+                write_expr(f, value, indent)?;
+                writeln!(f, ";")?;
+            }
+            Stmt::Assign { name, value } => {
+                write!(f, "{name} = ")?;
+                write_expr(f, value, indent)?;
+                writeln!(f, ";")?;
+            }
+            Stmt::While { cond, body } => {
+                write!(f, "while ")?;
+                write_operand(f, cond, indent)?;
+                writeln!(f, " {{")?;
+                write_stmts(f, body, indent + 1)?;
+                writeln!(f, "{}}}", indentation(indent))?;
+            }
+            Stmt::Expr(expr) => {
+                write_expr(f, expr, indent)?;
+                writeln!(f, ";")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Write an expression used as an operand, parenthesized unless it is a simple expression.
+fn write_operand(f: &mut impl Write, expr: &Expr, indent: usize) -> fmt::Result {
+    match expr {
+        Expr::U64(_)
+        | Expr::Bool(_)
+        | Expr::Var(_)
+        | Expr::Intrinsic(..)
+        | Expr::Call(..)
+        | Expr::Tuple(_)
+        | Expr::Array(_) => write_expr(f, expr, indent),
+        _ => {
+            write!(f, "(")?;
+            write_expr(f, expr, indent)?;
+            write!(f, ")")
+        }
+    }
+}
+
+fn write_args(f: &mut impl Write, args: &[Expr], indent: usize) -> fmt::Result {
+    for (idx, arg) in args.iter().enumerate() {
+        if idx > 0 {
+            write!(f, ", ")?;
+        }
+        write_operand(f, arg, indent)?;
+    }
+    Ok(())
+}
+
+fn write_expr(f: &mut impl Write, expr: &Expr, indent: usize) -> fmt::Result {
+    match expr {
+        Expr::U64(n) => write!(f, "{n}"),
+        Expr::Bool(b) => write!(f, "{b}"),
+        Expr::Var(name) => write!(f, "{name}"),
+        Expr::Intrinsic(name, args) => {
+            write!(f, "{name}(")?;
+            write_args(f, args, indent)?;
+            write!(f, ")")
+        }
+        Expr::Call(name, args) => {
+            write!(f, "{name}(")?;
+            write_args(f, args, indent)?;
+            write!(f, ")")
+        }
+        Expr::Lazy(op, lhs, rhs) => {
+            write_operand(f, lhs, indent)?;
+            write!(f, " {op} ")?;
+            write_operand(f, rhs, indent)
+        }
+        Expr::If(cond, then, r#else) => {
+            write!(f, "if ")?;
+            write_operand(f, cond, indent)?;
+            writeln!(f, " {{")?;
+            write_block_contents(f, then, indent + 1)?;
+            writeln!(f, "{}}} else {{", indentation(indent))?;
+            write_block_contents(f, r#else, indent + 1)?;
+            write!(f, "{}}}", indentation(indent))
+        }
+        Expr::Block(block) => {
+            writeln!(f, "{{")?;
+            write_block_contents(f, block, indent + 1)?;
+            write!(f, "{}}}", indentation(indent))
+        }
+        Expr::Tuple(elems) => {
+            write!(f, "(")?;
+            write_args(f, elems, indent)?;
+            write!(f, ")")
+        }
+        Expr::TupleField(tuple, idx) => {
+            write_operand(f, tuple, indent)?;
+            write!(f, ".{idx}")
+        }
+        Expr::Array(elems) => {
+            write!(f, "[")?;
+            write_args(f, elems, indent)?;
+            write!(f, "]")
+        }
+        Expr::Index(array, index) => {
+            write_operand(f, array, indent)?;
+            write!(f, "[")?;
+            write_expr(f, index, indent)?;
+            write!(f, "]")
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! Fuzzing the Sway compiler.
+//!
+//! The fuzz targets compile Sway programs through all the phases of the compiler, i.e. parsing,
+//! type checking, IR generation and optimization and ASM generation, and fail on internal compiler
+//! errors, i.e. on panics and [CompileError::Internal] errors. The programs are either arbitrary
+//! text, mostly exercising the parser, or well typed programs produced by the grammar-aware
+//! [generator], which get past type checking and exercise the later phases.
+//!
+//! The programs found to crash the compiler are shrunk by [minimize], and saved as regression
+//! tests in the `regressions` directory.
+
+pub mod generator;
+pub mod minimize;
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    sync::Arc,
+};
+use sway_core::{
+    language::Visibility, semantic_analysis::namespace, BuildConfig, BuildTarget, Engines, OptLevel,
+};
+use sway_error::{error::CompileError, handler::Handler};
+use sway_features::ExperimentalFeatures;
+use sway_types::Ident;
+
+/// The name of the package the fuzzed programs are compiled as.
+const PACKAGE_NAME: &str = "fuzz";
+
+/// The result of compiling a program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The program was compiled to ASM.
+    Compiled,
+    /// The program was rejected with regular compile errors.
+    Rejected,
+    /// The compiler panicked or failed with an internal compiler error.
+    InternalError(String),
+}
+
+impl Outcome {
+    pub fn is_internal_error(&self) -> bool {
+        matches!(self, Outcome::InternalError(_))
+    }
+}
+
+/// Compile `source` as the only module of a package without any dependencies, not even `core`,
+/// down to ASM.
+pub fn compile(source: &str) -> Outcome {
+    let compile = || {
+        let engines = Engines::default();
+        let handler = Handler::default();
+        let build_config = BuildConfig::root_from_file_name_and_manifest_path(
+            PathBuf::from("/fuzz/src/main.sw"),
+            PathBuf::from("/fuzz"),
+            BuildTarget::Fuel,
+        )
+        .with_optimization_level(OptLevel::Opt1);
+        let mut namespace = namespace::Root::from(namespace::Module::new(
+            Ident::new_no_span(PACKAGE_NAME.to_string()),
+            Visibility::Private,
+            None,
+        ));
+        // The new encoding requires `core`.
+        let experimental = ExperimentalFeatures {
+            new_encoding: false,
+            ..Default::default()
+        };
+
+        let res = sway_core::compile_to_asm(
+            &handler,
+            &engines,
+            Arc::from(source),
+            &mut namespace,
+            &build_config,
+            PACKAGE_NAME,
+            experimental,
+        );
+        let (errors, _warnings) = handler.consume();
+        if let Some(internal_error) = errors.iter().find(|error| {
+            matches!(
+                error,
+                CompileError::Internal(..) | CompileError::InternalOwned(..)
+            )
+        }) {
+            return Outcome::InternalError(internal_error.to_string());
+        }
+        match res {
+            Ok(_) if errors.is_empty() => Outcome::Compiled,
+            _ => Outcome::Rejected,
+        }
+    };
+
+    panic::catch_unwind(AssertUnwindSafe(compile)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Outcome::InternalError(format!("The compiler panicked: {message}"))
+    })
+}
+
+/// Compile `source` and panic if the compiler fails with an internal error.
+pub fn assert_no_internal_error(source: &str) {
+    if let Outcome::InternalError(error) = compile(source) {
+        panic!("{error}\n\nProgram:\n{source}");
+    }
+}
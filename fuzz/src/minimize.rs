@@ -0,0 +1,58 @@
+//! Shrinking the programs which make the compiler fail with an internal error.
+
+use crate::{compile, Outcome};
+use std::{
+    collections::hash_map::DefaultHasher,
+    fs,
+    hash::{Hash, Hasher},
+    io,
+    path::{Path, PathBuf},
+};
+
+/// Shrink `source` to a program with as few lines as possible which still makes the compiler fail
+/// with the same internal error, by delta debugging the lines of the program.
+///
+/// Returns `None` if `source` doesn't make the compiler fail with an internal error.
+pub fn minimize(source: &str) -> Option<String> {
+    let Outcome::InternalError(error) = compile(source) else {
+        return None;
+    };
+    // Only the first line of the error is compared, as the rest usually contains spans.
+    let error = error.lines().next().unwrap_or_default().to_string();
+    let fails_the_same = |lines: &[&str]| match compile(&lines.join("\n")) {
+        Outcome::InternalError(other) => other.lines().next().unwrap_or_default() == error,
+        _ => false,
+    };
+
+    let mut lines: Vec<&str> = source.lines().collect();
+    let mut chunks = 2;
+    while lines.len() >= 2 {
+        let chunk_len = lines.len().div_ceil(chunks);
+        let reduced = (0..lines.len()).step_by(chunk_len).find_map(|start| {
+            let mut complement = lines.clone();
+            complement.drain(start..(start + chunk_len).min(lines.len()));
+            fails_the_same(&complement).then_some(complement)
+        });
+        match reduced {
+            Some(reduced) => {
+                lines = reduced;
+                chunks = (chunks - 1).max(2);
+            }
+            None if chunks < lines.len() => chunks = (chunks * 2).min(lines.len()),
+            None => break,
+        }
+    }
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Save `source` as a regression test in `dir`, named after the hash of the program so that
+/// saving the same program twice doesn't duplicate it. Returns the path of the test.
+pub fn save_regression(dir: &Path, source: &str) -> io::Result<PathBuf> {
+    let mut hasher = DefaultHasher::new();
+    source.hash(&mut hasher);
+    let path = dir.join(format!("crash-{:016x}.sw", hasher.finish()));
+    fs::create_dir_all(dir)?;
+    fs::write(&path, source)?;
+    Ok(path)
+}
@@ -0,0 +1,52 @@
+use arbitrary::{Arbitrary, Unstructured};
+use std::{fs, path::Path};
+use sway_fuzz::{compile, generator::Program, Outcome};
+
+/// Replay the programs which made the compiler fail with an internal error in the past.
+#[test]
+fn regressions() {
+    let regressions_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("regressions");
+    let Ok(entries) = fs::read_dir(regressions_dir) else {
+        return;
+    };
+    for path in entries.map(|entry| entry.unwrap().path()) {
+        if path.extension().is_some_and(|ext| ext == "sw") {
+            let source = fs::read_to_string(&path).unwrap();
+            if let Outcome::InternalError(error) = compile(&source) {
+                panic!("{}: {error}", path.display());
+            }
+        }
+    }
+}
+
+/// The generated programs are meant to be well typed, so almost all of them should compile.
+#[test]
+fn generated_programs_compile() {
+    const PROGRAMS: u64 = 64;
+
+    let mut compiled = 0;
+    for seed in 0..PROGRAMS {
+        // A xorshift generator is good enough to feed the generator with varied bytes.
+        let mut state = seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1;
+        let bytes: Vec<u8> = (0..1024)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect();
+        let program = Program::arbitrary_take_rest(Unstructured::new(&bytes))
+            .unwrap()
+            .to_string();
+        match compile(&program) {
+            Outcome::Compiled => compiled += 1,
+            Outcome::Rejected => {}
+            Outcome::InternalError(error) => panic!("{error}\n\nProgram:\n{program}"),
+        }
+    }
+    assert!(
+        compiled * 2 > PROGRAMS,
+        "only {compiled} of {PROGRAMS} generated programs compiled"
+    );
+}
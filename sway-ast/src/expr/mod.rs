@@ -48,9 +48,17 @@ pub enum Expr {
         iterator: Box<Expr>,
         block: Braces<CodeBlockContents>,
     },
+    /// A labeled block or loop, like `'outer: while cond { ... }`.
+    ///
+    /// The `expr` is always an [Expr::Block], an [Expr::While], or an [Expr::For].
+    Labeled {
+        label: Label,
+        colon_token: ColonToken,
+        expr: Box<Expr>,
+    },
     FuncApp {
         func: Box<Expr>,
-        args: Parens<Punctuated<Expr, CommaToken>>,
+        args: Parens<Punctuated<CallArg, CommaToken>>,
     },
     Index {
         target: Box<Expr>,
@@ -61,7 +69,7 @@ pub enum Expr {
         dot_token: DotToken,
         path_seg: PathExprSegment,
         contract_args_opt: Option<Braces<Punctuated<ExprStructField, CommaToken>>>,
-        args: Parens<Punctuated<Expr, CommaToken>>,
+        args: Parens<Punctuated<CallArg, CommaToken>>,
     },
     FieldProjection {
         target: Box<Expr>,
@@ -74,6 +82,11 @@ pub enum Expr {
         field: BigUint,
         field_span: Span,
     },
+    /// A propagation of the failure of an `Option` or a `Result`, like `value?`.
+    Try {
+        expr: Box<Expr>,
+        question_mark_token: QuestionMarkToken,
+    },
     Ref {
         ampersand_token: AmpersandToken,
         mut_token: Option<MutToken>,
@@ -87,6 +100,11 @@ pub enum Expr {
         bang_token: BangToken,
         expr: Box<Expr>,
     },
+    Cast {
+        expr: Box<Expr>,
+        as_token: AsToken,
+        ty: Ty,
+    },
     Mul {
         lhs: Box<Expr>,
         star_token: StarToken,
@@ -182,19 +200,44 @@ pub enum Expr {
         double_pipe_token: DoublePipeToken,
         rhs: Box<Expr>,
     },
+    /// A range, like `start..end`, `start..` or `..end`.
+    Range {
+        start: Option<Box<Expr>>,
+        double_dot_token: DoubleDotToken,
+        end: Option<Box<Expr>>,
+    },
     Reassignment {
         assignable: Assignable,
         reassignment_op: ReassignmentOp,
         expr: Box<Expr>,
     },
+    /// A `break`, optionally out of a labeled block or loop, like `break 'outer`.
+    ///
+    /// Only a `break` out of a labeled block can have a value, like `break 'found index`.
     Break {
         break_token: BreakToken,
+        label_opt: Option<Label>,
+        value_opt: Option<Box<Expr>>,
     },
     Continue {
         continue_token: ContinueToken,
+        label_opt: Option<Label>,
     },
 }
 
+/// A label of a block or a loop, like `'outer`.
+#[derive(Clone, Debug, Serialize)]
+pub struct Label {
+    pub apostrophe_token: ApostropheToken,
+    pub name: Ident,
+}
+
+impl Spanned for Label {
+    fn span(&self) -> Span {
+        Span::join(self.apostrophe_token.span(), &self.name.span())
+    }
+}
+
 impl Spanned for Expr {
     fn span(&self) -> Span {
         match self {
@@ -235,6 +278,7 @@ impl Spanned for Expr {
             Expr::For {
                 for_token, block, ..
             } => Span::join(for_token.span(), &block.span()),
+            Expr::Labeled { label, expr, .. } => Span::join(label.span(), &expr.span()),
             Expr::FuncApp { func, args } => Span::join(func.span(), &args.span()),
             Expr::Index { target, arg } => Span::join(target.span(), &arg.span()),
             Expr::MethodCall { target, args, .. } => Span::join(target.span(), &args.span()),
@@ -242,6 +286,10 @@ impl Spanned for Expr {
             Expr::TupleFieldProjection {
                 target, field_span, ..
             } => Span::join(target.span(), field_span),
+            Expr::Try {
+                expr,
+                question_mark_token,
+            } => Span::join(expr.span(), &question_mark_token.span()),
             Expr::Ref {
                 ampersand_token,
                 expr,
@@ -249,6 +297,7 @@ impl Spanned for Expr {
             } => Span::join(ampersand_token.span(), &expr.span()),
             Expr::Deref { star_token, expr } => Span::join(star_token.span(), &expr.span()),
             Expr::Not { bang_token, expr } => Span::join(bang_token.span(), &expr.span()),
+            Expr::Cast { expr, ty, .. } => Span::join(expr.span(), &ty.span()),
             Expr::Pow { lhs, rhs, .. } => Span::join(lhs.span(), &rhs.span()),
             Expr::Mul { lhs, rhs, .. } => Span::join(lhs.span(), &rhs.span()),
             Expr::Div { lhs, rhs, .. } => Span::join(lhs.span(), &rhs.span()),
@@ -268,11 +317,39 @@ impl Spanned for Expr {
             Expr::GreaterThanEq { lhs, rhs, .. } => Span::join(lhs.span(), &rhs.span()),
             Expr::LogicalAnd { lhs, rhs, .. } => Span::join(lhs.span(), &rhs.span()),
             Expr::LogicalOr { lhs, rhs, .. } => Span::join(lhs.span(), &rhs.span()),
+            Expr::Range {
+                start,
+                double_dot_token,
+                end,
+            } => {
+                let span = match start {
+                    Some(start) => Span::join(start.span(), &double_dot_token.span()),
+                    None => double_dot_token.span(),
+                };
+                match end {
+                    Some(end) => Span::join(span, &end.span()),
+                    None => span,
+                }
+            }
             Expr::Reassignment {
                 assignable, expr, ..
             } => Span::join(assignable.span(), &expr.span()),
-            Expr::Break { break_token } => break_token.span(),
-            Expr::Continue { continue_token } => continue_token.span(),
+            Expr::Break {
+                break_token,
+                label_opt,
+                value_opt,
+            } => match (value_opt, label_opt) {
+                (Some(value), _) => Span::join(break_token.span(), &value.span()),
+                (None, Some(label)) => Span::join(break_token.span(), &label.span()),
+                (None, None) => break_token.span(),
+            },
+            Expr::Continue {
+                continue_token,
+                label_opt,
+            } => match label_opt {
+                Some(label) => Span::join(continue_token.span(), &label.span()),
+                None => continue_token.span(),
+            },
         }
     }
 }
@@ -335,6 +412,9 @@ pub enum IfCondition {
         lhs: Box<Pattern>,
         eq_token: EqToken,
         rhs: Box<Expr>,
+        /// The conditions chained to the `let` with `&&`, as in `if let Some(x) = e && x > 0`.
+        /// Chained `let` conditions have no chain of their own.
+        chain: Vec<(DoubleAmpersandToken, IfCondition)>,
     },
 }
 
@@ -380,6 +460,8 @@ pub enum ExprArrayDescriptor {
 
 #[derive(Clone, Debug, Serialize)]
 pub struct MatchBranch {
+    /// The optional leading pipe, as in `| A | B => ...`.
+    pub pipe_token_opt: Option<PipeToken>,
     pub pattern: Pattern,
     pub fat_right_arrow_token: FatRightArrowToken,
     pub kind: MatchBranchKind,
@@ -387,7 +469,11 @@ pub struct MatchBranch {
 
 impl Spanned for MatchBranch {
     fn span(&self) -> Span {
-        Span::join(self.pattern.span(), &self.kind.span())
+        let start = match &self.pipe_token_opt {
+            Some(pipe_token) => pipe_token.span(),
+            None => self.pattern.span(),
+        };
+        Span::join(start, &self.kind.span())
     }
 }
 
@@ -449,6 +535,23 @@ impl Spanned for ExprStructField {
     }
 }
 
+/// An argument of a function or method call, optionally preceded by the name of the parameter it
+/// is passed to, like `amount: 5`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CallArg {
+    pub name_opt: Option<(Ident, ColonToken)>,
+    pub value: Expr,
+}
+
+impl Spanned for CallArg {
+    fn span(&self) -> Span {
+        match &self.name_opt {
+            None => self.value.span(),
+            Some((name, _colon_token)) => Span::join(name.span(), &self.value.span()),
+        }
+    }
+}
+
 impl Expr {
     /// Returns the resulting [Assignable] if the `self` is a
     /// valid [Assignable], or an error containing the [Expr]
@@ -515,7 +618,8 @@ impl Expr {
             | Expr::If(..)
             | Expr::Match { .. }
             | Expr::While { .. }
-            | Expr::For { .. } => true,
+            | Expr::For { .. }
+            | Expr::Labeled { .. } => true,
             Expr::Error(..)
             | Expr::Path(..)
             | Expr::Literal(..)
@@ -530,9 +634,11 @@ impl Expr {
             | Expr::MethodCall { .. }
             | Expr::FieldProjection { .. }
             | Expr::TupleFieldProjection { .. }
+            | Expr::Try { .. }
             | Expr::Ref { .. }
             | Expr::Deref { .. }
             | Expr::Not { .. }
+            | Expr::Cast { .. }
             | Expr::Mul { .. }
             | Expr::Div { .. }
             | Expr::Pow { .. }
@@ -552,6 +658,7 @@ impl Expr {
             | Expr::GreaterThanEq { .. }
             | Expr::LogicalAnd { .. }
             | Expr::LogicalOr { .. }
+            | Expr::Range { .. }
             | Expr::Reassignment { .. }
             | Expr::Break { .. }
             | Expr::Continue { .. } => false,
@@ -576,14 +683,17 @@ impl Expr {
             Expr::Match { .. } => "match expression",
             Expr::While { .. } => "while loop",
             Expr::For { .. } => "for loop",
+            Expr::Labeled { .. } => "labeled expression",
             Expr::FuncApp { .. } => "function call",
             Expr::Index { .. } => "array element access",
             Expr::MethodCall { .. } => "method call",
             Expr::FieldProjection { .. } => "struct field access",
             Expr::TupleFieldProjection { .. } => "tuple element access",
+            Expr::Try { .. } => "propagation",
             Expr::Ref { .. } => "referencing",
             Expr::Deref { .. } => "dereferencing",
             Expr::Not { .. } => "negation",
+            Expr::Cast { .. } => "cast",
             Expr::Mul { .. } => "multiplication",
             Expr::Div { .. } => "division",
             Expr::Pow { .. } => "power operation",
@@ -603,6 +713,7 @@ impl Expr {
             Expr::GreaterThanEq { .. } => "greater than or equal operation",
             Expr::LogicalAnd { .. } => "logical and",
             Expr::LogicalOr { .. } => "logical or",
+            Expr::Range { .. } => "range",
             Expr::Reassignment { .. } => "reassignment",
             Expr::Break { .. } => "break",
             Expr::Continue { .. } => "continue",
@@ -42,9 +42,10 @@ pub enum Intrinsic {
     EncodeBufferEmpty,      // let buffer: (raw_ptr, u64, u64) = __encode_buffer_empty()
     EncodeBufferAppend, // let buffer: (raw_ptr, u64, u64) = __encode_buffer_append(buffer, primitive data type)
     EncodeBufferAsRawSlice, // let slice: raw_slice = __encode_buffer_as_raw_slice(buffer)
-    Slice, // let ref_to_slice = __slice::<T: array or ref_to_slice>(item: T, inclusive_start_index, exclusive_end_index)
+    Slice, // let ref_to_slice = __slice::<T: array or ref_to_slice>(item: T, inclusive_start_index, exclusive_end_index), or `&item[start..end]`
     ElemAt, // let elem: &T = __elem_at::<T: array or ref_to_slice>(item: T, index)
     Transmute, // let dst: B = __transmute::<A, B>(src)
+    Cast,  // let dst: u8 = __cast::<u8>(src), or `src as u8`
 }
 
 impl fmt::Display for Intrinsic {
@@ -92,6 +93,7 @@ impl fmt::Display for Intrinsic {
             Intrinsic::Slice => "slice",
             Intrinsic::ElemAt => "elem_at",
             Intrinsic::Transmute => "transmute",
+            Intrinsic::Cast => "cast",
         };
         write!(f, "{s}")
     }
@@ -143,6 +145,7 @@ impl Intrinsic {
             "__slice" => Slice,
             "__elem_at" => ElemAt,
             "__transmute" => Transmute,
+            "__cast" => Cast,
             _ => return None,
         })
     }
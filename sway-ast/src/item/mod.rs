@@ -99,11 +99,15 @@ pub struct FnArg {
     pub pattern: Pattern,
     pub colon_token: ColonToken,
     pub ty: Ty,
+    pub default_opt: Option<(EqToken, Expr)>,
 }
 
 impl Spanned for FnArg {
     fn span(&self) -> Span {
-        Span::join(self.pattern.span(), &self.ty.span())
+        match &self.default_opt {
+            Some((_eq_token, default)) => Span::join(self.pattern.span(), &default.span()),
+            None => Span::join(self.pattern.span(), &self.ty.span()),
+        }
     }
 }
 
@@ -139,6 +143,7 @@ impl Spanned for FnSignature {
 pub struct TraitType {
     pub name: Ident,
     pub type_token: TypeToken,
+    pub generics: Option<GenericParams>,
     pub eq_token_opt: Option<EqToken>,
     pub ty_opt: Option<Ty>,
     pub semicolon_token: SemicolonToken,
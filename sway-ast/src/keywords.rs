@@ -190,6 +190,8 @@ define_token!(
 define_token!(DotToken, "`.`", [Dot], []);
 define_token!(DoubleDotToken, "`..`", [Dot, Dot], [Dot]);
 define_token!(BangToken, "`!`", [Bang], [Equals]);
+define_token!(QuestionMarkToken, "`?`", [QuestionMark], []);
+define_token!(ApostropheToken, "`'`", [Apostrophe], []);
 define_token!(PercentToken, "`%`", [Percent], []);
 define_token!(AddToken, "`+`", [Add], [Equals]);
 define_token!(SubToken, "`-`", [Sub], [Equals]);
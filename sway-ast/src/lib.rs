@@ -25,8 +25,8 @@ pub use crate::{
     expr::{
         asm::{AsmBlock, AsmRegisterDeclaration},
         op_code::Instruction,
-        AbiCastArgs, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
-        ExprTupleDescriptor, IfCondition, IfExpr, MatchBranch, MatchBranchKind,
+        AbiCastArgs, CallArg, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
+        ExprTupleDescriptor, IfCondition, IfExpr, Label, MatchBranch, MatchBranchKind,
     },
     generics::{GenericArgs, GenericParams},
     intrinsics::*,
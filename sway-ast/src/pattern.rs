@@ -30,6 +30,12 @@ pub enum Pattern {
         fields: Braces<Punctuated<PatternStructField, CommaToken>>,
     },
     Tuple(Parens<Punctuated<Pattern, CommaToken>>),
+    /// An array pattern, like `[first, .., last]`.
+    Slice(SquareBrackets<Punctuated<Pattern, CommaToken>>),
+    /// The `..` in a slice pattern, matching any number of elements.
+    Rest {
+        token: DoubleDotToken,
+    },
     // to handle parser recovery: Error represents an incomplete Constructor
     Error(Box<[Span]>, #[serde(skip_serializing)] ErrorEmitted),
 }
@@ -62,6 +68,8 @@ impl Spanned for Pattern {
             Pattern::Constructor { path, args } => Span::join(path.span(), &args.span()),
             Pattern::Struct { path, fields } => Span::join(path.span(), &fields.span()),
             Pattern::Tuple(pat_tuple) => pat_tuple.span(),
+            Pattern::Slice(pat_slice) => pat_slice.span(),
+            Pattern::Rest { token } => token.span(),
             Pattern::Error(spans, _) => spans
                 .iter()
                 .cloned()
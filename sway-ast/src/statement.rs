@@ -22,6 +22,9 @@ pub struct StatementLet {
     pub ty_opt: Option<(ColonToken, Ty)>,
     pub eq_token: EqToken,
     pub expr: Expr,
+    /// The block executed when the `expr` does not match the `pattern`, as in
+    /// `let Some(x) = expr else { return };`. It must diverge.
+    pub else_opt: Option<(ElseToken, Braces<CodeBlockContents>)>,
     pub semicolon_token: SemicolonToken,
 }
 
@@ -254,12 +254,22 @@ impl CharExt for char {
             '|' => Some(PunctKind::Pipe),
             '_' => Some(PunctKind::Underscore),
             '#' => Some(PunctKind::Sharp),
+            '$' => Some(PunctKind::Dollar),
+            '?' => Some(PunctKind::QuestionMark),
+            '\'' => Some(PunctKind::Apostrophe),
             _ => None,
         }
     }
 }
 
 impl TokenStream {
+    pub fn new(token_trees: Vec<TokenTree>, full_span: Span) -> Self {
+        TokenStream {
+            token_trees,
+            full_span,
+        }
+    }
+
     pub fn token_trees(&self) -> &[TokenTree] {
         &self.token_trees
     }
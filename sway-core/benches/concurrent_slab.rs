@@ -0,0 +1,89 @@
+//! Compares the single-threaded and multi-threaded throughput of the `ConcurrentSlab`
+//! underlying the type and declaration engines.
+//!
+//! Run with `cargo bench -p sway-core --features bench --bench concurrent_slab`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::thread;
+use sway_core::concurrent_slab::ConcurrentSlab;
+
+const ITEMS_PER_THREAD: usize = 10_000;
+
+fn insert(slab: &ConcurrentSlab<usize>, threads: usize) {
+    thread::scope(|s| {
+        for _ in 0..threads {
+            s.spawn(|| {
+                for i in 0..ITEMS_PER_THREAD {
+                    black_box(slab.insert(i));
+                }
+            });
+        }
+    });
+}
+
+fn lookup(slab: &ConcurrentSlab<usize>, threads: usize) {
+    let len = slab.len();
+    thread::scope(|s| {
+        for thread in 0..threads {
+            s.spawn(move || {
+                // Every thread walks the whole slab, starting at a different offset.
+                let offset = thread * len / threads;
+                for i in 0..len {
+                    black_box(slab.get((offset + i) % len));
+                }
+            });
+        }
+    });
+}
+
+fn insert_and_lookup(slab: &ConcurrentSlab<usize>, threads: usize) {
+    thread::scope(|s| {
+        for _ in 0..threads {
+            s.spawn(|| {
+                for i in 0..ITEMS_PER_THREAD {
+                    let index = slab.insert(i);
+                    black_box(slab.get(index / 2));
+                }
+            });
+        }
+    });
+}
+
+fn bench_concurrent_slab(c: &mut Criterion) {
+    let thread_counts = [1, 2, 4, 8];
+
+    let mut group = c.benchmark_group("ConcurrentSlab insert");
+    for threads in thread_counts {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(threads),
+            &threads,
+            |b, &threads| b.iter(|| insert(&ConcurrentSlab::default(), threads)),
+        );
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("ConcurrentSlab lookup");
+    let slab = ConcurrentSlab::default();
+    insert(&slab, 8);
+    for threads in thread_counts {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(threads),
+            &threads,
+            |b, &threads| b.iter(|| lookup(&slab, threads)),
+        );
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("ConcurrentSlab insert and lookup");
+    for threads in thread_counts {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(threads),
+            &threads,
+            |b, &threads| b.iter(|| insert_and_lookup(&ConcurrentSlab::default(), threads)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_concurrent_slab);
+criterion_main!(benches);
@@ -188,10 +188,7 @@ impl TypeInfo {
                 format!("__slice {}", ty.abi_str(ctx, engines, false))
             }
             Alias { ty, .. } => ty.abi_str(ctx, engines, false),
-            TraitType {
-                name,
-                trait_type_id: _,
-            } => format!("trait type {}", name),
+            TraitType { name, .. } => format!("trait type {}", name),
             Ref {
                 to_mutable_value,
                 referenced_type,
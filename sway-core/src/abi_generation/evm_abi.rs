@@ -124,10 +124,7 @@ pub fn abi_str(type_info: &TypeInfo, engines: &Engines) -> String {
             format!("__slice {}", abi_str_type_arg(ty, engines))
         }
         Alias { ty, .. } => abi_str_type_arg(ty, engines),
-        TraitType {
-            name,
-            trait_type_id: _,
-        } => format!("trait type {}", name),
+        TraitType { name, .. } => format!("trait type {}", name),
         Ref {
             to_mutable_value,
             referenced_type,
@@ -175,10 +172,10 @@ pub fn abi_param_type(type_info: &TypeInfo, engines: &Engines) -> ethabi::ParamT
                     .collect::<Vec<ethabi::ParamType>>(),
             )
         }
-        Array(elem_ty, ..) => ethabi::ParamType::Array(Box::new(abi_param_type(
-            &type_engine.get(elem_ty.type_id),
-            engines,
-        ))),
+        Array(elem_ty, length) => ethabi::ParamType::FixedArray(
+            Box::new(abi_param_type(&type_engine.get(elem_ty.type_id), engines)),
+            length.val(),
+        ),
         _ => panic!("cannot convert type to Solidity ABI param type: {type_info:?}",),
     }
 }
@@ -188,6 +185,7 @@ fn generate_abi_function(
     engines: &Engines,
 ) -> ethabi::operation::Operation {
     let decl_engine = engines.de();
+    let type_engine = engines.te();
     let fn_decl = decl_engine.get_function(fn_decl_id);
     // A list of all `ethabi::Param`s needed for inputs
     let input_types = fn_decl
@@ -195,7 +193,7 @@ fn generate_abi_function(
         .iter()
         .map(|x| ethabi::Param {
             name: x.name.to_string(),
-            kind: ethabi::ParamType::Address,
+            kind: abi_param_type(&type_engine.get(x.type_argument.type_id), engines),
             internal_type: Some(get_type_str(
                 &x.type_argument.type_id,
                 engines,
@@ -204,15 +202,19 @@ fn generate_abi_function(
         })
         .collect::<Vec<_>>();
 
-    // The single `ethabi::Param` needed for the output
-    let output_type = ethabi::Param {
-        name: String::default(),
-        kind: ethabi::ParamType::Address,
-        internal_type: Some(get_type_str(
-            &fn_decl.return_type.type_id,
-            engines,
-            fn_decl.return_type.type_id,
-        )),
+    // The single `ethabi::Param` needed for the output, if the function returns a value
+    let output_types = if type_engine.get(fn_decl.return_type.type_id).is_unit() {
+        vec![]
+    } else {
+        vec![ethabi::Param {
+            name: String::default(),
+            kind: abi_param_type(&type_engine.get(fn_decl.return_type.type_id), engines),
+            internal_type: Some(get_type_str(
+                &fn_decl.return_type.type_id,
+                engines,
+                fn_decl.return_type.type_id,
+            )),
+        }]
     };
 
     // Generate the ABI data for the function
@@ -220,7 +222,7 @@ fn generate_abi_function(
     ethabi::operation::Operation::Function(ethabi::Function {
         name: fn_decl.name.as_str().to_string(),
         inputs: input_types,
-        outputs: vec![output_type],
+        outputs: output_types,
         constant: None,
         state_mutability: ethabi::StateMutability::Payable,
     })
@@ -7,8 +7,8 @@ use sway_error::handler::{ErrorEmitted, Handler};
 use sway_types::Span;
 
 use crate::{
-    language::ty::{TyFunctionDecl, TyProgram, TyProgramKind},
-    transform::AttributesMap,
+    language::ty::{TyFunctionDecl, TyFunctionParameter, TyProgram, TyProgramKind},
+    transform::{AttributeKind, AttributesMap},
     Engines, TypeId, TypeInfo, TypeParameter,
 };
 
@@ -1182,7 +1182,7 @@ impl TyFunctionDecl {
     ) -> Result<program_abi::ABIFunction, ErrorEmitted> {
         // Generate the JSON data for the function
         Ok(program_abi::ABIFunction {
-            name: self.name.as_str().to_string(),
+            name: self.abi_method_name().to_string(),
             inputs: self
                 .parameters
                 .iter()
@@ -1210,11 +1210,36 @@ impl TyFunctionDecl {
                 self.return_type.initial_type_id,
                 self.return_type.type_id,
             )?,
-            attributes: generate_attributes_map(&self.attributes),
+            attributes: generate_function_attributes(&self.attributes, &self.parameters),
         })
     }
 }
 
+/// The attributes of an ABI function, followed by a `default` attribute for every parameter with
+/// a default value, whose arguments are the name of the parameter and its default value.
+fn generate_function_attributes(
+    attr_map: &AttributesMap,
+    parameters: &[TyFunctionParameter],
+) -> Option<Vec<program_abi::Attribute>> {
+    let default_values = parameters.iter().filter_map(|param| {
+        param
+            .default_argument()
+            .map(|default_value| program_abi::Attribute {
+                name: "default".to_string(),
+                arguments: vec![
+                    param.name.to_string(),
+                    default_value.span.as_str().to_string(),
+                ],
+            })
+    });
+    let attributes = generate_attributes_map(attr_map)
+        .into_iter()
+        .flatten()
+        .chain(default_values)
+        .collect::<Vec<_>>();
+    (!attributes.is_empty()).then_some(attributes)
+}
+
 fn generate_attributes_map(attr_map: &AttributesMap) -> Option<Vec<program_abi::Attribute>> {
     if attr_map.is_empty() {
         None
@@ -1222,10 +1247,25 @@ fn generate_attributes_map(attr_map: &AttributesMap) -> Option<Vec<program_abi::
         Some(
             attr_map
                 .iter()
-                .flat_map(|(_attr_kind, attrs)| {
-                    attrs.iter().map(|attr| program_abi::Attribute {
+                .flat_map(|(attr_kind, attrs)| {
+                    attrs.iter().map(move |attr| program_abi::Attribute {
                         name: attr.name.to_string(),
-                        arguments: attr.args.iter().map(|arg| arg.name.to_string()).collect(),
+                        arguments: match attr_kind {
+                            // The pinned selector and method name are what the SDKs need.
+                            AttributeKind::Selector => attr_map
+                                .selector_override()
+                                .flatten()
+                                .map(|selector| format!("0x{:08x}", u32::from_be_bytes(selector)))
+                                .into_iter()
+                                .collect(),
+                            AttributeKind::MethodName => attr_map
+                                .method_name_override()
+                                .flatten()
+                                .map(str::to_string)
+                                .into_iter()
+                                .collect(),
+                            _ => attr.args.iter().map(|arg| arg.name.to_string()).collect(),
+                        },
                     })
                 })
                 .collect(),
@@ -0,0 +1,129 @@
+//! Registration of static analysis passes that are not part of the compiler.
+//!
+//! Third-party tools, like linters and security scanners, implement [AnalysisPass] and register
+//! it on the [BuildConfig] with [BuildConfig::with_analysis_pass], or on the `analysis_passes` of
+//! the `forc_pkg::BuildOpts` when building with `forc-pkg`. The compiler then runs the pass over
+//! the typed program and the unoptimized IR of every package it compiles, and reports the
+//! diagnostics the pass emits along with its own.
+//!
+//! [BuildConfig]: crate::BuildConfig
+//! [BuildConfig::with_analysis_pass]: crate::BuildConfig::with_analysis_pass
+use crate::{language::ty, Engines};
+use std::{fmt, sync::Arc};
+use sway_error::{
+    error::CompileError,
+    handler::{ErrorEmitted, Handler},
+    warning::{CompileWarning, Warning},
+};
+use sway_types::Span;
+
+/// A static analysis pass, run after the compiler's own analyses.
+///
+/// Both hooks do nothing by default, so a pass only implements the ones it needs.
+pub trait AnalysisPass: Send + Sync {
+    /// The name of the pass, shown in the diagnostics it reports.
+    fn name(&self) -> &str;
+
+    /// Analyzes the typed program of a package that type checked without errors.
+    fn analyze_typed_program(&self, _ctx: &AnalysisContext, _program: &ty::TyProgram) {}
+
+    /// Analyzes the IR of a package, before any optimization is applied to it.
+    fn analyze_ir(&self, _ctx: &AnalysisContext, _ir: &sway_ir::Context) {}
+}
+
+/// Gives an [AnalysisPass] access to the engines, and reports its diagnostics.
+pub struct AnalysisContext<'a> {
+    pub engines: &'a Engines,
+    handler: &'a Handler,
+    pass: &'a str,
+}
+
+impl<'a> AnalysisContext<'a> {
+    /// Reports a warning of the pass at the `span`.
+    pub fn warn(&self, span: Span, message: impl Into<String>) {
+        self.handler.emit_warn(CompileWarning {
+            span,
+            warning_content: Warning::AnalysisPass {
+                pass: self.pass.to_string(),
+                message: message.into(),
+            },
+        });
+    }
+
+    /// Reports an error of the pass at the `span`, which fails the compilation.
+    pub fn error(&self, span: Span, message: impl Into<String>) -> ErrorEmitted {
+        self.handler.emit_err(CompileError::AnalysisPass {
+            pass: self.pass.to_string(),
+            message: message.into(),
+            span,
+        })
+    }
+
+    /// The handler the compiler reports its diagnostics to, for passes that report the
+    /// compiler's own diagnostics.
+    pub fn handler(&self) -> &Handler {
+        self.handler
+    }
+}
+
+/// The analysis passes registered on a [crate::BuildConfig], in the order of registration.
+#[derive(Clone, Default)]
+pub struct AnalysisPasses(Vec<Arc<dyn AnalysisPass>>);
+
+impl AnalysisPasses {
+    pub fn push(&mut self, pass: Arc<dyn AnalysisPass>) {
+        self.0.push(pass);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Arc<dyn AnalysisPass>> {
+        self.0.iter()
+    }
+
+    pub(crate) fn run_on_typed_program(
+        &self,
+        handler: &Handler,
+        engines: &Engines,
+        program: &ty::TyProgram,
+    ) {
+        for pass in &self.0 {
+            let ctx = AnalysisContext {
+                engines,
+                handler,
+                pass: pass.name(),
+            };
+            pass.analyze_typed_program(&ctx, program);
+        }
+    }
+
+    pub(crate) fn run_on_ir(&self, handler: &Handler, engines: &Engines, ir: &sway_ir::Context) {
+        for pass in &self.0 {
+            let ctx = AnalysisContext {
+                engines,
+                handler,
+                pass: pass.name(),
+            };
+            pass.analyze_ir(&ctx, ir);
+        }
+    }
+}
+
+impl fmt::Debug for AnalysisPasses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|pass| pass.name()))
+            .finish()
+    }
+}
+
+/// Passes are equal if they are the same instances, registered in the same order.
+impl PartialEq for AnalysisPasses {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len() && self.0.iter().zip(&other.0).all(|(a, b)| Arc::ptr_eq(a, b))
+    }
+}
+
+impl Eq for AnalysisPasses {}
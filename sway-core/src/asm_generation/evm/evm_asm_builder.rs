@@ -2,8 +2,8 @@ use std::{collections::HashMap, sync::Arc};
 
 use crate::{
     asm_generation::{
-        asm_builder::AsmBuilder, from_ir::StateAccessType, fuel::data_section::DataSection,
-        instruction_set::InstructionSet, FinalizedAsm, ProgramABI, ProgramKind,
+        asm_builder::AsmBuilder, fuel::data_section::DataSection, instruction_set::InstructionSet,
+        FinalizedAsm, ProgramABI, ProgramKind,
     },
     asm_lang::Label,
     metadata::MetadataManager,
@@ -42,7 +42,6 @@ use etk_asm::{asm::Assembler, ops::*};
 /// a (potential) constructor of the contract.
 
 pub struct EvmAsmBuilder<'ir, 'eng> {
-    #[allow(dead_code)]
     program_kind: ProgramKind,
 
     sections: Vec<EvmAsmSection>,
@@ -50,7 +49,6 @@ pub struct EvmAsmBuilder<'ir, 'eng> {
     // Label maps are from IR functions or blocks to label name.  Functions have a start and end
     // label.
     pub(super) func_label_map: HashMap<Function, (Label, Label)>,
-    #[allow(dead_code)]
     pub(super) block_label_map: HashMap<Block, Label>,
 
     // IR context we're compiling.
@@ -64,8 +62,55 @@ pub struct EvmAsmBuilder<'ir, 'eng> {
 
     // In progress EVM asm section.
     pub(super) cur_section: Option<EvmAsmSection>,
+
+    // The entry functions of the program, in the order they were compiled.  They are dispatched
+    // to from the runtime code generated in `finalize()`.
+    entry_functions: Vec<Function>,
+
+    // Every IR value lives in its own statically allocated memory region.  Sway does not allow
+    // recursion, so a single region per value is enough.
+    value_addrs: HashMap<Value, u64>,
+    local_addrs: HashMap<LocalVar, u64>,
+    frames: HashMap<Function, EvmFrame>,
+
+    // The next free address of the static memory.
+    next_mem_addr: u64,
+
+    // The label of the shared code reverting on arithmetic overflows and divisions by zero.
+    panic_label: Label,
+}
+
+/// The statically allocated memory used to call a function.  The arguments of the function are
+/// stored in the memory regions of their values.
+#[derive(Clone, Copy, Debug)]
+struct EvmFrame {
+    /// The address of the word holding the code offset to jump to when returning.
+    return_addr: u64,
+    /// The address of the memory region holding the returned value.
+    return_value_addr: u64,
 }
 
+/// The address of a memory region, either known at compile time or held by an IR value.
+#[derive(Clone, Copy, Debug)]
+enum EvmAddr {
+    Static(u64),
+    Dynamic(Value),
+}
+
+/// The runtime code starts at this label.  Jump destinations are relative to it, as the runtime
+/// code is deployed without the constructor preceding it.
+const RUNTIME_START_LABEL: &str = "runtime_start";
+
+/// The size of an EVM word in bytes.  Every scalar value (`bool`, integers, `b256` and pointers)
+/// occupies a full word in memory.
+const EVM_WORD_SIZE: u64 = 32;
+
+/// The static memory starts after the scratch space, the free memory pointer and the zero slot.
+const STATIC_MEMORY_START: u64 = 0x80;
+
+/// The size of a function selector in the calldata.
+const SELECTOR_SIZE: u64 = 4;
+
 #[derive(Default, Debug)]
 pub struct EvmAsmSection {
     ops: Vec<etk_asm::ops::AbstractOp>,
@@ -110,35 +155,39 @@ impl<'ir, 'eng> AsmBuilder for EvmAsmBuilder<'ir, 'eng> {
     fn compile_configurable(&mut self, _config: &ConfigContent) {}
 
     fn finalize(
-        self,
-        _handler: &Handler,
+        mut self,
+        handler: &Handler,
         _build_config: Option<&crate::BuildConfig>,
-        _fallback_fn: Option<Label>,
+        fallback_fn: Option<Label>,
     ) -> Result<FinalizedAsm, ErrorEmitted> {
-        let mut global_ops = Vec::new();
+        let mut runtime_ops = vec![AbstractOp::Label(RUNTIME_START_LABEL.into())];
         let mut global_abi = Vec::new();
 
-        let mut size = 0;
-        let mut it = self.sections.iter().peekable();
-        while let Some(section) = it.next() {
-            size += section.size();
-            global_ops.append(&mut section.ops.clone());
+        let mut dispatcher = self.generate_dispatcher(handler, fallback_fn)?;
+        runtime_ops.append(&mut dispatcher.ops);
+
+        for section in &self.sections {
+            runtime_ops.push(AbstractOp::Op(Op::Invalid(etk_ops::london::Invalid)));
+            runtime_ops.append(&mut section.ops.clone());
             global_abi.append(&mut section.abi.clone());
+        }
 
-            if it.peek().is_some() {
-                size += AbstractOp::Op(Op::Invalid(etk_ops::london::Invalid))
-                    .size()
-                    .unwrap();
-                global_ops.push(AbstractOp::Op(Op::Invalid(etk_ops::london::Invalid)));
-            }
+        // The runtime code is self-contained, so it can be sized on its own.
+        let mut asm = Assembler::new();
+        if let Err(e) = asm.push_all(runtime_ops.clone()) {
+            return Err(handler.emit_err(CompileError::InternalOwned(
+                format!("Could not assemble the EVM runtime code: {e}"),
+                Self::empty_span(),
+            )));
         }
+        let size = asm.take().len();
 
         // First generate a dummy ctor section to calculate its size.
         let dummy = self.generate_constructor(false, size, 0);
 
         // Generate the actual ctor section with the correct size..
         let mut ctor = self.generate_constructor(false, size, dummy.size());
-        ctor.ops.append(&mut global_ops);
+        ctor.ops.append(&mut runtime_ops);
         global_abi.append(&mut ctor.abi);
 
         let final_program = EvmFinalProgram {
@@ -150,11 +199,9 @@ impl<'ir, 'eng> AsmBuilder for EvmAsmBuilder<'ir, 'eng> {
     }
 }
 
-#[allow(unused_variables)]
-#[allow(dead_code)]
 impl<'ir, 'eng> EvmAsmBuilder<'ir, 'eng> {
     pub fn new(program_kind: ProgramKind, context: &'ir Context<'eng>) -> Self {
-        Self {
+        let mut builder = Self {
             program_kind,
             sections: Vec::new(),
             func_label_map: HashMap::new(),
@@ -163,7 +210,15 @@ impl<'ir, 'eng> EvmAsmBuilder<'ir, 'eng> {
             md_mgr: MetadataManager::default(),
             label_idx: 0,
             cur_section: None,
-        }
+            entry_functions: Vec::new(),
+            value_addrs: HashMap::new(),
+            local_addrs: HashMap::new(),
+            frames: HashMap::new(),
+            next_mem_addr: STATIC_MEMORY_START,
+            panic_label: Label(0),
+        };
+        builder.panic_label = builder.get_label();
+        builder
     }
 
     fn generate_constructor(
@@ -176,7 +231,7 @@ impl<'ir, 'eng> EvmAsmBuilder<'ir, 'eng> {
         // https://medium.com/@hayeah/diving-into-the-ethereum-vm-part-5-the-smart-contract-creation-process-cb7b6133b855.
 
         let mut s = EvmAsmSection::new();
-        self.setup_free_memory_pointer(&mut s);
+        self.setup_free_memory_pointer(&mut s, STATIC_MEMORY_START);
 
         if is_payable {
             // Get the amount of ETH transferred to the contract by the parent contract,
@@ -257,7 +312,7 @@ impl<'ir, 'eng> EvmAsmBuilder<'ir, 'eng> {
         s.ops.push(AbstractOp::Op(Op::Return(Return)));
     }
 
-    fn setup_free_memory_pointer(&self, s: &mut EvmAsmSection) {
+    fn setup_free_memory_pointer(&self, s: &mut EvmAsmSection, free_memory_start: u64) {
         // Setup the initial free memory pointer.
         //
         // The "free memory pointer" is stored at position 0x40 in memory.
@@ -265,16 +320,15 @@ impl<'ir, 'eng> EvmAsmBuilder<'ir, 'eng> {
         // The 32 bytes after the free memory pointer (i.e., starting at 0x60) are meant to be
         // zero permanently and is used as the initial value for empty dynamic memory arrays.
         // This means that the allocatable memory starts at 0x80, which is the initial value
-        // of the free memory pointer.
+        // of the free memory pointer, unless the runtime code reserves static memory.
         //
-        //   push1 0x80
+        //   push free_memory_start
         //   push1 0x40
         //   mstore
 
-        s.ops
-            .push(AbstractOp::new(Op::Push1(Push1(Imm::with_expression(
-                Expression::Terminal(0x80.into()),
-            )))));
+        s.ops.push(AbstractOp::Push(Imm::from(Terminal::Number(
+            free_memory_start.into(),
+        ))));
         s.ops
             .push(AbstractOp::new(Op::Push1(Push1(Imm::with_expression(
                 Expression::Terminal(0x40.into()),
@@ -282,17 +336,553 @@ impl<'ir, 'eng> EvmAsmBuilder<'ir, 'eng> {
         s.ops.push(AbstractOp::new(Op::MStore(MStore)));
     }
 
+    /// Generates the start of the runtime code, which decodes the calldata and jumps to the
+    /// called entry function.
+    ///
+    /// Contracts dispatch on the first four bytes of the calldata, the selector of the called ABI
+    /// method, computed like Solidity does.  The arguments follow the selector, each scalar in
+    /// its own word, aggregates as static tuples.  Scripts have no selector and decode the
+    /// arguments of `main` from the start of the calldata.
+    fn generate_dispatcher(
+        &mut self,
+        handler: &Handler,
+        fallback_fn: Option<Label>,
+    ) -> Result<EvmAsmSection, ErrorEmitted> {
+        let mut s = EvmAsmSection::new();
+        self.setup_free_memory_pointer(&mut s, self.next_mem_addr);
+        self.cur_section = Some(s);
+
+        match self.program_kind {
+            ProgramKind::Contract => {
+                let no_selector_label = self.get_label();
+                let no_match_label = self.get_label();
+
+                // Dispatch to the fallback if the calldata is too short to hold a selector.
+                self.push_number(SELECTOR_SIZE);
+                self.push_op(Op::CallDataSize(CallDataSize));
+                self.push_op(Op::Lt(Lt));
+                self.push_label(no_selector_label);
+                self.push_op(Op::JumpI(JumpI));
+
+                // The selector is in the four most significant bytes of the first word.
+                self.push_number(0);
+                self.push_op(Op::CallDataLoad(CallDataLoad));
+                self.push_number(224);
+                self.push_op(Op::Shr(Shr));
+
+                let mut stubs = Vec::new();
+                for function in self.entry_functions.clone() {
+                    if function.is_fallback(self.context)
+                        || function.get_selector(self.context).is_none()
+                    {
+                        continue;
+                    }
+                    let selector = self.evm_selector(handler, function)?;
+                    let stub_label = self.get_label();
+                    self.push_op(Op::Dup1(Dup1));
+                    self.push_number(u32::from_be_bytes(selector) as u64);
+                    self.push_op(Op::Eq(Eq));
+                    self.push_label(stub_label);
+                    self.push_op(Op::JumpI(JumpI));
+                    stubs.push((stub_label, function));
+                }
+
+                self.insert_label(no_match_label);
+                self.push_op(Op::Pop(Pop));
+                self.insert_label(no_selector_label);
+                match fallback_fn {
+                    Some(fallback_label) => {
+                        self.push_label(fallback_label);
+                        self.push_op(Op::Jump(Jump));
+                    }
+                    None => self.push_revert_empty(),
+                }
+
+                for (stub_label, function) in stubs {
+                    self.insert_label(stub_label);
+                    self.push_op(Op::Pop(Pop));
+                    self.decode_args(handler, function, SELECTOR_SIZE)?;
+                    let (start_label, _) = self.func_to_labels(&function);
+                    self.push_label(start_label);
+                    self.push_op(Op::Jump(Jump));
+                }
+            }
+            ProgramKind::Script => {
+                let main = self
+                    .entry_functions
+                    .iter()
+                    .find(|function| function.get_name(self.context) == "main")
+                    .copied();
+                match main {
+                    Some(main) => {
+                        self.decode_args(handler, main, 0)?;
+                        let (start_label, _) = self.func_to_labels(&main);
+                        self.push_label(start_label);
+                        self.push_op(Op::Jump(Jump));
+                    }
+                    None => self.push_op(Op::Stop(Stop)),
+                }
+            }
+            ProgramKind::Library => self.push_op(Op::Stop(Stop)),
+            ProgramKind::Predicate => {
+                return Err(handler.emit_err(CompileError::Unimplemented {
+                    feature: "Compiling predicates for the EVM target".to_string(),
+                    help: vec![],
+                    span: Self::empty_span(),
+                }));
+            }
+        }
+
+        // Arithmetic overflows and divisions by zero revert without data.
+        self.insert_label(self.panic_label);
+        self.push_revert_empty();
+
+        Ok(self.cur_section.take().unwrap())
+    }
+
+    /// The Solidity compatible selector of the ABI method `function`.
+    fn evm_selector(
+        &mut self,
+        handler: &Handler,
+        function: Function,
+    ) -> Result<[u8; 4], ErrorEmitted> {
+        let mut params = Vec::new();
+        for (_, arg) in function.args_iter(self.context) {
+            let ty = arg.get_type(self.context).unwrap();
+            let Some(param) = self.evm_param_type(ty) else {
+                let feature = format!(
+                    "Using an argument of type `{}` in an ABI method",
+                    ty.as_string(self.context)
+                );
+                return Err(self.unimplemented_in_fn(handler, function, &feature));
+            };
+            params.push(param);
+        }
+        Ok(ethabi::short_signature(
+            function.get_name(self.context),
+            &params,
+        ))
+    }
+
+    /// The Solidity ABI type `ty` is encoded as.  Must match the types used in the JSON ABI.
+    fn evm_param_type(&self, ty: Type) -> Option<ethabi::ParamType> {
+        match ty.get_content(self.context) {
+            TypeContent::Bool => Some(ethabi::ParamType::Bool),
+            TypeContent::Uint(width) => Some(ethabi::ParamType::Uint(*width as usize)),
+            TypeContent::B256 => Some(ethabi::ParamType::Uint(256)),
+            TypeContent::Struct(fields) => fields
+                .iter()
+                .map(|field| self.evm_param_type(*field))
+                .collect::<Option<Vec<_>>>()
+                .map(ethabi::ParamType::Tuple),
+            TypeContent::Array(elem_ty, count) => self
+                .evm_param_type(*elem_ty)
+                .map(|elem| ethabi::ParamType::FixedArray(Box::new(elem), *count as usize)),
+            _ => None,
+        }
+    }
+
+    /// Copies the arguments of `function` from the calldata, starting at `offset`, into the
+    /// memory regions of its argument values.
+    fn decode_args(
+        &mut self,
+        handler: &Handler,
+        function: Function,
+        mut offset: u64,
+    ) -> Result<(), ErrorEmitted> {
+        for (_, arg) in function.args_iter(self.context) {
+            let arg_addr = self.value_addr(handler, *arg)?;
+            let words = self.value_words(handler, *arg)?;
+            if words == 1 {
+                self.push_number(offset);
+                self.push_op(Op::CallDataLoad(CallDataLoad));
+                self.push_number(arg_addr);
+                self.push_op(Op::MStore(MStore));
+            } else {
+                self.push_number(words * EVM_WORD_SIZE);
+                self.push_number(offset);
+                self.push_number(arg_addr);
+                self.push_op(Op::CallDataCopy(CallDataCopy));
+            }
+            offset += words * EVM_WORD_SIZE;
+        }
+        Ok(())
+    }
+
     fn empty_span() -> Span {
         let msg = "unknown source location";
         Span::new(Arc::from(msg), 0, msg.len(), None).unwrap()
     }
 
     fn get_label(&mut self) -> Label {
-        let next_val = self.label_idx;
         self.label_idx += 1;
         Label(self.label_idx)
     }
 
+    fn instr_span(&mut self, instr_val: &Value) -> Span {
+        self.md_mgr
+            .val_to_span(self.context, *instr_val)
+            .unwrap_or_else(Self::empty_span)
+    }
+
+    fn fn_span(&mut self, function: Function) -> Span {
+        self.md_mgr
+            .md_to_span(self.context, function.get_metadata(self.context))
+            .unwrap_or_else(Self::empty_span)
+    }
+
+    /// Reports `feature`, used by the instruction `instr_val`, as not supported by the EVM
+    /// backend.
+    fn unimplemented(
+        &mut self,
+        handler: &Handler,
+        instr_val: &Value,
+        feature: &str,
+    ) -> ErrorEmitted {
+        let span = self.instr_span(instr_val);
+        Self::emit_unimplemented(handler, span, feature)
+    }
+
+    /// Reports `feature`, used by `function`, as not supported by the EVM backend.
+    fn unimplemented_in_fn(
+        &mut self,
+        handler: &Handler,
+        function: Function,
+        feature: &str,
+    ) -> ErrorEmitted {
+        let span = self.fn_span(function);
+        Self::emit_unimplemented(handler, span, feature)
+    }
+
+    fn emit_unimplemented(handler: &Handler, span: Span, feature: &str) -> ErrorEmitted {
+        handler.emit_err(CompileError::Unimplemented {
+            feature: format!("{feature} on the EVM target"),
+            help: vec![],
+            span,
+        })
+    }
+
+    fn push_op(&mut self, op: Op<Imm>) {
+        self.cur_section
+            .as_mut()
+            .unwrap()
+            .ops
+            .push(AbstractOp::new(op));
+    }
+
+    fn push_number(&mut self, value: u64) {
+        self.cur_section
+            .as_mut()
+            .unwrap()
+            .ops
+            .push(AbstractOp::Push(Imm::from(Terminal::Number(value.into()))));
+    }
+
+    /// Pushes a 256-bit big-endian number, 64 bits at a time.
+    fn push_word(&mut self, bytes: &[u8; 32]) {
+        let limbs = bytes
+            .chunks(8)
+            .map(|chunk| u64::from_be_bytes(chunk.try_into().unwrap()))
+            .collect::<Vec<_>>();
+        let first = limbs.iter().position(|limb| *limb != 0).unwrap_or(3);
+        self.push_number(limbs[first]);
+        for limb in &limbs[first + 1..] {
+            self.push_number(64);
+            self.push_op(Op::Shl(Shl));
+            self.push_number(*limb);
+            self.push_op(Op::Or(Or));
+        }
+    }
+
+    /// Pushes the code offset of `label`, relative to the start of the runtime code.
+    fn push_label(&mut self, label: Label) {
+        let offset = Expression::Minus(
+            Box::new(Expression::Terminal(Terminal::Label(label.to_string()))),
+            Box::new(Expression::Terminal(Terminal::Label(
+                RUNTIME_START_LABEL.into(),
+            ))),
+        );
+        self.push_op(Op::Push2(Push2(Imm::with_expression(offset))));
+    }
+
+    fn insert_label(&mut self, label: Label) {
+        self.cur_section
+            .as_mut()
+            .unwrap()
+            .ops
+            .push(AbstractOp::Label(label.to_string()));
+        self.push_op(Op::JumpDest(JumpDest));
+    }
+
+    fn push_revert_empty(&mut self) {
+        // push1 0x00
+        // dup1
+        // revert
+        self.push_number(0);
+        self.push_op(Op::Dup1(Dup1));
+        self.push_op(Op::Revert(Revert));
+    }
+
+    /// Jumps to the panic code if the value on top of the stack is not zero.
+    fn push_panic_if(&mut self) {
+        self.push_label(self.panic_label);
+        self.push_op(Op::JumpI(JumpI));
+    }
+
+    /// Allocates a static memory region of `words` words.
+    fn alloc_words(&mut self, words: u64) -> u64 {
+        let addr = self.next_mem_addr;
+        self.next_mem_addr += words.max(1) * EVM_WORD_SIZE;
+        addr
+    }
+
+    /// The number of words a value of type `ty` occupies in memory, `None` if the type is not
+    /// supported by the EVM backend.
+    fn type_words(&self, ty: Type) -> Option<u64> {
+        match ty.get_content(self.context) {
+            TypeContent::Never
+            | TypeContent::Unit
+            | TypeContent::Bool
+            | TypeContent::Uint(_)
+            | TypeContent::B256
+            | TypeContent::Pointer(_) => Some(1),
+            TypeContent::Array(elem_ty, count) => {
+                self.type_words(*elem_ty).map(|words| words * count)
+            }
+            TypeContent::Struct(fields) => fields
+                .iter()
+                .map(|field| self.type_words(*field))
+                .sum::<Option<u64>>(),
+            // All variants of a union start at the same address.
+            TypeContent::Union(variants) => variants
+                .iter()
+                .map(|variant| self.type_words(*variant))
+                .collect::<Option<Vec<_>>>()
+                .map(|words| words.into_iter().max().unwrap_or(1)),
+            TypeContent::StringSlice
+            | TypeContent::StringArray(_)
+            | TypeContent::Slice
+            | TypeContent::TypedSlice(_) => None,
+        }
+    }
+
+    fn value_words(&mut self, handler: &Handler, value: Value) -> Result<u64, ErrorEmitted> {
+        let ty = value.get_type(self.context).unwrap();
+        self.words_of(handler, &value, ty)
+    }
+
+    fn pointee_words(&mut self, handler: &Handler, ptr: Value) -> Result<u64, ErrorEmitted> {
+        let ty = ptr
+            .get_type(self.context)
+            .and_then(|ty| ty.get_pointee_type(self.context))
+            .unwrap();
+        self.words_of(handler, &ptr, ty)
+    }
+
+    fn words_of(
+        &mut self,
+        handler: &Handler,
+        value: &Value,
+        ty: Type,
+    ) -> Result<u64, ErrorEmitted> {
+        match self.type_words(ty) {
+            Some(words) => Ok(words),
+            None => {
+                let feature = format!("Using values of type `{}`", ty.as_string(self.context));
+                Err(self.unimplemented(handler, value, &feature))
+            }
+        }
+    }
+
+    /// The address of the memory region holding the non-constant `value`.
+    fn value_addr(&mut self, handler: &Handler, value: Value) -> Result<u64, ErrorEmitted> {
+        if let Some(addr) = self.value_addrs.get(&value) {
+            return Ok(*addr);
+        }
+        let words = self.value_words(handler, value)?;
+        let addr = self.alloc_words(words);
+        self.value_addrs.insert(value, addr);
+        Ok(addr)
+    }
+
+    fn is_scalar(&self, value: &Value) -> bool {
+        value
+            .get_type(self.context)
+            .is_some_and(|ty| !ty.is_aggregate(self.context))
+    }
+
+    /// Pushes the scalar `value` on the stack.
+    fn push_value(&mut self, handler: &Handler, value: &Value) -> Result<(), ErrorEmitted> {
+        if let Some(constant) = value.get_constant(self.context) {
+            return self.push_constant(handler, Some(value), constant);
+        }
+        let addr = self.value_addr(handler, *value)?;
+        self.push_number(addr);
+        self.push_op(Op::MLoad(MLoad));
+        Ok(())
+    }
+
+    fn push_constant(
+        &mut self,
+        handler: &Handler,
+        value: Option<&Value>,
+        constant: &Constant,
+    ) -> Result<(), ErrorEmitted> {
+        match &constant.value {
+            ConstantValue::Undef | ConstantValue::Unit => self.push_number(0),
+            ConstantValue::Bool(b) => self.push_number(*b as u64),
+            ConstantValue::Uint(n) => self.push_number(*n),
+            ConstantValue::U256(n) | ConstantValue::B256(n) => self.push_word(&n.to_be_bytes()),
+            _ => {
+                let feature = format!(
+                    "Using constants of type `{}`",
+                    constant.ty.as_string(self.context)
+                );
+                let span = value
+                    .map(|value| self.instr_span(value))
+                    .unwrap_or_else(Self::empty_span);
+                return Err(Self::emit_unimplemented(handler, span, &feature));
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the words of the `constant` to memory, starting at `addr`.
+    fn store_constant(
+        &mut self,
+        handler: &Handler,
+        value: Option<&Value>,
+        constant: &Constant,
+        addr: u64,
+    ) -> Result<(), ErrorEmitted> {
+        match &constant.value {
+            ConstantValue::Array(elems) | ConstantValue::Struct(elems) => {
+                let mut offset = 0;
+                for elem in elems {
+                    self.store_constant(handler, value, elem, addr + offset * EVM_WORD_SIZE)?;
+                    offset += self.type_words(elem.ty).unwrap_or(1);
+                }
+            }
+            _ => {
+                self.push_constant(handler, value, constant)?;
+                self.push_number(addr);
+                self.push_op(Op::MStore(MStore));
+            }
+        }
+        Ok(())
+    }
+
+    /// The address of the memory region holding the aggregate `value`.  Constants are written to
+    /// a new region first.
+    fn aggregate_addr(&mut self, handler: &Handler, value: &Value) -> Result<u64, ErrorEmitted> {
+        match value.get_constant(self.context) {
+            Some(constant) => {
+                let words = self.value_words(handler, *value)?;
+                let addr = self.alloc_words(words);
+                self.store_constant(handler, Some(value), constant, addr)?;
+                Ok(addr)
+            }
+            None => self.value_addr(handler, *value),
+        }
+    }
+
+    fn push_addr(&mut self, handler: &Handler, addr: EvmAddr) -> Result<(), ErrorEmitted> {
+        match addr {
+            EvmAddr::Static(addr) => self.push_number(addr),
+            EvmAddr::Dynamic(ptr) => self.push_value(handler, &ptr)?,
+        }
+        Ok(())
+    }
+
+    /// Copies `words` words from `src` to `dst`.
+    fn copy_words(
+        &mut self,
+        handler: &Handler,
+        dst: EvmAddr,
+        src: EvmAddr,
+        words: u64,
+    ) -> Result<(), ErrorEmitted> {
+        for word in 0..words {
+            self.push_addr(handler, src)?;
+            self.push_number(word * EVM_WORD_SIZE);
+            self.push_op(Op::Add(Add));
+            self.push_op(Op::MLoad(MLoad));
+            self.push_addr(handler, dst)?;
+            self.push_number(word * EVM_WORD_SIZE);
+            self.push_op(Op::Add(Add));
+            self.push_op(Op::MStore(MStore));
+        }
+        Ok(())
+    }
+
+    /// Copies `value` into the memory region at `dst`.
+    fn copy_value_to(
+        &mut self,
+        handler: &Handler,
+        dst: EvmAddr,
+        value: &Value,
+    ) -> Result<(), ErrorEmitted> {
+        if self.is_scalar(value) {
+            self.push_value(handler, value)?;
+            self.push_addr(handler, dst)?;
+            self.push_op(Op::MStore(MStore));
+        } else {
+            let src = self.aggregate_addr(handler, value)?;
+            let words = self.value_words(handler, *value)?;
+            self.copy_words(handler, dst, EvmAddr::Static(src), words)?;
+        }
+        Ok(())
+    }
+
+    /// Stores the scalar on top of the stack as the result of `instr_val`.
+    fn store_result(&mut self, handler: &Handler, instr_val: &Value) -> Result<(), ErrorEmitted> {
+        let addr = self.value_addr(handler, *instr_val)?;
+        self.push_number(addr);
+        self.push_op(Op::MStore(MStore));
+        Ok(())
+    }
+
+    /// Keeps only the `width` least significant bits of the value on top of the stack.
+    fn push_mask(&mut self, width: u16) {
+        if width < 256 {
+            self.push_number(u64::MAX >> (64 - width));
+            self.push_op(Op::And(And));
+        }
+    }
+
+    /// Panics if the value on top of the stack does not fit into `width` bits.
+    fn push_overflow_check(&mut self, width: u16) {
+        if width < 256 {
+            self.push_op(Op::Dup1(Dup1));
+            self.push_number(width as u64);
+            self.push_op(Op::Shr(Shr));
+            self.push_panic_if();
+        }
+    }
+
+    fn frame(&mut self, handler: &Handler, function: Function) -> Result<EvmFrame, ErrorEmitted> {
+        if let Some(frame) = self.frames.get(&function) {
+            return Ok(*frame);
+        }
+        let return_type = function.get_return_type(self.context);
+        let Some(return_words) = self.type_words(return_type) else {
+            let feature = format!(
+                "Returning values of type `{}`",
+                return_type.as_string(self.context)
+            );
+            return Err(self.unimplemented_in_fn(handler, function, &feature));
+        };
+        let frame = EvmFrame {
+            return_addr: self.alloc_words(1),
+            return_value_addr: self.alloc_words(return_words),
+        };
+        for (_, arg) in function.args_iter(self.context) {
+            self.value_addr(handler, *arg)?;
+        }
+        self.frames.insert(function, frame);
+        Ok(frame)
+    }
+
     pub(super) fn compile_instruction(
         &mut self,
         handler: &Handler,
@@ -304,16 +894,18 @@ impl<'ir, 'eng> EvmAsmBuilder<'ir, 'eng> {
                 InstOp::AsmBlock(asm, args) => {
                     self.compile_asm_block(handler, instr_val, asm, args)?
                 }
-                InstOp::BitCast(val, ty) => self.compile_bitcast(instr_val, val, ty),
-                InstOp::UnaryOp { op, arg } => self.compile_unary_op(instr_val, op, arg),
+                InstOp::BitCast(val, ty) => self.compile_bitcast(handler, instr_val, val, ty)?,
+                InstOp::UnaryOp { op, arg } => {
+                    self.compile_unary_op(handler, instr_val, op, arg)?
+                }
                 InstOp::BinaryOp { op, arg1, arg2 } => {
-                    self.compile_binary_op(instr_val, op, arg1, arg2)
+                    self.compile_binary_op(handler, instr_val, op, arg1, arg2)?
                 }
-                InstOp::Branch(to_block) => self.compile_branch(to_block),
-                InstOp::Call(func, args) => self.compile_call(instr_val, func, args),
-                InstOp::CastPtr(val, ty) => self.compile_cast_ptr(instr_val, val, ty),
+                InstOp::Branch(to_block) => self.compile_branch(handler, to_block)?,
+                InstOp::Call(func, args) => self.compile_call(handler, instr_val, func, args)?,
+                InstOp::CastPtr(val, ty) => self.compile_cast_ptr(handler, instr_val, val, ty)?,
                 InstOp::Cmp(pred, lhs_value, rhs_value) => {
-                    self.compile_cmp(instr_val, pred, lhs_value, rhs_value)
+                    self.compile_cmp(handler, instr_val, pred, lhs_value, rhs_value)?
                 }
                 InstOp::ConditionalBranch {
                     cond_value,
@@ -322,61 +914,70 @@ impl<'ir, 'eng> EvmAsmBuilder<'ir, 'eng> {
                 } => {
                     self.compile_conditional_branch(handler, cond_value, true_block, false_block)?
                 }
-                InstOp::ContractCall {
-                    params,
-                    coins,
-                    asset_id,
-                    gas,
-                    ..
-                } => self.compile_contract_call(instr_val, params, coins, asset_id, gas),
-                InstOp::FuelVm(fuel_vm_instr) => {
-                    handler.emit_err(CompileError::Internal(
-                        "Invalid FuelVM IR instruction provided to the EVM code gen.",
-                        self.md_mgr
-                            .val_to_span(self.context, *instr_val)
-                            .unwrap_or_else(Self::empty_span),
-                    ));
+                InstOp::ContractCall { .. } => {
+                    return Err(self.unimplemented(handler, instr_val, "Calling contracts"));
                 }
+                InstOp::FuelVm(fuel_vm_instr) => match fuel_vm_instr {
+                    FuelVmInstruction::Log {
+                        log_val, log_id, ..
+                    } => self.compile_log(handler, instr_val, log_val, log_id)?,
+                    FuelVmInstruction::Revert(revert_val) => {
+                        self.compile_revert(handler, revert_val)?
+                    }
+                    FuelVmInstruction::StateLoadWord(key) => {
+                        self.compile_state_load_word(handler, instr_val, key)?
+                    }
+                    FuelVmInstruction::StateStoreWord { stored_val, key } => {
+                        self.compile_state_store_word(handler, stored_val, key)?
+                    }
+                    _ => {
+                        return Err(self.unimplemented(
+                            handler,
+                            instr_val,
+                            "Using FuelVM specific instructions",
+                        ));
+                    }
+                },
                 InstOp::GetElemPtr {
                     base,
                     elem_ptr_ty,
                     indices,
-                } => self.compile_get_elem_ptr(instr_val, base, elem_ptr_ty, indices),
-                InstOp::GetLocal(local_var) => self.compile_get_local(instr_val, local_var),
-                InstOp::GetConfig(_, name) => self.compile_get_config(instr_val, name),
-                InstOp::IntToPtr(val, _) => self.compile_int_to_ptr(instr_val, val),
+                } => self.compile_get_elem_ptr(handler, instr_val, base, elem_ptr_ty, indices)?,
+                InstOp::GetLocal(local_var) => {
+                    self.compile_get_local(handler, instr_val, local_var)?
+                }
+                InstOp::GetConfig(..) => {
+                    return Err(self.unimplemented(handler, instr_val, "Using configurables"));
+                }
+                InstOp::IntToPtr(val, _) => self.compile_int_to_ptr(handler, instr_val, val)?,
                 InstOp::Load(src_val) => self.compile_load(handler, instr_val, src_val)?,
-                InstOp::MemCopyBytes {
-                    dst_val_ptr,
-                    src_val_ptr,
-                    byte_len,
-                } => self.compile_mem_copy_bytes(instr_val, dst_val_ptr, src_val_ptr, *byte_len),
+                InstOp::MemCopyBytes { .. } => {
+                    return Err(self.unimplemented(handler, instr_val, "Copying raw bytes"));
+                }
                 InstOp::MemCopyVal {
                     dst_val_ptr,
                     src_val_ptr,
-                } => self.compile_mem_copy_val(instr_val, dst_val_ptr, src_val_ptr),
+                } => self.compile_mem_copy_val(handler, dst_val_ptr, src_val_ptr)?,
                 InstOp::Nop => (),
-                InstOp::PtrToInt(ptr_val, int_ty) => {
-                    self.compile_ptr_to_int(instr_val, ptr_val, int_ty)
+                InstOp::PtrToInt(ptr_val, _) => {
+                    self.compile_ptr_to_int(handler, instr_val, ptr_val)?
                 }
                 InstOp::Ret(ret_val, ty) => {
                     if func_is_entry {
-                        self.compile_ret_from_entry(instr_val, ret_val, ty)
+                        self.compile_ret_from_entry(handler, ret_val, ty)?
                     } else {
-                        self.compile_ret_from_call(instr_val, ret_val)
+                        self.compile_ret_from_call(handler, instr_val, ret_val)?
                     }
                 }
                 InstOp::Store {
                     dst_val_ptr: dst_val,
                     stored_val,
-                } => self.compile_store(handler, instr_val, dst_val, stored_val)?,
+                } => self.compile_store(handler, dst_val, stored_val)?,
             }
         } else {
             handler.emit_err(CompileError::Internal(
                 "Value not an instruction.",
-                self.md_mgr
-                    .val_to_span(self.context, *instr_val)
-                    .unwrap_or_else(Self::empty_span),
+                self.instr_span(instr_val),
             ));
         }
         Ok(())
@@ -386,46 +987,144 @@ impl<'ir, 'eng> EvmAsmBuilder<'ir, 'eng> {
         &mut self,
         handler: &Handler,
         instr_val: &Value,
-        asm: &AsmBlock,
-        asm_args: &[AsmArg],
+        _asm: &AsmBlock,
+        _asm_args: &[AsmArg],
     ) -> Result<(), ErrorEmitted> {
-        todo!();
+        Err(self.unimplemented(handler, instr_val, "Using ASM blocks"))
     }
 
-    fn compile_bitcast(&mut self, instr_val: &Value, bitcast_val: &Value, to_type: &Type) {
-        todo!();
+    fn compile_bitcast(
+        &mut self,
+        handler: &Handler,
+        instr_val: &Value,
+        bitcast_val: &Value,
+        _to_type: &Type,
+    ) -> Result<(), ErrorEmitted> {
+        // Scalars are all words, casting between them does not change their representation.
+        self.push_value(handler, bitcast_val)?;
+        self.store_result(handler, instr_val)
     }
 
-    fn compile_unary_op(&mut self, instr_val: &Value, op: &UnaryOpKind, arg: &Value) {
-        todo!();
+    fn compile_unary_op(
+        &mut self,
+        handler: &Handler,
+        instr_val: &Value,
+        op: &UnaryOpKind,
+        arg: &Value,
+    ) -> Result<(), ErrorEmitted> {
+        match op {
+            UnaryOpKind::Not => {
+                self.push_value(handler, arg)?;
+                let ty = arg.get_type(self.context).unwrap();
+                if ty.is_bool(self.context) {
+                    self.push_op(Op::IsZero(IsZero));
+                } else {
+                    self.push_op(Op::Not(Not));
+                    self.push_mask(ty.get_uint_width(self.context).unwrap_or(256));
+                }
+            }
+        }
+        self.store_result(handler, instr_val)
     }
 
     fn compile_binary_op(
         &mut self,
+        handler: &Handler,
         instr_val: &Value,
         op: &BinaryOpKind,
         arg1: &Value,
         arg2: &Value,
-    ) {
-        todo!();
+    ) -> Result<(), ErrorEmitted> {
+        let width = arg1
+            .get_type(self.context)
+            .and_then(|ty| ty.get_uint_width(self.context))
+            .unwrap_or(256);
+
+        // Like on the FuelVM, dividing by zero panics.
+        if matches!(op, BinaryOpKind::Div | BinaryOpKind::Mod) {
+            self.push_value(handler, arg2)?;
+            self.push_op(Op::IsZero(IsZero));
+            self.push_panic_if();
+        }
+
+        // The EVM pops the first operand from the top of the stack, except for shifts, which pop
+        // the shift amount first.
+        match op {
+            BinaryOpKind::Lsh | BinaryOpKind::Rsh => {
+                self.push_value(handler, arg1)?;
+                self.push_value(handler, arg2)?;
+            }
+            _ => {
+                self.push_value(handler, arg2)?;
+                self.push_value(handler, arg1)?;
+            }
+        }
+
+        match op {
+            BinaryOpKind::Add => self.push_op(Op::Add(Add)),
+            BinaryOpKind::Sub => self.push_op(Op::Sub(Sub)),
+            BinaryOpKind::Mul => self.push_op(Op::Mul(Mul)),
+            BinaryOpKind::Div => self.push_op(Op::Div(Div)),
+            BinaryOpKind::And => self.push_op(Op::And(And)),
+            BinaryOpKind::Or => self.push_op(Op::Or(Or)),
+            BinaryOpKind::Xor => self.push_op(Op::Xor(Xor)),
+            BinaryOpKind::Mod => self.push_op(Op::Mod(Mod)),
+            BinaryOpKind::Rsh => self.push_op(Op::Shr(Shr)),
+            BinaryOpKind::Lsh => self.push_op(Op::Shl(Shl)),
+        }
+
+        // Integers narrower than a word must not silently wrap around.  Left shifts drop the
+        // shifted out bits instead.
+        match op {
+            BinaryOpKind::Add | BinaryOpKind::Sub | BinaryOpKind::Mul => {
+                self.push_overflow_check(width)
+            }
+            BinaryOpKind::Lsh => self.push_mask(width),
+            _ => (),
+        }
+
+        self.store_result(handler, instr_val)
     }
 
-    fn compile_branch(&mut self, to_block: &BranchToWithArgs) {
-        todo!();
+    fn compile_branch(
+        &mut self,
+        handler: &Handler,
+        to_block: &BranchToWithArgs,
+    ) -> Result<(), ErrorEmitted> {
+        self.compile_branch_to_phi_value(handler, to_block)?;
+        let label = self.block_to_label(&to_block.block);
+        self.push_label(label);
+        self.push_op(Op::Jump(Jump));
+        Ok(())
     }
 
-    fn compile_cast_ptr(&mut self, instr_val: &Value, val: &Value, ty: &Type) {
-        todo!();
+    fn compile_cast_ptr(
+        &mut self,
+        handler: &Handler,
+        instr_val: &Value,
+        val: &Value,
+        _ty: &Type,
+    ) -> Result<(), ErrorEmitted> {
+        self.push_value(handler, val)?;
+        self.store_result(handler, instr_val)
     }
 
     fn compile_cmp(
         &mut self,
+        handler: &Handler,
         instr_val: &Value,
         pred: &Predicate,
         lhs_value: &Value,
         rhs_value: &Value,
-    ) {
-        todo!();
+    ) -> Result<(), ErrorEmitted> {
+        self.push_value(handler, rhs_value)?;
+        self.push_value(handler, lhs_value)?;
+        match pred {
+            Predicate::Equal => self.push_op(Op::Eq(Eq)),
+            Predicate::LessThan => self.push_op(Op::Lt(Lt)),
+            Predicate::GreaterThan => self.push_op(Op::Gt(Gt)),
+        }
+        self.store_result(handler, instr_val)
     }
 
     fn compile_conditional_branch(
@@ -435,57 +1134,144 @@ impl<'ir, 'eng> EvmAsmBuilder<'ir, 'eng> {
         true_block: &BranchToWithArgs,
         false_block: &BranchToWithArgs,
     ) -> Result<(), ErrorEmitted> {
-        todo!();
-    }
+        // The arguments of the true block can only be passed after the jump, so jump to a stub
+        // passing them first.
+        let true_label = if true_block.args.is_empty() {
+            self.block_to_label(&true_block.block)
+        } else {
+            self.get_label()
+        };
 
-    fn compile_branch_to_phi_value(&mut self, to_block: &BranchToWithArgs) {
-        todo!();
-    }
+        self.push_value(handler, cond_value)?;
+        self.push_label(true_label);
+        self.push_op(Op::JumpI(JumpI));
 
-    #[allow(clippy::too_many_arguments)]
-    fn compile_contract_call(
-        &mut self,
-        instr_val: &Value,
-        params: &Value,
-        coins: &Value,
-        asset_id: &Value,
-        gas: &Value,
-    ) {
-        todo!();
+        self.compile_branch(handler, false_block)?;
+
+        if !true_block.args.is_empty() {
+            self.insert_label(true_label);
+            self.compile_branch(handler, true_block)?;
+        }
+        Ok(())
     }
 
-    fn compile_get_storage_key(
+    fn compile_branch_to_phi_value(
         &mut self,
         handler: &Handler,
-        instr_val: &Value,
+        to_block: &BranchToWithArgs,
     ) -> Result<(), ErrorEmitted> {
-        todo!();
+        let params = to_block
+            .block
+            .arg_iter(self.context)
+            .copied()
+            .collect::<Vec<_>>();
+
+        // The arguments may refer to the parameters themselves, e.g., when swapping them in a
+        // loop.  Read all scalar arguments before writing any of the parameters.
+        let mut scalar_params = Vec::new();
+        for (param, arg) in params.iter().zip(to_block.args.iter()) {
+            if self.is_scalar(arg) {
+                self.push_value(handler, arg)?;
+                scalar_params.push(*param);
+            }
+        }
+        for param in scalar_params.iter().rev() {
+            self.store_result(handler, param)?;
+        }
+
+        for (param, arg) in params.iter().zip(to_block.args.iter()) {
+            if !self.is_scalar(arg) {
+                let param_addr = self.value_addr(handler, *param)?;
+                self.copy_value_to(handler, EvmAddr::Static(param_addr), arg)?;
+            }
+        }
+        Ok(())
     }
 
     fn compile_get_elem_ptr(
         &mut self,
+        handler: &Handler,
         instr_val: &Value,
         base: &Value,
-        elem_ptr_ty: &Type,
+        _elem_ptr_ty: &Type,
         indices: &[Value],
-    ) {
-        todo!();
-    }
-
-    fn compile_get_local(&mut self, instr_val: &Value, local_var: &LocalVar) {
-        todo!();
-    }
+    ) -> Result<(), ErrorEmitted> {
+        let mut ty = base
+            .get_type(self.context)
+            .and_then(|ty| ty.get_pointee_type(self.context))
+            .unwrap();
+
+        let mut static_offset = 0;
+        let mut dynamic_indices = Vec::new();
+        for index in indices {
+            let const_index = index
+                .get_constant(self.context)
+                .and_then(|c| match c.value {
+                    ConstantValue::Uint(n) => Some(n),
+                    _ => None,
+                });
+            match (ty.get_content(self.context).clone(), const_index) {
+                (TypeContent::Struct(fields), Some(idx)) => {
+                    static_offset += fields[..idx as usize]
+                        .iter()
+                        .map(|field| self.type_words(*field).unwrap_or(1))
+                        .sum::<u64>();
+                    ty = fields[idx as usize];
+                }
+                (TypeContent::Union(variants), Some(idx)) => ty = variants[idx as usize],
+                (TypeContent::Array(elem_ty, _), const_index) => {
+                    let elem_words = self.type_words(elem_ty).unwrap_or(1);
+                    match const_index {
+                        Some(idx) => static_offset += idx * elem_words,
+                        None => dynamic_indices.push((*index, elem_words)),
+                    }
+                    ty = elem_ty;
+                }
+                _ => {
+                    return Err(handler.emit_err(CompileError::Internal(
+                        "Invalid indices in get_elem_ptr.",
+                        self.instr_span(instr_val),
+                    )))
+                }
+            }
+        }
 
-    fn compile_get_config(&mut self, instr_val: &Value, name: &str) {
-        todo!();
+        self.push_value(handler, base)?;
+        self.push_number(static_offset * EVM_WORD_SIZE);
+        self.push_op(Op::Add(Add));
+        for (index, elem_words) in dynamic_indices {
+            self.push_value(handler, &index)?;
+            self.push_number(elem_words * EVM_WORD_SIZE);
+            self.push_op(Op::Mul(Mul));
+            self.push_op(Op::Add(Add));
+        }
+        self.store_result(handler, instr_val)
     }
 
-    fn compile_gtf(&mut self, instr_val: &Value, index: &Value, tx_field_id: u64) {
-        todo!();
+    fn compile_get_local(
+        &mut self,
+        handler: &Handler,
+        instr_val: &Value,
+        local_var: &LocalVar,
+    ) -> Result<(), ErrorEmitted> {
+        let Some(addr) = self.local_addrs.get(local_var).copied() else {
+            return Err(handler.emit_err(CompileError::Internal(
+                "Local variable not found.",
+                self.instr_span(instr_val),
+            )));
+        };
+        self.push_number(addr);
+        self.store_result(handler, instr_val)
     }
 
-    fn compile_int_to_ptr(&mut self, instr_val: &Value, int_to_ptr_val: &Value) {
-        todo!();
+    fn compile_int_to_ptr(
+        &mut self,
+        handler: &Handler,
+        instr_val: &Value,
+        int_to_ptr_val: &Value,
+    ) -> Result<(), ErrorEmitted> {
+        self.push_value(handler, int_to_ptr_val)?;
+        self.store_result(handler, instr_val)
     }
 
     fn compile_load(
@@ -494,80 +1280,119 @@ impl<'ir, 'eng> EvmAsmBuilder<'ir, 'eng> {
         instr_val: &Value,
         src_val: &Value,
     ) -> Result<(), ErrorEmitted> {
-        todo!();
-    }
-
-    fn compile_log(&mut self, instr_val: &Value, log_val: &Value, log_ty: &Type, log_id: &Value) {
-        todo!();
+        if self.is_scalar(instr_val) {
+            self.push_value(handler, src_val)?;
+            self.push_op(Op::MLoad(MLoad));
+            self.store_result(handler, instr_val)
+        } else {
+            let dst = self.value_addr(handler, *instr_val)?;
+            let words = self.value_words(handler, *instr_val)?;
+            self.copy_words(
+                handler,
+                EvmAddr::Static(dst),
+                EvmAddr::Dynamic(*src_val),
+                words,
+            )
+        }
     }
 
-    fn compile_mem_copy_bytes(
+    fn compile_log(
         &mut self,
+        handler: &Handler,
         instr_val: &Value,
-        dst_val_ptr: &Value,
-        src_val_ptr: &Value,
-        byte_len: u64,
-    ) {
-        todo!();
+        log_val: &Value,
+        log_id: &Value,
+    ) -> Result<(), ErrorEmitted> {
+        // The log id is the only topic, the logged value is the data.
+        //   push log_id
+        //   push size
+        //   push offset
+        //   log1
+        let (offset, size) = if self.is_scalar(log_val) {
+            self.copy_value_to(handler, EvmAddr::Static(0), log_val)?;
+            (0, EVM_WORD_SIZE)
+        } else {
+            let addr = self.aggregate_addr(handler, log_val)?;
+            let words = self.value_words(handler, *log_val)?;
+            (addr, words * EVM_WORD_SIZE)
+        };
+        if !self.is_scalar(log_id) {
+            return Err(self.unimplemented(handler, instr_val, "Logging with a non-scalar id"));
+        }
+        self.push_value(handler, log_id)?;
+        self.push_number(size);
+        self.push_number(offset);
+        self.push_op(Op::Log1(Log1));
+        Ok(())
     }
 
     fn compile_mem_copy_val(
         &mut self,
-        instr_val: &Value,
+        handler: &Handler,
         dst_val_ptr: &Value,
         src_val_ptr: &Value,
-    ) {
-        todo!();
-    }
-
-    fn compile_ptr_to_int(&mut self, instr_val: &Value, ptr_val: &Value, int_ty: &Type) {
-        todo!();
+    ) -> Result<(), ErrorEmitted> {
+        let words = self.pointee_words(handler, dst_val_ptr)?;
+        self.copy_words(
+            handler,
+            EvmAddr::Dynamic(*dst_val_ptr),
+            EvmAddr::Dynamic(*src_val_ptr),
+            words,
+        )
     }
 
-    fn compile_read_register(&mut self, instr_val: &Value, reg: &sway_ir::Register) {
-        todo!();
+    fn compile_ptr_to_int(
+        &mut self,
+        handler: &Handler,
+        instr_val: &Value,
+        ptr_val: &Value,
+    ) -> Result<(), ErrorEmitted> {
+        self.push_value(handler, ptr_val)?;
+        self.store_result(handler, instr_val)
     }
 
-    fn compile_ret_from_entry(&mut self, instr_val: &Value, ret_val: &Value, ret_type: &Type) {
+    fn compile_ret_from_entry(
+        &mut self,
+        handler: &Handler,
+        ret_val: &Value,
+        ret_type: &Type,
+    ) -> Result<(), ErrorEmitted> {
+        // The returned data is ABI encoded, scalars as a single word and aggregates as static
+        // tuples.
+        //   push size
+        //   push offset
+        //   return
         if ret_type.is_unit(self.context) {
-            // Unit returns should always be zero, although because they can be omitted from
-            // functions, the register is sometimes uninitialized. Manually return zero in this
-            // case.
-            self.cur_section
-                .as_mut()
-                .unwrap()
-                .ops
-                .push(AbstractOp::Op(Op::Return(Return)));
+            self.push_number(0);
+            self.push_number(0);
+        } else if self.is_scalar(ret_val) {
+            self.copy_value_to(handler, EvmAddr::Static(0), ret_val)?;
+            self.push_number(EVM_WORD_SIZE);
+            self.push_number(0);
         } else {
-            todo!();
+            let addr = self.aggregate_addr(handler, ret_val)?;
+            let words = self.value_words(handler, *ret_val)?;
+            self.push_number(words * EVM_WORD_SIZE);
+            self.push_number(addr);
         }
+        self.push_op(Op::Return(Return));
+        Ok(())
     }
 
-    fn compile_revert(&mut self, instr_val: &Value, revert_val: &Value) {
-        todo!();
-    }
-
-    fn compile_smo(
-        &mut self,
-        instr_val: &Value,
-        recipient_and_message: &Value,
-        message_size: &Value,
-        output_index: &Value,
-        coins: &Value,
-    ) {
-        todo!();
-    }
-
-    fn compile_state_access_quad_word(
+    fn compile_revert(
         &mut self,
         handler: &Handler,
-        instr_val: &Value,
-        val: &Value,
-        key: &Value,
-        number_of_slots: &Value,
-        access_type: StateAccessType,
+        revert_val: &Value,
     ) -> Result<(), ErrorEmitted> {
-        todo!();
+        // The revert code is returned as the revert data.
+        //   push1 0x20
+        //   push1 0x00
+        //   revert
+        self.copy_value_to(handler, EvmAddr::Static(0), revert_val)?;
+        self.push_number(EVM_WORD_SIZE);
+        self.push_number(0);
+        self.push_op(Op::Revert(Revert));
+        Ok(())
     }
 
     fn compile_state_load_word(
@@ -576,27 +1401,33 @@ impl<'ir, 'eng> EvmAsmBuilder<'ir, 'eng> {
         instr_val: &Value,
         key: &Value,
     ) -> Result<(), ErrorEmitted> {
-        todo!();
+        // The key is a pointer to a `b256`, which is a single EVM word.
+        self.push_value(handler, key)?;
+        self.push_op(Op::MLoad(MLoad));
+        self.push_op(Op::SLoad(SLoad));
+        self.store_result(handler, instr_val)
     }
 
     fn compile_state_store_word(
         &mut self,
         handler: &Handler,
-        instr_val: &Value,
         store_val: &Value,
         key: &Value,
     ) -> Result<(), ErrorEmitted> {
-        todo!();
+        self.push_value(handler, store_val)?;
+        self.push_value(handler, key)?;
+        self.push_op(Op::MLoad(MLoad));
+        self.push_op(Op::SStore(SStore));
+        Ok(())
     }
 
     fn compile_store(
         &mut self,
         handler: &Handler,
-        instr_val: &Value,
         dst_val: &Value,
         stored_val: &Value,
     ) -> Result<(), ErrorEmitted> {
-        todo!();
+        self.copy_value_to(handler, EvmAddr::Dynamic(*dst_val), stored_val)
     }
 
     pub(super) fn func_to_labels(&mut self, func: &Function) -> (Label, Label) {
@@ -614,31 +1445,15 @@ impl<'ir, 'eng> EvmAsmBuilder<'ir, 'eng> {
     ) -> Result<(), ErrorEmitted> {
         self.cur_section = Some(EvmAsmSection::new());
 
-        // push1 0x80
-        // push1 0x40
-        // mstore
-        self.cur_section
-            .as_mut()
-            .unwrap()
-            .ops
-            .push(AbstractOp::new(Op::Push1(Push1(Imm::with_expression(
-                Expression::Terminal(0x80.into()),
-            )))));
-        self.cur_section
-            .as_mut()
-            .unwrap()
-            .ops
-            .push(AbstractOp::new(Op::Push1(Push1(Imm::with_expression(
-                Expression::Terminal(0x40.into()),
-            )))));
-        self.cur_section
-            .as_mut()
-            .unwrap()
-            .ops
-            .push(AbstractOp::new(Op::MStore(MStore)));
-
-        //self.init_locals(function);
         let func_is_entry = function.is_entry(self.context);
+        if func_is_entry {
+            self.entry_functions.push(function);
+        }
+
+        let (start_label, _) = self.func_to_labels(&function);
+        self.insert_label(start_label);
+        self.frame(handler, function)?;
+        self.init_locals(handler, function)?;
 
         // Compile instructions.
         for block in function.block_iter(self.context) {
@@ -648,63 +1463,109 @@ impl<'ir, 'eng> EvmAsmBuilder<'ir, 'eng> {
             }
         }
 
-        // push1 0x00
-        // dup1
-        // revert
-        self.cur_section
-            .as_mut()
-            .unwrap()
-            .ops
-            .push(AbstractOp::new(Op::Push1(Push1(Imm::with_expression(
-                Expression::Terminal(0x00.into()),
-            )))));
-        self.cur_section
-            .as_mut()
-            .unwrap()
-            .ops
-            .push(AbstractOp::new(Op::Dup1(Dup1)));
-        self.cur_section
-            .as_mut()
-            .unwrap()
-            .ops
-            .push(AbstractOp::new(Op::Revert(Revert)));
-
-        // Generate the ABI.
-        #[allow(deprecated)]
-        self.cur_section
-            .as_mut()
-            .unwrap()
-            .abi
-            .push(ethabi::operation::Operation::Function(ethabi::Function {
-                name: function.get_name(self.context).to_string(),
-                inputs: vec![],
-                outputs: vec![],
-                constant: None,
-                state_mutability: ethabi::StateMutability::NonPayable,
-            }));
-
         self.sections.push(self.cur_section.take().unwrap());
         self.cur_section = None;
 
         Ok(())
     }
 
-    pub(super) fn compile_call(&mut self, instr_val: &Value, function: &Function, args: &[Value]) {
-        todo!();
+    /// Allocates the locals of `function` and writes their initial values.
+    fn init_locals(&mut self, handler: &Handler, function: Function) -> Result<(), ErrorEmitted> {
+        for (name, local_var) in function.locals_iter(self.context) {
+            let ty = local_var.get_inner_type(self.context);
+            let Some(words) = self.type_words(ty) else {
+                let feature = format!(
+                    "Declaring the local `{name}` of type `{}`",
+                    ty.as_string(self.context)
+                );
+                return Err(self.unimplemented_in_fn(handler, function, &feature));
+            };
+            let addr = self.alloc_words(words);
+            self.local_addrs.insert(*local_var, addr);
+
+            if let Some(constant) = local_var.get_initializer(self.context) {
+                self.store_constant(handler, None, constant, addr)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn compile_call(
+        &mut self,
+        handler: &Handler,
+        instr_val: &Value,
+        function: &Function,
+        args: &[Value],
+    ) -> Result<(), ErrorEmitted> {
+        // Functions are never reentered, so their arguments, return value and return address
+        // are passed through their statically allocated frame.
+        //   <copy the args into the frame>
+        //   push return_label
+        //   push return_addr
+        //   mstore
+        //   push start_label
+        //   jump
+        // return_label:
+        //   jumpdest
+        //   <copy the returned value from the frame>
+        let frame = self.frame(handler, *function)?;
+        let params = function
+            .args_iter(self.context)
+            .map(|(_, param)| *param)
+            .collect::<Vec<_>>();
+        for (param, arg) in params.iter().zip(args.iter()) {
+            let param_addr = self.value_addr(handler, *param)?;
+            self.copy_value_to(handler, EvmAddr::Static(param_addr), arg)?;
+        }
+
+        let return_label = self.get_label();
+        self.push_label(return_label);
+        self.push_number(frame.return_addr);
+        self.push_op(Op::MStore(MStore));
+        let (start_label, _) = self.func_to_labels(function);
+        self.push_label(start_label);
+        self.push_op(Op::Jump(Jump));
+        self.insert_label(return_label);
+
+        if self.is_scalar(instr_val) {
+            self.push_number(frame.return_value_addr);
+            self.push_op(Op::MLoad(MLoad));
+            self.store_result(handler, instr_val)
+        } else {
+            let dst = self.value_addr(handler, *instr_val)?;
+            let words = self.value_words(handler, *instr_val)?;
+            self.copy_words(
+                handler,
+                EvmAddr::Static(dst),
+                EvmAddr::Static(frame.return_value_addr),
+                words,
+            )
+        }
     }
 
-    pub(super) fn compile_ret_from_call(&mut self, instr_val: &Value, ret_val: &Value) {
-        todo!();
+    pub(super) fn compile_ret_from_call(
+        &mut self,
+        handler: &Handler,
+        instr_val: &Value,
+        ret_val: &Value,
+    ) -> Result<(), ErrorEmitted> {
+        let Some(instruction) = instr_val.get_instruction(self.context) else {
+            let span = self.instr_span(instr_val);
+            return Err(handler.emit_err(CompileError::Internal("Value not an instruction.", span)));
+        };
+        let function = instruction.parent.get_function(self.context);
+        let frame = self.frame(handler, function)?;
+        self.copy_value_to(handler, EvmAddr::Static(frame.return_value_addr), ret_val)?;
+        self.push_number(frame.return_addr);
+        self.push_op(Op::MLoad(MLoad));
+        self.push_op(Op::Jump(Jump));
+        Ok(())
     }
 
     pub(super) fn insert_block_label(&mut self, block: Block) {
         if &block.get_label(self.context) != "entry" {
             let label = self.block_to_label(&block);
-            self.cur_section
-                .as_mut()
-                .unwrap()
-                .ops
-                .push(AbstractOp::Label(label.to_string()));
+            self.insert_label(label);
         }
     }
 
@@ -725,9 +1586,7 @@ struct EvmFinalProgram {
 impl EvmFinalProgram {
     fn finalize(self) -> FinalizedAsm {
         FinalizedAsm {
-            data_section: DataSection {
-                ..Default::default()
-            },
+            data_section: DataSection::default(),
             program_section: InstructionSet::Evm { ops: self.ops },
             program_kind: ProgramKind::Script,
             entries: vec![],
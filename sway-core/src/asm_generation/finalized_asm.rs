@@ -3,7 +3,7 @@ use super::{
     fuel::{checks, data_section::DataSection},
     ProgramABI, ProgramKind,
 };
-use crate::asm_generation::fuel::data_section::{Datum, Entry, EntryName};
+use crate::asm_generation::fuel::data_section::{DataSectionSize, Datum, Entry, EntryName};
 use crate::asm_lang::allocated_ops::{AllocatedOp, AllocatedOpcode, FuelAsmData};
 use crate::decl_engine::DeclRefFunction;
 use crate::source_map::SourceMap;
@@ -65,6 +65,10 @@ pub struct FinalizedEntry {
 pub struct CompiledBytecode {
     pub bytecode: Vec<u8>,
     pub named_data_section_entries_offsets: BTreeMap<String, u64>,
+    /// The sizes in bytes of the configuration-time constants in the bytecode.
+    pub named_data_section_entries_sizes: BTreeMap<String, u64>,
+    /// The size of the data section, broken down by the origin of its entries.
+    pub data_section_size: DataSectionSize,
 }
 
 impl FinalizedAsm {
@@ -91,6 +95,8 @@ impl FinalizedAsm {
                     Ok(CompiledBytecode {
                         bytecode: assembler.take(),
                         named_data_section_entries_offsets: BTreeMap::new(),
+                        named_data_section_entries_sizes: BTreeMap::new(),
+                        data_section_size: DataSectionSize::default(),
                     })
                 }
             }
@@ -380,13 +386,24 @@ fn to_bytecode_mut(
             )
         })
         .collect::<BTreeMap<String, u64>>();
+    let named_data_section_entries_sizes = data_section
+        .configurables
+        .iter()
+        .filter_map(|entry| match &entry.name {
+            EntryName::Configurable(name) => Some((name.clone(), entry.to_bytes().len() as u64)),
+            EntryName::NonConfigurable => None,
+        })
+        .collect::<BTreeMap<String, u64>>();
 
+    let data_section_size = data_section.size();
     let mut data_section = data_section.serialize_to_bytes();
     bytecode.append(&mut data_section);
 
     CompiledBytecode {
         bytecode,
         named_data_section_entries_offsets,
+        named_data_section_entries_sizes,
+        data_section_size,
     }
 }
 
@@ -12,8 +12,12 @@ use crate::{asm_generation::ProgramKind, BuildConfig, BuildTarget};
 
 use crate::asm_lang::VirtualImmediate18;
 
-use sway_error::handler::{ErrorEmitted, Handler};
+use sway_error::{
+    error::CompileError,
+    handler::{ErrorEmitted, Handler},
+};
 use sway_ir::{Context, Kind, Module};
+use sway_types::Span;
 
 pub fn compile_ir_context_to_finalized_asm(
     handler: &Handler,
@@ -49,13 +53,28 @@ pub fn compile_ir_context_to_finalized_asm(
             build_config,
             FuelAsmBuilder::new(kind, DataSection::default(), reg_seqr, ir),
         ),
-        BuildTarget::EVM => compile(
-            handler,
-            ir,
-            module,
-            build_config,
-            EvmAsmBuilder::new(kind, ir),
-        ),
+        BuildTarget::EVM => {
+            // The EVM backend generates its own Solidity compatible dispatching and decoding,
+            // instead of the FuelVM specific `__entry` functions of the new encoding.
+            if ir.experimental.new_encoding {
+                return Err(handler.emit_err(CompileError::Unimplemented {
+                    feature:
+                        "Compiling with the `new_encoding` experimental feature for the EVM target"
+                            .to_string(),
+                    help: vec![
+                        "Disable it by passing `--no-experimental new_encoding`.".to_string()
+                    ],
+                    span: Span::dummy(),
+                }));
+            }
+            compile(
+                handler,
+                ir,
+                module,
+                build_config,
+                EvmAsmBuilder::new(kind, ir),
+            )
+        }
     }?;
 
     check_invalid_opcodes(handler, &finalized_asm)?;
@@ -1,9 +1,9 @@
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use sway_ir::{size_bytes_round_up_to_word_alignment, Constant, ConstantValue, Context, Padding};
 
 use std::{fmt, iter::repeat};
 
-#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize)]
 pub enum EntryName {
     NonConfigurable,
     Configurable(String),
@@ -189,30 +189,38 @@ impl Entry {
         matches!(self.value, Datum::Byte(_))
     }
 
-    pub(crate) fn equiv(&self, entry: &Entry) -> bool {
-        fn equiv_data(lhs: &Datum, rhs: &Datum) -> bool {
-            match (lhs, rhs) {
-                (Datum::Byte(l), Datum::Byte(r)) => l == r,
-                (Datum::Word(l), Datum::Word(r)) => l == r,
-                (Datum::ByteArray(l), Datum::ByteArray(r)) => l == r,
-                (Datum::Collection(l), Datum::Collection(r)) => {
-                    l.len() == r.len()
-                        && l.iter()
-                            .zip(r.iter())
-                            .all(|(l, r)| equiv_data(&l.value, &r.value))
-                }
-                _ => false,
-            }
-        }
+    /// Returns whether the entry can be pooled with adjacent entries in the same word.
+    ///
+    /// Single byte constants are loaded with `LB`, which takes a byte offset, so they don't need
+    /// to be word aligned. Configurables always are, as their offsets are part of the ABI.
+    fn is_poolable(&self) -> bool {
+        self.is_byte() && self.name == EntryName::NonConfigurable && self.padding.target_size() == 1
+    }
 
-        // If this corresponds to a configuration-time constants, then the entry names will be
-        // available (i.e. `Some(..)`) and they must be the same before we can merge the two
-        // entries. Otherwise, `self.name` and `entry.name` will be `None` in which case we're also
-        // allowed to merge the two entries (if their values are equivalent of course).
-        equiv_data(&self.value, &entry.value) && self.name == entry.name
+    /// The key under which the entry is deduplicated. Two entries with the same key have the same
+    /// serialized representation and are loaded the same way, so they can share their storage.
+    fn content_key(&self) -> DataContentKey {
+        let load = match self.value {
+            Datum::Byte(_) => DataLoadKind::Byte,
+            Datum::Word(_) => DataLoadKind::Word,
+            Datum::ByteArray(_) | Datum::Slice(_) | Datum::Collection(_) => DataLoadKind::Pointer,
+        };
+        (self.name.clone(), load, self.to_bytes())
     }
 }
 
+/// How an entry is loaded from the data section.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum DataLoadKind {
+    Byte,
+    Word,
+    Pointer,
+}
+
+// Configurables are only ever merged with configurables of the same name, so the name is part of
+// the key, and non-configurables are merged with any non-configurable of the same content.
+type DataContentKey = (EntryName, DataLoadKind, Vec<u8>);
+
 #[derive(Clone, Debug)]
 pub enum DataIdEntryKind {
     NonConfigurable,
@@ -241,12 +249,40 @@ impl fmt::Display for DataId {
     }
 }
 
+/// The size in bytes of the data section, broken down by the kind of its entries.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DataSectionSize {
+    /// Configuration-time constants.
+    pub configurables: usize,
+    /// Constants which fit in a register, i.e. bytes and words.
+    pub words: usize,
+    /// Constants loaded through a pointer, like `b256`s, strings, arrays and structs.
+    pub aggregates: usize,
+    /// Pointers to the constants loaded through a pointer.
+    pub pointers: usize,
+    /// Padding inserted to word align the entries.
+    pub padding: usize,
+    /// The size of the entries which were not emitted because an identical entry already exists.
+    pub deduplicated: usize,
+}
+
+impl DataSectionSize {
+    /// The total size of the data section in bytes.
+    pub fn total(&self) -> usize {
+        self.configurables + self.words + self.aggregates + self.pointers + self.padding
+    }
+}
+
 /// The data to be put in the data section of the asm
 #[derive(Default, Clone, Debug)]
 pub struct DataSection {
     pub non_configurables: Vec<Entry>,
     pub configurables: Vec<Entry>,
     pub(crate) pointer_id: FxHashMap<u64, DataId>,
+    /// The ids of the entries by content, used to deduplicate the entries.
+    content_ids: FxHashMap<DataContentKey, DataId>,
+    /// The size of the entries deduplicated so far.
+    deduplicated_bytes: usize,
 }
 
 impl DataSection {
@@ -289,25 +325,82 @@ impl DataSection {
     /// Given an absolute index, calculate the offset _from the beginning of the data section_ to the data
     /// in bytes.
     pub(crate) fn absolute_idx_to_offset(&self, idx: usize) -> usize {
-        self.iter_all_entries().take(idx).fold(0, |offset, entry| {
-            //entries must be word aligned
-            size_bytes_round_up_to_word_alignment!(offset + entry.to_bytes().len())
-        })
+        let mut offset = 0;
+        let mut prev_poolable = false;
+        for (i, entry) in self
+            .non_configurables
+            .iter()
+            .chain(self.configurables.iter())
+            .enumerate()
+        {
+            // Entries must be word aligned, unless they are pooled with the previous entry.
+            let poolable = entry.is_poolable();
+            if !(poolable && prev_poolable) {
+                offset = size_bytes_round_up_to_word_alignment!(offset);
+            }
+            if i == idx {
+                return offset;
+            }
+            offset += entry.to_bytes().len();
+            prev_poolable = poolable;
+        }
+        size_bytes_round_up_to_word_alignment!(offset)
     }
 
     pub(crate) fn serialize_to_bytes(&self) -> Vec<u8> {
         // not the exact right capacity but serves as a lower bound
         let mut buf = Vec::with_capacity(self.num_entries());
-        for entry in self.iter_all_entries() {
+        let mut prev_poolable = false;
+        for entry in self
+            .non_configurables
+            .iter()
+            .chain(self.configurables.iter())
+        {
+            // Entries must be word aligned, unless they are pooled with the previous entry.
+            let poolable = entry.is_poolable();
+            if !(poolable && prev_poolable) {
+                let aligned_len = size_bytes_round_up_to_word_alignment!(buf.len());
+                buf.resize(aligned_len, 0);
+            }
             buf.append(&mut entry.to_bytes());
-
-            //entries must be word aligned
-            let aligned_len = size_bytes_round_up_to_word_alignment!(buf.len());
-            buf.extend(vec![0u8; aligned_len - buf.len()]);
+            prev_poolable = poolable;
         }
+        let aligned_len = size_bytes_round_up_to_word_alignment!(buf.len());
+        buf.resize(aligned_len, 0);
         buf
     }
 
+    /// Returns the size of the data section broken down by the origin of its entries.
+    pub fn size(&self) -> DataSectionSize {
+        let pointers = self
+            .pointer_id
+            .values()
+            .map(|id| id.idx as usize)
+            .collect::<FxHashSet<_>>();
+
+        let mut size = DataSectionSize {
+            deduplicated: self.deduplicated_bytes,
+            ..Default::default()
+        };
+        for (idx, entry) in self.non_configurables.iter().enumerate() {
+            let len = entry.to_bytes().len();
+            if pointers.contains(&idx) {
+                size.pointers += len;
+            } else if entry.has_copy_type() {
+                size.words += len;
+            } else {
+                size.aggregates += len;
+            }
+        }
+        size.configurables = self
+            .configurables
+            .iter()
+            .map(|entry| entry.to_bytes().len())
+            .sum();
+        size.padding = self.serialize_to_bytes().len() - size.total();
+        size
+    }
+
     /// Returns whether a specific [DataId] value has a copy type (fits in a register).
     pub(crate) fn has_copy_type(&self, id: &DataId) -> Option<bool> {
         self.get(id).map(|entry| entry.has_copy_type())
@@ -346,6 +439,11 @@ impl DataSection {
     /// [DataId].
     pub(crate) fn insert_data_value(&mut self, new_entry: Entry) -> DataId {
         // if there is an identical data value, use the same id
+        let key = new_entry.content_key();
+        if let Some(id) = self.content_ids.get(&key) {
+            self.deduplicated_bytes += key.2.len();
+            return id.clone();
+        }
 
         let (value_pairs, kind) = match new_entry.name {
             EntryName::NonConfigurable => (
@@ -354,20 +452,14 @@ impl DataSection {
             ),
             EntryName::Configurable(_) => (&mut self.configurables, DataIdEntryKind::Configurable),
         };
-        match value_pairs.iter().position(|entry| entry.equiv(&new_entry)) {
-            Some(num) => DataId {
-                idx: num as u32,
-                kind,
-            },
-            None => {
-                value_pairs.push(new_entry);
-                // the index of the data section where the value is stored
-                DataId {
-                    idx: (value_pairs.len() - 1) as u32,
-                    kind,
-                }
-            }
-        }
+        value_pairs.push(new_entry);
+        // the index of the data section where the value is stored
+        let id = DataId {
+            idx: (value_pairs.len() - 1) as u32,
+            kind,
+        };
+        self.content_ids.insert(key, id.clone());
+        id
     }
 
     // If the stored data is Datum::Word, return the inner value.
@@ -433,3 +525,54 @@ fn display_bytes_for_data_section(bs: &Vec<u8>, prefix: &str) -> String {
     }
     format!("{prefix}[{}] {hex_str} {chr_str}", bs.len())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_entries_are_deduplicated() {
+        let mut data_section = DataSection::default();
+        let b256 = vec![7u8; 32];
+        let first = data_section.insert_data_value(Entry::new_byte_array(
+            b256.clone(),
+            EntryName::NonConfigurable,
+            None,
+        ));
+        let second = data_section.insert_data_value(Entry::new_byte_array(
+            b256,
+            EntryName::NonConfigurable,
+            None,
+        ));
+        assert_eq!(first.idx, second.idx);
+        assert_eq!(data_section.num_entries(), 1);
+
+        // A byte and a word with the same value are loaded differently.
+        data_section.insert_data_value(Entry::new_byte(1, EntryName::NonConfigurable, None));
+        data_section.insert_data_value(Entry::new_word(1, EntryName::NonConfigurable, None));
+        assert_eq!(data_section.num_entries(), 3);
+
+        let size = data_section.size();
+        assert_eq!(size.aggregates, 32);
+        assert_eq!(size.deduplicated, 32);
+    }
+
+    #[test]
+    fn adjacent_bytes_are_pooled() {
+        let mut data_section = DataSection::default();
+        for byte in 1..=3 {
+            data_section.insert_data_value(Entry::new_byte(byte, EntryName::NonConfigurable, None));
+        }
+        let word =
+            data_section.insert_data_value(Entry::new_word(42, EntryName::NonConfigurable, None));
+
+        assert_eq!(data_section.absolute_idx_to_offset(1), 1);
+        assert_eq!(data_section.absolute_idx_to_offset(2), 2);
+        assert_eq!(data_section.data_id_to_offset(&word), 8);
+        assert_eq!(
+            data_section.serialize_to_bytes(),
+            [vec![1, 2, 3, 0, 0, 0, 0, 0], 42u64.to_be_bytes().to_vec()].concat()
+        );
+        assert_eq!(data_section.size().padding, 5);
+    }
+}
@@ -9,7 +9,7 @@ pub mod instruction_set;
 
 mod finalized_asm;
 pub use finalized_asm::*;
-pub use fuel::data_section::{Datum, Entry};
+pub use fuel::data_section::{DataSectionSize, Datum, Entry};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ProgramKind {
@@ -808,10 +808,10 @@ fn realize_load(
         "Internal miscalculation in data section -- data id did not match up to any actual data",
     );
 
-    // all data is word-aligned right now, and `offset_to_id` returns the offset in bytes
+    // all data but the pooled bytes is word-aligned, and `offset_to_id` returns the offset in bytes
     let offset_bytes = data_section.data_id_to_offset(data_id) as u64;
     assert!(
-        offset_bytes % 8 == 0,
+        is_byte || offset_bytes % 8 == 0,
         "Internal miscalculation in data section -- data offset is not aligned to a word",
     );
     let offset_words = offset_bytes / 8;
@@ -1,12 +1,16 @@
+use crate::analysis::{AnalysisPass, AnalysisPasses};
 use itertools::Itertools;
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     collections::{BTreeMap, HashSet},
     path::PathBuf,
     sync::Arc,
 };
 use strum::{Display, EnumString};
-use sway_ir::{PassManager, PrintPassesOpts};
+use sway_ir::{
+    InlineHeuristics, LoopUnrollHeuristics, PassManager, PrintPassesOpts, VerifyLevel,
+    VerifyPassesOpts,
+};
 
 #[derive(
     Clone,
@@ -34,13 +38,19 @@ pub enum BuildTarget {
     EVM,
 }
 
-#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
 pub enum OptLevel {
     #[default]
     Opt0 = 0,
     Opt1 = 1,
 }
 
+impl serde::Serialize for OptLevel {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_u8(*self as u8)
+    }
+}
+
 impl<'de> serde::Deserialize<'de> for OptLevel {
     fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
         let num = u8::deserialize(d)?;
@@ -171,6 +181,135 @@ impl From<&PrintIr> for PrintPassesOpts {
     }
 }
 
+/// When the IR is verified while running the optimization passes. The initial IR is always
+/// verified.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum VerifyIrLevel {
+    #[default]
+    Initial,
+    /// Verify the final IR and, if it is invalid, find the pass producing it.
+    Final,
+    /// Verify the IR after each pass that modifies it.
+    AfterEachPass,
+}
+
+/// How to verify the IR.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct VerifyIr {
+    pub level: VerifyIrLevel,
+    /// The file to write the IR reproducing invalid IR to, if the passes produce invalid IR.
+    pub reproducer: Option<PathBuf>,
+}
+
+impl From<&VerifyIr> for VerifyPassesOpts {
+    fn from(value: &VerifyIr) -> Self {
+        Self {
+            level: match value.level {
+                VerifyIrLevel::Initial => VerifyLevel::None,
+                VerifyIrLevel::Final => VerifyLevel::Final,
+                VerifyIrLevel::AfterEachPass => VerifyLevel::AfterEachPass,
+            },
+            reproducer: value.reproducer.clone(),
+        }
+    }
+}
+
+/// Adjustments to the IR passes run for the selected optimization level.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct IrPassOverrides {
+    /// Optimization passes to run after the passes of the optimization level.
+    #[serde(default)]
+    pub enable: Vec<String>,
+    /// Passes of the optimization level that are not run.
+    #[serde(default)]
+    pub disable: Vec<String>,
+}
+
+impl IrPassOverrides {
+    /// Returns the first pass name that isn't a known optimization pass, if any.
+    pub fn unknown_pass(&self) -> Option<&str> {
+        self.enable
+            .iter()
+            .chain(self.disable.iter())
+            .find(|pass| !PassManager::OPTIMIZATION_PASSES.contains(&pass.as_str()))
+            .map(String::as_str)
+    }
+}
+
+/// Adjustments to the thresholds of the cost model of the function inlining pass.
+///
+/// The thresholds that are not set keep the values of [InlineHeuristics::default].
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct InlineThresholds {
+    /// Estimated gas cost of setting up a call frame, jumping to the callee and returning.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call_gas_cost: Option<u64>,
+    /// Estimated gas cost of passing a single argument to the callee.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arg_gas_cost: Option<u64>,
+    /// The gas equivalent of a single instruction added to the bytecode by inlining.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instruction_size_cost: Option<u64>,
+    /// Functions with at most this many instructions are always inlined.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_trivial_instructions: Option<u64>,
+}
+
+impl InlineThresholds {
+    /// Returns the default [InlineHeuristics] with the thresholds that are set replaced.
+    pub fn heuristics(&self) -> InlineHeuristics {
+        let default = InlineHeuristics::default();
+        InlineHeuristics {
+            call_gas_cost: self.call_gas_cost.unwrap_or(default.call_gas_cost),
+            arg_gas_cost: self.arg_gas_cost.unwrap_or(default.arg_gas_cost),
+            instruction_size_cost: self
+                .instruction_size_cost
+                .unwrap_or(default.instruction_size_cost),
+            max_trivial_instructions: self
+                .max_trivial_instructions
+                .unwrap_or(default.max_trivial_instructions),
+            ..default
+        }
+    }
+}
+
+/// Adjustments to the thresholds of the loop unrolling pass.
+///
+/// The thresholds that are not set keep the values of [LoopUnrollHeuristics::default].
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct LoopUnrollThresholds {
+    /// Loops with at most this many iterations are fully unrolled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_full_unroll_trip_count: Option<u64>,
+    /// The number of copies of the body of a partially unrolled loop.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partial_unroll_factor: Option<u64>,
+    /// The maximum number of instructions an unrolled loop may consist of.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_unrolled_instructions: Option<u64>,
+}
+
+impl LoopUnrollThresholds {
+    /// Returns the default [LoopUnrollHeuristics] with the thresholds that are set replaced.
+    pub fn heuristics(&self) -> LoopUnrollHeuristics {
+        let default = LoopUnrollHeuristics::default();
+        LoopUnrollHeuristics {
+            max_full_unroll_trip_count: self
+                .max_full_unroll_trip_count
+                .unwrap_or(default.max_full_unroll_trip_count),
+            partial_unroll_factor: self
+                .partial_unroll_factor
+                .unwrap_or(default.partial_unroll_factor),
+            max_unrolled_instructions: self
+                .max_unrolled_instructions
+                .unwrap_or(default.max_unrolled_instructions),
+        }
+    }
+}
+
 /// Configuration for the overall build and compilation process.
 #[derive(Clone)]
 pub struct BuildConfig {
@@ -185,8 +324,16 @@ pub struct BuildConfig {
     pub(crate) print_bytecode: bool,
     pub(crate) print_bytecode_spans: bool,
     pub(crate) print_ir: PrintIr,
+    pub(crate) verify_ir: VerifyIr,
     pub(crate) include_tests: bool,
     pub(crate) optimization_level: OptLevel,
+    pub(crate) inline_heuristics: InlineHeuristics,
+    pub(crate) loop_unroll_heuristics: LoopUnrollHeuristics,
+    pub(crate) ir_passes: IrPassOverrides,
+    pub(crate) monomorphization_report: Option<usize>,
+    pub(crate) monomorphization_budget: Option<usize>,
+    pub(crate) taint_analysis: bool,
+    pub(crate) analysis_passes: AnalysisPasses,
     pub time_phases: bool,
     pub profile: bool,
     pub metrics_outfile: Option<String>,
@@ -233,11 +380,19 @@ impl BuildConfig {
             print_bytecode: false,
             print_bytecode_spans: false,
             print_ir: PrintIr::default(),
+            verify_ir: VerifyIr::default(),
             include_tests: false,
             time_phases: false,
             profile: false,
             metrics_outfile: None,
             optimization_level: OptLevel::Opt0,
+            inline_heuristics: InlineHeuristics::default(),
+            loop_unroll_heuristics: LoopUnrollHeuristics::default(),
+            ir_passes: IrPassOverrides::default(),
+            monomorphization_report: None,
+            monomorphization_budget: None,
+            taint_analysis: false,
+            analysis_passes: AnalysisPasses::default(),
             lsp_mode: None,
         }
     }
@@ -275,6 +430,13 @@ impl BuildConfig {
         }
     }
 
+    /// Verify the IR while running the optimization passes according to `verify_ir`.
+    ///
+    /// Default: only the initial IR is verified.
+    pub fn with_verify_ir(self, verify_ir: VerifyIr) -> Self {
+        Self { verify_ir, ..self }
+    }
+
     pub fn with_time_phases(self, a: bool) -> Self {
         Self {
             time_phases: a,
@@ -300,6 +462,76 @@ impl BuildConfig {
         }
     }
 
+    /// The gas cost thresholds the function inlining pass weighs against code size.
+    ///
+    /// Default: [InlineHeuristics::default]
+    pub fn with_inline_heuristics(self, inline_heuristics: InlineHeuristics) -> Self {
+        Self {
+            inline_heuristics,
+            ..self
+        }
+    }
+
+    /// The trip count and size thresholds of the loop unrolling pass.
+    ///
+    /// Default: [LoopUnrollHeuristics::default]
+    pub fn with_loop_unroll_heuristics(self, loop_unroll_heuristics: LoopUnrollHeuristics) -> Self {
+        Self {
+            loop_unroll_heuristics,
+            ..self
+        }
+    }
+
+    /// Passes to run in addition to, or instead of, the passes of the optimization level.
+    ///
+    /// Unknown pass names are ignored.
+    pub fn with_ir_passes(self, ir_passes: IrPassOverrides) -> Self {
+        Self { ir_passes, ..self }
+    }
+
+    /// Print the `report` generic functions with the most monomorphized copies, and fail the build
+    /// if the total number of copies of generic functions exceeds the `budget`.
+    ///
+    /// Default: `None`, `None`
+    pub fn with_monomorphization_limits(
+        self,
+        report: Option<usize>,
+        budget: Option<usize>,
+    ) -> Self {
+        Self {
+            monomorphization_report: report,
+            monomorphization_budget: budget,
+            ..self
+        }
+    }
+
+    /// Whether to report the external inputs of contracts that reach storage writes or asset
+    /// operations without passing through a `#[sanitizer]` function.
+    ///
+    /// Default: `false`
+    pub fn with_taint_analysis(self, taint_analysis: bool) -> Self {
+        Self {
+            taint_analysis,
+            ..self
+        }
+    }
+
+    /// Registers an analysis pass to run over the typed program and the IR of the package.
+    ///
+    /// Passes run in the order they are registered. See [crate::analysis] for details.
+    pub fn with_analysis_pass(mut self, pass: Arc<dyn AnalysisPass>) -> Self {
+        self.analysis_passes.push(pass);
+        self
+    }
+
+    /// Registers all the `analysis_passes`, after the ones already registered.
+    pub fn with_analysis_passes(mut self, analysis_passes: AnalysisPasses) -> Self {
+        for pass in analysis_passes.iter() {
+            self.analysis_passes.push(pass.clone());
+        }
+        self
+    }
+
     /// Whether or not to include test functions in parsing, type-checking and codegen.
     ///
     /// This should be set to `true` by invocations like `forc test` or `forc check --tests`.
@@ -357,4 +589,14 @@ mod test {
             BuildTarget::default(),
         );
     }
+
+    #[test]
+    fn test_opt_level_serde_roundtrip() {
+        for opt_level in [OptLevel::Opt0, OptLevel::Opt1] {
+            let serialized = serde_json::to_string(&opt_level).unwrap();
+            assert_eq!(serialized, (opt_level as u8).to_string());
+            let deserialized: OptLevel = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(deserialized, opt_level);
+        }
+    }
 }
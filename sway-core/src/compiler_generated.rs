@@ -25,6 +25,18 @@ pub fn is_generated_destructured_struct_var_name(name: &str) -> bool {
     name.starts_with(DESTRUCTURED_STRUCT_VAR_NAME_PREFIX)
 }
 
+/// The prefix for the compiler generated names of arrays used in destructuring
+/// arrays with slice patterns in `let` statements.
+const DESTRUCTURED_ARRAY_VAR_NAME_PREFIX: &str = "__destructured_array_";
+
+pub(crate) fn generate_destructured_array_var_name(suffix: usize) -> String {
+    format!("{DESTRUCTURED_ARRAY_VAR_NAME_PREFIX}{suffix}")
+}
+
+pub fn is_generated_destructured_array_var_name(name: &str) -> bool {
+    name.starts_with(DESTRUCTURED_ARRAY_VAR_NAME_PREFIX)
+}
+
 /// The prefix for the compiler generated names of
 /// variables that store values matched in match expressions.
 const MATCHED_VALUE_VAR_NAME_PREFIX: &str = "__matched_value_";
@@ -58,6 +70,14 @@ pub fn is_generated_any_match_expression_var_name(name: &str) -> bool {
         || name.starts_with(MATCHED_OR_VARIANT_VARIABLES_VAR_NAME_PREFIX)
 }
 
+/// The prefix for the compiler generated names of
+/// variables that store the operands of the `?` operator.
+const TRY_OPERAND_VAR_NAME_PREFIX: &str = "__try_operand_";
+
+pub(crate) fn generate_try_operand_var_name(suffix: usize) -> String {
+    format!("{TRY_OPERAND_VAR_NAME_PREFIX}{suffix}")
+}
+
 /// A revert with this value signals that it was caused by an internal compiler error that
 /// occurred during the flattening of match arms that contain variables in OR match patterns.
 ///
@@ -69,3 +89,9 @@ pub(crate) const INVALID_MATCHED_OR_VARIABLE_INDEX_SIGNAL: u64 = 0xcccc_cccc_ccc
 ///
 /// The value is: 14757395258967588866
 pub(crate) const INVALID_DESUGARED_MATCHED_EXPRESSION_SIGNAL: u64 = 0xcccc_cccc_cccc_0002;
+
+/// A revert with this value signals that a range used to index an array or a slice,
+/// like in `&array[start..end]`, was out of bounds.
+///
+/// The value is: 18446744073709486087
+pub(crate) const RANGE_OUT_OF_BOUNDS_SIGNAL: u64 = 0xffff_ffff_ffff_0007;
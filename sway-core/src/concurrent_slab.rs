@@ -1,34 +1,50 @@
-use parking_lot::RwLock;
-use std::{fmt, sync::Arc};
+use parking_lot::{Mutex, RwLock};
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
 
-#[derive(Debug, Clone)]
-pub struct Inner<T> {
-    pub items: Vec<Option<Arc<T>>>,
-    pub free_list: Vec<usize>,
-}
+/// The number of shards the items of a [ConcurrentSlab] are spread over.
+const NUM_SHARDS: usize = 16;
 
-impl<T> Default for Inner<T> {
-    fn default() -> Self {
-        Self {
-            items: Default::default(),
-            free_list: Default::default(),
-        }
-    }
-}
+/// The number of consecutive indices stored in the same shard.
+///
+/// Indices are split into chunks of this size, and the chunks are assigned to
+/// the shards round-robin. This way neighbouring indices, which are usually
+/// accessed together, share a shard, while accesses to different regions of
+/// the slab do not contend on the same lock.
+const CHUNK_SIZE: usize = 256;
+
+type Shard<T> = RwLock<Vec<Option<Arc<T>>>>;
 
 #[derive(Debug)]
-pub(crate) struct ConcurrentSlab<T> {
-    pub inner: RwLock<Inner<T>>,
+pub struct ConcurrentSlab<T> {
+    shards: [Shard<T>; NUM_SHARDS],
+    /// One past the highest index ever handed out.
+    len: AtomicUsize,
+    free_list: Mutex<Vec<usize>>,
 }
 
+/// Clones a consistent snapshot of the slab.
+///
+/// The free list and all the shards are locked for the whole copy, so no
+/// item can be inserted, replaced or removed while it is taken. An insert
+/// that already reserved its index but has not stored the item yet is not
+/// part of the snapshot, and its index stays unused in the clone.
 impl<T> Clone for ConcurrentSlab<T>
 where
     T: Clone,
 {
     fn clone(&self) -> Self {
-        let inner = self.inner.read();
+        let free_list = self.free_list.lock();
+        let shards = self.shards.each_ref().map(|shard| shard.read());
         Self {
-            inner: RwLock::new(inner.clone()),
+            shards: shards.each_ref().map(|items| RwLock::new(items.to_vec())),
+            len: AtomicUsize::new(self.len.load(Ordering::SeqCst)),
+            free_list: Mutex::new(free_list.clone()),
         }
     }
 }
@@ -36,11 +52,28 @@ where
 impl<T> Default for ConcurrentSlab<T> {
     fn default() -> Self {
         Self {
-            inner: Default::default(),
+            shards: std::array::from_fn(|_| RwLock::default()),
+            len: AtomicUsize::new(0),
+            free_list: Mutex::default(),
         }
     }
 }
 
+/// Returns the shard and the position within the shard of the item at `index`.
+fn locate(index: usize) -> (usize, usize) {
+    let chunk = index / CHUNK_SIZE;
+    (
+        chunk % NUM_SHARDS,
+        (chunk / NUM_SHARDS) * CHUNK_SIZE + index % CHUNK_SIZE,
+    )
+}
+
+/// The inverse of [locate].
+fn index_of(shard: usize, position: usize) -> usize {
+    let chunk = (position / CHUNK_SIZE) * NUM_SHARDS + shard;
+    chunk * CHUNK_SIZE + position % CHUNK_SIZE
+}
+
 pub struct ListDisplay<I> {
     pub list: I,
 }
@@ -67,13 +100,33 @@ where
 {
     #[allow(dead_code)]
     pub fn len(&self) -> usize {
-        let inner = self.inner.read();
-        inner.items.len()
+        self.len.load(Ordering::SeqCst)
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
+    /// Returns all the items in the slab, ordered by their indices.
     pub fn values(&self) -> Vec<Arc<T>> {
-        let inner = self.inner.read();
-        inner.items.iter().filter_map(|x| x.clone()).collect()
+        let mut values = self
+            .shards
+            .iter()
+            .enumerate()
+            .flat_map(|(shard, items)| {
+                items
+                    .read()
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(position, item)| {
+                        item.clone().map(|item| (index_of(shard, position), item))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        values.sort_by_key(|(index, _)| *index);
+        values.into_iter().map(|(_, item)| item).collect()
     }
 
     pub fn insert(&self, value: T) -> usize {
@@ -81,60 +134,129 @@ where
     }
 
     pub fn insert_arc(&self, value: Arc<T>) -> usize {
-        let mut inner = self.inner.write();
-
-        if let Some(free) = inner.free_list.pop() {
-            assert!(inner.items[free].is_none());
-            inner.items[free] = Some(value);
-            free
-        } else {
-            inner.items.push(Some(value));
-            inner.items.len() - 1
+        let free = self.free_list.lock().pop();
+        let index = free.unwrap_or_else(|| self.len.fetch_add(1, Ordering::SeqCst));
+
+        let (shard, position) = locate(index);
+        let mut items = self.shards[shard].write();
+        if items.len() <= position {
+            items.resize_with(position + 1, || None);
         }
+        assert!(items[position].is_none());
+        items[position] = Some(value);
+        index
     }
 
     pub fn replace(&self, index: usize, new_value: T) -> Option<T> {
-        let mut inner = self.inner.write();
-        let item = inner.items.get_mut(index)?;
-        let old = item.replace(Arc::new(new_value))?;
-        Arc::into_inner(old)
+        self.replace_arc(index, Arc::new(new_value))
     }
 
     pub fn replace_arc(&self, index: usize, new_value: Arc<T>) -> Option<T> {
-        let mut inner = self.inner.write();
-        let item = inner.items.get_mut(index)?;
+        let (shard, position) = locate(index);
+        let mut items = self.shards[shard].write();
+        let item = items.get_mut(position)?;
         let old = item.replace(new_value)?;
         Arc::into_inner(old)
     }
 
     pub fn get(&self, index: usize) -> Arc<T> {
-        let inner = self.inner.read();
-        inner.items[index]
+        let (shard, position) = locate(index);
+        self.shards[shard].read()[position]
             .as_ref()
             .expect("invalid slab index for ConcurrentSlab::get")
             .clone()
     }
 
     pub fn retain(&self, predicate: impl Fn(&usize, &mut Arc<T>) -> bool) {
-        let mut inner = self.inner.write();
-
-        let Inner { items, free_list } = &mut *inner;
-        for (idx, item) in items.iter_mut().enumerate() {
-            if let Some(arc) = item {
-                if !predicate(&idx, arc) {
-                    free_list.push(idx);
-                    item.take();
+        let mut removed = vec![];
+        for (shard, items) in self.shards.iter().enumerate() {
+            let mut items = items.write();
+            for (position, item) in items.iter_mut().enumerate() {
+                if let Some(arc) = item {
+                    let index = index_of(shard, position);
+                    if !predicate(&index, arc) {
+                        removed.push(index);
+                        item.take();
+                    }
                 }
             }
         }
+        // Keep the order in which freed indices are reused independent of the sharding.
+        removed.sort_unstable();
+        self.free_list.lock().extend(removed);
+    }
+
+    /// Returns an estimate of the memory held by the slab, in bytes.
+    ///
+    /// Only the shallow size of the items is accounted for, not the heap memory they own.
+    pub fn memory_usage(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|items| {
+                let items = items.read();
+                let live_items = items.iter().flatten().count();
+                items.capacity() * std::mem::size_of::<Option<Arc<T>>>()
+                    + live_items * std::mem::size_of::<T>()
+            })
+            .sum::<usize>()
+            + self.free_list.lock().capacity() * std::mem::size_of::<usize>()
     }
 
     pub fn clear(&self) {
-        let mut inner = self.inner.write();
-        inner.items.clear();
-        inner.items.shrink_to(0);
+        let mut free_list = self.free_list.lock();
+        for items in &self.shards {
+            let mut items = items.write();
+            items.clear();
+            items.shrink_to(0);
+        }
+        self.len.store(0, Ordering::SeqCst);
+
+        free_list.clear();
+        free_list.shrink_to(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indices_round_trip_through_shards() {
+        for index in [
+            0,
+            1,
+            CHUNK_SIZE - 1,
+            CHUNK_SIZE,
+            CHUNK_SIZE * NUM_SHARDS + 3,
+            100_000,
+        ] {
+            let (shard, position) = locate(index);
+            assert_eq!(index_of(shard, position), index);
+        }
+    }
+
+    #[test]
+    fn inserts_are_sequential_and_freed_indices_are_reused() {
+        let slab = ConcurrentSlab::default();
+        let count = CHUNK_SIZE * NUM_SHARDS * 2 + 5;
+        for i in 0..count {
+            assert_eq!(slab.insert(i), i);
+        }
+        assert_eq!(slab.len(), count);
+        assert_eq!(*slab.get(CHUNK_SIZE * 3 + 1), CHUNK_SIZE * 3 + 1);
+        assert_eq!(
+            slab.values().iter().map(|v| **v).collect::<Vec<_>>(),
+            (0..count).collect::<Vec<_>>()
+        );
+
+        slab.retain(|index, _| index % 2 == 0);
+        assert_eq!(slab.values().len(), count / 2 + 1);
+        // Like in an unsharded slab, the highest freed index is reused first.
+        let last_odd = if count % 2 == 0 { count - 1 } else { count - 2 };
+        assert_eq!(slab.insert(0), last_odd);
 
-        inner.free_list.clear();
-        inner.free_list.shrink_to(0);
+        slab.clear();
+        assert_eq!(slab.len(), 0);
+        assert_eq!(slab.insert(7), 0);
     }
 }
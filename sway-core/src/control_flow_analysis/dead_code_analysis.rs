@@ -13,7 +13,11 @@ use crate::{
     type_system::TypeInfo,
     Engines, TypeArgument, TypeEngine, TypeId,
 };
-use petgraph::{prelude::NodeIndex, visit::Dfs};
+use petgraph::{
+    prelude::NodeIndex,
+    visit::{Bfs, Dfs, EdgeRef, Reversed},
+    Direction,
+};
 use std::collections::{BTreeSet, HashMap};
 use sway_ast::Intrinsic;
 use sway_error::{error::CompileError, type_error::TypeError};
@@ -354,6 +358,64 @@ impl<'cfg> ControlFlowGraph<'cfg> {
             .collect()
     }
 
+    /// Get the spans of the points the function `fn_decl` explicitly exits at, i.e., of its
+    /// `return`s and of its calls to `std::revert`, from the function's dead code graph.
+    pub(crate) fn explicit_exit_points(
+        engines: &'cfg Engines,
+        fn_decl: &ty::TyFunctionDecl,
+    ) -> Vec<Span> {
+        let mut graph = ControlFlowGraph::new(engines);
+        let entry_node = graph.add_node(format!("\"{}\" fn entry", fn_decl.name.as_str()).into());
+        if connect_typed_fn_decl(
+            engines,
+            fn_decl,
+            &mut graph,
+            entry_node,
+            fn_decl.span.clone(),
+            None,
+            &TreeType::Library,
+            NodeConnectionOptions::default(),
+        )
+        .is_err()
+        {
+            return vec![];
+        }
+        let Some(exit_point) = graph
+            .namespace
+            .function_namespace
+            .values()
+            .find(|entry| entry.entry_point == entry_node)
+            .map(|entry| entry.exit_point)
+        else {
+            return vec![];
+        };
+
+        // The `return` and `revert` edges start at organizational nodes, preceded by the program
+        // node of the exiting expression.
+        let reversed = Reversed(&graph.graph);
+        graph
+            .graph
+            .edges_directed(exit_point, Direction::Incoming)
+            .filter(|edge| matches!(edge.weight().label(), "return" | "revert"))
+            .filter_map(|edge| {
+                let mut bfs = Bfs::new(reversed, edge.source());
+                while let Some(node) = bfs.next(reversed) {
+                    if let ControlFlowGraphNode::ProgramNode { node, .. } = &graph.graph[node] {
+                        return Some(match &node.content {
+                            TyAstNodeContent::Expression(ty::TyExpression {
+                                expression: ty::TyExpressionVariant::ImplicitReturn(expr),
+                                ..
+                            }) => expr.span.clone(),
+                            TyAstNodeContent::Expression(expr) => expr.span.clone(),
+                            _ => node.span.clone(),
+                        });
+                    }
+                }
+                None
+            })
+            .collect()
+    }
+
     pub(crate) fn append_module_to_dead_code_graph<'eng: 'cfg>(
         engines: &'eng Engines,
         module_nodes: &[ty::TyAstNode],
@@ -1047,6 +1109,21 @@ fn connect_typed_fn_decl<'eng: 'cfg, 'cfg>(
             graph,
             fn_param_node,
         )?;
+
+        // the constants used in the default value are used by the function
+        if let Some(default_value) = &fn_param.default_value {
+            connect_expression(
+                engines,
+                &default_value.expression,
+                graph,
+                &[fn_param_node],
+                None,
+                "default parameter value",
+                tree_type,
+                default_value.span.clone(),
+                options,
+            )?;
+        }
     }
 
     let fn_exit_node = graph.add_node(format!("\"{}\" fn exit", fn_decl.name.as_str()).into());
@@ -1985,14 +2062,56 @@ fn connect_expression<'eng: 'cfg, 'cfg>(
             expression_span,
             options,
         ),
-        Break => {
+        LabeledBlock { block, .. } => {
+            // a labeled block can be exited early by a `break`,
+            // so the code after it is reachable even if the block itself diverges.
+            let block_entry = graph.add_node("labeled block entry".into());
+            for leaf in leaves {
+                graph.add_edge(*leaf, block_entry, "".into());
+            }
+            let block_exit = graph.add_node("labeled block exit".into());
+            graph.add_edge(
+                block_entry,
+                block_exit,
+                "labeled block is exited early".into(),
+            );
+
+            let l_leaves = connect_code_block(
+                engines,
+                block,
+                graph,
+                &[block_entry],
+                exit_node,
+                tree_type,
+                options,
+            )?;
+            for leaf in l_leaves {
+                graph.add_edge(leaf, block_exit, "".into());
+            }
+            Ok(vec![block_exit])
+        }
+        Break { value, .. } => {
+            let leaves = match value {
+                Some(value) => connect_expression(
+                    engines,
+                    &value.expression,
+                    graph,
+                    leaves,
+                    exit_node,
+                    "break value",
+                    tree_type,
+                    value.span.clone(),
+                    options,
+                )?,
+                None => leaves.to_vec(),
+            };
             let break_node = graph.add_node("break".to_string().into());
             for leaf in leaves {
-                graph.add_edge(*leaf, break_node, "".into());
+                graph.add_edge(leaf, break_node, "".into());
             }
             Ok(vec![])
         }
-        Continue => {
+        Continue { .. } => {
             let continue_node = graph.add_node("continue".to_string().into());
             for leaf in leaves {
                 graph.add_edge(*leaf, continue_node, "".into());
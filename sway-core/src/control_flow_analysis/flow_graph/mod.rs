@@ -60,6 +60,12 @@ impl std::fmt::Debug for ControlFlowGraphEdge {
     }
 }
 
+impl ControlFlowGraphEdge {
+    pub(crate) fn label(&self) -> &str {
+        &self.0
+    }
+}
+
 impl std::convert::From<&str> for ControlFlowGraphEdge {
     fn from(o: &str) -> Self {
         ControlFlowGraphEdge(o.to_string())
@@ -20,6 +20,12 @@
 //! need to verify that all paths do indeed contain a return statement.
 //!
 //!
+//! # Synopsis of Taint Analysis
+//! The optional taint analysis does not use the graph. It follows the external inputs of a
+//! contract through the typed AST of its ABI methods and the functions they call, and reports the
+//! inputs that reach storage writes or asset operations without being validated. See
+//! [taint_analysis] for details.
+//!
 //! # # Terms
 //! # # # Node
 //! A node is any [crate::semantic_analysis::TyAstNode], with some
@@ -39,5 +45,6 @@
 mod analyze_return_paths;
 mod dead_code_analysis;
 mod flow_graph;
+pub(crate) mod taint_analysis;
 
 pub use flow_graph::*;
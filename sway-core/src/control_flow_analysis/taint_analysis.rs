@@ -0,0 +1,502 @@
+//! Taint analysis of the external inputs of contracts.
+//!
+//! The arguments of the ABI methods, the data read from the transaction by the `std` functions
+//! listed in [EXTERNAL_DATA_FUNCTIONS], and the values returned by other contracts are controlled
+//! by the caller, and are considered tainted. The analysis follows tainted values through
+//! variables, expressions and function calls, and reports every flow of a tainted value into a
+//! storage write or an asset operation. Passing a value through a function marked as
+//! `#[sanitizer]` removes its taint.
+//!
+//! Called functions are analyzed once, into a [FnSummary] that records which of their parameters
+//! reach a sink or the returned value. The analysis is flow-insensitive within loops and does not
+//! track taint through references or implicit flows, so it is meant as an aid for auditors rather
+//! than a guarantee.
+
+use crate::{
+    decl_engine::DeclId,
+    language::{
+        ty::{self, TyFunctionDecl},
+        AsmOp,
+    },
+    transform::AttributeKind,
+    Engines,
+};
+use std::{collections::HashMap, fmt};
+use sway_error::warning::{CompileWarning, Warning};
+use sway_types::{
+    constants::{CONTRACT_CALL_ASSET_ID_PARAMETER_NAME, CONTRACT_CALL_COINS_PARAMETER_NAME, STD},
+    Ident, Span, Spanned,
+};
+
+/// The `std` functions returning data supplied by the sender of the transaction.
+const EXTERNAL_DATA_FUNCTIONS: &[&str] = &[
+    "input_message_data",
+    "input_predicate_data",
+    "tx_script_data",
+    "tx_witness_data",
+];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Sink {
+    StorageWrite,
+    AssetTransfer,
+    AssetMintOrBurn,
+}
+
+impl fmt::Display for Sink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Sink::StorageWrite => write!(f, "a storage write"),
+            Sink::AssetTransfer => write!(f, "an asset transfer"),
+            Sink::AssetMintOrBurn => write!(f, "an asset mint or burn"),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+enum Source {
+    /// The parameter at the index of the analyzed function.
+    Param(usize),
+    /// Data read from the transaction, or returned by another contract.
+    ExternalData { description: String, span: Span },
+}
+
+/// A tainted value, along with the variables it was assigned to on its way.
+#[derive(Clone)]
+struct Flow {
+    source: Source,
+    path: Vec<Span>,
+}
+
+/// The flows of a value, empty if the value is not tainted.
+#[derive(Clone, Default)]
+struct Taint(Vec<Flow>);
+
+impl Taint {
+    fn extend(&mut self, other: Taint) {
+        for flow in other.0 {
+            if !self.0.iter().any(|known| known.source == flow.source) {
+                self.0.push(flow);
+            }
+        }
+    }
+
+    fn through(mut self, span: Span) -> Taint {
+        for flow in &mut self.0 {
+            flow.path.push(span.clone());
+        }
+        self
+    }
+}
+
+/// How the parameters of a function flow through it.
+#[derive(Clone)]
+struct FnSummary {
+    /// The sink each parameter reaches, if any.
+    param_sinks: Vec<Option<Sink>>,
+    /// Whether each parameter flows into the returned value.
+    param_returns: Vec<bool>,
+    /// Whether the returned value contains external data read within the function.
+    returns_external_data: bool,
+}
+
+/// A tainted value reaching a sink.
+struct Finding {
+    flow: Flow,
+    sink: Sink,
+    span: Span,
+}
+
+pub(crate) fn analyze_program(engines: &Engines, prog: &ty::TyProgram) -> Vec<CompileWarning> {
+    let ty::TyProgramKind::Contract { .. } = &prog.kind else {
+        return vec![];
+    };
+
+    let mut analysis = TaintAnalysis {
+        engines,
+        summaries: HashMap::new(),
+    };
+    let mut warnings = vec![];
+    for fn_decl in contract_abi_methods(engines, &prog.root.all_nodes) {
+        let mut ctx = FnContext::new(&fn_decl);
+        analysis.analyze_code_block(&mut ctx, &fn_decl.body);
+        for finding in ctx.findings {
+            let (source, source_span) = match finding.flow.source {
+                Source::Param(index) => {
+                    let name = &fn_decl.parameters[index].name;
+                    (format!("Argument \"{name}\""), name.span())
+                }
+                Source::ExternalData { description, span } => (description, span),
+            };
+            warnings.push(CompileWarning {
+                span: finding.span,
+                warning_content: Warning::UncheckedExternalInput {
+                    source,
+                    source_span,
+                    path: finding.flow.path,
+                    sink: finding.sink.to_string(),
+                    fn_name: fn_decl.name.clone(),
+                },
+            });
+        }
+    }
+    warnings
+}
+
+// methods of the `impl <ABI> for Contract` declarations
+fn contract_abi_methods(
+    engines: &Engines,
+    ast_nodes: &[ty::TyAstNode],
+) -> Vec<std::sync::Arc<TyFunctionDecl>> {
+    let decl_engine = engines.de();
+    ast_nodes
+        .iter()
+        .flat_map(|ast_node| match &ast_node.content {
+            ty::TyAstNodeContent::Declaration(ty::TyDecl::ImplSelfOrTrait(
+                ty::ImplSelfOrTrait { decl_id, .. },
+            )) => {
+                let impl_trait = decl_engine.get_impl_self_or_trait(decl_id);
+                if !impl_trait.is_impl_contract(engines.te()) {
+                    return vec![];
+                }
+                impl_trait
+                    .items
+                    .iter()
+                    .filter_map(|item| match item {
+                        ty::TyImplItem::Fn(fn_decl) => Some(decl_engine.get_function(fn_decl.id())),
+                        ty::TyImplItem::Constant(_) | ty::TyImplItem::Type(_) => None,
+                    })
+                    .collect()
+            }
+            _ => vec![],
+        })
+        .collect()
+}
+
+/// The state of the analysis of a single function body.
+struct FnContext {
+    variables: HashMap<Ident, Taint>,
+    returned: Taint,
+    /// The values the labeled blocks being analyzed are exited with by `break`.
+    broken: HashMap<Ident, Taint>,
+    findings: Vec<Finding>,
+}
+
+impl FnContext {
+    fn new(fn_decl: &TyFunctionDecl) -> Self {
+        let variables = fn_decl
+            .parameters
+            .iter()
+            .enumerate()
+            .map(|(index, param)| {
+                let flow = Flow {
+                    source: Source::Param(index),
+                    path: vec![],
+                };
+                (param.name.clone(), Taint(vec![flow]))
+            })
+            .collect();
+        Self {
+            variables,
+            returned: Taint::default(),
+            broken: HashMap::new(),
+            findings: vec![],
+        }
+    }
+
+    fn reach_sink(&mut self, taint: Taint, sink: Sink, span: &Span) {
+        for flow in taint.0 {
+            self.findings.push(Finding {
+                flow,
+                sink,
+                span: span.clone(),
+            });
+        }
+    }
+}
+
+struct TaintAnalysis<'a> {
+    engines: &'a Engines,
+    summaries: HashMap<DeclId<TyFunctionDecl>, FnSummary>,
+}
+
+impl TaintAnalysis<'_> {
+    fn summary(&mut self, fn_id: &DeclId<TyFunctionDecl>) -> FnSummary {
+        if let Some(summary) = self.summaries.get(fn_id) {
+            return summary.clone();
+        }
+        let fn_decl = self.engines.de().get_function(fn_id);
+        let params = fn_decl.parameters.len();
+        let mut summary = FnSummary {
+            param_sinks: vec![None; params],
+            param_returns: vec![false; params],
+            returns_external_data: false,
+        };
+        // Recursive calls, which are rejected later on, see a summary without any flows.
+        self.summaries.insert(*fn_id, summary.clone());
+
+        let mut ctx = FnContext::new(&fn_decl);
+        let mut returned = self.analyze_code_block(&mut ctx, &fn_decl.body);
+        returned.extend(ctx.returned);
+        for finding in ctx.findings {
+            if let Source::Param(index) = finding.flow.source {
+                summary.param_sinks[index].get_or_insert(finding.sink);
+            }
+        }
+        for flow in returned.0 {
+            match flow.source {
+                Source::Param(index) => summary.param_returns[index] = true,
+                Source::ExternalData { .. } => summary.returns_external_data = true,
+            }
+        }
+        self.summaries.insert(*fn_id, summary.clone());
+        summary
+    }
+
+    fn analyze_code_block(&mut self, ctx: &mut FnContext, codeblock: &ty::TyCodeBlock) -> Taint {
+        let mut taint = Taint::default();
+        for ast_node in &codeblock.contents {
+            taint = match &ast_node.content {
+                ty::TyAstNodeContent::Declaration(ty::TyDecl::VariableDecl(var_decl)) => {
+                    let value = self
+                        .analyze_expression(ctx, &var_decl.body)
+                        .through(var_decl.name.span());
+                    ctx.variables.insert(var_decl.name.clone(), value);
+                    Taint::default()
+                }
+                ty::TyAstNodeContent::Expression(expr) => self.analyze_expression(ctx, expr),
+                ty::TyAstNodeContent::Declaration(_)
+                | ty::TyAstNodeContent::SideEffect(_)
+                | ty::TyAstNodeContent::Error(_, _) => Taint::default(),
+            };
+        }
+        taint
+    }
+
+    fn analyze_expressions<'e>(
+        &mut self,
+        ctx: &mut FnContext,
+        exprs: impl Iterator<Item = &'e ty::TyExpression>,
+    ) -> Taint {
+        let mut taint = Taint::default();
+        for expr in exprs {
+            taint.extend(self.analyze_expression(ctx, expr));
+        }
+        taint
+    }
+
+    fn analyze_expression(&mut self, ctx: &mut FnContext, expr: &ty::TyExpression) -> Taint {
+        use ty::TyExpressionVariant::*;
+        match &expr.expression {
+            Literal(_)
+            | ConstantExpression { .. }
+            | ConfigurableExpression { .. }
+            | FunctionParameter
+            | StorageAccess(_)
+            | Break { value: None, .. }
+            | Continue { .. }
+            | AbiName(_) => Taint::default(),
+            Break {
+                label,
+                value: Some(value),
+            } => {
+                let taint = self.analyze_expression(ctx, value);
+                if let Some(label) = label {
+                    ctx.broken.entry(label.clone()).or_default().extend(taint);
+                }
+                Taint::default()
+            }
+            VariableExpression { name, .. } => ctx.variables.get(name).cloned().unwrap_or_default(),
+            Reassignment(reassignment) => {
+                let value = self.analyze_expression(ctx, &reassignment.rhs);
+                if let ty::TyReassignmentTarget::ElementAccess { base_name, .. } = &reassignment.lhs
+                {
+                    let value = value.through(base_name.span());
+                    ctx.variables
+                        .entry(base_name.clone())
+                        .or_default()
+                        .extend(value);
+                }
+                Taint::default()
+            }
+            CodeBlock(codeblock) => self.analyze_code_block(ctx, codeblock),
+            LabeledBlock { label, block } => {
+                let mut taint = self.analyze_code_block(ctx, block);
+                if let Some(broken) = ctx.broken.remove(label) {
+                    taint.extend(broken);
+                }
+                taint
+            }
+            LazyOperator { lhs, rhs, .. }
+            | ArrayIndex {
+                prefix: lhs,
+                index: rhs,
+            } => self.analyze_expressions(ctx, [&**lhs, &**rhs].into_iter()),
+            FunctionApplication {
+                arguments,
+                fn_ref,
+                selector,
+                contract_call_params,
+                ..
+            } => {
+                let args = arguments
+                    .iter()
+                    .map(|(_, arg)| self.analyze_expression(ctx, arg))
+                    .collect::<Vec<_>>();
+                let fn_decl = self.engines.de().get_function(fn_ref);
+                if selector.is_some() {
+                    // Coins forwarded to another contract.
+                    for (name, param) in contract_call_params {
+                        let param_taint = self.analyze_expression(ctx, param);
+                        if name == CONTRACT_CALL_COINS_PARAMETER_NAME
+                            || name == CONTRACT_CALL_ASSET_ID_PARAMETER_NAME
+                        {
+                            ctx.reach_sink(param_taint, Sink::AssetTransfer, &param.span);
+                        }
+                    }
+                    return external_data(
+                        format!("Value returned by contract call \"{}\"", fn_decl.name),
+                        &expr.span,
+                    );
+                }
+                if fn_decl.attributes.contains_key(&AttributeKind::Sanitizer) {
+                    return Taint::default();
+                }
+                if is_external_data_function(&fn_decl) {
+                    return external_data(
+                        format!("Transaction data read by \"{}\"", fn_decl.name),
+                        &expr.span,
+                    );
+                }
+
+                let summary = self.summary(fn_ref.id());
+                let mut taint = Taint::default();
+                for (index, arg) in args.into_iter().enumerate() {
+                    if let Some(sink) = summary.param_sinks.get(index).copied().flatten() {
+                        ctx.reach_sink(arg.clone(), sink, &expr.span);
+                    }
+                    if summary.param_returns.get(index).copied().unwrap_or(false) {
+                        taint.extend(arg);
+                    }
+                }
+                if summary.returns_external_data {
+                    taint.extend(external_data(
+                        format!("External data returned by \"{}\"", fn_decl.name),
+                        &expr.span,
+                    ));
+                }
+                taint
+            }
+            IntrinsicFunction(intrinsic) => {
+                let taint = self.analyze_expressions(ctx, intrinsic.arguments.iter());
+                if let Some(sink) = sink_of_intrinsic(&intrinsic.kind) {
+                    ctx.reach_sink(taint.clone(), sink, &expr.span);
+                }
+                taint
+            }
+            Tuple { fields: exprs }
+            | Array {
+                elem_type: _,
+                contents: exprs,
+            } => self.analyze_expressions(ctx, exprs.iter()),
+            StructExpression { fields, .. } => {
+                self.analyze_expressions(ctx, fields.iter().map(|field| &field.value))
+            }
+            StructFieldAccess { prefix: expr, .. }
+            | TupleElemAccess { prefix: expr, .. }
+            | EnumTag { exp: expr }
+            | UnsafeDowncast { exp: expr, .. }
+            | AbiCast { address: expr, .. }
+            | ImplicitReturn(expr)
+            | Ref(expr)
+            | Deref(expr) => self.analyze_expression(ctx, expr),
+            Return(expr) => {
+                let taint = self.analyze_expression(ctx, expr);
+                ctx.returned.extend(taint);
+                Taint::default()
+            }
+            EnumInstantiation { contents, .. } => match contents {
+                Some(expr) => self.analyze_expression(ctx, expr),
+                None => Taint::default(),
+            },
+            MatchExp { desugared, .. } => self.analyze_expression(ctx, desugared),
+            IfExp {
+                condition,
+                then,
+                r#else,
+            } => {
+                self.analyze_expression(ctx, condition);
+                let mut taint = self.analyze_expression(ctx, then);
+                if let Some(r#else) = r#else {
+                    taint.extend(self.analyze_expression(ctx, r#else));
+                }
+                taint
+            }
+            WhileLoop {
+                condition, body, ..
+            } => {
+                // Analyzing the loop twice propagates the values assigned at the end of an
+                // iteration to the uses at the start of the next one.
+                for _ in 0..2 {
+                    self.analyze_expression(ctx, condition);
+                    self.analyze_code_block(ctx, body);
+                }
+                Taint::default()
+            }
+            ForLoop { desugared } => self.analyze_expression(ctx, desugared),
+            AsmExpression {
+                registers, body, ..
+            } => {
+                let taint = self.analyze_expressions(
+                    ctx,
+                    registers
+                        .iter()
+                        .filter_map(|register| register.initializer.as_ref()),
+                );
+                for op in body {
+                    if let Some(sink) = sink_of_asm_op(op) {
+                        ctx.reach_sink(taint.clone(), sink, &op.span);
+                    }
+                }
+                taint
+            }
+        }
+    }
+}
+
+fn external_data(description: String, span: &Span) -> Taint {
+    Taint(vec![Flow {
+        source: Source::ExternalData {
+            description,
+            span: span.clone(),
+        },
+        path: vec![],
+    }])
+}
+
+fn is_external_data_function(fn_decl: &TyFunctionDecl) -> bool {
+    fn_decl
+        .call_path
+        .prefixes
+        .first()
+        .is_some_and(|prefix| prefix.as_str() == STD)
+        && EXTERNAL_DATA_FUNCTIONS.contains(&fn_decl.name.as_str())
+}
+
+fn sink_of_intrinsic(intrinsic: &sway_ast::Intrinsic) -> Option<Sink> {
+    use sway_ast::Intrinsic::*;
+    match intrinsic {
+        StateClear | StateStoreWord | StateStoreQuad => Some(Sink::StorageWrite),
+        Smo => Some(Sink::AssetTransfer),
+        _ => None,
+    }
+}
+
+fn sink_of_asm_op(op: &AsmOp) -> Option<Sink> {
+    match op.op_name.as_str().to_lowercase().as_str() {
+        "scwq" | "sww" | "swwq" => Some(Sink::StorageWrite),
+        "tr" | "tro" | "smo" => Some(Sink::AssetTransfer),
+        "mint" | "burn" => Some(Sink::AssetMintOrBurn),
+        _ => None,
+    }
+}
@@ -506,6 +506,32 @@ decl_engine_clear_module!(
     type_alias_slab, ty::TyTypeAliasDecl;
 );
 
+macro_rules! decl_engine_memory_usage {
+    ($($slab:ident, $decl:ty);* $(;)?) => {
+        impl DeclEngine {
+            /// Returns an estimate of the memory held by the declaration slabs, in bytes.
+            pub fn memory_usage(&self) -> usize {
+                0 $(+ self.$slab.memory_usage())*
+            }
+        }
+    };
+}
+
+decl_engine_memory_usage!(
+    function_slab, ty::TyFunctionDecl;
+    trait_slab, ty::TyTraitDecl;
+    trait_fn_slab, ty::TyTraitFn;
+    trait_type_slab, ty::TyTraitType;
+    impl_self_or_trait_slab, ty::TyImplTrait;
+    struct_slab, ty::TyStructDecl;
+    storage_slab, ty::TyStorageDecl;
+    abi_slab, ty::TyAbiDecl;
+    constant_slab, ty::TyConstantDecl;
+    configurable_slab, ty::TyConfigurableDecl;
+    enum_slab, ty::TyEnumDecl;
+    type_alias_slab, ty::TyTypeAliasDecl;
+);
+
 impl DeclEngine {
     /// Given a [DeclRef] `index`, finds all the parents of `index` and all the
     /// recursive parents of those parents, and so on. Does not perform
@@ -611,6 +637,17 @@ impl DeclEngine {
         vec
     }
 
+    /// Returns all the [ty::TyAbiDecl]s whose name is the same as `abi_name`.
+    ///
+    /// The method does a linear search over all the declared ABIs.
+    pub fn get_abis_by_name(&self, abi_name: &Ident) -> Vec<Arc<ty::TyAbiDecl>> {
+        self.abi_slab
+            .values()
+            .into_iter()
+            .filter(|abi_decl| abi_decl.name == *abi_name)
+            .collect()
+    }
+
     /// Friendly helper method for calling the `get` method from the
     /// implementation of [DeclEngineGet] for [DeclEngine]
     ///
@@ -169,6 +169,34 @@ decl_engine_clear!(
     type_alias_slab, TypeAliasDeclaration;
 );
 
+macro_rules! decl_engine_memory_usage {
+    ($($slab:ident, $decl:ty);* $(;)?) => {
+        impl ParsedDeclEngine {
+            /// Returns an estimate of the memory held by the declaration slabs, in bytes.
+            pub fn memory_usage(&self) -> usize {
+                0 $(+ self.$slab.memory_usage())*
+            }
+        }
+    };
+}
+
+decl_engine_memory_usage!(
+    variable_slab, VariableDeclaration;
+    function_slab, FunctionDeclaration;
+    trait_slab, TraitDeclaration;
+    trait_fn_slab, TraitFn;
+    trait_type_slab, TraitTypeDeclaration;
+    impl_self_or_trait_slab, ImplSelfOrTrait;
+    struct_slab, StructDeclaration;
+    storage_slab, StorageDeclaration;
+    abi_slab, AbiDeclaration;
+    constant_slab, ConstantDeclaration;
+    configurable_slab, ConfigurableDeclaration;
+    enum_slab, EnumDeclaration;
+    enum_variant_slab, EnumVariant;
+    type_alias_slab, TypeAliasDeclaration;
+);
+
 macro_rules! decl_engine_clear_program {
     ($(($slab:ident, $getter:expr)),* $(,)?) => {
         impl ParsedDeclEngine {
@@ -5,6 +5,7 @@ use crate::{
 };
 use std::{
     cmp::Ordering,
+    collections::HashSet,
     fmt,
     hash::{BuildHasher, Hash, Hasher},
     sync::Arc,
@@ -59,6 +60,52 @@ impl Engines {
         self.query_engine.clear_module(source_id);
     }
 
+    /// Returns an estimate of the memory held by each of the engines.
+    pub fn memory_report(&self) -> EnginesMemoryReport {
+        EnginesMemoryReport {
+            type_engine: self.type_engine.memory_usage(),
+            decl_engine: self.decl_engine.memory_usage(),
+            parsed_decl_engine: self.parsed_decl_engine.memory_usage(),
+            query_engine: self.query_engine.memory_usage(),
+            source_engine: self.source_engine.memory_usage(),
+        }
+    }
+
+    /// Removes all data of the programs which are not in `roots` from the engines, including
+    /// their cached modules. Returns the ids of the removed programs.
+    ///
+    /// Long-running hosts like the language server can call this once a compilation
+    /// completes, passing the programs they still need, to bound their memory usage
+    /// without recreating the engines.
+    pub fn collect_garbage(
+        &mut self,
+        roots: &[sway_types::ProgramId],
+    ) -> Vec<sway_types::ProgramId> {
+        let unreachable = self
+            .source_engine
+            .all_program_ids()
+            .into_iter()
+            .filter(|program_id| !roots.contains(program_id))
+            .collect::<Vec<_>>();
+
+        let mut paths = HashSet::new();
+        for program_id in &unreachable {
+            self.clear_program(program_id);
+            if let Some(source_ids) = self
+                .source_engine
+                .get_source_ids_from_program_id(*program_id)
+            {
+                paths.extend(source_ids.iter().map(|id| self.source_engine.get_path(id)));
+            }
+        }
+        self.query_engine.clear_paths(&paths);
+        for program_id in &unreachable {
+            self.source_engine.clear_program(program_id);
+        }
+
+        unreachable
+    }
+
     /// Helps out some `thing: T` by adding `self` as context.
     pub fn help_out<T>(&self, thing: T) -> WithEngines<'_, T> {
         WithEngines {
@@ -68,6 +115,40 @@ impl Engines {
     }
 }
 
+/// An estimate of the memory held by each of the [Engines], in bytes.
+///
+/// Only the shallow size of the stored items is accounted for, so the actual memory usage is
+/// higher, but the estimates grow and shrink along with it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EnginesMemoryReport {
+    pub type_engine: usize,
+    pub decl_engine: usize,
+    pub parsed_decl_engine: usize,
+    pub query_engine: usize,
+    pub source_engine: usize,
+}
+
+impl EnginesMemoryReport {
+    pub fn total(&self) -> usize {
+        self.type_engine
+            + self.decl_engine
+            + self.parsed_decl_engine
+            + self.query_engine
+            + self.source_engine
+    }
+}
+
+impl fmt::Display for EnginesMemoryReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "type engine:        {} bytes", self.type_engine)?;
+        writeln!(f, "decl engine:        {} bytes", self.decl_engine)?;
+        writeln!(f, "parsed decl engine: {} bytes", self.parsed_decl_engine)?;
+        writeln!(f, "query engine:       {} bytes", self.query_engine)?;
+        writeln!(f, "source engine:      {} bytes", self.source_engine)?;
+        write!(f, "total:              {} bytes", self.total())
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct WithEngines<'a, T> {
     pub thing: T,
@@ -413,3 +494,36 @@ where
 pub trait SpannedWithEngines {
     fn span(&self, engines: &Engines) -> Span;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use sway_types::ProgramId;
+
+    #[test]
+    fn collect_garbage_removes_unreachable_programs() {
+        let mut engines = Engines::default();
+        let kept = ProgramId::new(1);
+        let collected = ProgramId::new(2);
+        let kept_path = PathBuf::from("/kept/src/main.sw");
+        let collected_path = PathBuf::from("/collected/src/lib.sw");
+        let kept_source_id = engines.se().get_source_id_with_program_id(&kept_path, kept);
+        engines
+            .se()
+            .get_source_id_with_program_id(&collected_path, collected);
+        assert_eq!(engines.se().all_program_ids(), vec![kept, collected]);
+
+        assert_eq!(engines.collect_garbage(&[kept]), vec![collected]);
+        assert_eq!(engines.se().all_program_ids(), vec![kept]);
+        assert!(engines
+            .se()
+            .get_source_ids_from_program_id(collected)
+            .is_none());
+        assert!(!engines.se().all_files().contains(&collected_path));
+        assert_eq!(engines.se().get_path(&kept_source_id), kept_path);
+
+        // Collecting again has nothing left to remove.
+        assert!(engines.collect_garbage(&[kept]).is_empty());
+    }
+}
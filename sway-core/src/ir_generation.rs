@@ -2,7 +2,9 @@ pub(crate) mod compile;
 pub mod const_eval;
 mod convert;
 mod function;
+pub mod layout;
 mod lexical_map;
+mod monomorphization;
 mod purity;
 pub mod storage;
 mod types;
@@ -17,6 +19,7 @@ use sway_features::ExperimentalFeatures;
 use sway_ir::{Context, Function, Kind, Module};
 use sway_types::{span::Span, Ident};
 
+pub(crate) use monomorphization::check_monomorphization;
 pub(crate) use purity::{check_function_purity, PurityEnv};
 
 use crate::{
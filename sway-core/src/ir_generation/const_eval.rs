@@ -7,7 +7,7 @@ use crate::{
     engine_threading::*,
     language::{
         ty::{self, TyConstantDecl, TyIntrinsicFunctionKind},
-        CallPath, Literal,
+        AsmOp, AsmRegister, CallPath, Literal,
     },
     metadata::MetadataManager,
     semantic_analysis::*,
@@ -23,6 +23,7 @@ use super::{
 use sway_ast::Intrinsic;
 use sway_error::error::CompileError;
 use sway_ir::{
+    const_s256,
     constant::{Constant, ConstantValue},
     context::Context,
     module::Module,
@@ -708,14 +709,27 @@ fn const_eval_typed_expr(
                 }
             }
         }
+        ty::TyExpressionVariant::AsmExpression {
+            registers,
+            body,
+            returns,
+            ..
+        } if matches!(*lookup.engines.te().get(expr.return_type), TypeInfo::B256) => Some(
+            const_eval_s256_asm(lookup, known_consts, registers, body, returns).ok_or_else(
+                || ConstEvalError::CannotBeEvaluatedToConst {
+                    span: expr.span.clone(),
+                },
+            )?,
+        ),
         ty::TyExpressionVariant::FunctionParameter
         | ty::TyExpressionVariant::AsmExpression { .. }
         | ty::TyExpressionVariant::LazyOperator { .. }
         | ty::TyExpressionVariant::AbiCast { .. }
         | ty::TyExpressionVariant::StorageAccess(_)
         | ty::TyExpressionVariant::AbiName(_)
-        | ty::TyExpressionVariant::Break
-        | ty::TyExpressionVariant::Continue
+        | ty::TyExpressionVariant::LabeledBlock { .. }
+        | ty::TyExpressionVariant::Break { .. }
+        | ty::TyExpressionVariant::Continue { .. }
         | ty::TyExpressionVariant::ForLoop { .. } => {
             return Err(ConstEvalError::CannotBeEvaluatedToConst {
                 span: expr.span.clone(),
@@ -724,6 +738,40 @@ fn const_eval_typed_expr(
     })
 }
 
+/// Evaluate an asm block consisting of a single `s256 hash ptr len` instruction and returning
+/// `hash`, as used by `std::hash::sha256_b256` to derive storage keys from other keys.
+fn const_eval_s256_asm(
+    lookup: &mut LookupEnv,
+    known_consts: &mut MappedStack<Ident, Constant>,
+    registers: &[ty::TyAsmRegisterDeclaration],
+    body: &[AsmOp],
+    returns: &Option<(AsmRegister, Span)>,
+) -> Option<Constant> {
+    let [op] = body else {
+        return None;
+    };
+    let [hash, ptr, len] = op.op_args.as_slice() else {
+        return None;
+    };
+    if op.op_name.as_str() != "s256"
+        || op.immediate.is_some()
+        || returns.as_ref().map(|(register, _)| register.name.as_str()) != Some(hash.as_str())
+    {
+        return None;
+    }
+
+    let mut initializer = |name: &Ident| {
+        let register = registers.iter().find(|register| register.name == *name)?;
+        const_eval_typed_expr(lookup, known_consts, register.initializer.as_ref()?)
+            .ok()
+            .flatten()
+    };
+    let data = initializer(ptr)?;
+    let len = initializer(len)?.as_uint()?;
+    let digest = const_s256(lookup.context, &data, len)?;
+    Some(Constant::new_b256(lookup.context, digest))
+}
+
 // the (constant) value of a codeblock is essentially it's last expression if there is one
 // or if it makes sense as the last expression, e.g. a dangling let-expression in a codeblock
 // would be an evaluation error
@@ -1496,6 +1544,37 @@ fn const_eval_intrinsic(
             let c = transmute_bytes(lookup.context, &mut cursor, &dst_ir_type)?;
             Ok(Some(c))
         }
+        Intrinsic::Cast => {
+            assert!(args.len() == 1);
+
+            let dst_type = &intrinsic.type_arguments[0];
+            let dst_ir_type = convert_resolved_type_id(
+                lookup.engines.te(),
+                lookup.engines.de(),
+                lookup.context,
+                dst_type.type_id,
+                &dst_type.span,
+            )
+            .map_err(|_| ConstEvalError::CompileError)?;
+
+            match (&args[0].value, dst_ir_type.get_uint_width(lookup.context)) {
+                (ConstantValue::Uint(n), Some(width)) => {
+                    let n = match width {
+                        8 => *n as u8 as u64,
+                        16 => *n as u16 as u64,
+                        32 => *n as u32 as u64,
+                        _ => *n,
+                    };
+                    Ok(Some(Constant {
+                        ty: dst_ir_type,
+                        value: ConstantValue::Uint(n),
+                    }))
+                }
+                _ => Err(ConstEvalError::CannotBeEvaluatedToConst {
+                    span: intrinsic.span.clone(),
+                }),
+            }
+        }
     }
 }
 
@@ -1605,6 +1684,7 @@ mod tests {
         // Expressions that can be converted to constant
         assert_is_constant(true, "", "1");
         assert_is_constant(true, "", "true");
+        assert_is_constant(true, "", "256 as u8");
         assert_is_constant(true, "fn one() -> u64 { 1 }", "one()");
         assert_is_constant(true, "fn id(x: u64) -> u64 { x }", "id(1)");
         assert_is_constant(true, "enum Color { Blue: () }", "Color::Blue");
@@ -1657,8 +1737,16 @@ mod tests {
             "__not(0x0000000000000000000000000000000000000000000000000000000000000001u256)",
         );
 
+        // sha256 of constant data
+        assert_is_constant(
+            true,
+            "",
+            "asm(hash: 0x0000000000000000000000000000000000000000000000000000000000000000, ptr: 0x2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b, bytes: 32) { s256 hash ptr bytes; hash: b256 }",
+        );
+
         // Expressions that cannot be converted to constant
         assert_is_constant(false, "", "{ return 1; }");
+        assert_is_constant(false, "", "asm(r1: 1, r2) { add r2 r1 r1; r2: u64 }");
         assert_is_constant(false, "", "{ return 1; 1}");
         assert_is_constant(
             false,
@@ -74,7 +74,7 @@ pub(super) fn convert_resolved_typeid_no_span(
     convert_resolved_type_id(type_engine, decl_engine, context, ast_type, &span)
 }
 
-fn convert_resolved_type_info(
+pub(super) fn convert_resolved_type_info(
     type_engine: &TypeEngine,
     decl_engine: &DeclEngine,
     context: &mut Context,
@@ -6,6 +6,7 @@ use super::{
     CompiledFunctionCache,
 };
 use crate::{
+    compiler_generated::RANGE_OUT_OF_BOUNDS_SIGNAL,
     engine_threading::*,
     ir_generation::const_eval::{
         compile_constant_expression, compile_constant_expression_to_constant,
@@ -95,6 +96,8 @@ pub(crate) struct FnCompiler<'eng> {
     pub(super) current_block: Block,
     block_to_break_to: Option<Block>,
     block_to_continue_to: Option<Block>,
+    // The labeled blocks and loops enclosing the expression being compiled, innermost last.
+    label_targets: Vec<LabelTarget>,
     current_fn_param: Option<ty::TyFunctionParameter>,
     lexical_map: LexicalMap,
     cache: &'eng mut CompiledFunctionCache,
@@ -104,6 +107,14 @@ pub(crate) struct FnCompiler<'eng> {
     messages_types_map: HashMap<TypeId, MessageId>,
 }
 
+/// A labeled block or loop that can be exited by `break 'label` or continued by `continue 'label`.
+struct LabelTarget {
+    label: Ident,
+    break_block: Block,
+    /// The block to jump to on `continue`, or `None` for labeled blocks, which cannot be continued.
+    continue_block: Option<Block>,
+}
+
 fn to_constant(_s: &mut FnCompiler<'_>, context: &mut Context, value: u64) -> Value {
     let needed_size = Constant::new_uint(context, 64, value);
     Value::new_constant(context, needed_size)
@@ -170,6 +181,7 @@ impl<'eng> FnCompiler<'eng> {
             current_block: function.get_entry_block(context),
             block_to_break_to: None,
             block_to_continue_to: None,
+            label_targets: vec![],
             lexical_map,
             cache,
             current_fn_param: None,
@@ -679,13 +691,49 @@ impl<'eng> FnCompiler<'eng> {
             ty::TyExpressionVariant::EnumTag { exp } => {
                 self.compile_enum_tag(context, md_mgr, exp.to_owned())
             }
-            ty::TyExpressionVariant::WhileLoop { body, condition } => {
-                self.compile_while_loop(context, md_mgr, body, condition, span_md_idx)
-            }
+            ty::TyExpressionVariant::WhileLoop {
+                body,
+                condition,
+                label,
+            } => self.compile_while_loop(
+                context,
+                md_mgr,
+                body,
+                condition,
+                label.as_ref(),
+                span_md_idx,
+            ),
             ty::TyExpressionVariant::ForLoop { desugared } => {
                 self.compile_expression(context, md_mgr, desugared)
             }
-            ty::TyExpressionVariant::Break => {
+            ty::TyExpressionVariant::LabeledBlock { label, block } => {
+                self.compile_labeled_block(context, md_mgr, label, block, ast_expr.return_type)
+            }
+            ty::TyExpressionVariant::Break {
+                label: Some(label),
+                value,
+            } => self.compile_labeled_break(context, md_mgr, label, value.as_deref()),
+            ty::TyExpressionVariant::Continue { label: Some(label) } => {
+                let continue_block = self
+                    .label_targets
+                    .iter()
+                    .rev()
+                    .find(|target| target.label == *label)
+                    .and_then(|target| target.continue_block);
+                match continue_block {
+                    Some(continue_block) => {
+                        let val = self
+                            .current_block
+                            .append(context)
+                            .branch(continue_block, vec![]);
+                        Ok(TerminatorValue::new(val, context))
+                    }
+                    None => Err(CompileError::ContinueOutsideLoop {
+                        span: ast_expr.span.clone(),
+                    }),
+                }
+            }
+            ty::TyExpressionVariant::Break { label: None, .. } => {
                 match self.block_to_break_to {
                     // If `self.block_to_break_to` is not None, then it has been set inside
                     // a loop and the use of `break` here is legal, so create a branch
@@ -2177,14 +2225,70 @@ impl<'eng> FnCompiler<'eng> {
 
                 Ok(TerminatorValue::new(buffer, context))
             }
-            Intrinsic::Slice => self.compile_intrinsic_slice(arguments, context, md_mgr),
+            Intrinsic::Slice => self.compile_intrinsic_slice(arguments, context, md_mgr, &span),
             Intrinsic::ElemAt => self.compile_intrinsic_elem_at(arguments, context, md_mgr),
             Intrinsic::Transmute => {
                 self.compile_intrinsic_transmute(arguments, return_type, context, md_mgr, &span)
             }
+            Intrinsic::Cast => {
+                self.compile_intrinsic_cast(arguments, return_type, context, md_mgr, &span)
+            }
         }
     }
 
+    fn compile_intrinsic_cast(
+        &mut self,
+        arguments: &[ty::TyExpression],
+        return_type: TypeId,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        span: &Span,
+    ) -> Result<TerminatorValue, CompileError> {
+        assert!(arguments.len() == 1);
+
+        let te = self.engines.te();
+        let de = self.engines.de();
+
+        let return_type_ir_type = convert_resolved_type_id(te, de, context, return_type, span)?;
+        let value = return_on_termination_or_extract!(self.compile_expression_to_value(
+            context,
+            md_mgr,
+            &arguments[0]
+        )?);
+        let value_type = value
+            .get_type(context)
+            .expect("cast argument type not found");
+
+        let (Some(from_width), Some(to_width)) = (
+            value_type.get_uint_width(context),
+            return_type_ir_type.get_uint_width(context),
+        ) else {
+            return Err(CompileError::Internal(
+                "Casts are only supported between integer types",
+                span.clone(),
+            ));
+        };
+
+        // Integers are held in full words, so narrowing casts have to clear the upper bits,
+        // while widening casts only change the type.
+        let value = if to_width < from_width {
+            let mask = Constant::get_uint(context, from_width, (1u64 << to_width) - 1);
+            self.current_block
+                .append(context)
+                .binary_op(BinaryOpKind::And, value, mask)
+        } else {
+            value
+        };
+
+        let span_md_idx = md_mgr.span_to_md(context, span);
+        let final_value = self
+            .current_block
+            .append(context)
+            .bitcast(value, return_type_ir_type)
+            .add_metadatum(context, span_md_idx);
+        Ok(TerminatorValue::new(final_value, context))
+    }
+
     fn compile_intrinsic_transmute(
         &mut self,
         arguments: &[ty::TyExpression],
@@ -2375,13 +2479,98 @@ impl<'eng> FnCompiler<'eng> {
         Ok(TerminatorValue::new(ptr_to_elem, context))
     }
 
+    /// Returns the number of elements of the array or slice referenced by the `first_argument_value`.
+    fn len_of_referenced_elements(
+        &mut self,
+        context: &mut Context,
+        first_argument_expr: &TyExpression,
+        first_argument_value: Value,
+    ) -> Result<Value, CompileError> {
+        let te = self.engines.te();
+
+        let err = CompileError::TypeArgumentsNotAllowed {
+            span: first_argument_expr.span.clone(),
+        };
+
+        match &*te.get(first_argument_expr.return_type) {
+            TypeInfo::Ref {
+                referenced_type, ..
+            } => match &*te.get(referenced_type.type_id) {
+                TypeInfo::Array(_, len) => Ok(to_constant(self, context, len.val() as u64)),
+                TypeInfo::Slice(_) => {
+                    let first_argument_value =
+                        save_to_local_return_ptr(self, context, first_argument_value)?;
+
+                    let ptr_arg = AsmArg {
+                        name: Ident::new_no_span("ptr".into()),
+                        initializer: Some(first_argument_value),
+                    };
+                    let len_out_arg = AsmArg {
+                        name: Ident::new_no_span("len_out".into()),
+                        initializer: None,
+                    };
+
+                    let return_type = Type::get_uint64(context);
+                    Ok(self.current_block.append(context).asm_block(
+                        vec![ptr_arg, len_out_arg],
+                        vec![AsmInstruction::lw_no_span("len_out", "ptr", "i1")],
+                        return_type,
+                        Some(Ident::new_no_span("len_out".into())),
+                    ))
+                }
+                _ => Err(err),
+            },
+            _ => Err(err),
+        }
+    }
+
+    /// Reverts with [RANGE_OUT_OF_BOUNDS_SIGNAL] unless `start <= end <= len`.
+    fn compile_range_bounds_check(
+        &mut self,
+        context: &mut Context,
+        start: Value,
+        end: Value,
+        len: Value,
+        span_md_idx: Option<MetadataIndex>,
+    ) {
+        let check_end_block = self.function.create_block(context, None);
+        let revert_block = self.function.create_block(context, None);
+        let in_bounds_block = self.function.create_block(context, None);
+
+        let start_gt_end =
+            self.current_block
+                .append(context)
+                .cmp(Predicate::GreaterThan, start, end);
+        self.current_block
+            .append(context)
+            .conditional_branch(start_gt_end, revert_block, check_end_block, vec![], vec![])
+            .add_metadatum(context, span_md_idx);
+
+        let end_gt_len = check_end_block
+            .append(context)
+            .cmp(Predicate::GreaterThan, end, len);
+        check_end_block
+            .append(context)
+            .conditional_branch(end_gt_len, revert_block, in_bounds_block, vec![], vec![])
+            .add_metadatum(context, span_md_idx);
+
+        let signal = to_constant(self, context, RANGE_OUT_OF_BOUNDS_SIGNAL);
+        revert_block
+            .append(context)
+            .revert(signal)
+            .add_metadatum(context, span_md_idx);
+
+        self.current_block = in_bounds_block;
+    }
+
     fn compile_intrinsic_slice(
         &mut self,
         arguments: &[ty::TyExpression],
         context: &mut Context,
         md_mgr: &mut MetadataManager,
+        span: &Span,
     ) -> Result<TerminatorValue, CompileError> {
-        assert!(arguments.len() == 3);
+        assert!(arguments.len() == 2 || arguments.len() == 3);
 
         let first_argument_expr = &arguments[0];
         let first_argument_value = return_on_termination_or_extract!(
@@ -2389,6 +2578,8 @@ impl<'eng> FnCompiler<'eng> {
         );
         let (ptr_to_first_elem, elem_type_id) =
             self.ptr_to_first_element(context, first_argument_expr, first_argument_value, md_mgr)?;
+        let len =
+            self.len_of_referenced_elements(context, first_argument_expr, first_argument_value)?;
 
         let start = &arguments[1];
         let start = return_on_termination_or_extract!(
@@ -2402,10 +2593,16 @@ impl<'eng> FnCompiler<'eng> {
             start,
         )?;
 
-        let end = &arguments[2];
-        let end = return_on_termination_or_extract!(
-            self.compile_expression_to_value(context, md_mgr, end)?
-        );
+        // Without an end, the slice ends at the end of the referenced elements.
+        let end = match arguments.get(2) {
+            Some(end) => return_on_termination_or_extract!(
+                self.compile_expression_to_value(context, md_mgr, end)?
+            ),
+            None => len,
+        };
+
+        let span_md_idx = md_mgr.span_to_md(context, span);
+        self.compile_range_bounds_check(context, start, end, len, span_md_idx);
 
         let slice_len = self
             .current_block
@@ -3139,6 +3336,7 @@ impl<'eng> FnCompiler<'eng> {
         md_mgr: &mut MetadataManager,
         body: &ty::TyCodeBlock,
         condition: &ty::TyExpression,
+        label: Option<&Ident>,
         span_md_idx: Option<MetadataIndex>,
     ) -> Result<TerminatorValue, CompileError> {
         // We're dancing around a bit here to make the blocks sit in the right order.  Ideally we
@@ -3180,6 +3378,13 @@ impl<'eng> FnCompiler<'eng> {
         // Keep track of the current blocks to jump to in case of a break or continue.
         self.block_to_break_to = Some(break_block);
         self.block_to_continue_to = Some(cond_block);
+        if let Some(label) = label {
+            self.label_targets.push(LabelTarget {
+                label: label.clone(),
+                break_block,
+                continue_block: Some(cond_block),
+            });
+        }
 
         // Fill in the body block now, jump unconditionally to the cond block at its end.
         let body_block = self
@@ -3198,6 +3403,9 @@ impl<'eng> FnCompiler<'eng> {
         // Restore the blocks to jump to now that we're done with the current loop
         self.block_to_break_to = prev_block_to_break_to;
         self.block_to_continue_to = prev_block_to_continue_to;
+        if label.is_some() {
+            self.label_targets.pop();
+        }
 
         // Create the final block now we're finished with the body.
         let final_block = self
@@ -3221,6 +3429,94 @@ impl<'eng> FnCompiler<'eng> {
         Ok(TerminatorValue::new(val, context))
     }
 
+    fn compile_labeled_block(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        label: &Ident,
+        block: &ty::TyCodeBlock,
+        return_type: TypeId,
+    ) -> Result<TerminatorValue, CompileError> {
+        // Same as with the while loops, the 'break' block must be created before the body block(s)
+        // so that we can break to it from the body, and it jumps to the final block created after
+        // them. Both blocks get the value of the labeled block as their argument.
+        let return_type = convert_resolved_typeid_no_span(
+            self.engines.te(),
+            self.engines.de(),
+            context,
+            return_type,
+        )
+        .unwrap_or_else(|_| Type::get_unit(context));
+
+        let break_block = self
+            .function
+            .create_block(context, Some("labeled_block_break".into()));
+        let break_val_arg_idx = break_block.new_arg(context, return_type);
+
+        self.label_targets.push(LabelTarget {
+            label: label.clone(),
+            break_block,
+            continue_block: None,
+        });
+        let body_val = self.compile_code_block(context, md_mgr, block);
+        self.label_targets.pop();
+        let body_val = body_val.map_err(|mut x| x.pop().unwrap())?;
+        let body_end_block = self.current_block;
+
+        let final_block = self
+            .function
+            .create_block(context, Some("end_labeled_block".into()));
+        let final_val_arg_idx = final_block.new_arg(context, return_type);
+
+        let break_val = break_block.get_arg(context, break_val_arg_idx).unwrap();
+        break_block
+            .append(context)
+            .branch(final_block, vec![break_val]);
+        if !body_val.is_terminator {
+            body_end_block
+                .append(context)
+                .branch(final_block, vec![body_val.value]);
+        }
+
+        self.current_block = final_block;
+        let val = final_block.get_arg(context, final_val_arg_idx).unwrap();
+        Ok(TerminatorValue::new(val, context))
+    }
+
+    fn compile_labeled_break(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        label: &Ident,
+        value: Option<&ty::TyExpression>,
+    ) -> Result<TerminatorValue, CompileError> {
+        let Some(target) = self
+            .label_targets
+            .iter()
+            .rev()
+            .find(|target| target.label == *label)
+        else {
+            return Err(CompileError::UnknownLabel {
+                label: label.clone(),
+                span: label.span(),
+            });
+        };
+        let break_block = target.break_block;
+
+        // Loops are exited without a value, and labeled blocks with the value of the `break`,
+        // or `()` if there is none.
+        let args = match (target.continue_block, value) {
+            (Some(_), _) => vec![],
+            (None, Some(value)) => vec![return_on_termination_or_extract!(
+                self.compile_expression_to_value(context, md_mgr, value)?
+            )],
+            (None, None) => vec![Constant::get_unit(context)],
+        };
+
+        let val = self.current_block.append(context).branch(break_block, args);
+        Ok(TerminatorValue::new(val, context))
+    }
+
     pub(crate) fn get_function_var(&self, context: &mut Context, name: &str) -> Option<LocalVar> {
         self.lexical_map
             .get(name)
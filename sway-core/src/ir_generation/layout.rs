@@ -0,0 +1,183 @@
+//! The memory layout and the ABI encoded size of types, as shown to users by tooling.
+//!
+//! The layout is computed from the IR types the compiler lowers the types to, so it is exactly the
+//! layout of the compiled code.
+
+use super::convert::convert_resolved_type_info;
+use crate::{
+    type_system::{AbiEncodeSizeHint, TypeInfo},
+    Engines, TypeId,
+};
+use sway_features::ExperimentalFeatures;
+use sway_ir::{Context, Type, TypeContent};
+use sway_types::Span;
+
+/// The memory layout of a type.
+#[derive(Debug)]
+pub struct TypeLayout {
+    /// The size of a value of the type in memory, in bytes.
+    pub size_in_bytes: u64,
+    /// The alignment of a value of the type in memory, in bytes.
+    pub alignment_in_bytes: u64,
+    /// For structs and tuples, the offsets of the fields, and for enums, the offsets of the
+    /// values of the variants, in declaration order. Empty for all other types.
+    pub fields: Vec<FieldLayout>,
+    /// The size of the ABI encoding of a value of the type, if it can be known without encoding
+    /// the value.
+    pub abi_encoded_size: Option<AbiEncodeSizeHint>,
+}
+
+/// The position of a field of a struct or tuple, or of the value of an enum variant, within a
+/// value of the type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldLayout {
+    /// The name of the field or variant, or the index of the tuple element.
+    pub name: String,
+    /// The offset of the field from the beginning of the value, in bytes.
+    pub offset_in_bytes: u64,
+    /// The size of the field, in bytes.
+    pub size_in_bytes: u64,
+}
+
+impl TypeLayout {
+    /// Returns the layout of `type_info`, or `None` if the type doesn't have a layout, like
+    /// generic types and unsized types.
+    pub fn new(engines: &Engines, type_info: &TypeInfo) -> Option<Self> {
+        if let TypeInfo::Alias { ty, .. } = type_info {
+            return Self::new(engines, &engines.te().get(ty.type_id));
+        }
+
+        let mut context = Context::new(engines.se(), ExperimentalFeatures::default());
+        let ir_type = convert_resolved_type_info(
+            engines.te(),
+            engines.de(),
+            &mut context,
+            type_info,
+            &Span::dummy(),
+        )
+        .ok()?;
+
+        let field_names = field_names(engines, type_info);
+        let fields = match type_info {
+            TypeInfo::Enum(_) => enum_variant_layouts(&context, ir_type, field_names),
+            _ => struct_field_layouts(&context, ir_type, field_names),
+        };
+
+        Some(TypeLayout {
+            size_in_bytes: ir_type.size(&context).in_bytes(),
+            alignment_in_bytes: alignment_in_bytes(&context, ir_type),
+            fields,
+            abi_encoded_size: has_abi_encode_size_hint(engines, type_info)
+                .then(|| type_info.abi_encode_size_hint(engines)),
+        })
+    }
+}
+
+fn field_names(engines: &Engines, type_info: &TypeInfo) -> Vec<String> {
+    match type_info {
+        TypeInfo::Struct(decl_id) => engines
+            .de()
+            .get_struct(decl_id)
+            .fields
+            .iter()
+            .map(|field| field.name.to_string())
+            .collect(),
+        TypeInfo::Enum(decl_id) => engines
+            .de()
+            .get_enum(decl_id)
+            .variants
+            .iter()
+            .map(|variant| variant.name.to_string())
+            .collect(),
+        TypeInfo::Tuple(fields) => (0..fields.len()).map(|index| index.to_string()).collect(),
+        _ => vec![],
+    }
+}
+
+fn struct_field_layouts(context: &Context, ir_type: Type, names: Vec<String>) -> Vec<FieldLayout> {
+    names
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, name)| {
+            let (offset_in_bytes, field_type) =
+                ir_type.get_struct_field_offset_and_type(context, index as u64)?;
+            Some(FieldLayout {
+                name,
+                offset_in_bytes,
+                size_in_bytes: field_type.size(context).in_bytes(),
+            })
+        })
+        .collect()
+}
+
+/// Enums are lowered to a tag followed by a union of the variant values, unless none of the
+/// variants has a value.
+fn enum_variant_layouts(context: &Context, ir_type: Type, names: Vec<String>) -> Vec<FieldLayout> {
+    if ir_type.get_field_types(context).len() < 2 {
+        return vec![];
+    }
+    let Some((union_offset, union_type)) = ir_type.get_struct_field_offset_and_type(context, 1)
+    else {
+        return vec![];
+    };
+    names
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, name)| {
+            let (offset_in_union, variant_type) =
+                union_type.get_union_field_offset_and_type(context, index as u64)?;
+            Some(FieldLayout {
+                name,
+                offset_in_bytes: union_offset + offset_in_union,
+                size_in_bytes: variant_type.size(context).in_bytes(),
+            })
+        })
+        .collect()
+}
+
+/// Values of types of a single byte are byte aligned, as are arrays of them. All other values are
+/// word aligned.
+fn alignment_in_bytes(context: &Context, ir_type: Type) -> u64 {
+    match ir_type.get_content(context) {
+        TypeContent::Uint(8) | TypeContent::Bool | TypeContent::Unit | TypeContent::Never => 1,
+        TypeContent::Array(elem_type, _) => alignment_in_bytes(context, *elem_type),
+        _ => 8,
+    }
+}
+
+/// [TypeInfo::abi_encode_size_hint] is only implemented for the types that can be ABI encoded.
+fn has_abi_encode_size_hint(engines: &Engines, type_info: &TypeInfo) -> bool {
+    let inner_types: Vec<TypeId> = match type_info {
+        TypeInfo::Boolean
+        | TypeInfo::UnsignedInteger(_)
+        | TypeInfo::Numeric
+        | TypeInfo::B256
+        | TypeInfo::Slice(_)
+        | TypeInfo::RawUntypedSlice
+        | TypeInfo::StringSlice
+        | TypeInfo::RawUntypedPtr
+        | TypeInfo::Ptr(_)
+        | TypeInfo::StringArray(_) => return true,
+        TypeInfo::Alias { ty, .. } => vec![ty.type_id],
+        TypeInfo::Array(elem, _) => vec![elem.type_id],
+        TypeInfo::Tuple(items) => items.iter().map(|item| item.type_id).collect(),
+        TypeInfo::Struct(decl_id) => engines
+            .de()
+            .get_struct(decl_id)
+            .fields
+            .iter()
+            .map(|field| field.type_argument.type_id)
+            .collect(),
+        TypeInfo::Enum(decl_id) => engines
+            .de()
+            .get_enum(decl_id)
+            .variants
+            .iter()
+            .map(|variant| variant.type_argument.type_id)
+            .collect(),
+        _ => return false,
+    };
+    inner_types
+        .into_iter()
+        .all(|type_id| has_abi_encode_size_hint(engines, &engines.te().get(type_id)))
+}
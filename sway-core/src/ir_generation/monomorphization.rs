@@ -0,0 +1,106 @@
+use crate::metadata::MetadataManager;
+
+use sway_error::{
+    error::CompileError,
+    handler::{ErrorEmitted, Handler},
+};
+use sway_ir::Context;
+use sway_types::span::Span;
+
+use std::collections::HashMap;
+
+/// The IR functions compiled from the same function declaration.
+#[derive(Debug, Clone)]
+pub(crate) struct MonomorphizedFunction {
+    /// The name of the function declaration.
+    pub(crate) span: Span,
+    /// The number of IR functions compiled from the declaration.
+    pub(crate) copies: usize,
+    /// The total number of IR instructions of all the copies.
+    pub(crate) ir_size: usize,
+}
+
+/// Groups the functions of the unoptimized IR by the declaration they were compiled from, and
+/// returns the declarations compiled more than once, sorted by their number of copies and then by
+/// their total IR size.
+pub(crate) fn monomorphized_functions(context: &Context) -> Vec<MonomorphizedFunction> {
+    let mut md_mgr = MetadataManager::default();
+    let mut functions = HashMap::<Span, MonomorphizedFunction>::new();
+    for function in context
+        .module_iter()
+        .flat_map(|module| module.function_iter(context))
+    {
+        // Functions generated by the compiler don't have a declaration.
+        let Some(span) = md_mgr.md_to_fn_name_span(context, function.get_metadata(context)) else {
+            continue;
+        };
+        let entry = functions
+            .entry(span.clone())
+            .or_insert(MonomorphizedFunction {
+                span,
+                copies: 0,
+                ir_size: 0,
+            });
+        entry.copies += 1;
+        entry.ir_size += function.num_instructions(context);
+    }
+
+    let mut functions = functions
+        .into_values()
+        .filter(|function| function.copies > 1)
+        .collect::<Vec<_>>();
+    functions.sort_by(|a, b| {
+        b.copies
+            .cmp(&a.copies)
+            .then(b.ir_size.cmp(&a.ir_size))
+            .then_with(|| a.span.as_str().cmp(b.span.as_str()))
+    });
+    functions
+}
+
+/// Prints the `top` functions with the most monomorphized copies and fails if the total number of
+/// copies exceeds the `budget`.
+pub(crate) fn check_monomorphization(
+    handler: &Handler,
+    context: &Context,
+    top: Option<usize>,
+    budget: Option<usize>,
+) -> Result<(), ErrorEmitted> {
+    if top.is_none() && budget.is_none() {
+        return Ok(());
+    }
+
+    let functions = monomorphized_functions(context);
+    let instantiations: usize = functions.iter().map(|function| function.copies).sum();
+
+    if let Some(top) = top {
+        let mut output = format!(
+            ";; Monomorphization report: {instantiations} copies of {} functions\n",
+            functions.len()
+        );
+        output.push_str(&format!(";; {:>8} {:>10}  function", "copies", "IR size"));
+        for function in functions.iter().take(top) {
+            output.push_str(&format!(
+                "\n;; {:>8} {:>10}  {}",
+                function.copies,
+                function.ir_size,
+                function.span.as_str()
+            ));
+        }
+        tracing::info!("{output}");
+    }
+
+    match budget {
+        Some(budget) if instantiations > budget => Err(handler.emit_err(
+            CompileError::MonomorphizationBudgetExceeded {
+                budget,
+                instantiations,
+                span: functions
+                    .first()
+                    .map(|function| function.span.clone())
+                    .unwrap_or_else(Span::dummy),
+            },
+        )),
+        _ => Ok(()),
+    }
+}
@@ -27,6 +27,10 @@ pub(crate) struct PurityEnv {
 /// The checker will check this function and any that it calls.
 ///
 /// Returns bools for whether it (reads, writes).
+///
+/// With the `infer_storage_purity` experimental feature enabled, private non-entry functions
+/// without storage attributes have their purity inferred from their bodies and callees instead
+/// of being checked. Mismatches are then reported on the public functions calling them.
 pub(crate) fn check_function_purity(
     handler: &Handler,
     env: &mut PurityEnv,
@@ -40,6 +44,7 @@ pub(crate) fn check_function_purity(
     // - via ASM blocks with storage VM instructions, or
     // - via calls into functions with the above.
     let attributed_purity = md_mgr.md_to_purity(context, function.get_metadata(context));
+    let is_inferred = is_purity_inferred(context, function, attributed_purity);
 
     let mut storage_access_violations = vec![];
     let (reads, writes) = function.instruction_iter(context).fold(
@@ -105,7 +110,13 @@ pub(crate) fn check_function_purity(
                                 });
                             if callee_reads || callee_writes {
                                 let callee_span = md_mgr.md_to_fn_call_path_span(context, ins_value.get_metadata(context)).unwrap_or(Span::dummy());
-                                let storage_access = StorageAccess::ImpureFunctionCall(callee_span.clone(), callee_reads, callee_writes);
+                                let callee_purity = md_mgr.md_to_purity(context, callee.get_metadata(context));
+                                let storage_access = StorageAccess::ImpureFunctionCall(
+                                    callee_span.clone(),
+                                    callee_reads,
+                                    callee_writes,
+                                    is_purity_inferred(context, callee, callee_purity),
+                                );
                                 if violates_purity(&storage_access, &attributed_purity) {
                                     storage_access_violations.push((callee_span, storage_access));
                                 }
@@ -123,8 +134,9 @@ pub(crate) fn check_function_purity(
     // Simple closures for each of the error types.
     let error = |span: Span, needed| {
         // We don't emit errors on the generated `__entry` function
-        // but do on the original entry functions and all other functions.
-        if !function.is_entry(context) || function.is_original_entry(context) {
+        // but do on the original entry functions and all other functions,
+        // unless their purity is inferred.
+        if !is_inferred && (!function.is_entry(context) || function.is_original_entry(context)) {
             handler.emit_err(CompileError::StorageAccessMismatched {
                 span,
                 is_pure: matches!(attributed_purity, Pure),
@@ -177,6 +189,18 @@ pub(crate) fn check_function_purity(
     (reads, writes)
 }
 
+/// Returns true if the purity of `function` is inferred rather than checked against its attributes.
+///
+/// That is the case for private functions that are not entries and have no storage attributes,
+/// when the `infer_storage_purity` experimental feature is enabled.
+fn is_purity_inferred(context: &Context, function: &Function, attributed_purity: Purity) -> bool {
+    context.experimental.infer_storage_purity
+        && attributed_purity == Pure
+        && !function.is_public(context)
+        && !function.is_entry(context)
+        && !function.is_original_entry(context)
+}
+
 fn is_store_access_fuel_vm_instruction(inst: &FuelVmInstruction) -> bool {
     matches!(
         inst,
@@ -69,6 +69,8 @@ pub struct FunctionParameter {
     pub is_mutable: bool,
     pub mutability_span: Span,
     pub type_argument: TypeArgument,
+    /// The value passed to the parameter when a call doesn't pass an argument to it.
+    pub default_value: Option<Expression>,
 }
 
 impl EqWithEngines for FunctionParameter {}
@@ -79,6 +81,7 @@ impl PartialEqWithEngines for FunctionParameter {
             && self.is_mutable == other.is_mutable
             && self.mutability_span == other.mutability_span
             && self.type_argument.eq(&other.type_argument, ctx)
+            && self.default_value.eq(&other.default_value, ctx)
     }
 }
 
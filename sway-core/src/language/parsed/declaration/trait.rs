@@ -128,6 +128,8 @@ impl Spanned for TraitFn {
 #[derive(Debug, Clone)]
 pub struct TraitTypeDeclaration {
     pub name: Ident,
+    /// The generic parameters of a generic associated type, e.g. `T` in `type Output<T>;`.
+    pub type_parameters: Vec<TypeParameter>,
     pub attributes: transform::AttributesMap,
     pub ty_opt: Option<TypeArgument>,
     pub span: Span,
@@ -137,6 +139,7 @@ impl EqWithEngines for TraitTypeDeclaration {}
 impl PartialEqWithEngines for TraitTypeDeclaration {
     fn eq(&self, other: &Self, ctx: &PartialEqWithEnginesContext) -> bool {
         self.name == other.name
+            && self.type_parameters.eq(&other.type_parameters, ctx)
             && self.attributes == other.attributes
             && self.ty_opt.eq(&other.ty_opt, ctx)
     }
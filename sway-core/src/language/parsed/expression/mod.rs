@@ -326,12 +326,20 @@ impl PartialEqWithEngines for AbiCastExpression {
 pub struct ArrayIndexExpression {
     pub prefix: Box<Expression>,
     pub index: Box<Expression>,
+    /// If set, the `index` is a `u64` literal counting from the end of the array, `1` being
+    /// the last element, and the array must have at least the set number of elements.
+    ///
+    /// Used for the elements following the `..` of a slice pattern, like `last` in
+    /// `let [first, .., last] = array;`.
+    pub from_end: Option<u64>,
 }
 
 impl EqWithEngines for ArrayIndexExpression {}
 impl PartialEqWithEngines for ArrayIndexExpression {
     fn eq(&self, other: &Self, ctx: &PartialEqWithEnginesContext) -> bool {
-        self.prefix.eq(&other.prefix, ctx) && self.index.eq(&other.index, ctx)
+        self.prefix.eq(&other.prefix, ctx)
+            && self.index.eq(&other.index, ctx)
+            && self.from_end == other.from_end
     }
 }
 
@@ -371,12 +379,58 @@ pub struct WhileLoopExpression {
     pub condition: Box<Expression>,
     pub body: CodeBlock,
     pub is_desugared_for_loop: bool,
+    /// The label of the loop, e.g., `'outer` in `'outer: while true { ... }`.
+    pub label: Option<Ident>,
 }
 
 impl EqWithEngines for WhileLoopExpression {}
 impl PartialEqWithEngines for WhileLoopExpression {
     fn eq(&self, other: &Self, ctx: &PartialEqWithEnginesContext) -> bool {
-        self.condition.eq(&other.condition, ctx) && self.body.eq(&other.body, ctx)
+        self.condition.eq(&other.condition, ctx)
+            && self.body.eq(&other.body, ctx)
+            && self.label == other.label
+    }
+}
+
+/// A block labeled with `'label: { ... }`, that can be exited early with `break 'label value;`.
+#[derive(Debug, Clone)]
+pub struct LabeledBlockExpression {
+    pub label: Ident,
+    pub block: CodeBlock,
+}
+
+impl EqWithEngines for LabeledBlockExpression {}
+impl PartialEqWithEngines for LabeledBlockExpression {
+    fn eq(&self, other: &Self, ctx: &PartialEqWithEnginesContext) -> bool {
+        self.label == other.label && self.block.eq(&other.block, ctx)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BreakExpression {
+    /// The label of the block or loop to break out of. If `None`, the innermost loop is exited.
+    pub label: Option<Ident>,
+    /// The value of the labeled block that is exited. Only labeled blocks can be exited with a value.
+    pub value: Option<Box<Expression>>,
+}
+
+impl EqWithEngines for BreakExpression {}
+impl PartialEqWithEngines for BreakExpression {
+    fn eq(&self, other: &Self, ctx: &PartialEqWithEnginesContext) -> bool {
+        self.label == other.label && self.value.eq(&other.value, ctx)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ContinueExpression {
+    /// The label of the loop to continue. If `None`, the innermost loop is continued.
+    pub label: Option<Ident>,
+}
+
+impl EqWithEngines for ContinueExpression {}
+impl PartialEqWithEngines for ContinueExpression {
+    fn eq(&self, other: &Self, _ctx: &PartialEqWithEnginesContext) -> bool {
+        self.label == other.label
     }
 }
 
@@ -470,8 +524,10 @@ pub enum ExpressionKind {
     WhileLoop(WhileLoopExpression),
     /// A control flow element which loops between values of an iterator.
     ForLoop(ForLoopExpression),
-    Break,
-    Continue,
+    /// A block labeled with `'label: { ... }`.
+    LabeledBlock(LabeledBlockExpression),
+    Break(BreakExpression),
+    Continue(ContinueExpression),
     Reassignment(ReassignmentExpression),
     /// An implicit return expression is different from a [Expression::Return] because
     /// it is not a control flow item. Therefore it is a different variant.
@@ -482,6 +538,11 @@ pub enum ExpressionKind {
     Return(Box<Expression>),
     Ref(RefExpression),
     Deref(Box<Expression>),
+    /// A propagation of a failure, like `value?`.
+    Try(TryExpression),
+    /// An argument of a function or method call passed by the name of its parameter, like
+    /// `amount: 5`. It only appears among the arguments of calls.
+    NamedArgument(Box<NamedArgumentExpression>),
 }
 
 impl EqWithEngines for Expression {}
@@ -542,8 +603,11 @@ impl PartialEqWithEngines for ExpressionKind {
             }
             (ExpressionKind::WhileLoop(lhs), ExpressionKind::WhileLoop(rhs)) => lhs.eq(rhs, ctx),
             (ExpressionKind::ForLoop(lhs), ExpressionKind::ForLoop(rhs)) => lhs.eq(rhs, ctx),
-            (ExpressionKind::Break, ExpressionKind::Break) => true,
-            (ExpressionKind::Continue, ExpressionKind::Continue) => true,
+            (ExpressionKind::LabeledBlock(lhs), ExpressionKind::LabeledBlock(rhs)) => {
+                lhs.eq(rhs, ctx)
+            }
+            (ExpressionKind::Break(lhs), ExpressionKind::Break(rhs)) => lhs.eq(rhs, ctx),
+            (ExpressionKind::Continue(lhs), ExpressionKind::Continue(rhs)) => lhs.eq(rhs, ctx),
             (ExpressionKind::Reassignment(lhs), ExpressionKind::Reassignment(rhs)) => {
                 lhs.eq(rhs, ctx)
             }
@@ -553,6 +617,10 @@ impl PartialEqWithEngines for ExpressionKind {
             (ExpressionKind::Return(lhs), ExpressionKind::Return(rhs)) => lhs.eq(rhs, ctx),
             (ExpressionKind::Ref(lhs), ExpressionKind::Ref(rhs)) => lhs.eq(rhs, ctx),
             (ExpressionKind::Deref(lhs), ExpressionKind::Deref(rhs)) => lhs.eq(rhs, ctx),
+            (ExpressionKind::Try(lhs), ExpressionKind::Try(rhs)) => lhs.eq(rhs, ctx),
+            (ExpressionKind::NamedArgument(lhs), ExpressionKind::NamedArgument(rhs)) => {
+                lhs.eq(rhs, ctx)
+            }
             _ => false,
         }
     }
@@ -572,6 +640,47 @@ impl PartialEqWithEngines for RefExpression {
     }
 }
 
+/// The `?` operator applied to a value of a type implementing the `std::ops::Try` trait.
+///
+/// The expression is desugared during type checking, once the return type of the enclosing
+/// function is known, into a `match` on the `std::ops::ControlFlow` returned by `branch`. The
+/// `match` evaluates to the value, or returns the failure from the enclosing function, converted
+/// by `std::ops::FromResidual::from_residual`.
+#[derive(Debug, Clone)]
+pub struct TryExpression {
+    pub expr: Box<Expression>,
+    pub question_mark_span: Span,
+    /// The name of the variable the value of the `expr` is stored in.
+    pub operand_var_name: Ident,
+    /// The name of the variable the result of `branch` is stored in, and matched on.
+    pub matched_value_var_name: Ident,
+}
+
+impl EqWithEngines for TryExpression {}
+impl PartialEqWithEngines for TryExpression {
+    fn eq(&self, other: &Self, ctx: &PartialEqWithEnginesContext) -> bool {
+        self.expr.eq(&other.expr, ctx)
+    }
+}
+
+/// An argument passed by the name of the parameter, like `amount: 5`.
+///
+/// Either all or none of the arguments of a call are named. Named arguments can be given in any
+/// order, and are put in the order of the parameters when the called function is known during
+/// type checking.
+#[derive(Debug, Clone)]
+pub struct NamedArgumentExpression {
+    pub name: Ident,
+    pub value: Expression,
+}
+
+impl EqWithEngines for NamedArgumentExpression {}
+impl PartialEqWithEngines for NamedArgumentExpression {
+    fn eq(&self, other: &Self, ctx: &PartialEqWithEnginesContext) -> bool {
+        self.name == other.name && self.value.eq(&other.value, ctx)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ReassignmentTarget {
     /// An [Expression] representing a single variable or a path
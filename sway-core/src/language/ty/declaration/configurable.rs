@@ -111,3 +111,245 @@ impl ReplaceDecls for TyConfigurableDecl {
         }
     }
 }
+
+/// The layout of the configurables of a program within its bytecode.
+///
+/// The layout is emitted as a build artifact so that SDKs and deployment tools can set the values
+/// of the configurables in the compiled bytecode, see [ConfigurablesLayout::patch].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigurablesLayout {
+    pub configurables: Vec<ConfigurableLayout>,
+}
+
+/// The layout of a single configurable.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigurableLayout {
+    /// The name of the configurable.
+    pub name: String,
+    /// The type of the configurable, as written in the source.
+    #[serde(rename = "type")]
+    pub type_name: String,
+    /// How the value of the configurable is encoded in the bytecode.
+    pub encoding: ConfigurableEncoding,
+    /// The offset of the value from the beginning of the bytecode, in bytes.
+    pub offset: u64,
+    /// The size of the space reserved for the value, in bytes.
+    pub size_in_bytes: u64,
+    /// The encoded default value, as a hex string.
+    pub default_value: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigurableEncoding {
+    /// The value is stored in its in-memory representation, padded to full words.
+    Memory,
+    /// The value is stored ABI encoded and decoded when the program starts. Values of types with a
+    /// variable encoded size, like enums, may be shorter than the reserved space and are padded
+    /// with zeros.
+    Abi,
+}
+
+/// An error setting the value of a configurable in the bytecode.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigurablePatchError {
+    /// The program has no configurable with the given name, or it was removed as unused.
+    UnknownConfigurable { name: String },
+    /// The encoded value doesn't fit into the space reserved for the configurable.
+    SizeMismatch {
+        name: String,
+        expected: u64,
+        found: u64,
+    },
+    /// The configurable lies outside of the bytecode, i.e., the layout is not the layout of the
+    /// bytecode.
+    OutOfBounds { name: String },
+}
+
+impl fmt::Display for ConfigurablePatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigurablePatchError::UnknownConfigurable { name } => {
+                write!(f, "The program has no configurable \"{name}\".")
+            }
+            ConfigurablePatchError::SizeMismatch {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "The value of configurable \"{name}\" must be {expected} bytes long, but it is {found} bytes long."
+            ),
+            ConfigurablePatchError::OutOfBounds { name } => write!(
+                f,
+                "Configurable \"{name}\" lies outside of the bytecode. The layout doesn't belong to the bytecode."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConfigurablePatchError {}
+
+impl ConfigurablesLayout {
+    /// Returns the layout of the `configurables` of a program compiled into `compiled`.
+    ///
+    /// Configurables which are never used are removed from the bytecode and are not part of the
+    /// layout.
+    pub fn new(
+        engines: &Engines,
+        configurables: &[TyConfigurableDecl],
+        compiled: &crate::CompiledBytecode,
+        new_encoding: bool,
+    ) -> Self {
+        let encoding = if new_encoding {
+            ConfigurableEncoding::Abi
+        } else {
+            ConfigurableEncoding::Memory
+        };
+        let mut configurables = configurables
+            .iter()
+            .filter_map(|decl| {
+                let name = decl.call_path.suffix.as_str();
+                let offset = *compiled.named_data_section_entries_offsets.get(name)?;
+                let size_in_bytes = *compiled.named_data_section_entries_sizes.get(name)?;
+                let default_value = compiled
+                    .bytecode
+                    .get(offset as usize..(offset + size_in_bytes) as usize)?;
+                Some(ConfigurableLayout {
+                    name: name.to_string(),
+                    type_name: engines.help_out(decl.type_ascription.type_id).to_string(),
+                    encoding,
+                    offset,
+                    size_in_bytes,
+                    default_value: format!(
+                        "0x{}",
+                        default_value
+                            .iter()
+                            .map(|b| format!("{b:02x}"))
+                            .collect::<String>()
+                    ),
+                })
+            })
+            .collect::<Vec<_>>();
+        configurables.sort_by_key(|configurable| configurable.offset);
+        ConfigurablesLayout { configurables }
+    }
+
+    /// Returns the layout of the configurable with the given name, if any.
+    pub fn configurable(&self, name: &str) -> Option<&ConfigurableLayout> {
+        self.configurables
+            .iter()
+            .find(|configurable| configurable.name == name)
+    }
+
+    /// Sets the value of the configurable `name` in `bytecode` to `encoded_value`, which must be
+    /// encoded as described by the [ConfigurableEncoding] of the configurable.
+    ///
+    /// The bytecode is left untouched if the value doesn't fit into the space reserved for the
+    /// configurable.
+    pub fn patch(
+        &self,
+        bytecode: &mut [u8],
+        name: &str,
+        encoded_value: &[u8],
+    ) -> Result<(), ConfigurablePatchError> {
+        let configurable =
+            self.configurable(name)
+                .ok_or_else(|| ConfigurablePatchError::UnknownConfigurable {
+                    name: name.to_string(),
+                })?;
+
+        let found = encoded_value.len() as u64;
+        let fits = match configurable.encoding {
+            ConfigurableEncoding::Memory => found == configurable.size_in_bytes,
+            ConfigurableEncoding::Abi => found <= configurable.size_in_bytes,
+        };
+        if !fits {
+            return Err(ConfigurablePatchError::SizeMismatch {
+                name: name.to_string(),
+                expected: configurable.size_in_bytes,
+                found,
+            });
+        }
+
+        let start = configurable.offset as usize;
+        let end = start + configurable.size_in_bytes as usize;
+        let reserved =
+            bytecode
+                .get_mut(start..end)
+                .ok_or_else(|| ConfigurablePatchError::OutOfBounds {
+                    name: name.to_string(),
+                })?;
+        let (value, padding) = reserved.split_at_mut(encoded_value.len());
+        value.copy_from_slice(encoded_value);
+        padding.fill(0);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout(encoding: ConfigurableEncoding) -> ConfigurablesLayout {
+        ConfigurablesLayout {
+            configurables: vec![ConfigurableLayout {
+                name: "VALUE".to_string(),
+                type_name: "u64".to_string(),
+                encoding,
+                offset: 8,
+                size_in_bytes: 8,
+                default_value: "0x0000000000000001".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn patch_configurable() {
+        let mut bytecode = vec![0xff; 24];
+        layout(ConfigurableEncoding::Memory)
+            .patch(&mut bytecode, "VALUE", &42u64.to_be_bytes())
+            .unwrap();
+        assert_eq!(&bytecode[8..16], &42u64.to_be_bytes());
+        assert_eq!(&bytecode[..8], &[0xff; 8]);
+        assert_eq!(&bytecode[16..], &[0xff; 8]);
+
+        layout(ConfigurableEncoding::Abi)
+            .patch(&mut bytecode, "VALUE", &[1, 2])
+            .unwrap();
+        assert_eq!(&bytecode[8..16], &[1, 2, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn patch_configurable_errors() {
+        let mut bytecode = vec![0; 24];
+        assert_eq!(
+            layout(ConfigurableEncoding::Memory).patch(&mut bytecode, "OTHER", &[0; 8]),
+            Err(ConfigurablePatchError::UnknownConfigurable {
+                name: "OTHER".to_string()
+            })
+        );
+        assert_eq!(
+            layout(ConfigurableEncoding::Memory).patch(&mut bytecode, "VALUE", &[0; 4]),
+            Err(ConfigurablePatchError::SizeMismatch {
+                name: "VALUE".to_string(),
+                expected: 8,
+                found: 4,
+            })
+        );
+        assert_eq!(
+            layout(ConfigurableEncoding::Abi).patch(&mut bytecode, "VALUE", &[0; 9]),
+            Err(ConfigurablePatchError::SizeMismatch {
+                name: "VALUE".to_string(),
+                expected: 8,
+                found: 9,
+            })
+        );
+        assert_eq!(
+            layout(ConfigurableEncoding::Abi).patch(&mut bytecode[..12], "VALUE", &[0; 8]),
+            Err(ConfigurablePatchError::OutOfBounds {
+                name: "VALUE".to_string()
+            })
+        );
+    }
+}
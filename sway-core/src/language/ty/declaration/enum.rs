@@ -1,5 +1,6 @@
 use crate::{
     engine_threading::*,
+    fuel_prelude::fuel_crypto::Hasher as Sha256,
     has_changes,
     language::{parsed::EnumDeclaration, ty::TyDeclParsedType, CallPath, Visibility},
     transform,
@@ -103,7 +104,58 @@ impl MonomorphizeHelper for TyEnumDecl {
     }
 }
 
+/// The error code of a variant of an enum annotated with `#[error_type]`.
+///
+/// The code is the first eight bytes of `sha256("<enum path>::<variant name>")`, interpreted as
+/// a big-endian `u64`, where the enum path is fully qualified, e.g. `my_pkg::errors::Error`.
+/// It only depends on the names, so it stays stable when variants get reordered or added.
+/// The compiler implements `core::error_type::ErrorType` for the enum to return these codes,
+/// and `std::revert::revert_with_error` reverts with them.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ErrorSelector {
+    pub enum_path: String,
+    pub variant_name: String,
+    pub selector: u64,
+    #[serde(skip)]
+    pub span: Span,
+}
+
+impl ErrorSelector {
+    pub fn compute(enum_path: &str, variant_name: &str) -> u64 {
+        let hash = Sha256::hash(format!("{enum_path}::{variant_name}").as_bytes());
+        u64::from_be_bytes(
+            hash[..8]
+                .try_into()
+                .expect("sha256 hash has at least 8 bytes"),
+        )
+    }
+}
+
 impl TyEnumDecl {
+    /// Returns true if the enum is annotated with `#[error_type]`.
+    pub fn is_error_type(&self) -> bool {
+        self.attributes
+            .contains_key(&transform::AttributeKind::ErrorType)
+    }
+
+    /// Returns the error selectors of all the variants if the enum is an `#[error_type]`.
+    pub fn error_selectors(&self) -> Vec<ErrorSelector> {
+        if !self.is_error_type() {
+            return vec![];
+        }
+        // The call path of an enum declaration is always the full path from the package root.
+        let enum_path = self.call_path.to_string();
+        self.variants
+            .iter()
+            .map(|variant| ErrorSelector {
+                enum_path: enum_path.clone(),
+                variant_name: variant.name.as_str().to_string(),
+                selector: ErrorSelector::compute(&enum_path, variant.name.as_str()),
+                span: variant.name.span(),
+            })
+            .collect()
+    }
+
     pub(crate) fn expect_variant_from_name(
         &self,
         handler: &Handler,
@@ -371,12 +371,16 @@ impl TyFunctionDecl {
 
     /// Converts a [TyFunctionDecl] into a value that is to be used in contract function
     /// selectors.
-    /// Hashes the name and parameters using SHA256, and then truncates to four bytes.
+    /// Hashes the name and parameters using SHA256, and then truncates to four bytes, unless
+    /// the selector is pinned by a `#[selector]` attribute.
     pub fn to_fn_selector_value(
         &self,
         handler: &Handler,
         engines: &Engines,
     ) -> Result<[u8; 4], ErrorEmitted> {
+        if let Some(Some(selector)) = self.attributes.selector_override() {
+            return Ok(selector);
+        }
         let hash = self.to_fn_selector_value_untruncated(handler, engines)?;
         // 4 bytes truncation via copying into a 4 byte buffer
         let mut buf = [0u8; 4];
@@ -409,6 +413,15 @@ impl TyFunctionDecl {
         ))
     }
 
+    /// The name contract calls identify this ABI method by when using the new encoding, unless
+    /// pinned by a `#[method_name]` attribute, the name of the method.
+    pub fn abi_method_name(&self) -> &str {
+        match self.attributes.method_name_override() {
+            Some(Some(method_name)) => method_name,
+            _ => self.name.as_str(),
+        }
+    }
+
     /// Whether or not this function is the default entry point.
     pub fn is_entry(&self) -> bool {
         matches!(self.kind, TyFunctionDeclKind::Entry)
@@ -445,6 +458,12 @@ impl TyFunctionDecl {
         self.attributes.contains_key(&AttributeKind::Fallback)
     }
 
+    /// The spans of the `return`s and `std::revert` calls the function may exit at, found through
+    /// its control flow graph.
+    pub fn explicit_exit_points(&self, engines: &Engines) -> Vec<Span> {
+        crate::control_flow_analysis::ControlFlowGraph::explicit_exit_points(engines, self)
+    }
+
     /// Whether or not this function is a constructor for the type given by `type_id`.
     ///
     /// Returns `Some(true)` if the function is surely the constructor and `Some(false)` if
@@ -490,6 +509,8 @@ pub struct TyFunctionParameter {
     pub is_mutable: bool,
     pub mutability_span: Span,
     pub type_argument: TypeArgument,
+    /// The value passed to the parameter when a call doesn't pass an argument to it.
+    pub default_value: Option<TyExpression>,
 }
 
 impl EqWithEngines for TyFunctionParameter {}
@@ -512,6 +533,7 @@ impl HashWithEngines for TyFunctionParameter {
             // these fields are not hashed because they aren't relevant/a
             // reliable source of obj v. obj distinction
             mutability_span: _,
+            default_value: _,
         } = self;
         name.hash(state);
         type_argument.hash(state, engines);
@@ -299,3 +299,261 @@ impl HashWithEngines for TyStorageField {
         initializer.hash(state, engines);
     }
 }
+
+/// The storage layout of a contract, listing where and how each storage field is stored.
+///
+/// The layout is emitted as a build artifact so that storage can be inspected and compared
+/// between versions of a contract, e.g., when upgrading a contract behind a proxy.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageLayout {
+    pub fields: Vec<StorageFieldLayout>,
+}
+
+/// The layout of a single storage field.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageFieldLayout {
+    /// The full name of the field, e.g., "storage::ns1.field".
+    pub name: String,
+    /// The type of the field, as written in the source.
+    #[serde(rename = "type")]
+    pub type_name: String,
+    /// How the key of the field is obtained.
+    pub key_derivation: StorageKeyDerivation,
+    /// The key of the first slot occupied by the field, as a hex string.
+    pub key: String,
+    /// The size of the field's value in bytes.
+    pub size_in_bytes: u64,
+    /// The keys of all the slots occupied by the initial value of the field, as hex strings.
+    pub slots: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StorageKeyDerivation {
+    /// The key is generated by the compiler by hashing the `preimage`.
+    /// If `storage_domain` is true, the preimage is prefixed with the storage domain byte.
+    Generated {
+        preimage: String,
+        storage_domain: bool,
+    },
+    /// The key is explicitly defined by using the `in` keyword.
+    Explicit,
+}
+
+/// A change to a storage field between two versions of a contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StorageLayoutChange {
+    /// The field exists only in the new layout.
+    Added { name: String },
+    /// The field exists only in the old layout. Its values stay in storage, but are not
+    /// accessible anymore through the `storage` keyword.
+    Removed { name: String },
+    TypeChanged {
+        name: String,
+        old_type: String,
+        new_type: String,
+    },
+    KeyChanged {
+        name: String,
+        old_key: String,
+        new_key: String,
+    },
+    SizeChanged {
+        name: String,
+        old_size: u64,
+        new_size: u64,
+    },
+    /// The field occupies a slot that was occupied by a different field in the old layout.
+    SlotReused {
+        name: String,
+        slot: String,
+        old_name: String,
+    },
+}
+
+impl StorageLayoutChange {
+    /// Returns true if the change makes the new layout incompatible with the storage
+    /// already written by a contract using the old layout.
+    pub fn is_incompatible(&self) -> bool {
+        !matches!(
+            self,
+            StorageLayoutChange::Added { .. } | StorageLayoutChange::Removed { .. }
+        )
+    }
+}
+
+impl std::fmt::Display for StorageLayoutChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StorageLayoutChange::Added { name } => write!(f, "\"{name}\" was added."),
+            StorageLayoutChange::Removed { name } => write!(f, "\"{name}\" was removed."),
+            StorageLayoutChange::TypeChanged {
+                name,
+                old_type,
+                new_type,
+            } => write!(
+                f,
+                "The type of \"{name}\" changed from \"{old_type}\" to \"{new_type}\"."
+            ),
+            StorageLayoutChange::KeyChanged {
+                name,
+                old_key,
+                new_key,
+            } => write!(
+                f,
+                "The key of \"{name}\" changed from {old_key} to {new_key}."
+            ),
+            StorageLayoutChange::SizeChanged {
+                name,
+                old_size,
+                new_size,
+            } => write!(
+                f,
+                "The size of \"{name}\" changed from {old_size} to {new_size} bytes."
+            ),
+            StorageLayoutChange::SlotReused {
+                name,
+                slot,
+                old_name,
+            } => write!(
+                f,
+                "\"{name}\" occupies the slot {slot} previously occupied by \"{old_name}\"."
+            ),
+        }
+    }
+}
+
+impl StorageLayout {
+    /// Returns the layout of the field with the given full name, if any.
+    pub fn field(&self, name: &str) -> Option<&StorageFieldLayout> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Compares this layout, of an already deployed contract, with the `new` layout of
+    /// its upgraded version and returns all the changes between the two.
+    pub fn diff(&self, new: &StorageLayout) -> Vec<StorageLayoutChange> {
+        let mut changes = vec![];
+        for old_field in &self.fields {
+            let Some(new_field) = new.field(&old_field.name) else {
+                changes.push(StorageLayoutChange::Removed {
+                    name: old_field.name.clone(),
+                });
+                continue;
+            };
+            if old_field.type_name != new_field.type_name {
+                changes.push(StorageLayoutChange::TypeChanged {
+                    name: new_field.name.clone(),
+                    old_type: old_field.type_name.clone(),
+                    new_type: new_field.type_name.clone(),
+                });
+            }
+            if old_field.key != new_field.key {
+                changes.push(StorageLayoutChange::KeyChanged {
+                    name: new_field.name.clone(),
+                    old_key: old_field.key.clone(),
+                    new_key: new_field.key.clone(),
+                });
+            }
+            if old_field.size_in_bytes != new_field.size_in_bytes {
+                changes.push(StorageLayoutChange::SizeChanged {
+                    name: new_field.name.clone(),
+                    old_size: old_field.size_in_bytes,
+                    new_size: new_field.size_in_bytes,
+                });
+            }
+        }
+
+        let old_slot_owners = self
+            .fields
+            .iter()
+            .flat_map(|field| field.slots.iter().map(move |slot| (slot, &field.name)))
+            .collect::<std::collections::HashMap<_, _>>();
+        for new_field in &new.fields {
+            if self.field(&new_field.name).is_none() {
+                changes.push(StorageLayoutChange::Added {
+                    name: new_field.name.clone(),
+                });
+            }
+            if let Some((slot, old_name)) = new_field.slots.iter().find_map(|slot| {
+                old_slot_owners
+                    .get(slot)
+                    .filter(|old_name| ***old_name != new_field.name)
+                    .map(|old_name| (slot, *old_name))
+            }) {
+                changes.push(StorageLayoutChange::SlotReused {
+                    name: new_field.name.clone(),
+                    slot: slot.clone(),
+                    old_name: old_name.clone(),
+                });
+            }
+        }
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &str, type_name: &str, slots: &[&str]) -> StorageFieldLayout {
+        StorageFieldLayout {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+            key_derivation: StorageKeyDerivation::Explicit,
+            key: slots[0].to_string(),
+            size_in_bytes: 8 * slots.len() as u64,
+            slots: slots.iter().map(|slot| slot.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn storage_layout_diff() {
+        let old = StorageLayout {
+            fields: vec![
+                field("storage.a", "u64", &["0x01"]),
+                field("storage.b", "u64", &["0x02"]),
+                field("storage.c", "u64", &["0x03"]),
+            ],
+        };
+        let new = StorageLayout {
+            fields: vec![
+                field("storage.a", "u64", &["0x01"]),
+                field("storage.b", "bool", &["0x04", "0x05"]),
+                field("storage.d", "u64", &["0x03"]),
+            ],
+        };
+        let changes = old.diff(&new);
+        assert_eq!(
+            changes,
+            vec![
+                StorageLayoutChange::TypeChanged {
+                    name: "storage.b".to_string(),
+                    old_type: "u64".to_string(),
+                    new_type: "bool".to_string(),
+                },
+                StorageLayoutChange::KeyChanged {
+                    name: "storage.b".to_string(),
+                    old_key: "0x02".to_string(),
+                    new_key: "0x04".to_string(),
+                },
+                StorageLayoutChange::SizeChanged {
+                    name: "storage.b".to_string(),
+                    old_size: 8,
+                    new_size: 16,
+                },
+                StorageLayoutChange::Removed {
+                    name: "storage.c".to_string(),
+                },
+                StorageLayoutChange::Added {
+                    name: "storage.d".to_string(),
+                },
+                StorageLayoutChange::SlotReused {
+                    name: "storage.d".to_string(),
+                    slot: "0x03".to_string(),
+                    old_name: "storage.c".to_string(),
+                },
+            ]
+        );
+        assert!(old.diff(&old).is_empty());
+    }
+}
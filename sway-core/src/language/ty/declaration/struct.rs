@@ -13,7 +13,7 @@ use std::{
     cmp::Ordering,
     hash::{Hash, Hasher},
 };
-use sway_types::{Ident, Named, Span, Spanned};
+use sway_types::{integer_bits::IntegerBits, Ident, Named, Span, Spanned};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TyStructDecl {
@@ -137,6 +137,62 @@ impl TyStructDecl {
     pub(crate) fn is_empty(&self) -> bool {
         self.fields.is_empty()
     }
+
+    /// Returns true if the struct is annotated with `#[event]`.
+    pub fn is_event(&self) -> bool {
+        self.attributes
+            .contains_key(&transform::AttributeKind::Event)
+    }
+
+    /// Returns the `#[indexed]` fields of an `#[event]` struct, along with their position within
+    /// the encoded log data.
+    ///
+    /// Indexed fields must precede all other fields and have a fixed size encoding, which is
+    /// checked when the struct is type checked. This makes their offsets in the encoded data
+    /// independent of the values of the remaining fields.
+    pub fn event_indexed_fields(&self, engines: &Engines) -> Vec<EventIndexedField> {
+        let mut offset = 0;
+        self.fields
+            .iter()
+            .take_while(|field| field.is_indexed())
+            .filter_map(|field| {
+                let size = TyStructField::indexed_field_encoding_size(
+                    engines,
+                    field.type_argument.type_id,
+                )?;
+                let indexed_field = EventIndexedField {
+                    name: field.name.as_str().to_string(),
+                    type_name: engines.help_out(field.type_argument.type_id).to_string(),
+                    offset,
+                    size,
+                };
+                offset += size;
+                Some(indexed_field)
+            })
+            .collect()
+    }
+}
+
+/// An `#[indexed]` field of a struct annotated with `#[event]`.
+///
+/// Indexers can read the value of the field directly from the `size` bytes at `offset` in the
+/// data of the log, without decoding the whole event.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EventIndexedField {
+    pub name: String,
+    pub type_name: String,
+    pub offset: u64,
+    pub size: u64,
+}
+
+/// A logged `#[event]` struct, published next to the ABI so that indexers can filter events
+/// on their indexed fields.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EventDescriptor {
+    pub name: String,
+    /// The log id of the event, as found in the `logged_types` of the ABI.
+    pub log_id: String,
+    pub indexed_fields: Vec<EventIndexedField>,
 }
 
 /// Provides information about the struct access within a particular [Namespace].
@@ -189,6 +245,29 @@ pub struct TyStructField {
 }
 
 impl TyStructField {
+    /// Returns true if the field is annotated with `#[indexed]`.
+    pub fn is_indexed(&self) -> bool {
+        self.attributes
+            .contains_key(&transform::AttributeKind::Indexed)
+    }
+
+    /// Returns the size of the encoding of a type that can be used for an `#[indexed]` field,
+    /// or `None` if the type cannot be indexed because its encoding is not of a fixed size.
+    pub(crate) fn indexed_field_encoding_size(engines: &Engines, type_id: TypeId) -> Option<u64> {
+        match &*engines.te().get(type_id) {
+            TypeInfo::Boolean => Some(1),
+            TypeInfo::UnsignedInteger(bits) => Some(match bits {
+                IntegerBits::Eight => 1,
+                IntegerBits::Sixteen => 2,
+                IntegerBits::ThirtyTwo => 4,
+                IntegerBits::SixtyFour => 8,
+                IntegerBits::V256 => 32,
+            }),
+            TypeInfo::B256 => Some(32),
+            _ => None,
+        }
+    }
+
     pub fn is_private(&self) -> bool {
         matches!(self.visibility, Visibility::Private)
     }
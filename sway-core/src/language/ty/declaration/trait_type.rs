@@ -2,6 +2,7 @@ use crate::{
     engine_threading::*, has_changes, language::parsed::TraitTypeDeclaration,
     language::ty::TyDeclParsedType, transform, type_system::*,
 };
+use monomorphization::MonomorphizeHelper;
 use serde::{Deserialize, Serialize};
 use std::{
     fmt,
@@ -12,6 +13,8 @@ use sway_types::{Ident, Named, Span, Spanned};
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TyTraitType {
     pub name: Ident,
+    /// The generic parameters of a generic associated type, e.g. `T` in `type Output<T>;`.
+    pub type_parameters: Vec<TypeParameter>,
     pub attributes: transform::AttributesMap,
     pub ty: Option<TypeArgument>,
     pub implementing_type: TypeId,
@@ -48,6 +51,7 @@ impl EqWithEngines for TyTraitType {}
 impl PartialEqWithEngines for TyTraitType {
     fn eq(&self, other: &Self, ctx: &PartialEqWithEnginesContext) -> bool {
         self.name == other.name
+            && self.type_parameters.eq(&other.type_parameters, ctx)
             && self.ty.eq(&other.ty, ctx)
             && self.implementing_type.eq(&other.implementing_type)
     }
@@ -57,6 +61,7 @@ impl HashWithEngines for TyTraitType {
     fn hash<H: Hasher>(&self, state: &mut H, engines: &Engines) {
         let TyTraitType {
             name,
+            type_parameters,
             ty,
             implementing_type,
             // these fields are not hashed because they aren't relevant/a
@@ -65,6 +70,7 @@ impl HashWithEngines for TyTraitType {
             attributes: _,
         } = self;
         name.hash(state);
+        type_parameters.hash(state, engines);
         ty.hash(state, engines);
         implementing_type.hash(state);
     }
@@ -73,12 +79,27 @@ impl HashWithEngines for TyTraitType {
 impl SubstTypes for TyTraitType {
     fn subst_inner(&mut self, ctx: &SubstTypesContext) -> HasChanges {
         has_changes! {
+            self.type_parameters.subst(ctx);
             self.ty.subst(ctx);
             self.implementing_type.subst(ctx);
         }
     }
 }
 
+impl MonomorphizeHelper for TyTraitType {
+    fn type_parameters(&self) -> &[TypeParameter] {
+        &self.type_parameters
+    }
+
+    fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    fn has_self_type_param(&self) -> bool {
+        false
+    }
+}
+
 impl Spanned for TyTraitType {
     fn span(&self) -> Span {
         self.span.clone()
@@ -8,3 +8,54 @@ pub struct ContractCallParams {
     pub(crate) contract_address: Box<TyExpression>,
     pub(crate) contract_caller: Box<TyExpression>,
 }
+
+/// An external contract call a program can make, published next to the ABI so that auditors
+/// and indexers can build the call graph of a whole dapp.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ContractCallDescriptor {
+    /// The function making the call, prefixed with the implementing type for methods.
+    pub caller: String,
+    /// The source location of the call, as `path:line:column`.
+    pub location: String,
+    /// The name of the ABI the contract is called through, if known.
+    pub abi: Option<String>,
+    /// The name of the called ABI method.
+    pub method: String,
+    /// The function selector of the old encoding, or the method name the new encoding
+    /// dispatches on.
+    pub selector: String,
+    /// The contract ID the call is made to.
+    pub target: ContractCallTarget,
+    /// Whether the called method is `#[payable]`.
+    pub payable: bool,
+    /// Whether the called method reads the storage of the called contract.
+    pub reads_storage: bool,
+    /// Whether the called method writes the storage of the called contract.
+    pub writes_storage: bool,
+    /// Whether the call forwards coins, i.e. specifies the `coins` call parameter.
+    pub forwards_coins: bool,
+    /// Whether the call specifies the `asset_id` call parameter.
+    pub forwards_asset_id: bool,
+    /// Whether the call limits the forwarded gas, i.e. specifies the `gas` call parameter.
+    pub limits_gas: bool,
+}
+
+/// Where the contract ID of an external contract call comes from.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ContractCallTarget {
+    /// A literal contract ID, known at compile time.
+    Literal { value: String },
+    /// A constant.
+    Constant { name: String },
+    /// A configurable, set at deployment time.
+    Configurable { name: String },
+    /// A storage field of the calling contract.
+    Storage { name: String },
+    /// A parameter of the calling function.
+    Parameter { name: String },
+    /// A mutable variable of the calling function.
+    Variable { name: String },
+    /// Any other expression, e.g. the value returned by a function.
+    Expression,
+}
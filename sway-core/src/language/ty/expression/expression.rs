@@ -248,7 +248,7 @@ impl CollectTypesMetadata for TyExpression {
                 res.append(&mut (**prefix).collect_types_metadata(handler, ctx)?);
                 res.append(&mut (**index).collect_types_metadata(handler, ctx)?);
             }
-            CodeBlock(block) => {
+            CodeBlock(block) | LabeledBlock { block, .. } => {
                 for content in block.contents.iter() {
                     res.append(&mut content.collect_types_metadata(handler, ctx)?);
                 }
@@ -330,7 +330,9 @@ impl CollectTypesMetadata for TyExpression {
                         .collect_types_metadata(handler, ctx)?,
                 );
             }
-            WhileLoop { condition, body } => {
+            WhileLoop {
+                condition, body, ..
+            } => {
                 res.append(&mut condition.collect_types_metadata(handler, ctx)?);
                 for content in body.contents.iter() {
                     res.append(&mut content.collect_types_metadata(handler, ctx)?);
@@ -342,6 +344,9 @@ impl CollectTypesMetadata for TyExpression {
             ImplicitReturn(exp) | Return(exp) => {
                 res.append(&mut exp.collect_types_metadata(handler, ctx)?)
             }
+            Break {
+                value: Some(value), ..
+            } => res.append(&mut value.collect_types_metadata(handler, ctx)?),
             Ref(exp) | Deref(exp) => res.append(&mut exp.collect_types_metadata(handler, ctx)?),
             // storage access can never be generic
             // variable expressions don't ever have return types themselves, they're stored in
@@ -352,8 +357,8 @@ impl CollectTypesMetadata for TyExpression {
             | StorageAccess { .. }
             | Literal(_)
             | AbiName(_)
-            | Break
-            | Continue
+            | Break { value: None, .. }
+            | Continue { .. }
             | FunctionParameter => {}
             Reassignment(reassignment) => {
                 res.append(&mut reassignment.rhs.collect_types_metadata(handler, ctx)?);
@@ -158,12 +158,23 @@ pub enum TyExpressionVariant {
     WhileLoop {
         condition: Box<TyExpression>,
         body: TyCodeBlock,
+        label: Option<Ident>,
     },
     ForLoop {
         desugared: Box<TyExpression>,
     },
-    Break,
-    Continue,
+    LabeledBlock {
+        label: Ident,
+        block: TyCodeBlock,
+    },
+    Break {
+        label: Option<Ident>,
+        /// The value of the labeled block that is exited.
+        value: Option<Box<TyExpression>>,
+    },
+    Continue {
+        label: Option<Ident>,
+    },
     Reassignment(Box<TyReassignment>),
     ImplicitReturn(Box<TyExpression>),
     Return(Box<TyExpression>),
@@ -424,12 +435,37 @@ impl PartialEqWithEngines for TyExpressionVariant {
                 Self::WhileLoop {
                     body: l_body,
                     condition: l_condition,
+                    label: l_label,
                 },
                 Self::WhileLoop {
                     body: r_body,
                     condition: r_condition,
+                    label: r_label,
+                },
+            ) => l_body.eq(r_body, ctx) && l_condition.eq(r_condition, ctx) && l_label == r_label,
+            (
+                Self::LabeledBlock {
+                    label: l_label,
+                    block: l_block,
+                },
+                Self::LabeledBlock {
+                    label: r_label,
+                    block: r_block,
+                },
+            ) => l_label == r_label && l_block.eq(r_block, ctx),
+            (
+                Self::Break {
+                    label: l_label,
+                    value: l_value,
+                },
+                Self::Break {
+                    label: r_label,
+                    value: r_value,
                 },
-            ) => l_body.eq(r_body, ctx) && l_condition.eq(r_condition, ctx),
+            ) => l_label == r_label && l_value.eq(r_value, ctx),
+            (Self::Continue { label: l_label }, Self::Continue { label: r_label }) => {
+                l_label == r_label
+            }
             (l, r) => std::mem::discriminant(l) == std::mem::discriminant(r),
         }
     }
@@ -626,14 +662,30 @@ impl HashWithEngines for TyExpressionVariant {
                 exp.hash(state, engines);
                 variant.hash(state, engines);
             }
-            Self::WhileLoop { condition, body } => {
+            Self::WhileLoop {
+                condition,
+                body,
+                label,
+            } => {
                 condition.hash(state, engines);
                 body.hash(state, engines);
+                label.hash(state);
             }
             Self::ForLoop { desugared } => {
                 desugared.hash(state, engines);
             }
-            Self::Break | Self::Continue | Self::FunctionParameter => {}
+            Self::LabeledBlock { label, block } => {
+                label.hash(state);
+                block.hash(state, engines);
+            }
+            Self::Break { label, value } => {
+                label.hash(state);
+                value.hash(state, engines);
+            }
+            Self::Continue { label } => {
+                label.hash(state);
+            }
+            Self::FunctionParameter => {}
             Self::Reassignment(exp) => {
                 exp.hash(state, engines);
             }
@@ -772,13 +824,19 @@ impl SubstTypes for TyExpressionVariant {
             WhileLoop {
                 ref mut condition,
                 ref mut body,
+                ..
             } => {
                 condition.subst(ctx);
                 body.subst(ctx)
             }
             ForLoop { ref mut desugared } => desugared.subst(ctx),
-            Break => HasChanges::No,
-            Continue => HasChanges::No,
+            LabeledBlock { ref mut block, .. } => block.subst(ctx),
+            Break {
+                value: Some(ref mut value),
+                ..
+            } => value.subst(ctx),
+            Break { value: None, .. } => HasChanges::No,
+            Continue { .. } => HasChanges::No,
             Reassignment(reassignment) => reassignment.subst(ctx),
             ImplicitReturn(expr) | Return(expr) => expr.subst(ctx),
             Ref(exp) | Deref(exp) => exp.subst(ctx),
@@ -971,6 +1029,7 @@ impl ReplaceDecls for TyExpressionVariant {
                 WhileLoop {
                     ref mut condition,
                     ref mut body,
+                    ..
                 } => {
                     let mut has_changes = false;
                     if let Ok(r) = condition.replace_decls(decl_mapping, handler, ctx) {
@@ -984,8 +1043,15 @@ impl ReplaceDecls for TyExpressionVariant {
                 ForLoop { ref mut desugared } => {
                     desugared.replace_decls(decl_mapping, handler, ctx)
                 }
-                Break => Ok(false),
-                Continue => Ok(false),
+                LabeledBlock { ref mut block, .. } => {
+                    block.replace_decls(decl_mapping, handler, ctx)
+                }
+                Break {
+                    value: Some(ref mut value),
+                    ..
+                } => value.replace_decls(decl_mapping, handler, ctx),
+                Break { value: None, .. } => Ok(false),
+                Continue { .. } => Ok(false),
                 Reassignment(reassignment) => {
                     reassignment.replace_decls(decl_mapping, handler, ctx)
                 }
@@ -1114,15 +1180,24 @@ impl TypeCheckAnalysis for TyExpressionVariant {
             TyExpressionVariant::UnsafeDowncast { exp, .. } => {
                 exp.type_check_analyze(handler, ctx)?;
             }
-            TyExpressionVariant::WhileLoop { condition, body } => {
+            TyExpressionVariant::WhileLoop {
+                condition, body, ..
+            } => {
                 condition.type_check_analyze(handler, ctx)?;
                 body.type_check_analyze(handler, ctx)?;
             }
             TyExpressionVariant::ForLoop { desugared } => {
                 desugared.type_check_analyze(handler, ctx)?;
             }
-            TyExpressionVariant::Break => {}
-            TyExpressionVariant::Continue => {}
+            TyExpressionVariant::LabeledBlock { block, .. } => {
+                block.type_check_analyze(handler, ctx)?;
+            }
+            TyExpressionVariant::Break { value, .. } => {
+                if let Some(value) = value {
+                    value.type_check_analyze(handler, ctx)?;
+                }
+            }
+            TyExpressionVariant::Continue { .. } => {}
             TyExpressionVariant::Reassignment(node) => {
                 node.type_check_analyze(handler, ctx)?;
             }
@@ -1237,15 +1312,24 @@ impl TypeCheckFinalization for TyExpressionVariant {
                 TyExpressionVariant::UnsafeDowncast { exp, .. } => {
                     exp.type_check_finalize(handler, ctx)?;
                 }
-                TyExpressionVariant::WhileLoop { condition, body } => {
+                TyExpressionVariant::WhileLoop {
+                    condition, body, ..
+                } => {
                     condition.type_check_finalize(handler, ctx)?;
                     body.type_check_finalize(handler, ctx)?;
                 }
                 TyExpressionVariant::ForLoop { desugared } => {
                     desugared.type_check_finalize(handler, ctx)?;
                 }
-                TyExpressionVariant::Break => {}
-                TyExpressionVariant::Continue => {}
+                TyExpressionVariant::LabeledBlock { block, .. } => {
+                    block.type_check_finalize(handler, ctx)?;
+                }
+                TyExpressionVariant::Break { value, .. } => {
+                    if let Some(value) = value {
+                        value.type_check_finalize(handler, ctx)?;
+                    }
+                }
+                TyExpressionVariant::Continue { .. } => {}
                 TyExpressionVariant::Reassignment(node) => {
                     node.type_check_finalize(handler, ctx)?;
                 }
@@ -1348,6 +1432,7 @@ impl UpdateConstantExpression for TyExpressionVariant {
             WhileLoop {
                 ref mut condition,
                 ref mut body,
+                ..
             } => {
                 condition.update_constant_expression(engines, implementing_type);
                 body.update_constant_expression(engines, implementing_type);
@@ -1355,8 +1440,15 @@ impl UpdateConstantExpression for TyExpressionVariant {
             ForLoop { ref mut desugared } => {
                 desugared.update_constant_expression(engines, implementing_type);
             }
-            Break => (),
-            Continue => (),
+            LabeledBlock { ref mut block, .. } => {
+                block.update_constant_expression(engines, implementing_type);
+            }
+            Break {
+                value: Some(ref mut value),
+                ..
+            } => value.update_constant_expression(engines, implementing_type),
+            Break { value: None, .. } => (),
+            Continue { .. } => (),
             Reassignment(reassignment) => {
                 reassignment.update_constant_expression(engines, implementing_type)
             }
@@ -1510,8 +1602,13 @@ impl DebugWithEngines for TyExpressionVariant {
                 format!("while loop on {:?}", engines.help_out(&**condition))
             }
             TyExpressionVariant::ForLoop { .. } => "for loop".to_string(),
-            TyExpressionVariant::Break => "break".to_string(),
-            TyExpressionVariant::Continue => "continue".to_string(),
+            TyExpressionVariant::LabeledBlock { label, .. } => format!("'{label}: block"),
+            TyExpressionVariant::Break { label: None, .. } => "break".to_string(),
+            TyExpressionVariant::Break {
+                label: Some(label), ..
+            } => format!("break '{label}"),
+            TyExpressionVariant::Continue { label: None } => "continue".to_string(),
+            TyExpressionVariant::Continue { label: Some(label) } => format!("continue '{label}"),
             TyExpressionVariant::Reassignment(reassignment) => {
                 let target = match &reassignment.lhs {
                     TyReassignmentTarget::Deref(exp) => format!("{:?}", engines.help_out(exp)),
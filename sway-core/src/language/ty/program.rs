@@ -24,7 +24,13 @@ pub struct TyProgram {
     pub declarations: Vec<TyDecl>,
     pub configurables: Vec<TyConfigurableDecl>,
     pub storage_slots: Vec<StorageSlot>,
+    pub storage_layout: StorageLayout,
+    pub error_selectors: Vec<ErrorSelector>,
     pub logged_types: Vec<(LogId, TypeId)>,
+    /// The logged `#[event]` structs, collected from the `logged_types`.
+    pub events: Vec<EventDescriptor>,
+    /// The external contract calls the program can make.
+    pub contract_calls: Vec<ContractCallDescriptor>,
     pub messages_types: Vec<(MessageId, TypeId)>,
 }
 
@@ -424,6 +430,74 @@ impl TyProgram {
         Ok((typed_program_kind, declarations, configurables))
     }
 
+    /// Collects the error selectors of all `#[error_type]` enums declared in the modules of the
+    /// program, and checks that no two variants share the same selector.
+    pub(crate) fn collect_error_selectors(
+        handler: &Handler,
+        engines: &Engines,
+        root: &TyModule,
+    ) -> Result<Vec<ErrorSelector>, ErrorEmitted> {
+        let decl_engine = engines.de();
+        handler.scope(|handler| {
+            let mut selectors: Vec<ErrorSelector> = vec![];
+            let modules = std::iter::once(root).chain(
+                root.submodules_recursive()
+                    .map(|(_, submod)| submod.module.as_ref()),
+            );
+            for node in modules.flat_map(|module| module.all_nodes.iter()) {
+                let TyAstNodeContent::Declaration(TyDecl::EnumDecl(EnumDecl { decl_id })) =
+                    &node.content
+                else {
+                    continue;
+                };
+                let enum_decl = decl_engine.get_enum(decl_id);
+                for selector in enum_decl.error_selectors() {
+                    if let Some(existing) = selectors
+                        .iter()
+                        .find(|existing| existing.selector == selector.selector)
+                    {
+                        handler.emit_err(CompileError::DuplicatedErrorSelector {
+                            first_variant: format!(
+                                "{}::{}",
+                                existing.enum_path, existing.variant_name
+                            ),
+                            first_span: existing.span.clone(),
+                            second_variant: format!(
+                                "{}::{}",
+                                selector.enum_path, selector.variant_name
+                            ),
+                            second_span: selector.span.clone(),
+                            selector: selector.selector,
+                        });
+                    }
+                    selectors.push(selector);
+                }
+            }
+            Ok(selectors)
+        })
+    }
+
+    /// Describes the `#[event]` structs among the logged types of the program.
+    pub(crate) fn collect_events(&self, engines: &Engines) -> Vec<EventDescriptor> {
+        let mut events: Vec<EventDescriptor> = vec![];
+        for (log_id, type_id) in self.logged_types.iter() {
+            let TypeInfo::Struct(decl_id) = &*engines.te().get(*type_id) else {
+                continue;
+            };
+            let struct_decl = engines.de().get_struct(decl_id);
+            let log_id = log_id.hash_id.to_string();
+            if !struct_decl.is_event() || events.iter().any(|event| event.log_id == log_id) {
+                continue;
+            }
+            events.push(EventDescriptor {
+                name: engines.help_out(*type_id).to_string(),
+                log_id,
+                indexed_fields: struct_decl.event_indexed_fields(engines),
+            });
+        }
+        events
+    }
+
     /// All test function declarations within the program.
     pub fn test_fns<'a: 'b, 'b>(
         &'b self,
@@ -7,10 +7,14 @@ pub mod error;
 pub mod engine_threading;
 
 pub mod abi_generation;
+pub mod analysis;
 pub mod asm_generation;
 mod asm_lang;
 mod build_config;
 pub mod compiler_generated;
+#[cfg(feature = "bench")]
+pub mod concurrent_slab;
+#[cfg(not(feature = "bench"))]
 mod concurrent_slab;
 mod control_flow_analysis;
 mod debug_generation;
@@ -30,28 +34,34 @@ use crate::source_map::SourceMap;
 pub use asm_generation::from_ir::compile_ir_context_to_finalized_asm;
 use asm_generation::FinalizedAsm;
 pub use asm_generation::{CompiledBytecode, FinalizedEntry};
-pub use build_config::{BuildConfig, BuildTarget, LspConfig, OptLevel, PrintAsm, PrintIr};
+pub use build_config::{
+    BuildConfig, BuildTarget, InlineThresholds, IrPassOverrides, LoopUnrollThresholds, LspConfig,
+    OptLevel, PrintAsm, PrintIr, VerifyIr, VerifyIrLevel,
+};
 use control_flow_analysis::ControlFlowGraph;
 pub use debug_generation::write_dwarf;
 use indexmap::IndexMap;
 use metadata::MetadataManager;
-use query_engine::{ModuleCacheKey, ModuleCommonInfo, ParsedModuleInfo, ProgramsCacheEntry};
+use query_engine::{
+    DeclFingerprints, ModuleCacheKey, ModuleCommonInfo, ParsedModuleInfo, ProgramsCacheEntry,
+};
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use sway_ast::AttributeDecl;
+use sway_ast::{attribute::Annotated, AttributeDecl, ItemKind};
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_features::ExperimentalFeatures;
 use sway_ir::{
-    create_o1_pass_group, register_known_passes, Context, Kind, Module, PassGroup, PassManager,
-    PrintPassesOpts, ARG_DEMOTION_NAME, CONST_DEMOTION_NAME, DCE_NAME, FN_DCE_NAME,
-    FN_DEDUP_DEBUG_PROFILE_NAME, FN_INLINE_NAME, MEM2REG_NAME, MEMCPYOPT_NAME, MISC_DEMOTION_NAME,
-    RET_DEMOTION_NAME, SIMPLIFY_CFG_NAME, SROA_NAME,
+    create_o1_pass_group, register_known_passes, Context, InlineHeuristics, Kind, Module,
+    PassGroup, PassManager, PrintPassesOpts, VerifyPassesOpts, ARG_DEMOTION_NAME,
+    CONST_DEMOTION_NAME, DCE_NAME, FN_DCE_NAME, FN_DEDUP_DEBUG_PROFILE_NAME, FN_INLINE_NAME,
+    LOOP_REDUCE_NAME, MEM2REG_NAME, MEMCPYOPT_NAME, MISC_DEMOTION_NAME, RET_DEMOTION_NAME,
+    SIMPLIFY_CFG_NAME, SROA_NAME,
 };
 use sway_types::constants::DOC_COMMENT_ATTRIBUTE_NAME;
-use sway_types::SourceEngine;
+use sway_types::{SourceEngine, SourceId};
 use sway_utils::{time_expr, PerformanceData, PerformanceMetric};
 use transform::{Attribute, AttributeArg, AttributeKind, AttributesMap};
 use types::{CollectTypesMetadata, CollectTypesMetadataContext, TypeMetadata};
@@ -71,7 +81,7 @@ pub mod fuel_prelude {
     pub use fuel_vm::{self, fuel_asm, fuel_crypto, fuel_tx, fuel_types};
 }
 
-pub use engine_threading::Engines;
+pub use engine_threading::{Engines, EnginesMemoryReport};
 
 /// Given an input `Arc<str>` and an optional [BuildConfig], parse the input into a [lexed::LexedProgram] and [parsed::ParseProgram].
 ///
@@ -189,6 +199,21 @@ fn module_attrs_to_map(
     Ok(AttributesMap::new(Arc::new(attrs_map)))
 }
 
+/// Parses the source of a single file, expanding its macros if declarative macros are enabled,
+/// and rejecting them otherwise.
+fn parse_file(
+    handler: &Handler,
+    src: Arc<str>,
+    source_id: Option<SourceId>,
+    experimental: ExperimentalFeatures,
+) -> Result<Annotated<sway_ast::Module>, ErrorEmitted> {
+    if experimental.declarative_macros {
+        sway_parse::parse_file_expanding_macros(handler, src, source_id)
+    } else {
+        sway_parse::parse_file_rejecting_macros(handler, src, source_id)
+    }
+}
+
 /// When no `BuildConfig` is given, we're assumed to be parsing in-memory with no submodules.
 fn parse_in_memory(
     handler: &Handler,
@@ -199,7 +224,7 @@ fn parse_in_memory(
     let mut hasher = DefaultHasher::new();
     src.hash(&mut hasher);
     let hash = hasher.finish();
-    let module = sway_parse::parse_file(handler, src, None)?;
+    let module = parse_file(handler, src, None, experimental)?;
 
     let (kind, tree) = to_parsed_lang::convert_parse_tree(
         &mut to_parsed_lang::Context::new(BuildTarget::EVM, experimental),
@@ -343,7 +368,7 @@ fn parse_module_tree(
     // Parse this module first.
     let module_dir = path.parent().expect("module file has no parent directory");
     let source_id = engines.se().get_source_id(&path.clone());
-    let module = sway_parse::parse_file(handler, src.clone(), Some(source_id))?;
+    let module = parse_file(handler, src.clone(), Some(source_id), experimental)?;
 
     // Parse all submodules before converting to the `ParseTree`.
     // This always recovers on parse errors for the file itself by skipping that file.
@@ -368,6 +393,7 @@ fn parse_module_tree(
     )?;
     let module_kind_span = module.value.kind.span();
     let attributes = module_attrs_to_map(handler, &module.attribute_list)?;
+    let fingerprints = module_decl_fingerprints(&module.value);
 
     let lexed_submodules = submodules
         .iter()
@@ -410,6 +436,7 @@ fn parse_module_tree(
         include_tests,
         dependencies,
         hash,
+        fingerprints: Arc::new(fingerprints),
     };
     let parsed_info = ParsedModuleInfo {
         modified_time,
@@ -425,10 +452,47 @@ fn parse_module_tree(
     })
 }
 
+/// Hashes the source of the top level declarations of `module`, see [DeclFingerprints].
+fn module_decl_fingerprints(module: &sway_ast::Module) -> DeclFingerprints {
+    let mut fingerprints = DeclFingerprints::default();
+    let mut unnamed_hasher = DefaultHasher::new();
+    for item in &module.items {
+        let name = match &item.value {
+            ItemKind::Struct(item_struct) => Some(&item_struct.name),
+            ItemKind::Enum(item_enum) => Some(&item_enum.name),
+            ItemKind::Fn(item_fn) => Some(&item_fn.fn_signature.name),
+            ItemKind::Trait(item_trait) => Some(&item_trait.name),
+            ItemKind::Abi(item_abi) => Some(&item_abi.name),
+            ItemKind::Const(item_const) => Some(&item_const.name),
+            ItemKind::TypeAlias(item_type_alias) => Some(&item_type_alias.name),
+            ItemKind::Submodule(_)
+            | ItemKind::Use(_)
+            | ItemKind::Impl(_)
+            | ItemKind::Storage(_)
+            | ItemKind::Configurable(_)
+            | ItemKind::Error(..) => None,
+        };
+        match name {
+            Some(name) => {
+                let mut hasher = DefaultHasher::new();
+                item.span().as_str().hash(&mut hasher);
+                fingerprints
+                    .named
+                    .insert(name.as_str().to_string(), hasher.finish());
+            }
+            None => item.span().as_str().hash(&mut unnamed_hasher),
+        }
+    }
+    fingerprints.unnamed = unnamed_hasher.finish();
+    fingerprints
+}
+
 /// Checks if the typed module cache for a given path is up to date.
 ///
 /// This function determines whether the cached typed representation of a module
-/// is still valid based on file versions and dependencies.
+/// is still valid based on file versions and dependencies. A module only depends on
+/// the declarations it uses from other modules, its submodules included, so changes
+/// to other declarations of those modules keep the cache valid.
 ///
 /// Note: This functionality is currently only supported when the compiler is
 /// initiated from the language server.
@@ -450,11 +514,13 @@ pub(crate) fn is_ty_module_cache_up_to_date(
                     version.map_or(true, |v| typed.version.map_or(false, |tv| v <= tv))
                 });
 
-            // If the cache is up to date, recursively check all dependencies
+            // If the cache is up to date, check the declarations used from other modules,
+            // including the submodules, which are type checked on their own
             cache_up_to_date
-                && entry.common.dependencies.iter().all(|dep_path| {
-                    is_ty_module_cache_up_to_date(engines, dep_path, include_tests, build_config)
-                })
+                && typed
+                    .decl_dependencies
+                    .iter()
+                    .all(|dep| cache.is_decl_dependency_up_to_date(dep))
         })
     })
 }
@@ -621,6 +687,11 @@ pub fn parsed_to_ast(
                 _ => None,
             }));
 
+        // The offsets of the indexed fields are only known for the new encoding.
+        if experimental.new_encoding {
+            typed_program.events = typed_program.collect_events(engines);
+        }
+
         typed_program
             .messages_types
             .extend(types_metadata.iter().filter_map(|m| match m {
@@ -673,6 +744,24 @@ pub fn parsed_to_ast(
         handler.emit_warn(warn);
     }
 
+    typed_program.contract_calls =
+        semantic_analysis::contract_call_analysis::analyze_program(engines, &typed_program);
+
+    // Taint analysis of external inputs
+    if build_config.is_some_and(|config| config.taint_analysis) {
+        for warn in control_flow_analysis::taint_analysis::analyze_program(engines, &typed_program)
+        {
+            handler.emit_warn(warn);
+        }
+    }
+
+    // Third-party analysis passes
+    if let Some(build_config) = build_config {
+        build_config
+            .analysis_passes
+            .run_on_typed_program(handler, engines, &typed_program);
+    }
+
     // Check that all storage initializers can be evaluated at compile time.
     let typed_wiss_res = typed_program.get_typed_program_with_initialized_storage_slots(
         handler,
@@ -887,11 +976,68 @@ pub(crate) fn compile_ast_to_ir_to_asm(
         }
     };
 
+    // Report the duplicated instantiations of generic functions before the optimizations merge or
+    // inline them.
+    ir_generation::check_monomorphization(
+        handler,
+        &ir,
+        build_config.monomorphization_report,
+        build_config.monomorphization_budget,
+    )?;
+
+    // Third-party analysis passes see the IR before it is optimized.
+    build_config
+        .analysis_passes
+        .run_on_ir(handler, engines, &ir);
+
+    optimize_ir_to_asm(handler, &mut ir, build_config)
+}
+
+/// Given the textual form of an IR module, as printed by `--ir`, try compiling it to a
+/// `CompiledAsm`, running the same passes as when compiling Sway source code.
+pub fn compile_ir_to_asm(
+    handler: &Handler,
+    engines: &Engines,
+    ir_input: &str,
+    build_config: &BuildConfig,
+    experimental: ExperimentalFeatures,
+) -> Result<CompiledAsm, ErrorEmitted> {
+    let mut ir = sway_ir::parser::parse(ir_input, engines.se(), experimental).map_err(|err| {
+        handler.emit_err(CompileError::InternalOwned(
+            err.to_string(),
+            span::Span::dummy(),
+        ))
+    })?;
+    let asm = optimize_ir_to_asm(handler, &mut ir, build_config).map_err(|err| {
+        handler.dedup();
+        err
+    })?;
+    Ok(CompiledAsm(asm))
+}
+
+/// Check the purity of the entry points of `ir`, optimize it and compile it to asm.
+fn optimize_ir_to_asm(
+    handler: &Handler,
+    ir: &mut Context,
+    build_config: &BuildConfig,
+) -> Result<FinalizedAsm, ErrorEmitted> {
+    // Annotate the calls that were not inlined if the IR after inlining is going to be printed.
+    ir.inline_heuristics = InlineHeuristics {
+        annotate_decisions: build_config.print_ir.r#final
+            || build_config
+                .print_ir
+                .passes
+                .iter()
+                .any(|pass| pass == FN_INLINE_NAME),
+        ..build_config.inline_heuristics
+    };
+    ir.loop_unroll_heuristics = build_config.loop_unroll_heuristics;
+
     // Find all the entry points for purity checking and DCE.
     let entry_point_functions: Vec<::sway_ir::Function> = ir
         .module_iter()
-        .flat_map(|module| module.function_iter(&ir))
-        .filter(|func| func.is_entry(&ir))
+        .flat_map(|module| module.function_iter(ir))
+        .filter(|func| func.is_entry(ir))
         .collect();
 
     // Do a purity check on the _unoptimised_ IR.
@@ -899,7 +1045,7 @@ pub(crate) fn compile_ast_to_ir_to_asm(
         let mut env = ir_generation::PurityEnv::default();
         let mut md_mgr = metadata::MetadataManager::default();
         for entry_point in &entry_point_functions {
-            check_function_purity(handler, &mut env, &ir, &mut md_mgr, entry_point);
+            check_function_purity(handler, &mut env, ir, &mut md_mgr, entry_point);
         }
     }
 
@@ -926,6 +1072,19 @@ pub(crate) fn compile_ast_to_ir_to_asm(
         }
     }
 
+    // Apply the pass adjustments of the build profile.
+    for pass in &build_config.ir_passes.enable {
+        if let Some(pass) = PassManager::OPTIMIZATION_PASSES
+            .into_iter()
+            .find(|known| *known == pass)
+        {
+            pass_group.append_pass(pass);
+        }
+    }
+    for pass in &build_config.ir_passes.disable {
+        pass_group.remove_pass(pass);
+    }
+
     // Target specific transforms should be moved into something more configured.
     if build_config.build_target == BuildTarget::Fuel {
         // FuelVM target specific transforms.
@@ -949,6 +1108,17 @@ pub(crate) fn compile_ast_to_ir_to_asm(
                 pass_group.append_pass(SROA_NAME);
                 pass_group.append_pass(MEM2REG_NAME);
                 pass_group.append_pass(DCE_NAME);
+
+                // Strength reduction introduces pointer arithmetic, which the memory
+                // optimizations above can't see through, so it runs last.
+                if !build_config
+                    .ir_passes
+                    .disable
+                    .iter()
+                    .any(|pass| pass == LOOP_REDUCE_NAME)
+                {
+                    pass_group.append_pass(LOOP_REDUCE_NAME);
+                }
             }
             OptLevel::Opt0 => {}
         }
@@ -956,18 +1126,23 @@ pub(crate) fn compile_ast_to_ir_to_asm(
 
     // Run the passes.
     let print_passes_opts: PrintPassesOpts = (&build_config.print_ir).into();
-    let res =
-        if let Err(ir_error) = pass_mgr.run_with_print(&mut ir, &pass_group, &print_passes_opts) {
-            Err(handler.emit_err(CompileError::InternalOwned(
-                ir_error.to_string(),
-                span::Span::dummy(),
-            )))
-        } else {
-            Ok(())
-        };
+    let verify_passes_opts: VerifyPassesOpts = (&build_config.verify_ir).into();
+    let res = if let Err(ir_error) = pass_mgr.run_with_print_and_verify(
+        ir,
+        &pass_group,
+        &print_passes_opts,
+        &verify_passes_opts,
+    ) {
+        Err(handler.emit_err(CompileError::InternalOwned(
+            ir_error.to_string(),
+            span::Span::dummy(),
+        )))
+    } else {
+        Ok(())
+    };
     res?;
 
-    compile_ir_context_to_finalized_asm(handler, &ir, Some(build_config))
+    compile_ir_context_to_finalized_asm(handler, ir, Some(build_config))
 }
 
 /// Given input Sway source code, compile to [CompiledBytecode], containing the asm in bytecode form.
@@ -1,6 +1,6 @@
 use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     ops::{Deref, DerefMut},
     path::PathBuf,
     sync::Arc,
@@ -36,6 +36,41 @@ pub struct ModuleCommonInfo {
     pub hash: u64,
     pub include_tests: bool,
     pub dependencies: Vec<Arc<PathBuf>>,
+    pub fingerprints: Arc<DeclFingerprints>,
+}
+
+/// Hashes of the source of the top level declarations of a module.
+///
+/// These allow a typed module to stay cached when a module it imports from changes, as long as
+/// the declarations it actually uses remain the same.
+#[derive(Clone, Debug, Default)]
+pub struct DeclFingerprints {
+    /// Hashes of the named declarations, keyed by name.
+    pub named: HashMap<String, u64>,
+    /// Combined hash of all the other items, like `impl` blocks and `use` statements.  Trait
+    /// implementations apply wherever the implemented types are used, so every module importing
+    /// from this one depends on them.
+    pub unnamed: u64,
+}
+
+impl DeclFingerprints {
+    /// Get the fingerprint of the declaration `name`, or of the unnamed items if `None`.
+    pub fn get(&self, name: Option<&str>) -> Option<u64> {
+        match name {
+            Some(name) => self.named.get(name).copied(),
+            None => Some(self.unnamed),
+        }
+    }
+}
+
+/// A declaration of another module a typed module depends on, with its fingerprint at the time
+/// the module was type checked.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DeclDependency {
+    pub path: Arc<PathBuf>,
+    /// The name of the declaration, or `None` for the unnamed items of the module.
+    pub name: Option<String>,
+    pub fingerprint: u64,
 }
 
 #[derive(Clone, Debug)]
@@ -48,6 +83,8 @@ pub struct ParsedModuleInfo {
 pub struct TypedModuleInfo {
     pub module: Arc<TyModule>,
     pub version: Option<u64>,
+    /// The declarations of other modules used by this module.
+    pub decl_dependencies: Vec<DeclDependency>,
 }
 
 #[derive(Clone, Debug)]
@@ -121,10 +158,30 @@ impl ModuleCacheMap {
             self.insert(key.clone(), ModuleCacheEntry::new(new_common, new_parsed));
         }
     }
+
+    /// Get the current fingerprint of the declaration `name` of the module at `path`, see
+    /// [DeclFingerprints::get].
+    ///
+    /// Dependencies may have been compiled with or without tests, so both entries are looked up.
+    pub fn decl_fingerprint(&self, path: &Arc<PathBuf>, name: Option<&str>) -> Option<u64> {
+        [false, true].into_iter().find_map(|include_tests| {
+            self.get(&ModuleCacheKey::new(path.clone(), include_tests))
+                .and_then(|entry| entry.common.fingerprints.get(name))
+        })
+    }
+
+    /// Is the dependency `dep` still the same as when it was recorded?
+    pub fn is_decl_dependency_up_to_date(&self, dep: &DeclDependency) -> bool {
+        self.decl_fingerprint(&dep.path, dep.name.as_deref()) == Some(dep.fingerprint)
+    }
 }
 
 pub type ProgramsCacheMap = HashMap<Arc<PathBuf>, ProgramsCacheEntry>;
 pub type FunctionsCacheMap = HashMap<(IdentUnique, String), FunctionCacheEntry>;
+/// Monomorphized functions, keyed by the generic function and the concrete type arguments
+/// it was monomorphized with.
+pub type MonomorphizationCacheMap =
+    HashMap<(DeclId<TyFunctionDecl>, Vec<String>), FunctionCacheEntry>;
 
 #[derive(Clone, Debug)]
 pub struct ProgramsCacheEntry {
@@ -145,6 +202,7 @@ pub struct QueryEngine {
     pub module_cache: CowCache<ModuleCacheMap>,
     // NOTE: Any further AstNodes that are cached need to have garbage collection applied, see clear_module()
     function_cache: CowCache<FunctionsCacheMap>,
+    monomorphization_cache: CowCache<MonomorphizationCacheMap>,
 }
 
 impl Clone for QueryEngine {
@@ -153,6 +211,7 @@ impl Clone for QueryEngine {
             programs_cache: CowCache::new(self.programs_cache.read().clone()),
             module_cache: CowCache::new(self.module_cache.read().clone()),
             function_cache: CowCache::new(self.function_cache.read().clone()),
+            monomorphization_cache: CowCache::new(self.monomorphization_cache.read().clone()),
         }
     }
 }
@@ -207,14 +266,45 @@ impl QueryEngine {
         );
     }
 
-    /// Removes all data associated with the `source_id` from the function cache.
+    pub fn get_monomorphized_function(
+        &self,
+        generic_fn_decl: DeclId<TyFunctionDecl>,
+        type_arguments: &[String],
+    ) -> Option<DeclRef<DeclId<TyFunctionDecl>>> {
+        let cache = self.monomorphization_cache.read();
+        cache
+            .get(&(generic_fn_decl, type_arguments.to_vec()))
+            .map(|s| s.fn_decl.clone())
+    }
+
+    pub fn insert_monomorphized_function(
+        &self,
+        generic_fn_decl: DeclId<TyFunctionDecl>,
+        type_arguments: Vec<String>,
+        fn_decl: DeclRef<DeclId<TyFunctionDecl>>,
+    ) {
+        let mut cache = self.monomorphization_cache.write();
+        cache.insert(
+            (generic_fn_decl, type_arguments),
+            FunctionCacheEntry { fn_decl },
+        );
+    }
+
+    /// Removes all data associated with the `source_id` from the function caches.
     pub fn clear_module(&mut self, source_id: &SourceId) {
         self.function_cache
             .write()
             .retain(|(ident, _), _| ident.span().source_id().map_or(true, |id| id != source_id));
+        self.monomorphization_cache.write().retain(|_, entry| {
+            entry
+                .fn_decl
+                .decl_span()
+                .source_id()
+                .map_or(true, |id| id != source_id)
+        });
     }
 
-    /// Removes all data associated with the `program_id` from the function cache.
+    /// Removes all data associated with the `program_id` from the function caches.
     pub fn clear_program(&mut self, program_id: &ProgramId) {
         self.function_cache.write().retain(|(ident, _), _| {
             ident
@@ -222,6 +312,38 @@ impl QueryEngine {
                 .source_id()
                 .map_or(true, |id| id.program_id() != *program_id)
         });
+        self.monomorphization_cache.write().retain(|_, entry| {
+            entry
+                .fn_decl
+                .decl_span()
+                .source_id()
+                .map_or(true, |id| id.program_id() != *program_id)
+        });
+    }
+
+    /// Removes the cached modules and programs whose root module is located at any of `paths`.
+    pub fn clear_paths(&mut self, paths: &HashSet<PathBuf>) {
+        self.module_cache
+            .write()
+            .retain(|key, _| !paths.contains(key.path.as_ref()));
+        self.programs_cache
+            .write()
+            .retain(|path, _| !paths.contains(path.as_ref()));
+    }
+
+    /// Returns an estimate of the memory held by the caches, in bytes.
+    ///
+    /// Cached modules and programs share their contents with the compiled programs, so only
+    /// the cache entries themselves are accounted for.
+    pub fn memory_usage(&self) -> usize {
+        self.programs_cache.read().capacity()
+            * std::mem::size_of::<(Arc<PathBuf>, ProgramsCacheEntry)>()
+            + self.module_cache.read().capacity()
+                * std::mem::size_of::<(ModuleCacheKey, ModuleCacheEntry)>()
+            + self.function_cache.read().capacity()
+                * std::mem::size_of::<((IdentUnique, String), FunctionCacheEntry)>()
+            + self.monomorphization_cache.read().capacity()
+                * std::mem::size_of::<((DeclId<TyFunctionDecl>, Vec<String>), FunctionCacheEntry)>()
     }
 
     ///  Commits all changes to their respective caches.
@@ -229,6 +351,7 @@ impl QueryEngine {
         self.programs_cache.commit();
         self.module_cache.commit();
         self.function_cache.commit();
+        self.monomorphization_cache.commit();
     }
 }
 
@@ -2,6 +2,7 @@
 pub mod ast_node;
 pub(crate) mod cei_pattern_analysis;
 pub(crate) mod coins_analysis;
+pub(crate) mod contract_call_analysis;
 mod module;
 pub mod namespace;
 mod node_dependencies;
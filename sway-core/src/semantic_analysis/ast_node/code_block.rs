@@ -120,8 +120,8 @@ impl ty::TyCodeBlock {
                             ty::TyAstNodeContent::Expression(ty::TyExpression {
                                 expression:
                                     ty::TyExpressionVariant::Return(_)
-                                    | ty::TyExpressionVariant::Break
-                                    | ty::TyExpressionVariant::Continue,
+                                    | ty::TyExpressionVariant::Break { .. }
+                                    | ty::TyExpressionVariant::Continue { .. },
                                 ..
                             }),
                         ..
@@ -28,6 +28,7 @@ use crate::{
     semantic_analysis::{
         declaration::insert_supertraits_into_namespace, AbiMode, TypeCheckContext,
     },
+    transform::{AttributeKind, AttributesMap},
     TypeId,
 };
 
@@ -136,6 +137,7 @@ impl ty::TyAbiDecl {
                             // with the same name as the current interface method
                             error_on_shadowing_superabi_method(&method.name, &mut ctx);
                             let method = ty::TyTraitFn::type_check(handler, ctx.by_ref(), &method)?;
+                            check_abi_method_overrides(handler, &method.attributes);
                             for param in &method.parameters {
                                 if param.is_reference || param.is_mutable {
                                     handler.emit_err(
@@ -220,6 +222,8 @@ impl ty::TyAbiDecl {
                     ));
                 }
 
+                check_selector_collisions(handler, &ctx, &new_interface_surface, &supertraits);
+
                 // Compared to regular traits, we do not insert recursively methods of ABI supertraits
                 // into the interface surface, we do not want supertrait methods to be available to
                 // the ABI user, only the contract methods can use supertrait methods
@@ -467,3 +471,136 @@ impl TypeCheckFinalization for TyAbiDecl {
         })
     }
 }
+
+/// Checks that the `#[selector]` and `#[method_name]` attributes of an ABI method have valid values.
+fn check_abi_method_overrides(handler: &Handler, attributes: &AttributesMap) {
+    let invalid_override = |kind: &AttributeKind, expected: &str| {
+        let attribute = attributes.get(kind).and_then(|attrs| attrs.last());
+        if let Some(attribute) = attribute {
+            handler.emit_err(CompileError::InvalidAbiMethodOverride {
+                attribute: attribute.name.to_string(),
+                expected: expected.to_string(),
+                span: attribute.span.clone(),
+            });
+        }
+    };
+    if let Some(None) = attributes.selector_override() {
+        invalid_override(
+            &AttributeKind::Selector,
+            "`value = 0x...` with a selector fitting into four bytes",
+        );
+    }
+    if let Some(None) = attributes.method_name_override() {
+        invalid_override(
+            &AttributeKind::MethodName,
+            "`value = \"...\"` with a non-empty method name",
+        );
+    }
+}
+
+/// Checks that the interface methods of an ABI, including the methods inherited from its
+/// super-ABIs, all have distinct function selectors when using the old encoding, or distinct
+/// method names when using the new one, so that a contract call is dispatched to the right method.
+///
+/// Methods with the same name are reported as conflicting super-ABI methods, so only methods with
+/// distinct names but the same truncated selector hash, or the same `#[method_name]`, are reported
+/// here.
+fn check_selector_collisions(
+    handler: &Handler,
+    ctx: &TypeCheckContext,
+    interface_surface: &[ty::TyTraitInterfaceItem],
+    supertraits: &[Supertrait],
+) {
+    let engines = ctx.engines();
+    let decl_engine = engines.de();
+
+    // The methods of each super-ABI, followed by the methods of the ABI itself, which has no id
+    // yet. The collisions within a single super-ABI were already reported when checking it.
+    let mut methods = vec![];
+    let mut visited_superabis = HashSet::new();
+    let mut superabis = supertraits.to_vec();
+    while let Some(supertrait) = superabis.pop() {
+        let Ok(ty::TyDecl::AbiDecl(ty::AbiDecl { decl_id })) =
+            ctx.resolve_call_path(&Handler::default(), &supertrait.name)
+        else {
+            continue;
+        };
+        if !visited_superabis.insert(decl_id) {
+            continue;
+        }
+        let superabi = decl_engine.get_abi(&decl_id);
+        methods.extend(
+            interface_methods(engines, &superabi.interface_surface)
+                .map(|method| (Some(decl_id), method)),
+        );
+        superabis.extend(superabi.supertraits.iter().cloned());
+    }
+    methods.extend(interface_methods(engines, interface_surface).map(|method| (None, method)));
+
+    let collides =
+        |abi: &Option<DeclId<ty::TyAbiDecl>>,
+         method: &ty::TyFunctionDecl,
+         (other_abi, other): &(Option<DeclId<ty::TyAbiDecl>>, Ident)| {
+            *other != method.name && (abi.is_none() || abi != other_abi)
+        };
+
+    if ctx.experimental.new_encoding {
+        let mut method_names = HashMap::<String, (Option<DeclId<ty::TyAbiDecl>>, Ident)>::new();
+        for (abi, method) in methods {
+            let method_name = method.abi_method_name().to_string();
+            match method_names.get(&method_name) {
+                Some(other) if collides(&abi, &method, other) => {
+                    handler.emit_err(CompileError::AbiMethodNameCollision {
+                        span: method.name.span(),
+                        first_method: other.1.to_string(),
+                        second_method: method.name.to_string(),
+                        method_name,
+                    });
+                }
+                Some(_) => {}
+                None => {
+                    method_names.insert(method_name, (abi, method.name.clone()));
+                }
+            }
+        }
+        return;
+    }
+
+    let mut selectors = HashMap::<[u8; 4], (Option<DeclId<ty::TyAbiDecl>>, Ident)>::new();
+    for (abi, method) in methods {
+        // Errors in the method signatures are reported when type checking the methods.
+        let Ok(selector) = method.to_fn_selector_value(&Handler::default(), engines) else {
+            continue;
+        };
+        match selectors.get(&selector) {
+            Some(other) if collides(&abi, &method, other) => {
+                handler.emit_err(CompileError::AbiSelectorCollision {
+                    span: method.name.span(),
+                    first_method: other.1.to_string(),
+                    second_method: method.name.to_string(),
+                    selector: selector.iter().map(|b| format!("{b:02x}")).collect(),
+                });
+            }
+            Some(_) => {}
+            None => {
+                selectors.insert(selector, (abi, method.name.clone()));
+            }
+        }
+    }
+}
+
+/// Returns the methods declared in the interface surface of an ABI.
+fn interface_methods<'a>(
+    engines: &'a Engines,
+    interface_surface: &'a [ty::TyTraitInterfaceItem],
+) -> impl Iterator<Item = ty::TyFunctionDecl> + 'a {
+    interface_surface.iter().filter_map(|item| match item {
+        ty::TyTraitInterfaceItem::TraitFn(decl_ref) => Some(
+            engines
+                .de()
+                .get_trait_fn(decl_ref)
+                .to_dummy_func(AbiMode::NonAbi, None),
+        ),
+        _ => None,
+    })
+}
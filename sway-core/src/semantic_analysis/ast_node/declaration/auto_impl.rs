@@ -66,7 +66,7 @@ where
         r.unwrap()
     }
 
-    fn generate_type_parameters_declaration_code(
+    pub(super) fn generate_type_parameters_declaration_code(
         &self,
         type_parameters: &[TypeParameter],
     ) -> String {
@@ -81,7 +81,7 @@ where
         }
     }
 
-    fn generate_type_parameters_constraints_code(
+    pub(super) fn generate_type_parameters_constraints_code(
         &self,
         type_parameters: &[TypeParameter],
         extra_constraint: &str,
@@ -111,7 +111,7 @@ where
         code
     }
 
-    fn generate_abi_encode_code(
+    pub(super) fn generate_abi_encode_code(
         &self,
         name: &BaseIdent,
         type_parameters: &[TypeParameter],
@@ -142,7 +142,7 @@ where
         }
     }
 
-    fn generate_abi_decode_code(
+    pub(super) fn generate_abi_decode_code(
         &self,
         name: &BaseIdent,
         type_parameters: &[TypeParameter],
@@ -172,7 +172,11 @@ where
         }
     }
 
-    fn generate_abi_encode_struct_body(&self, _engines: &Engines, decl: &TyStructDecl) -> String {
+    pub(super) fn generate_abi_encode_struct_body(
+        &self,
+        _engines: &Engines,
+        decl: &TyStructDecl,
+    ) -> String {
         let mut code = String::new();
 
         for f in decl.fields.iter() {
@@ -185,7 +189,7 @@ where
         code
     }
 
-    fn generate_abi_decode_struct_body(
+    pub(super) fn generate_abi_decode_struct_body(
         &self,
         engines: &Engines,
         decl: &TyStructDecl,
@@ -202,7 +206,7 @@ where
         Some(format!("Self {{ {code} }}"))
     }
 
-    fn generate_abi_decode_enum_body(
+    pub(super) fn generate_abi_decode_enum_body(
         &self,
         engines: &Engines,
         decl: &TyEnumDecl,
@@ -237,7 +241,11 @@ where
         Some(code)
     }
 
-    fn generate_abi_encode_enum_body(&self, engines: &Engines, decl: &TyEnumDecl) -> String {
+    pub(super) fn generate_abi_encode_enum_body(
+        &self,
+        engines: &Engines,
+        decl: &TyEnumDecl,
+    ) -> String {
         if decl.variants.is_empty() {
             return "".into();
         }
@@ -356,7 +364,7 @@ where
         }
     }
 
-    fn parse_impl_trait_to_ty_ast_node(
+    pub(super) fn parse_impl_trait_to_ty_ast_node(
         &mut self,
         engines: &Engines,
         program_id: Option<ProgramId>,
@@ -569,8 +577,9 @@ where
             };
 
             let method_name = decl.name.as_str();
+            let abi_method_name = decl.abi_method_name();
 
-            code.push_str(&format!("if _method_name == \"{method_name}\" {{\n"));
+            code.push_str(&format!("if _method_name == \"{abi_method_name}\" {{\n"));
 
             if args_types == "()" {
                 code.push_str(&format!(
@@ -18,7 +18,8 @@ use crate::{
         CallPath,
     },
     semantic_analysis::*,
-    EnforceTypeArguments, Engines, SubstTypes, TypeArgument, TypeBinding, TypeCheckTypeBinding,
+    AbiEncodeSizeHint, EnforceTypeArguments, Engines, SubstTypes, TypeArgument, TypeBinding,
+    TypeCheckTypeBinding,
 };
 
 impl ty::TyConfigurableDecl {
@@ -134,6 +135,19 @@ impl ty::TyConfigurableDecl {
             })?;
             let (decode_fn_ref, _, _): (crate::decl_engine::DeclRefFunction, _, _) = r?;
 
+            // The value can be set after compilation, so the space reserved for it in the
+            // bytecode must fit every value of the type.
+            if !matches!(
+                type_engine
+                    .get(type_ascription.type_id)
+                    .abi_encode_size_hint(engines),
+                AbiEncodeSizeHint::Exact(_) | AbiEncodeSizeHint::Range(_, _)
+            ) {
+                handler.emit_err(CompileError::CannotBeEvaluatedToConfigurableSizeUnknown {
+                    span: type_ascription.span.clone(),
+                });
+            }
+
             let decode_fn_id = *decode_fn_ref.id();
             let mut decode_fn_decl = (*engines.de().get_function(&decode_fn_id)).clone();
             let decl_mapping = crate::TypeParameter::gather_decl_mapping_from_trait_constraints(
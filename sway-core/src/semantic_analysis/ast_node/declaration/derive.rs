@@ -0,0 +1,294 @@
+//! Implementations of the traits listed in the `#[derive(...)]` attributes of structs and enums.
+//!
+//! Derived implementations are generated as Sway code right after their struct or enum is type
+//! checked, the same way the encoding traits are implemented automatically.
+
+use super::auto_impl::EncodingAutoImplContext;
+use crate::{
+    decl_engine::DeclEngineGet,
+    language::ty::{TyAstNode, TyDecl, TyEnumDecl, TyStructDecl},
+    transform::{AttributeKind, AttributesMap},
+    Engines, TypeParameter,
+};
+use sway_error::{error::CompileError, handler::Handler};
+use sway_types::{
+    constants::{
+        DERIVE_ABI_DECODE_NAME, DERIVE_ABI_ENCODE_NAME, DERIVE_CLONE_NAME, DERIVE_EQ_NAME,
+        DERIVE_HASH_NAME, DERIVE_PARTIAL_EQ_NAME,
+    },
+    BaseIdent, Named, Span, Spanned,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DerivedTrait {
+    Eq,
+    Clone,
+    Hash,
+    AbiEncode,
+    AbiDecode,
+}
+
+impl DerivedTrait {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            // `Eq` is the only equality trait, so deriving `PartialEq` implements it as well.
+            DERIVE_EQ_NAME | DERIVE_PARTIAL_EQ_NAME => Some(Self::Eq),
+            DERIVE_CLONE_NAME => Some(Self::Clone),
+            DERIVE_HASH_NAME => Some(Self::Hash),
+            DERIVE_ABI_ENCODE_NAME => Some(Self::AbiEncode),
+            DERIVE_ABI_DECODE_NAME => Some(Self::AbiDecode),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Eq => DERIVE_EQ_NAME,
+            Self::Clone => DERIVE_CLONE_NAME,
+            Self::Hash => DERIVE_HASH_NAME,
+            Self::AbiEncode => DERIVE_ABI_ENCODE_NAME,
+            Self::AbiDecode => DERIVE_ABI_DECODE_NAME,
+        }
+    }
+
+    fn is_encoding_trait(&self) -> bool {
+        matches!(self, Self::AbiEncode | Self::AbiDecode)
+    }
+}
+
+/// Returns the traits listed in the `#[derive(...)]` attributes, along with the span of their
+/// names. Unknown traits are already reported when the attributes are converted.
+fn derived_traits(attributes: &AttributesMap) -> Vec<(DerivedTrait, Span)> {
+    let mut derived_traits: Vec<(DerivedTrait, Span)> = vec![];
+    for attribute in attributes.get(&AttributeKind::Derive).into_iter().flatten() {
+        for arg in attribute.args.iter() {
+            let Some(derived_trait) = DerivedTrait::from_name(arg.name.as_str()) else {
+                continue;
+            };
+            if !derived_traits.iter().any(|(t, _)| *t == derived_trait) {
+                derived_traits.push((derived_trait, arg.name.span()));
+            }
+        }
+    }
+    derived_traits
+}
+
+impl<'a, 'b> EncodingAutoImplContext<'a, 'b>
+where
+    'a: 'b,
+{
+    /// Implements the traits derived by the struct or enum `decl` and returns their `AstNode`s.
+    ///
+    /// The encoding traits are skipped if they are already `encoding_auto_implemented`.
+    pub fn generate_derives(
+        &mut self,
+        handler: &Handler,
+        engines: &Engines,
+        decl: &TyDecl,
+        encoding_auto_implemented: bool,
+    ) -> Vec<TyAstNode> {
+        let decl_engine = engines.de();
+        let (struct_decl, enum_decl) = match decl {
+            TyDecl::StructDecl(decl) => (Some(decl_engine.get_struct(&decl.decl_id)), None),
+            TyDecl::EnumDecl(decl) => (None, Some(decl_engine.get_enum(&decl.decl_id))),
+            _ => return vec![],
+        };
+        let (name, attributes, span) = match (&struct_decl, &enum_decl) {
+            (Some(decl), _) => (decl.name(), &decl.attributes, &decl.span),
+            (_, Some(decl)) => (decl.name(), &decl.attributes, &decl.span),
+            _ => unreachable!("the declaration is either a struct or an enum"),
+        };
+        let program_id = span.source_id().map(|sid| sid.program_id());
+
+        let mut nodes = vec![];
+        for (derived_trait, trait_span) in derived_traits(attributes) {
+            if encoding_auto_implemented && derived_trait.is_encoding_trait() {
+                continue;
+            }
+
+            let code = match (&struct_decl, &enum_decl) {
+                (Some(decl), _) => self.generate_struct_derive_code(engines, derived_trait, decl),
+                (_, Some(decl)) => self.generate_enum_derive_code(engines, derived_trait, decl),
+                _ => None,
+            };
+            let node = code.and_then(|code| {
+                self.parse_impl_trait_to_ty_ast_node(engines, program_id, &code)
+                    .ok()
+            });
+
+            match node {
+                Some(node) => nodes.push(node),
+                None => {
+                    handler.emit_err(CompileError::CannotDeriveTrait {
+                        trait_name: derived_trait.name().to_string(),
+                        type_name: name.clone(),
+                        span: trait_span,
+                    });
+                }
+            }
+        }
+        nodes
+    }
+
+    fn generate_struct_derive_code(
+        &self,
+        engines: &Engines,
+        derived_trait: DerivedTrait,
+        decl: &TyStructDecl,
+    ) -> Option<String> {
+        let fields = decl.fields.iter().map(|f| f.name.as_str());
+        let method = match derived_trait {
+            DerivedTrait::AbiEncode => {
+                let body = self.generate_abi_encode_struct_body(engines, decl);
+                return Some(self.generate_abi_encode_code(
+                    decl.name(),
+                    &decl.type_parameters,
+                    body,
+                ));
+            }
+            DerivedTrait::AbiDecode => {
+                let body = self.generate_abi_decode_struct_body(engines, decl)?;
+                return Some(self.generate_abi_decode_code(
+                    decl.name(),
+                    &decl.type_parameters,
+                    body,
+                ));
+            }
+            DerivedTrait::Eq => {
+                let body = fields
+                    .map(|f| format!("self.{f} == other.{f}"))
+                    .collect::<Vec<_>>();
+                let body = if body.is_empty() {
+                    "true".to_string()
+                } else {
+                    body.join(" && ")
+                };
+                format!("fn eq(self, other: Self) -> bool {{ {body} }}")
+            }
+            DerivedTrait::Clone => {
+                let body = fields
+                    .map(|f| format!("{f}: self.{f}.clone(), "))
+                    .collect::<String>();
+                format!("fn clone(self) -> Self {{ Self {{ {body} }} }}")
+            }
+            DerivedTrait::Hash => {
+                let body = fields
+                    .map(|f| format!("self.{f}.hash(state);\n"))
+                    .collect::<String>();
+                format!("fn hash(self, ref mut state: std::hash::Hasher) {{ {body} }}")
+            }
+        };
+
+        Some(self.generate_derive_code(decl.name(), &decl.type_parameters, derived_trait, method))
+    }
+
+    fn generate_enum_derive_code(
+        &self,
+        engines: &Engines,
+        derived_trait: DerivedTrait,
+        decl: &TyEnumDecl,
+    ) -> Option<String> {
+        let enum_name = decl.call_path.suffix.as_str();
+        let variants = decl.variants.iter().map(|v| {
+            let is_unit = engines.te().get(v.type_argument.type_id).is_unit();
+            (v.name.as_str(), is_unit, v.tag)
+        });
+        let method = match derived_trait {
+            DerivedTrait::AbiEncode => {
+                let body = self.generate_abi_encode_enum_body(engines, decl);
+                return Some(self.generate_abi_encode_code(
+                    decl.name(),
+                    &decl.type_parameters,
+                    body,
+                ));
+            }
+            DerivedTrait::AbiDecode => {
+                let body = self.generate_abi_decode_enum_body(engines, decl)?;
+                return Some(self.generate_abi_decode_code(
+                    decl.name(),
+                    &decl.type_parameters,
+                    body,
+                ));
+            }
+            DerivedTrait::Eq => {
+                let mut arms = variants
+                    .map(|(variant, is_unit, _)| match is_unit {
+                        true => {
+                            format!("({enum_name}::{variant}, {enum_name}::{variant}) => true,\n")
+                        }
+                        false => format!(
+                            "({enum_name}::{variant}(a), {enum_name}::{variant}(b)) => a == b,\n"
+                        ),
+                    })
+                    .collect::<String>();
+                if decl.variants.len() > 1 {
+                    arms.push_str("_ => false,\n");
+                }
+                let body = if decl.variants.is_empty() {
+                    "true".to_string()
+                } else {
+                    format!("match (self, other) {{ {arms} }}")
+                };
+                format!("fn eq(self, other: Self) -> bool {{ {body} }}")
+            }
+            DerivedTrait::Clone => {
+                let arms = variants
+                    .map(|(variant, is_unit, _)| match is_unit {
+                        true => format!("{enum_name}::{variant} => {enum_name}::{variant},\n"),
+                        false => format!(
+                            "{enum_name}::{variant}(value) => {enum_name}::{variant}(value.clone()),\n"
+                        ),
+                    })
+                    .collect::<String>();
+                let body = if decl.variants.is_empty() {
+                    "self".to_string()
+                } else {
+                    format!("match self {{ {arms} }}")
+                };
+                format!("fn clone(self) -> Self {{ {body} }}")
+            }
+            DerivedTrait::Hash => {
+                // The tag is hashed first, so that variants with equal values hash differently.
+                let arms = variants
+                    .map(|(variant, is_unit, tag)| match is_unit {
+                        true => format!("{enum_name}::{variant} => {{ {tag}u64.hash(state); }},\n"),
+                        false => format!(
+                            "{enum_name}::{variant}(value) => {{ {tag}u64.hash(state); value.hash(state); }},\n"
+                        ),
+                    })
+                    .collect::<String>();
+                let body = if decl.variants.is_empty() {
+                    String::new()
+                } else {
+                    format!("match self {{ {arms} }};")
+                };
+                format!("fn hash(self, ref mut state: std::hash::Hasher) {{ {body} }}")
+            }
+        };
+
+        Some(self.generate_derive_code(decl.name(), &decl.type_parameters, derived_trait, method))
+    }
+
+    /// Generates the implementation of `derived_trait` for the type `name`, whose type parameters
+    /// must implement the trait as well.
+    fn generate_derive_code(
+        &self,
+        name: &BaseIdent,
+        type_parameters: &[TypeParameter],
+        derived_trait: DerivedTrait,
+        method: String,
+    ) -> String {
+        let trait_name = derived_trait.name();
+        let type_parameters_declaration =
+            self.generate_type_parameters_declaration_code(type_parameters);
+        let type_parameters_constraints =
+            self.generate_type_parameters_constraints_code(type_parameters, trait_name);
+
+        let name = name.as_str();
+
+        format!("#[allow(dead_code)] impl{type_parameters_declaration} {trait_name} for {name}{type_parameters_declaration}{type_parameters_constraints} {{
+            #[allow(dead_code)]
+            {method}
+        }}")
+    }
+}
@@ -0,0 +1,78 @@
+//! Implementation of the `core::error_type::ErrorType` trait for enums annotated with
+//! `#[error_type]`.
+//!
+//! The implementation returns the error selector of each variant, so that
+//! `std::revert::revert_with_error` reverts with the same codes `forc build` publishes.
+
+use super::auto_impl::EncodingAutoImplContext;
+use crate::{
+    decl_engine::DeclEngineGet,
+    language::ty::{TyAstNode, TyDecl},
+    Engines,
+};
+use sway_types::{Named, Spanned};
+
+impl<'a, 'b> EncodingAutoImplContext<'a, 'b>
+where
+    'a: 'b,
+{
+    /// Implements `ErrorType` for the enum `decl` if it is annotated with `#[error_type]`.
+    ///
+    /// Returns `None` if the enum is not an error type, has no variants, or if the trait is not
+    /// in scope, e.g. when compiling `core` itself or a program without it.
+    pub fn generate_error_type(&mut self, engines: &Engines, decl: &TyDecl) -> Option<TyAstNode> {
+        let TyDecl::EnumDecl(decl) = decl else {
+            return None;
+        };
+        let enum_decl = engines.de().get_enum(&decl.decl_id);
+        if !enum_decl.is_error_type() || enum_decl.variants.is_empty() {
+            return None;
+        }
+
+        let enum_name = enum_decl.name().as_str();
+        let arms = enum_decl
+            .variants
+            .iter()
+            .zip(enum_decl.error_selectors())
+            .map(|(variant, selector)| {
+                let variant_name = variant.name.as_str();
+                let selector = selector.selector;
+                match engines.te().get(variant.type_argument.type_id).is_unit() {
+                    true => format!("{enum_name}::{variant_name} => {selector}u64,\n"),
+                    false => format!("{enum_name}::{variant_name}(_) => {selector}u64,\n"),
+                }
+            })
+            .collect::<String>();
+
+        let type_parameters_declaration =
+            self.generate_type_parameters_declaration_code(&enum_decl.type_parameters);
+        let type_parameters_constraints = enum_decl
+            .type_parameters
+            .iter()
+            .filter(|t| !t.trait_constraints.is_empty())
+            .map(|t| {
+                let constraints = t
+                    .trait_constraints
+                    .iter()
+                    .map(|c| c.trait_name.suffix.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" + ");
+                format!("{}: {constraints},\n", t.name.as_str())
+            })
+            .collect::<String>();
+        let type_parameters_constraints = if type_parameters_constraints.is_empty() {
+            String::new()
+        } else {
+            format!(" where {type_parameters_constraints}")
+        };
+
+        let code = format!("#[allow(dead_code)] impl{type_parameters_declaration} ErrorType for {enum_name}{type_parameters_declaration}{type_parameters_constraints} {{
+            #[allow(dead_code)]
+            fn error_code(self) -> u64 {{ match self {{ {arms} }} }}
+        }}");
+
+        let program_id = enum_decl.span().source_id().map(|sid| sid.program_id());
+        self.parse_impl_trait_to_ty_ast_node(engines, program_id, &code)
+            .ok()
+    }
+}
@@ -368,6 +368,7 @@ fn test_function_selector_behavior() {
                     .te()
                     .insert_string_array_without_annotations(&engines, 5)
                     .into(),
+                default_value: None,
             },
             ty::TyFunctionParameter {
                 name: Ident::new_no_span("baz".into()),
@@ -382,6 +383,7 @@ fn test_function_selector_behavior() {
                     span: Span::dummy(),
                     call_path_tree: None,
                 },
+                default_value: None,
             },
         ],
         span: Span::dummy(),
@@ -1,5 +1,8 @@
 use crate::{
-    language::{parsed::FunctionParameter, ty},
+    language::{
+        parsed::{ArrayExpression, Expression, ExpressionKind, FunctionParameter},
+        ty,
+    },
     semantic_analysis::TypeCheckContext,
     type_system::*,
 };
@@ -8,12 +11,12 @@ use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
 };
-use sway_types::Spanned;
+use sway_types::{Ident, Spanned};
 
 impl ty::TyFunctionParameter {
     pub(crate) fn type_check(
         handler: &Handler,
-        ctx: TypeCheckContext,
+        mut ctx: TypeCheckContext,
         parameter: FunctionParameter,
     ) -> Result<Self, ErrorEmitted> {
         let type_engine = ctx.engines.te();
@@ -24,6 +27,7 @@ impl ty::TyFunctionParameter {
             is_mutable,
             mutability_span,
             mut type_argument,
+            default_value,
         } = parameter;
 
         type_argument.type_id = ctx
@@ -36,6 +40,18 @@ impl ty::TyFunctionParameter {
             )
             .unwrap_or_else(|err| type_engine.id_of_error_recovery(err));
 
+        let default_value = default_value
+            .map(|default_value| {
+                type_check_default_value(
+                    handler,
+                    ctx.by_ref(),
+                    &name,
+                    &type_argument,
+                    &default_value,
+                )
+            })
+            .transpose()?;
+
         type_argument.type_id.check_type_parameter_bounds(
             handler,
             ctx,
@@ -59,6 +75,7 @@ impl ty::TyFunctionParameter {
             is_mutable,
             mutability_span,
             type_argument,
+            default_value,
         };
 
         Ok(typed_parameter)
@@ -66,7 +83,7 @@ impl ty::TyFunctionParameter {
 
     pub(crate) fn type_check_interface_parameter(
         handler: &Handler,
-        ctx: TypeCheckContext,
+        mut ctx: TypeCheckContext,
         parameter: &FunctionParameter,
     ) -> Result<Self, ErrorEmitted> {
         let type_engine = ctx.engines.te();
@@ -77,6 +94,7 @@ impl ty::TyFunctionParameter {
             is_mutable,
             mutability_span,
             type_argument,
+            default_value,
         } = parameter;
 
         let mut new_type_argument = type_argument.clone();
@@ -90,12 +108,26 @@ impl ty::TyFunctionParameter {
             )
             .unwrap_or_else(|err| type_engine.id_of_error_recovery(err));
 
+        let default_value = default_value
+            .as_ref()
+            .map(|default_value| {
+                type_check_default_value(
+                    handler,
+                    ctx.by_ref(),
+                    name,
+                    &new_type_argument,
+                    default_value,
+                )
+            })
+            .transpose()?;
+
         let typed_parameter = ty::TyFunctionParameter {
             name: name.clone(),
             is_reference: *is_reference,
             is_mutable: *is_mutable,
             mutability_span: mutability_span.clone(),
             type_argument: new_type_argument,
+            default_value,
         };
 
         Ok(typed_parameter)
@@ -121,4 +153,64 @@ impl ty::TyFunctionParameter {
             })),
         );
     }
+
+    /// Returns the expression passed to this parameter when a call doesn't pass an argument to it,
+    /// or `None` if the parameter doesn't have a default value.
+    ///
+    /// Constants in the default value are replaced by their values, so that the expression can be
+    /// type checked at the call site, where the constants might not be in scope.
+    pub(crate) fn default_argument(&self) -> Option<Expression> {
+        self.default_value.as_ref().and_then(constant_to_expression)
+    }
+}
+
+fn type_check_default_value(
+    handler: &Handler,
+    ctx: TypeCheckContext,
+    param_name: &Ident,
+    type_argument: &TypeArgument,
+    default_value: &Expression,
+) -> Result<ty::TyExpression, ErrorEmitted> {
+    let ctx = ctx
+        .with_help_text("The default value must be of the type of the parameter.")
+        .with_type_annotation(type_argument.type_id);
+    let typed_default_value = ty::TyExpression::type_check(handler, ctx, default_value)?;
+    if constant_to_expression(&typed_default_value).is_none() {
+        return Err(
+            handler.emit_err(CompileError::NonConstantDefaultParameterValue {
+                param_name: param_name.clone(),
+                span: default_value.span(),
+            }),
+        );
+    }
+    Ok(typed_default_value)
+}
+
+/// Converts a typed constant expression back to an expression which evaluates to the same value,
+/// or returns `None` if the expression is not a literal, a constant, or a tuple or array of them.
+fn constant_to_expression(expr: &ty::TyExpression) -> Option<Expression> {
+    let kind = match &expr.expression {
+        ty::TyExpressionVariant::Literal(literal) => ExpressionKind::Literal(literal.clone()),
+        ty::TyExpressionVariant::ConstantExpression { decl, .. } => {
+            return decl.value.as_ref().and_then(constant_to_expression);
+        }
+        ty::TyExpressionVariant::Tuple { fields } => ExpressionKind::Tuple(
+            fields
+                .iter()
+                .map(constant_to_expression)
+                .collect::<Option<_>>()?,
+        ),
+        ty::TyExpressionVariant::Array { contents, .. } => ExpressionKind::Array(ArrayExpression {
+            contents: contents
+                .iter()
+                .map(constant_to_expression)
+                .collect::<Option<_>>()?,
+            length_span: None,
+        }),
+        _ => return None,
+    };
+    Some(Expression {
+        kind,
+        span: expr.span.clone(),
+    })
 }
@@ -870,12 +870,20 @@ fn type_check_trait_implementation(
                     .new_unknown_generic_self(trait_name.span(), false)
                     .0;
                 if let Some(type_arg) = type_decl.ty.clone() {
+                    // For generic associated types the mapping goes from the associated type
+                    // applied to its own type parameters, e.g. `Self::Output<T> -> Vec<T>`.
+                    let type_parameters_as_arguments = type_decl
+                        .type_parameters
+                        .iter()
+                        .map(TypeArgument::from)
+                        .collect::<Vec<_>>();
                     trait_type_mapping.extend(
                         &TypeSubstMap::from_type_parameters_and_type_arguments(
                             vec![type_engine.insert_trait_type(
                                 engines,
                                 type_decl.name.clone(),
                                 implementing_for,
+                                type_parameters_as_arguments.clone(),
                             )],
                             vec![type_arg.type_id],
                         ),
@@ -886,6 +894,7 @@ fn type_check_trait_implementation(
                                 engines,
                                 type_decl.name.clone(),
                                 self_type_id,
+                                type_parameters_as_arguments,
                             )],
                             vec![type_arg.type_id],
                         ),
@@ -1011,6 +1020,23 @@ fn type_check_trait_implementation(
             TyImplItem::Fn(decl_ref) => {
                 let mut method = (*decl_engine.get_function(decl_ref)).clone();
 
+                // Default implementations that constrain the trait's type parameters in their
+                // `where` clause declare their own copies of those type parameters. Bind them to
+                // the type arguments of this impl, ensuring that the additional bounds hold.
+                let parent_type_mapping = specialize_default_method_parent_type_parameters(
+                    handler,
+                    ctx.by_ref(),
+                    &method,
+                    trait_type_parameters,
+                    trait_type_arguments,
+                    trait_name,
+                )?;
+                method.subst(&SubstTypesContext::new(
+                    engines,
+                    &parent_type_mapping,
+                    !ctx.code_block_first_pass(),
+                ));
+
                 // We need to add impl type parameters to the method's type parameters
                 // so that in-line monomorphization can complete.
                 //
@@ -1232,6 +1258,11 @@ fn type_check_impl_method(
                 });
                 continue;
             }
+
+            // the default values declared in the interface are the defaults of the implementation
+            if impl_method_param.default_value.is_none() {
+                impl_method_param.default_value = impl_method_signature_param.default_value.clone();
+            }
         }
 
         // check to see if the purity of the function declaration is the same
@@ -1283,6 +1314,35 @@ fn type_check_impl_method(
             (true, true) | (false, false) => (), // no payability mismatch
         }
 
+        // the selector and the method name of a contract method can only be pinned
+        // by the ABI method, the implementation inherits them from the signature
+        if is_contract {
+            use crate::transform::AttributeKind::{MethodName, Selector};
+            let overrides = [Selector, MethodName];
+            for kind in &overrides {
+                if let Some(attribute) = impl_method.attributes.get(kind).and_then(|a| a.last()) {
+                    handler.emit_err(CompileError::AbiMethodOverrideOnImplMethod {
+                        attribute: attribute.name.to_string(),
+                        method_name: impl_method.name.clone(),
+                        span: attribute.span.clone(),
+                    });
+                }
+            }
+            if overrides.iter().any(|kind| {
+                impl_method.attributes.contains_key(kind)
+                    || impl_method_signature.attributes.contains_key(kind)
+            }) {
+                let mut attributes = (**impl_method.attributes).clone();
+                for kind in overrides {
+                    attributes.shift_remove(&kind);
+                    if let Some(attrs) = impl_method_signature.attributes.get(&kind) {
+                        attributes.insert(kind, attrs.clone());
+                    }
+                }
+                impl_method.attributes = crate::transform::AttributesMap::new(Arc::new(attributes));
+            }
+        }
+
         // this subst is required to replace associated types, namely TypeInfo::TraitType.
         let mut impl_method_return_type_id = impl_method.return_type.type_id;
         impl_method_return_type_id.subst(&ctx.subst_ctx());
@@ -1519,7 +1579,23 @@ fn type_check_type_decl(
 
     // Ensure that the type checklist contains this type.
     match type_checklist.get(&type_name) {
-        Some(_type_decl) => {}
+        Some(trait_type_decl) => {
+            // Ensure that a generic associated type has as many type parameters as its
+            // declaration in the interface.
+            let expected = trait_type_decl.type_parameters.len();
+            let given = type_decl.type_parameters.len();
+            if expected != given {
+                return Err(handler.emit_err(
+                    CompileError::AssociatedTypeTypeParametersCountMismatch {
+                        name: type_name.clone(),
+                        interface_name: interface_name(),
+                        given,
+                        expected,
+                        span: type_decl.span.clone(),
+                    },
+                ));
+            }
+        }
         None => {
             return Err(
                 handler.emit_err(CompileError::TypeNotAPartOfInterfaceSurface {
@@ -1612,6 +1688,85 @@ fn check_for_unconstrained_type_parameters(
     })
 }
 
+/// Returns the mapping from the type parameters that a trait's default `method` declares
+/// for the trait's own type parameters, to the type arguments of the impl being checked.
+///
+/// A default method declares such a type parameter when it constrains one of the trait's
+/// type parameters in its `where` clause:
+///
+/// ```ignore
+/// trait Container<T> {
+///     fn get(self) -> T;
+/// } {
+///     fn contains(self, value: T) -> bool where T: Eq {
+///         self.get() == value
+///     }
+/// }
+/// ```
+///
+/// An error is emitted for every bound in the `where` clause that the corresponding type
+/// argument of the impl doesn't satisfy, e.g., for `impl Container<NotEq> for MyType`.
+fn specialize_default_method_parent_type_parameters(
+    handler: &Handler,
+    mut ctx: TypeCheckContext,
+    method: &TyFunctionDecl,
+    trait_type_parameters: &[TypeParameter],
+    trait_type_arguments: &[TypeArgument],
+    trait_name: &CallPath,
+) -> Result<TypeSubstMap, ErrorEmitted> {
+    let engines = ctx.engines();
+    let mut type_parameters = vec![];
+    let mut type_arguments = vec![];
+
+    handler.scope(|handler| {
+        for method_type_param in method.type_parameters.iter().filter(|p| p.is_from_parent) {
+            let Some((trait_type_param, trait_type_arg)) = trait_type_parameters
+                .iter()
+                .zip(trait_type_arguments)
+                .find(|(trait_type_param, _)| trait_type_param.name == method_type_param.name)
+            else {
+                continue;
+            };
+
+            // Bounds declared on the trait itself are already checked for the impl as a whole.
+            for constraint in method_type_param.trait_constraints.iter().filter(|c| {
+                !trait_type_param
+                    .trait_constraints
+                    .iter()
+                    .any(|tc| tc.trait_name == c.trait_name)
+            }) {
+                let bounds_handler = Handler::default();
+                let _ = trait_type_arg.type_id.check_type_parameter_bounds(
+                    &bounds_handler,
+                    ctx.by_ref(),
+                    &trait_type_arg.span(),
+                    Some(TypeParameter {
+                        trait_constraints: vec![constraint.clone()],
+                        ..method_type_param.clone()
+                    }),
+                );
+                if bounds_handler.has_errors() {
+                    handler.emit_err(CompileError::DefaultMethodTraitConstraintNotSatisfied {
+                        method_name: method.name.clone(),
+                        trait_name: trait_name.suffix.clone(),
+                        ty: engines.help_out(trait_type_arg.type_id).to_string(),
+                        constraint: constraint.trait_name.suffix.to_string(),
+                        span: trait_type_arg.span(),
+                    });
+                }
+            }
+
+            type_parameters.push(method_type_param.type_id);
+            type_arguments.push(trait_type_arg.type_id);
+        }
+
+        Ok(TypeSubstMap::from_type_parameters_and_type_arguments(
+            type_parameters,
+            type_arguments,
+        ))
+    })
+}
+
 fn handle_supertraits(
     handler: &Handler,
     mut ctx: TypeCheckContext,
@@ -4,7 +4,9 @@ mod configurable;
 mod constant;
 #[allow(clippy::module_inception)]
 mod declaration;
+mod derive;
 mod r#enum;
+mod error_type;
 mod function;
 mod impl_trait;
 mod storage;
@@ -4,7 +4,8 @@ use crate::{
     decl_engine::parsed_id::ParsedDeclId,
     fuel_prelude::fuel_tx::StorageSlot,
     ir_generation::{
-        const_eval::compile_constant_expression_to_constant, storage::serialize_to_storage_slots,
+        const_eval::compile_constant_expression_to_constant,
+        storage::{get_storage_key, get_storage_key_string, serialize_to_storage_slots},
     },
     language::{
         parsed::StorageDeclaration,
@@ -21,10 +22,9 @@ use fuel_vm::fuel_tx::Bytes32;
 use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
-    warning::CompileWarning,
 };
 use sway_ir::{ConstantValue, Context, Module};
-use sway_types::{u256::U256, Spanned};
+use sway_types::u256::U256;
 
 impl ty::TyStorageDecl {
     pub(crate) fn collect(
@@ -36,70 +36,82 @@ impl ty::TyStorageDecl {
         Ok(())
     }
 
-    pub(crate) fn get_initialized_storage_slots(
+    /// Returns the storage slots initialized by the storage declaration, together with
+    /// its [ty::StorageLayout].
+    ///
+    /// Emits an error if two storage fields, possibly in different storage namespaces,
+    /// occupy the same storage slot.
+    pub(crate) fn get_initialized_storage_slots_and_layout(
         &self,
         handler: &Handler,
         engines: &Engines,
         context: &mut Context,
         md_mgr: &mut MetadataManager,
         module: Module,
-    ) -> Result<Vec<StorageSlot>, ErrorEmitted> {
+    ) -> Result<(Vec<StorageSlot>, ty::StorageLayout), ErrorEmitted> {
         handler.scope(|handler| {
             let mut slot_fields = HashMap::<Bytes32, TyStorageField>::new();
+            let mut layout = ty::StorageLayout::default();
             let storage_slots = self
                 .fields
                 .iter()
                 .map(|f| {
-                    let slots = f.get_initialized_storage_slots(engines, context, md_mgr, module);
+                    let slots = f
+                        .get_initialized_storage_slots_and_layout(engines, context, md_mgr, module);
 
-                    // Check if slot with same key was already used and throw warning.
-                    if let Ok(slots) = &slots {
+                    // Check if slot with same key was already used and throw an error.
+                    if let Ok((slots, _)) = &slots {
                         for s in slots.iter() {
                             if let Some(old_field) = slot_fields.insert(*s.key(), f.clone()) {
-                                handler.emit_warn(CompileWarning {
-                                    span: f.span(),
-                                    warning_content:
-                                        sway_error::warning::Warning::DuplicatedStorageKey {
-                                            first_field: (&old_field.name).into(),
-                                            first_field_full_name: old_field.full_name(),
-                                            first_field_key_is_compiler_generated: old_field
-                                                .key_expression
-                                                .is_none(),
-                                            second_field: (&f.name).into(),
-                                            second_field_full_name: f.full_name(),
-                                            second_field_key_is_compiler_generated: f
-                                                .key_expression
-                                                .is_none(),
-                                            key: format!("0x{:x}", s.key()),
-                                            experimental_storage_domains: context
-                                                .experimental
-                                                .storage_domains,
-                                        },
-                                })
+                                handler.emit_err(CompileError::DuplicatedStorageKey {
+                                    first_field: (&old_field.name).into(),
+                                    first_field_full_name: old_field.full_name(),
+                                    first_field_key_is_compiler_generated: old_field
+                                        .key_expression
+                                        .is_none(),
+                                    second_field: (&f.name).into(),
+                                    second_field_full_name: f.full_name(),
+                                    second_field_key_is_compiler_generated: f
+                                        .key_expression
+                                        .is_none(),
+                                    key: format!("0x{:x}", s.key()),
+                                    experimental_storage_domains: context
+                                        .experimental
+                                        .storage_domains,
+                                });
                             }
                         }
                     }
                     slots
                 })
                 .filter_map(|s| s.map_err(|e| handler.emit_err(e)).ok())
-                .flatten()
+                .flat_map(|(slots, field_layout)| {
+                    layout.fields.push(field_layout);
+                    slots
+                })
                 .collect::<Vec<_>>();
 
-            Ok(storage_slots)
+            Ok((storage_slots, layout))
         })
     }
 }
 
 impl ty::TyStorageField {
-    pub(crate) fn get_initialized_storage_slots(
+    pub(crate) fn get_initialized_storage_slots_and_layout(
         &self,
         engines: &Engines,
         context: &mut Context,
         md_mgr: &mut MetadataManager,
         module: Module,
-    ) -> Result<Vec<StorageSlot>, CompileError> {
+    ) -> Result<(Vec<StorageSlot>, ty::StorageFieldLayout), CompileError> {
         let key =
             Self::get_key_expression_const(&self.key_expression, engines, context, md_mgr, module)?;
+        let storage_field_names = self
+            .namespace_names
+            .iter()
+            .map(|i| i.as_str().to_string())
+            .chain(vec![self.name.as_str().to_string()])
+            .collect::<Vec<_>>();
         compile_constant_expression_to_constant(
             engines,
             context,
@@ -110,17 +122,32 @@ impl ty::TyStorageField {
             &self.initializer,
         )
         .map(|constant| {
-            serialize_to_storage_slots(
+            let slots = serialize_to_storage_slots(
                 &constant,
                 context,
-                self.namespace_names
-                    .iter()
-                    .map(|i| i.as_str().to_string())
-                    .chain(vec![self.name.as_str().to_string()])
-                    .collect(),
+                storage_field_names.clone(),
                 key,
                 &constant.ty,
-            )
+            );
+            let key_derivation = match key {
+                Some(_) => ty::StorageKeyDerivation::Explicit,
+                None => ty::StorageKeyDerivation::Generated {
+                    preimage: get_storage_key_string(&storage_field_names),
+                    storage_domain: context.experimental.storage_domains,
+                },
+            };
+            let layout = ty::StorageFieldLayout {
+                name: self.full_name(),
+                type_name: engines.help_out(self.type_argument.type_id).to_string(),
+                key_derivation,
+                key: format!(
+                    "0x{:x}",
+                    get_storage_key(storage_field_names, key, context.experimental)
+                ),
+                size_in_bytes: constant.ty.size(context).in_bytes(),
+                slots: slots.iter().map(|s| format!("0x{:x}", s.key())).collect(),
+            };
+            (slots, layout)
         })
     }
 
@@ -5,7 +5,10 @@ use crate::{
     type_system::*,
     Engines,
 };
-use sway_error::handler::{ErrorEmitted, Handler};
+use sway_error::{
+    error::CompileError,
+    handler::{ErrorEmitted, Handler},
+};
 use symbol_collection_context::SymbolCollectionContext;
 
 impl ty::TyStructDecl {
@@ -72,11 +75,56 @@ impl ty::TyStructDecl {
                 attributes,
             };
 
+            decl.check_indexed_fields(handler, ctx.engines)?;
+
             Ok(decl)
         })
     }
 }
 
+impl ty::TyStructDecl {
+    /// Checks that `#[indexed]` fields are only used in `#[event]` structs, are declared before
+    /// all other fields, and have a type with a fixed size encoding.
+    fn check_indexed_fields(
+        &self,
+        handler: &Handler,
+        engines: &Engines,
+    ) -> Result<(), ErrorEmitted> {
+        handler.scope(|handler| {
+            let is_event = self.is_event();
+            let mut first_non_indexed_field: Option<&ty::TyStructField> = None;
+            for field in self.fields.iter() {
+                if !field.is_indexed() {
+                    first_non_indexed_field.get_or_insert(field);
+                    continue;
+                }
+                if !is_event {
+                    handler.emit_err(CompileError::IndexedFieldOutsideOfEvent {
+                        field_name: field.name.clone(),
+                        struct_name: self.call_path.suffix.clone(),
+                    });
+                    continue;
+                }
+                if let Some(non_indexed_field) = first_non_indexed_field {
+                    handler.emit_err(CompileError::IndexedFieldAfterNonIndexedField {
+                        field_name: field.name.clone(),
+                        non_indexed_field_name: non_indexed_field.name.clone(),
+                    });
+                }
+                let type_id = field.type_argument.type_id;
+                if ty::TyStructField::indexed_field_encoding_size(engines, type_id).is_none() {
+                    handler.emit_err(CompileError::IndexedFieldTypeNotSupported {
+                        field_name: field.name.clone(),
+                        ty: engines.help_out(type_id).to_string(),
+                        span: field.type_argument.span.clone(),
+                    });
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
 impl ty::TyStructField {
     pub(crate) fn type_check(
         handler: &Handler,
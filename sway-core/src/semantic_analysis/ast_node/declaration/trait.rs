@@ -242,11 +242,13 @@ impl TyTraitDecl {
                     IsExtendingExistingImpl::Yes,
                 )?;
 
-                // Type check the items.
-                let mut new_items = vec![];
+                // Type check the signatures of the items first, so that the default
+                // implementations can call each other regardless of their order.
+                let mut method_signatures = vec![];
+                let mut dummy_methods = vec![];
                 for method_decl_id in methods.into_iter() {
                     let method = engines.pe().get_function(&method_decl_id);
-                    let method = ty::TyFunctionDecl::type_check(
+                    let signature = ty::TyFunctionDecl::type_check_signature(
                         handler,
                         ctx.by_ref(),
                         &method,
@@ -255,6 +257,41 @@ impl TyTraitDecl {
                         Some(self_type_param.type_id),
                     )
                     .unwrap_or_else(|_| ty::TyFunctionDecl::error(&method));
+                    dummy_methods.push(ty::TyImplItem::Fn(decl_engine.insert(
+                        ty::TyFunctionDecl {
+                            is_trait_method_dummy: true,
+                            is_type_check_finalized: true,
+                            ..signature.clone()
+                        },
+                        None,
+                    )));
+                    method_signatures.push((method_decl_id, method, signature));
+                }
+
+                // insert placeholder functions representing the default implementations
+                // to allow the default implementations to use each other
+                ctx.insert_trait_implementation(
+                    handler,
+                    CallPath::ident_to_fullpath(name.clone(), ctx.namespace()),
+                    new_type_parameters.iter().map(|x| x.into()).collect(),
+                    self_type,
+                    &dummy_methods,
+                    &span,
+                    None,
+                    IsImplSelf::No,
+                    IsExtendingExistingImpl::Yes,
+                )?;
+
+                // Type check the bodies of the items.
+                let mut new_items = vec![];
+                for (method_decl_id, method, mut signature) in method_signatures.into_iter() {
+                    let method = ty::TyFunctionDecl::type_check_body(
+                        handler,
+                        ctx.by_ref(),
+                        &method,
+                        &mut signature,
+                    )
+                    .unwrap_or_else(|_| ty::TyFunctionDecl::error(&method));
                     new_items.push(ty::TyTraitItem::Fn(
                         decl_engine.insert(method, Some(&method_decl_id)),
                     ));
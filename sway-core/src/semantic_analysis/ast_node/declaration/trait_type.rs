@@ -14,6 +14,7 @@ use crate::{
         symbol_collection_context::SymbolCollectionContext, TypeCheckAnalysis,
         TypeCheckAnalysisContext, TypeCheckContext,
     },
+    type_system::{TypeArgument, TypeParameter},
     EnforceTypeArguments, Engines,
 };
 
@@ -40,15 +41,52 @@ impl ty::TyTraitType {
     ) -> Result<Self, ErrorEmitted> {
         let parsed::TraitTypeDeclaration {
             name,
+            type_parameters,
             attributes,
             ty_opt,
             span,
         } = trait_type;
 
-        let engines = ctx.engines();
-        let type_engine = engines.te();
+        let Some(implementing_type) = ctx.self_type() else {
+            return Err(handler.emit_err(CompileError::Internal(
+                "Self type not provided.",
+                span,
+            )));
+        };
+
+        let (type_parameters, ty) = if type_parameters.is_empty() {
+            (vec![], Self::type_check_ty(handler, ctx, ty_opt))
+        } else {
+            // The type parameters of a generic associated type are only visible within its
+            // own declaration, so they are inserted into a throwaway copy of the namespace.
+            ctx.scoped(handler, None, |mut ctx| {
+                let type_parameters = TypeParameter::type_check_type_params(
+                    handler,
+                    ctx.by_ref(),
+                    type_parameters,
+                    None,
+                )?;
+                Ok((type_parameters, Self::type_check_ty(handler, ctx, ty_opt)))
+            })?
+        };
+
+        Ok(ty::TyTraitType {
+            name,
+            type_parameters,
+            attributes,
+            ty,
+            implementing_type,
+            span,
+        })
+    }
 
-        let ty = if let Some(mut ty) = ty_opt {
+    fn type_check_ty(
+        handler: &Handler,
+        ctx: TypeCheckContext,
+        ty_opt: Option<TypeArgument>,
+    ) -> Option<TypeArgument> {
+        let type_engine = ctx.engines().te();
+        ty_opt.map(|mut ty| {
             ty.type_id = ctx
                 .resolve_type(
                     handler,
@@ -58,22 +96,8 @@ impl ty::TyTraitType {
                     None,
                 )
                 .unwrap_or_else(|err| type_engine.id_of_error_recovery(err));
-            Some(ty)
-        } else {
-            None
-        };
-
-        if let Some(implementing_type) = ctx.self_type() {
-            Ok(ty::TyTraitType {
-                name,
-                attributes,
-                ty,
-                implementing_type,
-                span,
-            })
-        } else {
-            Err(handler.emit_err(CompileError::Internal("Self type not provided.", span)))
-        }
+            ty
+        })
     }
 
     /// Used to create a stubbed out constant when the constant fails to
@@ -81,12 +105,14 @@ impl ty::TyTraitType {
     pub(crate) fn error(engines: &Engines, decl: parsed::TraitTypeDeclaration) -> TyTraitType {
         let parsed::TraitTypeDeclaration {
             name,
+            type_parameters,
             attributes,
             ty_opt,
             span,
         } = decl;
         TyTraitType {
             name,
+            type_parameters,
             attributes,
             ty: ty_opt,
             implementing_type: engines.te().new_self_type(engines, Span::dummy()),
@@ -2,6 +2,7 @@ use sway_ast::intrinsics::Intrinsic;
 use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
+    warning::{CompileWarning, Warning},
 };
 use sway_types::integer_bits::IntegerBits;
 use sway_types::Span;
@@ -109,6 +110,7 @@ impl ty::TyIntrinsicFunctionKind {
             Intrinsic::Transmute => {
                 type_check_transmute(arguments, handler, kind, type_arguments, span, ctx)
             }
+            Intrinsic::Cast => type_check_cast(handler, ctx, kind, arguments, type_arguments, span),
         }
     }
 }
@@ -220,6 +222,126 @@ fn type_check_transmute(
     ))
 }
 
+/// Signature: `__cast<T>(value: U) -> T`
+/// Description: Converts the integer `value` to the integer type `T`, truncating it if `T` is
+///              narrower than `U`. `value as T` is lowered to this intrinsic.
+/// Constraints: `T` and `U` are `u8`, `u16`, `u32` or `u64`.
+fn type_check_cast(
+    handler: &Handler,
+    mut ctx: TypeCheckContext,
+    kind: sway_ast::Intrinsic,
+    arguments: &[Expression],
+    type_arguments: &[TypeArgument],
+    span: Span,
+) -> Result<(ty::TyIntrinsicFunctionKind, TypeId), ErrorEmitted> {
+    let type_engine = ctx.engines.te();
+    let engines = ctx.engines();
+
+    if arguments.len() != 1 {
+        return Err(handler.emit_err(CompileError::IntrinsicIncorrectNumArgs {
+            name: kind.to_string(),
+            expected: 1,
+            span,
+        }));
+    }
+
+    if type_arguments.len() != 1 {
+        return Err(handler.emit_err(CompileError::IntrinsicIncorrectNumTArgs {
+            name: kind.to_string(),
+            expected: 1,
+            span,
+        }));
+    }
+
+    let target_type = ctx
+        .resolve_type(
+            handler,
+            type_arguments[0].type_id,
+            &type_arguments[0].span,
+            EnforceTypeArguments::Yes,
+            None,
+        )
+        .unwrap_or_else(|err| type_engine.id_of_error_recovery(err));
+
+    let mut ctx = ctx
+        .with_help_text("")
+        .with_type_annotation(type_engine.new_unknown());
+    let operand_expr = ty::TyExpression::type_check(handler, ctx.by_ref(), &arguments[0])?;
+
+    let castable_bits = |type_id: TypeId| match &*type_engine.get(type_id) {
+        TypeInfo::UnsignedInteger(bits) if *bits != IntegerBits::V256 => Ok(Some(*bits)),
+        TypeInfo::ErrorRecovery(err) => Err(*err),
+        _ => Ok(None),
+    };
+
+    // Untyped numeric expressions, like integer literals, default to `u64`.
+    let is_numeric = matches!(
+        &*type_engine.get(operand_expr.return_type),
+        TypeInfo::Numeric
+    );
+    let operand_bits = if is_numeric {
+        type_engine.unify(
+            handler,
+            engines,
+            operand_expr.return_type,
+            type_engine.id_of_u64(),
+            &span,
+            "",
+            None,
+        );
+        Some(IntegerBits::SixtyFour)
+    } else {
+        castable_bits(operand_expr.return_type)?
+    };
+    let target_bits = castable_bits(target_type)?;
+
+    let (Some(operand_bits), Some(target_bits)) = (operand_bits, target_bits) else {
+        return Err(handler.emit_err(CompileError::InvalidCast {
+            from: engines.help_out(operand_expr.return_type).to_string(),
+            to: engines.help_out(target_type).to_string(),
+            span,
+        }));
+    };
+
+    if !is_numeric && operand_bits == target_bits {
+        handler.emit_warn(CompileWarning {
+            span: span.clone(),
+            warning_content: Warning::RedundantCast {
+                ty: engines.help_out(target_type).to_string(),
+            },
+        });
+    }
+
+    if let ty::TyExpressionVariant::Literal(literal) = &operand_expr.expression {
+        if let Some(value) = literal.cast_value_to_u64() {
+            let truncated_value = target_bits.truncate(value);
+            if truncated_value != value {
+                handler.emit_warn(CompileWarning {
+                    span: span.clone(),
+                    warning_content: Warning::CastTruncatesLiteral {
+                        value: value.to_string(),
+                        truncated_value: truncated_value.to_string(),
+                        ty: engines.help_out(target_type).to_string(),
+                    },
+                });
+            }
+        }
+    }
+
+    Ok((
+        ty::TyIntrinsicFunctionKind {
+            kind,
+            arguments: vec![operand_expr],
+            type_arguments: vec![TypeArgument {
+                type_id: target_type,
+                ..type_arguments[0].clone()
+            }],
+            span,
+        },
+        target_type,
+    ))
+}
+
 fn type_check_elem_at(
     arguments: &[Expression],
     handler: &Handler,
@@ -303,7 +425,8 @@ fn type_check_slice(
     _type_arguments: &[TypeArgument],
     span: Span,
 ) -> Result<(ty::TyIntrinsicFunctionKind, TypeId), ErrorEmitted> {
-    if arguments.len() != 3 {
+    // The end index can be omitted, in which case the slice ends at the end of the item.
+    if arguments.len() != 2 && arguments.len() != 3 {
         return Err(handler.emit_err(CompileError::IntrinsicIncorrectNumArgs {
             name: kind.to_string(),
             expected: 3,
@@ -324,18 +447,21 @@ fn type_check_slice(
     };
 
     // end index argument
-    let end_ty_expr = {
-        let ctx = ctx
-            .by_ref()
-            .with_help_text("")
-            .with_type_annotation(type_engine.id_of_u64());
-        ty::TyExpression::type_check(handler, ctx, &arguments[2])?
+    let end_ty_expr = match arguments.get(2) {
+        Some(end) => {
+            let ctx = ctx
+                .by_ref()
+                .with_help_text("")
+                .with_type_annotation(type_engine.id_of_u64());
+            Some(ty::TyExpression::type_check(handler, ctx, end)?)
+        }
+        None => None,
     };
 
     // check first argument
     let first_argument_span = arguments[0].span.clone();
     let first_argument_type = type_engine.new_unknown();
-    let first_argument_ty_expr = {
+    let mut first_argument_ty_expr = {
         let ctx = ctx
             .by_ref()
             .with_help_text("")
@@ -343,6 +469,25 @@ fn type_check_slice(
         ty::TyExpression::type_check(handler, ctx, &arguments[0])?
     };
 
+    // References to references to arrays or slices are dereferenced,
+    // so that e.g. `&slice[1..3]` can slice a `slice: &__slice[T]`.
+    loop {
+        let referenced_type_id = match &*type_engine.get(first_argument_ty_expr.return_type) {
+            TypeInfo::Ref {
+                referenced_type, ..
+            } => referenced_type.type_id,
+            _ => break,
+        };
+        if !type_engine.get(referenced_type_id).is_reference() {
+            break;
+        }
+        first_argument_ty_expr = ty::TyExpression {
+            expression: ty::TyExpressionVariant::Deref(Box::new(first_argument_ty_expr)),
+            return_type: referenced_type_id,
+            span: first_argument_span.clone(),
+        };
+    }
+
     // statically check start and end, if possible
     let start_literal = start_ty_expr
         .expression
@@ -350,8 +495,8 @@ fn type_check_slice(
         .and_then(|x| x.cast_value_to_u64());
 
     let end_literal = end_ty_expr
-        .expression
-        .as_literal()
+        .as_ref()
+        .and_then(|end| end.expression.as_literal())
         .and_then(|x| x.cast_value_to_u64());
 
     if let (Some(start), Some(end)) = (start_literal, end_literal) {
@@ -382,7 +527,7 @@ fn type_check_slice(
         span: first_argument_span,
         hint: "Only references to arrays or slices can be used as argument here".to_string(),
     };
-    let r = match &*type_engine.get(first_argument_type) {
+    let r = match &*type_engine.get(first_argument_ty_expr.return_type) {
         TypeInfo::Ref {
             referenced_type,
             to_mutable_value,
@@ -410,6 +555,13 @@ fn type_check_slice(
                     }
                 }
 
+                // The length of arrays is known, so a missing end is the array length.
+                let end_ty_expr = end_ty_expr.unwrap_or_else(|| ty::TyExpression {
+                    expression: ty::TyExpressionVariant::Literal(Literal::U64(array_len)),
+                    return_type: type_engine.id_of_u64(),
+                    span: span.clone(),
+                });
+
                 Some((
                     TyIntrinsicFunctionKind {
                         kind,
@@ -423,7 +575,10 @@ fn type_check_slice(
             TypeInfo::Slice(elem_type_arg) => Some((
                 TyIntrinsicFunctionKind {
                     kind,
-                    arguments: vec![first_argument_ty_expr, start_ty_expr, end_ty_expr],
+                    arguments: std::iter::once(first_argument_ty_expr)
+                        .chain(std::iter::once(start_ty_expr))
+                        .chain(end_ty_expr)
+                        .collect(),
                     type_arguments: vec![],
                     span,
                 },
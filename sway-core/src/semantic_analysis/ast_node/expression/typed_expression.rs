@@ -6,6 +6,7 @@ mod lazy_operator;
 mod method_application;
 mod struct_field_access;
 mod struct_instantiation;
+mod try_expression;
 mod tuple_index_access;
 mod unsafe_downcast;
 
@@ -13,8 +14,8 @@ use self::constant_expression::instantiate_constant_expression;
 
 pub(crate) use self::{
     enum_instantiation::*, function_application::*, if_expression::*, lazy_operator::*,
-    method_application::*, struct_field_access::*, struct_instantiation::*, tuple_index_access::*,
-    unsafe_downcast::*,
+    method_application::*, struct_field_access::*, struct_instantiation::*, try_expression::*,
+    tuple_index_access::*, unsafe_downcast::*,
 };
 
 use crate::{
@@ -262,8 +263,15 @@ impl ty::TyExpression {
             ExpressionKind::ForLoop(expr) => {
                 Self::collect(handler, engines, ctx, &expr.desugared)?;
             }
-            ExpressionKind::Break => {}
-            ExpressionKind::Continue => {}
+            ExpressionKind::LabeledBlock(expr) => {
+                TyCodeBlock::collect(handler, engines, ctx, &expr.block)?
+            }
+            ExpressionKind::Break(expr) => {
+                if let Some(value) = &expr.value {
+                    Self::collect(handler, engines, ctx, value)?;
+                }
+            }
+            ExpressionKind::Continue(_) => {}
             ExpressionKind::Reassignment(expr) => {
                 match &expr.lhs {
                     ReassignmentTarget::ElementAccess(expr) => {
@@ -285,6 +293,17 @@ impl ty::TyExpression {
             ExpressionKind::Deref(expr) => {
                 Self::collect(handler, engines, ctx, expr)?;
             }
+            ExpressionKind::Try(try_expr) => {
+                Self::collect(handler, engines, ctx, &try_expr.expr)?;
+                // The branches of the desugared `match` are only known after type checking the
+                // value, and have nothing to collect.
+                for branch_span in try_expression_branch_spans(&expr.span, try_expr) {
+                    ctx.scoped(engines, branch_span, None, |_| Ok(())).0?;
+                }
+            }
+            ExpressionKind::NamedArgument(named_arg) => {
+                Self::collect(handler, engines, ctx, &named_arg.value)?;
+            }
         }
         Ok(())
     }
@@ -465,12 +484,16 @@ impl ty::TyExpression {
             ExpressionKind::Array(array_expression) => {
                 Self::type_check_array(handler, ctx.by_ref(), &array_expression.contents, span)
             }
-            ExpressionKind::ArrayIndex(ArrayIndexExpression { prefix, index }) => {
+            ExpressionKind::ArrayIndex(ArrayIndexExpression {
+                prefix,
+                index,
+                from_end,
+            }) => {
                 let ctx = ctx
                     .by_ref()
                     .with_type_annotation(type_engine.new_unknown())
                     .with_help_text("");
-                Self::type_check_array_index(handler, ctx, prefix, index, span)
+                Self::type_check_array_index(handler, ctx, prefix, index, *from_end, span)
             }
             ExpressionKind::StorageAccess(StorageAccessExpression {
                 namespace_names,
@@ -505,28 +528,47 @@ impl ty::TyExpression {
                 condition,
                 body,
                 is_desugared_for_loop,
+                label,
             }) => Self::type_check_while_loop(
                 handler,
                 ctx.by_ref(),
                 condition,
                 body,
                 *is_desugared_for_loop,
+                label.clone(),
                 span,
             ),
             ExpressionKind::ForLoop(ForLoopExpression { desugared }) => {
                 Self::type_check_for_loop(handler, ctx.by_ref(), desugared)
             }
-            ExpressionKind::Break => {
-                let expr = ty::TyExpression {
-                    expression: ty::TyExpressionVariant::Break,
-                    return_type: type_engine.id_of_never(),
-                    span,
-                };
-                Ok(expr)
+            ExpressionKind::LabeledBlock(LabeledBlockExpression { label, block }) => {
+                Self::type_check_labeled_block(handler, ctx.by_ref(), label.clone(), block, span)
             }
-            ExpressionKind::Continue => {
+            ExpressionKind::Break(BreakExpression { label, value }) => {
+                Self::type_check_break(handler, ctx.by_ref(), label.clone(), value.as_deref(), span)
+            }
+            ExpressionKind::Continue(ContinueExpression { label }) => {
+                if let Some(label) = label {
+                    match ctx.enclosing_label(label) {
+                        Some(enclosing_label) if enclosing_label.block_type.is_some() => {
+                            return Err(handler.emit_err(CompileError::ContinueToLabeledBlock {
+                                label: label.clone(),
+                                span: label.span(),
+                            }));
+                        }
+                        Some(_) => {}
+                        None => {
+                            return Err(handler.emit_err(CompileError::UnknownLabel {
+                                label: label.clone(),
+                                span: label.span(),
+                            }));
+                        }
+                    }
+                }
                 let expr = ty::TyExpression {
-                    expression: ty::TyExpressionVariant::Continue,
+                    expression: ty::TyExpressionVariant::Continue {
+                        label: label.clone(),
+                    },
                     return_type: type_engine.id_of_never(),
                     span,
                 };
@@ -575,6 +617,14 @@ impl ty::TyExpression {
             ExpressionKind::Deref(expr) => {
                 Self::type_check_deref(handler, ctx.by_ref(), expr, span)
             }
+            ExpressionKind::Try(try_expr) => {
+                type_check_try_expression(handler, ctx.by_ref(), try_expr, span)
+            }
+            // Named arguments of function and method calls are put in the order of the parameters
+            // before the arguments get type checked, so any other named argument is misplaced.
+            ExpressionKind::NamedArgument(_) => {
+                Err(handler.emit_err(CompileError::NamedArgumentNotAllowed { span }))
+            }
         };
         let mut typed_expression = match res {
             Ok(r) => r,
@@ -2012,6 +2062,7 @@ impl ty::TyExpression {
         mut ctx: TypeCheckContext,
         prefix: &Expression,
         index: &Expression,
+        from_end: Option<u64>,
         span: Span,
     ) -> Result<Self, ErrorEmitted> {
         let type_engine = ctx.engines.te();
@@ -2063,16 +2114,37 @@ impl ty::TyExpression {
             };
         }
 
-        let TypeInfo::Array(array_type_argument, _) = &*current_type else {
+        let TypeInfo::Array(array_type_argument, array_len) = &*current_type else {
             panic!("The current type must be an array.");
         };
 
-        let index_te = {
-            let ctx = ctx
-                .with_help_text("Array index must be of type \"u64\".")
-                .with_type_annotation(type_engine.id_of_u64());
+        let index_te = match from_end {
+            // The index counts from the end of the array, which is known only now.
+            Some(min_len) => {
+                let count = array_len.val() as u64;
+                if count < min_len {
+                    return Err(handler.emit_err(CompileError::SlicePatternArrayTooShort {
+                        min_len,
+                        count,
+                        span,
+                    }));
+                }
+                let ExpressionKind::Literal(Literal::U64(position)) = index.kind else {
+                    panic!("Array indices counting from the end must be `u64` literals.");
+                };
+                ty::TyExpression {
+                    expression: ty::TyExpressionVariant::Literal(Literal::U64(count - position)),
+                    return_type: type_engine.id_of_u64(),
+                    span: index.span.clone(),
+                }
+            }
+            None => {
+                let ctx = ctx
+                    .with_help_text("Array index must be of type \"u64\".")
+                    .with_type_annotation(type_engine.id_of_u64());
 
-            ty::TyExpression::type_check(handler, ctx, index)?
+                ty::TyExpression::type_check(handler, ctx, index)?
+            }
         };
 
         Ok(ty::TyExpression {
@@ -2113,6 +2185,7 @@ impl ty::TyExpression {
         condition: &Expression,
         body: &CodeBlock,
         is_desugared_for_loop: bool,
+        label: Option<Ident>,
         span: Span,
     ) -> Result<Self, ErrorEmitted> {
         let type_engine = ctx.engines.te();
@@ -2137,12 +2210,16 @@ impl ty::TyExpression {
                  assigning it to a mutable variable declared outside of the loop \
                  instead."
             });
+        if let Some(label) = &label {
+            ctx = ctx.with_enclosing_label(label.clone(), None);
+        }
         let typed_body = ty::TyCodeBlock::type_check(handler, ctx.by_ref(), body, false)?;
 
         let exp = ty::TyExpression {
             expression: ty::TyExpressionVariant::WhileLoop {
                 condition: Box::new(typed_condition),
                 body: typed_body,
+                label,
             },
             return_type: unit_ty,
             span,
@@ -2150,12 +2227,182 @@ impl ty::TyExpression {
         Ok(exp)
     }
 
+    fn type_check_labeled_block(
+        handler: &Handler,
+        mut ctx: TypeCheckContext,
+        label: Ident,
+        block: &CodeBlock,
+        span: Span,
+    ) -> Result<Self, ErrorEmitted> {
+        let type_engine = ctx.engines.te();
+
+        // The values the block is exited with by `break` must be of the same type as the block.
+        let block_type = ctx.type_annotation();
+        let (typed_block, body_type) = match ty::TyCodeBlock::type_check(
+            handler,
+            ctx.by_ref()
+                .with_enclosing_label(label.clone(), Some(block_type)),
+            block,
+            false,
+        ) {
+            Ok(res) => {
+                let (body_type, _span) = TyCodeBlock::compute_return_type_and_span(&ctx, &res);
+                (res, body_type)
+            }
+            Err(_err) => (ty::TyCodeBlock::default(), type_engine.id_of_unit()),
+        };
+
+        // A block whose end is never reached, e.g., because it always ends with a `break`,
+        // still has the type of the values it is exited with.
+        let return_type = if matches!(&*type_engine.get(body_type), TypeInfo::Never)
+            && !matches!(&*type_engine.get(block_type), TypeInfo::Unknown)
+        {
+            block_type
+        } else {
+            body_type
+        };
+
+        Ok(ty::TyExpression {
+            expression: ty::TyExpressionVariant::LabeledBlock {
+                label,
+                block: typed_block,
+            },
+            return_type,
+            span,
+        })
+    }
+
+    fn type_check_break(
+        handler: &Handler,
+        mut ctx: TypeCheckContext,
+        label: Option<Ident>,
+        value: Option<&Expression>,
+        span: Span,
+    ) -> Result<Self, ErrorEmitted> {
+        let type_engine = ctx.engines.te();
+        let engines = ctx.engines();
+
+        let block_type = match &label {
+            Some(label) => match ctx.enclosing_label(label) {
+                Some(enclosing_label) => enclosing_label.block_type,
+                None => {
+                    return Err(handler.emit_err(CompileError::UnknownLabel {
+                        label: label.clone(),
+                        span: label.span(),
+                    }))
+                }
+            },
+            None => None,
+        };
+
+        let value = match (value, block_type) {
+            (Some(value), Some(block_type)) => {
+                let ctx = ctx
+                    .by_ref()
+                    .with_type_annotation(block_type)
+                    .with_help_text(
+                        "The value of a `break` must be of the same type as the labeled block.",
+                    );
+                let value_span = value.span();
+                let value = ty::TyExpression::type_check(handler, ctx, value)
+                    .unwrap_or_else(|err| ty::TyExpression::error(err, value_span, engines));
+                Some(Box::new(value))
+            }
+            (Some(value), None) => {
+                // The parser accepts values only after labels, so the label belongs to a loop.
+                return Err(handler.emit_err(CompileError::BreakWithValueOutOfLoop {
+                    label: label.expect("`break` with a value must have a label"),
+                    span: value.span(),
+                }));
+            }
+            (None, Some(block_type)) => {
+                // Exiting a labeled block without a value is the same as exiting it with `()`.
+                type_engine.unify(
+                    handler,
+                    engines,
+                    type_engine.id_of_unit(),
+                    block_type,
+                    &span,
+                    "The value of a `break` must be of the same type as the labeled block.",
+                    None,
+                );
+                None
+            }
+            (None, None) => None,
+        };
+
+        Ok(ty::TyExpression {
+            expression: ty::TyExpressionVariant::Break { label, value },
+            return_type: type_engine.id_of_never(),
+            span,
+        })
+    }
+
     fn type_check_for_loop(
         handler: &Handler,
         ctx: TypeCheckContext,
         desugared: &Expression,
     ) -> Result<Self, ErrorEmitted> {
-        Self::type_check(handler, ctx, desugared)
+        let engines = ctx.engines();
+        let typed_desugared = Self::type_check(handler, ctx, desugared)?;
+        Self::check_for_loop_iteration_bound(handler, engines, &typed_desugared);
+        Ok(typed_desugared)
+    }
+
+    /// Warns if the iterator of a desugared `for` loop is created by a function marked with
+    /// `#[iteration_bound(arg)]`, and the `arg` it is called with is not a constant.
+    fn check_for_loop_iteration_bound(
+        handler: &Handler,
+        engines: &Engines,
+        typed_desugared: &ty::TyExpression,
+    ) {
+        // The iterator is declared by the first statement of the desugared loop.
+        let TyExpressionVariant::CodeBlock(TyCodeBlock { contents, .. }) =
+            &typed_desugared.expression
+        else {
+            return;
+        };
+        let Some(ty::TyAstNode {
+            content: ty::TyAstNodeContent::Declaration(TyDecl::VariableDecl(iterable_decl)),
+            ..
+        }) = contents.first()
+        else {
+            return;
+        };
+        let TyExpressionVariant::FunctionApplication {
+            arguments, fn_ref, ..
+        } = &iterable_decl.body.expression
+        else {
+            return;
+        };
+
+        let fn_decl = engines.de().get_function(fn_ref);
+        let Some(attributes) = fn_decl
+            .attributes
+            .get(&crate::transform::AttributeKind::IterationBound)
+        else {
+            return;
+        };
+        for bound_name in attributes.iter().flat_map(|attr| attr.args.iter()) {
+            let Some((_, bound)) = arguments
+                .iter()
+                .find(|(name, _)| name.as_str() == bound_name.name.as_str())
+            else {
+                continue;
+            };
+            if !matches!(
+                bound.expression,
+                TyExpressionVariant::Literal(_) | TyExpressionVariant::ConstantExpression { .. }
+            ) {
+                handler.emit_warn(CompileWarning {
+                    span: bound.span.clone(),
+                    warning_content: Warning::IterationBoundNotConstant {
+                        fn_name: fn_decl.name.clone(),
+                        bound_name: bound_name.name.clone(),
+                    },
+                });
+            }
+        }
     }
 
     fn type_check_reassignment(
@@ -2343,7 +2590,9 @@ impl ty::TyExpression {
                             indices.push(ty::ProjectionKind::TupleField { index, index_span });
                             expr = prefix;
                         }
-                        ExpressionKind::ArrayIndex(ArrayIndexExpression { prefix, index }) => {
+                        ExpressionKind::ArrayIndex(ArrayIndexExpression {
+                            prefix, index, ..
+                        }) => {
                             let ctx = ctx
                                 .by_ref()
                                 .with_help_text("Array index must be of type \"u64\".")
@@ -9,6 +9,7 @@ use crate::{
     semantic_analysis::{ast_node::*, TypeCheckContext},
 };
 use indexmap::IndexMap;
+use std::borrow::Cow;
 use sway_error::error::CompileError;
 use sway_types::{IdentUnique, Spanned};
 
@@ -40,7 +41,13 @@ pub(crate) fn instantiate_function_application(
         );
     }
 
-    let arguments = arguments.unwrap_or_default();
+    let arguments = order_call_arguments(
+        handler,
+        &function_decl.name,
+        arguments.unwrap_or_default(),
+        &function_decl.parameters,
+    )?;
+    let arguments = &*arguments;
 
     // check that the number of parameters and the number of the arguments is the same
     check_function_arguments_arity(
@@ -230,6 +237,84 @@ fn unify_arguments_and_parameters(
     })
 }
 
+/// Puts the `arguments` of a call in the order of the `parameters` they are passed to, and
+/// completes them with the default values of the parameters no argument is passed to.
+///
+/// The arguments preceding the first named argument are positional. Parameters which are neither
+/// passed an argument nor have a default value are left out, so that the arity check reports them
+/// as missing.
+pub(crate) fn order_call_arguments<'a>(
+    handler: &Handler,
+    fn_name: &Ident,
+    arguments: &'a [Expression],
+    parameters: &[ty::TyFunctionParameter],
+) -> Result<Cow<'a, [Expression]>, ErrorEmitted> {
+    let positional_count = arguments
+        .iter()
+        .take_while(|arg| !matches!(arg.kind, ExpressionKind::NamedArgument(_)))
+        .count();
+
+    if positional_count == arguments.len() {
+        let default_arguments = parameters
+            .get(arguments.len()..)
+            .unwrap_or_default()
+            .iter()
+            .map_while(|param| param.default_argument())
+            .collect::<Vec<_>>();
+        return Ok(if default_arguments.is_empty() {
+            Cow::Borrowed(arguments)
+        } else {
+            Cow::Owned(arguments.iter().cloned().chain(default_arguments).collect())
+        });
+    }
+
+    handler.scope(|handler| {
+        let mut ordered_arguments: Vec<Option<Expression>> = vec![None; parameters.len()];
+        for (ordered_argument, arg) in ordered_arguments
+            .iter_mut()
+            .zip(&arguments[..positional_count])
+        {
+            *ordered_argument = Some(arg.clone());
+        }
+        // The conversion of the parse tree guarantees that all the following arguments are named.
+        let named_arguments =
+            arguments[positional_count..]
+                .iter()
+                .filter_map(|arg| match &arg.kind {
+                    ExpressionKind::NamedArgument(named_arg) => Some(named_arg),
+                    _ => None,
+                });
+        for named_arg in named_arguments {
+            let Some(index) = parameters
+                .iter()
+                .position(|param| param.name == named_arg.name)
+            else {
+                handler.emit_err(CompileError::UnknownNamedArgument {
+                    fn_name: fn_name.clone(),
+                    name: named_arg.name.clone(),
+                    span: named_arg.name.span(),
+                });
+                continue;
+            };
+            if ordered_arguments[index].is_some() {
+                handler.emit_err(CompileError::DuplicateNamedArgument {
+                    name: named_arg.name.clone(),
+                    span: named_arg.name.span(),
+                });
+                continue;
+            }
+            ordered_arguments[index] = Some(named_arg.value.clone());
+        }
+        Ok(Cow::Owned(
+            ordered_arguments
+                .into_iter()
+                .zip(parameters)
+                .filter_map(|(arg, param)| arg.or_else(|| param.default_argument()))
+                .collect(),
+        ))
+    })
+}
+
 pub(crate) fn check_function_arguments_arity(
     handler: &Handler,
     arguments_len: usize,
@@ -12,10 +12,13 @@ use crate::{
     semantic_analysis::*,
     type_system::*,
 };
-use ast_node::typed_expression::check_function_arguments_arity;
+use ast_node::typed_expression::{check_function_arguments_arity, order_call_arguments};
 use indexmap::IndexMap;
 use itertools::izip;
-use std::collections::{HashMap, VecDeque};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, VecDeque},
+};
 use sway_error::{
     error::CompileError,
     handler::{ErrorEmitted, Handler},
@@ -40,8 +43,14 @@ pub(crate) fn type_check_method_application(
     // type check the function arguments (1st pass)
     // Some arguments may fail on this first pass because they may require the type_annotation to the parameter type.
     // If they fail the args_opt_buf will contain a None value.
+    // Named arguments are only type checked on the second pass, once their parameters are known.
     let mut args_opt_buf = VecDeque::new();
     for (index, arg) in arguments.iter().enumerate() {
+        if matches!(arg.kind, ExpressionKind::NamedArgument(_)) {
+            args_opt_buf.push_back((None, true));
+            continue;
+        }
+
         let ctx = ctx
             .by_ref()
             .with_help_text("")
@@ -106,6 +115,33 @@ pub(crate) fn type_check_method_application(
 
     let mut method = (*decl_engine.get_function(&fn_ref)).clone();
 
+    // Put the named arguments in the order of the parameters, and complete the arguments with
+    // the default values of the parameters. The contract caller of a contract call is not passed
+    // to any parameter.
+    let ordered_arguments;
+    let (contract_caller, call_arguments) =
+        arguments.split_at(usize::from(method.is_contract_call).min(arguments.len()));
+    let positional_count = contract_caller.len()
+        + call_arguments
+            .iter()
+            .take_while(|arg| !matches!(arg.kind, ExpressionKind::NamedArgument(_)))
+            .count();
+    let arguments =
+        match order_call_arguments(handler, &method.name, call_arguments, &method.parameters)? {
+            Cow::Borrowed(_) => arguments,
+            Cow::Owned(call_arguments) => {
+                // Only the positional arguments could have been type checked on the first pass.
+                args_opt_buf.truncate(positional_count);
+                ordered_arguments = contract_caller
+                    .iter()
+                    .cloned()
+                    .chain(call_arguments)
+                    .collect::<Vec<_>>();
+                args_opt_buf.resize(ordered_arguments.len(), (None, true));
+                &ordered_arguments[..]
+            }
+        };
+
     // unify method return type with current ctx.type_annotation().
     type_engine.unify_with_generic(
         handler,
@@ -488,10 +524,15 @@ pub(crate) fn type_check_method_application(
             }
         }
 
-        fn string_slice_literal(ident: &BaseIdent) -> Expression {
+        fn method_name_literal(method: &ty::TyFunctionDecl) -> Expression {
+            let value = if method.abi_method_name() == method.name.as_str() {
+                method.name.span()
+            } else {
+                Span::from_string(method.abi_method_name().to_string())
+            };
             Expression {
-                kind: ExpressionKind::Literal(Literal::String(ident.span())),
-                span: ident.span(),
+                kind: ExpressionKind::Literal(Literal::String(value)),
+                span: method.name.span(),
             }
         }
 
@@ -537,7 +578,7 @@ pub(crate) fn type_check_method_application(
             &mut ctx,
             span,
             method.return_type.type_id,
-            string_slice_literal(&method.name),
+            method_name_literal(&method),
             old_arguments.first().cloned().unwrap(),
             args,
             arguments.iter().map(|x| x.1.return_type).collect(),
@@ -925,7 +966,24 @@ pub(crate) fn monomorphize_method(
 ) -> Result<DeclRefFunction, ErrorEmitted> {
     let engines = ctx.engines();
     let decl_engine = engines.de();
-    let mut func_decl = (*decl_engine.get_function(&decl_ref)).clone();
+
+    let func_decl = decl_engine.get_function(&decl_ref);
+    let is_cacheable = !ctx.code_block_first_pass()
+        && func_decl.is_type_check_finalized
+        && !func_decl.is_trait_method_dummy;
+
+    // Without type arguments, a function with a concrete signature is always instantiated
+    // the same, so we can skip the monomorphization entirely if it was cached.
+    if is_cacheable
+        && type_arguments.is_empty()
+        && TyFunctionSig::from_fn_decl(&func_decl).is_concrete(engines)
+    {
+        if let Some(cached_fn_ref) = engines.qe().get_monomorphized_function(*decl_ref.id(), &[]) {
+            return Ok(cached_fn_ref);
+        }
+    }
+
+    let mut func_decl = (*func_decl).clone();
 
     // monomorphize the function declaration
     ctx.monomorphize(
@@ -936,18 +994,43 @@ pub(crate) fn monomorphize_method(
         &decl_ref.span(),
     )?;
 
+    let type_arguments = type_arguments
+        .iter()
+        .map(|type_argument| type_argument.type_id.get_type_str(engines))
+        .collect::<Vec<_>>();
+    let is_cacheable = is_cacheable && TyFunctionSig::from_fn_decl(&func_decl).is_concrete(engines);
+    if is_cacheable {
+        if let Some(cached_fn_ref) = engines
+            .qe()
+            .get_monomorphized_function(*decl_ref.id(), &type_arguments)
+        {
+            return Ok(cached_fn_ref);
+        }
+    }
+
     if let Some(implementing_type) = &func_decl.implementing_type {
         func_decl
             .body
             .update_constant_expression(engines, implementing_type);
     }
 
+    let generic_fn_decl = *decl_ref.id();
     let decl_ref = decl_engine
         .insert(
             func_decl,
             decl_engine.get_parsed_decl_id(decl_ref.id()).as_ref(),
         )
-        .with_parent(decl_engine, (*decl_ref.id()).into());
+        .with_parent(decl_engine, generic_fn_decl.into());
+
+    // Monomorphized functions are shared by all the packages compiled with the same engines,
+    // so identical instantiations of library functions are only created once.
+    if is_cacheable {
+        engines.qe().insert_monomorphized_function(
+            generic_fn_decl,
+            type_arguments,
+            decl_ref.clone(),
+        );
+    }
 
     Ok(decl_ref)
 }
@@ -0,0 +1,303 @@
+use sway_error::{
+    error::CompileError,
+    handler::{ErrorEmitted, Handler},
+};
+use sway_types::{Ident, Span, Spanned};
+
+use crate::{
+    language::{parsed::*, ty, CallPath},
+    semantic_analysis::TypeCheckContext,
+    type_system::*,
+    Engines,
+};
+
+/// The spans of the lexical scopes of the two branches of the `match` the `try_expr` is desugared
+/// into, the one of the value and the one of the failure.
+pub(crate) fn try_expression_branch_spans(span: &Span, try_expr: &TryExpression) -> [Span; 2] {
+    [span.clone(), try_expr.question_mark_span.clone()]
+}
+
+/// Type checks `expr?`, by desugaring it into:
+///
+/// ```ignore
+/// {
+///     let __try_operand_N = expr;
+///     let __matched_value_N = ::std::ops::branch(__try_operand_N);
+///     match __matched_value_N {
+///         ControlFlow::Continue(value) => value,
+///         ControlFlow::Break(residual) => return R::from_residual(residual),
+///     }
+/// }
+/// ```
+///
+/// where `R` is the return type of the enclosing function. The type of `expr` must implement
+/// `std::ops::Try`, and `R` must implement `std::ops::FromResidual` for the residual of `expr`.
+pub(crate) fn type_check_try_expression(
+    handler: &Handler,
+    mut ctx: TypeCheckContext,
+    try_expr: &TryExpression,
+    span: Span,
+) -> Result<ty::TyExpression, ErrorEmitted> {
+    let type_engine = ctx.engines.te();
+    let decl_engine = ctx.engines.de();
+    let engines = ctx.engines();
+
+    let typed_operand = {
+        let ctx = ctx
+            .by_ref()
+            .with_help_text("")
+            .with_type_annotation(type_engine.new_unknown());
+        ty::TyExpression::type_check(handler, ctx, &try_expr.expr)?
+    };
+    let operand_type = typed_operand.return_type;
+
+    // The operand is referred to by name in the desugared call to `branch`.
+    let operand_var_name = try_expr.operand_var_name.clone();
+    let operand_var_decl = variable_decl(operand_var_name.clone(), typed_operand);
+    ctx.insert_symbol(handler, operand_var_name.clone(), operand_var_decl.clone())?;
+
+    // Failing to call `branch` means that the operand doesn't implement `Try`.
+    let branch_handler = Handler::default();
+    let typed_branch = ty::TyExpression::type_check(
+        &branch_handler,
+        ctx.by_ref()
+            .with_help_text("")
+            .with_type_annotation(type_engine.new_unknown()),
+        &branch_call(&operand_var_name, &try_expr.question_mark_span),
+    );
+    let typed_branch = match typed_branch {
+        Ok(typed_branch) if !branch_handler.has_errors() => typed_branch,
+        _ => {
+            return Err(handler.emit_err(CompileError::TryOperatorUnsupportedType {
+                ty: engines.help_out(operand_type).to_string(),
+                span: try_expr.expr.span(),
+            }))
+        }
+    };
+    handler.append(branch_handler);
+
+    let control_flow_decl = match &*type_engine.get(typed_branch.return_type) {
+        TypeInfo::Enum(decl_id) => decl_engine.get_enum(decl_id),
+        _ => {
+            return Err(handler.emit_err(CompileError::Internal(
+                "`branch` must return a `ControlFlow`.",
+                span,
+            )))
+        }
+    };
+    let residual_type = control_flow_decl.type_parameters[0].type_id;
+
+    let matched_value_var_name = try_expr.matched_value_var_name.clone();
+    let matched_value = ty::TyExpression {
+        expression: ty::TyExpressionVariant::VariableExpression {
+            name: matched_value_var_name.clone(),
+            span: typed_branch.span.clone(),
+            mutability: ty::VariableMutability::Immutable,
+            call_path: None,
+        },
+        return_type: typed_branch.return_type,
+        span: typed_branch.span.clone(),
+    };
+    let matched_value_var_decl = variable_decl(matched_value_var_name, typed_branch);
+
+    // Only the failure branch can fail to type check, if the enclosing function doesn't return
+    // a type the residual can be converted into.
+    let function_return_type = ctx.function_type_annotation();
+    let branches = desugared_branches(
+        engines,
+        &control_flow_decl.call_path,
+        function_return_type,
+        try_expr,
+        &span,
+    );
+    let match_handler = Handler::default();
+    let typed_match = ty::TyMatchExpression::type_check(
+        &match_handler,
+        ctx.by_ref()
+            .with_help_text("")
+            .with_type_annotation(type_engine.new_unknown()),
+        matched_value,
+        branches,
+        span.clone(),
+    );
+    let (typed_match_expression, typed_scrutinees) = match typed_match {
+        Ok(typed_match) if !match_handler.has_errors() => typed_match,
+        _ => {
+            let return_type = match &*type_engine.get(function_return_type) {
+                TypeInfo::Unknown => None,
+                _ => Some(engines.help_out(function_return_type).to_string()),
+            };
+            return Err(
+                handler.emit_err(CompileError::TryOperatorIncompatibleReturnType {
+                    operand_type: engines.help_out(operand_type).to_string(),
+                    residual_type: engines.help_out(residual_type).to_string(),
+                    return_type,
+                    span,
+                }),
+            );
+        }
+    };
+    handler.append(match_handler);
+
+    let desugared = typed_match_expression.desugar(handler, ctx.by_ref())?;
+    let return_type = desugared.return_type;
+    let match_exp = ty::TyExpression {
+        span: desugared.span.clone(),
+        return_type,
+        expression: ty::TyExpressionVariant::MatchExp {
+            desugared: Box::new(desugared),
+            scrutinees: typed_scrutinees,
+        },
+    };
+
+    Ok(ty::TyExpression {
+        expression: ty::TyExpressionVariant::CodeBlock(ty::TyCodeBlock {
+            contents: vec![
+                ty::TyAstNode {
+                    content: ty::TyAstNodeContent::Declaration(operand_var_decl),
+                    span: operand_var_name.span(),
+                },
+                ty::TyAstNode {
+                    content: ty::TyAstNodeContent::Declaration(matched_value_var_decl),
+                    span: try_expr.matched_value_var_name.span(),
+                },
+                ty::TyAstNode {
+                    content: ty::TyAstNodeContent::Expression(ty::TyExpression {
+                        span: match_exp.span.clone(),
+                        return_type,
+                        expression: ty::TyExpressionVariant::ImplicitReturn(Box::new(match_exp)),
+                    }),
+                    span: span.clone(),
+                },
+            ],
+            whole_block_span: span.clone(),
+        }),
+        return_type,
+        span,
+    })
+}
+
+/// Returns the declaration of the immutable variable `name` holding the `body`.
+fn variable_decl(name: Ident, body: ty::TyExpression) -> ty::TyDecl {
+    ty::TyDecl::VariableDecl(Box::new(ty::TyVariableDecl {
+        name,
+        return_type: body.return_type,
+        type_ascription: body.return_type.into(),
+        body,
+        mutability: ty::VariableMutability::Immutable,
+    }))
+}
+
+/// Returns the call `::std::ops::branch(operand)`, where `operand` is the variable
+/// `operand_var_name`.
+fn branch_call(operand_var_name: &Ident, question_mark_span: &Span) -> Expression {
+    let ident = |name: &str| Ident::new_with_override(name.to_string(), question_mark_span.clone());
+    Expression {
+        kind: ExpressionKind::MethodApplication(Box::new(MethodApplicationExpression {
+            method_name_binding: TypeBinding {
+                inner: MethodName::FromTrait {
+                    call_path: CallPath {
+                        prefixes: vec![ident("std"), ident("ops")],
+                        suffix: ident("branch"),
+                        is_absolute: true,
+                    },
+                },
+                type_arguments: TypeArgs::Regular(vec![]),
+                span: question_mark_span.clone(),
+            },
+            contract_call_params: vec![],
+            arguments: vec![Expression {
+                kind: ExpressionKind::Variable(operand_var_name.clone()),
+                span: operand_var_name.span(),
+            }],
+        })),
+        span: question_mark_span.clone(),
+    }
+}
+
+/// Returns the branches of the `match` on the `ControlFlow` declared at `control_flow_call_path`,
+/// returned by `branch`. `function_return_type` is the return type of the enclosing function.
+fn desugared_branches(
+    engines: &Engines,
+    control_flow_call_path: &CallPath,
+    function_return_type: TypeId,
+    try_expr: &TryExpression,
+    span: &Span,
+) -> Vec<MatchBranch> {
+    let question_mark_span = try_expr.question_mark_span.clone();
+    let ident = |name: &str| Ident::new_with_override(name.to_string(), question_mark_span.clone());
+    let variant_call_path = |variant_name: &str| {
+        let mut prefixes = control_flow_call_path.prefixes.clone();
+        prefixes.push(control_flow_call_path.suffix.clone());
+        CallPath {
+            prefixes,
+            suffix: ident(variant_name),
+            is_absolute: true,
+        }
+    };
+    let variable = |name: &str| Ident::new_with_override(name.to_string(), span.clone());
+    let expression = |kind| Expression {
+        kind,
+        span: question_mark_span.clone(),
+    };
+
+    let [value_branch_span, failure_branch_span] = try_expression_branch_spans(span, try_expr);
+
+    // `ControlFlow::Continue(value) => value`.
+    let value = variable("__try_value");
+    let value_branch = MatchBranch {
+        scrutinee: Scrutinee::EnumScrutinee {
+            call_path: variant_call_path("Continue"),
+            value: Box::new(Scrutinee::Variable {
+                name: value.clone(),
+                span: span.clone(),
+            }),
+            span: span.clone(),
+        },
+        result: expression(ExpressionKind::Variable(value)),
+        span: value_branch_span,
+    };
+
+    // `ControlFlow::Break(residual) => return R::from_residual(residual)`.
+    let residual = variable("__try_residual");
+    let from_residual_call =
+        ExpressionKind::MethodApplication(Box::new(MethodApplicationExpression {
+            method_name_binding: TypeBinding {
+                inner: MethodName::FromType {
+                    call_path_binding: TypeBinding {
+                        inner: CallPath {
+                            prefixes: vec![],
+                            suffix: (
+                                (*engines.te().get(function_return_type)).clone(),
+                                ident(&engines.help_out(function_return_type).to_string()),
+                            ),
+                            is_absolute: false,
+                        },
+                        type_arguments: TypeArgs::Regular(vec![]),
+                        span: question_mark_span.clone(),
+                    },
+                    method_name: ident("from_residual"),
+                },
+                type_arguments: TypeArgs::Regular(vec![]),
+                span: question_mark_span.clone(),
+            },
+            contract_call_params: vec![],
+            arguments: vec![expression(ExpressionKind::Variable(residual.clone()))],
+        }));
+    let failure_branch = MatchBranch {
+        scrutinee: Scrutinee::EnumScrutinee {
+            call_path: variant_call_path("Break"),
+            value: Box::new(Scrutinee::Variable {
+                name: residual,
+                span: question_mark_span.clone(),
+            }),
+            span: question_mark_span.clone(),
+        },
+        result: expression(ExpressionKind::Return(Box::new(expression(
+            from_residual_call,
+        )))),
+        span: failure_branch_span,
+    };
+
+    vec![value_branch, failure_branch]
+}
@@ -5,19 +5,31 @@
 // for more detail on vulnerabilities in case of storage modification after interaction
 // and this [blog post](https://chainsecurity.com/curve-lp-oracle-manipulation-post-mortem)
 // for more information on storage reads after interaction.
-// We also treat the balance tree reads and writes separately,
-// as well as modifying output messages.
+// We also treat the balance tree reads and value transfers (transferring, minting
+// and burning assets) separately, as well as modifying output messages.
+// Besides CEI, the same effects are used to check that ABI methods reading
+// the amount of coins forwarded to them (`msg_amount`) are `#[payable]`.
+//
+// CEI only looks at a single function. During an external call, the called contract
+// can also re-enter any other ABI method of this contract, so we additionally report
+// storage fields that are read before an external call in one ABI method and written
+// by another one, as the value read can be stale once the call returns.
+// Functions that call a function marked as `#[reentrancy_guard]` cannot be re-entered,
+// so they are excluded from both analyses.
 
 use crate::{
     decl_engine::*,
     language::{
         ty::{self, TyFunctionDecl, TyImplSelfOrTrait},
-        AsmOp,
+        AsmOp, AsmRegister,
     },
     Engines,
 };
 use std::fmt;
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::Arc,
+};
 use sway_error::warning::{CompileWarning, Warning};
 use sway_types::{Ident, Span, Spanned};
 
@@ -27,11 +39,12 @@ enum Effect {
     StorageWrite, // storage modification
     StorageRead,  // storage read
     // Note: there are no operations that only write to the balance tree
-    BalanceTreeRead,      // balance tree read operation
-    BalanceTreeReadWrite, // balance tree read and write operation
-    OutputMessage,        // operation creates a new `Output::Message`
-    MintAsset,            // mint operation
-    BurnAsset,            // burn operation
+    BalanceTreeRead, // balance tree read operation
+    TransferAsset,   // transfer operation, reads and writes the balance tree
+    OutputMessage,   // operation creates a new `Output::Message`
+    MintAsset,       // mint operation
+    BurnAsset,       // burn operation
+    MsgAmountRead,   // read of the amount of coins forwarded with the call
 }
 
 impl fmt::Display for Effect {
@@ -42,10 +55,11 @@ impl fmt::Display for Effect {
             StorageWrite => write!(f, "Storage write"),
             StorageRead => write!(f, "Storage read"),
             BalanceTreeRead => write!(f, "Balance tree read"),
-            BalanceTreeReadWrite => write!(f, "Balance tree update"),
+            TransferAsset => write!(f, "Asset transferred"),
             OutputMessage => write!(f, "Output message sent"),
             MintAsset => write!(f, "Asset minted"),
             BurnAsset => write!(f, "Asset burned"),
+            MsgAmountRead => write!(f, "Forwarded coins amount read"),
         }
     }
 }
@@ -58,12 +72,19 @@ impl Effect {
             StorageWrite => "making all storage writes",
             StorageRead => "making all storage reads",
             BalanceTreeRead => "making all balance tree reads",
-            BalanceTreeReadWrite => "making all balance tree updates",
+            TransferAsset => "transferring assets",
             OutputMessage => "sending all output messages",
             MintAsset => "minting assets",
             BurnAsset => "burning assets",
+            MsgAmountRead => "reading the forwarded coins amount",
         })
     }
+
+    // Reading the forwarded coins amount is not affected by calling another contract,
+    // as the amount is restored when the call returns.
+    fn is_reported_after_interaction(&self) -> bool {
+        !matches!(self, Effect::Interaction | Effect::MsgAmountRead)
+    }
 }
 
 // The algorithm that searches for storage operations after interaction
@@ -91,14 +112,311 @@ fn analyze_contract(engines: &Engines, ast_nodes: &[ty::TyAstNode]) -> Vec<Compi
     let mut warnings: Vec<CompileWarning> = vec![];
     for fn_decl in contract_entry_points(decl_engine, ast_nodes) {
         // no need to analyze the entry fn
-        if fn_decl.name.as_str() == "__entry" {
+        if fn_decl.name.as_str() == "__entry" || calls_reentrancy_guard(engines, &fn_decl.body) {
             continue;
         }
         analyze_code_block(engines, &fn_decl.body, &fn_decl.name, &mut warnings);
     }
+    let abi_methods = contract_abi_methods(engines, ast_nodes);
+    for fn_decl in &abi_methods {
+        analyze_payable(engines, fn_decl, &mut warnings);
+    }
+    analyze_cross_function_reentrancy(engines, &abi_methods, &mut warnings);
     warnings
 }
 
+// methods of the `impl <ABI> for Contract` declarations
+fn contract_abi_methods(
+    engines: &Engines,
+    ast_nodes: &[ty::TyAstNode],
+) -> Vec<Arc<ty::TyFunctionDecl>> {
+    use crate::ty::TyAstNodeContent::Declaration;
+    let decl_engine = engines.de();
+    ast_nodes
+        .iter()
+        .flat_map(|ast_node| match &ast_node.content {
+            Declaration(ty::TyDecl::ImplSelfOrTrait(ty::ImplSelfOrTrait { decl_id, .. }))
+                if decl_engine
+                    .get_impl_self_or_trait(decl_id)
+                    .is_impl_contract(engines.te()) =>
+            {
+                impl_trait_methods(decl_engine, decl_id)
+            }
+            _ => vec![],
+        })
+        .collect()
+}
+
+// A method that is not `#[payable]` cannot be called with coins forwarded to it,
+// so reading the forwarded amount, directly or via called functions, is most likely
+// a sign of a missing `#[payable]` attribute.
+fn analyze_payable(
+    engines: &Engines,
+    fn_decl: &ty::TyFunctionDecl,
+    warnings: &mut Vec<CompileWarning>,
+) {
+    if fn_decl
+        .attributes
+        .contains_key(&crate::transform::AttributeKind::Payable)
+    {
+        return;
+    }
+    if effects_of_codeblock(engines, &fn_decl.body).contains(&Effect::MsgAmountRead) {
+        warnings.push(CompileWarning {
+            span: fn_decl.name.span(),
+            warning_content: Warning::NonPayableMethodReadsMsgAmount {
+                method_name: fn_decl.name.clone(),
+            },
+        });
+    }
+}
+
+// A storage field read before an external call is stale after the call if the called
+// contract can re-enter another ABI method that writes the field.
+fn analyze_cross_function_reentrancy(
+    engines: &Engines,
+    abi_methods: &[Arc<ty::TyFunctionDecl>],
+    warnings: &mut Vec<CompileWarning>,
+) {
+    let unguarded_methods = abi_methods
+        .iter()
+        .filter(|fn_decl| !calls_reentrancy_guard(engines, &fn_decl.body))
+        .collect::<Vec<_>>();
+    let written_fields = unguarded_methods
+        .iter()
+        .map(|fn_decl| {
+            let mut accesses = StorageAccesses::default();
+            accesses.collect_from_codeblock(engines, &fn_decl.body);
+            accesses.writes
+        })
+        .collect::<Vec<_>>();
+
+    for fn_decl in &unguarded_methods {
+        for (field, read_span) in storage_reads_before_interaction(engines, &fn_decl.body) {
+            let reentered = unguarded_methods
+                .iter()
+                .zip(&written_fields)
+                .find(|(other, writes)| other.name != fn_decl.name && writes.contains(&field));
+            if let Some((other, _)) = reentered {
+                warnings.push(CompileWarning {
+                    span: read_span,
+                    warning_content: Warning::CrossFunctionReentrancy {
+                        storage_field: field,
+                        method_name: fn_decl.name.clone(),
+                        reentered_method_name: other.name.clone(),
+                    },
+                });
+            }
+        }
+    }
+}
+
+// The storage fields read by the statements of the code block up to, and including,
+// the first one that interacts with another contract. Empty if there is no interaction.
+fn storage_reads_before_interaction(
+    engines: &Engines,
+    codeblock: &ty::TyCodeBlock,
+) -> BTreeMap<String, Span> {
+    let mut accesses = StorageAccesses::default();
+    for ast_node in &codeblock.contents {
+        if let Some(expr) = ast_node_expression(ast_node) {
+            accesses.collect(engines, expr);
+        }
+        if effects_of_codeblock_entry(engines, ast_node).contains(&Effect::Interaction) {
+            return accesses.reads;
+        }
+    }
+    BTreeMap::new()
+}
+
+// Whether the code block calls a function marked as `#[reentrancy_guard]`.
+fn calls_reentrancy_guard(engines: &Engines, codeblock: &ty::TyCodeBlock) -> bool {
+    fn calls_guard(engines: &Engines, expr: &ty::TyExpression) -> bool {
+        if let ty::TyExpressionVariant::FunctionApplication { fn_ref, .. } = &expr.expression {
+            if engines
+                .de()
+                .get_function(fn_ref)
+                .attributes
+                .contains_key(&crate::transform::AttributeKind::ReentrancyGuard)
+            {
+                return true;
+            }
+        }
+        sub_expressions(expr)
+            .into_iter()
+            .any(|expr| calls_guard(engines, expr))
+    }
+    codeblock
+        .contents
+        .iter()
+        .filter_map(ast_node_expression)
+        .any(|expr| calls_guard(engines, expr))
+}
+
+// The storage fields accessed by an expression, including within the functions it calls.
+// Fields are identified by their namespaces and name, e.g. `my_namespace::balances`.
+#[derive(Default)]
+struct StorageAccesses {
+    reads: BTreeMap<String, Span>,
+    writes: HashSet<String>,
+    visited_fns: HashSet<DeclId<TyFunctionDecl>>,
+}
+
+impl StorageAccesses {
+    fn collect_from_codeblock(&mut self, engines: &Engines, codeblock: &ty::TyCodeBlock) {
+        for expr in codeblock.contents.iter().filter_map(ast_node_expression) {
+            self.collect(engines, expr);
+        }
+    }
+
+    fn collect(&mut self, engines: &Engines, expr: &ty::TyExpression) {
+        use crate::ty::TyExpressionVariant::*;
+        match &expr.expression {
+            StorageAccess(access) => {
+                self.reads
+                    .entry(access.storage_field_names.join("::"))
+                    .or_insert_with(|| expr.span.clone());
+                return;
+            }
+            FunctionApplication {
+                arguments,
+                fn_ref,
+                selector: None,
+                ..
+            } => {
+                let func = engines.de().get_function(fn_ref);
+                // Methods of storage types, like `read` and `write`, access the field
+                // they are called on.
+                if let Some((_, self_arg)) = arguments.first() {
+                    if let Some(field) = storage_field_of(self_arg) {
+                        let effects = effects_of_codeblock(engines, &func.body);
+                        if effects.contains(&Effect::StorageWrite) {
+                            self.writes.insert(field.clone());
+                        }
+                        if effects.contains(&Effect::StorageRead) {
+                            self.reads.entry(field).or_insert_with(|| expr.span.clone());
+                        }
+                        if !matches!(self_arg.expression, StorageAccess(_)) {
+                            self.collect(engines, self_arg);
+                        }
+                        for (_, arg) in &arguments[1..] {
+                            self.collect(engines, arg);
+                        }
+                        return;
+                    }
+                }
+                if self.visited_fns.insert(*fn_ref.id()) {
+                    self.collect_from_codeblock(engines, &func.body);
+                }
+            }
+            _ => {}
+        }
+        for expr in sub_expressions(expr) {
+            self.collect(engines, expr);
+        }
+    }
+}
+
+// The storage field a method is called on, e.g. `balances` for `storage.balances.get(key).read()`.
+fn storage_field_of(expr: &ty::TyExpression) -> Option<String> {
+    use crate::ty::TyExpressionVariant::*;
+    match &expr.expression {
+        StorageAccess(access) => Some(access.storage_field_names.join("::")),
+        FunctionApplication {
+            arguments,
+            selector: None,
+            ..
+        } => arguments
+            .first()
+            .and_then(|(_, self_arg)| storage_field_of(self_arg)),
+        StructFieldAccess { prefix, .. } => storage_field_of(prefix),
+        _ => None,
+    }
+}
+
+fn ast_node_expression(ast_node: &ty::TyAstNode) -> Option<&ty::TyExpression> {
+    match &ast_node.content {
+        ty::TyAstNodeContent::Declaration(ty::TyDecl::VariableDecl(var_decl)) => {
+            Some(&var_decl.body)
+        }
+        ty::TyAstNodeContent::Expression(expr) => Some(expr),
+        _ => None,
+    }
+}
+
+// The expressions directly nested within an expression, in the order of evaluation.
+pub(crate) fn sub_expressions(expr: &ty::TyExpression) -> Vec<&ty::TyExpression> {
+    use crate::ty::TyExpressionVariant::*;
+    match &expr.expression {
+        Literal(_)
+        | ConstantExpression { .. }
+        | ConfigurableExpression { .. }
+        | VariableExpression { .. }
+        | FunctionParameter
+        | StorageAccess(_)
+        | Break { value: None, .. }
+        | Continue { .. }
+        | AbiName(_) => vec![],
+        Break {
+            value: Some(value), ..
+        } => vec![&**value],
+        FunctionApplication { arguments, .. } => arguments.iter().map(|(_, e)| e).collect(),
+        LazyOperator { lhs, rhs, .. }
+        | ArrayIndex {
+            prefix: lhs,
+            index: rhs,
+        } => vec![&**lhs, &**rhs],
+        Tuple { fields: exprs }
+        | Array {
+            elem_type: _,
+            contents: exprs,
+        } => exprs.iter().collect(),
+        StructExpression { fields, .. } => fields.iter().map(|field| &field.value).collect(),
+        CodeBlock(codeblock)
+        | LabeledBlock {
+            block: codeblock, ..
+        } => codeblock
+            .contents
+            .iter()
+            .filter_map(ast_node_expression)
+            .collect(),
+        WhileLoop {
+            condition, body, ..
+        } => std::iter::once(&**condition)
+            .chain(body.contents.iter().filter_map(ast_node_expression))
+            .collect(),
+        IfExp {
+            condition,
+            then,
+            r#else,
+        } => {
+            let mut exprs = vec![&**condition, &**then];
+            exprs.extend(r#else.as_deref());
+            exprs
+        }
+        StructFieldAccess { prefix: expr, .. }
+        | TupleElemAccess { prefix: expr, .. }
+        | EnumTag { exp: expr }
+        | UnsafeDowncast { exp: expr, .. }
+        | AbiCast { address: expr, .. }
+        | ImplicitReturn(expr)
+        | Return(expr)
+        | Ref(expr)
+        | Deref(expr)
+        | MatchExp {
+            desugared: expr, ..
+        }
+        | ForLoop { desugared: expr } => vec![&**expr],
+        EnumInstantiation { contents, .. } => contents.as_deref().into_iter().collect(),
+        Reassignment(reassignment) => vec![&reassignment.rhs],
+        IntrinsicFunction(intrinsic) => intrinsic.arguments.iter().collect(),
+        AsmExpression { registers, .. } => registers
+            .iter()
+            .filter_map(|register| register.initializer.as_ref())
+            .collect(),
+    }
+}
+
 // standalone functions and methods
 fn contract_entry_points(
     decl_engine: &DeclEngine,
@@ -225,11 +543,17 @@ fn analyze_expression(
         | VariableExpression { .. }
         | FunctionParameter
         | StorageAccess(_)
-        | Break
-        | Continue
+        | Break { value: None, .. }
+        | Continue { .. }
         | AbiName(_) => effects_of_expression(engines, expr),
+        Break {
+            value: Some(value), ..
+        } => analyze_expression(engines, value, block_name, warnings),
         Reassignment(reassgn) => analyze_expression(engines, &reassgn.rhs, block_name, warnings),
-        CodeBlock(codeblock) => analyze_code_block(engines, codeblock, block_name, warnings),
+        CodeBlock(codeblock)
+        | LabeledBlock {
+            block: codeblock, ..
+        } => analyze_code_block(engines, codeblock, block_name, warnings),
         LazyOperator {
             lhs: left,
             rhs: right,
@@ -336,7 +660,9 @@ fn analyze_expression(
             };
             set_union(cond_then_effs, cond_else_effs)
         }
-        WhileLoop { condition, body } => {
+        WhileLoop {
+            condition, body, ..
+        } => {
             // if the loop (condition + body) contains both interaction and state effects
             // in _any_ order, we report CEI pattern violation
             let cond_effs = analyze_expression(engines, condition, block_name, warnings);
@@ -352,7 +678,10 @@ fn analyze_expression(
         }
         ForLoop { desugared } => analyze_expression(engines, desugared, block_name, warnings),
         AsmExpression {
-            registers, body, ..
+            registers,
+            body,
+            returns,
+            ..
         } => {
             let init_exprs = registers
                 .iter()
@@ -363,7 +692,10 @@ fn analyze_expression(
                 // TODO: improve locations accuracy
                 warn_after_interaction(&asmblock_effs, &expr.span, &expr.span, block_name, warnings)
             }
-            set_union(init_effs, asmblock_effs)
+            set_union(
+                set_union(init_effs, asmblock_effs),
+                effects_of_asm_returns(returns),
+            )
         }
     }
 }
@@ -463,8 +795,9 @@ fn warn_after_interaction(
     block_name: &Ident,
     warnings: &mut Vec<CompileWarning>,
 ) {
-    let interaction_singleton = HashSet::from([Effect::Interaction]);
-    let state_effects = ast_node_effects.difference(&interaction_singleton);
+    let state_effects = ast_node_effects
+        .iter()
+        .filter(|eff| eff.is_reported_after_interaction());
     for eff in state_effects {
         warnings.push(CompileWarning {
             span: Span::join(interaction_span.clone(), effect_span),
@@ -504,9 +837,12 @@ fn effects_of_expression(engines: &Engines, expr: &ty::TyExpression) -> HashSet<
         | ConfigurableExpression { .. }
         | VariableExpression { .. }
         | FunctionParameter
-        | Break
-        | Continue
+        | Break { value: None, .. }
+        | Continue { .. }
         | AbiName(_) => HashSet::new(),
+        Break {
+            value: Some(value), ..
+        } => effects_of_expression(engines, value),
         // this type of assignment only mutates local variables and not storage
         Reassignment(reassgn) => effects_of_expression(engines, &reassgn.rhs),
         StorageAccess(_) => match &*type_engine.get(expr.return_type) {
@@ -543,7 +879,10 @@ fn effects_of_expression(engines: &Engines, expr: &ty::TyExpression) -> HashSet<
             contents: exprs,
         } => effects_of_expressions(engines, exprs),
         StructExpression { fields, .. } => effects_of_struct_expressions(engines, fields),
-        CodeBlock(codeblock) => effects_of_codeblock(engines, codeblock),
+        CodeBlock(codeblock)
+        | LabeledBlock {
+            block: codeblock, ..
+        } => effects_of_codeblock(engines, codeblock),
         MatchExp { desugared, .. } => effects_of_expression(engines, desugared),
         IfExp {
             condition,
@@ -576,7 +915,9 @@ fn effects_of_expression(engines: &Engines, expr: &ty::TyExpression) -> HashSet<
             .union(&effects_of_intrinsic(&intr_fn.kind))
             .cloned()
             .collect(),
-        WhileLoop { condition, body } => effects_of_expression(engines, condition)
+        WhileLoop {
+            condition, body, ..
+        } => effects_of_expression(engines, condition)
             .union(&effects_of_codeblock(engines, body))
             .cloned()
             .collect(),
@@ -600,12 +941,12 @@ fn effects_of_expression(engines: &Engines, expr: &ty::TyExpression) -> HashSet<
         AsmExpression {
             registers,
             body,
+            returns,
             whole_block_span: _,
-            ..
-        } => effects_of_register_initializers(engines, registers)
-            .union(&effects_of_asm_ops(body))
-            .cloned()
-            .collect(),
+        } => set_union(
+            effects_of_register_initializers(engines, registers),
+            set_union(effects_of_asm_ops(body), effects_of_asm_returns(returns)),
+        ),
     }
 }
 
@@ -655,10 +996,22 @@ fn effects_of_intrinsic(intr: &sway_ast::Intrinsic) -> HashSet<Effect> {
 }
 
 fn effects_of_asm_op(op: &AsmOp) -> HashSet<Effect> {
+    let mut effs = effects_of_asm_op_name(op);
+    if op
+        .op_args
+        .iter()
+        .any(|arg| is_msg_amount_register(arg.as_str()))
+    {
+        effs.insert(Effect::MsgAmountRead);
+    }
+    effs
+}
+
+fn effects_of_asm_op_name(op: &AsmOp) -> HashSet<Effect> {
     match op.op_name.as_str().to_lowercase().as_str() {
         "scwq" | "sww" | "swwq" => HashSet::from([Effect::StorageWrite]),
         "srw" | "srwq" => HashSet::from([Effect::StorageRead]),
-        "tr" | "tro" => HashSet::from([Effect::BalanceTreeReadWrite]),
+        "tr" | "tro" => HashSet::from([Effect::TransferAsset]),
         "bal" => HashSet::from([Effect::BalanceTreeRead]),
         "smo" => HashSet::from([Effect::OutputMessage]),
         "call" => HashSet::from([Effect::Interaction]),
@@ -669,6 +1022,20 @@ fn effects_of_asm_op(op: &AsmOp) -> HashSet<Effect> {
     }
 }
 
+// `$bal` holds the amount of coins forwarded with the current call.
+fn is_msg_amount_register(reg: &str) -> bool {
+    reg == "bal"
+}
+
+fn effects_of_asm_returns(returns: &Option<(AsmRegister, Span)>) -> HashSet<Effect> {
+    match returns {
+        Some((reg, _)) if is_msg_amount_register(&reg.name) => {
+            HashSet::from([Effect::MsgAmountRead])
+        }
+        _ => HashSet::new(),
+    }
+}
+
 fn set_union<E>(set1: HashSet<E>, set2: HashSet<E>) -> HashSet<E>
 where
     E: std::hash::Hash + Eq + Clone,
@@ -62,7 +62,8 @@ pub fn possibly_nonzero_u64_expression(ctx: &TypeCheckContext, expr: &ty::TyExpr
         | TupleElemAccess { .. }
         | StorageAccess(_)
         | WhileLoop { .. }
-        | ForLoop { .. } => true,
+        | ForLoop { .. }
+        | LabeledBlock { .. } => true,
         // The following expression variants are unreachable, because of the type system
         // but we still consider these as non-zero to be on the safe side
         LazyOperator { .. }
@@ -76,8 +77,8 @@ pub fn possibly_nonzero_u64_expression(ctx: &TypeCheckContext, expr: &ty::TyExpr
         | AbiName(_)
         | UnsafeDowncast { .. }
         | EnumTag { .. }
-        | Break
-        | Continue
+        | Break { .. }
+        | Continue { .. }
         | Reassignment(_)
         | ImplicitReturn(_)
         | Return(_)
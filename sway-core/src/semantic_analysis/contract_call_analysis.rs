@@ -0,0 +1,403 @@
+//! Collection of the external contract calls a program can make.
+//!
+//! Every contract call in the functions and methods of the program is described by a
+//! [ContractCallDescriptor], which is published next to the ABI, so that auditors and indexers
+//! can build the call graph of a whole dapp from the artifacts of its contracts and scripts.
+//!
+//! The contract ID a call is made to is traced back through immutable variables and function
+//! calls with a single argument, like `ContractId::from` or `bits`, to where it comes from. Mutable
+//! variables are not traced, as their value can change before the call.
+
+use crate::{
+    language::{
+        ty::{self, ContractCallDescriptor, ContractCallTarget, TyFunctionDecl},
+        Literal, Purity,
+    },
+    transform::{AttributeKind, AttributesMap},
+    type_system::{AbiName, TypeInfo},
+    Engines,
+};
+use std::collections::HashMap;
+use sway_types::{
+    constants::{
+        CONTRACT_CALL_ASSET_ID_PARAMETER_NAME, CONTRACT_CALL_COINS_PARAMETER_NAME,
+        CONTRACT_CALL_GAS_PARAMETER_NAME,
+    },
+    Ident, Span,
+};
+
+pub(crate) fn analyze_program(
+    engines: &Engines,
+    prog: &ty::TyProgram,
+) -> Vec<ContractCallDescriptor> {
+    let modules = std::iter::once(&prog.root).chain(
+        prog.root
+            .submodules_recursive()
+            .map(|(_, submod)| submod.module.as_ref()),
+    );
+    let mut calls = vec![];
+    for (caller, fn_decl) in modules.flat_map(|module| functions(engines, &module.all_nodes)) {
+        if fn_decl.is_test() {
+            continue;
+        }
+        let mut analysis = FnAnalysis::new(engines, caller, &fn_decl);
+        analysis.visit_code_block(&fn_decl.body);
+        calls.extend(analysis.calls);
+    }
+    calls
+}
+
+/// The standalone functions and methods declared by `ast_nodes`, along with their names.
+fn functions(
+    engines: &Engines,
+    ast_nodes: &[ty::TyAstNode],
+) -> Vec<(String, std::sync::Arc<TyFunctionDecl>)> {
+    let decl_engine = engines.de();
+    ast_nodes
+        .iter()
+        .flat_map(|ast_node| match &ast_node.content {
+            ty::TyAstNodeContent::Declaration(ty::TyDecl::FunctionDecl(ty::FunctionDecl {
+                decl_id,
+            })) => {
+                let fn_decl = decl_engine.get_function(decl_id);
+                vec![(fn_decl.name.to_string(), fn_decl)]
+            }
+            ty::TyAstNodeContent::Declaration(ty::TyDecl::ImplSelfOrTrait(
+                ty::ImplSelfOrTrait { decl_id, .. },
+            )) => {
+                let impl_trait = decl_engine.get_impl_self_or_trait(decl_id);
+                let implementing_for = engines.help_out(impl_trait.implementing_for.type_id);
+                impl_trait
+                    .items
+                    .iter()
+                    .filter_map(|item| match item {
+                        ty::TyImplItem::Fn(fn_decl) => {
+                            let fn_decl = decl_engine.get_function(fn_decl.id());
+                            Some((format!("{implementing_for}::{}", fn_decl.name), fn_decl))
+                        }
+                        ty::TyImplItem::Constant(_) | ty::TyImplItem::Type(_) => None,
+                    })
+                    .collect()
+            }
+            _ => vec![],
+        })
+        .collect()
+}
+
+/// The ABI method called by a contract call.
+struct CalledMethod {
+    name: String,
+    purity: Purity,
+    payable: bool,
+}
+
+impl CalledMethod {
+    fn new(name: &Ident, purity: Purity, attributes: &AttributesMap) -> Self {
+        Self {
+            name: name.to_string(),
+            purity,
+            payable: attributes.contains_key(&AttributeKind::Payable),
+        }
+    }
+}
+
+/// The analysis of a single function body.
+struct FnAnalysis<'a> {
+    engines: &'a Engines,
+    caller: String,
+    /// Where the values of the variables in scope come from.
+    variables: HashMap<Ident, ContractCallTarget>,
+    calls: Vec<ContractCallDescriptor>,
+}
+
+impl<'a> FnAnalysis<'a> {
+    fn new(engines: &'a Engines, caller: String, fn_decl: &TyFunctionDecl) -> Self {
+        let variables = fn_decl
+            .parameters
+            .iter()
+            .map(|param| {
+                let target = ContractCallTarget::Parameter {
+                    name: param.name.to_string(),
+                };
+                (param.name.clone(), target)
+            })
+            .collect();
+        Self {
+            engines,
+            caller,
+            variables,
+            calls: vec![],
+        }
+    }
+
+    fn visit_code_block(&mut self, codeblock: &ty::TyCodeBlock) {
+        let outer_variables = self.variables.clone();
+        for ast_node in &codeblock.contents {
+            match &ast_node.content {
+                ty::TyAstNodeContent::Declaration(ty::TyDecl::VariableDecl(var_decl)) => {
+                    self.visit_expression(&var_decl.body);
+                    let target = if var_decl.mutability.is_mutable() {
+                        ContractCallTarget::Variable {
+                            name: var_decl.name.to_string(),
+                        }
+                    } else {
+                        self.target_of(&var_decl.body)
+                    };
+                    self.variables.insert(var_decl.name.clone(), target);
+                }
+                ty::TyAstNodeContent::Expression(expr) => self.visit_expression(expr),
+                ty::TyAstNodeContent::Declaration(_)
+                | ty::TyAstNodeContent::SideEffect(_)
+                | ty::TyAstNodeContent::Error(_, _) => {}
+            }
+        }
+        self.variables = outer_variables;
+    }
+
+    fn visit_expression(&mut self, expr: &ty::TyExpression) {
+        use ty::TyExpressionVariant::*;
+        match &expr.expression {
+            CodeBlock(codeblock)
+            | LabeledBlock {
+                block: codeblock, ..
+            } => self.visit_code_block(codeblock),
+            WhileLoop {
+                condition, body, ..
+            } => {
+                self.visit_expression(condition);
+                self.visit_code_block(body);
+            }
+            _ => {
+                if let Some(call) = self.describe_contract_call(expr) {
+                    self.calls.push(call);
+                }
+                for sub_expr in super::cei_pattern_analysis::sub_expressions(expr) {
+                    self.visit_expression(sub_expr);
+                }
+            }
+        }
+    }
+
+    fn describe_contract_call(&self, expr: &ty::TyExpression) -> Option<ContractCallDescriptor> {
+        let ty::TyExpressionVariant::FunctionApplication {
+            arguments,
+            fn_ref,
+            selector,
+            contract_call_params,
+            contract_caller,
+            ..
+        } = &expr.expression
+        else {
+            return None;
+        };
+
+        // Whether the `coins`, `asset_id` and `gas` call parameters are specified.
+        let (abi, method, selector, address, [forwards_coins, forwards_asset_id, limits_gas]) =
+            match (selector, contract_caller) {
+                // With the old encoding, the call is made to the ABI method itself.
+                (Some(params), _) => {
+                    let method = self.engines.de().get_function(fn_ref);
+                    let selector = match params.func_selector {
+                        Some(func_selector) => format!(
+                            "0x{}",
+                            func_selector
+                                .iter()
+                                .map(|byte| format!("{byte:02x}"))
+                                .collect::<String>()
+                        ),
+                        None => method.abi_method_name().to_string(),
+                    };
+                    (
+                        self.abi_name(&params.contract_caller),
+                        Some(CalledMethod::new(
+                            &method.name,
+                            method.purity,
+                            &method.attributes,
+                        )),
+                        selector,
+                        &*params.contract_address,
+                        [
+                            CONTRACT_CALL_COINS_PARAMETER_NAME,
+                            CONTRACT_CALL_ASSET_ID_PARAMETER_NAME,
+                            CONTRACT_CALL_GAS_PARAMETER_NAME,
+                        ]
+                        .map(|name| contract_call_params.contains_key(name)),
+                    )
+                }
+                // With the new encoding, the call is made to
+                // `contract_call(address, method_name, args, coins, asset_id, gas)`, and the call
+                // parameters which are not specified have their default literal values.
+                (None, Some(contract_caller)) => {
+                    let [(_, address), (_, method_name), _, (_, coins), (_, asset_id), (_, gas)] =
+                        arguments.as_slice()
+                    else {
+                        return None;
+                    };
+                    let ty::TyExpressionVariant::Literal(Literal::String(method_name)) =
+                        &method_name.expression
+                    else {
+                        return None;
+                    };
+                    let abi = self.abi_name(contract_caller);
+                    let method = abi
+                        .as_ref()
+                        .and_then(|abi| self.abi_method(abi, method_name.as_str()));
+                    (
+                        abi,
+                        method,
+                        method_name.as_str().to_string(),
+                        address,
+                        [
+                            !is_literal(coins, &Literal::U64(0)),
+                            !is_literal(asset_id, &Literal::B256([0; 32])),
+                            !is_literal(gas, &Literal::U64(u64::MAX)),
+                        ],
+                    )
+                }
+                (None, None) => return None,
+            };
+
+        let purity = method
+            .as_ref()
+            .map(|method| method.purity)
+            .unwrap_or_default();
+        Some(ContractCallDescriptor {
+            caller: self.caller.clone(),
+            location: self.location(&expr.span),
+            abi: abi.map(|abi| abi.to_string()),
+            method: method
+                .as_ref()
+                .map_or_else(|| selector.clone(), |method| method.name.clone()),
+            selector,
+            target: self.target_of(address),
+            payable: method.as_ref().is_some_and(|method| method.payable),
+            reads_storage: matches!(purity, Purity::Reads | Purity::ReadsWrites),
+            writes_storage: matches!(purity, Purity::Writes | Purity::ReadsWrites),
+            forwards_coins,
+            forwards_asset_id,
+            limits_gas,
+        })
+    }
+
+    /// The name of the ABI of the contract caller `contract_caller`.
+    fn abi_name(&self, contract_caller: &ty::TyExpression) -> Option<Ident> {
+        match &*self.engines.te().get(contract_caller.return_type) {
+            TypeInfo::ContractCaller {
+                abi_name: AbiName::Known(call_path),
+                ..
+            } => Some(call_path.suffix.clone()),
+            _ => None,
+        }
+    }
+
+    /// The method of the ABI named `abi_name` whose ABI method name is `method_name`.
+    fn abi_method(&self, abi_name: &Ident, method_name: &str) -> Option<CalledMethod> {
+        let decl_engine = self.engines.de();
+        let abi_method_name =
+            |name: &Ident, attributes: &AttributesMap| match attributes.method_name_override() {
+                Some(Some(method_name)) => method_name.to_string(),
+                _ => name.to_string(),
+            };
+        decl_engine
+            .get_abis_by_name(abi_name)
+            .iter()
+            .find_map(|abi_decl| {
+                let interface_methods =
+                    abi_decl
+                        .interface_surface
+                        .iter()
+                        .filter_map(|item| match item {
+                            ty::TyTraitInterfaceItem::TraitFn(fn_ref) => {
+                                let trait_fn = decl_engine.get_trait_fn(fn_ref);
+                                (abi_method_name(&trait_fn.name, &trait_fn.attributes)
+                                    == method_name)
+                                    .then(|| {
+                                        CalledMethod::new(
+                                            &trait_fn.name,
+                                            trait_fn.purity,
+                                            &trait_fn.attributes,
+                                        )
+                                    })
+                            }
+                            ty::TyTraitInterfaceItem::Constant(_)
+                            | ty::TyTraitInterfaceItem::Type(_) => None,
+                        });
+                let provided_methods = abi_decl.items.iter().filter_map(|item| match item {
+                    ty::TyTraitItem::Fn(fn_ref) => {
+                        let fn_decl = decl_engine.get_function(fn_ref);
+                        (fn_decl.abi_method_name() == method_name).then(|| {
+                            CalledMethod::new(&fn_decl.name, fn_decl.purity, &fn_decl.attributes)
+                        })
+                    }
+                    ty::TyTraitItem::Constant(_) | ty::TyTraitItem::Type(_) => None,
+                });
+                interface_methods.chain(provided_methods).next()
+            })
+    }
+
+    /// Where the value of `expr`, used as a contract ID, comes from.
+    fn target_of(&self, expr: &ty::TyExpression) -> ContractCallTarget {
+        use ty::TyExpressionVariant::*;
+        match &expr.expression {
+            Literal(crate::language::Literal::B256(bytes)) => ContractCallTarget::Literal {
+                value: format!(
+                    "0x{}",
+                    bytes
+                        .iter()
+                        .map(|byte| format!("{byte:02x}"))
+                        .collect::<String>()
+                ),
+            },
+            ConstantExpression { decl, .. } => ContractCallTarget::Constant {
+                name: decl.call_path.suffix.to_string(),
+            },
+            ConfigurableExpression { decl, .. } => ContractCallTarget::Configurable {
+                name: decl.call_path.suffix.to_string(),
+            },
+            StorageAccess(access) => ContractCallTarget::Storage {
+                name: access.storage_field_names.join("::"),
+            },
+            VariableExpression { name, .. } => self
+                .variables
+                .get(name)
+                .cloned()
+                .unwrap_or(ContractCallTarget::Expression),
+            // Conversions like `ContractId::from(...)` and `id.bits()`, and reads of storage
+            // fields like `storage.map.get(key).read()`.
+            FunctionApplication {
+                arguments,
+                selector: None,
+                contract_caller: None,
+                ..
+            } => {
+                let Some((_, first_arg)) = arguments.first() else {
+                    return ContractCallTarget::Expression;
+                };
+                let target = self.target_of(first_arg);
+                if arguments.len() == 1 || matches!(target, ContractCallTarget::Storage { .. }) {
+                    target
+                } else {
+                    ContractCallTarget::Expression
+                }
+            }
+            StructExpression { fields, .. } if fields.len() == 1 => {
+                self.target_of(&fields[0].value)
+            }
+            Ref(expr) | Deref(expr) => self.target_of(expr),
+            _ => ContractCallTarget::Expression,
+        }
+    }
+
+    fn location(&self, span: &Span) -> String {
+        let line_col = span.start_pos().line_col();
+        let path = span
+            .source_id()
+            .map(|source_id| self.engines.se().get_path(source_id).display().to_string())
+            .unwrap_or("<autogenerated>".to_string());
+        format!("{path}:{}:{}", line_col.line, line_col.col)
+    }
+}
+
+fn is_literal(expr: &ty::TyExpression, literal: &Literal) -> bool {
+    matches!(&expr.expression, ty::TyExpressionVariant::Literal(value) if value == literal)
+}
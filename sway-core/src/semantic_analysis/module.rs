@@ -2,6 +2,7 @@ use std::{
     collections::{HashMap, HashSet},
     fmt::Display,
     fs,
+    path::PathBuf,
     sync::Arc,
 };
 
@@ -21,7 +22,7 @@ use crate::{
         ty::{self, TyAstNodeContent, TyDecl},
         CallPath, ModName,
     },
-    query_engine::{ModuleCacheKey, TypedModuleInfo},
+    query_engine::{DeclDependency, ModuleCacheKey, TypedModuleInfo},
     semantic_analysis::*,
     BuildConfig, Engines, TypeInfo,
 };
@@ -295,7 +296,8 @@ impl ty::TyModule {
 
     /// Type-check the given parsed module to produce a typed module.
     ///
-    /// Recursively type-checks submodules first.
+    /// Recursively type-checks submodules first. A cached module is reused if the declarations
+    /// it uses from other modules are unchanged, see [TypedModuleInfo::decl_dependencies].
     pub fn type_check(
         handler: &Handler,
         mut ctx: TypeCheckContext,
@@ -313,15 +315,6 @@ impl ty::TyModule {
             ..
         } = parsed;
 
-        // Try to get the cached root module if it's up to date
-        if let Some(module) = ty::TyModule::get_cached_ty_module_if_up_to_date(
-            parsed.span.source_id(),
-            engines,
-            build_config,
-        ) {
-            return Ok(module);
-        }
-
         // Type-check submodules first in order of evaluation previously computed by the dependency graph.
         let submodules_res = module_eval_order
             .iter()
@@ -361,6 +354,28 @@ impl ty::TyModule {
             })
             .collect::<Result<Vec<_>, _>>();
 
+        // Try to get the cached root module if it's up to date, along with the submodules above
+        if let Ok(submodules) = &submodules_res {
+            if let Some(module) = ty::TyModule::get_cached_ty_module_if_up_to_date(
+                parsed.span.source_id(),
+                engines,
+                build_config,
+            ) {
+                let mut namespace = module.namespace.clone();
+                for (name, submodule) in ctx.namespace().module(engines).submodules() {
+                    namespace
+                        .module_mut(engines)
+                        .insert_submodule(name.clone(), submodule.clone());
+                }
+                #[allow(clippy::arc_with_non_send_sync)]
+                return Ok(Arc::new(Self {
+                    submodules: submodules.clone(),
+                    namespace,
+                    ..(*module).clone()
+                }));
+            }
+        }
+
         // TODO: Ordering should be solved across all modules prior to the beginning of type-check.
         let ordered_nodes = node_dependencies::order_ast_nodes_by_dependency(
             handler,
@@ -478,11 +493,14 @@ impl ty::TyModule {
 
             let include_tests = build_config.map_or(false, |x| x.include_tests);
             let key = ModuleCacheKey::new(path.clone().into(), include_tests);
+            let decl_dependencies =
+                Self::collect_decl_dependencies(engines, &ctx, source_id, &tree.root_nodes);
             engines.qe().update_typed_module_cache_entry(
                 &key,
                 TypedModuleInfo {
                     module: ty_module.clone(),
                     version,
+                    decl_dependencies,
                 },
             );
         }
@@ -490,6 +508,76 @@ impl ty::TyModule {
         Ok(ty_module)
     }
 
+    /// Collects the declarations of other modules imported into the module being type checked,
+    /// or referred to by path in its `nodes`, along with the unnamed items of their modules,
+    /// which contain the trait implementations.
+    fn collect_decl_dependencies(
+        engines: &Engines,
+        ctx: &TypeCheckContext,
+        source_id: &SourceId,
+        nodes: &[AstNode],
+    ) -> Vec<DeclDependency> {
+        let items = &ctx.namespace.module(engines).root_lexical_scope().items;
+        let imported = items
+            .use_item_synonyms
+            .iter()
+            .map(|(name, (source_name, _, decl, _))| (source_name.as_ref().unwrap_or(name), decl))
+            .chain(
+                items
+                    .use_glob_synonyms
+                    .iter()
+                    .flat_map(|(name, decls)| decls.iter().map(move |(_, decl, _)| (name, decl))),
+            )
+            .map(|(name, decl)| (name.clone(), decl.clone()));
+
+        // Paths like `a::b::Foo` or `a::Enum::Variant` are resolved to the declaration they
+        // start with, `Foo` or `Enum` respectively.
+        let handler = Handler::default();
+        let qualified = node_dependencies::qualified_call_paths(engines, nodes)
+            .into_iter()
+            .filter_map(|call_path| {
+                let resolve = |call_path: &CallPath| {
+                    ctx.resolve_call_path(&handler, call_path).ok().map(|decl| {
+                        (
+                            call_path.suffix.clone(),
+                            namespace::ResolvedDeclaration::Typed(decl),
+                        )
+                    })
+                };
+                resolve(&call_path).or_else(|| {
+                    let (suffix, prefixes) = call_path.prefixes.split_last()?;
+                    resolve(&CallPath {
+                        prefixes: prefixes.to_vec(),
+                        suffix: suffix.clone(),
+                        is_absolute: call_path.is_absolute,
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let module_cache = engines.qe().module_cache.read();
+        let mut dependencies = HashSet::new();
+        for (name, decl) in imported.chain(qualified) {
+            let Some(decl_source_id) = decl.span(engines).source_id().copied() else {
+                continue;
+            };
+            if decl_source_id == *source_id {
+                continue;
+            }
+            let path: Arc<PathBuf> = engines.se().get_path(&decl_source_id).into();
+            for name in [Some(name.as_str()), None] {
+                if let Some(fingerprint) = module_cache.decl_fingerprint(&path, name) {
+                    dependencies.insert(DeclDependency {
+                        path: path.clone(),
+                        name: name.map(str::to_string),
+                        fingerprint,
+                    });
+                }
+            }
+        }
+        dependencies.into_iter().collect()
+    }
+
     // Filter and gather impl items
     fn get_all_impls(
         ctx: TypeCheckContext<'_>,
@@ -562,28 +650,36 @@ impl ty::TyModule {
                 continue;
             };
 
-            if ctx.experimental.new_encoding {
-                let mut generated = vec![];
-                if let (true, Some(mut ctx)) = (
-                    auto_impl_encoding_traits,
-                    EncodingAutoImplContext::new(&mut ctx),
-                ) {
-                    match &node.content {
-                        TyAstNodeContent::Declaration(decl @ TyDecl::StructDecl(_))
-                        | TyAstNodeContent::Declaration(decl @ TyDecl::EnumDecl(_)) => {
-                            let (a, b) = ctx.generate(engines, decl);
-                            generated.extend(a);
-                            generated.extend(b);
-                        }
-                        _ => {}
+            let encoding_auto_implemented =
+                ctx.experimental.new_encoding && auto_impl_encoding_traits;
+
+            let mut generated = vec![];
+            if let TyAstNodeContent::Declaration(
+                decl @ (TyDecl::StructDecl(_) | TyDecl::EnumDecl(_)),
+            ) = &node.content
+            {
+                if let Some(mut ctx) = EncodingAutoImplContext::new(&mut ctx) {
+                    if encoding_auto_implemented {
+                        let (a, b) = ctx.generate(engines, decl);
+                        generated.extend(a);
+                        generated.extend(b);
                     }
-                };
 
-                typed_nodes.push(node);
-                typed_nodes.extend(generated);
-            } else {
-                typed_nodes.push(node);
+                    // Implementations of the traits listed in `#[derive(...)]`.
+                    generated.extend(ctx.generate_derives(
+                        handler,
+                        engines,
+                        decl,
+                        encoding_auto_implemented,
+                    ));
+
+                    // Implementation of `ErrorType` for `#[error_type]` enums.
+                    generated.extend(ctx.generate_error_type(engines, decl));
+                }
             }
+
+            typed_nodes.push(node);
+            typed_nodes.extend(generated);
         }
 
         Ok(typed_nodes)
@@ -719,3 +815,126 @@ impl ty::TySubmodule {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BuildTarget, LspConfig};
+    use std::collections::BTreeMap;
+    use sway_features::ExperimentalFeatures;
+
+    const ROOT: &str = "library;
+
+mod a;
+
+use a::Foo;
+
+pub fn root_fn(foo: Foo) -> u64 {
+    foo.x + a::helper()
+}
+";
+
+    const SUBMODULE: &str = "library;
+
+pub struct Foo {
+    pub x: u64,
+}
+
+pub fn helper() -> u64 {
+    1
+}
+
+pub fn unrelated() -> u64 {
+    2
+}
+";
+
+    /// Type checks the library in `dir` with the submodule `a` at `version`, and returns the
+    /// declaration of `root_fn`, which is only type checked again if the cached root is stale.
+    fn type_check_root_fn(
+        engines: &Engines,
+        dir: &std::path::Path,
+        version: u64,
+    ) -> DeclId<ty::TyFunctionDecl> {
+        let root_path = dir.join("lib.sw");
+        let lsp_mode = LspConfig {
+            optimized_build: false,
+            file_versions: BTreeMap::from([
+                (root_path.clone(), Some(1)),
+                (dir.join("a.sw"), Some(version)),
+            ]),
+        };
+        let build_config = BuildConfig::root_from_file_name_and_manifest_path(
+            root_path,
+            dir.to_path_buf(),
+            BuildTarget::default(),
+        )
+        .with_lsp_mode(Some(lsp_mode));
+        let handler = Handler::default();
+        let mut root = namespace::Root::from(namespace::Module::new(
+            BaseIdent::new_no_span("decl_dependencies_test".to_string()),
+            crate::Visibility::Public,
+            None,
+        ));
+        let programs = crate::compile_to_ast(
+            &handler,
+            engines,
+            Arc::from(ROOT),
+            &mut root,
+            Some(&build_config),
+            "test",
+            None,
+            ExperimentalFeatures::default(),
+        )
+        .unwrap();
+        let (errors, _warnings) = handler.consume();
+        assert!(errors.is_empty(), "{errors:#?}");
+
+        programs
+            .typed
+            .unwrap()
+            .root
+            .all_nodes
+            .iter()
+            .find_map(|node| match &node.content {
+                TyAstNodeContent::Declaration(TyDecl::FunctionDecl(decl)) => Some(decl.decl_id),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    /// Writes the library to a fresh directory and type checks it once.
+    fn setup(name: &str) -> (Engines, PathBuf, DeclId<ty::TyFunctionDecl>) {
+        let dir = std::env::temp_dir().join(format!("sway_decl_dependencies_{name}"));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let dir = dir.canonicalize().unwrap();
+        fs::write(dir.join("lib.sw"), ROOT).unwrap();
+        fs::write(dir.join("a.sw"), SUBMODULE).unwrap();
+        let engines = Engines::default();
+        let root_fn = type_check_root_fn(&engines, &dir, 1);
+        (engines, dir, root_fn)
+    }
+
+    #[test]
+    fn cached_module_is_reused_when_used_decls_are_unchanged() {
+        let (engines, dir, root_fn) = setup("hit");
+        fs::write(dir.join("a.sw"), SUBMODULE.replace("    2\n", "    3\n")).unwrap();
+        assert_eq!(type_check_root_fn(&engines, &dir, 2), root_fn);
+    }
+
+    #[test]
+    fn cached_module_is_stale_when_imported_decl_changes() {
+        let (engines, dir, root_fn) = setup("imported");
+        let submodule = SUBMODULE.replace("pub x: u64,", "pub x: u64,\n    pub y: u64,");
+        fs::write(dir.join("a.sw"), submodule).unwrap();
+        assert_ne!(type_check_root_fn(&engines, &dir, 2), root_fn);
+    }
+
+    #[test]
+    fn cached_module_is_stale_when_path_qualified_decl_changes() {
+        let (engines, dir, root_fn) = setup("qualified");
+        fs::write(dir.join("a.sw"), SUBMODULE.replace("    1\n", "    4\n")).unwrap();
+        assert_ne!(type_check_root_fn(&engines, &dir, 2), root_fn);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     engine_threading::Engines,
-    language::{ty, Visibility},
+    language::{ty, CallPath, Visibility},
     Ident, TypeId,
 };
 
@@ -379,6 +379,46 @@ impl Module {
             })
             .collect::<Vec<_>>()
     }
+
+    /// Returns the spans of all impls of the trait with the given full call path found in any
+    /// lexical scope of this module and of all of its submodules, including the external ones.
+    pub fn get_all_impl_spans_for_trait_name(&self, trait_name: &CallPath) -> Vec<Span> {
+        self.collect_impl_spans(&|items| items.get_impl_spans_for_trait_name(trait_name))
+    }
+
+    /// Returns the spans of all impls for the type declared by `ty_decl` found in any lexical
+    /// scope of this module and of all of its submodules, including the external ones.
+    pub fn get_all_impl_spans_for_decl(
+        &self,
+        engines: &Engines,
+        ty_decl: &ty::TyDecl,
+    ) -> Vec<Span> {
+        let Ok(type_id) = ty_decl.return_type(&Handler::default(), engines) else {
+            return vec![];
+        };
+        self.collect_impl_spans(&|items| items.get_impl_spans_for_type(engines, &type_id))
+    }
+
+    /// Collects the spans returned by `f` for the items of every lexical scope in the module tree.
+    ///
+    /// The same impl ends up in the trait map of every module importing it, so the spans are
+    /// sorted by their location and deduplicated.
+    fn collect_impl_spans(&self, f: &impl Fn(&Items) -> Vec<Span>) -> Vec<Span> {
+        fn collect(module: &Module, f: &impl Fn(&Items) -> Vec<Span>, spans: &mut Vec<Span>) {
+            for lexical_scope in &module.lexical_scopes {
+                spans.extend(f(&lexical_scope.items));
+            }
+            for submodule in module.submodules.values() {
+                collect(submodule, f, spans);
+            }
+        }
+
+        let mut spans = vec![];
+        collect(self, f, &mut spans);
+        spans.sort_by_key(|span| (span.source_id().copied(), span.start(), span.end()));
+        spans.dedup();
+        spans
+    }
 }
 
 impl From<Root> for Module {
@@ -119,7 +119,7 @@ impl ResolvedDeclaration {
         }
     }
 
-    fn span(&self, engines: &Engines) -> sway_types::Span {
+    pub(crate) fn span(&self, engines: &Engines) -> sway_types::Span {
         match self {
             ResolvedDeclaration::Parsed(decl) => decl.span(engines),
             ResolvedDeclaration::Typed(decl) => decl.span(engines),
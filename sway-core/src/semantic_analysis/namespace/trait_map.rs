@@ -23,8 +23,8 @@ use crate::{
         CallPath,
     },
     type_system::{SubstTypes, TypeId},
-    IncludeSelf, SubstTypesContext, TraitConstraint, TypeArgument, TypeEngine, TypeInfo,
-    TypeSubstMap, UnifyCheck,
+    IncludeSelf, SubstTypesContext, TraitConstraint, TreatNumericAs, TypeArgument, TypeEngine,
+    TypeInfo, TypeSubstMap, UnifyCheck, VecSet,
 };
 
 use super::Module;
@@ -284,10 +284,18 @@ impl TraitMap {
 
                 let unify_checker = UnifyCheck::non_generic_constraint_subset(engines);
 
+                // Generic impls whose type parameters are bounded differently, e.g.,
+                // `impl<T> Foo for Bar<T> where T: A` and `impl<T> Foo for Bar<T> where T: B`,
+                // do not conflict here. Which one applies is decided when an impl is selected
+                // for a concrete type, and an ambiguity is reported there if both do.
+                let constraints_are_equal =
+                    Self::generic_trait_constraints_are_equal(engines, type_id, *map_type_id);
+
                 // Types are subset if the `type_id` that we want to insert can unify with the
                 // existing `map_type_id`. In addition we need to additionally check for the case of
                 // `&mut <type>` and `&<type>`.
-                let types_are_subset = unify_checker.check(type_id, *map_type_id)
+                let types_are_subset = constraints_are_equal
+                    && unify_checker.check(type_id, *map_type_id)
                     && is_unified_type_subset(engines.te(), type_id, *map_type_id);
 
                 /// `left` can unify into `right`. Additionally we need to check subset condition in case of
@@ -453,6 +461,98 @@ impl TraitMap {
         })
     }
 
+    /// Returns the constrained generic types within `type_id`, together with their names and
+    /// trait constraints.
+    fn generic_trait_constraints(
+        engines: &Engines,
+        type_id: TypeId,
+    ) -> Vec<(TypeId, Ident, VecSet<TraitConstraint>)> {
+        type_id
+            .extract_inner_types(engines, IncludeSelf::Yes)
+            .into_iter()
+            .filter_map(|inner_type_id| match &*engines.te().get(inner_type_id) {
+                TypeInfo::UnknownGeneric {
+                    name,
+                    trait_constraints,
+                    ..
+                } if !trait_constraints.is_empty() => {
+                    Some((inner_type_id, name.clone(), trait_constraints.clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the generic types within `left` and `right` have the same names and
+    /// are bounded by the same trait constraints, regardless of the order of the constraints.
+    fn generic_trait_constraints_are_equal(engines: &Engines, left: TypeId, right: TypeId) -> bool {
+        let ctx = PartialEqWithEnginesContext::new(engines);
+        let left = Self::generic_trait_constraints(engines, left);
+        let right = Self::generic_trait_constraints(engines, right);
+        left.len() == right.len()
+            && left.iter().all(|(_, left_name, left_constraints)| {
+                right.iter().any(|(_, right_name, right_constraints)| {
+                    left_name == right_name
+                        && PartialEqWithEngines::eq(left_constraints, right_constraints, &ctx)
+                })
+            })
+    }
+
+    /// Returns `true` if the trait constraints on the generic types of `impl_type_id`, the type
+    /// an impl block is declared for, hold for `type_id`.
+    ///
+    /// E.g., for `impl<T> Foo for Bar<T> where T: A` and a `type_id` `Bar<u64>`, checks if `u64`
+    /// implements `A`. Generic types that are not resolved to a concrete type are considered to
+    /// satisfy their constraints.
+    fn impl_trait_constraints_hold(
+        &self,
+        engines: &Engines,
+        impl_type_id: TypeId,
+        type_id: TypeId,
+    ) -> bool {
+        let constraints = Self::generic_trait_constraints(engines, impl_type_id);
+        if constraints.is_empty() {
+            return true;
+        }
+
+        let type_engine = engines.te();
+        let unify_check = UnifyCheck::non_dynamic_equality(engines);
+        let type_mapping = TypeSubstMap::from_superset_and_subset(
+            type_engine,
+            engines.de(),
+            impl_type_id,
+            type_id,
+        );
+        constraints
+            .iter()
+            .all(|(generic_type_id, _, trait_constraints)| {
+                let Some(type_arg) = type_mapping.find_match(*generic_type_id, engines) else {
+                    return true;
+                };
+                if !type_arg.is_concrete(engines, TreatNumericAs::Concrete) {
+                    return true;
+                }
+                let implemented_traits = self.get_implemented_traits(type_arg, engines);
+                trait_constraints.iter().all(|constraint| {
+                    let constraint_type_id = type_engine.new_custom(
+                        engines,
+                        constraint.trait_name.suffix.clone().into(),
+                        if constraint.type_arguments.is_empty() {
+                            None
+                        } else {
+                            Some(constraint.type_arguments.clone())
+                        },
+                    );
+                    implemented_traits
+                        .iter()
+                        .any(|(trait_name, trait_type_id)| {
+                            *trait_name == constraint.trait_name.suffix
+                                && unify_check.check(*trait_type_id, constraint_type_id)
+                        })
+                })
+            })
+    }
+
     fn insert_inner(
         &mut self,
         trait_name: TraitName,
@@ -1209,6 +1309,13 @@ impl TraitMap {
     ) -> Result<ResolvedTraitImplItem, ErrorEmitted> {
         let mut candidates = HashMap::<String, ResolvedTraitImplItem>::new();
         for (trait_item, trait_key) in self.get_items_and_trait_key_for_type(engines, type_id) {
+            // Skip the items of impls whose `where` clauses do not hold for `type_id`, so that
+            // e.g. `Bar::<u64>::X` selects the associated constant `X` of the one impl of
+            // `impl<T> Foo for Bar<T> where T: A` and `impl<T> Foo for Bar<T> where T: B`
+            // that applies to `u64`.
+            if !self.impl_trait_constraints_hold(engines, trait_key.type_id, type_id) {
+                continue;
+            }
             match trait_item {
                 ResolvedTraitImplItem::Parsed(impl_item) => match impl_item {
                     ImplItem::Fn(fn_ref) => {
@@ -1311,6 +1418,7 @@ impl TraitMap {
                                     .unwrap()
                                     .to_string(),
                                 engines.help_out(type_id).to_string(),
+                                String::new(),
                             )
                         })
                         .collect::<Vec<_>>(),
@@ -265,12 +265,26 @@ fn depends_on(
     }
 }
 
+/// Gathers the paths with prefixes, e.g. `a::b::Foo`, the `nodes` refer to.
+///
+/// These refer to declarations of other modules without importing them.
+pub(crate) fn qualified_call_paths(engines: &Engines, nodes: &[AstNode]) -> HashSet<CallPath> {
+    nodes
+        .iter()
+        .fold(Dependencies::default(), |deps, node| {
+            deps.gather_from_node(engines, node)
+        })
+        .qualified
+}
+
 // -------------------------------------------------------------------------------------------------
 // Dependencies are just a collection of dependee symbols.
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 struct Dependencies {
     deps: HashSet<DependentSymbol>,
+    /// The paths with prefixes, which can refer to the declarations of other modules.
+    qualified: HashSet<CallPath>,
 }
 
 impl Dependencies {
@@ -282,10 +296,7 @@ impl Dependencies {
             AstNodeContent::Declaration(decl) => decl_name(engines, decl).map(|name| {
                 (
                     name,
-                    Dependencies {
-                        deps: HashSet::new(),
-                    }
-                    .gather_from_decl(engines, decl),
+                    Dependencies::default().gather_from_decl(engines, decl),
                 )
             }),
             _ => None,
@@ -357,7 +368,7 @@ impl Dependencies {
                         TraitItem::TraitFn(decl_id) => {
                             let sig = engines.pe().get_trait_fn(decl_id);
                             deps.gather_from_iter(sig.parameters.iter(), |deps, param| {
-                                deps.gather_from_type_argument(engines, &param.type_argument)
+                                deps.gather_from_fn_param(engines, param)
                             })
                             .gather_from_type_argument(engines, &sig.return_type)
                         }
@@ -421,7 +432,7 @@ impl Dependencies {
                     TraitItem::TraitFn(decl_id) => {
                         let sig = engines.pe().get_trait_fn(decl_id);
                         deps.gather_from_iter(sig.parameters.iter(), |deps, param| {
-                            deps.gather_from_type_argument(engines, &param.type_argument)
+                            deps.gather_from_fn_param(engines, param)
                         })
                         .gather_from_type_argument(engines, &sig.return_type)
                     }
@@ -509,6 +520,14 @@ impl Dependencies {
         }
     }
 
+    fn gather_from_fn_param(self, engines: &Engines, param: &FunctionParameter) -> Self {
+        let deps = self.gather_from_type_argument(engines, &param.type_argument);
+        match &param.default_value {
+            Some(default_value) => deps.gather_from_expr(engines, default_value),
+            None => deps,
+        }
+    }
+
     fn gather_from_trait_fn_decl(self, engines: &Engines, fn_decl: &TraitFn) -> Self {
         let TraitFn {
             parameters,
@@ -516,7 +535,7 @@ impl Dependencies {
             ..
         } = fn_decl;
         self.gather_from_iter(parameters.iter(), |deps, param| {
-            deps.gather_from_type_argument(engines, &param.type_argument)
+            deps.gather_from_fn_param(engines, param)
         })
         .gather_from_type_argument(engines, return_type)
     }
@@ -530,7 +549,7 @@ impl Dependencies {
             ..
         } = fn_decl;
         self.gather_from_iter(parameters.iter(), |deps, param| {
-            deps.gather_from_type_argument(engines, &param.type_argument)
+            deps.gather_from_fn_param(engines, param)
         })
         .gather_from_type_argument(engines, return_type)
         .gather_from_block(engines, body)
@@ -611,6 +630,18 @@ impl Dependencies {
                     qualified_path_root: _,
                 } = &**e;
                 let mut this = self;
+                if !call_path_binding.inner.prefixes.is_empty() {
+                    let suffix = &call_path_binding.inner.suffix;
+                    this.qualified.insert(CallPath {
+                        prefixes: call_path_binding.inner.prefixes.clone(),
+                        suffix: suffix
+                            .before
+                            .as_ref()
+                            .map_or(&suffix.suffix, |before| &before.inner)
+                            .clone(),
+                        is_absolute: call_path_binding.inner.is_absolute,
+                    });
+                }
                 if call_path_binding.inner.prefixes.is_empty() {
                     if let Some(before) = &call_path_binding.inner.suffix.before {
                         // We have just `Foo::Bar`, and nothing before `Foo`,
@@ -664,8 +695,8 @@ impl Dependencies {
             }
 
             ExpressionKind::Literal(_)
-            | ExpressionKind::Break
-            | ExpressionKind::Continue
+            | ExpressionKind::Break(BreakExpression { value: None, .. })
+            | ExpressionKind::Continue(_)
             | ExpressionKind::StorageAccess(_)
             | ExpressionKind::Error(_, _) => self,
 
@@ -688,6 +719,12 @@ impl Dependencies {
             ExpressionKind::ForLoop(ForLoopExpression { desugared, .. }) => {
                 self.gather_from_expr(engines, desugared)
             }
+            ExpressionKind::LabeledBlock(LabeledBlockExpression { block, .. }) => {
+                self.gather_from_block(engines, block)
+            }
+            ExpressionKind::Break(BreakExpression {
+                value: Some(value), ..
+            }) => self.gather_from_expr(engines, value),
             ExpressionKind::Reassignment(reassignment) => {
                 self.gather_from_expr(engines, &reassignment.rhs)
             }
@@ -695,7 +732,13 @@ impl Dependencies {
                 self.gather_from_expr(engines, expr)
             }
             ExpressionKind::Ref(RefExpression { value: expr, .. })
-            | ExpressionKind::Deref(expr) => self.gather_from_expr(engines, expr),
+            | ExpressionKind::Deref(expr)
+            | ExpressionKind::Try(TryExpression { expr, .. }) => {
+                self.gather_from_expr(engines, expr)
+            }
+            ExpressionKind::NamedArgument(named_arg) => {
+                self.gather_from_expr(engines, &named_arg.value)
+            }
         }
     }
 
@@ -741,6 +784,9 @@ impl Dependencies {
         use_prefix: bool,
         is_fn_app: bool,
     ) -> Self {
+        if !call_path.prefixes.is_empty() {
+            self.qualified.insert(call_path.clone());
+        }
         if call_path.prefixes.is_empty() {
             // We can just use the suffix.
             self.deps.insert(if is_fn_app {
@@ -792,6 +838,9 @@ impl Dependencies {
                 qualified_call_path: name,
                 type_arguments,
             } => {
+                if !name.call_path.prefixes.is_empty() {
+                    self.qualified.insert(name.call_path.clone());
+                }
                 self.deps
                     .insert(DependentSymbol::Symbol(name.clone().call_path.suffix));
                 match type_arguments {
@@ -76,13 +76,19 @@ impl TyProgram {
             ctx.experimental,
         )?;
 
+        let error_selectors = TyProgram::collect_error_selectors(handler, engines, &root)?;
+
         let program = TyProgram {
             kind,
             root: (*root).clone(),
             declarations,
             configurables,
             storage_slots: vec![],
+            storage_layout: ty::StorageLayout::default(),
+            error_selectors,
             logged_types: vec![],
+            events: vec![],
+            contract_calls: vec![],
             messages_types: vec![],
         };
 
@@ -109,14 +115,16 @@ impl TyProgram {
                 match storage_decl {
                     Some(ty::TyDecl::StorageDecl(ty::StorageDecl { decl_id, .. })) => {
                         let decl = decl_engine.get_storage(decl_id);
-                        let mut storage_slots = decl.get_initialized_storage_slots(
-                            handler, engines, context, md_mgr, module,
-                        )?;
+                        let (mut storage_slots, storage_layout) = decl
+                            .get_initialized_storage_slots_and_layout(
+                                handler, engines, context, md_mgr, module,
+                            )?;
                         // Sort the slots to standardize the output. Not strictly required by the
                         // spec.
                         storage_slots.sort();
                         Ok(Self {
                             storage_slots,
+                            storage_layout,
                             ..self
                         })
                     }
@@ -322,6 +322,9 @@ impl ResolveSymbols for Supertrait {
 impl ResolveSymbols for FunctionParameter {
     fn resolve_symbols(&mut self, handler: &Handler, mut ctx: SymbolResolveContext) {
         self.type_argument.resolve_symbols(handler, ctx.by_ref());
+        if let Some(default_value) = self.default_value.as_mut() {
+            default_value.resolve_symbols(handler, ctx.by_ref());
+        }
     }
 }
 
@@ -626,8 +629,18 @@ impl ResolveSymbols for ExpressionKind {
                 expr.body.resolve_symbols(handler, ctx.by_ref());
             }
             ExpressionKind::ForLoop(expr) => expr.desugared.resolve_symbols(handler, ctx.by_ref()),
-            ExpressionKind::Break => {}
-            ExpressionKind::Continue => {}
+            ExpressionKind::LabeledBlock(expr) => {
+                expr.block
+                    .contents
+                    .iter_mut()
+                    .for_each(|node| node.resolve_symbols(handler, ctx.by_ref()));
+            }
+            ExpressionKind::Break(expr) => {
+                if let Some(value) = expr.value.as_mut() {
+                    value.resolve_symbols(handler, ctx.by_ref());
+                }
+            }
+            ExpressionKind::Continue(_) => {}
             ExpressionKind::Reassignment(expr) => {
                 match &mut expr.lhs {
                     ReassignmentTarget::ElementAccess(expr) => {
@@ -641,6 +654,10 @@ impl ResolveSymbols for ExpressionKind {
             ExpressionKind::Return(expr) => expr.resolve_symbols(handler, ctx.by_ref()),
             ExpressionKind::Ref(expr) => expr.value.resolve_symbols(handler, ctx.by_ref()),
             ExpressionKind::Deref(expr) => expr.resolve_symbols(handler, ctx.by_ref()),
+            ExpressionKind::Try(expr) => expr.expr.resolve_symbols(handler, ctx.by_ref()),
+            ExpressionKind::NamedArgument(expr) => {
+                expr.value.resolve_symbols(handler, ctx.by_ref())
+            }
         }
     }
 }
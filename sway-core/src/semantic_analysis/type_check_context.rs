@@ -110,6 +110,19 @@ pub struct TypeCheckContext<'a> {
     // In some nested places of the first pass we want to disable the first pass optimizations
     // To disable those optimizations we can set this to false.
     code_block_first_pass: bool,
+
+    /// The labels of the blocks and loops that enclose the expression being type checked,
+    /// with the innermost label last.
+    enclosing_labels: Vec<EnclosingLabel>,
+}
+
+/// A label of a block or a loop, e.g., `'outer` in `'outer: while true { ... }`.
+#[derive(Clone)]
+pub(crate) struct EnclosingLabel {
+    pub(crate) name: Ident,
+    /// The type of the values the labeled block can be exited with,
+    /// or `None` if the label belongs to a loop.
+    pub(crate) block_type: Option<TypeId>,
 }
 
 impl<'a> TypeCheckContext<'a> {
@@ -139,6 +152,7 @@ impl<'a> TypeCheckContext<'a> {
             experimental,
             collecting_unifications: false,
             code_block_first_pass: false,
+            enclosing_labels: vec![],
         }
     }
 
@@ -183,6 +197,7 @@ impl<'a> TypeCheckContext<'a> {
             experimental,
             collecting_unifications: false,
             code_block_first_pass: false,
+            enclosing_labels: vec![],
         }
     }
 
@@ -214,6 +229,7 @@ impl<'a> TypeCheckContext<'a> {
             experimental: self.experimental,
             collecting_unifications: self.collecting_unifications,
             code_block_first_pass: self.code_block_first_pass,
+            enclosing_labels: self.enclosing_labels.clone(),
         }
     }
 
@@ -251,6 +267,7 @@ impl<'a> TypeCheckContext<'a> {
                         experimental: self.experimental,
                         collecting_unifications: self.collecting_unifications,
                         code_block_first_pass: self.code_block_first_pass,
+                        enclosing_labels: self.enclosing_labels.clone(),
                     };
                     with_scoped_ctx(ctx)
                 },
@@ -275,6 +292,7 @@ impl<'a> TypeCheckContext<'a> {
                 experimental: self.experimental,
                 collecting_unifications: self.collecting_unifications,
                 code_block_first_pass: self.code_block_first_pass,
+                enclosing_labels: self.enclosing_labels.clone(),
             };
             with_scoped_ctx(ctx)
         }
@@ -315,6 +333,7 @@ impl<'a> TypeCheckContext<'a> {
                         experimental: self.experimental,
                         collecting_unifications: self.collecting_unifications,
                         code_block_first_pass: self.code_block_first_pass,
+                        enclosing_labels: self.enclosing_labels.clone(),
                     };
                     Ok((with_scoped_ctx(ctx)?, namespace))
                 },
@@ -339,6 +358,7 @@ impl<'a> TypeCheckContext<'a> {
                 experimental: self.experimental,
                 collecting_unifications: self.collecting_unifications,
                 code_block_first_pass: self.code_block_first_pass,
+                enclosing_labels: self.enclosing_labels.clone(),
             };
             Ok((with_scoped_ctx(ctx)?, namespace))
         }
@@ -482,6 +502,14 @@ impl<'a> TypeCheckContext<'a> {
         }
     }
 
+    /// Map this `TypeCheckContext` instance to a new one with the label of the block
+    /// or the loop being type checked added to the enclosing labels.
+    pub(crate) fn with_enclosing_label(mut self, name: Ident, block_type: Option<TypeId>) -> Self {
+        self.enclosing_labels
+            .push(EnclosingLabel { name, block_type });
+        self
+    }
+
     /// Map this `TypeCheckContext` instance to a new one with
     /// `disallow_functions` set to `true`.
     pub(crate) fn disallow_functions(self) -> Self {
@@ -565,6 +593,14 @@ impl<'a> TypeCheckContext<'a> {
         self.code_block_first_pass
     }
 
+    /// Returns the innermost enclosing label with the given `name`, if any.
+    pub(crate) fn enclosing_label(&self, name: &Ident) -> Option<&EnclosingLabel> {
+        self.enclosing_labels
+            .iter()
+            .rev()
+            .find(|label| label.name == *name)
+    }
+
     /// Get the engines needed for engine threading.
     pub(crate) fn engines(&self) -> &'a Engines {
         self.engines
@@ -939,6 +975,23 @@ impl<'a> TypeCheckContext<'a> {
                 }
             }
 
+            // When multiple methods apply, discard the ones coming from impls whose `where`
+            // clauses do not hold for `type_id`, e.g., the method from
+            // `impl<T> Foo for Bar<T> where T: A` when calling it on a `Bar<u64>` and `u64`
+            // doesn't implement `A`.
+            if maybe_method_decl_refs.len() > 1 {
+                let mut satisfied_method_decl_refs = vec![];
+                for decl_ref in maybe_method_decl_refs.iter() {
+                    let method = decl_engine.get_function(decl_ref);
+                    if self.impl_trait_constraints_are_satisfied(&method, type_id) {
+                        satisfied_method_decl_refs.push(decl_ref.clone());
+                    }
+                }
+                if !satisfied_method_decl_refs.is_empty() {
+                    maybe_method_decl_refs = satisfied_method_decl_refs;
+                }
+            }
+
             if !maybe_method_decl_refs.is_empty() {
                 let mut trait_methods = HashMap::<
                     (
@@ -1090,8 +1143,8 @@ impl<'a> TypeCheckContext<'a> {
                             )
                         }
                         let mut trait_strings = trait_methods
-                            .keys()
-                            .map(|t| {
+                            .iter()
+                            .map(|(t, method_ref)| {
                                 (
                                     to_string(t.0.clone(), t.1.clone()),
                                     t.2.clone()
@@ -1100,9 +1153,10 @@ impl<'a> TypeCheckContext<'a> {
                                             Some(self.engines().help_out(type_id).to_string())
                                         })
                                         .unwrap(),
+                                    self.impl_where_clause_string(method_ref),
                                 )
                             })
-                            .collect::<Vec<(String, String)>>();
+                            .collect::<Vec<(String, String, String)>>();
                         // Sort so the output of the error is always the same.
                         trait_strings.sort();
                         return Err(handler.emit_err(
@@ -1417,6 +1471,78 @@ impl<'a> TypeCheckContext<'a> {
             .insert_for_type(engines, type_id, code_block_first_pass.into());
     }
 
+    /// Returns `true` if the trait constraints on the type parameters of the impl block that
+    /// `method` is declared in hold for `type_id`.
+    ///
+    /// E.g., for a `method` declared in `impl<T> Foo for Bar<T> where T: A` and a `type_id`
+    /// `Bar<u64>`, checks if `u64` implements `A`.
+    fn impl_trait_constraints_are_satisfied(
+        &mut self,
+        method: &ty::TyFunctionDecl,
+        type_id: TypeId,
+    ) -> bool {
+        let Some(TyDecl::ImplSelfOrTrait(impl_trait)) = &method.implementing_type else {
+            return true;
+        };
+        let impl_decl = self
+            .engines
+            .de()
+            .get_impl_self_or_trait(&impl_trait.decl_id);
+        let type_mapping = TypeSubstMap::from_superset_and_subset(
+            self.engines.te(),
+            self.engines.de(),
+            impl_decl.implementing_for.type_id,
+            type_id,
+        );
+        impl_decl
+            .impl_type_parameters
+            .iter()
+            .filter(|type_param| !type_param.trait_constraints.is_empty())
+            .all(|type_param| {
+                match type_mapping.find_match(type_param.type_id, self.engines) {
+                    Some(type_arg) => {
+                        self.check_type_impls_traits(type_arg, &type_param.trait_constraints)
+                    }
+                    None => true,
+                }
+            })
+    }
+
+    /// Returns the `where` clause of the impl block that `method_ref` is declared in,
+    /// e.g., ` where T: A + B`, or an empty string if the impl has no trait constraints.
+    fn impl_where_clause_string(&self, method_ref: &DeclRefFunction) -> String {
+        let method = self.engines.de().get_function(method_ref);
+        let Some(TyDecl::ImplSelfOrTrait(impl_trait)) = &method.implementing_type else {
+            return String::new();
+        };
+        let impl_decl = self
+            .engines
+            .de()
+            .get_impl_self_or_trait(&impl_trait.decl_id);
+        let bounds = impl_decl
+            .impl_type_parameters
+            .iter()
+            .filter(|type_param| !type_param.trait_constraints.is_empty())
+            .map(|type_param| {
+                format!(
+                    "{}: {}",
+                    type_param.name,
+                    type_param
+                        .trait_constraints
+                        .iter()
+                        .map(|constraint| self.engines.help_out(constraint).to_string())
+                        .collect::<Vec<_>>()
+                        .join(" + ")
+                )
+            })
+            .collect::<Vec<_>>();
+        if bounds.is_empty() {
+            String::new()
+        } else {
+            format!(" where {}", bounds.join(", "))
+        }
+    }
+
     pub fn check_type_impls_traits(
         &mut self,
         type_id: TypeId,
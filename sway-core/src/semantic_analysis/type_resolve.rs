@@ -13,7 +13,7 @@ use crate::{
     monomorphization::type_decl_opt_to_type_id,
     namespace::{Module, ModulePath, ResolvedDeclaration, ResolvedTraitImplItem, Root},
     type_system::SubstTypes,
-    EnforceTypeArguments, Engines, Namespace, SubstTypesContext, TypeId, TypeInfo,
+    EnforceTypeArguments, Engines, Namespace, SubstTypesContext, TypeId, TypeInfo, TypeSubstMap,
 };
 
 /// Specifies if visibility checks should be performed as part of name resolution.
@@ -131,6 +131,7 @@ pub fn resolve_type(
         TypeInfo::TraitType {
             name,
             trait_type_id,
+            mut type_arguments,
         } => {
             let trait_item_ref = namespace
                 .root
@@ -142,7 +143,41 @@ pub fn resolve_type(
             if let ResolvedTraitImplItem::Typed(TyTraitItem::Type(type_ref)) = trait_item_ref {
                 let type_decl = engines.de().get_type(type_ref.id());
                 if let Some(ty) = &type_decl.ty {
-                    ty.type_id
+                    if type_arguments.is_empty() {
+                        ty.type_id
+                    } else {
+                        // Instantiate the generic associated type with its type arguments.
+                        for type_argument in type_arguments.iter_mut() {
+                            type_argument.type_id = resolve_type(
+                                handler,
+                                engines,
+                                namespace,
+                                mod_path,
+                                type_argument.type_id,
+                                span,
+                                enforce_type_arguments,
+                                None,
+                                self_type,
+                                subst_ctx,
+                                check_visibility,
+                            )
+                            .unwrap_or_else(|err| engines.te().id_of_error_recovery(err));
+                        }
+                        let type_mapping = TypeSubstMap::from_type_parameters_and_type_arguments(
+                            type_decl
+                                .type_parameters
+                                .iter()
+                                .map(|type_param| type_param.type_id)
+                                .collect(),
+                            type_arguments
+                                .iter()
+                                .map(|type_arg| type_arg.type_id)
+                                .collect(),
+                        );
+                        let mut type_id = ty.type_id;
+                        type_id.subst(&SubstTypesContext::new(engines, &type_mapping, false));
+                        type_id
+                    }
                 } else {
                     type_id
                 }
@@ -26,7 +26,11 @@ use std::{hash::Hash, sync::Arc};
 use sway_ast::Literal;
 use sway_types::{
     constants::{
-        ALLOW_DEAD_CODE_NAME, ALLOW_DEPRECATED_NAME, CFG_PROGRAM_TYPE_ARG_NAME, CFG_TARGET_ARG_NAME,
+        ABI_METHOD_OVERRIDE_VALUE_ARG_NAME, ALLOW_DEAD_CODE_NAME, ALLOW_DEPRECATED_NAME,
+        CFG_PROGRAM_TYPE_ARG_NAME, CFG_TARGET_ARG_NAME, DERIVE_ABI_DECODE_NAME,
+        DERIVE_ABI_ENCODE_NAME, DERIVE_CLONE_NAME, DERIVE_EQ_NAME, DERIVE_HASH_NAME,
+        DERIVE_PARTIAL_EQ_NAME, MOCK_CONTRACT_ARG_NAME, MOCK_METHOD_ARG_NAME,
+        MOCK_RETURNS_ARG_NAME,
     },
     Ident, Span, Spanned,
 };
@@ -67,6 +71,16 @@ pub enum AttributeKind {
     Cfg,
     Deprecated,
     Fallback,
+    ErrorType,
+    Event,
+    Indexed,
+    Derive,
+    Mock,
+    Sanitizer,
+    ReentrancyGuard,
+    IterationBound,
+    Selector,
+    MethodName,
 }
 
 impl AttributeKind {
@@ -78,7 +92,10 @@ impl AttributeKind {
             Doc | DocComment | Storage | Inline | Test | Payable | Deprecated | Fallback => {
                 (0, None)
             }
-            Allow | Cfg => (1, Some(1)),
+            Allow | Cfg | IterationBound | Selector | MethodName => (1, Some(1)),
+            ErrorType | Event | Indexed | Sanitizer | ReentrancyGuard => (0, Some(0)),
+            Derive => (1, None),
+            Mock => (2, Some(3)),
         }
     }
 
@@ -86,7 +103,8 @@ impl AttributeKind {
     pub fn expected_args_values(self, _arg_index: usize) -> Option<Vec<String>> {
         use AttributeKind::*;
         match self {
-            Deprecated | Doc | DocComment | Storage | Inline | Test | Payable | Fallback => None,
+            Deprecated | Doc | DocComment | Storage | Inline | Test | Payable | Fallback
+            | ErrorType | Event | Indexed | Sanitizer | ReentrancyGuard | IterationBound => None,
             Allow => Some(vec![
                 ALLOW_DEAD_CODE_NAME.to_string(),
                 ALLOW_DEPRECATED_NAME.to_string(),
@@ -99,6 +117,20 @@ impl AttributeKind {
                 cfgs.extend(sway_features::CFG.iter().map(|x| x.to_string()));
                 Some(cfgs)
             }
+            Derive => Some(vec![
+                DERIVE_EQ_NAME.to_string(),
+                DERIVE_PARTIAL_EQ_NAME.to_string(),
+                DERIVE_CLONE_NAME.to_string(),
+                DERIVE_HASH_NAME.to_string(),
+                DERIVE_ABI_ENCODE_NAME.to_string(),
+                DERIVE_ABI_DECODE_NAME.to_string(),
+            ]),
+            Mock => Some(vec![
+                MOCK_CONTRACT_ARG_NAME.to_string(),
+                MOCK_METHOD_ARG_NAME.to_string(),
+                MOCK_RETURNS_ARG_NAME.to_string(),
+            ]),
+            Selector | MethodName => Some(vec![ABI_METHOD_OVERRIDE_VALUE_ARG_NAME.to_string()]),
         }
     }
 }
@@ -133,6 +165,39 @@ impl AttributesMap {
     pub fn inner(&self) -> &IndexMap<AttributeKind, Vec<Attribute>> {
         &self.0
     }
+
+    /// Returns the selector pinned by a `#[selector(value = 0x...)]` attribute.
+    ///
+    /// Returns `Some(None)` if the attribute is present but its value isn't an integer fitting
+    /// into four bytes.
+    pub fn selector_override(&self) -> Option<Option<[u8; 4]>> {
+        let value = self.abi_method_override_value(&AttributeKind::Selector)?;
+        Some(match value {
+            Some(Literal::Int(lit)) => u32::try_from(&lit.parsed).ok().map(u32::to_be_bytes),
+            _ => None,
+        })
+    }
+
+    /// Returns the method name pinned by a `#[method_name(value = "...")]` attribute.
+    ///
+    /// Returns `Some(None)` if the attribute is present but its value isn't a non-empty string.
+    pub fn method_name_override(&self) -> Option<Option<&str>> {
+        let value = self.abi_method_override_value(&AttributeKind::MethodName)?;
+        Some(match value {
+            Some(Literal::String(lit)) if !lit.parsed.is_empty() => Some(lit.parsed.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Returns the value of the `value` argument of the last attribute of the given `kind`,
+    /// or `None` if there is no such attribute.
+    fn abi_method_override_value(&self, kind: &AttributeKind) -> Option<Option<&Literal>> {
+        let attribute = self.get(kind)?.last()?;
+        Some(match attribute.args.as_slice() {
+            [arg] if arg.name.as_str() == ABI_METHOD_OVERRIDE_VALUE_ARG_NAME => arg.value.as_ref(),
+            _ => None,
+        })
+    }
 }
 
 impl std::ops::Deref for AttributesMap {
@@ -17,6 +17,9 @@ pub struct Context {
     /// Unique suffix used to generate unique names for destructured tuples.
     destructured_tuple_unique_suffix: usize,
 
+    /// Unique suffix used to generate unique names for destructured arrays.
+    destructured_array_unique_suffix: usize,
+
     /// Unique suffix used to generate unique names for variables
     /// that store values matched in match expressions.
     match_expression_matched_value_unique_suffix: usize,
@@ -43,6 +46,7 @@ impl Context {
             module_has_configurable_block: std::default::Default::default(),
             destructured_struct_unique_suffix: std::default::Default::default(),
             destructured_tuple_unique_suffix: std::default::Default::default(),
+            destructured_array_unique_suffix: std::default::Default::default(),
             match_expression_matched_value_unique_suffix: std::default::Default::default(),
             for_unique_suffix: std::default::Default::default(),
             program_type: std::default::Default::default(),
@@ -72,6 +76,12 @@ impl Context {
         self.destructured_tuple_unique_suffix
     }
 
+    /// Returns a unique suffix used to generate a unique name for a destructured array
+    pub fn next_destructured_array_unique_suffix(&mut self) -> usize {
+        self.destructured_array_unique_suffix += 1;
+        self.destructured_array_unique_suffix
+    }
+
     /// Returns a unique suffix used to generate a unique name for a variable
     /// that stores the value matched in a match expression.
     pub fn next_match_expression_matched_value_unique_suffix(&mut self) -> usize {
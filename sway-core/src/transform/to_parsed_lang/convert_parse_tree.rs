@@ -1,7 +1,7 @@
 use crate::{
     compiler_generated::{
-        generate_destructured_struct_var_name, generate_matched_value_var_name,
-        generate_tuple_var_name,
+        generate_destructured_array_var_name, generate_destructured_struct_var_name,
+        generate_matched_value_var_name, generate_try_operand_var_name, generate_tuple_var_name,
     },
     decl_engine::{parsed_engine::ParsedDeclEngineInsert, parsed_id::ParsedDeclId},
     language::{parsed::*, *},
@@ -17,14 +17,15 @@ use sway_ast::{
     attribute::Annotated,
     expr::{LoopControlFlow, ReassignmentOp, ReassignmentOpVariant},
     ty::TyTupleDescriptor,
-    AbiCastArgs, AngleBrackets, AsmBlock, Assignable, AttributeDecl, Braces, CodeBlockContents,
-    CommaToken, DoubleColonToken, Expr, ExprArrayDescriptor, ExprStructField, ExprTupleDescriptor,
-    FnArg, FnArgs, FnSignature, GenericArgs, GenericParams, IfCondition, IfExpr, Instruction,
-    Intrinsic, Item, ItemAbi, ItemConfigurable, ItemConst, ItemEnum, ItemFn, ItemImpl, ItemKind,
-    ItemStorage, ItemStruct, ItemTrait, ItemTraitItem, ItemTypeAlias, ItemUse, LitInt, LitIntType,
-    MatchBranchKind, Module, ModuleKind, Parens, PathExpr, PathExprSegment, PathType,
-    PathTypeSegment, Pattern, PatternStructField, PubToken, Punctuated, QualifiedPathRoot,
-    Statement, StatementLet, Submodule, TraitType, Traits, Ty, TypeField, UseTree, WhereClause,
+    AbiCastArgs, AngleBrackets, AsmBlock, Assignable, AttributeDecl, Braces, CallArg,
+    CodeBlockContents, CommaToken, DoubleColonToken, ElseToken, Expr, ExprArrayDescriptor,
+    ExprStructField, ExprTupleDescriptor, FnArg, FnArgs, FnSignature, GenericArgs, GenericParams,
+    IfCondition, IfExpr, Instruction, Intrinsic, Item, ItemAbi, ItemConfigurable, ItemConst,
+    ItemEnum, ItemFn, ItemImpl, ItemKind, ItemStorage, ItemStruct, ItemTrait, ItemTraitItem,
+    ItemTypeAlias, ItemUse, LitInt, LitIntType, MatchBranchKind, Module, ModuleKind, Parens,
+    PathExpr, PathExprSegment, PathType, PathTypeSegment, Pattern, PatternStructField, PubToken,
+    Punctuated, QualifiedPathRoot, Statement, StatementLet, Submodule, TraitType, Traits, Ty,
+    TypeField, UseTree, WhereClause,
 };
 use sway_error::handler::{ErrorEmitted, Handler};
 use sway_error::warning::{CompileWarning, Warning};
@@ -33,10 +34,13 @@ use sway_features::ExperimentalFeatures;
 use sway_types::{
     constants::{
         ALLOW_ATTRIBUTE_NAME, CFG_ATTRIBUTE_NAME, CFG_PROGRAM_TYPE_ARG_NAME, CFG_TARGET_ARG_NAME,
-        DEPRECATED_ATTRIBUTE_NAME, DOC_ATTRIBUTE_NAME, DOC_COMMENT_ATTRIBUTE_NAME,
-        FALLBACK_ATTRIBUTE_NAME, INLINE_ATTRIBUTE_NAME, PAYABLE_ATTRIBUTE_NAME,
-        STORAGE_PURITY_ATTRIBUTE_NAME, STORAGE_PURITY_READ_NAME, STORAGE_PURITY_WRITE_NAME,
-        TEST_ATTRIBUTE_NAME, VALID_ATTRIBUTE_NAMES,
+        DEPRECATED_ATTRIBUTE_NAME, DERIVE_ATTRIBUTE_NAME, DOC_ATTRIBUTE_NAME,
+        DOC_COMMENT_ATTRIBUTE_NAME, ERROR_TYPE_ATTRIBUTE_NAME, EVENT_ATTRIBUTE_NAME,
+        FALLBACK_ATTRIBUTE_NAME, INDEXED_ATTRIBUTE_NAME, INLINE_ATTRIBUTE_NAME,
+        ITERATION_BOUND_ATTRIBUTE_NAME, METHOD_NAME_ATTRIBUTE_NAME, MOCK_ATTRIBUTE_NAME,
+        PAYABLE_ATTRIBUTE_NAME, REENTRANCY_GUARD_ATTRIBUTE_NAME, SANITIZER_ATTRIBUTE_NAME,
+        SELECTOR_ATTRIBUTE_NAME, STORAGE_PURITY_ATTRIBUTE_NAME, STORAGE_PURITY_READ_NAME,
+        STORAGE_PURITY_WRITE_NAME, TEST_ATTRIBUTE_NAME, VALID_ATTRIBUTE_NAMES,
     },
     integer_bits::IntegerBits,
     BaseIdent,
@@ -120,6 +124,9 @@ pub fn item_to_ast_nodes(
     if !cfg_eval(context, handler, &attributes, context.experimental)? {
         return Ok(vec![]);
     }
+    if !matches!(item.value, ItemKind::Enum(_)) {
+        reject_error_type_attribute(handler, &attributes);
+    }
 
     let decl = |d| vec![AstNodeContent::Declaration(d)];
 
@@ -406,6 +413,7 @@ fn item_struct_to_struct_declaration(
         .into_iter()
         .map(|type_field| {
             let attributes = item_attrs_to_map(context, handler, &type_field.attribute_list)?;
+            reject_error_type_attribute(handler, &attributes);
             if !cfg_eval(context, handler, &attributes, context.experimental)? {
                 return Ok(None);
             }
@@ -474,6 +482,7 @@ fn item_enum_to_enum_declaration(
         .enumerate()
         .map(|(tag, type_field)| {
             let attributes = item_attrs_to_map(context, handler, &type_field.attribute_list)?;
+            reject_error_type_attribute(handler, &attributes);
             if !cfg_eval(context, handler, &attributes, context.experimental)? {
                 return Ok(None);
             }
@@ -672,6 +681,7 @@ fn item_trait_to_trait_declaration(
         .into_iter()
         .map(|annotated| {
             let attributes = item_attrs_to_map(context, handler, &annotated.attribute_list)?;
+            reject_error_type_attribute(handler, &attributes);
             if !cfg_eval(context, handler, &attributes, context.experimental)? {
                 return Ok(None);
             }
@@ -700,6 +710,7 @@ fn item_trait_to_trait_declaration(
             .into_iter()
             .map(|item_fn| {
                 let attributes = item_attrs_to_map(context, handler, &item_fn.attribute_list)?;
+                reject_error_type_attribute(handler, &attributes);
                 if !cfg_eval(context, handler, &attributes, context.experimental)? {
                     return Ok(None);
                 }
@@ -749,6 +760,7 @@ pub fn item_impl_to_declaration(
         .into_iter()
         .map(|item| {
             let attributes = item_attrs_to_map(context, handler, &item.attribute_list)?;
+            reject_error_type_attribute(handler, &attributes);
             if !cfg_eval(context, handler, &attributes, context.experimental)? {
                 return Ok(None);
             }
@@ -876,6 +888,7 @@ fn item_abi_to_abi_declaration(
                 .map(|annotated| {
                     let attributes =
                         item_attrs_to_map(context, handler, &annotated.attribute_list)?;
+                    reject_error_type_attribute(handler, &attributes);
                     if !cfg_eval(context, handler, &attributes, context.experimental)? {
                         return Ok(None);
                     }
@@ -922,6 +935,7 @@ fn item_abi_to_abi_declaration(
                 .into_iter()
                 .map(|item_fn| {
                     let attributes = item_attrs_to_map(context, handler, &item_fn.attribute_list)?;
+                    reject_error_type_attribute(handler, &attributes);
                     if !cfg_eval(context, handler, &attributes, context.experimental)? {
                         return Ok(None);
                     }
@@ -1013,8 +1027,11 @@ pub(crate) fn trait_type_to_trait_type_declaration(
     attributes: AttributesMap,
 ) -> Result<ParsedDeclId<TraitTypeDeclaration>, ErrorEmitted> {
     let span = trait_type.span();
+    let type_parameters =
+        generic_params_opt_to_type_parameters(context, handler, engines, trait_type.generics, None)?;
     let trait_type_decl = TraitTypeDeclaration {
         name: trait_type.name.clone(),
+        type_parameters,
         attributes,
         ty_opt: if let Some(ty) = trait_type.ty_opt {
             Some(ty_to_type_argument(context, handler, engines, ty)?)
@@ -1042,6 +1059,7 @@ fn item_storage_to_storage_declaration(
         .into_iter()
         .map(|storage_entry| {
             let attributes = item_attrs_to_map(context, handler, &storage_entry.attribute_list)?;
+            reject_error_type_attribute(handler, &attributes);
             if !cfg_eval(context, handler, &attributes, context.experimental)? {
                 return Ok(None);
             }
@@ -1124,6 +1142,7 @@ fn item_configurable_to_configurable_declarations(
         .map(|configurable_field| {
             let attributes =
                 item_attrs_to_map(context, handler, &configurable_field.attribute_list)?;
+            reject_error_type_attribute(handler, &attributes);
             if !cfg_eval(context, handler, &attributes, context.experimental)? {
                 return Ok(None);
             }
@@ -1342,32 +1361,189 @@ fn braced_code_block_contents_to_code_block(
 ) -> Result<CodeBlock, ErrorEmitted> {
     let whole_block_span = braced_code_block_contents.span();
     let code_block_contents = braced_code_block_contents.into_inner();
-    let contents = {
-        let mut error = None;
+    let contents = code_block_statements_to_ast_nodes(
+        context,
+        handler,
+        engines,
+        code_block_contents.statements,
+        code_block_contents.final_expr_opt,
+    )?;
+
+    Ok(CodeBlock {
+        contents,
+        whole_block_span,
+    })
+}
 
-        let mut contents = Vec::new();
-        for statement in code_block_contents.statements {
-            match statement_to_ast_nodes(context, handler, engines, statement) {
+/// Converts the `statements` of a code block, followed by its `final_expr_opt`.
+///
+/// A `let-else` statement takes the statements following it, which become the code block
+/// in which its pattern variables are declared. See [let_else_to_ast_node].
+fn code_block_statements_to_ast_nodes(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    statements: Vec<Statement>,
+    final_expr_opt: Option<Box<Expr>>,
+) -> Result<Vec<AstNode>, ErrorEmitted> {
+    let mut error = None;
+
+    let mut contents = Vec::new();
+    let mut statements = statements.into_iter();
+    while let Some(statement) = statements.next() {
+        match statement {
+            Statement::Let(statement_let) if statement_let.else_opt.is_some() => {
+                let ast_node = let_else_to_ast_node(
+                    context,
+                    handler,
+                    engines,
+                    statement_let,
+                    statements.collect(),
+                    final_expr_opt,
+                )?;
+                contents.push(ast_node);
+                return match error {
+                    Some(error) => Err(error),
+                    None => Ok(contents),
+                };
+            }
+            statement => match statement_to_ast_nodes(context, handler, engines, statement) {
                 Ok(mut ast_nodes) => contents.append(&mut ast_nodes),
                 Err(e) => error = Some(e),
-            }
+            },
         }
+    }
 
-        if let Some(expr) = code_block_contents.final_expr_opt {
-            let final_ast_node = expr_to_ast_node(context, handler, engines, *expr, false)?;
-            contents.push(final_ast_node);
-        }
+    if let Some(expr) = final_expr_opt {
+        let final_ast_node = expr_to_ast_node(context, handler, engines, *expr, false)?;
+        contents.push(final_ast_node);
+    }
 
-        if let Some(error) = error {
-            return Err(error);
-        } else {
-            contents
-        }
+    if let Some(error) = error {
+        Err(error)
+    } else {
+        Ok(contents)
+    }
+}
+
+/// Converts `let pattern = expr else { ... };`, followed by the `statements` and the
+/// `final_expr_opt` of its code block, into:
+///
+/// ```ignore
+/// match expr {
+///     pattern => {
+///         statements;
+///         final_expr
+///     },
+///     _ => {
+///         let _: ! = { ... };
+///     },
+/// }
+/// ```
+///
+/// The pattern variables are thus in scope exactly in the statements following the `let`,
+/// and the `else` block must diverge, e.g., by returning or reverting.
+fn let_else_to_ast_node(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    statement_let: StatementLet,
+    statements: Vec<Statement>,
+    final_expr_opt: Option<Box<Expr>>,
+) -> Result<AstNode, ErrorEmitted> {
+    let StatementLet {
+        let_token,
+        pattern,
+        ty_opt,
+        expr,
+        else_opt,
+        semicolon_token,
+        ..
+    } = statement_let;
+    let (else_token, else_block) = else_opt.expect("the `let` statement has an `else` block");
+
+    // The spans of the generated code blocks and match branches must differ from each other,
+    // because they identify their lexical scopes. The rest of the code block starts at the
+    // semicolon of the `let`, the branch of the pattern at the pattern itself, and the whole
+    // `match` at the `let` keyword.
+    let rest_end_span = match (&final_expr_opt, statements.last()) {
+        (Some(final_expr), _) => final_expr.span(),
+        (None, Some(statement)) => statement.span(),
+        (None, None) => semicolon_token.span(),
+    };
+    let rest_span = Span::join(semicolon_token.span(), &rest_end_span);
+    let span = Span::join(let_token.span(), &rest_end_span);
+
+    let scrutinee = pattern_to_scrutinee(context, handler, pattern)?;
+    let pattern_branch_span = Span::join(scrutinee.span(), &rest_end_span);
+    let rest =
+        code_block_statements_to_ast_nodes(context, handler, engines, statements, final_expr_opt)?;
+    let pattern_branch = MatchBranch {
+        scrutinee,
+        result: Expression {
+            kind: ExpressionKind::CodeBlock(CodeBlock {
+                contents: rest,
+                whole_block_span: rest_span.clone(),
+            }),
+            span: rest_span,
+        },
+        span: pattern_branch_span,
     };
 
-    Ok(CodeBlock {
-        contents,
-        whole_block_span,
+    // The `else` block is assigned to a variable of the never type, so that a block that does
+    // not diverge is reported as a type mismatch.
+    let else_block_span = else_block.span();
+    let else_block =
+        braced_code_block_contents_to_expression(context, handler, engines, else_block)?;
+    let diverging_var_name = Ident::new_with_override("_".to_string(), else_token.span());
+    let diverging_var_decl = engines.pe().insert(VariableDeclaration {
+        type_ascription: {
+            let type_id = engines.te().id_of_never();
+            TypeArgument {
+                type_id,
+                initial_type_id: type_id,
+                span: else_token.span(),
+                call_path_tree: None,
+            }
+        },
+        name: diverging_var_name,
+        is_mutable: false,
+        body: else_block,
+    });
+    let else_branch = MatchBranch {
+        scrutinee: Scrutinee::CatchAll {
+            span: else_token.span(),
+        },
+        result: Expression {
+            kind: ExpressionKind::CodeBlock(CodeBlock {
+                contents: vec![AstNode {
+                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                        diverging_var_decl,
+                    )),
+                    span: else_block_span.clone(),
+                }],
+                whole_block_span: else_token.span(),
+            }),
+            span: else_token.span(),
+        },
+        span: Span::join(else_token.span(), &else_block_span),
+    };
+
+    let match_expression = match_expr_to_expression(
+        context,
+        handler,
+        engines,
+        expr,
+        ty_opt.map(|(_colon_token, ty)| ty),
+        vec![pattern_branch, else_branch],
+        span.clone(),
+    )?;
+    Ok(AstNode {
+        content: AstNodeContent::Expression(Expression {
+            kind: ExpressionKind::ImplicitReturn(Box::new(match_expression)),
+            span: span.clone(),
+        }),
+        span,
     })
 }
 
@@ -1406,6 +1582,7 @@ fn fn_args_to_function_parameters(
                     span: self_token.span(),
                     call_path_tree: None,
                 },
+                default_value: None,
             }];
             if let Some((_comma_token, args)) = args_opt {
                 for arg in args {
@@ -1430,6 +1607,24 @@ fn fn_args_to_function_parameters(
         }
     }
 
+    // Only the trailing parameters can be left out of a call, so once a parameter has a default
+    // value, all the parameters following it must have one too.
+    if let Some(first_default) = function_parameters
+        .iter()
+        .position(|fn_param| fn_param.default_value.is_some())
+    {
+        if let Some(fn_param) = function_parameters[first_default..]
+            .iter()
+            .find(|fn_param| fn_param.default_value.is_none())
+        {
+            let error = ConvertParseTreeError::MissingTrailingDefaultParameterValue {
+                name: fn_param.name.clone(),
+                span: fn_param.name.span(),
+            };
+            return Err(handler.emit_err(error.into()));
+        }
+    }
+
     Ok(function_parameters)
 }
 
@@ -1775,7 +1970,7 @@ fn method_call_fields_to_method_application_expression(
     target: Box<Expr>,
     path_seg: PathExprSegment,
     contract_args_opt: Option<Braces<Punctuated<ExprStructField, CommaToken>>>,
-    args: Parens<Punctuated<Expr, CommaToken>>,
+    args: Parens<Punctuated<CallArg, CommaToken>>,
 ) -> Result<Box<MethodApplicationExpression>, ErrorEmitted> {
     let (method_name, type_arguments) =
         path_expr_segment_to_ident_or_type_argument(context, handler, engines, path_seg)?;
@@ -1805,10 +2000,10 @@ fn method_call_fields_to_method_application_expression(
             })
             .collect::<Result<_, _>>()?,
     };
-    let arguments = iter::once(*target)
-        .chain(args.into_inner())
-        .map(|expr| expr_to_expression(context, handler, engines, expr))
-        .collect::<Result<_, _>>()?;
+    let target = expr_to_expression(context, handler, engines, *target)?;
+    let arguments = iter::once(target)
+        .chain(call_args_to_expressions(context, handler, engines, args)?)
+        .collect();
     Ok(Box::new(MethodApplicationExpression {
         method_name_binding,
         contract_call_params,
@@ -1821,7 +2016,7 @@ fn expr_func_app_to_expression_kind(
     handler: &Handler,
     engines: &Engines,
     func: Box<Expr>,
-    args: Parens<Punctuated<Expr, CommaToken>>,
+    args: Parens<Punctuated<CallArg, CommaToken>>,
 ) -> Result<ExpressionKind, ErrorEmitted> {
     let span = Span::join(func.span(), &args.span());
 
@@ -1873,11 +2068,7 @@ fn expr_func_app_to_expression_kind(
         }
     };
 
-    let arguments = args
-        .into_inner()
-        .into_iter()
-        .map(|expr| expr_to_expression(context, handler, engines, expr))
-        .collect::<Result<_, _>>()?;
+    let arguments = call_args_to_expressions(context, handler, engines, args)?;
 
     let name_args_span = |start, end: Option<_>| match end {
         Some(end) => Span::join(start, &end),
@@ -2000,6 +2191,39 @@ fn expr_func_app_to_expression_kind(
     )))
 }
 
+/// Converts the arguments of a function or method call. Named arguments are kept as
+/// [ExpressionKind::NamedArgument]s, and put in the order of the parameters during type checking.
+fn call_args_to_expressions(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    args: Parens<Punctuated<CallArg, CommaToken>>,
+) -> Result<Vec<Expression>, ErrorEmitted> {
+    let span = args.span();
+    let args = args.into_inner().into_iter().collect::<Vec<_>>();
+    let named_args_count = args.iter().filter(|arg| arg.name_opt.is_some()).count();
+    if named_args_count != 0 && named_args_count != args.len() {
+        let error = ConvertParseTreeError::MixedNamedAndPositionalArguments { span };
+        return Err(handler.emit_err(error.into()));
+    }
+    args.into_iter()
+        .map(|arg| {
+            let span = arg.span();
+            let value = expr_to_expression(context, handler, engines, arg.value)?;
+            Ok(match arg.name_opt {
+                Some((name, _colon_token)) => Expression {
+                    kind: ExpressionKind::NamedArgument(Box::new(NamedArgumentExpression {
+                        name,
+                        value,
+                    })),
+                    span,
+                },
+                None => value,
+            })
+        })
+        .collect()
+}
+
 fn expr_to_expression(
     context: &mut Context,
     handler: &Handler,
@@ -2123,7 +2347,7 @@ fn expr_to_expression(
                     .collect::<Result<_, _>>()?
             };
 
-            match_expr_to_expression(context, handler, engines, *value, branches, span)?
+            match_expr_to_expression(context, handler, engines, *value, None, branches, span)?
         }
         Expr::While {
             condition, block, ..
@@ -2132,6 +2356,7 @@ fn expr_to_expression(
                 condition: Box::new(expr_to_expression(context, handler, engines, *condition)?),
                 body: braced_code_block_contents_to_code_block(context, handler, engines, block)?,
                 is_desugared_for_loop: false,
+                label: None,
             }),
             span,
         },
@@ -2147,12 +2372,63 @@ fn expr_to_expression(
             &value_pattern,
             iterator,
             block,
+            None,
             span,
         )?,
+        Expr::Labeled { label, expr, .. } => {
+            let label = label.name;
+            match *expr {
+                Expr::Block(block) => Expression {
+                    kind: ExpressionKind::LabeledBlock(LabeledBlockExpression {
+                        label,
+                        block: braced_code_block_contents_to_code_block(
+                            context, handler, engines, block,
+                        )?,
+                    }),
+                    span,
+                },
+                Expr::While {
+                    condition, block, ..
+                } => Expression {
+                    kind: ExpressionKind::WhileLoop(WhileLoopExpression {
+                        condition: Box::new(expr_to_expression(
+                            context, handler, engines, *condition,
+                        )?),
+                        body: braced_code_block_contents_to_code_block(
+                            context, handler, engines, block,
+                        )?,
+                        is_desugared_for_loop: false,
+                        label: Some(label),
+                    }),
+                    span,
+                },
+                Expr::For {
+                    value_pattern,
+                    iterator,
+                    block,
+                    ..
+                } => for_expr_to_expression(
+                    context,
+                    handler,
+                    engines,
+                    &value_pattern,
+                    iterator,
+                    block,
+                    Some(label),
+                    span,
+                )?,
+                // The parser only allows blocks and loops to be labeled.
+                expr => expr_to_expression(context, handler, engines, expr)?,
+            }
+        }
         Expr::FuncApp { func, args } => {
             let kind = expr_func_app_to_expression_kind(context, handler, engines, func, args)?;
             Expression { kind, span }
         }
+        Expr::Index { arg, .. } if matches!(**arg.get(), Expr::Range { .. }) => {
+            let error = ConvertParseTreeError::RangeIndexNotReferenced { span };
+            return Err(handler.emit_err(error.into()));
+        }
         Expr::Index { target, arg } => Expression {
             kind: ExpressionKind::ArrayIndex(ArrayIndexExpression {
                 prefix: Box::new(expr_to_expression(context, handler, engines, *target)?),
@@ -2162,6 +2438,7 @@ fn expr_to_expression(
                     engines,
                     *arg.into_inner(),
                 )?),
+                from_end: None,
             }),
             span,
         },
@@ -2248,14 +2525,48 @@ fn expr_to_expression(
             }),
             span,
         },
+        Expr::Try {
+            expr,
+            question_mark_token,
+        } => {
+            let unique_suffix = context.next_match_expression_matched_value_unique_suffix();
+            let operand_var_name =
+                Ident::new_with_override(generate_try_operand_var_name(unique_suffix), expr.span());
+            let matched_value_var_name = Ident::new_with_override(
+                generate_matched_value_var_name(unique_suffix),
+                expr.span(),
+            );
+            Expression {
+                kind: ExpressionKind::Try(TryExpression {
+                    expr: Box::new(expr_to_expression(context, handler, engines, *expr)?),
+                    question_mark_span: question_mark_token.span(),
+                    operand_var_name,
+                    matched_value_var_name,
+                }),
+                span,
+            }
+        }
         Expr::Ref {
             mut_token, expr, ..
-        } => Expression {
-            kind: ExpressionKind::Ref(RefExpression {
-                to_mutable_value: mut_token.is_some(),
-                value: Box::new(expr_to_expression(context, handler, engines, *expr)?),
-            }),
-            span,
+        } => match *expr {
+            Expr::Index { target, arg } if matches!(**arg.get(), Expr::Range { .. }) => {
+                range_index_to_expression(
+                    context,
+                    handler,
+                    engines,
+                    mut_token.is_some(),
+                    *target,
+                    *arg.into_inner(),
+                    span,
+                )?
+            }
+            expr => Expression {
+                kind: ExpressionKind::Ref(RefExpression {
+                    to_mutable_value: mut_token.is_some(),
+                    value: Box::new(expr_to_expression(context, handler, engines, expr)?),
+                }),
+                span,
+            },
         },
         Expr::Deref { expr, .. } => Expression {
             kind: ExpressionKind::Deref(Box::new(expr_to_expression(
@@ -2267,6 +2578,22 @@ fn expr_to_expression(
             let expr = expr_to_expression(context, handler, engines, *expr)?;
             op_call("not", bang_token.span(), span, &[expr])?
         }
+        Expr::Cast { expr, as_token, ty } => {
+            let expr = expr_to_expression(context, handler, engines, *expr)?;
+            let type_argument = ty_to_type_argument(context, handler, engines, ty)?;
+            Expression {
+                kind: ExpressionKind::IntrinsicFunction(IntrinsicFunctionExpression {
+                    name: Ident::new_with_override("__cast".into(), as_token.span()),
+                    kind_binding: TypeBinding {
+                        inner: Intrinsic::Cast,
+                        type_arguments: TypeArgs::Regular(vec![type_argument]),
+                        span: span.clone(),
+                    },
+                    arguments: vec![expr],
+                }),
+                span,
+            }
+        }
         Expr::Pow {
             lhs,
             double_star_token,
@@ -2436,6 +2763,10 @@ fn expr_to_expression(
             }),
             span,
         },
+        Expr::Range { .. } => {
+            let error = ConvertParseTreeError::RangesNotSupportedHere { span };
+            return Err(handler.emit_err(error.into()));
+        }
         Expr::Reassignment {
             assignable,
             expr,
@@ -2474,18 +2805,86 @@ fn expr_to_expression(
                 }
             }
         },
-        Expr::Break { .. } => Expression {
-            kind: ExpressionKind::Break,
+        Expr::Break {
+            label_opt,
+            value_opt,
+            ..
+        } => Expression {
+            kind: ExpressionKind::Break(BreakExpression {
+                label: label_opt.map(|label| label.name),
+                value: value_opt
+                    .map(|value| expr_to_expression(context, handler, engines, *value))
+                    .transpose()?
+                    .map(Box::new),
+            }),
             span,
         },
-        Expr::Continue { .. } => Expression {
-            kind: ExpressionKind::Continue,
+        Expr::Continue { label_opt, .. } => Expression {
+            kind: ExpressionKind::Continue(ContinueExpression {
+                label: label_opt.map(|label| label.name),
+            }),
             span,
         },
     };
     Ok(expression)
 }
 
+/// Converts the range indexing `&target[start..end]` to a call to the `__slice` intrinsic.
+///
+/// A missing start is `0`, and a missing end is the length of the `target`, which `__slice`
+/// takes if called with only two arguments.
+fn range_index_to_expression(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    to_mutable_value: bool,
+    target: Expr,
+    range: Expr,
+    span: Span,
+) -> Result<Expression, ErrorEmitted> {
+    let Expr::Range {
+        start,
+        double_dot_token,
+        end,
+    } = range
+    else {
+        unreachable!("Range indexing is always done with a range.");
+    };
+
+    let target_span = target.span();
+    let target = Expression {
+        kind: ExpressionKind::Ref(RefExpression {
+            to_mutable_value,
+            value: Box::new(expr_to_expression(context, handler, engines, target)?),
+        }),
+        span: target_span,
+    };
+    let start = match start {
+        Some(start) => expr_to_expression(context, handler, engines, *start)?,
+        None => Expression {
+            kind: ExpressionKind::Literal(Literal::U64(0)),
+            span: double_dot_token.span(),
+        },
+    };
+    let mut arguments = vec![target, start];
+    if let Some(end) = end {
+        arguments.push(expr_to_expression(context, handler, engines, *end)?);
+    }
+
+    Ok(Expression {
+        kind: ExpressionKind::IntrinsicFunction(IntrinsicFunctionExpression {
+            name: Ident::new_with_override("__slice".into(), double_dot_token.span()),
+            kind_binding: TypeBinding {
+                inner: Intrinsic::Slice,
+                type_arguments: TypeArgs::Regular(vec![]),
+                span: span.clone(),
+            },
+            arguments,
+        }),
+        span,
+    })
+}
+
 fn op_call(
     name: &'static str,
     op_span: Span,
@@ -2540,6 +2939,7 @@ fn storage_entry_to_storage_entry(
             .flat_map(|storage_entry| {
                 let attributes =
                     item_attrs_to_map(context, handler, &storage_entry.attribute_list)?;
+                reject_error_type_attribute(handler, &attributes);
                 if !cfg_eval(context, handler, &attributes, context.experimental)? {
                     return Ok::<Option<StorageEntry>, ErrorEmitted>(None);
                 }
@@ -2713,6 +3113,14 @@ fn fn_arg_to_function_parameter(
             let error = ConvertParseTreeError::TuplePatternsNotSupportedHere { span: pat_span };
             return Err(handler.emit_err(error.into()));
         }
+        Pattern::Slice(..) => {
+            let error = ConvertParseTreeError::SlicePatternsNotSupportedHere { span: pat_span };
+            return Err(handler.emit_err(error.into()));
+        }
+        Pattern::Rest { .. } => {
+            let error = ConvertParseTreeError::RestPatternsNotSupportedHere { span: pat_span };
+            return Err(handler.emit_err(error.into()));
+        }
     };
     let mutability_span = match (&reference, &mutable) {
         (None, None) => Span::dummy(),
@@ -2726,6 +3134,10 @@ fn fn_arg_to_function_parameter(
         is_mutable: mutable.is_some(),
         mutability_span,
         type_argument: ty_to_type_argument(context, handler, engines, fn_arg.ty)?,
+        default_value: fn_arg
+            .default_opt
+            .map(|(_eq_token, default)| expr_to_expression(context, handler, engines, default))
+            .transpose()?,
     };
     Ok(function_parameter)
 }
@@ -2934,7 +3346,101 @@ fn if_expr_to_expression(
         )?),
         span: then_block_span.clone(),
     };
-    let else_block = match else_opt {
+    let expression = match condition {
+        IfCondition::Expr(condition) => Expression {
+            kind: ExpressionKind::If(IfExpression {
+                condition: Box::new(expr_to_expression(context, handler, engines, *condition)?),
+                then: Box::new(then_block),
+                r#else: else_to_expression(context, handler, engines, else_opt)?.map(Box::new),
+            }),
+            span,
+        },
+        IfCondition::Let {
+            let_token,
+            lhs,
+            eq_token,
+            rhs,
+            chain,
+        } => {
+            // A chain `if let p1 = e1 && c2 && let p3 = e3 { .. } else { .. }` is nested into
+            // `if let p1 = e1 { if c2 { if let p3 = e3 { .. } else { .. } } else { .. } } else { .. }`,
+            // where each condition gets its own copy of the `else` block.
+            let mut conditions = vec![IfCondition::Let {
+                let_token,
+                lhs,
+                eq_token,
+                rhs,
+                chain: vec![],
+            }];
+            conditions.extend(
+                chain
+                    .into_iter()
+                    .map(|(_double_ampersand_token, link)| link),
+            );
+
+            let innermost = conditions.len() - 1;
+            let mut expression = then_block;
+            for (i, condition) in conditions.into_iter().enumerate().rev() {
+                let condition_start_span = match &condition {
+                    IfCondition::Expr(expr) => expr.span(),
+                    IfCondition::Let { let_token, .. } => let_token.span(),
+                };
+                // The nested conditions span until the end of the whole `if`. This keeps the
+                // spans of their generated code blocks, which identify lexical scopes, distinct.
+                let condition_span = if i == 0 {
+                    span.clone()
+                } else {
+                    Span::join(condition_start_span.clone(), &span)
+                };
+                let else_block = else_to_expression(context, handler, engines, else_opt.clone())?;
+                expression = match condition {
+                    IfCondition::Expr(condition) => Expression {
+                        kind: ExpressionKind::If(IfExpression {
+                            condition: Box::new(expr_to_expression(
+                                context, handler, engines, *condition,
+                            )?),
+                            then: Box::new(expression),
+                            r#else: else_block.map(Box::new),
+                        }),
+                        span: condition_span,
+                    },
+                    IfCondition::Let { lhs, rhs, .. } => {
+                        let empty_else_span = if i == innermost {
+                            then_block_span.clone()
+                        } else {
+                            condition_start_span
+                        };
+                        if_let_to_expression(
+                            context,
+                            handler,
+                            engines,
+                            *lhs,
+                            *rhs,
+                            expression,
+                            else_block,
+                            empty_else_span,
+                            condition_span,
+                        )?
+                    }
+                };
+            }
+            expression
+        }
+    };
+    Ok(expression)
+}
+
+/// Converts the `else` of an `if` expression, if any.
+fn else_to_expression(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    else_opt: Option<(
+        ElseToken,
+        LoopControlFlow<Braces<CodeBlockContents>, Box<IfExpr>>,
+    )>,
+) -> Result<Option<Expression>, ErrorEmitted> {
+    let expression = match else_opt {
         None => None,
         Some((_else_token, tail)) => {
             let expression = match tail {
@@ -2953,58 +3459,64 @@ fn if_expr_to_expression(
             Some(expression)
         }
     };
-    let expression = match condition {
-        IfCondition::Expr(condition) => Expression {
-            kind: ExpressionKind::If(IfExpression {
-                condition: Box::new(expr_to_expression(context, handler, engines, *condition)?),
-                then: Box::new(then_block),
-                r#else: else_block.map(Box::new),
-            }),
-            span,
-        },
-        IfCondition::Let { lhs, rhs, .. } => {
-            let scrutinee = pattern_to_scrutinee(context, handler, *lhs)?;
-            let scrutinee_span = scrutinee.span();
-            let mut branches = vec![MatchBranch {
-                scrutinee,
-                result: then_block.clone(),
-                span: Span::join(scrutinee_span, &then_block_span),
-            }];
-            branches.push(match else_block {
-                Some(else_block) => {
-                    let else_block_span = else_block.span();
-                    MatchBranch {
-                        scrutinee: Scrutinee::CatchAll {
-                            span: else_block_span.clone(),
-                        },
-                        result: else_block,
-                        span: else_block_span,
-                    }
-                }
-                None => {
-                    let else_block_span = then_block.span();
-                    MatchBranch {
-                        scrutinee: Scrutinee::CatchAll {
-                            span: else_block_span.clone(),
-                        },
-                        // If there's no else in an `if-let` expression,
-                        // then the else is equivalent to an empty block.
-                        result: Expression {
-                            kind: ExpressionKind::CodeBlock(CodeBlock {
-                                contents: vec![],
-                                whole_block_span: else_block_span.clone(),
-                            }),
-                            span: else_block_span.clone(),
-                        },
-                        span: else_block_span,
-                    }
-                }
-            });
+    Ok(expression)
+}
 
-            match_expr_to_expression(context, handler, engines, *rhs, branches, span)?
+/// Converts `if let lhs = rhs { then } else { else }` into a `match` on the `rhs`.
+///
+/// Without an `else` block, the `else` is an empty block spanning `empty_else_span`.
+#[allow(clippy::too_many_arguments)]
+fn if_let_to_expression(
+    context: &mut Context,
+    handler: &Handler,
+    engines: &Engines,
+    lhs: Pattern,
+    rhs: Expr,
+    then: Expression,
+    else_block: Option<Expression>,
+    empty_else_span: Span,
+    span: Span,
+) -> Result<Expression, ErrorEmitted> {
+    let then_span = then.span();
+    let scrutinee = pattern_to_scrutinee(context, handler, lhs)?;
+    let scrutinee_span = scrutinee.span();
+    let mut branches = vec![MatchBranch {
+        scrutinee,
+        result: then,
+        span: Span::join(scrutinee_span, &then_span),
+    }];
+    branches.push(match else_block {
+        Some(else_block) => {
+            let else_block_span = else_block.span();
+            MatchBranch {
+                scrutinee: Scrutinee::CatchAll {
+                    span: else_block_span.clone(),
+                },
+                result: else_block,
+                span: else_block_span,
+            }
         }
-    };
-    Ok(expression)
+        None => {
+            let else_block_span = empty_else_span;
+            MatchBranch {
+                scrutinee: Scrutinee::CatchAll {
+                    span: else_block_span.clone(),
+                },
+                // If there's no else in an `if-let` expression,
+                // then the else is equivalent to an empty block.
+                result: Expression {
+                    kind: ExpressionKind::CodeBlock(CodeBlock {
+                        contents: vec![],
+                        whole_block_span: else_block_span.clone(),
+                    }),
+                    span: else_block_span.clone(),
+                },
+                span: else_block_span,
+            }
+        }
+    });
+
+    match_expr_to_expression(context, handler, engines, rhs, None, branches, span)
 }
 
 fn match_expr_to_expression(
@@ -3012,6 +3524,7 @@ fn match_expr_to_expression(
     handler: &Handler,
     engines: &Engines,
     value: Expr,
+    value_ty_opt: Option<Ty>,
     branches: Vec<MatchBranch>,
     span: Span,
 ) -> Result<Expression, ErrorEmitted> {
@@ -3031,13 +3544,16 @@ fn match_expr_to_expression(
     };
 
     let var_decl = engines.pe().insert(VariableDeclaration {
-        type_ascription: {
-            let type_id = engines.te().new_unknown();
-            TypeArgument {
-                type_id,
-                initial_type_id: type_id,
-                span: var_decl_name.span(),
-                call_path_tree: None,
+        type_ascription: match value_ty_opt {
+            Some(ty) => ty_to_type_argument(context, handler, engines, ty)?,
+            None => {
+                let type_id = engines.te().new_unknown();
+                TypeArgument {
+                    type_id,
+                    initial_type_id: type_id,
+                    span: var_decl_name.span(),
+                    call_path_tree: None,
+                }
             }
         },
         name: var_decl_name,
@@ -3081,6 +3597,7 @@ fn for_expr_to_expression(
     value_pattern: &Pattern,
     iterator: Box<Expr>,
     block: Braces<CodeBlockContents>,
+    label: Option<Ident>,
     span: Span,
 ) -> Result<Expression, ErrorEmitted> {
     // Desugar for loop into:
@@ -3234,7 +3751,10 @@ fn for_expr_to_expression(
                         kind: ExpressionKind::CodeBlock(CodeBlock {
                             contents: vec![AstNode {
                                 content: AstNodeContent::Expression(Expression {
-                                    kind: ExpressionKind::Break,
+                                    kind: ExpressionKind::Break(BreakExpression {
+                                        label: None,
+                                        value: None,
+                                    }),
                                     span: Span::dummy(),
                                 }),
                                 span: Span::dummy(),
@@ -3280,6 +3800,7 @@ fn for_expr_to_expression(
                             }),
                             body: while_body,
                             is_desugared_for_loop: true,
+                            label,
                         }),
                         span: Span::dummy(),
                     }),
@@ -3359,10 +3880,15 @@ fn literal_to_literal(
         sway_ast::Literal::Bool(lit_bool) => Literal::Boolean(lit_bool.kind.into()),
         sway_ast::Literal::String(lit_string) => {
             let full_span = lit_string.span();
+            // Raw strings, e.g., `r#"..."#`, are additionally delimited by `r` and `#`s.
+            let (raw_prefix_len, hashes) = match full_span.as_str().strip_prefix('r') {
+                Some(rest) => (1, rest.len() - rest.trim_start_matches('#').len()),
+                None => (0, 0),
+            };
             let inner_span = Span::new(
                 full_span.src().clone(),
-                full_span.start() + 1,
-                full_span.end() - 1,
+                full_span.start() + raw_prefix_len + hashes + 1,
+                full_span.end() - hashes - 1,
                 full_span.source_id().copied(),
             )
             .unwrap();
@@ -4052,6 +4578,158 @@ fn statement_let_to_ast_nodes_unfold(
             }
             ast_nodes
         }
+        Pattern::Slice(pat_slice) => {
+            let mut ast_nodes = Vec::new();
+
+            let pat_slice_span = pat_slice.span();
+            let elems = pat_slice.into_inner().into_iter().collect::<Vec<_>>();
+
+            // At most one rest pattern is allowed, and it can be anywhere in the pattern.
+            let is_rest = |elem: &Pattern| matches!(elem, Pattern::Rest { .. });
+            let rest_position = elems.iter().position(is_rest);
+            if let Some(second_rest_pattern) =
+                rest_position.and_then(|pos| elems.iter().skip(pos + 1).find(|elem| is_rest(elem)))
+            {
+                let error = ConvertParseTreeError::MultipleRestPatterns {
+                    span: second_rest_pattern.span(),
+                };
+                return Err(handler.emit_err(error.into()));
+            }
+
+            // Generate a deterministic name for the array.
+            let array_name = generate_destructured_array_var_name(
+                context.next_destructured_array_unique_suffix(),
+            );
+
+            let array_name = Ident::new_with_override(array_name, pat_slice_span.clone());
+
+            // Without a rest pattern, ascribe a second declaration to an array of placeholders
+            // to check that the array has as many elements as the pattern.
+            let placeholders_type_ascription = match rest_position {
+                Some(_) => None,
+                None => {
+                    let type_id = engines.te().insert_array_without_annotations(
+                        engines,
+                        engines.te().new_placeholder(TypeParameter::new_placeholder(
+                            engines.te().new_unknown(),
+                            pat_slice_span.clone(),
+                        )),
+                        elems.len(),
+                    );
+                    Some(TypeArgument {
+                        type_id,
+                        initial_type_id: type_id,
+                        span: pat_slice_span.clone(),
+                        call_path_tree: None,
+                    })
+                }
+            };
+
+            // Parse the type ascription and the type ascription span.
+            // In the event that the user did not provide a type ascription,
+            // it is set to the array of placeholders, if any, or to TypeInfo::Unknown.
+            let type_ascription = match (&ty_opt, &placeholders_type_ascription) {
+                (Some(ty), _) => ty_to_type_argument(context, handler, engines, ty.clone())?,
+                (None, Some(placeholders_type_ascription)) => placeholders_type_ascription.clone(),
+                (None, None) => {
+                    let type_id = engines.te().new_unknown();
+                    TypeArgument {
+                        type_id,
+                        initial_type_id: type_id,
+                        span: pat_slice_span.clone(),
+                        call_path_tree: None,
+                    }
+                }
+            };
+
+            // Save the array to the new name as a new variable declaration.
+            let save_body_first = engines.pe().insert(VariableDeclaration {
+                name: array_name.clone(),
+                type_ascription,
+                body: expression,
+                is_mutable: false,
+            });
+            ast_nodes.push(AstNode {
+                content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                    save_body_first,
+                )),
+                span: span.clone(),
+            });
+
+            // create a variable expression that points to the new array name that we just created
+            let new_expr = Expression {
+                kind: ExpressionKind::Variable(array_name.clone()),
+                span: span.clone(),
+            };
+
+            if let Some(placeholders_type_ascription) = placeholders_type_ascription {
+                // Override the previous declaration with an array of placeholders to check the
+                // length of the array
+                let check_array_len_second = engines.pe().insert(VariableDeclaration {
+                    name: array_name,
+                    type_ascription: placeholders_type_ascription,
+                    body: new_expr.clone(),
+                    is_mutable: false,
+                });
+                ast_nodes.push(AstNode {
+                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                        check_array_len_second,
+                    )),
+                    span: span.clone(),
+                });
+            }
+
+            // from the possible type annotation, if the annotation was an array annotation,
+            // extract the element type of the annotation
+            let elem_ty_opt = match ty_opt {
+                Some(Ty::Array(descriptor)) => Some(*descriptor.into_inner().ty),
+                _ => None,
+            };
+
+            // for all of the elements in the array destructuring on the LHS,
+            // recursively create variable declarations
+            let num_elems = elems.len() as u64;
+            for (position, pattern) in elems.into_iter().enumerate() {
+                let position = position as u64;
+                let (index, from_end) = match rest_position.map(|pos| pos as u64) {
+                    Some(rest_position) if position == rest_position => continue,
+                    // The elements following the rest pattern are counted from the end of the
+                    // array, which must have at least all the other elements of the pattern.
+                    Some(rest_position) if position > rest_position => {
+                        (num_elems - position, Some(num_elems - 1))
+                    }
+                    _ => (position, None),
+                };
+                let index_span = pattern.span();
+
+                // recursively create variable declarations for the subpatterns on the LHS
+                // and add them to the ast nodes
+                ast_nodes.extend(statement_let_to_ast_nodes_unfold(
+                    context,
+                    handler,
+                    engines,
+                    pattern,
+                    elem_ty_opt.clone(),
+                    Expression {
+                        kind: ExpressionKind::ArrayIndex(ArrayIndexExpression {
+                            prefix: Box::new(new_expr.clone()),
+                            index: Box::new(Expression {
+                                kind: ExpressionKind::Literal(Literal::U64(index)),
+                                span: index_span,
+                            }),
+                            from_end,
+                        }),
+                        span: span.clone(),
+                    },
+                    span.clone(),
+                )?);
+            }
+            ast_nodes
+        }
+        Pattern::Rest { .. } => {
+            let error = ConvertParseTreeError::RestPatternsNotSupportedHere { span };
+            return Err(handler.emit_err(error.into()));
+        }
     };
     Ok(ast_nodes)
 }
@@ -4267,6 +4945,14 @@ fn pattern_to_scrutinee(
             },
             span,
         },
+        Pattern::Slice(..) => {
+            let error = ConvertParseTreeError::SlicePatternsNotSupportedHere { span };
+            return Err(handler.emit_err(error.into()));
+        }
+        Pattern::Rest { .. } => {
+            let error = ConvertParseTreeError::RestPatternsNotSupportedHere { span };
+            return Err(handler.emit_err(error.into()));
+        }
         Pattern::Error(spans, err) => Scrutinee::Error { spans, err },
     };
     Ok(scrutinee)
@@ -4427,6 +5113,7 @@ fn assignable_to_expression(
                         engines,
                         *arg.into_inner(),
                     )?),
+                    from_end: None,
                 }),
                 span,
             },
@@ -4731,6 +5418,16 @@ fn item_attrs_to_map(
                 CFG_ATTRIBUTE_NAME => Some(AttributeKind::Cfg),
                 DEPRECATED_ATTRIBUTE_NAME => Some(AttributeKind::Deprecated),
                 FALLBACK_ATTRIBUTE_NAME => Some(AttributeKind::Fallback),
+                ERROR_TYPE_ATTRIBUTE_NAME => Some(AttributeKind::ErrorType),
+                EVENT_ATTRIBUTE_NAME => Some(AttributeKind::Event),
+                INDEXED_ATTRIBUTE_NAME => Some(AttributeKind::Indexed),
+                DERIVE_ATTRIBUTE_NAME => Some(AttributeKind::Derive),
+                MOCK_ATTRIBUTE_NAME => Some(AttributeKind::Mock),
+                SANITIZER_ATTRIBUTE_NAME => Some(AttributeKind::Sanitizer),
+                REENTRANCY_GUARD_ATTRIBUTE_NAME => Some(AttributeKind::ReentrancyGuard),
+                ITERATION_BOUND_ATTRIBUTE_NAME => Some(AttributeKind::IterationBound),
+                SELECTOR_ATTRIBUTE_NAME => Some(AttributeKind::Selector),
+                METHOD_NAME_ATTRIBUTE_NAME => Some(AttributeKind::MethodName),
                 _ => None,
             } {
                 match attrs_map.get_mut(&attr_kind) {
@@ -4787,6 +5484,19 @@ fn item_attrs_to_map(
     Ok(AttributesMap::new(Arc::new(attrs_map)))
 }
 
+/// Emits an error for every `#[error_type]` attribute in `attributes`.
+/// Only enums can be error types, so this is called for all other attributed items.
+fn reject_error_type_attribute(handler: &Handler, attributes: &AttributesMap) {
+    for attribute in attributes.get(&AttributeKind::ErrorType).into_iter().flatten() {
+        handler.emit_err(
+            ConvertParseTreeError::ErrorTypeAttributeOnNonEnum {
+                span: attribute.span.clone(),
+            }
+            .into(),
+        );
+    }
+}
+
 fn error_if_self_param_is_not_allowed(
     _context: &mut Context,
     handler: &Handler,
@@ -755,12 +755,16 @@ impl TypeEngine {
 
     /// Inserts a new [TypeInfo::TraitType] into the [TypeEngine] and returns
     /// its [TypeId], or returns a [TypeId] of an existing shareable trait type type
-    /// that corresponds to the trait type given by the `name` and `trait_type_id`.
+    /// that corresponds to the trait type given by the `name`, `trait_type_id`,
+    /// and `type_arguments`.
+    ///
+    /// The `type_arguments` are empty for non-generic associated types.
     pub(crate) fn insert_trait_type(
         &self,
         engines: &Engines,
         name: Ident,
         trait_type_id: TypeId,
+        type_arguments: Vec<TypeArgument>,
     ) -> TypeId {
         let source_id = self.get_trait_type_fallback_source_id(&name, &trait_type_id);
         // The trait type type shareability would be calculated as `!(false || false) ==>> true`.
@@ -770,6 +774,7 @@ impl TypeEngine {
         let type_info = TypeInfo::TraitType {
             name,
             trait_type_id,
+            type_arguments,
         };
         self.insert_or_replace_type_source_info(
             engines,
@@ -1421,7 +1426,9 @@ impl TypeEngine {
             TypeInfo::Custom { type_arguments, .. } =>
                 type_arguments.as_ref().is_some_and(|type_arguments|
                     self.module_might_outlive_type_arguments(engines, module_source_id, type_arguments)),
-            TypeInfo::TraitType { trait_type_id, .. } => self.module_might_outlive_type(engines, module_source_id, *trait_type_id)
+            TypeInfo::TraitType { trait_type_id, type_arguments, .. } =>
+                self.module_might_outlive_type(engines, module_source_id, *trait_type_id)
+                || self.module_might_outlive_type_arguments(engines, module_source_id, type_arguments),
         }
     }
 
@@ -1642,6 +1649,7 @@ impl TypeEngine {
             TypeInfo::TraitType {
                 name,
                 trait_type_id,
+                ..
             } => self.get_trait_type_fallback_source_id(name, trait_type_id),
         }
     }
@@ -1879,6 +1887,14 @@ impl TypeEngine {
         self.clear_items(|id| id != source_id);
     }
 
+    /// Returns an estimate of the memory held by the type engine, in bytes.
+    pub fn memory_usage(&self) -> usize {
+        self.slab.memory_usage()
+            + self.unifications.memory_usage()
+            + self.shareable_types.read().capacity()
+                * std::mem::size_of::<(Arc<TypeSourceInfo>, TypeId)>()
+    }
+
     /// Replaces the replaceable type behind the `type_id` with the `new_value`.
     /// The existing source id will be preserved.
     ///
@@ -162,6 +162,9 @@ pub enum TypeInfo {
     TraitType {
         name: Ident,
         trait_type_id: TypeId,
+        /// The type arguments of a generic associated type, e.g. `u64` in `Self::Output<u64>`.
+        /// Empty for non-generic associated types.
+        type_arguments: Vec<TypeArgument>,
     },
     Ref {
         to_mutable_value: bool,
@@ -240,9 +243,11 @@ impl HashWithEngines for TypeInfo {
             TypeInfo::TraitType {
                 name,
                 trait_type_id,
+                type_arguments,
             } => {
                 name.hash(state);
                 trait_type_id.hash(state);
+                type_arguments.hash(state, engines);
             }
             TypeInfo::Ref {
                 to_mutable_value,
@@ -369,10 +374,12 @@ impl PartialEqWithEngines for TypeInfo {
                 Self::TraitType {
                     name: l_name,
                     trait_type_id: l_trait_type_id,
+                    type_arguments: l_type_args,
                 },
                 Self::TraitType {
                     name: r_name,
                     trait_type_id: r_trait_type_id,
+                    type_arguments: r_type_args,
                 },
             ) => {
                 l_name == r_name
@@ -380,6 +387,7 @@ impl PartialEqWithEngines for TypeInfo {
                         || type_engine
                             .get(*l_trait_type_id)
                             .eq(&type_engine.get(*r_trait_type_id), ctx))
+                    && l_type_args.eq(r_type_args, ctx)
             }
             (
                 Self::Ref {
@@ -499,14 +507,17 @@ impl OrdWithEngines for TypeInfo {
                 Self::TraitType {
                     name: l_name,
                     trait_type_id: l_trait_type_id,
+                    type_arguments: l_type_args,
                 },
                 Self::TraitType {
                     name: r_name,
                     trait_type_id: r_trait_type_id,
+                    type_arguments: r_type_args,
                 },
             ) => l_trait_type_id
                 .cmp(r_trait_type_id)
-                .then_with(|| l_name.cmp(r_name)),
+                .then_with(|| l_name.cmp(r_name))
+                .then_with(|| l_type_args.cmp(r_type_args, ctx)),
             (
                 Self::Ref {
                     to_mutable_value: l_to_mut,
@@ -609,7 +620,16 @@ impl DisplayWithEngines for TypeInfo {
             TraitType {
                 name,
                 trait_type_id,
-            } => format!("trait type {}::{}", engines.help_out(trait_type_id), name),
+                type_arguments,
+            } => format!(
+                "trait type {}::{}",
+                engines.help_out(trait_type_id),
+                print_inner_types(
+                    engines,
+                    name.as_str(),
+                    type_arguments.iter().map(|arg| arg.type_id)
+                )
+            ),
             Ref {
                 to_mutable_value,
                 referenced_type: ty,
@@ -750,7 +770,16 @@ impl DebugWithEngines for TypeInfo {
             TraitType {
                 name,
                 trait_type_id,
-            } => format!("trait type {}::{}", engines.help_out(trait_type_id), name),
+                type_arguments,
+            } => format!(
+                "trait type {}::{}",
+                engines.help_out(trait_type_id),
+                print_inner_types_debug(
+                    engines,
+                    name.as_str(),
+                    type_arguments.iter().map(|arg| arg.type_id)
+                )
+            ),
             Ref {
                 to_mutable_value,
                 referenced_type: ty,
@@ -1728,10 +1757,7 @@ impl TypeInfo {
                 format!("__slice {}", ty.type_id.get_type_str(engines))
             }
             Alias { ty, .. } => ty.type_id.get_type_str(engines),
-            TraitType {
-                name,
-                trait_type_id: _,
-            } => format!("trait type {}", name),
+            TraitType { name, .. } => format!("trait type {}", name),
             Ref {
                 to_mutable_value,
                 referenced_type,
@@ -1883,6 +1909,18 @@ impl std::ops::Mul<usize> for AbiEncodeSizeHint {
     }
 }
 
+impl fmt::Display for AbiEncodeSizeHint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AbiEncodeSizeHint::CustomImpl => write!(f, "depends on a custom `AbiEncode` impl"),
+            AbiEncodeSizeHint::PotentiallyInfinite => write!(f, "unbounded"),
+            AbiEncodeSizeHint::Exact(size) => write!(f, "{size} bytes"),
+            AbiEncodeSizeHint::Range(min, max) if min == max => write!(f, "{min} bytes"),
+            AbiEncodeSizeHint::Range(min, max) => write!(f, "{min} to {max} bytes"),
+        }
+    }
+}
+
 fn print_inner_types(
     engines: &Engines,
     name: &str,
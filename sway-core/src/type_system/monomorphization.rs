@@ -307,10 +307,32 @@ pub(crate) fn type_decl_opt_to_type_id(
         }))) => {
             let decl_type = decl_engine.get_type(&decl_id);
 
+            // Generic associated types must always be given their type arguments.
+            let mut type_arguments = type_arguments.unwrap_or_default();
+            let type_mapping = prepare_type_subst_map_for_monomorphize(
+                handler,
+                engines,
+                namespace,
+                &*decl_type,
+                &mut type_arguments,
+                EnforceTypeArguments::Yes,
+                span,
+                mod_path,
+                self_type,
+                subst_ctx,
+            )?;
+
             if let Some(ty) = &decl_type.ty {
-                ty.type_id
+                let mut type_id = ty.type_id;
+                type_id.subst(&SubstTypesContext::new(engines, &type_mapping, false));
+                type_id
             } else if let Some(implementing_type) = self_type {
-                type_engine.insert_trait_type(engines, decl_type.name.clone(), implementing_type)
+                type_engine.insert_trait_type(
+                    engines,
+                    decl_type.name.clone(),
+                    implementing_type,
+                    type_arguments,
+                )
             } else {
                 return Err(handler.emit_err(CompileError::Internal(
                     "Self type not provided.",
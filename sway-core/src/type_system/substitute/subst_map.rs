@@ -8,6 +8,7 @@ use crate::{
     type_system::priv_prelude::*,
 };
 use std::{collections::BTreeMap, fmt};
+use sway_types::Ident;
 
 type SourceType = TypeId;
 type DestinationType = TypeId;
@@ -482,7 +483,39 @@ impl TypeSubstMap {
                 ty.type_id = type_id;
                 type_engine.insert_ptr(engines, ty)
             }),
-            TypeInfo::TraitType { .. } => iter_for_match(engines, self, &type_info),
+            TypeInfo::TraitType {
+                name,
+                trait_type_id,
+                mut type_arguments,
+            } => {
+                if let Some(type_id) = iter_for_match(engines, self, &type_info) {
+                    return Some(type_id);
+                }
+                if type_arguments.is_empty() {
+                    return None;
+                }
+
+                // A generic associated type, e.g. `Self::Output<T>`. Substitute its type
+                // arguments first, and then look for the definition of the associated type.
+                let mut need_to_create_new = false;
+                for type_arg in type_arguments.iter_mut() {
+                    if let Some(type_id) = self.find_match(type_arg.type_id, engines) {
+                        need_to_create_new = true;
+                        type_arg.type_id = type_id;
+                    }
+                }
+                self.find_generic_trait_type_match(engines, &name, trait_type_id, &type_arguments)
+                    .or_else(|| {
+                        need_to_create_new.then(|| {
+                            type_engine.insert_trait_type(
+                                engines,
+                                name,
+                                trait_type_id,
+                                type_arguments,
+                            )
+                        })
+                    })
+            }
             TypeInfo::Ref {
                 to_mutable_value,
                 referenced_type: mut ty,
@@ -507,6 +540,52 @@ impl TypeSubstMap {
     }
 }
 
+impl TypeSubstMap {
+    /// Find the definition of the generic associated type `name` of `trait_type_id` and
+    /// instantiate it with the given `type_arguments`.
+    ///
+    /// Definitions of generic associated types are mapped from a [TypeInfo::TraitType] whose
+    /// type arguments are the associated type's own type parameters, e.g.
+    /// `Self::Output<T> -> Vec<T>`, so they can't be found by structural equality.
+    fn find_generic_trait_type_match(
+        &self,
+        engines: &Engines,
+        name: &Ident,
+        trait_type_id: TypeId,
+        type_arguments: &[TypeArgument],
+    ) -> Option<TypeId> {
+        let type_engine = engines.te();
+        let eq_ctx = PartialEqWithEnginesContext::new(engines);
+        self.mapping.iter().find_map(|(source_type, dest_type)| {
+            let source_type_info = type_engine.get(*source_type);
+            let TypeInfo::TraitType {
+                name: source_name,
+                trait_type_id: source_trait_type_id,
+                type_arguments: type_parameters,
+            } = &*source_type_info
+            else {
+                return None;
+            };
+            let is_definition = source_name == name
+                && type_parameters.len() == type_arguments.len()
+                && (*source_trait_type_id == trait_type_id
+                    || type_engine
+                        .get(*source_trait_type_id)
+                        .eq(&type_engine.get(trait_type_id), &eq_ctx));
+            if !is_definition {
+                return None;
+            }
+            let instantiation = TypeSubstMap::from_type_parameters_and_type_arguments(
+                type_parameters.iter().map(|param| param.type_id).collect(),
+                type_arguments.iter().map(|arg| arg.type_id).collect(),
+            );
+            let mut type_id = *dest_type;
+            type_id.subst(&SubstTypesContext::new(engines, &instantiation, false));
+            Some(type_id)
+        })
+    }
+}
+
 fn iter_for_match(
     engines: &Engines,
     type_mapping: &TypeSubstMap,
@@ -0,0 +1,130 @@
+//! Stable codes of all the diagnostics, and their extended explanations, as shown by
+//! `forc explain`.
+//!
+//! Every diagnostic enum assigns a code to each of its variants with the `diagnostic_codes!`
+//! macro. The codes are listed right after each enum, and the match generated from them
+//! must cover all the variants, so a new variant can't be added without a code.
+
+use crate::{
+    convert_parse_tree_error::ConvertParseTreeError, diagnostic::Code, error::CompileError,
+    lex_error::LexErrorKind, parser_error::ParseErrorKind, type_error::TypeError, warning::Warning,
+};
+
+/// Implements `code` and `all_codes` for a diagnostic enum.
+///
+/// The variants listed in `delegate` wrap diagnostics of other kinds, and take the code of the
+/// wrapped diagnostic. All other variants get a code within the given diagnostic area. Once
+/// assigned, a code must never change or be reused, so that it stays meaningful across releases.
+macro_rules! diagnostic_codes {
+    (
+        $ty:ident in $area:ident,
+        delegate { $($delegate:pat => $delegate_code:expr,)* }
+        codes { $($variant:ident = $number:literal,)* }
+    ) => {
+        impl $ty {
+            /// Returns the stable code of the diagnostic, e.g. `E4001`.
+            pub fn code(&self) -> $crate::diagnostic::Code {
+                match self {
+                    $($delegate => $delegate_code,)*
+                    $(Self::$variant { .. } => $crate::diagnostic::Code::$area($number),)*
+                }
+            }
+
+            /// Returns the codes of all the diagnostics of this kind, along with the names of
+            /// their variants.
+            pub fn all_codes() -> Vec<($crate::diagnostic::Code, &'static str)> {
+                vec![$(($crate::diagnostic::Code::$area($number), stringify!($variant)),)*]
+            }
+        }
+    };
+}
+
+/// The extended explanations, written in Markdown.
+///
+/// Each explanation lives in `explanations/<CODE>.md` and starts with a `# <CODE>` heading.
+const EXPLANATIONS: &[(&str, &str)] = &[
+    ("E0005", include_str!("../explanations/E0005.md")),
+    ("E1058", include_str!("../explanations/E1058.md")),
+    ("E3001", include_str!("../explanations/E3001.md")),
+    ("E4003", include_str!("../explanations/E4003.md")),
+    ("E4128", include_str!("../explanations/E4128.md")),
+    ("W0009", include_str!("../explanations/W0009.md")),
+];
+
+/// A diagnostic code, along with its diagnostic and extended explanation.
+#[derive(Debug, Clone)]
+pub struct CodeInfo {
+    pub code: Code,
+    /// The name of the diagnostic, e.g. `UnknownVariable`.
+    pub name: &'static str,
+    pub explanation: Option<&'static str>,
+}
+
+/// Returns all the diagnostic codes, ordered by code.
+pub fn all_codes() -> Vec<CodeInfo> {
+    let mut codes = [
+        LexErrorKind::all_codes(),
+        ParseErrorKind::all_codes(),
+        ConvertParseTreeError::all_codes(),
+        TypeError::all_codes(),
+        CompileError::all_codes(),
+        Warning::all_codes(),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|(code, name)| CodeInfo {
+        explanation: explanation(code.as_str()),
+        code,
+        name,
+    })
+    .collect::<Vec<_>>();
+    codes.sort_by(|lhs, rhs| lhs.code.as_str().cmp(rhs.code.as_str()));
+    codes
+}
+
+/// Returns the diagnostic with the given `code`, e.g. `E4001` or `e4001`.
+pub fn lookup(code: &str) -> Option<CodeInfo> {
+    all_codes()
+        .into_iter()
+        .find(|info| info.code.as_str().eq_ignore_ascii_case(code))
+}
+
+fn explanation(code: &str) -> Option<&'static str> {
+    EXPLANATIONS
+        .iter()
+        .find(|(explained_code, _)| *explained_code == code)
+        .map(|(_, explanation)| *explanation)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn codes_are_unique() {
+        let mut seen = HashSet::new();
+        for info in all_codes() {
+            assert!(
+                seen.insert(info.code.as_str().to_string()),
+                "Code {} is assigned to more than one diagnostic.",
+                info.code.as_str()
+            );
+        }
+    }
+
+    #[test]
+    fn explanations_belong_to_existing_codes() {
+        let codes = all_codes();
+        for (code, explanation) in EXPLANATIONS {
+            assert!(
+                codes.iter().any(|info| info.code.as_str() == *code),
+                "The explanation of {code} does not belong to any diagnostic."
+            );
+            assert!(
+                explanation.starts_with(&format!("# {code}")),
+                "The explanation of {code} must start with a `# {code}` heading."
+            );
+        }
+    }
+}
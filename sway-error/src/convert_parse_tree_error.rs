@@ -61,6 +61,16 @@ pub enum ConvertParseTreeError {
     TuplePatternsNotSupportedHere { span: Span },
     #[error("ref patterns not supported in this position")]
     RefPatternsNotSupportedHere { span: Span },
+    #[error("slice patterns not supported in this position")]
+    SlicePatternsNotSupportedHere { span: Span },
+    #[error("rest patterns are only supported within slice patterns")]
+    RestPatternsNotSupportedHere { span: Span },
+    #[error("`..` can only be used once per slice pattern")]
+    MultipleRestPatterns { span: Span },
+    #[error("ranges are only supported for indexing arrays and slices, like in `&array[1..3]`")]
+    RangesNotSupportedHere { span: Span },
+    #[error("indexing with a range must be referenced, like in `&array[1..3]`")]
+    RangeIndexNotReferenced { span: Span },
     #[error("constructor patterns require a single argument")]
     ConstructorPatternOneArg { span: Span },
     #[error("constructor patterns cannot contain sub-patterns")]
@@ -123,6 +133,88 @@ pub enum ConvertParseTreeError {
     InvalidCfgArg { span: Span, value: String },
     #[error("Unknown type name \"self\". A self type with a similar name exists (notice the capitalization): `Self`")]
     UnknownTypeNameSelf { span: Span },
+    #[error("Either all or none of the arguments of a call must be named.")]
+    MixedNamedAndPositionalArguments { span: Span },
+    #[error("Parameter \"{name}\" must have a default value, because it follows a parameter with a default value.")]
+    MissingTrailingDefaultParameterValue { name: Ident, span: Span },
+    #[error("The \"error_type\" attribute can only be used on enums.")]
+    ErrorTypeAttributeOnNonEnum { span: Span },
+}
+
+diagnostic_codes! {
+    ConvertParseTreeError in parse_tree_conversion,
+    delegate {
+    }
+    codes {
+        ImportsWithoutItemsNotSupported = 1,
+        FunctionArbitraryExpression = 2,
+        GenericsNotSupportedHere = 3,
+        MultipleGenericsNotSupported = 4,
+        TupleIndexOutOfRange = 5,
+        ShlNotImplemented = 6,
+        ShrNotImplemented = 7,
+        BitXorNotImplemented = 8,
+        IntTySuffixNotSupported = 9,
+        IntLiteralOutOfRange = 10,
+        IntLiteralExpected = 11,
+        QualifiedPathRootsNotImplemented = 12,
+        CharLiteralsNotImplemented = 13,
+        HexLiteralLength = 14,
+        BinaryLiteralLength = 15,
+        U8LiteralOutOfRange = 16,
+        U16LiteralOutOfRange = 17,
+        U32LiteralOutOfRange = 18,
+        U64LiteralOutOfRange = 19,
+        SignedIntegersNotSupported = 20,
+        RefVariablesNotSupported = 21,
+        LiteralPatternsNotSupportedHere = 22,
+        ConstantPatternsNotSupportedHere = 23,
+        ConstructorPatternsNotSupportedHere = 24,
+        StructPatternsNotSupportedHere = 25,
+        WildcardPatternsNotSupportedHere = 26,
+        OrPatternsNotSupportedHere = 27,
+        TuplePatternsNotSupportedHere = 28,
+        RefPatternsNotSupportedHere = 29,
+        SlicePatternsNotSupportedHere = 30,
+        RestPatternsNotSupportedHere = 31,
+        MultipleRestPatterns = 32,
+        RangesNotSupportedHere = 33,
+        RangeIndexNotReferenced = 34,
+        ConstructorPatternOneArg = 35,
+        ConstructorPatternSubPatterns = 36,
+        PathsNotSupportedHere = 37,
+        FullySpecifiedTypesNotSupported = 38,
+        ContractCallerOneGenericArg = 39,
+        ContractCallerNamedTypeGenericArg = 40,
+        InvalidAttributeArgument = 41,
+        ConstrainedNonExistentType = 42,
+        GetStorageKeyTooManyArgs = 43,
+        RecursiveType = 44,
+        DuplicateEnumVariant = 45,
+        DuplicateStorageField = 46,
+        DuplicateConfigurable = 47,
+        MultipleConfigurableBlocksInModule = 48,
+        DuplicateStructField = 49,
+        DuplicateParameterIdentifier = 50,
+        SelfParameterNotAllowedForFn = 51,
+        TestFnOnlyAllowedAtModuleLevel = 52,
+        SelfImplForContract = 53,
+        CannotDocCommentDependency = 54,
+        CannotAnnotateDependency = 55,
+        ExpectedDependencyAtBeginning = 56,
+        ConstantRequiresExpression = 57,
+        ConstantRequiresTypeAscription = 58,
+        InvalidCfgTargetArgValue = 59,
+        ExpectedCfgTargetArgValue = 60,
+        InvalidCfgProgramTypeArgValue = 61,
+        ExpectedCfgProgramTypeArgValue = 62,
+        UnexpectedValueForCfgExperimental = 63,
+        InvalidCfgArg = 64,
+        UnknownTypeNameSelf = 65,
+        MixedNamedAndPositionalArguments = 66,
+        MissingTrailingDefaultParameterValue = 67,
+        ErrorTypeAttributeOnNonEnum = 68,
+    }
 }
 
 impl Spanned for ConvertParseTreeError {
@@ -157,6 +249,11 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::OrPatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::TuplePatternsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::RefPatternsNotSupportedHere { span } => span.clone(),
+            ConvertParseTreeError::SlicePatternsNotSupportedHere { span } => span.clone(),
+            ConvertParseTreeError::RestPatternsNotSupportedHere { span } => span.clone(),
+            ConvertParseTreeError::MultipleRestPatterns { span } => span.clone(),
+            ConvertParseTreeError::RangesNotSupportedHere { span } => span.clone(),
+            ConvertParseTreeError::RangeIndexNotReferenced { span } => span.clone(),
             ConvertParseTreeError::ConstructorPatternOneArg { span } => span.clone(),
             ConvertParseTreeError::ConstructorPatternSubPatterns { span } => span.clone(),
             ConvertParseTreeError::PathsNotSupportedHere { span } => span.clone(),
@@ -188,6 +285,11 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::UnexpectedValueForCfgExperimental { span } => span.clone(),
             ConvertParseTreeError::InvalidCfgArg { span, .. } => span.clone(),
             ConvertParseTreeError::UnknownTypeNameSelf { span } => span.clone(),
+            ConvertParseTreeError::MixedNamedAndPositionalArguments { span } => span.clone(),
+            ConvertParseTreeError::MissingTrailingDefaultParameterValue { span, .. } => {
+                span.clone()
+            }
+            ConvertParseTreeError::ErrorTypeAttributeOnNonEnum { span } => span.clone(),
         }
     }
 }
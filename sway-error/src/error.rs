@@ -1,5 +1,5 @@
 use crate::convert_parse_tree_error::ConvertParseTreeError;
-use crate::diagnostic::{Code, Diagnostic, Hint, Issue, Reason, ToDiagnostic};
+use crate::diagnostic::{Diagnostic, Hint, Issue, Reason, ToDiagnostic};
 use crate::formatting::*;
 use crate::lex_error::LexError;
 use crate::parser_error::{ParseError, ParseErrorKind};
@@ -265,6 +265,14 @@ pub enum CompileError {
         interface_name: InterfaceName,
         span: Span,
     },
+    #[error("Type \"{name}\" has {given} type parameter{}, but its declaration in {interface_name} has {expected}.", if *given == 1 { "" } else { "s" })]
+    AssociatedTypeTypeParametersCountMismatch {
+        name: Ident,
+        interface_name: InterfaceName,
+        given: usize,
+        expected: usize,
+        span: Span,
+    },
     #[error("Constants are missing from this trait implementation: {}",
         missing_constants.iter().map(|ident| ident.as_str().to_string())
         .collect::<Vec<_>>()
@@ -510,6 +518,14 @@ pub enum CompileError {
         trait_name: String,
         span: Span,
     },
+    #[error("Default implementation of \"{method_name}\" in trait \"{trait_name}\" requires \"{ty}\" to implement trait \"{constraint}\".")]
+    DefaultMethodTraitConstraintNotSatisfied {
+        method_name: Ident,
+        trait_name: Ident,
+        ty: String,
+        constraint: String,
+        span: Span,
+    },
     #[error(
         "Expects trait constraint \"{param}: {trait_name}\" which is missing from type parameter \"{param}\"."
     )]
@@ -667,6 +683,12 @@ pub enum CompileError {
     InvalidOpcodeFromPredicate { opcode: String, span: Span },
     #[error("Index out of bounds; the length is {count} but the index is {index}.")]
     ArrayOutOfBounds { index: u64, count: u64, span: Span },
+    #[error("This slice pattern needs an array of at least {min_len} elements, but the array has {count} elements.")]
+    SlicePatternArrayTooShort {
+        min_len: u64,
+        count: u64,
+        span: Span,
+    },
     #[error(
         "Invalid range; the range end at index {end} is smaller than its start at index {start}"
     )]
@@ -865,6 +887,54 @@ pub enum CompileError {
         available_fields: Vec<(Vec<Ident>, Ident)>,
         storage_decl_span: Span,
     },
+    #[error("Two storage fields have the same storage key.\nFirst field: {first_field_full_name}\nSecond field: {second_field_full_name}\nKey: {key}")]
+    DuplicatedStorageKey {
+        first_field: IdentUnique,
+        first_field_full_name: String,
+        first_field_key_is_compiler_generated: bool,
+        second_field: IdentUnique,
+        second_field_full_name: String,
+        second_field_key_is_compiler_generated: bool,
+        key: String,
+        // True if the experimental feature `storage_domains` is used.
+        experimental_storage_domains: bool,
+    },
+    #[error("Two error type variants have the same error selector.\nFirst variant: {first_variant}\nSecond variant: {second_variant}\nSelector: {selector}")]
+    DuplicatedErrorSelector {
+        first_variant: String,
+        first_span: Span,
+        second_variant: String,
+        second_span: Span,
+        selector: u64,
+    },
+    #[error("Field \"{field_name}\" is marked as indexed, but struct \"{struct_name}\" is not an event.")]
+    IndexedFieldOutsideOfEvent {
+        field_name: Ident,
+        struct_name: Ident,
+    },
+    #[error("Indexed field \"{field_name}\" must be declared before the non-indexed field \"{non_indexed_field_name}\".")]
+    IndexedFieldAfterNonIndexedField {
+        field_name: Ident,
+        non_indexed_field_name: Ident,
+    },
+    #[error("Indexed field \"{field_name}\" has type \"{ty}\", which cannot be indexed.")]
+    IndexedFieldTypeNotSupported {
+        field_name: Ident,
+        ty: String,
+        span: Span,
+    },
+    #[error("Cannot derive \"{trait_name}\" for \"{type_name}\".")]
+    CannotDeriveTrait {
+        trait_name: String,
+        type_name: Ident,
+        span: Span,
+    },
+    #[error("The program has {instantiations} monomorphized copies of generic functions, exceeding the budget of {budget}.")]
+    MonomorphizationBudgetExceeded {
+        budget: usize,
+        instantiations: usize,
+        span: Span,
+    },
     #[error("No storage has been declared")]
     NoDeclaredStorage { span: Span },
     #[error("Multiple storage declarations were found")]
@@ -909,6 +979,12 @@ pub enum CompileError {
         expected: u64,
         span: Span,
     },
+    #[error("Cannot cast \"{from}\" to \"{to}\". Casts with \"as\" are supported only between the integer types \"u8\", \"u16\", \"u32\" and \"u64\".")]
+    InvalidCast {
+        from: String,
+        to: String,
+        span: Span,
+    },
     #[error("Expected string literal")]
     ExpectedStringLiteral { span: Span },
     #[error("\"break\" used outside of a loop")]
@@ -967,7 +1043,7 @@ pub enum CompileError {
         // Make order deterministic
         as_traits.sort_by_key(|a| a.0.to_lowercase());
         for (index, as_trait) in as_traits.iter().enumerate() {
-            candidates = format!("{candidates}\n  Disambiguate the associated {item_kind} for candidate #{index}\n    <{} as {}>::{item_name}", as_trait.1, as_trait.0);
+            candidates = format!("{candidates}\n  Disambiguate the associated {item_kind} for candidate #{index}\n    <{} as {}>::{item_name}{}", as_trait.1, as_trait.0, as_trait.2);
         }
         candidates
     })]
@@ -975,7 +1051,9 @@ pub enum CompileError {
         span: Span,
         item_name: String,
         item_kind: String,
-        as_traits: Vec<(String, String)>,
+        /// The candidate traits, the types they are implemented for, and the `where` clauses
+        /// of their impls, e.g., ` where T: Eq`, or empty strings for impls without trait constraints.
+        as_traits: Vec<(String, String, String)>,
     },
     #[error("Provided generic type is not of type str.")]
     NonStrGenericType { span: Span },
@@ -990,6 +1068,13 @@ pub enum CompileError {
         superabi1: String,
         superabi2: String,
     },
+    #[error("ABI methods \"{first_method}\" and \"{second_method}\" have the same function selector 0x{selector}.")]
+    AbiSelectorCollision {
+        span: Span,
+        first_method: String,
+        second_method: String,
+        selector: String,
+    },
     #[error("Associated types not supported in ABI.")]
     AssociatedTypeNotSupportedInAbi { span: Span },
     #[error("Cannot call ABI supertrait's method as a contract method: \"{fn_name}\"")]
@@ -1035,6 +1120,297 @@ pub enum CompileError {
         trait_names: Vec<String>,
         trait_types_and_names: Vec<(String, String)>,
     },
+    #[error("{message} (reported by the \"{pass}\" analysis pass)")]
+    AnalysisPass {
+        pass: String,
+        message: String,
+        span: Span,
+    },
+    #[error("The `?` operator cannot be applied to a value of type \"{ty}\".")]
+    TryOperatorUnsupportedType { ty: String, span: Span },
+    #[error(
+        "The `?` operator can only be used in a function whose return type implements \"FromResidual<{residual_type}>\"."
+    )]
+    TryOperatorIncompatibleReturnType {
+        operand_type: String,
+        residual_type: String,
+        /// The return type of the enclosing function, or `None` if `?` is not used in a function.
+        return_type: Option<String>,
+        span: Span,
+    },
+    #[error("Label \"'{label}\" is not declared by any of the enclosing blocks or loops.")]
+    UnknownLabel { label: Ident, span: Span },
+    #[error("\"break\" with a value can only exit a labeled block, but \"'{label}\" is the label of a loop.")]
+    BreakWithValueOutOfLoop { label: Ident, span: Span },
+    #[error("\"continue\" can only be used with loops, but \"'{label}\" is the label of a block.")]
+    ContinueToLabeledBlock { label: Ident, span: Span },
+    #[error("Invalid \"#[{attribute}]\" attribute. Expected {expected}.")]
+    InvalidAbiMethodOverride {
+        attribute: String,
+        expected: String,
+        span: Span,
+    },
+    #[error("\"#[{attribute}]\" must be declared on the ABI method \"{method_name}\", not on its implementation.")]
+    AbiMethodOverrideOnImplMethod {
+        attribute: String,
+        method_name: Ident,
+        span: Span,
+    },
+    #[error("ABI methods \"{first_method}\" and \"{second_method}\" have the same method name \"{method_name}\".")]
+    AbiMethodNameCollision {
+        span: Span,
+        first_method: String,
+        second_method: String,
+        method_name: String,
+    },
+    #[error("Function \"{fn_name}\" has no parameter named \"{name}\".")]
+    UnknownNamedArgument {
+        fn_name: Ident,
+        name: Ident,
+        span: Span,
+    },
+    #[error("Argument \"{name}\" is passed more than once.")]
+    DuplicateNamedArgument { name: Ident, span: Span },
+    #[error("Named arguments can only be passed to functions and methods.")]
+    NamedArgumentNotAllowed { span: Span },
+    #[error("The default value of parameter \"{param_name}\" must be a literal, a constant, or a tuple or array of them.")]
+    NonConstantDefaultParameterValue { param_name: Ident, span: Span },
+}
+
+diagnostic_codes! {
+    CompileError in semantic_analysis,
+    delegate {
+        Self::TypeError(error) => error.code(),
+        Self::ConvertParseTree { error } => error.code(),
+        Self::Lex { error } => error.kind.code(),
+        Self::Parse { error } => error.kind.code(),
+    }
+    codes {
+        ModuleDepGraphEvaluationError = 1,
+        ModuleDepGraphCyclicReference = 2,
+        UnknownVariable = 3,
+        NotAVariable = 4,
+        Unimplemented = 5,
+        ParseError = 6,
+        Internal = 7,
+        InternalOwned = 8,
+        NoPredicateMainFunction = 9,
+        PredicateMainDoesNotReturnBool = 10,
+        NoScriptMainFunction = 11,
+        MultipleDefinitionsOfFallbackFunction = 12,
+        MultipleDefinitionsOfFunction = 13,
+        MultipleDefinitionsOfName = 14,
+        MultipleDefinitionsOfConstant = 15,
+        MultipleDefinitionsOfType = 16,
+        MultipleDefinitionsOfMatchArmVariable = 17,
+        AssignmentToNonMutableVariable = 18,
+        AssignmentToConstantOrConfigurable = 19,
+        DeclAssignmentTargetCannotBeAssignedTo = 20,
+        AssignmentViaNonMutableReference = 21,
+        MethodRequiresMutableSelf = 22,
+        MutableParameterNotSupported = 23,
+        ImmutableArgumentToMutableParameter = 24,
+        RefMutableNotAllowedInContractAbi = 25,
+        RefMutCannotReferenceConstant = 26,
+        RefMutCannotReferenceImmutableVariable = 27,
+        AssociatedFunctionCalledAsMethod = 28,
+        TypeParameterNotInTypeScope = 29,
+        MismatchedTypeInInterfaceSurface = 30,
+        UnknownTrait = 31,
+        FunctionNotAPartOfInterfaceSurface = 32,
+        ConstantNotAPartOfInterfaceSurface = 33,
+        TypeNotAPartOfInterfaceSurface = 34,
+        AssociatedTypeTypeParametersCountMismatch = 35,
+        MissingInterfaceSurfaceConstants = 36,
+        MissingInterfaceSurfaceTypes = 37,
+        MissingInterfaceSurfaceMethods = 38,
+        IncorrectNumberOfTypeArguments = 39,
+        DoesNotTakeTypeArguments = 40,
+        DoesNotTakeTypeArgumentsAsPrefix = 41,
+        TypeArgumentsNotAllowed = 42,
+        NeedsTypeArguments = 43,
+        EnumNotFound = 44,
+        StructInstantiationMissingFieldForErrorRecovery = 45,
+        StructInstantiationMissingFields = 46,
+        StructCannotBeInstantiated = 47,
+        StructFieldIsPrivate = 48,
+        StructFieldDoesNotExist = 49,
+        StructFieldDuplicated = 50,
+        MethodNotFound = 51,
+        ModuleNotFound = 52,
+        FieldAccessOnNonStruct = 53,
+        TupleElementAccessOnNonTuple = 54,
+        NotIndexable = 55,
+        NotAnEnum = 56,
+        NotAStruct = 57,
+        DeclIsNotAnEnum = 58,
+        DeclIsNotAStruct = 59,
+        DeclIsNotAFunction = 60,
+        DeclIsNotAVariable = 61,
+        DeclIsNotAnAbi = 62,
+        DeclIsNotATrait = 63,
+        DeclIsNotAnImplTrait = 64,
+        DeclIsNotATraitFn = 65,
+        DeclIsNotStorage = 66,
+        DeclIsNotAConstant = 67,
+        DeclIsNotATypeAlias = 68,
+        SymbolNotFound = 69,
+        SymbolWithMultipleBindings = 70,
+        ImportPrivateSymbol = 71,
+        ImportPrivateModule = 72,
+        NoElseBranch = 73,
+        NotAType = 74,
+        MissingEnumInstantiator = 75,
+        PathDoesNotReturn = 76,
+        ExpectedModuleDocComment = 77,
+        UnknownRegister = 78,
+        MissingImmediate = 79,
+        InvalidImmediateValue = 80,
+        UnknownEnumVariant = 81,
+        UnrecognizedOp = 82,
+        UnableToInferGeneric = 83,
+        UnconstrainedGenericParameter = 84,
+        TraitConstraintNotSatisfied = 85,
+        DefaultMethodTraitConstraintNotSatisfied = 86,
+        TraitConstraintMissing = 87,
+        Immediate06TooLarge = 88,
+        Immediate12TooLarge = 89,
+        Immediate18TooLarge = 90,
+        Immediate24TooLarge = 91,
+        IncorrectNumberOfAsmRegisters = 92,
+        UnnecessaryImmediate = 93,
+        AmbiguousPath = 94,
+        ModulePathIsNotAnExpression = 95,
+        UnknownType = 96,
+        UnknownTypeName = 97,
+        FileCouldNotBeRead = 98,
+        ImportMustBeLibrary = 99,
+        MoreThanOneEnumInstantiator = 100,
+        UnnecessaryEnumInstantiator = 101,
+        UnitVariantWithParenthesesEnumInstantiator = 102,
+        TraitNotFound = 103,
+        TraitNotImportedAtFunctionApplication = 104,
+        InvalidExpressionOnLhs = 105,
+        CannotBeEvaluatedToConst = 106,
+        CannotBeEvaluatedToConfigurableSizeUnknown = 107,
+        TooManyArgumentsForFunction = 108,
+        TooFewArgumentsForFunction = 109,
+        MissingParenthesesForFunction = 110,
+        InvalidAbiType = 111,
+        NotAnAbi = 112,
+        ImplAbiForNonContract = 113,
+        ConflictingImplsForTraitAndType = 114,
+        DuplicateDeclDefinedForType = 115,
+        IncorrectNumberOfInterfaceSurfaceFunctionParameters = 116,
+        ArgumentParameterTypeMismatch = 117,
+        RecursiveCall = 118,
+        RecursiveCallChain = 119,
+        RecursiveType = 120,
+        RecursiveTypeChain = 121,
+        GMFromExternalContext = 122,
+        MintFromExternalContext = 123,
+        BurnFromExternalContext = 124,
+        ContractStorageFromExternalContext = 125,
+        InvalidOpcodeFromPredicate = 126,
+        ArrayOutOfBounds = 127,
+        SlicePatternArrayTooShort = 128,
+        InvalidRangeEndGreaterThanStart = 129,
+        TupleIndexOutOfBounds = 130,
+        ConstantRequiresExpression = 131,
+        ConstantsCannotBeShadowed = 132,
+        ConfigurablesCannotBeShadowed = 133,
+        ConfigurablesCannotBeMatchedAgainst = 134,
+        ConstantShadowsVariable = 135,
+        ConstantDuplicatesConstantOrConfigurable = 136,
+        ShadowsOtherSymbol = 137,
+        GenericShadowsGeneric = 138,
+        MatchExpressionNonExhaustive = 139,
+        MatchStructPatternMissingFields = 140,
+        MatchStructPatternMustIgnorePrivateFields = 141,
+        MatchArmVariableNotDefinedInAllAlternatives = 142,
+        MatchArmVariableMismatchedType = 143,
+        MatchedValueIsNotValid = 144,
+        TraitDeclPureImplImpure = 145,
+        TraitImplPurityMismatch = 146,
+        ImpureInNonContract = 147,
+        StorageAccessMismatched = 148,
+        ParameterRefMutabilityMismatch = 149,
+        IntegerTooLarge = 150,
+        IntegerTooSmall = 151,
+        IntegerContainsInvalidDigit = 152,
+        AbiAsSupertrait = 153,
+        SupertraitImplRequired = 154,
+        ContractCallParamRepeated = 155,
+        UnrecognizedContractParam = 156,
+        CallParamForNonContractCallMethod = 157,
+        StorageFieldDoesNotExist = 158,
+        DuplicatedStorageKey = 159,
+        DuplicatedErrorSelector = 160,
+        IndexedFieldOutsideOfEvent = 161,
+        IndexedFieldAfterNonIndexedField = 162,
+        IndexedFieldTypeNotSupported = 163,
+        CannotDeriveTrait = 164,
+        MonomorphizationBudgetExceeded = 165,
+        NoDeclaredStorage = 166,
+        MultipleStorageDeclarations = 167,
+        InvalidStorageOnlyTypeDecl = 168,
+        UnexpectedDeclaration = 169,
+        ContractAddressMustBeKnown = 170,
+        NonConstantDeclValue = 171,
+        StorageDeclarationInNonContract = 172,
+        IntrinsicUnsupportedArgType = 173,
+        IntrinsicIncorrectNumArgs = 174,
+        IntrinsicIncorrectNumTArgs = 175,
+        InvalidCast = 176,
+        ExpectedStringLiteral = 177,
+        BreakOutsideLoop = 178,
+        ContinueOutsideLoop = 179,
+        ContractIdConstantNotAConstDecl = 180,
+        ContractIdValueNotALiteral = 181,
+        TypeNotAllowed = 182,
+        RefMutableNotAllowedInMain = 183,
+        InitializedRegisterReassignment = 184,
+        DisallowedControlFlowInstruction = 185,
+        CallingPrivateLibraryMethod = 186,
+        DisallowedIntrinsicInPredicate = 187,
+        CoinsPassedToNonPayableMethod = 188,
+        TraitImplPayabilityMismatch = 189,
+        ConfigurableInLibrary = 190,
+        MultipleApplicableItemsInScope = 191,
+        NonStrGenericType = 192,
+        ContractCallsItsOwnMethod = 193,
+        AbiShadowsSuperAbiMethod = 194,
+        ConflictingSuperAbiMethods = 195,
+        AbiSelectorCollision = 196,
+        AssociatedTypeNotSupportedInAbi = 197,
+        AbiSupertraitMethodCallAsContractCall = 198,
+        TypeIsNotValidAsImplementingFor = 199,
+        UninitRegisterInAsmBlockBeingRead = 200,
+        ExpressionCannotBeDereferenced = 201,
+        FallbackFnsAreContractOnly = 202,
+        FallbackFnsCannotHaveParameters = 203,
+        CouldNotGenerateEntry = 204,
+        CouldNotGenerateEntryMissingCore = 205,
+        CouldNotGenerateEntryMissingImpl = 206,
+        EncodingUnsupportedType = 207,
+        ConfigurableMissingAbiDecodeInPlace = 208,
+        ABIHashCollision = 209,
+        TypeMustBeKnownAtThisPoint = 210,
+        MultipleImplsSatisfyingTraitForType = 211,
+        AnalysisPass = 212,
+        TryOperatorUnsupportedType = 213,
+        TryOperatorIncompatibleReturnType = 214,
+        UnknownLabel = 215,
+        BreakWithValueOutOfLoop = 216,
+        ContinueToLabeledBlock = 217,
+        InvalidAbiMethodOverride = 218,
+        AbiMethodOverrideOnImplMethod = 219,
+        AbiMethodNameCollision = 220,
+        UnknownNamedArgument = 221,
+        DuplicateNamedArgument = 222,
+        NamedArgumentNotAllowed = 223,
+        NonConstantDefaultParameterValue = 224,
+    }
 }
 
 impl std::convert::From<TypeError> for CompileError {
@@ -1082,6 +1458,7 @@ impl Spanned for CompileError {
             FunctionNotAPartOfInterfaceSurface { span, .. } => span.clone(),
             ConstantNotAPartOfInterfaceSurface { span, .. } => span.clone(),
             TypeNotAPartOfInterfaceSurface { span, .. } => span.clone(),
+            AssociatedTypeTypeParametersCountMismatch { span, .. } => span.clone(),
             MissingInterfaceSurfaceConstants { span, .. } => span.clone(),
             MissingInterfaceSurfaceTypes { span, .. } => span.clone(),
             MissingInterfaceSurfaceMethods { span, .. } => span.clone(),
@@ -1120,6 +1497,7 @@ impl Spanned for CompileError {
             UnconstrainedGenericParameter { span, .. } => span.clone(),
             TraitConstraintNotSatisfied { span, .. } => span.clone(),
             TraitConstraintMissing { span, .. } => span.clone(),
+            DefaultMethodTraitConstraintNotSatisfied { span, .. } => span.clone(),
             Immediate06TooLarge { span, .. } => span.clone(),
             Immediate12TooLarge { span, .. } => span.clone(),
             Immediate18TooLarge { span, .. } => span.clone(),
@@ -1163,6 +1541,7 @@ impl Spanned for CompileError {
             ContractStorageFromExternalContext { span, .. } => span.clone(),
             InvalidOpcodeFromPredicate { span, .. } => span.clone(),
             ArrayOutOfBounds { span, .. } => span.clone(),
+            SlicePatternArrayTooShort { span, .. } => span.clone(),
             ConstantRequiresExpression { span, .. } => span.clone(),
             ConstantsCannotBeShadowed { name, .. } => name.span(),
             ConfigurablesCannotBeShadowed { name, .. } => name.span(),
@@ -1204,6 +1583,13 @@ impl Spanned for CompileError {
             CallParamForNonContractCallMethod { span, .. } => span.clone(),
             StorageFieldDoesNotExist { field_name, .. } => field_name.span(),
             InvalidStorageOnlyTypeDecl { span, .. } => span.clone(),
+            DuplicatedStorageKey { first_field, .. } => first_field.span(),
+            DuplicatedErrorSelector { second_span, .. } => second_span.clone(),
+            IndexedFieldOutsideOfEvent { field_name, .. } => field_name.span(),
+            IndexedFieldAfterNonIndexedField { field_name, .. } => field_name.span(),
+            IndexedFieldTypeNotSupported { span, .. } => span.clone(),
+            CannotDeriveTrait { span, .. } => span.clone(),
+            MonomorphizationBudgetExceeded { span, .. } => span.clone(),
             NoDeclaredStorage { span, .. } => span.clone(),
             MultipleStorageDeclarations { span, .. } => span.clone(),
             UnexpectedDeclaration { span, .. } => span.clone(),
@@ -1218,6 +1604,7 @@ impl Spanned for CompileError {
             IntrinsicUnsupportedArgType { span, .. } => span.clone(),
             IntrinsicIncorrectNumArgs { span, .. } => span.clone(),
             IntrinsicIncorrectNumTArgs { span, .. } => span.clone(),
+            InvalidCast { span, .. } => span.clone(),
             BreakOutsideLoop { span } => span.clone(),
             ContinueOutsideLoop { span } => span.clone(),
             ContractIdConstantNotAConstDecl { span } => span.clone(),
@@ -1236,6 +1623,7 @@ impl Spanned for CompileError {
             ContractCallsItsOwnMethod { span } => span.clone(),
             AbiShadowsSuperAbiMethod { span, .. } => span.clone(),
             ConflictingSuperAbiMethods { span, .. } => span.clone(),
+            AbiSelectorCollision { span, .. } => span.clone(),
             AssociatedTypeNotSupportedInAbi { span, .. } => span.clone(),
             AbiSupertraitMethodCallAsContractCall { span, .. } => span.clone(),
             TypeNotAllowed { span, .. } => span.clone(),
@@ -1255,6 +1643,19 @@ impl Spanned for CompileError {
             InvalidRangeEndGreaterThanStart { span, .. } => span.clone(),
             TypeMustBeKnownAtThisPoint { span, .. } => span.clone(),
             MultipleImplsSatisfyingTraitForType { span, .. } => span.clone(),
+            AnalysisPass { span, .. } => span.clone(),
+            TryOperatorUnsupportedType { span, .. } => span.clone(),
+            TryOperatorIncompatibleReturnType { span, .. } => span.clone(),
+            UnknownLabel { span, .. } => span.clone(),
+            BreakWithValueOutOfLoop { span, .. } => span.clone(),
+            ContinueToLabeledBlock { span, .. } => span.clone(),
+            InvalidAbiMethodOverride { span, .. } => span.clone(),
+            AbiMethodOverrideOnImplMethod { span, .. } => span.clone(),
+            AbiMethodNameCollision { span, .. } => span.clone(),
+            UnknownNamedArgument { span, .. } => span.clone(),
+            DuplicateNamedArgument { span, .. } => span.clone(),
+            NamedArgumentNotAllowed { span } => span.clone(),
+            NonConstantDefaultParameterValue { span, .. } => span.clone(),
         }
     }
 }
@@ -1272,11 +1673,10 @@ impl Spanned for CompileError {
 //       And our #[error] macro will anyhow encapsulate it and ensure consistency.
 impl ToDiagnostic for CompileError {
     fn to_diagnostic(&self, source_engine: &SourceEngine) -> Diagnostic {
-        let code = Code::semantic_analysis;
         use CompileError::*;
         match self {
             ConstantsCannotBeShadowed { shadowing_source, name, constant_span, constant_decl_span, is_alias } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Constants cannot be shadowed".to_string())),
+                reason: Some(Reason::new(self.code(), "Constants cannot be shadowed".to_string())),
                 issue: Issue::error(
                     source_engine,
                     name.span(),
@@ -1341,7 +1741,7 @@ impl ToDiagnostic for CompileError {
                 ],
             },
             ConfigurablesCannotBeShadowed { shadowing_source, name, configurable_span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Configurables cannot be shadowed".to_string())),
+                reason: Some(Reason::new(self.code(), "Configurables cannot be shadowed".to_string())),
                 issue: Issue::error(
                     source_engine,
                     name.span(),
@@ -1377,7 +1777,7 @@ impl ToDiagnostic for CompileError {
                 ],
             },
             ConfigurablesCannotBeMatchedAgainst { name, configurable_span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Configurables cannot be matched against".to_string())),
+                reason: Some(Reason::new(self.code(), "Configurables cannot be matched against".to_string())),
                 issue: Issue::error(
                     source_engine,
                     name.span(),
@@ -1424,7 +1824,7 @@ impl ToDiagnostic for CompileError {
                 ],
             },
             ConstantShadowsVariable { name , variable_span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Constants cannot shadow variables".to_string())),
+                reason: Some(Reason::new(self.code(), "Constants cannot shadow variables".to_string())),
                 issue: Issue::error(
                     source_engine,
                     name.span(),
@@ -1443,7 +1843,7 @@ impl ToDiagnostic for CompileError {
                 ],
             },
             ConstantDuplicatesConstantOrConfigurable { existing_constant_or_configurable, new_constant_or_configurable, name, existing_span } => Diagnostic {
-                reason: Some(Reason::new(code(1), match (*existing_constant_or_configurable, *new_constant_or_configurable) {
+                reason: Some(Reason::new(self.code(), match (*existing_constant_or_configurable, *new_constant_or_configurable) {
                     ("Constant", "Constant") => "Constant of the same name already exists".to_string(),
                     ("Constant", "Configurable") => "Constant of the same name as configurable already exists".to_string(),
                     ("Configurable", "Constant") => "Configurable of the same name as constant already exists".to_string(),
@@ -1478,7 +1878,7 @@ impl ToDiagnostic for CompileError {
                 ],
             },
             MultipleDefinitionsOfMatchArmVariable { match_value, match_type, first_definition, first_definition_is_struct_field, duplicate, duplicate_is_struct_field } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Match pattern variable is already defined".to_string())),
+                reason: Some(Reason::new(self.code(), "Match pattern variable is already defined".to_string())),
                 issue: Issue::error(
                     source_engine,
                     duplicate.clone(),
@@ -1535,7 +1935,7 @@ impl ToDiagnostic for CompileError {
                 ],
             },
             MatchArmVariableMismatchedType { match_value, match_type, variable, first_definition, expected, received } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Match pattern variable has mismatched type".to_string())),
+                reason: Some(Reason::new(self.code(), "Match pattern variable has mismatched type".to_string())),
                 issue: Issue::error(
                     source_engine,
                     variable.span(),
@@ -1558,7 +1958,7 @@ impl ToDiagnostic for CompileError {
                 ],
             },
             MatchArmVariableNotDefinedInAllAlternatives { match_value, match_type, variable, missing_in_alternatives} => Diagnostic {
-                reason: Some(Reason::new(code(1), "Match pattern variable is not defined in all alternatives".to_string())),
+                reason: Some(Reason::new(self.code(), "Match pattern variable is not defined in all alternatives".to_string())),
                 issue: Issue::error(
                     source_engine,
                     variable.span(),
@@ -1590,7 +1990,7 @@ impl ToDiagnostic for CompileError {
                 ],
             },
             MatchStructPatternMissingFields { missing_fields, missing_fields_are_public, struct_name, struct_decl_span, total_number_of_fields, span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Struct pattern has missing fields".to_string())),
+                reason: Some(Reason::new(self.code(), "Struct pattern has missing fields".to_string())),
                 issue: Issue::error(
                     source_engine,
                     span.clone(),
@@ -1630,7 +2030,7 @@ impl ToDiagnostic for CompileError {
                 ],
             },
             MatchStructPatternMustIgnorePrivateFields { private_fields, struct_name, struct_decl_span, all_fields_are_private, span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Struct pattern must ignore inaccessible private fields".to_string())),
+                reason: Some(Reason::new(self.code(), "Struct pattern must ignore inaccessible private fields".to_string())),
                 issue: Issue::error(
                     source_engine,
                     span.clone(),
@@ -1671,7 +2071,7 @@ impl ToDiagnostic for CompileError {
                 let trait_candidates = &trait_candidates; // Remove mutability.
 
                 Diagnostic {
-                    reason: Some(Reason::new(code(1), "Trait is not imported".to_string())),
+                    reason: Some(Reason::new(self.code(), "Trait is not imported".to_string())),
                     issue: Issue::error(
                         source_engine,
                         function_call_site_span.clone(),
@@ -1758,9 +2158,46 @@ impl ToDiagnostic for CompileError {
                     },
                 }
             },
+            TryOperatorUnsupportedType { ty, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "The `?` operator cannot be applied to this expression".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("This expression is of type \"{ty}\", whose failure cannot be propagated.")
+                ),
+                hints: vec![
+                    Hint::help(
+                        source_engine,
+                        span.clone(),
+                        "The `?` operator can only be applied to values of types implementing the \"std::ops::Try\" trait, like \"Option\" and \"Result\".".to_string()
+                    ),
+                ],
+                help: vec![],
+            },
+            TryOperatorIncompatibleReturnType { operand_type, residual_type, return_type, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "The `?` operator is used in an incompatible function".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    match return_type {
+                        Some(return_type) => format!("This propagates the failure of \"{operand_type}\", but the function returns \"{return_type}\"."),
+                        None => format!("This propagates the failure of \"{operand_type}\", but it is not used within a function."),
+                    }
+                ),
+                hints: vec![
+                    Hint::help(
+                        source_engine,
+                        span.clone(),
+                        format!("The `?` operator returns the failure from the enclosing function, so the return type of the function must implement \"FromResidual<{residual_type}>\"."),
+                    ),
+                ],
+                help: vec![
+                    "Consider handling the failure with a `match` expression instead.".to_string(),
+                ],
+            },
             // TODO-IG: Extend error messages to pointers, once typed pointers are defined and can be dereferenced.
             ExpressionCannotBeDereferenced { expression_type, span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Expression cannot be dereferenced".to_string())),
+                reason: Some(Reason::new(self.code(), "Expression cannot be dereferenced".to_string())),
                 issue: Issue::error(
                     source_engine,
                     span.clone(),
@@ -1781,7 +2218,7 @@ impl ToDiagnostic for CompileError {
                 help: vec![],
             },
             StructInstantiationMissingFields { field_names, struct_name, span, struct_decl_span, total_number_of_fields } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Struct instantiation has missing fields".to_string())),
+                reason: Some(Reason::new(self.code(), "Struct instantiation has missing fields".to_string())),
                 issue: Issue::error(
                     source_engine,
                     span.clone(),
@@ -1808,7 +2245,7 @@ impl ToDiagnostic for CompileError {
                 help: vec![],
             },
             StructCannotBeInstantiated { struct_name, span, struct_decl_span, private_fields, constructors, all_fields_are_private, is_in_storage_declaration, struct_can_be_changed } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Struct cannot be instantiated due to inaccessible private fields".to_string())),
+                reason: Some(Reason::new(self.code(), "Struct cannot be instantiated due to inaccessible private fields".to_string())),
                 issue: Issue::error(
                     source_engine,
                     span.clone(),
@@ -1960,7 +2397,7 @@ impl ToDiagnostic for CompileError {
                 }
             },
             StructFieldIsPrivate { field_name, struct_name, field_decl_span, struct_can_be_changed, usage_context } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Private struct field is inaccessible".to_string())),
+                reason: Some(Reason::new(self.code(), "Private struct field is inaccessible".to_string())),
                 issue: Issue::error(
                     source_engine,
                     field_name.span(),
@@ -2029,7 +2466,7 @@ impl ToDiagnostic for CompileError {
                 ],
             },
             StructFieldDoesNotExist { field_name, available_fields, is_public_struct_access, struct_name, struct_decl_span, struct_is_empty, usage_context } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Struct field does not exist".to_string())),
+                reason: Some(Reason::new(self.code(), "Struct field does not exist".to_string())),
                 issue: Issue::error(
                     source_engine,
                     field_name.span(),
@@ -2089,7 +2526,7 @@ impl ToDiagnostic for CompileError {
                 help: vec![],
             },
             StructFieldDuplicated { field_name, duplicate } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Struct field has multiple definitions".to_string())),
+                reason: Some(Reason::new(self.code(), "Struct field has multiple definitions".to_string())),
                 issue: Issue::error(
                     source_engine,
                     field_name.span(),
@@ -2107,7 +2544,7 @@ impl ToDiagnostic for CompileError {
                 help: vec![],
             },
             NotIndexable { actually, span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Type is not indexable".to_string())),
+                reason: Some(Reason::new(self.code(), "Type is not indexable".to_string())),
                 issue: Issue::error(
                     source_engine,
                     span.clone(),
@@ -2122,7 +2559,7 @@ impl ToDiagnostic for CompileError {
                 ],
             },
             FieldAccessOnNonStruct { actually, storage_variable, field_name, span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Field access requires a struct".to_string())),
+                reason: Some(Reason::new(self.code(), "Field access requires a struct".to_string())),
                 issue: Issue::error(
                     source_engine,
                     span.clone(),
@@ -2159,7 +2596,7 @@ impl ToDiagnostic for CompileError {
                 }
             },
 	    SymbolWithMultipleBindings { name, paths, span } => Diagnostic {
-		reason: Some(Reason::new(code(1), "Multiple bindings for symbol in this scope".to_string())),
+		reason: Some(Reason::new(self.code(), "Multiple bindings for symbol in this scope".to_string())),
 		issue: Issue::error(
 		    source_engine,
 		    span.clone(),
@@ -2168,8 +2605,154 @@ impl ToDiagnostic for CompileError {
 		hints: paths.iter().map(|path| Hint::info(source_engine, Span::dummy(), format!("{path}::{}", name.as_str()))).collect(),
 		help: vec![format!("Consider using a fully qualified name, e.g., {}::{}", paths[0], name.as_str())],
 	    },
+            DuplicatedStorageKey { first_field, first_field_full_name, first_field_key_is_compiler_generated, second_field, second_field_full_name, second_field_key_is_compiler_generated, key, experimental_storage_domains } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Two storage fields have the same storage key".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    first_field.span(),
+                    format!("\"{first_field_full_name}\" has the same storage key as \"{second_field_full_name}\"."),
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        second_field.span(),
+                        format!("\"{second_field_full_name}\" is declared here."),
+                    ),
+                ],
+                help: vec![
+                    if *first_field_key_is_compiler_generated || *second_field_key_is_compiler_generated {
+                        format!("The key of \"{}\" is generated by the compiler using the following formula:",
+                            if *first_field_key_is_compiler_generated {
+                                first_field_full_name
+                            } else {
+                                second_field_full_name
+                            }
+                        )
+                    } else {
+                        "Both keys are explicitly defined by using the `in` keyword.".to_string()
+                    },
+                    if *first_field_key_is_compiler_generated || *second_field_key_is_compiler_generated {
+                        if *experimental_storage_domains {
+                            format!("{}sha256((0u8, \"{}\"))",
+                                Indent::Single,
+                                if *first_field_key_is_compiler_generated {
+                                    first_field_full_name
+                                } else {
+                                    second_field_full_name
+                                }
+                            )
+                        } else {
+                            format!("{}sha256(\"{}\")",
+                                Indent::Single,
+                                if *first_field_key_is_compiler_generated {
+                                    first_field_full_name
+                                } else {
+                                    second_field_full_name
+                                }
+                            )
+                        }
+                    } else {
+                        Diagnostic::help_none()
+                    },
+                    format!("The common key is: {key}.")
+                ],
+            },
+            DuplicatedErrorSelector { first_variant, first_span, second_variant, second_span, selector } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Two error type variants have the same error selector".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    second_span.clone(),
+                    format!("\"{second_variant}\" has the same error selector as \"{first_variant}\"."),
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        first_span.clone(),
+                        format!("\"{first_variant}\" is declared here."),
+                    ),
+                ],
+                help: vec![
+                    "The selector of an error type variant is generated by the compiler using the following formula:".to_string(),
+                    format!("{}sha256(\"<enum path>::<variant name>\")[0..8]", Indent::Single),
+                    Diagnostic::help_empty_line(),
+                    format!("The common selector is: {selector}."),
+                    "Consider renaming one of the error types or variants.".to_string(),
+                ],
+            },
+            IndexedFieldOutsideOfEvent { field_name, struct_name } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Indexed field is not in an event".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    field_name.span(),
+                    format!("Field \"{field_name}\" is marked as `#[indexed]`, but struct \"{struct_name}\" is not an event."),
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        struct_name.span(),
+                        format!("Struct \"{struct_name}\" is declared here."),
+                    ),
+                ],
+                help: vec![
+                    "Only fields of structs annotated with `#[event]` can be indexed.".to_string(),
+                    format!("Consider annotating \"{struct_name}\" with `#[event]`, or removing the `#[indexed]` attribute."),
+                ],
+            },
+            IndexedFieldAfterNonIndexedField { field_name, non_indexed_field_name } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Indexed field is declared after a non-indexed field".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    field_name.span(),
+                    format!("Indexed field \"{field_name}\" is declared after the non-indexed field \"{non_indexed_field_name}\"."),
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        non_indexed_field_name.span(),
+                        format!("Non-indexed field \"{non_indexed_field_name}\" is declared here."),
+                    ),
+                ],
+                help: vec![
+                    "Indexed fields must be declared before all other fields of an event,".to_string(),
+                    "so that their position in the logged data does not depend on the values of the other fields.".to_string(),
+                    Diagnostic::help_empty_line(),
+                    format!("Consider moving \"{field_name}\" before \"{non_indexed_field_name}\"."),
+                ],
+            },
+            IndexedFieldTypeNotSupported { field_name, ty, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Indexed field type is not supported".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("Indexed field \"{field_name}\" has type \"{ty}\", which cannot be indexed."),
+                ),
+                hints: vec![],
+                help: vec![
+                    "Indexed fields must have a type with a fixed size encoding:".to_string(),
+                    format!("{}`bool`, `u8`, `u16`, `u32`, `u64`, `u256`, or `b256`.", Indent::Single),
+                ],
+            },
+            CannotDeriveTrait { trait_name, type_name, span } => Diagnostic {
+                reason: Some(Reason::new(self.code(), "Trait cannot be derived".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("\"{trait_name}\" cannot be derived for \"{type_name}\"."),
+                ),
+                hints: vec![
+                    Hint::info(
+                        source_engine,
+                        type_name.span(),
+                        format!("\"{type_name}\" is declared here."),
+                    ),
+                ],
+                help: vec![
+                    format!("To derive \"{trait_name}\", all fields and variants of \"{type_name}\" must implement it,"),
+                    format!("and \"{trait_name}\" must be in scope, e.g. imported with a `use` statement."),
+                ],
+            },
             StorageFieldDoesNotExist { field_name, available_fields, storage_decl_span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Storage field does not exist".to_string())),
+                reason: Some(Reason::new(self.code(), "Storage field does not exist".to_string())),
                 issue: Issue::error(
                     source_engine,
                     field_name.span(),
@@ -2215,7 +2798,7 @@ impl ToDiagnostic for CompileError {
                 help: vec![],
             },
             TupleIndexOutOfBounds { index, count, tuple_type, span, prefix_span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Tuple index is out of bounds".to_string())),
+                reason: Some(Reason::new(self.code(), "Tuple index is out of bounds".to_string())),
                 issue: Issue::error(
                     source_engine,
                     span.clone(),
@@ -2231,7 +2814,7 @@ impl ToDiagnostic for CompileError {
                 help: vec![],
             },
             TupleElementAccessOnNonTuple { actually, span, index, index_span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Tuple element access requires a tuple".to_string())),
+                reason: Some(Reason::new(self.code(), "Tuple element access requires a tuple".to_string())),
                 issue: Issue::error(
                     source_engine,
                     span.clone(),
@@ -2251,7 +2834,7 @@ impl ToDiagnostic for CompileError {
                 ],
             },
             RefMutCannotReferenceConstant { constant, span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "References to mutable values cannot reference constants".to_string())),
+                reason: Some(Reason::new(self.code(), "References to mutable values cannot reference constants".to_string())),
                 issue: Issue::error(
                     source_engine,
                     span.clone(),
@@ -2265,7 +2848,7 @@ impl ToDiagnostic for CompileError {
                 ],
             },
             RefMutCannotReferenceImmutableVariable { decl_name, span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "References to mutable values cannot reference immutable variables".to_string())),
+                reason: Some(Reason::new(self.code(), "References to mutable values cannot reference immutable variables".to_string())),
                 issue: Issue::error(
                     source_engine,
                     span.clone(),
@@ -2287,7 +2870,7 @@ impl ToDiagnostic for CompileError {
                 ],
             },
             ConflictingImplsForTraitAndType { trait_name, type_implementing_for, existing_impl_span, second_impl_span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Trait is already implemented for type".to_string())),
+                reason: Some(Reason::new(self.code(), "Trait is already implemented for type".to_string())),
                 issue: Issue::error(
                     source_engine,
                     second_impl_span.clone(),
@@ -2308,7 +2891,7 @@ impl ToDiagnostic for CompileError {
                 ],
             },
             AssignmentToNonMutableVariable { lhs_span, decl_name } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Immutable variables cannot be assigned to".to_string())),
+                reason: Some(Reason::new(self.code(), "Immutable variables cannot be assigned to".to_string())),
                 issue: Issue::error(
                     source_engine,
                     lhs_span.clone(),
@@ -2341,7 +2924,7 @@ impl ToDiagnostic for CompileError {
                 ],
             },
             AssignmentToConstantOrConfigurable { lhs_span, is_configurable, decl_name } => Diagnostic {
-                reason: Some(Reason::new(code(1), format!("{} cannot be assigned to",
+                reason: Some(Reason::new(self.code(), format!("{} cannot be assigned to",
                     if *is_configurable {
                         "Configurables"
                     } else {
@@ -2388,7 +2971,7 @@ impl ToDiagnostic for CompileError {
                 help: vec![],
             },
             DeclAssignmentTargetCannotBeAssignedTo { decl_name, decl_friendly_type_name, lhs_span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Assignment target cannot be assigned to".to_string())),
+                reason: Some(Reason::new(self.code(), "Assignment target cannot be assigned to".to_string())),
                 issue: Issue::error(
                     source_engine,
                     lhs_span.clone(),
@@ -2423,7 +3006,7 @@ impl ToDiagnostic for CompileError {
                 help: vec![],
             },
             AssignmentViaNonMutableReference { decl_reference_name, decl_reference_rhs, decl_reference_type, span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Reference is not a reference to a mutable value (`&mut`)".to_string())),
+                reason: Some(Reason::new(self.code(), "Reference is not a reference to a mutable value (`&mut`)".to_string())),
                 issue: Issue::error(
                     source_engine,
                     span.clone(),
@@ -2489,7 +3072,7 @@ impl ToDiagnostic for CompileError {
                 ],
             },
             Unimplemented { feature, help, span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Used feature is currently not implemented".to_string())),
+                reason: Some(Reason::new(self.code(), "Used feature is currently not implemented".to_string())),
                 issue: Issue::error(
                     source_engine,
                     span.clone(),
@@ -2499,7 +3082,7 @@ impl ToDiagnostic for CompileError {
                 help: help.clone(),
             },
             MatchedValueIsNotValid { supported_types_message, span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Matched value is not valid".to_string())),
+                reason: Some(Reason::new(self.code(), "Matched value is not valid".to_string())),
                 issue: Issue::error(
                     source_engine,
                     span.clone(),
@@ -2519,7 +3102,7 @@ impl ToDiagnostic for CompileError {
                 }
             },
             TypeIsNotValidAsImplementingFor { invalid_type, trait_name, span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Self type of an impl block is not valid".to_string())),
+                reason: Some(Reason::new(self.code(), "Self type of an impl block is not valid".to_string())),
                 issue: Issue::error(
                     source_engine,
                     span.clone(),
@@ -2583,7 +3166,7 @@ impl ToDiagnostic for CompileError {
                 }
             },
             ModulePathIsNotAnExpression { module_path, span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Module path is not an expression".to_string())),
+                reason: Some(Reason::new(self.code(), "Module path is not an expression".to_string())),
                 issue: Issue::error(
                     source_engine,
                     span.clone(),
@@ -2604,7 +3187,7 @@ impl ToDiagnostic for CompileError {
             Parse { error } => {
                 match &error.kind {
                     ParseErrorKind::UnassignableExpression { erroneous_expression_kind, erroneous_expression_span } => Diagnostic {
-                        reason: Some(Reason::new(code(1), "Expression cannot be assigned to".to_string())),
+                        reason: Some(Reason::new(self.code(), "Expression cannot be assigned to".to_string())),
                         // A bit of a special handling for parentheses, because they are the only
                         // expression kind whose friendly name is in plural. Having it in singular
                         // or without this simple special handling gives very odd sounding sentences.
@@ -2665,7 +3248,7 @@ impl ToDiagnostic for CompileError {
                         ]
                     },
                     ParseErrorKind::UnrecognizedOpCode { known_op_codes } => Diagnostic {
-                        reason: Some(Reason::new(code(1), "Assembly instruction is unknown".to_string())),
+                        reason: Some(Reason::new(self.code(), "Assembly instruction is unknown".to_string())),
                         issue: Issue::error(
                             source_engine,
                             error.span.clone(),
@@ -2700,7 +3283,7 @@ impl ToDiagnostic for CompileError {
                 }
             },
             ConfigurableMissingAbiDecodeInPlace { span } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Configurables need a function named \"abi_decode_in_place\" to be in scope".to_string())),
+                reason: Some(Reason::new(self.code(), "Configurables need a function named \"abi_decode_in_place\" to be in scope".to_string())),
                 issue: Issue::error(
                     source_engine,
                     span.clone(),
@@ -2716,7 +3299,7 @@ impl ToDiagnostic for CompileError {
                 // Pure function cannot access storage
                 //   or
                 // Storage read-only function cannot write to storage
-                reason: Some(Reason::new(code(1), format!("{} function cannot {} storage",
+                reason: Some(Reason::new(self.code(), format!("{} function cannot {} storage",
                     if *is_pure {
                         "Pure"
                     } else {
@@ -2767,7 +3350,7 @@ impl ToDiagnostic for CompileError {
                 ],
             },
             MultipleImplsSatisfyingTraitForType { span, type_annotation , trait_names, trait_types_and_names: trait_types_and_spans } => Diagnostic {
-                reason: Some(Reason::new(code(1), format!("Multiple impls satisfying {} for {}", trait_names.join("+"), type_annotation))),
+                reason: Some(Reason::new(self.code(), format!("Multiple impls satisfying {} for {}", trait_names.join("+"), type_annotation))),
                 issue: Issue::error(
                     source_engine,
                     span.clone(),
@@ -2883,16 +3466,20 @@ pub enum StorageAccess {
     WriteWord,
     WriteSlots,
     /// Storage access happens via call to an impure function.
-    /// The parameters are the call path span and if the called function
-    /// reads from and writes to the storage: (call_path, reads, writes).
-    ImpureFunctionCall(Span, bool, bool),
+    /// The parameters are the call path span, if the called function
+    /// reads from and writes to the storage, and if that access was inferred
+    /// rather than declared: (call_path, reads, writes, is_inferred).
+    ImpureFunctionCall(Span, bool, bool, bool),
 }
 
 impl StorageAccess {
     pub fn is_write(&self) -> bool {
         matches!(
             self,
-            Self::Clear | Self::WriteWord | Self::WriteSlots | Self::ImpureFunctionCall(_, _, true)
+            Self::Clear
+                | Self::WriteWord
+                | Self::WriteSlots
+                | Self::ImpureFunctionCall(_, _, true, _)
         )
     }
 }
@@ -2905,19 +3492,30 @@ impl fmt::Display for StorageAccess {
             Self::ReadSlots => f.write_str("Reading storage slots happens here."),
             Self::WriteWord => f.write_str("Writing a word to the storage happens here."),
             Self::WriteSlots => f.write_str("Writing to storage slots happens here."),
-            Self::ImpureFunctionCall(call_path, reads, writes) => f.write_fmt(format_args!(
-                "Function \"{}\" {} the storage.",
-                call_path_suffix_with_args(&call_path.as_str().to_string()),
-                match (reads, writes) {
-                    (true, true) => "reads from and writes to",
-                    (true, false) => "reads from",
-                    (false, true) => "writes to",
-                    (false, false) => unreachable!(
+            Self::ImpureFunctionCall(call_path, reads, writes, is_inferred) => {
+                f.write_fmt(format_args!(
+                    "Function \"{}\" {} the storage{}.",
+                    call_path_suffix_with_args(&call_path.as_str().to_string()),
+                    match (reads, writes) {
+                        (true, true) => "reads from and writes to",
+                        (true, false) => "reads from",
+                        (false, true) => "writes to",
+                        (false, false) => unreachable!(
                         "Function \"{}\" is impure, so it must read from or write to the storage.",
                         call_path.as_str()
                     ),
-                }
-            )),
+                    },
+                    if *is_inferred {
+                        match (reads, writes) {
+                            (true, true) => " (inferred as \"#[storage(read, write)]\")",
+                            (true, false) => " (inferred as \"#[storage(read)]\")",
+                            _ => " (inferred as \"#[storage(write)]\")",
+                        }
+                    } else {
+                        ""
+                    }
+                ))
+            }
         }
     }
 }
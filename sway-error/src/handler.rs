@@ -1,5 +1,6 @@
 use crate::{error::CompileError, warning::CompileWarning};
 use core::cell::RefCell;
+use sway_types::{SourceEngine, Spanned};
 
 /// A handler with which you can emit diagnostics.
 #[derive(Default, Debug, Clone)]
@@ -77,6 +78,22 @@ impl Handler {
         (inner.errors, inner.warnings)
     }
 
+    /// Extract all the warnings and errors from this handler, grouped by their source file.
+    ///
+    /// Diagnostics of different files can be found in a different order between compilations.
+    /// Use this instead of [Handler::consume] before the final emission of the diagnostics, to
+    /// get the same output for the same program. The diagnostics of a single file keep the order
+    /// in which they were emitted, since typically they are grouped by relevance.
+    pub fn consume_sorted(
+        self,
+        source_engine: &SourceEngine,
+    ) -> (Vec<CompileError>, Vec<CompileWarning>) {
+        let (mut errors, mut warnings) = self.consume();
+        sort_diagnostics_by_file(source_engine, &mut errors);
+        sort_diagnostics_by_file(source_engine, &mut warnings);
+        (errors, warnings)
+    }
+
     pub fn append(&self, other: Handler) {
         let (errors, warnings) = other.consume();
         for warn in warnings {
@@ -123,6 +140,68 @@ impl Handler {
 
         emitted
     }
+
+    /// Checks the warnings emitted so far against `policy`, returning how they fail the
+    /// compilation, if they do.
+    ///
+    /// The warnings whose codes are allowed by the policy are ignored, both when treating
+    /// warnings as errors and when counting them against the warning budget.
+    pub fn check_warnings(&self, policy: &WarningPolicy) -> Option<WarningPolicyViolation> {
+        let count = self
+            .inner
+            .borrow()
+            .warnings
+            .iter()
+            .filter(|warning| {
+                !policy
+                    .allowed
+                    .iter()
+                    .any(|code| code == warning.code().as_str())
+            })
+            .count();
+        if count == 0 {
+            None
+        } else if policy.error_on_warnings {
+            Some(WarningPolicyViolation::Denied { count })
+        } else {
+            policy
+                .max_warnings
+                .filter(|max| count > *max)
+                .map(|max| WarningPolicyViolation::BudgetExceeded { count, max })
+        }
+    }
+}
+
+/// Decides when the warnings emitted during a compilation fail it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WarningPolicy {
+    /// Treat every warning as an error.
+    pub error_on_warnings: bool,
+    /// The codes of the warnings the policy doesn't apply to, e.g. `W0009`.
+    pub allowed: Vec<String>,
+    /// The number of warnings tolerated before they fail the compilation.
+    pub max_warnings: Option<usize>,
+}
+
+/// How the warnings of a compilation violate its [WarningPolicy].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarningPolicyViolation {
+    /// Warnings are treated as errors.
+    Denied { count: usize },
+    /// There are more warnings than the warning budget allows.
+    BudgetExceeded { count: usize, max: usize },
+}
+
+impl core::fmt::Display for WarningPolicyViolation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Denied { count } => write!(f, "{count} warning(s) treated as error(s)."),
+            Self::BudgetExceeded { count, max } => write!(
+                f,
+                "{count} warning(s) exceed the budget of {max} warning(s) set by `max-warnings`."
+            ),
+        }
+    }
 }
 
 /// Proof that an error was emitted through a `Handler`.
@@ -131,6 +210,19 @@ pub struct ErrorEmitted {
     _priv: (),
 }
 
+/// Sorts `diagnostics` by their file, keeping the emission order within a file.
+///
+/// The sort is stable, and diagnostics without a source file come last.
+fn sort_diagnostics_by_file<T: Spanned>(source_engine: &SourceEngine, diagnostics: &mut [T]) {
+    diagnostics.sort_by_cached_key(|diagnostic| {
+        let path = diagnostic
+            .span()
+            .source_id()
+            .map(|source_id| source_engine.get_path(source_id));
+        (path.is_none(), path)
+    });
+}
+
 /// We want compile errors and warnings to retain their ordering, since typically
 /// they are grouped by relevance. However, we want to deduplicate them.
 /// Stdlib dedup in Rust assumes sorted data for efficiency, but we don't want that.
@@ -143,3 +235,103 @@ fn dedup_unsorted<T: PartialEq + std::hash::Hash + Clone + Eq>(mut data: Vec<T>)
     data.retain(|item| seen.insert(item.clone()));
     data
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::warning::Warning;
+    use std::{path::PathBuf, sync::Arc};
+    use sway_types::{Ident, Span};
+
+    #[test]
+    fn consume_sorted_orders_by_file_and_keeps_emission_order() {
+        let source_engine = SourceEngine::default();
+        let src: Arc<str> = Arc::from("struct a {} struct b {}");
+        let span = |path: &str, start, end| {
+            let source_id = source_engine.get_source_id(&PathBuf::from(path));
+            Span::new(src.clone(), start, end, Some(source_id)).unwrap()
+        };
+        let warning = |span: Span| CompileWarning {
+            span: span.clone(),
+            warning_content: Warning::NonClassCaseStructName {
+                struct_name: Ident::new(span),
+            },
+        };
+
+        let handler = Handler::default();
+        handler.emit_warn(warning(span("b.sw", 7, 8)));
+        handler.emit_warn(warning(span("a.sw", 19, 20)));
+        handler.emit_warn(warning(span("a.sw", 7, 8)));
+
+        let (_, warnings) = handler.consume_sorted(&source_engine);
+        let positions = warnings
+            .iter()
+            .map(|warning| {
+                let path = source_engine.get_path(warning.span.source_id().unwrap());
+                (path, warning.span.start())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            positions,
+            [
+                (PathBuf::from("a.sw"), 19),
+                (PathBuf::from("a.sw"), 7),
+                (PathBuf::from("b.sw"), 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_warnings_applies_policy() {
+        let warning = || CompileWarning {
+            span: Span::dummy(),
+            warning_content: Warning::NonClassCaseStructName {
+                struct_name: Ident::new_no_span("a".to_string()),
+            },
+        };
+        let code = warning().code().as_str().to_string();
+
+        let handler = Handler::default();
+        handler.emit_warn(warning());
+        handler.emit_warn(warning());
+
+        assert_eq!(handler.check_warnings(&WarningPolicy::default()), None);
+        assert_eq!(
+            handler.check_warnings(&WarningPolicy {
+                error_on_warnings: true,
+                ..Default::default()
+            }),
+            Some(WarningPolicyViolation::Denied { count: 2 })
+        );
+        assert_eq!(
+            handler.check_warnings(&WarningPolicy {
+                error_on_warnings: true,
+                allowed: vec![code.clone()],
+                ..Default::default()
+            }),
+            None
+        );
+        assert_eq!(
+            handler.check_warnings(&WarningPolicy {
+                max_warnings: Some(2),
+                ..Default::default()
+            }),
+            None
+        );
+        assert_eq!(
+            handler.check_warnings(&WarningPolicy {
+                max_warnings: Some(1),
+                ..Default::default()
+            }),
+            Some(WarningPolicyViolation::BudgetExceeded { count: 2, max: 1 })
+        );
+        assert_eq!(
+            handler.check_warnings(&WarningPolicy {
+                allowed: vec![code],
+                max_warnings: Some(0),
+                ..Default::default()
+            }),
+            None
+        );
+    }
+}
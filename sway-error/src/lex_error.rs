@@ -63,6 +63,34 @@ pub enum LexErrorKind {
     U256NotInHex,
 }
 
+diagnostic_codes! {
+    LexErrorKind in lexical_analysis,
+    delegate {
+    }
+    codes {
+        UnclosedMultilineComment = 1,
+        UnexpectedCloseDelimiter = 2,
+        MismatchedDelimiters = 3,
+        UnclosedDelimiter = 4,
+        UnclosedStringLiteral = 5,
+        UnclosedCharLiteral = 6,
+        ExpectedCloseQuote = 7,
+        IncompleteHexIntLiteral = 8,
+        IncompleteBinaryIntLiteral = 9,
+        IncompleteOctalIntLiteral = 10,
+        InvalidIntSuffix = 11,
+        InvalidCharacter = 12,
+        InvalidHexEscape = 13,
+        UnicodeEscapeMissingBrace = 14,
+        InvalidUnicodeEscapeDigit = 15,
+        UnicodeEscapeOutOfRange = 16,
+        UnicodeEscapeInvalidCharValue = 17,
+        UnicodeTextDirInLiteral = 18,
+        InvalidEscapeCode = 19,
+        U256NotInHex = 20,
+    }
+}
+
 impl Spanned for LexError {
     fn span(&self) -> Span {
         self.span.clone()
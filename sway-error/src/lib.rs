@@ -1,3 +1,7 @@
+// Defines the `diagnostic_codes!` macro, used within the following modules.
+#[macro_use]
+pub mod codes;
+
 pub mod convert_parse_tree_error;
 pub mod diagnostic;
 pub mod error;
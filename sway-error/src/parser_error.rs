@@ -110,6 +110,118 @@ pub enum ParseErrorKind {
     MissingColonInEnumTypeField,
     #[error("Expected storage key of type U256.")]
     ExpectedStorageKeyU256,
+    #[error("Invalid macro definition. Macros are defined as `macro_rules! name {{ (pattern) => {{ body }}; ... }}`.")]
+    InvalidMacroDefinition,
+    #[error(
+        "Unknown macro fragment specifier `{}`. Expected `ident`, `expr`, `ty`, `literal` or `tt`.",
+        kind
+    )]
+    UnknownMacroFragmentSpecifier { kind: Ident },
+    #[error("Unknown macro variable `${}`.", name)]
+    UnknownMacroVariable { name: Ident },
+    #[error("No rule of the macro `{}` matches these arguments.", name)]
+    NoMatchingMacroRule { name: Ident },
+    #[error("Macro variable `${}` is repeated a different number of times than the other variables within the same repetition.", name)]
+    MacroRepetitionMismatch { name: Ident },
+    #[error("Macro variable `${}` is still repeating at this depth.", name)]
+    MacroVariableStillRepeating { name: Ident },
+    #[error("This repetition does not contain any macro variable that repeats at this depth.")]
+    MacroRepetitionWithoutVariables,
+    #[error(
+        "Recursion limit of {} reached while expanding the macro `{}`.",
+        limit,
+        name
+    )]
+    MacroRecursionLimitReached { name: Ident, limit: usize },
+    #[error("Expected a string literal with the format, e.g. `format!(\"x = {{}}\", x)`.")]
+    ExpectedFormatString,
+    #[error("Invalid format string. Arguments are placed with `{{}}`, `{{0}}` or `{{name}}`, and braces are written as `{{{{` and `}}}}`.")]
+    InvalidFormatString,
+    #[error(
+        "The format string refers to argument {}, but there {} only {} argument{}.",
+        index,
+        if *count == 1 { "is" } else { "are" },
+        count,
+        if *count == 1 { "" } else { "s" }
+    )]
+    MissingFormatArgument { index: usize, count: usize },
+    #[error("This argument is never used in the format string.")]
+    UnusedFormatArgument,
+    #[error(
+        "Expected a block, `while` or `for` after the label. Only blocks and loops can be labeled."
+    )]
+    ExpectedLabeledBlockOrLoop,
+    #[error("`{}!` is a declarative macro, which requires the experimental `declarative_macros` feature. Enable it with `--experimental declarative_macros`.", name)]
+    DeclarativeMacrosNotEnabled { name: Ident },
+}
+
+diagnostic_codes! {
+    ParseErrorKind in parsing,
+    delegate {
+    }
+    codes {
+        ExpectedImportNameGroupOrGlob = 1,
+        ExpectedAnItem = 2,
+        ExpectedAnItemAfterDocComment = 3,
+        ExpectedCommaOrCloseParenInFnArgs = 4,
+        UnrecognizedOpCode = 5,
+        UnexpectedTokenInStatement = 6,
+        UnassignableExpression = 7,
+        UnexpectedTokenAfterArrayIndex = 8,
+        InvalidLiteralFieldName = 9,
+        InvalidStatement = 10,
+        InvalidItem = 11,
+        IntFieldWithTypeSuffix = 12,
+        ExpectedFieldName = 13,
+        ExpectedCommaOrCloseParenInTupleOrParenExpression = 14,
+        ExpectedExpression = 15,
+        UnexpectedTokenAfterArrayLength = 16,
+        ExpectedCommaSemicolonOrCloseBracketInArray = 17,
+        UnexpectedTokenAfterAsmReturnType = 18,
+        MalformedAsmImmediate = 19,
+        ExpectedIdent = 20,
+        ExpectedPattern = 21,
+        UnexpectedTokenAfterStrLength = 22,
+        ExpectedType = 23,
+        UnexpectedTokenAfterArrayTypeLength = 24,
+        ExpectedOpenBrace = 25,
+        ExpectedOpenParen = 26,
+        ExpectedOpenBracket = 27,
+        ExpectedLiteral = 28,
+        ExpectedModuleKind = 29,
+        ExpectedPunct = 30,
+        ExpectedKeyword = 31,
+        UnexpectedTokenAfterAbiAddress = 32,
+        ExpectedAnAttribute = 33,
+        UnexpectedTokenAfterAttribute = 34,
+        InvalidDoubleUnderscore = 35,
+        UnexpectedRestPattern = 36,
+        ReservedKeywordIdentifier = 37,
+        UnnecessaryVisibilityQualifier = 38,
+        ExpectedDocComment = 39,
+        ExpectedModuleDocComment = 40,
+        UnexpectedClass = 41,
+        FieldProjectionWithGenericArgs = 42,
+        UnexpectedTokenAfterPtrType = 43,
+        UnexpectedTokenAfterSliceType = 44,
+        ExpectedPathType = 45,
+        MissingColonInEnumTypeField = 46,
+        ExpectedStorageKeyU256 = 47,
+        InvalidMacroDefinition = 48,
+        UnknownMacroFragmentSpecifier = 49,
+        UnknownMacroVariable = 50,
+        NoMatchingMacroRule = 51,
+        MacroRepetitionMismatch = 52,
+        MacroVariableStillRepeating = 53,
+        MacroRepetitionWithoutVariables = 54,
+        MacroRecursionLimitReached = 55,
+        ExpectedFormatString = 56,
+        InvalidFormatString = 57,
+        MissingFormatArgument = 58,
+        UnusedFormatArgument = 59,
+        ExpectedLabeledBlockOrLoop = 60,
+        DeclarativeMacrosNotEnabled = 61,
+    }
 }
 
 #[derive(Debug, Error, Clone, PartialEq, Eq, Hash)]
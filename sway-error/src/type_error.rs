@@ -33,6 +33,18 @@ pub enum TypeError {
     LiteralOverflow { expected: String, span: Span },
 }
 
+diagnostic_codes! {
+    TypeError in type_checking,
+    delegate {
+    }
+    codes {
+        MismatchedType = 1,
+        UnknownType = 2,
+        MatchArmScrutineeWrongType = 3,
+        LiteralOverflow = 4,
+    }
+}
+
 impl Spanned for TypeError {
     fn span(&self) -> Span {
         use TypeError::*;
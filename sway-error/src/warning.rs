@@ -1,7 +1,4 @@
-use crate::{
-    diagnostic::{Code, Diagnostic, Hint, Issue, Reason, ToDiagnostic},
-    formatting::Indent,
-};
+use crate::diagnostic::{Code, Diagnostic, Hint, Issue, Reason, ToDiagnostic};
 
 use core::fmt;
 
@@ -31,6 +28,11 @@ impl CompileWarning {
     pub fn source_id(&self) -> Option<SourceId> {
         self.span.source_id().cloned()
     }
+
+    /// Returns the stable code of the warning, e.g. `W0001`.
+    pub fn code(&self) -> Code {
+        self.warning_content.code()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -126,23 +128,94 @@ pub enum Warning {
         effect_in_suggestion: String,
         block_name: Ident,
     },
+    NonPayableMethodReadsMsgAmount {
+        method_name: Ident,
+    },
     ModulePrivacyDisabled,
     UsingDeprecated {
         message: String,
     },
-    DuplicatedStorageKey {
-        first_field: IdentUnique,
-        first_field_full_name: String,
-        first_field_key_is_compiler_generated: bool,
-        second_field: IdentUnique,
-        second_field_full_name: String,
-        second_field_key_is_compiler_generated: bool,
-        key: String,
-        // True if the experimental feature `storage_domains` is used.
-        experimental_storage_domains: bool,
+    CastTruncatesLiteral {
+        value: String,
+        truncated_value: String,
+        ty: String,
+    },
+    RedundantCast {
+        ty: String,
+    },
+    AnalysisPass {
+        pass: String,
+        message: String,
+    },
+    UncheckedExternalInput {
+        /// The description of the external input, e.g. "Argument \"amount\"".
+        source: String,
+        source_span: Span,
+        /// The variables the input is assigned to on its way to the sink.
+        path: Vec<Span>,
+        /// The description of the sink, e.g. "a storage write".
+        sink: String,
+        fn_name: Ident,
+    },
+    CrossFunctionReentrancy {
+        storage_field: String,
+        method_name: Ident,
+        reentered_method_name: Ident,
+    },
+    IterationBoundNotConstant {
+        fn_name: Ident,
+        bound_name: Ident,
     },
 }
 
+diagnostic_codes! {
+    Warning in warnings,
+    delegate {
+    }
+    codes {
+        NonClassCaseStructName = 1,
+        NonClassCaseTypeParameter = 2,
+        NonClassCaseTraitName = 3,
+        NonClassCaseEnumName = 4,
+        NonClassCaseEnumVariantName = 5,
+        NonSnakeCaseStructFieldName = 6,
+        NonSnakeCaseFunctionName = 7,
+        NonScreamingSnakeCaseConstName = 8,
+        UnusedReturnValue = 9,
+        SimilarMethodFound = 10,
+        ShadowsOtherSymbol = 11,
+        AsmBlockIsEmpty = 12,
+        UninitializedAsmRegShadowsItem = 13,
+        OverridingTraitImplementation = 14,
+        DeadDeclaration = 15,
+        DeadEnumDeclaration = 16,
+        DeadFunctionDeclaration = 17,
+        DeadStructDeclaration = 18,
+        DeadTrait = 19,
+        UnreachableCode = 20,
+        DeadEnumVariant = 21,
+        DeadMethod = 22,
+        StructFieldNeverRead = 23,
+        ShadowingReservedRegister = 24,
+        DeadStorageDeclaration = 25,
+        DeadStorageDeclarationForFunction = 26,
+        MatchExpressionUnreachableArm = 27,
+        UnrecognizedAttribute = 28,
+        AttributeExpectedNumberOfArguments = 29,
+        UnexpectedAttributeArgumentValue = 30,
+        EffectAfterInteraction = 31,
+        NonPayableMethodReadsMsgAmount = 32,
+        ModulePrivacyDisabled = 33,
+        UsingDeprecated = 34,
+        CastTruncatesLiteral = 35,
+        RedundantCast = 36,
+        AnalysisPass = 37,
+        UncheckedExternalInput = 38,
+        CrossFunctionReentrancy = 39,
+        IterationBoundNotConstant = 40,
+    }
+}
+
 impl fmt::Display for Warning {
     // This trait requires `fmt` with this exact signature.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -284,12 +357,28 @@ impl fmt::Display for Warning {
             EffectAfterInteraction {effect, effect_in_suggestion, block_name} =>
                 write!(f, "{effect} after external contract interaction in function or method \"{block_name}\". \
                           Consider {effect_in_suggestion} before calling another contract"),
+            NonPayableMethodReadsMsgAmount { method_name } =>
+                write!(f, "Method \"{method_name}\" reads the amount of coins forwarded with the call, but is not \"#[payable]\". \
+                          Coins cannot be forwarded to non-payable methods, so the amount will always be zero. \
+                          Consider marking the method as \"#[payable]\""),
             ModulePrivacyDisabled => write!(f, "Module privacy rules will soon change to make modules private by default.
                                             You can enable the new behavior with the --experimental-private-modules flag, which will become the default behavior in a later release.
                                             More details are available in the related RFC: https://github.com/FuelLabs/sway-rfcs/blob/master/rfcs/0008-private-modules.md"),
             UsingDeprecated { message } => write!(f, "{}", message),
-            DuplicatedStorageKey { first_field_full_name, second_field_full_name, key, .. } =>
-                write!(f, "Two storage fields have the same storage key.\nFirst field: {first_field_full_name}\nSecond field: {second_field_full_name}\nKey: {key}"),
+            CastTruncatesLiteral { value, truncated_value, ty } => write!(f,
+                "Literal {value} does not fit into \"{ty}\" and is truncated to {truncated_value} by the cast. \
+                 Consider using a literal that fits into \"{ty}\"."),
+            RedundantCast { ty } => write!(f, "Casting a value of type \"{ty}\" to \"{ty}\" has no effect."),
+            AnalysisPass { pass, message } => write!(f, "{message} (reported by the \"{pass}\" analysis pass)"),
+            UncheckedExternalInput { source, sink, fn_name, .. } => write!(f,
+                "{source} of \"{fn_name}\" reaches {sink} without passing through a sanitizer."),
+            CrossFunctionReentrancy { storage_field, method_name, reentered_method_name } => write!(f,
+                "Storage field \"{storage_field}\" is read in \"{method_name}\" before an external contract call, \
+                 but can be written by re-entering \"{reentered_method_name}\" during the call. \
+                 Consider calling a \"#[reentrancy_guard]\" function in both methods"),
+            IterationBoundNotConstant { fn_name, bound_name } => write!(f,
+                "The iteration bound \"{bound_name}\" passed to \"{fn_name}\" is not a constant, \
+                 so the cost of this loop cannot be bounded at compile time."),
         }
     }
 }
@@ -300,12 +389,11 @@ const FUTURE_HARD_ERROR_HELP: &str =
 
 impl ToDiagnostic for CompileWarning {
     fn to_diagnostic(&self, source_engine: &sway_types::SourceEngine) -> Diagnostic {
-        let code = Code::warnings;
         use sway_types::style::*;
         use Warning::*;
         match &self.warning_content {
             NonScreamingSnakeCaseConstName { name } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Constant name is not idiomatic".to_string())),
+                reason: Some(Reason::new(self.code(), "Constant name is not idiomatic".to_string())),
                 issue: Issue::warning(
                     source_engine,
                     name.span(),
@@ -324,7 +412,7 @@ impl ToDiagnostic for CompileWarning {
                 ],
             },
             MatchExpressionUnreachableArm { match_value, match_type, preceding_arms, unreachable_arm, is_last_arm, is_catch_all_arm } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Match arm is unreachable".to_string())),
+                reason: Some(Reason::new(self.code(), "Match arm is unreachable".to_string())),
                 issue: Issue::warning(
                     source_engine,
                     unreachable_arm.clone(),
@@ -381,7 +469,7 @@ impl ToDiagnostic for CompileWarning {
                 }
             },
             UninitializedAsmRegShadowsItem { constant_or_configurable_or_variable, item } => Diagnostic {
-                reason: Some(Reason::new(code(1), format!("Uninitialized ASM register is shadowing a {}", constant_or_configurable_or_variable.to_ascii_lowercase()))),
+                reason: Some(Reason::new(self.code(), format!("Uninitialized ASM register is shadowing a {}", constant_or_configurable_or_variable.to_ascii_lowercase()))),
                 issue: Issue::warning(
                     source_engine,
                     self.span(),
@@ -411,7 +499,7 @@ impl ToDiagnostic for CompileWarning {
                 help: vec![],
             },
             AsmBlockIsEmpty => Diagnostic {
-                reason: Some(Reason::new(code(1), "ASM block is empty".to_string())),
+                reason: Some(Reason::new(self.code(), "ASM block is empty".to_string())),
                 issue: Issue::warning(
                     source_engine,
                     self.span(),
@@ -422,56 +510,31 @@ impl ToDiagnostic for CompileWarning {
                     "Consider adding assembly instructions or a return register to the ASM block, or removing the block altogether.".to_string(),
                 ],
             },
-            DuplicatedStorageKey { first_field, first_field_full_name, first_field_key_is_compiler_generated, second_field, second_field_full_name, second_field_key_is_compiler_generated, key, experimental_storage_domains } => Diagnostic {
-                reason: Some(Reason::new(code(1), "Two storage fields have the same storage key".to_string())),
+            UncheckedExternalInput { source, source_span, path, sink, fn_name } => Diagnostic {
+                reason: Some(Reason::new(self.code(), format!("Unchecked external input reaches {sink}"))),
                 issue: Issue::warning(
                     source_engine,
-                    first_field.span(),
-                    format!("\"{first_field_full_name}\" has the same storage key as \"{second_field_full_name}\"."),
+                    self.span(),
+                    format!("{source} of \"{fn_name}\" reaches {sink} here, without passing through a sanitizer."),
                 ),
-                hints: vec![
-                    Hint::info(
+                hints: {
+                    let mut hints = vec![
+                        Hint::info(
+                            source_engine,
+                            source_span.clone(),
+                            format!("{source} is controlled by the caller."),
+                        ),
+                    ];
+                    hints.extend(path.iter().map(|step| Hint::info(
                         source_engine,
-                        second_field.span(),
-                        format!("\"{second_field_full_name}\" is declared here."),
-                    ),
-                ],
+                        step.clone(),
+                        "It is assigned here.".to_string(),
+                    )));
+                    hints
+                },
                 help: vec![
-                    if *first_field_key_is_compiler_generated || *second_field_key_is_compiler_generated {
-                        format!("The key of \"{}\" is generated by the compiler using the following formula:",
-                            if *first_field_key_is_compiler_generated {
-                                first_field_full_name
-                            } else {
-                                second_field_full_name
-                            }
-                        )
-                    } else {
-                        "Both keys are explicitly defined by using the `in` keyword.".to_string()
-                    },
-                    if *first_field_key_is_compiler_generated || *second_field_key_is_compiler_generated {
-                        if *experimental_storage_domains {
-                            format!("{}sha256((0u8, \"{}\"))",
-                                Indent::Single,
-                                if *first_field_key_is_compiler_generated {
-                                    first_field_full_name
-                                } else {
-                                    second_field_full_name
-                                }
-                            )
-                        } else {
-                            format!("{}sha256(\"{}\")",
-                                Indent::Single,
-                                if *first_field_key_is_compiler_generated {
-                                    first_field_full_name
-                                } else {
-                                    second_field_full_name
-                                }
-                            )
-                        }
-                    } else {
-                        Diagnostic::help_none()
-                    },
-                    format!("The common key is: {key}.")
+                    "Consider validating the input with a function marked as `#[sanitizer]`, and using the value it returns.".to_string(),
+                    "E.g.: `let amount = checked_amount(amount);`.".to_string(),
                 ],
             },
            _ => Diagnostic {
@@ -133,6 +133,10 @@ features! {
     "https://github.com/FuelLabs/sway/issues/5727",
     storage_domains = false,
     "https://github.com/FuelLabs/sway/issues/6701",
+    infer_storage_purity = false,
+    "https://docs.fuel.network/docs/sway/blockchain-development/purity/",
+    declarative_macros = false,
+    "https://docs.fuel.network/docs/sway/advanced/macros/",
 }
 
 #[derive(Clone, Debug, Default, Parser)]
@@ -2,5 +2,7 @@ pub mod call_graph;
 pub use call_graph::*;
 pub mod dominator;
 pub use dominator::*;
+pub mod loops;
+pub use loops::*;
 pub mod memory_utils;
 pub use memory_utils::*;
@@ -55,3 +55,23 @@ pub fn callee_first_order(cg: &CallGraph) -> Vec<Function> {
 
     res
 }
+
+/// Returns true if `function` can, directly or indirectly, call itself.
+pub fn is_recursive(cg: &CallGraph, function: &Function) -> bool {
+    let mut visited = FxIndexSet::<Function>::default();
+    let mut to_visit = cg
+        .get(function)
+        .map(|callees| callees.iter().copied().collect::<Vec<_>>())
+        .unwrap_or_default();
+    while let Some(callee) = to_visit.pop() {
+        if callee == *function {
+            return true;
+        }
+        if visited.insert(callee) {
+            if let Some(callees) = cg.get(&callee) {
+                to_visit.extend(callees.iter().copied());
+            }
+        }
+    }
+    false
+}
@@ -213,6 +213,11 @@ impl DomTree {
         false
     }
 
+    /// Is `block` in the tree, i.e., is it reachable from the entry block?
+    pub fn contains(&self, block: &Block) -> bool {
+        self.0.contains_key(block)
+    }
+
     /// Get an iterator over the children nodes
     pub fn children(&self, node: Block) -> impl Iterator<Item = Block> + '_ {
         self.0[&node].children.iter().cloned()
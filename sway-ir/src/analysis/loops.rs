@@ -0,0 +1,308 @@
+//! Natural loop analysis.
+//!
+//! A natural loop is identified by a back edge, i.e., an edge from a block (the latch) to a block
+//! which dominates it (the header).  The loop body consists of the header and every block which
+//! can reach a latch without going through the header.  Back edges sharing a header are merged
+//! into a single loop.
+
+use crate::{
+    block::Block, AnalysisResult, AnalysisResultT, AnalysisResults, BinaryOpKind, BranchToWithArgs,
+    Context, DomTree, Function, InstOp, Instruction, IrError, Pass, PassMutability, Predicate,
+    ScopedPass, Value, DOMINATORS_NAME,
+};
+use sway_types::FxIndexSet;
+
+/// A natural loop in the CFG of a function.
+pub struct Loop {
+    /// The single entry block of the loop, which dominates every block in it.
+    pub header: Block,
+    /// The blocks in the loop which branch back to the header.
+    pub latches: Vec<Block>,
+    /// All the blocks in the loop, including the header and the latches.
+    pub blocks: FxIndexSet<Block>,
+    /// The blocks outside of the loop which are branched to from within it.
+    pub exits: FxIndexSet<Block>,
+}
+
+impl Loop {
+    /// Is `block` part of this loop?
+    pub fn contains(&self, block: &Block) -> bool {
+        self.blocks.contains(block)
+    }
+
+    /// Is `other` nested within this loop?
+    pub fn contains_loop(&self, other: &Loop) -> bool {
+        self.header != other.header && self.contains(&other.header)
+    }
+
+    /// Is `value` defined outside of the loop, i.e., is it the same on every iteration?
+    pub fn is_invariant(&self, context: &Context, value: &Value) -> bool {
+        match value.get_instruction(context) {
+            Some(Instruction { parent, .. }) => !self.contains(parent),
+            None => value
+                .get_argument(context)
+                .map_or(true, |arg| !self.contains(&arg.block)),
+        }
+    }
+
+    /// Get the latch, if there is only one.
+    pub fn latch(&self) -> Option<Block> {
+        match self.latches.as_slice() {
+            [latch] => Some(*latch),
+            _ => None,
+        }
+    }
+
+    /// Get the only block outside of the loop branching to the header, if there is one.
+    pub fn preheader(&self, context: &Context) -> Option<Block> {
+        let mut outside_preds = self
+            .header
+            .pred_iter(context)
+            .filter(|pred| !self.contains(pred));
+        match (outside_preds.next(), outside_preds.next()) {
+            (Some(preheader), None) => Some(*preheader),
+            _ => None,
+        }
+    }
+
+    /// Get the basic induction variables of the loop.
+    ///
+    /// Induction variables are only found in loops with a preheader and a single latch.
+    pub fn induction_variables(&self, context: &Context) -> Vec<InductionVariable> {
+        let (Some(preheader), Some(latch)) = (self.preheader(context), self.latch()) else {
+            return Vec::new();
+        };
+        let start_vals = preheader.get_succ_params(context, &self.header);
+        let next_vals = latch.get_succ_params(context, &self.header);
+
+        self.header
+            .arg_iter(context)
+            .zip(start_vals.iter().zip(next_vals.iter()))
+            .enumerate()
+            .filter_map(|(arg_idx, (value, (start, next)))| {
+                if !value
+                    .get_type(context)
+                    .is_some_and(|ty| ty.is_uint64(context))
+                {
+                    return None;
+                }
+                let Some(Instruction {
+                    op: InstOp::BinaryOp { op, arg1, arg2 },
+                    ..
+                }) = next.get_instruction(context)
+                else {
+                    return None;
+                };
+                let step = match op {
+                    BinaryOpKind::Add | BinaryOpKind::Sub if arg1 == value => arg2,
+                    BinaryOpKind::Add if arg2 == value => arg1,
+                    _ => return None,
+                };
+                Some(InductionVariable {
+                    value: *value,
+                    arg_idx,
+                    start: *start,
+                    next: *next,
+                    step_op: *op,
+                    step: step.get_constant(context)?.as_uint()?,
+                })
+            })
+            .collect()
+    }
+
+    /// Get the number of iterations after which the header leaves the loop, if it can be
+    /// determined at compile time.
+    ///
+    /// This requires the header to branch on a comparison of an induction variable with a constant
+    /// start value against a constant.  Loops running for more than `max_trip_count` iterations
+    /// are not analysed.
+    pub fn trip_count(&self, context: &Context, max_trip_count: u64) -> Option<u64> {
+        let Some(Instruction {
+            op:
+                InstOp::ConditionalBranch {
+                    cond_value,
+                    true_block,
+                    false_block,
+                },
+            ..
+        }) = self.header.get_terminator(context)
+        else {
+            return None;
+        };
+        let continue_on_true = match (
+            self.contains(&true_block.block),
+            self.contains(&false_block.block),
+        ) {
+            (true, false) => true,
+            (false, true) => false,
+            _ => return None,
+        };
+
+        let Some(Instruction {
+            op: InstOp::Cmp(pred, lhs, rhs),
+            ..
+        }) = cond_value.get_instruction(context)
+        else {
+            return None;
+        };
+        let as_uint = |value: &Value| value.get_constant(context).and_then(|c| c.as_uint());
+        let (induction_var, bound, induction_var_on_lhs) = match (as_uint(lhs), as_uint(rhs)) {
+            (None, Some(bound)) => (*lhs, bound, true),
+            (Some(bound), None) => (*rhs, bound, false),
+            _ => return None,
+        };
+        let induction_var = self
+            .induction_variables(context)
+            .into_iter()
+            .find(|iv| iv.value == induction_var)?;
+
+        // Run the induction variable until the loop is exited.
+        let mut value = as_uint(&induction_var.start)?;
+        let mut trip_count = 0;
+        loop {
+            let (lhs, rhs) = if induction_var_on_lhs {
+                (value, bound)
+            } else {
+                (bound, value)
+            };
+            let cond = match pred {
+                Predicate::Equal => lhs == rhs,
+                Predicate::LessThan => lhs < rhs,
+                Predicate::GreaterThan => lhs > rhs,
+            };
+            if cond != continue_on_true {
+                return Some(trip_count);
+            }
+            if trip_count == max_trip_count {
+                return None;
+            }
+            trip_count += 1;
+            value = induction_var.value_after(value, 1)?;
+        }
+    }
+}
+
+/// A basic induction variable, i.e., a header argument of type `u64` which is incremented or
+/// decremented by a constant on every iteration.
+pub struct InductionVariable {
+    /// The header argument.
+    pub value: Value,
+    /// The index of the header argument.
+    pub arg_idx: usize,
+    /// The value passed from the preheader.
+    pub start: Value,
+    /// The value passed from the latch.
+    pub next: Value,
+    /// Either [BinaryOpKind::Add] or [BinaryOpKind::Sub].
+    pub step_op: BinaryOpKind,
+    pub step: u64,
+}
+
+impl InductionVariable {
+    /// Get the value of the induction variable `iterations` iterations after it is `value`, or
+    /// `None` if it overflows.
+    pub fn value_after(&self, value: u64, iterations: u64) -> Option<u64> {
+        let delta = self.step.checked_mul(iterations)?;
+        match self.step_op {
+            BinaryOpKind::Add => value.checked_add(delta),
+            _ => value.checked_sub(delta),
+        }
+    }
+}
+
+/// All the natural loops of a function, in the order their headers appear in the function.
+#[derive(Default)]
+pub struct Loops(Vec<Loop>);
+impl AnalysisResultT for Loops {}
+
+impl Loops {
+    /// Get an iterator over all the loops.
+    pub fn iter(&self) -> impl Iterator<Item = &Loop> {
+        self.0.iter()
+    }
+
+    /// Get an iterator over the loops which do not contain any other loop.
+    pub fn innermost(&self) -> impl Iterator<Item = &Loop> {
+        self.0
+            .iter()
+            .filter(|lp| !self.0.iter().any(|other| lp.contains_loop(other)))
+    }
+}
+
+pub const LOOPS_NAME: &str = "loops";
+
+pub fn create_loops_pass() -> Pass {
+    Pass {
+        name: LOOPS_NAME,
+        descr: "Natural loops computation",
+        deps: vec![DOMINATORS_NAME],
+        runner: ScopedPass::FunctionPass(PassMutability::Analysis(compute_loops_pass)),
+    }
+}
+
+pub fn compute_loops_pass(
+    context: &Context,
+    analyses: &AnalysisResults,
+    function: Function,
+) -> Result<AnalysisResult, IrError> {
+    let dom_tree: &DomTree = analyses.get_analysis_result(function);
+    Ok(Box::new(compute_loops(context, &function, dom_tree)))
+}
+
+/// Compute the natural loops of `function`.
+/// Beware: Unreachable blocks are never part of a loop.
+pub fn compute_loops(context: &Context, function: &Function, dom_tree: &DomTree) -> Loops {
+    let mut loops = Loops::default();
+
+    for block in function
+        .block_iter(context)
+        .filter(|block| dom_tree.contains(block))
+    {
+        // Collect the back edges to `block`, if it is a loop header.
+        let latches: Vec<Block> = block
+            .pred_iter(context)
+            .filter(|pred| dom_tree.contains(pred) && dom_tree.dominates(block, **pred))
+            .cloned()
+            .collect();
+        if latches.is_empty() {
+            continue;
+        }
+
+        // Walk backwards from the latches until we reach the header.
+        let mut blocks = FxIndexSet::default();
+        blocks.insert(block);
+        let mut worklist = latches.clone();
+        while let Some(body_block) = worklist.pop() {
+            if blocks.insert(body_block) {
+                worklist.extend(
+                    body_block
+                        .pred_iter(context)
+                        .filter(|pred| dom_tree.contains(pred)),
+                );
+            }
+        }
+
+        // Keep the blocks in the order they appear in the function.
+        let blocks: FxIndexSet<Block> = function
+            .block_iter(context)
+            .filter(|b| blocks.contains(b))
+            .collect();
+
+        let exits = blocks
+            .iter()
+            .flat_map(|b| b.successors(context))
+            .filter_map(|BranchToWithArgs { block: succ, .. }| {
+                (!blocks.contains(&succ)).then_some(succ)
+            })
+            .collect();
+
+        loops.0.push(Loop {
+            header: block,
+            latches,
+            blocks,
+            exits,
+        });
+    }
+
+    loops
+}
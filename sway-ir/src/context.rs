@@ -17,6 +17,7 @@ use crate::{
     local_var::LocalVarContent,
     metadata::Metadatum,
     module::{Kind, ModuleContent, ModuleIterator},
+    optimize::{InlineHeuristics, LoopUnrollHeuristics},
     value::ValueContent,
     Type, TypeContent,
 };
@@ -42,6 +43,12 @@ pub struct Context<'eng> {
     next_unique_sym_tag: u64,
 
     pub experimental: ExperimentalFeatures,
+
+    /// The thresholds used by the function inlining pass.
+    pub inline_heuristics: InlineHeuristics,
+
+    /// The thresholds used by the loop unrolling pass.
+    pub loop_unroll_heuristics: LoopUnrollHeuristics,
 }
 
 impl<'eng> Context<'eng> {
@@ -59,6 +66,8 @@ impl<'eng> Context<'eng> {
             next_unique_sym_tag: Default::default(),
             program_kind: Kind::Contract,
             experimental,
+            inline_heuristics: InlineHeuristics::default(),
+            loop_unroll_heuristics: LoopUnrollHeuristics::default(),
         };
         Type::create_basic_types(&mut def);
         def
@@ -15,6 +15,9 @@ pub enum IrError {
     RemoveMissingBlock(String),
     ValueNotFound(String),
     InconsistentParent(String, String, String),
+    WriteFailure(String, String),
+
+    VerifyPassFailure(Option<String>, Box<IrError>),
 
     VerifyArgumentValueIsNotArgument(String),
     VerifyUnaryOpIncorrectArgType,
@@ -126,6 +129,17 @@ impl fmt::Display for IrError {
                     but found {found_parent}."
                 )
             }
+            IrError::WriteFailure(path, reason) => {
+                write!(f, "Unable to write {path}: {reason}.")
+            }
+            IrError::VerifyPassFailure(Some(pass), error) => {
+                write!(f, "The `{pass}` pass produced invalid IR. {error}")
+            }
+            IrError::VerifyPassFailure(None, error) => write!(
+                f,
+                "The optimization passes produced invalid IR, but no single pass could be found \
+                to produce it. {error}"
+            ),
             IrError::VerifyArgumentValueIsNotArgument(callee) => write!(
                 f,
                 "Verification failed: Argument specifier for function '{callee}' is not an \
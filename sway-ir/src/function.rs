@@ -317,6 +317,11 @@ impl Function {
         context.functions[self.0].selector
     }
 
+    /// Whether or not the function was declared public.
+    pub fn is_public(&self, context: &Context) -> bool {
+        context.functions[self.0].is_public
+    }
+
     /// Whether or not the function is a program entry point, i.e. `main`, `#[test]` fns or abi
     /// methods.
     pub fn is_entry(&self, context: &Context) -> bool {
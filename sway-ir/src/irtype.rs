@@ -168,6 +168,11 @@ impl Type {
         Self::get_or_create_unique_type(context, TypeContent::TypedSlice(item_ty))
     }
 
+    /// Get string slice type
+    pub fn get_string_slice(context: &mut Context) -> Type {
+        Self::get_or_create_unique_type(context, TypeContent::StringSlice)
+    }
+
     /// Return a string representation of type, used for printing.
     pub fn as_string(&self, context: &Context) -> String {
         let sep_types_str = |agg_content: &Vec<Type>, sep: &str| {
@@ -25,8 +25,14 @@ pub mod cse;
 pub use cse::*;
 pub mod dce;
 pub use dce::*;
+pub mod hash_fold;
+pub use hash_fold::*;
 pub mod inline;
 pub use inline::*;
+pub mod loop_reduce;
+pub use loop_reduce::*;
+pub mod loop_unroll;
+pub use loop_unroll::*;
 pub mod mem2reg;
 pub use mem2reg::*;
 pub mod memcpyopt;
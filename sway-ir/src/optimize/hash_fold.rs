@@ -0,0 +1,288 @@
+//! Compile-time evaluation of hashes.
+//!
+//! Storage keys are commonly derived from other, statically known keys by hashing them, e.g., the
+//! elements of a `StorageVec` are stored starting at the `sha256` hash of its field id.  Asm blocks
+//! consisting of a single `s256 hash ptr len` instruction and returning `hash` are replaced by the
+//! resulting `b256` constant if the `len` bytes at `ptr` are known at compile time, i.e., if `ptr`
+//! is either a constant or loaded from a local which is initialized by a single constant store
+//! dominating the load.
+//!
+//! Only asm blocks whose `hash` buffer is not read anywhere else are folded, as the buffer is no
+//! longer written to once the asm block is removed.
+
+use rustc_hash::FxHashMap;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    asm::{AsmArg, AsmBlock},
+    constant::{Constant, ConstantValue},
+    context::Context,
+    error::IrError,
+    function::Function,
+    instruction::InstOp,
+    local_var::LocalVar,
+    value::Value,
+    AnalysisResults, Block, DomTree, Instruction, Pass, PassMutability, ScopedPass,
+    DOMINATORS_NAME,
+};
+
+pub const HASH_FOLD_NAME: &str = "hash-fold";
+
+pub fn create_hash_fold_pass() -> Pass {
+    Pass {
+        name: HASH_FOLD_NAME,
+        descr: "Compile-time evaluation of hashes of constant data",
+        deps: vec![DOMINATORS_NAME],
+        runner: ScopedPass::FunctionPass(PassMutability::Transform(hash_fold)),
+    }
+}
+
+pub fn hash_fold(
+    context: &mut Context,
+    analyses: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let dom_tree: &DomTree = analyses.get_analysis_result(function);
+    let locals = LocalAccesses::collect(context, function);
+
+    let mut digests = Vec::new();
+    for (_, inst) in function.instruction_iter(context) {
+        let Some(Instruction {
+            op: InstOp::AsmBlock(asm_block, args),
+            ..
+        }) = inst.get_instruction(context)
+        else {
+            continue;
+        };
+        let Some((hash, data, len)) = match_s256_asm_block(context, asm_block, args) else {
+            continue;
+        };
+        if !hash.is_constant(context) && !locals.is_only_load(hash) {
+            continue;
+        }
+        let Some(len) = len.get_constant(context).and_then(Constant::as_uint) else {
+            continue;
+        };
+        let Some(data) = locals.known_value(context, dom_tree, data) else {
+            continue;
+        };
+        if let Some(digest) = const_s256(context, data, len) {
+            digests.push((inst, digest));
+        }
+    }
+    if digests.is_empty() {
+        return Ok(false);
+    }
+
+    let replacements: FxHashMap<Value, Value> = digests
+        .iter()
+        .map(|(inst, digest)| (*inst, Constant::get_b256(context, *digest)))
+        .collect();
+    function.replace_values(context, &replacements, None);
+    for (inst, _) in digests {
+        let block = inst.get_instruction(context).unwrap().parent;
+        block.remove_instruction(context, inst);
+    }
+
+    Ok(true)
+}
+
+/// Compute the `s256` hash of the first `len` bytes of the memory occupied by `data`.
+///
+/// Returns `None` if `data` is passed to asm blocks by value rather than by reference, if it is
+/// shorter than `len` bytes, or if its memory layout isn't supported, i.e., if it isn't made up of
+/// `u64`, `u256` and `b256` values only.
+pub fn const_s256(context: &Context, data: &Constant, len: u64) -> Option<[u8; 32]> {
+    if !matches!(
+        data.value,
+        ConstantValue::U256(_)
+            | ConstantValue::B256(_)
+            | ConstantValue::Struct(_)
+            | ConstantValue::Array(_)
+    ) {
+        return None;
+    }
+
+    fn append_bytes(context: &Context, constant: &Constant, bytes: &mut Vec<u8>) -> Option<()> {
+        match &constant.value {
+            ConstantValue::Uint(n) if constant.ty.is_uint64(context) => {
+                bytes.extend(n.to_be_bytes())
+            }
+            ConstantValue::U256(n) | ConstantValue::B256(n) => bytes.extend(n.to_be_bytes()),
+            ConstantValue::Struct(elems) | ConstantValue::Array(elems) => {
+                for elem in elems {
+                    append_bytes(context, elem, bytes)?;
+                }
+            }
+            _ => return None,
+        }
+        Some(())
+    }
+
+    let mut bytes = Vec::new();
+    append_bytes(context, data, &mut bytes)?;
+    let bytes = bytes.get(..usize::try_from(len).ok()?)?;
+    Some(Sha256::digest(bytes).into())
+}
+
+/// Match an asm block consisting of a single `s256 hash ptr len` instruction and returning
+/// `hash`.  Returns the initializers of `hash`, `ptr` and `len`.
+fn match_s256_asm_block(
+    context: &Context,
+    asm_block: &AsmBlock,
+    args: &[AsmArg],
+) -> Option<(Value, Value, Value)> {
+    let [inst] = asm_block.body.as_slice() else {
+        return None;
+    };
+    let [hash, ptr, len] = inst.args.as_slice() else {
+        return None;
+    };
+    if inst.op_name.as_str() != "s256"
+        || inst.immediate.is_some()
+        || asm_block.return_name.as_ref() != Some(hash)
+        || !asm_block.return_type.is_b256(context)
+    {
+        return None;
+    }
+
+    let initializer = |name| {
+        args.iter()
+            .find(|arg| arg.name == *name)
+            .and_then(|arg| arg.initializer)
+    };
+    Some((initializer(hash)?, initializer(ptr)?, initializer(len)?))
+}
+
+/// The loads and stores of a local.
+#[derive(Default)]
+struct LocalAccess {
+    /// The stored values.
+    stores: Vec<(Value, Value)>,
+    /// The loads, along with the constant indices of the element they load.
+    loads: Vec<(Value, Vec<u64>)>,
+    /// Is the local used in any other way, e.g., passed to a call or asm block?
+    escapes: bool,
+}
+
+/// The accesses to all the locals of a function.
+struct LocalAccesses {
+    locals: FxHashMap<LocalVar, LocalAccess>,
+    /// The local and the element indices every load instruction reads from.
+    loads: FxHashMap<Value, (LocalVar, Vec<u64>)>,
+}
+
+impl LocalAccesses {
+    fn collect(context: &Context, function: Function) -> Self {
+        let get_local = |value: &Value| match value.get_instruction(context) {
+            Some(Instruction {
+                op: InstOp::GetLocal(local),
+                ..
+            }) => Some(*local),
+            _ => None,
+        };
+
+        // Element pointers into locals at constant indices.
+        let elem_ptrs: FxHashMap<Value, (LocalVar, Vec<u64>)> = function
+            .instruction_iter(context)
+            .filter_map(|(_, inst)| match &inst.get_instruction(context)?.op {
+                InstOp::GetElemPtr { base, indices, .. } => {
+                    let indices = indices
+                        .iter()
+                        .map(|idx| idx.get_constant(context).and_then(Constant::as_uint))
+                        .collect::<Option<_>>()?;
+                    Some((inst, (get_local(base)?, indices)))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let mut locals: FxHashMap<LocalVar, LocalAccess> = FxHashMap::default();
+        let mut loads = FxHashMap::default();
+        for (_, inst) in function.instruction_iter(context) {
+            let op = &inst.get_instruction(context).unwrap().op;
+            for operand in op.get_operands() {
+                let (local, indices) = match get_local(&operand) {
+                    Some(local) => (local, Vec::new()),
+                    None => match elem_ptrs.get(&operand) {
+                        Some(elem_ptr) => elem_ptr.clone(),
+                        None => continue,
+                    },
+                };
+                let access = locals.entry(local).or_default();
+                match op {
+                    InstOp::Load(ptr) if *ptr == operand => {
+                        access.loads.push((inst, indices.clone()));
+                        loads.insert(inst, (local, indices));
+                    }
+                    InstOp::Store {
+                        dst_val_ptr,
+                        stored_val,
+                    } if *dst_val_ptr == operand
+                        && *stored_val != operand
+                        && indices.is_empty() =>
+                    {
+                        access.stores.push((inst, *stored_val));
+                    }
+                    InstOp::GetElemPtr { .. } if elem_ptrs.contains_key(&inst) => (),
+                    _ => access.escapes = true,
+                }
+            }
+        }
+
+        LocalAccesses { locals, loads }
+    }
+
+    /// Is `value` loaded from a local which isn't loaded anywhere else?
+    fn is_only_load(&self, value: Value) -> bool {
+        self.loads.get(&value).is_some_and(|(local, _)| {
+            let access = &self.locals[local];
+            !access.escapes && access.loads.len() == 1
+        })
+    }
+
+    /// Get the value of `value`, if it is either a constant or loaded from a local whose only
+    /// store is a constant dominating the load.
+    fn known_value<'a>(
+        &self,
+        context: &'a Context,
+        dom_tree: &DomTree,
+        value: Value,
+    ) -> Option<&'a Constant> {
+        if let Some(constant) = value.get_constant(context) {
+            return Some(constant);
+        }
+
+        let (local, indices) = self.loads.get(&value)?;
+        let access = &self.locals[local];
+        let [(store, stored_val)] = access.stores.as_slice() else {
+            return None;
+        };
+        if access.escapes || !dominates(context, dom_tree, *store, value) {
+            return None;
+        }
+        indices.iter().try_fold(
+            stored_val.get_constant(context)?,
+            |constant, idx| match &constant.value {
+                ConstantValue::Struct(elems) | ConstantValue::Array(elems) => {
+                    elems.get(usize::try_from(*idx).ok()?)
+                }
+                _ => None,
+            },
+        )
+    }
+}
+
+/// Is the instruction `dominator` executed before every execution of the instruction `dominatee`?
+fn dominates(context: &Context, dom_tree: &DomTree, dominator: Value, dominatee: Value) -> bool {
+    let parent = |inst: Value| -> Block { inst.get_instruction(context).unwrap().parent };
+    let (dominator_block, dominatee_block) = (parent(dominator), parent(dominatee));
+    if dominator_block != dominatee_block {
+        return dom_tree.contains(&dominatee_block)
+            && dom_tree.dominates(dominator_block, dominatee_block);
+    }
+    dominator_block
+        .instruction_iter(context)
+        .find(|inst| *inst == dominator || *inst == dominatee)
+        == Some(dominator)
+}
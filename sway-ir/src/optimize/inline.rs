@@ -16,7 +16,7 @@ use crate::{
     instruction::{FuelVmInstruction, InstOp},
     irtype::Type,
     local_var::LocalVar,
-    metadata::{combine, MetadataIndex},
+    metadata::{combine, MetadataIndex, Metadatum},
     value::{Value, ValueContent, ValueDatum},
     AnalysisResults, BlockArgument, Instruction, Module, Pass, PassMutability, ScopedPass,
 };
@@ -72,6 +72,42 @@ pub fn metadata_to_inline(context: &Context, md_idx: Option<MetadataIndex>) -> O
     })
 }
 
+/// Thresholds of the cost model used by the [fn_inline] pass.
+///
+/// Inlining a call removes the gas spent on setting up the call frame, passing the arguments
+/// and returning, but duplicates the body of the callee at the call site. A function is inlined
+/// if the gas saved over all of its call sites outweighs the resulting bytecode size increase.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InlineHeuristics {
+    /// Estimated gas cost of setting up a call frame, jumping to the callee and returning.
+    pub call_gas_cost: u64,
+    /// Estimated gas cost of passing a single argument to the callee.
+    pub arg_gas_cost: u64,
+    /// The gas equivalent of a single instruction added to the bytecode by inlining.
+    pub instruction_size_cost: u64,
+    /// Functions with at most this many instructions are always inlined, as their body is
+    /// about as large as the call itself.
+    pub max_trivial_instructions: u64,
+    /// If true, calls which are not inlined get annotated with the reason in their metadata,
+    /// which makes the decisions visible in the printed IR.
+    pub annotate_decisions: bool,
+}
+
+impl Default for InlineHeuristics {
+    fn default() -> Self {
+        Self {
+            call_gas_cost: 10,
+            arg_gas_cost: 2,
+            instruction_size_cost: 4,
+            max_trivial_instructions: 4,
+            annotate_decisions: false,
+        }
+    }
+}
+
+/// The metadata tag used to annotate calls which were not inlined.
+pub const INLINE_DECISION_MD_TAG: &str = "inline_decision";
+
 pub fn fn_inline(
     context: &mut Context,
     _: &AnalysisResults,
@@ -97,50 +133,111 @@ pub fn fn_inline(
                 counts
             });
 
+    let cg =
+        call_graph::build_call_graph(context, &module.function_iter(context).collect::<Vec<_>>());
+    let heuristics = context.inline_heuristics;
+
     let inline_heuristic = |ctx: &Context, func: &Function, _call_site: &Value| {
-        // The encoding code in the `__entry` functions contains pointer patterns that mark
-        // escape analysis and referred symbols as incomplete. This effectively forbids optimizations
-        // like SROA nad DCE. If we inline original entries, like e.g., `main`, the code in them will
-        // also not be optimized. Therefore, we forbid inlining of original entries into `__entry`.
-        if func.is_original_entry(ctx) {
-            return false;
-        }
+        inline_decision(ctx, &heuristics, &call_counts, &cg, func).is_ok()
+    };
 
-        let attributed_inline = metadata_to_inline(ctx, func.get_metadata(ctx));
-        match attributed_inline {
-            Some(Inline::Always) => {
-                // TODO: check if inlining of function is possible
-                // return true;
-            }
-            Some(Inline::Never) => {
-                return false;
+    let functions = call_graph::callee_first_order(&cg);
+    let mut modified = false;
+
+    for function in &functions {
+        modified |= inline_some_function_calls(context, function, inline_heuristic)?;
+    }
+
+    if heuristics.annotate_decisions {
+        for function in &functions {
+            let calls = function
+                .instruction_iter(context)
+                .filter_map(
+                    |(_block, call_val)| match call_val.get_instruction(context) {
+                        Some(Instruction {
+                            op: InstOp::Call(callee, _),
+                            ..
+                        }) => Some((call_val, *callee)),
+                        _ => None,
+                    },
+                )
+                .collect::<Vec<_>>();
+            for (call_val, callee) in calls {
+                if let Err(reason) =
+                    inline_decision(context, &heuristics, &call_counts, &cg, &callee)
+                {
+                    let decision_md = MetadataIndex::new_struct(
+                        context,
+                        INLINE_DECISION_MD_TAG,
+                        vec![Metadatum::String(format!("not inlined: {reason}"))],
+                    );
+                    call_val.add_metadatum(context, Some(decision_md));
+                }
             }
-            None => {}
         }
+    }
 
-        // If the function is called only once then definitely inline it.
-        if call_counts.get(func).copied().unwrap_or(0) == 1 {
-            return true;
-        }
+    Ok(modified)
+}
+
+/// Decides whether the calls to `func` should be inlined.
+/// Returns the reason if they should not.
+fn inline_decision(
+    ctx: &Context,
+    heuristics: &InlineHeuristics,
+    call_counts: &HashMap<Function, u64>,
+    cg: &call_graph::CallGraph,
+    func: &Function,
+) -> Result<(), String> {
+    // The encoding code in the `__entry` functions contains pointer patterns that mark
+    // escape analysis and referred symbols as incomplete. This effectively forbids optimizations
+    // like SROA nad DCE. If we inline original entries, like e.g., `main`, the code in them will
+    // also not be optimized. Therefore, we forbid inlining of original entries into `__entry`.
+    if func.is_original_entry(ctx) {
+        return Err("original entry".to_string());
+    }
 
-        // If the function is (still) small then also inline it.
-        const MAX_INLINE_INSTRS_COUNT: usize = 4;
-        if func.num_instructions_incl_asm_instructions(ctx) <= MAX_INLINE_INSTRS_COUNT {
-            return true;
+    let attributed_inline = metadata_to_inline(ctx, func.get_metadata(ctx));
+    match attributed_inline {
+        Some(Inline::Always) => {
+            // Inlining a recursive function would never terminate.
+            return if call_graph::is_recursive(cg, func) {
+                Err("recursive".to_string())
+            } else {
+                Ok(())
+            };
+        }
+        Some(Inline::Never) => {
+            return Err("#[inline(never)]".to_string());
         }
+        None => {}
+    }
 
-        false
-    };
+    // If the function is called only once then definitely inline it.
+    let call_count = call_counts.get(func).copied().unwrap_or(0);
+    if call_count == 1 {
+        return Ok(());
+    }
 
-    let cg =
-        call_graph::build_call_graph(context, &module.function_iter(context).collect::<Vec<_>>());
-    let functions = call_graph::callee_first_order(&cg);
-    let mut modified = false;
+    // If the function is (still) small then also inline it.
+    let num_instructions = func.num_instructions_incl_asm_instructions(ctx) as u64;
+    if num_instructions <= heuristics.max_trivial_instructions {
+        return Ok(());
+    }
 
-    for function in functions {
-        modified |= inline_some_function_calls(context, &function, inline_heuristic)?;
+    // Otherwise, weigh the gas saved at all the call sites against the size of the copies
+    // of the body, the original body being replaced by the first copy.
+    let gas_saved = call_count
+        * (heuristics.call_gas_cost + heuristics.arg_gas_cost * func.num_args(ctx) as u64);
+    let size_cost =
+        num_instructions * call_count.saturating_sub(1) * heuristics.instruction_size_cost;
+    if gas_saved >= size_cost {
+        Ok(())
+    } else {
+        Err(format!(
+            "gas saved {gas_saved} is less than size cost {size_cost}"
+        ))
     }
-    Ok(modified)
 }
 
 /// Inline all calls made from a specific function, effectively removing all `Call` instructions.
@@ -0,0 +1,239 @@
+//! Loop strength reduction.
+//!
+//! Expressions recomputed from a basic induction variable on every iteration of a loop are
+//! replaced by new induction variables, which are updated incrementally in the latch:
+//!
+//! - `mul i, C` becomes an induction variable starting at `start * C` and stepped by `step * C`.
+//!   As the new variable is also updated after the last iteration, this is only done if the
+//!   trip count of the loop is known and the multiplication can't overflow.
+//! - `get_elem_ptr base, T, i`, indexing into a loop invariant array, becomes a pointer starting
+//!   at `get_elem_ptr base, T, start` and advanced by `step` elements.
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    block::Block,
+    constant::Constant,
+    context::Context,
+    error::IrError,
+    function::Function,
+    instruction::{BinaryOpKind, InsertionPosition, InstOp, InstructionInserter},
+    irtype::Type,
+    value::Value,
+    AnalysisResults, InductionVariable, Instruction, Loop, Loops, Pass, PassMutability, ScopedPass,
+    LOOPS_NAME,
+};
+
+pub const LOOP_REDUCE_NAME: &str = "loop-reduce";
+
+pub fn create_loop_reduce_pass() -> Pass {
+    Pass {
+        name: LOOP_REDUCE_NAME,
+        descr: "Loop strength reduction",
+        deps: vec![LOOPS_NAME],
+        runner: ScopedPass::FunctionPass(PassMutability::Transform(loop_reduce)),
+    }
+}
+
+/// Loops are only analysed up to this many iterations when checking for overflows.
+const MAX_ANALYSED_TRIP_COUNT: u64 = 1 << 16;
+
+pub fn loop_reduce(
+    context: &mut Context,
+    analyses: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let loops: &Loops = analyses.get_analysis_result(function);
+
+    // The transformation only adds header arguments and instructions, the loops stay intact.
+    let mut modified = false;
+    for lp in loops.iter() {
+        modified |= reduce_loop(context, function, lp);
+    }
+
+    Ok(modified)
+}
+
+/// An expression which can be computed incrementally.
+enum Reducible {
+    /// `mul iv, factor`
+    Mul { factor: u64 },
+    /// `get_elem_ptr base, elem_ty, iv`
+    ElemPtr {
+        base: Value,
+        elem_ty: Type,
+        elem_ptr_ty: Type,
+    },
+}
+
+fn reduce_loop(context: &mut Context, function: Function, lp: &Loop) -> bool {
+    let ivs = lp.induction_variables(context);
+    if ivs.is_empty() {
+        return false;
+    }
+    let trip_count = lp.trip_count(context, MAX_ANALYSED_TRIP_COUNT);
+
+    // The largest value each induction variable is going to reach, if known.
+    let max_value = |iv: &InductionVariable| {
+        let start = iv.start.get_constant(context)?.as_uint()?;
+        match iv.step_op {
+            BinaryOpKind::Add => iv.value_after(start, trip_count?),
+            _ => Some(start),
+        }
+    };
+
+    let mut candidates = Vec::new();
+    for block in &lp.blocks {
+        for inst in block.instruction_iter(context) {
+            match &inst.get_instruction(context).unwrap().op {
+                InstOp::BinaryOp {
+                    op: BinaryOpKind::Mul,
+                    arg1,
+                    arg2,
+                } => {
+                    let Some((iv, factor)) = ivs.iter().find_map(|iv| {
+                        let other = if *arg1 == iv.value {
+                            arg2
+                        } else if *arg2 == iv.value {
+                            arg1
+                        } else {
+                            return None;
+                        };
+                        Some((iv, other.get_constant(context)?.as_uint()?))
+                    }) else {
+                        continue;
+                    };
+                    if max_value(iv)
+                        .and_then(|max| max.checked_mul(factor))
+                        .is_some()
+                        && iv.step.checked_mul(factor).is_some()
+                    {
+                        candidates.push((inst, iv, Reducible::Mul { factor }));
+                    }
+                }
+                InstOp::GetElemPtr {
+                    base,
+                    elem_ptr_ty,
+                    indices,
+                } => {
+                    let [index] = indices.as_slice() else {
+                        continue;
+                    };
+                    let Some(iv) = ivs.iter().find(|iv| iv.value == *index) else {
+                        continue;
+                    };
+                    let is_array_base = base
+                        .match_ptr_type(context)
+                        .is_some_and(|ty| ty.is_array(context));
+                    if is_array_base && lp.is_invariant(context, base) {
+                        let elem_ty = elem_ptr_ty.get_pointee_type(context).unwrap();
+                        let elem_size = elem_ty.size(context).in_bytes();
+                        if iv.step.checked_mul(elem_size).is_some() {
+                            candidates.push((
+                                inst,
+                                iv,
+                                Reducible::ElemPtr {
+                                    base: *base,
+                                    elem_ty,
+                                    elem_ptr_ty: *elem_ptr_ty,
+                                },
+                            ));
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+    if candidates.is_empty() {
+        return false;
+    }
+
+    let preheader = lp.preheader(context).unwrap();
+    let latch = lp.latch().unwrap();
+
+    // Equal expressions share the same new induction variable.
+    let mut mul_ivs: FxHashMap<(Value, u64), Value> = FxHashMap::default();
+    let mut elem_ptr_ivs: FxHashMap<(Value, Value), Value> = FxHashMap::default();
+    let mut replacements = FxHashMap::default();
+    for (inst, iv, reducible) in candidates {
+        let new_iv = match reducible {
+            Reducible::Mul { factor } => *mul_ivs.entry((iv.value, factor)).or_insert_with(|| {
+                let start = iv.start.get_constant(context).unwrap().as_uint().unwrap();
+                let start = Constant::get_uint(context, 64, start * factor);
+                let step = Constant::get_uint(context, 64, iv.step * factor);
+                let ty = Type::get_uint64(context);
+                add_induction_variable(context, lp, ty, start, |context, new_iv| {
+                    before_terminator(context, latch).binary_op(iv.step_op, new_iv, step)
+                })
+            }),
+            Reducible::ElemPtr {
+                base,
+                elem_ty,
+                elem_ptr_ty,
+            } => *elem_ptr_ivs.entry((iv.value, base)).or_insert_with(|| {
+                let start = before_terminator(context, preheader).get_elem_ptr(
+                    base,
+                    elem_ty,
+                    vec![iv.start],
+                );
+                let step = iv.step * elem_ty.size(context).in_bytes();
+                let step = Constant::get_uint(context, 64, step);
+                add_induction_variable(context, lp, elem_ptr_ty, start, |context, new_iv| {
+                    let u64_ty = Type::get_uint64(context);
+                    let addr = before_terminator(context, latch).ptr_to_int(new_iv, u64_ty);
+                    let next_addr =
+                        before_terminator(context, latch).binary_op(iv.step_op, addr, step);
+                    before_terminator(context, latch).int_to_ptr(next_addr, elem_ptr_ty)
+                })
+            }),
+        };
+        replacements.insert(inst, new_iv);
+    }
+
+    // Replace the expressions, which are now dead.
+    function.replace_values(context, &replacements, None);
+    for inst in replacements.keys() {
+        let block = inst.get_instruction(context).unwrap().parent;
+        block.remove_instruction(context, *inst);
+    }
+
+    true
+}
+
+/// Add a header argument of type `ty` to `lp`, starting at `start` and updated to the value
+/// built by `make_next` in the latch.  Returns the header argument.
+fn add_induction_variable(
+    context: &mut Context,
+    lp: &Loop,
+    ty: Type,
+    start: Value,
+    make_next: impl FnOnce(&mut Context, Value) -> Value,
+) -> Value {
+    let header = lp.header;
+    let idx = header.new_arg(context, ty);
+    let new_iv = header.get_arg(context, idx).unwrap();
+
+    let preheader = lp.preheader(context).unwrap();
+    preheader
+        .get_succ_params_mut(context, &header)
+        .unwrap()
+        .push(start);
+
+    let latch = lp.latch().unwrap();
+    let next = make_next(context, new_iv);
+    latch
+        .get_succ_params_mut(context, &header)
+        .unwrap()
+        .push(next);
+
+    new_iv
+}
+
+fn before_terminator<'a, 'eng>(
+    context: &'a mut Context<'eng>,
+    block: Block,
+) -> InstructionInserter<'a, 'eng> {
+    let pos = block.num_instructions(context) - 1;
+    InstructionInserter::new(context, block, InsertionPosition::At(pos))
+}
@@ -0,0 +1,348 @@
+//! Loop unrolling.
+//!
+//! Loops whose trip count is known at compile time are unrolled.  A loop is _counted_ if its
+//! header is the only block exiting it and the exit condition compares an induction variable, a
+//! header block argument starting from a constant and stepped by a constant on every iteration,
+//! against a constant bound.
+//!
+//! - Loops with a small trip count are fully unrolled, i.e., replaced by a straight sequence of
+//!   copies of their body.
+//! - Loops with a larger trip count divisible by the unroll factor are partially unrolled, i.e.,
+//!   their body is repeated so that the exit condition is only evaluated once every few
+//!   iterations.
+//!
+//! The header condition of the copies is left in place but no longer branched on, so that
+//! subsequent constant folding, DCE and CFG simplification can clean up after this pass.
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    block::Block, context::Context, error::IrError, function::Function, instruction::InstOp,
+    value::Value, AnalysisResults, BranchToWithArgs, Instruction, Loop, Loops, Pass,
+    PassMutability, ScopedPass, LOOPS_NAME,
+};
+
+pub const LOOP_UNROLL_NAME: &str = "loop-unroll";
+
+pub fn create_loop_unroll_pass() -> Pass {
+    Pass {
+        name: LOOP_UNROLL_NAME,
+        descr: "Loop unrolling",
+        deps: vec![LOOPS_NAME],
+        runner: ScopedPass::FunctionPass(PassMutability::Transform(loop_unroll)),
+    }
+}
+
+/// Thresholds used by the [loop_unroll] pass.
+///
+/// Unrolling removes the branches and the induction variable updates between iterations and
+/// exposes the constant induction variable values to further optimization, at the cost of
+/// duplicating the loop body.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LoopUnrollHeuristics {
+    /// Loops with at most this many iterations are fully unrolled.
+    pub max_full_unroll_trip_count: u64,
+    /// The number of copies of the body of a partially unrolled loop.  Loops are only partially
+    /// unrolled if their trip count is a multiple of this factor.  A factor below 2 disables
+    /// partial unrolling.
+    pub partial_unroll_factor: u64,
+    /// The maximum number of instructions an unrolled loop may consist of.
+    pub max_unrolled_instructions: u64,
+}
+
+impl Default for LoopUnrollHeuristics {
+    fn default() -> Self {
+        Self {
+            max_full_unroll_trip_count: 16,
+            partial_unroll_factor: 4,
+            max_unrolled_instructions: 256,
+        }
+    }
+}
+
+/// Loops are only analysed up to this many iterations, longer running loops are never unrolled.
+const MAX_ANALYSED_TRIP_COUNT: u64 = 1 << 16;
+
+pub fn loop_unroll(
+    context: &mut Context,
+    analyses: &AnalysisResults,
+    function: Function,
+) -> Result<bool, IrError> {
+    let loops: &Loops = analyses.get_analysis_result(function);
+    let heuristics = context.loop_unroll_heuristics;
+
+    // Innermost loops are disjoint, unrolling one of them doesn't invalidate the others.
+    let mut modified = false;
+    for lp in loops.innermost() {
+        let Some(counted) = analyse_counted_loop(context, lp) else {
+            continue;
+        };
+
+        let loop_size = lp
+            .blocks
+            .iter()
+            .map(|block| block.num_instructions(context) as u64)
+            .sum::<u64>();
+
+        // The header is evaluated once more than the body when fully unrolling.
+        let full_unroll_size = loop_size.saturating_mul(counted.trip_count + 1);
+        let factor = heuristics.partial_unroll_factor;
+        if counted.trip_count <= heuristics.max_full_unroll_trip_count
+            && full_unroll_size <= heuristics.max_unrolled_instructions
+        {
+            fully_unroll(context, function, lp, &counted)?;
+            modified = true;
+        } else if factor > 1
+            && counted.trip_count % factor == 0
+            && loop_size.saturating_mul(factor) <= heuristics.max_unrolled_instructions
+        {
+            partially_unroll(context, function, lp, &counted, factor);
+            modified = true;
+        }
+    }
+
+    Ok(modified)
+}
+
+/// A loop with a known trip count.
+struct CountedLoop {
+    /// The only block outside of the loop branching to the header.
+    preheader: Block,
+    /// The only block inside of the loop branching to the header.
+    latch: Block,
+    /// The number of times the body of the loop is executed.
+    trip_count: u64,
+}
+
+fn analyse_counted_loop(context: &Context, lp: &Loop) -> Option<CountedLoop> {
+    let preheader = lp.preheader(context)?;
+    let latch = lp.latch()?;
+
+    // Only the header may leave the loop, every other block either stays within the loop or
+    // returns from the function.
+    if lp
+        .blocks
+        .iter()
+        .filter(|block| **block != lp.header)
+        .flat_map(|block| block.successors(context))
+        .any(|BranchToWithArgs { block, .. }| !lp.contains(&block))
+    {
+        return None;
+    }
+
+    let trip_count = lp.trip_count(context, MAX_ANALYSED_TRIP_COUNT)?;
+
+    Some(CountedLoop {
+        preheader,
+        latch,
+        trip_count,
+    })
+}
+
+/// Replace the loop by `trip_count` copies of its body.  The original header is kept as the
+/// final exit check, so that the values it defines remain available after the loop.
+fn fully_unroll(
+    context: &mut Context,
+    function: Function,
+    lp: &Loop,
+    counted: &CountedLoop,
+) -> Result<(), IrError> {
+    let header = lp.header;
+
+    // Create the iterations last to first, each of them branching to the next one.
+    let mut next_header = header;
+    for _ in 0..counted.trip_count {
+        next_header = clone_iteration(context, function, lp, next_header, Some(next_header));
+    }
+    if next_header != header {
+        let params = counted.preheader.get_succ_params(context, &header);
+        counted
+            .preheader
+            .replace_successor(context, header, next_header, params);
+    }
+
+    // The original header now always exits the loop.
+    let Some(Instruction {
+        op:
+            InstOp::ConditionalBranch {
+                true_block,
+                false_block,
+                ..
+            },
+        ..
+    }) = header.get_terminator(context).cloned()
+    else {
+        unreachable!("The header of a counted loop ends with a conditional branch.")
+    };
+    let (body_target, exit_target) = if lp.contains(&true_block.block) {
+        (true_block, false_block)
+    } else {
+        (false_block, true_block)
+    };
+    header.remove_last_instruction(context);
+    body_target.block.remove_pred(context, &header);
+    header
+        .append(context)
+        .branch(exit_target.block, exit_target.args);
+
+    // The original body is now unreachable.
+    header.remove_pred(context, &counted.latch);
+    for block in lp.blocks.iter().filter(|block| **block != header) {
+        function.remove_block(context, block)?;
+    }
+
+    Ok(())
+}
+
+/// Repeat the body of the loop `factor` times, only checking the exit condition every `factor`
+/// iterations.  The trip count must be a multiple of `factor`.
+fn partially_unroll(
+    context: &mut Context,
+    function: Function,
+    lp: &Loop,
+    counted: &CountedLoop,
+    factor: u64,
+) {
+    let header = lp.header;
+
+    // The copies go after the loop.
+    let last_block = *lp.blocks.last().unwrap();
+    let mut insert_before = function
+        .block_iter(context)
+        .skip_while(|block| *block != last_block)
+        .nth(1);
+
+    // Create the copies last to first, each of them branching to the next one.
+    let mut next_header = header;
+    for _ in 1..factor {
+        next_header = clone_iteration(context, function, lp, next_header, insert_before);
+        insert_before = Some(next_header);
+    }
+    let params = counted.latch.get_succ_params(context, &header);
+    counted
+        .latch
+        .replace_successor(context, header, next_header, params);
+}
+
+/// Clone all the blocks of the loop as a single iteration, which unconditionally enters the body
+/// from its header and branches to `next_header` from its latch.  Returns the cloned header.
+fn clone_iteration(
+    context: &mut Context,
+    function: Function,
+    lp: &Loop,
+    next_header: Block,
+    insert_before: Option<Block>,
+) -> Block {
+    let header = lp.header;
+
+    // Create the blocks and their arguments.  The header is created first so that it can be
+    // used as the insertion point of the next iteration.
+    let mut block_map: FxHashMap<Block, Block> = FxHashMap::default();
+    let mut value_map: FxHashMap<Value, Value> = FxHashMap::default();
+    for block in std::iter::once(&header).chain(lp.blocks.iter().filter(|b| **b != header)) {
+        let label = Some(block.get_label(context));
+        let new_block = match insert_before {
+            Some(insert_before) => function
+                .create_block_before(context, &insert_before, label)
+                .unwrap(),
+            None => function.create_block(context, label),
+        };
+        let args: Vec<Value> = block.arg_iter(context).copied().collect();
+        for arg in args {
+            let ty = arg.get_type(context).unwrap();
+            let idx = new_block.new_arg(context, ty);
+            value_map.insert(arg, new_block.get_arg(context, idx).unwrap());
+        }
+        block_map.insert(*block, new_block);
+    }
+
+    // Copy the instructions.  A block may refer to values of blocks which are copied later, so
+    // the values are only mapped once all of them have been copied.
+    let mut new_instructions = Vec::new();
+    for block in &lp.blocks {
+        let new_block = block_map[block];
+        for inst in block.instruction_iter(context) {
+            let Some(Instruction { op, .. }) = inst.get_instruction(context).cloned() else {
+                continue;
+            };
+            if matches!(op, InstOp::Branch(_) | InstOp::ConditionalBranch { .. }) {
+                continue;
+            }
+            let metadata = inst.get_metadata(context);
+            let new_inst =
+                Value::new_instruction(context, new_block, op).add_metadatum(context, metadata);
+            new_block.append(context).insert(new_inst);
+            value_map.insert(inst, new_inst);
+            new_instructions.push(new_inst);
+        }
+    }
+    for new_inst in new_instructions {
+        new_inst.replace_instruction_values(context, &value_map);
+    }
+
+    // Copy the branches, redirecting the back edge to the next iteration.
+    let map_value = |value: &Value| value_map.get(value).copied().unwrap_or(*value);
+    let map_branch = |branch: &BranchToWithArgs| {
+        let target = if branch.block == header {
+            next_header
+        } else {
+            block_map[&branch.block]
+        };
+        (
+            target,
+            branch.args.iter().map(map_value).collect::<Vec<_>>(),
+        )
+    };
+    for block in &lp.blocks {
+        let new_block = block_map[block];
+        match block.get_terminator(context).cloned() {
+            Some(Instruction {
+                op: InstOp::Branch(branch),
+                ..
+            }) => {
+                let (target, args) = map_branch(&branch);
+                new_block.append(context).branch(target, args);
+            }
+            Some(Instruction {
+                op:
+                    InstOp::ConditionalBranch {
+                        true_block,
+                        false_block,
+                        ..
+                    },
+                ..
+            }) if *block == header => {
+                // The copied header always enters the body.
+                let body = if lp.contains(&true_block.block) {
+                    true_block
+                } else {
+                    false_block
+                };
+                let (target, args) = map_branch(&body);
+                new_block.append(context).branch(target, args);
+            }
+            Some(Instruction {
+                op:
+                    InstOp::ConditionalBranch {
+                        cond_value,
+                        true_block,
+                        false_block,
+                    },
+                ..
+            }) => {
+                let (true_target, true_args) = map_branch(&true_block);
+                let (false_target, false_args) = map_branch(&false_block);
+                new_block.append(context).conditional_branch(
+                    map_value(&cond_value),
+                    true_target,
+                    false_target,
+                    true_args,
+                    false_args,
+                );
+            }
+            _ => (),
+        }
+    }
+
+    block_map[&header]
+}
@@ -56,6 +56,7 @@ mod ir_builder {
             rule module_kind() -> Kind
                 = "script" _ { Kind::Script }
                 / "predicate" _ { Kind::Predicate }
+                / "library" _ { Kind::Library }
 
             rule contract() -> IrAstModule
                 = "contract" _ "{" _
@@ -226,10 +227,12 @@ mod ir_builder {
                 / op_nop()
                 / op_ptr_to_int()
                 / op_read_register()
+                / op_retd()
                 / op_ret()
                 / op_revert()
                 / op_jmp_mem()
                 / op_smo()
+                / op_state_clear()
                 / op_state_load_quad_word()
                 / op_state_load_word()
                 / op_state_store_quad_word()
@@ -388,6 +391,11 @@ mod ir_builder {
                     IrAstOperation::Ret(ty, vn)
                 }
 
+            rule op_retd() -> IrAstOperation
+                = "retd" _ ptr:id() len:id() {
+                    IrAstOperation::Retd(ptr, len)
+                }
+
             rule op_revert() -> IrAstOperation
                 = "revert" _ vn:id() {
                     IrAstOperation::Revert(vn)
@@ -494,13 +502,17 @@ mod ir_builder {
                 = "()" _ { IrAstConstValue::Unit }
                 / "true" _ { IrAstConstValue::Bool(true) }
                 / "false" _ { IrAstConstValue::Bool(false) }
-                / "0x" s:$(hex_digit()*<64>) _ {
+                / "0x" s:$(hex_digit()*<64>) !hex_digit() _ {
                     IrAstConstValue::Hex256(string_to_hex::<32>(s))
                 }
+                / "0x" s:$(hex_digit()*) _ {
+                    IrAstConstValue::Bytes(hex_string_to_vec(s))
+                }
                 / n:decimal() { IrAstConstValue::Number(n) }
                 / string_const()
                 / array_const()
                 / struct_const()
+                / reference_const()
 
             rule string_const() -> IrAstConstValue
                 = ['"'] chs:str_char()* ['"'] _ {
@@ -531,10 +543,8 @@ mod ir_builder {
                 }
 
             rule array_const() -> IrAstConstValue
-                = "[" _ els:(field_or_element_const() ++ comma()) "]" _ {
-                    let el_ty = els[0].0.clone();
-                    let els = els.into_iter().map(|(_, cv)| cv).collect::<Vec<_>>();
-                    IrAstConstValue::Array(el_ty, els)
+                = "[" _ els:(field_or_element_const() ** comma()) "]" _ {
+                    IrAstConstValue::Array(els)
                 }
 
             rule struct_const() -> IrAstConstValue
@@ -542,6 +552,11 @@ mod ir_builder {
                     IrAstConstValue::Struct(flds)
                 }
 
+            rule reference_const() -> IrAstConstValue
+                = "&(" _ ty:ast_ty() cv:constant() ")" _ {
+                    IrAstConstValue::Reference(Box::new((ty, cv)))
+                }
+
             rule field_or_element_const() -> (IrAstTy, IrAstConst)
                 = ty:ast_ty() cv:constant() {
                     (ty, cv)
@@ -552,13 +567,18 @@ mod ir_builder {
 
             rule ast_ty() -> IrAstTy
                 = ("unit" / "()") _ { IrAstTy::Unit }
+                / "never" !id_char() _ { IrAstTy::Never }
                 / "bool" _ { IrAstTy::Bool }
                 / "u8" _ { IrAstTy::U8 }
+                / "u16" _ { IrAstTy::U16 }
+                / "u32" _ { IrAstTy::U32 }
                 / "u64" _ { IrAstTy::U64 }
                 / "u256" _ { IrAstTy::U256 }
                 / "b256" _ { IrAstTy::B256 }
                 / "slice" _ { IrAstTy::Slice }
+                / "__slice" _ "[" _ ty:ast_ty() "]" _ { IrAstTy::TypedSlice(Box::new(ty)) }
                 / "string" _ "<" _ sz:decimal() ">" _ { IrAstTy::String(sz) }
+                / "str" !id_char() _ { IrAstTy::StringSlice }
                 / array_ty()
                 / struct_ty()
                 / union_ty()
@@ -620,9 +640,9 @@ mod ir_builder {
                 / "!" idx:dec_digits() __ {
                     IrMetadatum::Index(idx)
                 }
-                / ['"'] s:$(([^ '"' | '\\'] / ['\\'] ['\\' | '"' ])+) ['"'] __ {
-                    // Metadata strings are printed with '\\' escaped on parsing we unescape it.
-                    IrMetadatum::String(s.to_owned().replace("\\\\", "\\"))
+                / ['"'] s:$(([^ '"' | '\\'] / ['\\'] [_])*) ['"'] __ {?
+                    // Metadata strings are printed escaped, the way Rust debug prints them.
+                    unescape_md_string(s).map(IrMetadatum::String).ok_or("metadata string")
                 }
                 / tag:$(id_char0() id_char()*) __ els:metadata_item()* {
                     IrMetadatum::Struct(tag.to_owned(), els)
@@ -760,6 +780,7 @@ mod ir_builder {
         PtrToInt(String, IrAstTy),
         ReadRegister(String),
         Ret(IrAstTy, String),
+        Retd(String, String),
         Revert(String),
         JmpMem,
         Smo(String, String, String, String),
@@ -798,8 +819,10 @@ mod ir_builder {
         Hex256([u8; 32]),
         Number(u64),
         String(Vec<u8>),
-        Array(IrAstTy, Vec<IrAstConst>),
+        Bytes(Vec<u8>),
+        Array(Vec<(IrAstTy, IrAstConst)>),
         Struct(Vec<(IrAstTy, IrAstConst)>),
+        Reference(Box<(IrAstTy, IrAstConst)>),
     }
 
     #[derive(Debug)]
@@ -831,16 +854,21 @@ mod ir_builder {
                         let value = B256::from_be_bytes(bs);
                         ConstantValue::B256(value)
                     }
+                    IrAstTy::Slice => ConstantValue::RawUntypedSlice(bs.to_vec()),
                     _ => unreachable!("invalid type for hex number"),
                 },
                 IrAstConstValue::Number(n) => ConstantValue::Uint(*n),
                 IrAstConstValue::String(bs) => ConstantValue::String(bs.clone()),
-                IrAstConstValue::Array(el_ty, els) => {
+                IrAstConstValue::Bytes(bs) => ConstantValue::RawUntypedSlice(bs.clone()),
+                IrAstConstValue::Array(els) => {
                     let els: Vec<_> = els
                         .iter()
-                        .map(|cv| cv.value.as_constant(context, el_ty.clone()))
+                        .map(|(ty, cv)| cv.value.as_constant(context, ty.clone()))
                         .collect();
-                    ConstantValue::Array(els)
+                    match val_ty {
+                        IrAstTy::TypedSlice(_) => ConstantValue::Slice(els),
+                        _ => ConstantValue::Array(els),
+                    }
                 }
                 IrAstConstValue::Struct(flds) => {
                     let fields: Vec<_> = flds
@@ -849,6 +877,10 @@ mod ir_builder {
                         .collect::<Vec<_>>();
                     ConstantValue::Struct(fields)
                 }
+                IrAstConstValue::Reference(referenced) => {
+                    let (ty, cv) = referenced.as_ref();
+                    ConstantValue::Reference(Box::new(cv.value.as_constant(context, ty.clone())))
+                }
             }
         }
 
@@ -870,14 +902,24 @@ mod ir_builder {
                         Constant::get_uint256(context, n)
                     }
                     IrAstTy::B256 => Constant::get_b256(context, *bs),
+                    IrAstTy::Slice => {
+                        let slice_const = self.as_constant(context, val_ty);
+                        Value::new_constant(context, slice_const)
+                    }
                     _ => unreachable!("invalid type for hex number"),
                 },
                 IrAstConstValue::Number(n) => match val_ty {
                     IrAstTy::U8 => Constant::get_uint(context, 8, *n),
+                    IrAstTy::U16 => Constant::get_uint(context, 16, *n),
+                    IrAstTy::U32 => Constant::get_uint(context, 32, *n),
                     IrAstTy::U64 => Constant::get_uint(context, 64, *n),
                     _ => unreachable!(),
                 },
                 IrAstConstValue::String(s) => Constant::get_string(context, s.clone()),
+                IrAstConstValue::Array(..) if matches!(val_ty, IrAstTy::TypedSlice(_)) => {
+                    let slice_const = self.as_constant(context, val_ty);
+                    Value::new_constant(context, slice_const)
+                }
                 IrAstConstValue::Array(..) => {
                     let array_const = self.as_constant(context, val_ty);
                     Constant::get_array(context, array_const)
@@ -886,20 +928,29 @@ mod ir_builder {
                     let struct_const = self.as_constant(context, val_ty);
                     Constant::get_struct(context, struct_const)
                 }
+                IrAstConstValue::Bytes(_) | IrAstConstValue::Reference(_) => {
+                    let new_const = self.as_constant(context, val_ty);
+                    Value::new_constant(context, new_const)
+                }
             }
         }
     }
 
     #[derive(Clone, Debug)]
     enum IrAstTy {
+        Never,
         Unit,
         Bool,
         U8,
+        U16,
+        U32,
         U64,
         U256,
         B256,
         Slice,
+        TypedSlice(Box<IrAstTy>),
         String(u64),
+        StringSlice,
         Array(Box<IrAstTy>, u64),
         Union(Vec<IrAstTy>),
         Struct(Vec<IrAstTy>),
@@ -909,14 +960,22 @@ mod ir_builder {
     impl IrAstTy {
         fn to_ir_type(&self, context: &mut Context) -> Type {
             match self {
+                IrAstTy::Never => Type::get_never(context),
                 IrAstTy::Unit => Type::get_unit(context),
                 IrAstTy::Bool => Type::get_bool(context),
                 IrAstTy::U8 => Type::get_uint8(context),
+                IrAstTy::U16 => Type::get_uint16(context),
+                IrAstTy::U32 => Type::get_uint32(context),
                 IrAstTy::U64 => Type::get_uint64(context),
                 IrAstTy::U256 => Type::get_uint256(context),
                 IrAstTy::B256 => Type::get_b256(context),
                 IrAstTy::Slice => Type::get_slice(context),
+                IrAstTy::TypedSlice(el_ty) => {
+                    let el_ty = el_ty.to_ir_type(context);
+                    Type::get_typed_slice(context, el_ty)
+                }
                 IrAstTy::String(n) => Type::new_string_array(context, *n),
+                IrAstTy::StringSlice => Type::get_string_slice(context),
                 IrAstTy::Array(el_ty, count) => {
                     let el_ty = el_ty.to_ir_type(context);
                     Type::new_array(context, el_ty, *count)
@@ -955,8 +1014,9 @@ mod ir_builder {
 
     use std::{
         cell::Cell,
-        collections::{BTreeMap, HashMap},
+        collections::{BTreeMap, HashMap, HashSet},
         iter::FromIterator,
+        path::PathBuf,
     };
 
     pub(super) fn build_context(
@@ -1390,6 +1450,10 @@ mod ir_builder {
                             .ret(*val_map.get(&ret_val_name).unwrap(), ty)
                             .add_metadatum(context, opt_metadata)
                     }
+                    IrAstOperation::Retd(ptr, len) => block
+                        .append(context)
+                        .retd(*val_map.get(&ptr).unwrap(), *val_map.get(&len).unwrap())
+                        .add_metadatum(context, opt_metadata),
                     IrAstOperation::Revert(ret_val_name) => block
                         .append(context)
                         .revert(*val_map.get(&ret_val_name).unwrap())
@@ -1536,10 +1600,27 @@ mod ir_builder {
 
     /// Create the metadata for the module in `context` and generate a map from the parsed
     /// `MdIdxRef`s to the new actual metadata.
+    ///
+    /// Source files are printed as their paths, just like strings.  The strings referred to as the
+    /// file of a span, i.e., by the first field of a struct made of an index and two integers, are
+    /// converted back to source ids.
     fn build_metadata_map(
         context: &mut Context,
         ir_metadata: Vec<(MdIdxRef, IrMetadatum)>,
     ) -> HashMap<MdIdxRef, MetadataIndex> {
+        let source_file_idcs: HashSet<MdIdxRef> = ir_metadata
+            .iter()
+            .filter_map(|(_, md)| match md {
+                IrMetadatum::Struct(_, els) => match els.as_slice() {
+                    [IrMetadatum::Index(idx), IrMetadatum::Integer(_), IrMetadatum::Integer(_)] => {
+                        Some(*idx)
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+
         fn convert_md(md: IrMetadatum, md_map: &mut HashMap<MdIdxRef, MetadataIndex>) -> Metadatum {
             match md {
                 IrMetadatum::Integer(i) => Metadatum::Integer(i),
@@ -1572,13 +1653,45 @@ mod ir_builder {
         let mut md_map = HashMap::new();
 
         for (ir_idx, ir_md) in ir_metadata {
-            let md = convert_md(ir_md, &mut md_map);
+            let md = match ir_md {
+                IrMetadatum::String(path) if source_file_idcs.contains(&ir_idx) => {
+                    Metadatum::SourceId(context.source_engine.get_source_id(&PathBuf::from(path)))
+                }
+                ir_md => convert_md(ir_md, &mut md_map),
+            };
             let md_idx = MetadataIndex(context.metadata.insert(md));
             md_map.insert(ir_idx, md_idx);
         }
         md_map
     }
 
+    /// Unescape a metadata string, which is printed the way Rust debug prints strings.
+    fn unescape_md_string(s: &str) -> Option<String> {
+        let mut unescaped = String::with_capacity(s.len());
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                unescaped.push(c);
+                continue;
+            }
+            unescaped.push(match chars.next()? {
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                '0' => '\0',
+                c @ ('\\' | '"' | '\'') => c,
+                'u' => {
+                    let (code, rest) = chars.as_str().strip_prefix('{')?.split_once('}')?;
+                    let c = char::from_u32(u32::from_str_radix(code, 16).ok()?)?;
+                    chars = rest.chars();
+                    c
+                }
+                _ => return None,
+            });
+        }
+        Some(unescaped)
+    }
+
     fn string_to_hex<const N: usize>(s: &str) -> [u8; N] {
         let mut bytes: [u8; N] = [0; N];
         let mut cur_byte: u8 = 0;
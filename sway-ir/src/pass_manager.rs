@@ -3,19 +3,21 @@ use crate::{
     create_const_folding_pass, create_cse_pass, create_dce_pass, create_dom_fronts_pass,
     create_dominators_pass, create_escaped_symbols_pass, create_fn_dce_pass,
     create_fn_dedup_debug_profile_pass, create_fn_dedup_release_profile_pass,
-    create_fn_inline_pass, create_mem2reg_pass, create_memcpyopt_pass, create_misc_demotion_pass,
+    create_fn_inline_pass, create_hash_fold_pass, create_loop_reduce_pass, create_loop_unroll_pass,
+    create_loops_pass, create_mem2reg_pass, create_memcpyopt_pass, create_misc_demotion_pass,
     create_module_printer_pass, create_module_verifier_pass, create_postorder_pass,
     create_ret_demotion_pass, create_simplify_cfg_pass, create_sroa_pass, Context, Function,
     IrError, Module, ARG_DEMOTION_NAME, CCP_NAME, CONST_DEMOTION_NAME, CONST_FOLDING_NAME,
     CSE_NAME, DCE_NAME, FN_DCE_NAME, FN_DEDUP_DEBUG_PROFILE_NAME, FN_DEDUP_RELEASE_PROFILE_NAME,
-    FN_INLINE_NAME, MEM2REG_NAME, MEMCPYOPT_NAME, MISC_DEMOTION_NAME, RET_DEMOTION_NAME,
-    SIMPLIFY_CFG_NAME, SROA_NAME,
+    FN_INLINE_NAME, HASH_FOLD_NAME, LOOP_REDUCE_NAME, LOOP_UNROLL_NAME, MEM2REG_NAME,
+    MEMCPYOPT_NAME, MISC_DEMOTION_NAME, RET_DEMOTION_NAME, SIMPLIFY_CFG_NAME, SROA_NAME,
 };
 use downcast_rs::{impl_downcast, Downcast};
 use rustc_hash::FxHashMap;
 use std::{
     any::{type_name, TypeId},
     collections::{hash_map, HashSet},
+    path::{Path, PathBuf},
 };
 
 /// Result of an analysis. Specific result must be downcasted to.
@@ -157,6 +159,29 @@ pub struct PrintPassesOpts {
     pub passes: HashSet<String>,
 }
 
+/// How strictly the IR is verified while running [Pass]es.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyLevel {
+    /// The IR is not verified.
+    #[default]
+    None,
+    /// The IR is verified once all the passes have run. If it is invalid, the pass producing it
+    /// is searched for by rerunning the passes on the initial IR with each pass disabled in turn.
+    Final,
+    /// The IR is verified after each pass that modifies it.
+    AfterEachPass,
+}
+
+/// Options for verifying the IR in case of running [Pass]es with verification requested.
+#[derive(Debug, Default)]
+pub struct VerifyPassesOpts {
+    pub level: VerifyLevel,
+    /// If the passes produce invalid IR, the IR they were run on is written to this file, along
+    /// with the passes to run on it to reproduce the invalid IR. With [VerifyLevel::AfterEachPass]
+    /// this is the IR the invalidating pass was run on, otherwise it is the initial IR.
+    pub reproducer: Option<PathBuf>,
+}
+
 #[derive(Default)]
 pub struct PassManager {
     passes: FxHashMap<&'static str, Pass>,
@@ -164,7 +189,7 @@ pub struct PassManager {
 }
 
 impl PassManager {
-    pub const OPTIMIZATION_PASSES: [&'static str; 14] = [
+    pub const OPTIMIZATION_PASSES: [&'static str; 17] = [
         FN_INLINE_NAME,
         SIMPLIFY_CFG_NAME,
         SROA_NAME,
@@ -179,6 +204,9 @@ impl PassManager {
         CONST_DEMOTION_NAME,
         RET_DEMOTION_NAME,
         MISC_DEMOTION_NAME,
+        LOOP_UNROLL_NAME,
+        LOOP_REDUCE_NAME,
+        HASH_FOLD_NAME,
     ];
 
     /// Register a pass. Should be called only once for each pass.
@@ -281,6 +309,19 @@ impl PassManager {
         ir: &mut Context,
         passes: &PassGroup,
         print_opts: &PrintPassesOpts,
+    ) -> Result<bool, IrError> {
+        self.run_with_print_and_verify(ir, passes, print_opts, &VerifyPassesOpts::default())
+    }
+
+    /// Run the `passes` and return true if the `passes` modify the initial `ir`.
+    /// The IR states are printed according to the printing options provided in `print_opts`,
+    /// and verified according to the verification options provided in `verify_opts`.
+    pub fn run_with_print_and_verify(
+        &mut self,
+        ir: &mut Context,
+        passes: &PassGroup,
+        print_opts: &PrintPassesOpts,
+        verify_opts: &VerifyPassesOpts,
     ) -> Result<bool, IrError> {
         // Empty IRs are result of compiling dependencies. We don't want to print those.
         fn ir_is_empty(ir: &Context) -> bool {
@@ -308,17 +349,52 @@ impl PassManager {
             print_initial_or_final_ir(ir, "Initial");
         }
 
+        let initial_ir = (verify_opts.level == VerifyLevel::Final).then(|| ir.to_string());
+
         let mut modified = false;
-        for pass in passes.flatten_pass_group() {
+        let flattened_passes = passes.flatten_pass_group();
+        for pass in flattened_passes.iter().copied() {
+            // The IR the pass is run on is only kept if it may be needed for a reproducer.
+            let ir_before_pass = (verify_opts.level == VerifyLevel::AfterEachPass
+                && verify_opts.reproducer.is_some())
+            .then(|| ir.to_string());
+
             let modified_in_pass = self.actually_run(ir, pass)?;
 
             if print_opts.passes.contains(pass) && (!print_opts.modified_only || modified_in_pass) {
                 print_ir_after_pass(ir, self.lookup_registered_pass(pass).unwrap());
             }
 
+            if verify_opts.level == VerifyLevel::AfterEachPass && modified_in_pass {
+                if let Err(error) = ir.verify_modules() {
+                    if let (Some(path), Some(ir_before_pass)) =
+                        (&verify_opts.reproducer, ir_before_pass)
+                    {
+                        write_reproducer(path, &ir_before_pass, &[pass], &error)?;
+                    }
+                    return Err(IrError::VerifyPassFailure(
+                        Some(pass.to_string()),
+                        Box::new(error),
+                    ));
+                }
+            }
+
             modified |= modified_in_pass;
         }
 
+        if let Some(initial_ir) = initial_ir {
+            if let Err(error) = ir.verify_modules() {
+                if let Some(path) = &verify_opts.reproducer {
+                    write_reproducer(path, &initial_ir, &flattened_passes, &error)?;
+                }
+                let pass = self.find_invalidating_pass(ir, &initial_ir, &flattened_passes);
+                return Err(IrError::VerifyPassFailure(
+                    pass.map(str::to_string),
+                    Box::new(error),
+                ));
+            }
+        }
+
         if print_opts.r#final {
             print_initial_or_final_ir(ir, "Final");
         }
@@ -326,6 +402,52 @@ impl PassManager {
         Ok(modified)
     }
 
+    /// Find the transformation pass which, when disabled, makes running the `passes` on the
+    /// `initial_ir` produce valid IR. The passes are rerun on a fresh copy of the initial IR,
+    /// parsed from its printed form, for each of the transformation passes in turn.
+    ///
+    /// Returns `None` if disabling any single pass doesn't help, or if the initial IR cannot be
+    /// parsed back.
+    fn find_invalidating_pass(
+        &mut self,
+        ir: &Context,
+        initial_ir: &str,
+        passes: &[&'static str],
+    ) -> Option<&'static str> {
+        let mut candidates: Vec<&'static str> = vec![];
+        for pass in passes {
+            let is_transform = self
+                .lookup_registered_pass(pass)
+                .is_some_and(|pass| pass.is_transform());
+            if is_transform && !candidates.contains(pass) {
+                candidates.push(pass);
+            }
+        }
+
+        // The analyses results refer to `ir` and must not be reused for the copies.
+        let analyses = std::mem::take(&mut self.analyses);
+        let invalidating_pass = candidates.into_iter().find(|disabled_pass| {
+            let Ok(mut bisected_ir) =
+                crate::parser::parse(initial_ir, ir.source_engine, ir.experimental)
+            else {
+                return false;
+            };
+            bisected_ir.inline_heuristics = ir.inline_heuristics;
+            bisected_ir.loop_unroll_heuristics = ir.loop_unroll_heuristics;
+
+            self.analyses = AnalysisResults::default();
+            passes
+                .iter()
+                .filter(|pass| *pass != disabled_pass)
+                .try_for_each(|pass| self.actually_run(&mut bisected_ir, *pass).map(|_| ()))
+                .and_then(|_| bisected_ir.verify_modules())
+                .is_ok()
+        });
+        self.analyses = analyses;
+
+        invalidating_pass
+    }
+
     /// Get reference to a registered pass.
     pub fn lookup_registered_pass(&self, name: &str) -> Option<&Pass> {
         self.passes.get(name)
@@ -343,6 +465,23 @@ impl PassManager {
     }
 }
 
+/// Write the `ir` which running the `passes` on produces invalid IR to the file at `path`.
+fn write_reproducer(
+    path: &Path,
+    ir: &str,
+    passes: &[&'static str],
+    error: &IrError,
+) -> Result<(), IrError> {
+    let passes = passes.join(" ");
+    let reproducer = format!(
+        "// Running the passes `{passes}` on this IR produces invalid IR: {error}\n\
+         // Reproduce with: opt {passes} -verify-after-each -i {}\n\n{ir}",
+        path.display()
+    );
+    std::fs::write(path, reproducer)
+        .map_err(|err| IrError::WriteFailure(path.display().to_string(), err.to_string()))
+}
+
 /// A group of passes.
 /// Can contain sub-groups.
 #[derive(Default)]
@@ -379,6 +518,17 @@ impl PassGroup {
     pub fn append_group(&mut self, group: PassGroup) {
         self.0.push(PassOrGroup::Group(group));
     }
+
+    /// Remove all occurrences of a pass from this group and its subgroups.
+    pub fn remove_pass(&mut self, pass: &str) {
+        self.0.retain_mut(|pass_or_group| match pass_or_group {
+            PassOrGroup::Pass(p) => *p != pass,
+            PassOrGroup::Group(group) => {
+                group.remove_pass(pass);
+                true
+            }
+        });
+    }
 }
 
 /// A convenience utility to register known passes.
@@ -387,6 +537,7 @@ pub fn register_known_passes(pm: &mut PassManager) {
     pm.register(create_postorder_pass());
     pm.register(create_dominators_pass());
     pm.register(create_dom_fronts_pass());
+    pm.register(create_loops_pass());
     pm.register(create_escaped_symbols_pass());
     pm.register(create_module_printer_pass());
     pm.register(create_module_verifier_pass());
@@ -407,6 +558,9 @@ pub fn register_known_passes(pm: &mut PassManager) {
     pm.register(create_ret_demotion_pass());
     pm.register(create_misc_demotion_pass());
     pm.register(create_memcpyopt_pass());
+    pm.register(create_loop_unroll_pass());
+    pm.register(create_loop_reduce_pass());
+    pm.register(create_hash_fold_pass());
 }
 
 pub fn create_o1_pass_group() -> PassGroup {
@@ -421,6 +575,7 @@ pub fn create_o1_pass_group() -> PassGroup {
     o1.append_pass(FN_INLINE_NAME);
     o1.append_pass(CCP_NAME);
     o1.append_pass(CONST_FOLDING_NAME);
+    o1.append_pass(HASH_FOLD_NAME);
     o1.append_pass(SIMPLIFY_CFG_NAME);
     o1.append_pass(CSE_NAME);
     o1.append_pass(CONST_FOLDING_NAME);
@@ -1190,7 +1190,7 @@ impl Constant {
                     .join(", ")
             ),
             ConstantValue::Slice(elems) => format!(
-                "__slice[{}] [{}]",
+                "{} [{}]",
                 self.ty.as_string(context),
                 elems
                     .iter()
@@ -1207,7 +1207,11 @@ impl Constant {
                     .collect::<Vec<String>>()
                     .join(", ")
             ),
-            ConstantValue::Reference(constant) => format!("&({})", constant.as_lit_string(context)),
+            ConstantValue::Reference(constant) => format!(
+                "{} &({})",
+                self.ty.as_string(context),
+                constant.as_lit_string(context)
+            ),
             ConstantValue::RawUntypedSlice(bytes) => {
                 format!(
                     "{} 0x{}",
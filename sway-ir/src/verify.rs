@@ -46,11 +46,17 @@ pub fn create_module_verifier_pass() -> Pass {
 impl<'eng> Context<'eng> {
     /// Verify the contents of this [`Context`] is valid.
     pub fn verify(self) -> Result<Self, IrError> {
+        self.verify_modules()?;
+        Ok(self)
+    }
+
+    /// Verify the contents of this [`Context`] is valid, without taking ownership of it.
+    pub fn verify_modules(&self) -> Result<(), IrError> {
         for (module, _) in &self.modules {
             let module = Module(module);
             self.verify_module(module)?;
         }
-        Ok(self)
+        Ok(())
     }
 
     fn verify_module(&self, module: Module) -> Result<(), IrError> {
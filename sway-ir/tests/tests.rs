@@ -5,12 +5,14 @@ use sway_features::ExperimentalFeatures;
 use sway_ir::{
     create_arg_demotion_pass, create_ccp_pass, create_const_demotion_pass,
     create_const_folding_pass, create_cse_pass, create_dce_pass, create_dom_fronts_pass,
-    create_dominators_pass, create_escaped_symbols_pass, create_mem2reg_pass,
-    create_memcpyopt_pass, create_misc_demotion_pass, create_postorder_pass,
+    create_dominators_pass, create_escaped_symbols_pass, create_fn_inline_pass,
+    create_hash_fold_pass, create_loop_reduce_pass, create_loop_unroll_pass, create_loops_pass,
+    create_mem2reg_pass, create_memcpyopt_pass, create_misc_demotion_pass, create_postorder_pass,
     create_ret_demotion_pass, create_simplify_cfg_pass, metadata_to_inline, optimize as opt,
-    register_known_passes, Context, Function, IrError, PassGroup, PassManager, Value, DCE_NAME,
-    FN_DCE_NAME, FN_DEDUP_DEBUG_PROFILE_NAME, FN_DEDUP_RELEASE_PROFILE_NAME, MEM2REG_NAME,
-    SROA_NAME,
+    register_known_passes, AnalysisResults, Context, Function, InlineHeuristics, IrError, Pass,
+    PassGroup, PassManager, PassMutability, PrintPassesOpts, ScopedPass, Value, VerifyLevel,
+    VerifyPassesOpts, DCE_NAME, FN_DCE_NAME, FN_DEDUP_DEBUG_PROFILE_NAME,
+    FN_DEDUP_RELEASE_PROFILE_NAME, MEM2REG_NAME, SROA_NAME,
 };
 use sway_types::SourceEngine;
 
@@ -219,6 +221,41 @@ fn inline() {
 
 // -------------------------------------------------------------------------------------------------
 
+#[test]
+fn fn_inline() {
+    run_tests("fn_inline", |first_line, ir: &mut Context| {
+        // The first line can override the thresholds of the cost model, e.g.
+        // `// call_gas_cost 100 annotate`.  See InlineHeuristics for their meaning.
+        let mut words = first_line.split(' ').collect::<Vec<_>>();
+        let params = if words.is_empty() || words.remove(0) != "//" {
+            Vec::new()
+        } else {
+            words
+        };
+        let mut heuristics = InlineHeuristics::default();
+        for (i, param) in params.iter().enumerate() {
+            let arg = || params.get(i + 1).and_then(|arg| arg.parse().ok());
+            match *param {
+                "call_gas_cost" => heuristics.call_gas_cost = arg().unwrap(),
+                "arg_gas_cost" => heuristics.arg_gas_cost = arg().unwrap(),
+                "instruction_size_cost" => heuristics.instruction_size_cost = arg().unwrap(),
+                "max_trivial_instructions" => heuristics.max_trivial_instructions = arg().unwrap(),
+                "annotate" => heuristics.annotate_decisions = true,
+                _ => {}
+            }
+        }
+        ir.inline_heuristics = heuristics;
+
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        let pass = pass_mgr.register(create_fn_inline_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
 // Clippy suggests using the map iterator below directly instead of collecting from it first, but
 // if we try that then we have borrowing issues with `ir` which is used within the closure.
 #[allow(clippy::needless_collect)]
@@ -355,6 +392,56 @@ fn demote_ret() {
 
 // -------------------------------------------------------------------------------------------------
 
+#[allow(clippy::needless_collect)]
+#[test]
+fn loop_unroll() {
+    run_tests("loop_unroll", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        pass_mgr.register(create_postorder_pass());
+        pass_mgr.register(create_dominators_pass());
+        pass_mgr.register(create_loops_pass());
+        let pass = pass_mgr.register(create_loop_unroll_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[allow(clippy::needless_collect)]
+#[test]
+fn loop_reduce() {
+    run_tests("loop_reduce", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        pass_mgr.register(create_postorder_pass());
+        pass_mgr.register(create_dominators_pass());
+        pass_mgr.register(create_loops_pass());
+        let pass = pass_mgr.register(create_loop_reduce_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[allow(clippy::needless_collect)]
+#[test]
+fn hash_fold() {
+    run_tests("hash_fold", |_first_line, ir: &mut Context| {
+        let mut pass_mgr = PassManager::default();
+        let mut pass_group = PassGroup::default();
+        pass_mgr.register(create_postorder_pass());
+        pass_mgr.register(create_dominators_pass());
+        let pass = pass_mgr.register(create_hash_fold_pass());
+        pass_group.append_pass(pass);
+        pass_mgr.run(ir, &pass_group).unwrap()
+    })
+}
+
+// -------------------------------------------------------------------------------------------------
+
 #[allow(clippy::needless_collect)]
 #[test]
 fn demote_misc() {
@@ -431,12 +518,94 @@ fn verify() {
     run_ir_verifier_tests("verify")
 }
 
+#[test]
+fn verify_passes() {
+    const BREAK_IR_NAME: &str = "break-ir";
+
+    // Remove the terminators of the entry blocks, which leaves the IR invalid.
+    fn break_ir(
+        context: &mut Context,
+        _: &AnalysisResults,
+        function: Function,
+    ) -> Result<bool, IrError> {
+        let entry_block = function.get_entry_block(context);
+        let terminator = entry_block.instruction_iter(context).last().unwrap();
+        entry_block.remove_instruction(context, terminator);
+        Ok(true)
+    }
+
+    let input = r#"
+script {
+    entry fn main() -> u64 {
+        entry():
+        v0 = const u64 1
+        v1 = add v0, v0
+        v2 = add v1, v0
+        ret u64 v2
+    }
+}
+"#;
+
+    let source_engine = SourceEngine::default();
+    let mut pass_mgr = PassManager::default();
+    register_known_passes(&mut pass_mgr);
+    pass_mgr.register(Pass {
+        name: BREAK_IR_NAME,
+        descr: "Break the IR",
+        deps: vec![],
+        runner: ScopedPass::FunctionPass(PassMutability::Transform(break_ir)),
+    });
+    let mut pass_group = PassGroup::default();
+    pass_group.append_pass(DCE_NAME);
+    pass_group.append_pass(BREAK_IR_NAME);
+
+    let print_opts = PrintPassesOpts {
+        initial: false,
+        r#final: false,
+        modified_only: true,
+        passes: Default::default(),
+    };
+    let reproducer = std::env::temp_dir().join("sway_ir_verify_passes_reproducer.ir");
+    for level in [VerifyLevel::Final, VerifyLevel::AfterEachPass] {
+        let mut ir =
+            sway_ir::parser::parse(input, &source_engine, ExperimentalFeatures::default()).unwrap();
+        let verify_opts = VerifyPassesOpts {
+            level,
+            reproducer: Some(reproducer.clone()),
+        };
+        let err = pass_mgr
+            .run_with_print_and_verify(&mut ir, &pass_group, &print_opts, &verify_opts)
+            .unwrap_err();
+        assert!(
+            matches!(&err, IrError::VerifyPassFailure(Some(pass), _) if pass == BREAK_IR_NAME),
+            "{err}"
+        );
+
+        // The reproducer is the valid IR the passes were run on.
+        let reproducer_ir = std::fs::read_to_string(&reproducer).unwrap();
+        assert!(reproducer_ir.contains(BREAK_IR_NAME));
+        sway_ir::parser::parse(
+            &reproducer_ir,
+            &source_engine,
+            ExperimentalFeatures::default(),
+        )
+        .unwrap();
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 #[test]
 fn serialize() {
     // This isn't running a pass, it's just confirming that the IR can be loaded and printed, and
-    // FileCheck can just confirm certain instructions came out OK.
-    run_tests("serialize", |_, _: &mut Context| true)
+    // FileCheck can just confirm certain instructions came out OK.  The printed IR must also parse
+    // back to the same IR.
+    run_tests("serialize", |_, ir: &mut Context| {
+        let printed = sway_ir::printer::to_string(ir);
+        let reparsed = sway_ir::parser::parse(&printed, ir.source_engine(), ir.experimental)
+            .unwrap_or_else(|err| panic!("{err}\n{printed}"));
+        assert_eq!(printed, sway_ir::printer::to_string(&reparsed));
+        true
+    })
 }
 
 // -------------------------------------------------------------------------------------------------
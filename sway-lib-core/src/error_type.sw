@@ -0,0 +1,30 @@
+library;
+
+/// A type whose values can be raised as errors with a stable error code.
+///
+/// # Additional Information
+///
+/// The compiler implements this trait for every enum annotated with `#[error_type]`.
+/// The error code of a variant is the first eight bytes of
+/// `sha256("<enum path>::<variant name>")`, interpreted as a big-endian `u64`.
+pub trait ErrorType {
+    /// Returns the error code of `self`.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The error code of the variant `self` holds.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// #[error_type]
+    /// enum Error {
+    ///     NotOwner: (),
+    /// }
+    ///
+    /// fn foo() {
+    ///     let code = Error::NotOwner.error_code();
+    /// }
+    /// ```
+    fn error_code(self) -> u64;
+}
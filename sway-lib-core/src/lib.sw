@@ -11,3 +11,4 @@ pub mod never;
 pub mod r#storage;
 pub mod prelude;
 pub mod codec;
+pub mod error_type;
@@ -12,3 +12,4 @@ pub use ::ops::*;
 pub use ::storage::*;
 pub use ::str::*;
 pub use ::codec::*;
+pub use ::error_type::*;
@@ -26,7 +26,7 @@ pub fn from_str_array<S>(s: S) -> str {
     let ptr = asm(size: __size_of::<S>(), dest, src: src) {
         aloc size;
         move dest hp;
-        mcp dest src size;
+        mcp  dest src size;
         dest: raw_ptr
     };
 
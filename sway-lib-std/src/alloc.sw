@@ -50,7 +50,7 @@ library;
 pub fn alloc<T>(count: u64) -> raw_ptr {
     asm(size: __size_of::<T>() * count, ptr) {
         aloc size;
-        move ptr hp;
+        move ptr  hp;
         ptr: raw_ptr
     }
 }
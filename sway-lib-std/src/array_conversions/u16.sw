@@ -24,12 +24,12 @@ impl u16 {
         let output = [0_u8, 0_u8];
 
         asm(input: self, off: 0xFF, i: 0x8, output: output, r1) {
-            and r1 input off;
-            sb output r1 i0;
+            and r1     input off;
+            sb  output r1    i0;
 
-            srl r1 input i;
-            and r1 r1 off;
-            sb output r1 i1;
+            srl r1     input i;
+            and r1     r1    off;
+            sb  output r1    i1;
 
             output: [u8; 2]
         }
@@ -58,7 +58,7 @@ impl u16 {
     pub fn from_le_bytes(bytes: [u8; 2]) -> Self {
         asm(a: bytes[0], b: bytes[1], i: 0x8, r1) {
             sll r1 b i;
-            or r1 a r1;
+            or  r1 a r1;
             r1: u16
         }
     }
@@ -84,11 +84,11 @@ impl u16 {
         let output = [0_u8, 0_u8];
 
         asm(input: self, off: 0xFF, i: 0x8, output: output, r1) {
-            srl r1 input i;
-            sb output r1 i0;
+            srl r1     input i;
+            sb  output r1    i0;
 
-            and r1 input off;
-            sb output r1 i1;
+            and r1     input off;
+            sb  output r1    i1;
 
             output: [u8; 2]
         }
@@ -116,8 +116,8 @@ impl u16 {
     /// ```
     pub fn from_be_bytes(bytes: [u8; 2]) -> Self {
         asm(a: bytes[0], b: bytes[1], i: 0x8, r1) {
-            sll r1 a i;
-            or r1 r1 b;
+            sll r1 a  i;
+            or  r1 r1 b;
             r1: u16
         }
     }
@@ -34,20 +34,20 @@ impl u32 {
             output: output,
             r1,
         ) {
-            and r1 input off;
-            sb output r1 i0;
+            and r1     input off;
+            sb  output r1    i0;
 
-            srl r1 input i;
-            and r1 r1 off;
-            sb output r1 i1;
+            srl r1     input i;
+            and r1     r1    off;
+            sb  output r1    i1;
 
-            srl r1 input j;
-            and r1 r1 off;
-            sb output r1 i2;
+            srl r1     input j;
+            and r1     r1    off;
+            sb  output r1    i2;
 
-            srl r1 input k;
-            and r1 r1 off;
-            sb output r1 i3;
+            srl r1     input k;
+            and r1     r1    off;
+            sb  output r1    i3;
 
             output: [u8; 4]
         }
@@ -86,12 +86,12 @@ impl u32 {
             r2,
             r3,
         ) {
-            sll r1 c j;
-            sll r2 d k;
-            or r3 r1 r2;
-            sll r1 b i;
-            or r2 a r1;
-            or r1 r2 r3;
+            sll r1 c  j;
+            sll r2 d  k;
+            or  r3 r1 r2;
+            sll r1 b  i;
+            or  r2 a  r1;
+            or  r1 r2 r3;
             r1: u32
         }
     }
@@ -127,20 +127,20 @@ impl u32 {
             output: output,
             r1,
         ) {
-            srl r1 input k;
-            and r1 r1 off;
-            sb output r1 i0;
+            srl r1     input k;
+            and r1     r1    off;
+            sb  output r1    i0;
 
-            srl r1 input j;
-            and r1 r1 off;
-            sb output r1 i1;
+            srl r1     input j;
+            and r1     r1    off;
+            sb  output r1    i1;
 
-            srl r1 input i;
-            and r1 r1 off;
-            sb output r1 i2;
+            srl r1     input i;
+            and r1     r1    off;
+            sb  output r1    i2;
 
-            and r1 input off;
-            sb output r1 i3;
+            and r1     input off;
+            sb  output r1    i3;
 
             output: [u8; 4]
         }
@@ -179,12 +179,12 @@ impl u32 {
             r2,
             r3,
         ) {
-            sll r1 a k;
-            sll r2 b j;
-            or r3 r1 r2;
-            sll r1 c i;
-            or r2 r3 r1;
-            or r1 r2 d;
+            sll r1 a  k;
+            sll r2 b  j;
+            or  r3 r1 r2;
+            sll r1 c  i;
+            or  r2 r3 r1;
+            or  r1 r2 d;
             r1: u32
         }
     }
@@ -26,14 +26,14 @@ impl u16 {
     pub fn to_le_bytes(self) -> Bytes {
         let ptr = asm(input: self, off: 0xFF, i: 0x8, size: 2, ptr, r1) {
             aloc size;
-            move ptr hp;
+            move ptr  hp;
 
-            and r1 input off;
-            sb ptr r1 i0;
+            and  r1   input off;
+            sb   ptr  r1    i0;
 
-            srl r1 input i;
-            and r1 r1 off;
-            sb ptr r1 i1;
+            srl  r1   input i;
+            and  r1   r1    off;
+            sb   ptr  r1    i1;
             ptr: raw_ptr
         };
 
@@ -76,7 +76,7 @@ impl u16 {
         let i = 0x8;
         asm(a: a, b: b, i: i, r1) {
             sll r1 b i;
-            or r1 a r1;
+            or  r1 a r1;
             r1: u16
         }
     }
@@ -103,13 +103,13 @@ impl u16 {
     pub fn to_be_bytes(self) -> Bytes {
         let ptr = asm(input: self, off: 0xFF, i: 0x8, size: 2, ptr, r1) {
             aloc size;
-            move ptr hp;
+            move ptr  hp;
 
-            srl r1 input i;
-            sb ptr r1 i0;
+            srl  r1   input i;
+            sb   ptr  r1    i0;
 
-            and r1 input off;
-            sb ptr r1 i1;
+            and  r1   input off;
+            sb   ptr  r1    i1;
 
             ptr: raw_ptr
         };
@@ -152,8 +152,8 @@ impl u16 {
         let b = (ptr.add_uint_offset(1)).read_byte();
 
         asm(a: a, b: b, i: 0x8, r1) {
-            sll r1 a i;
-            or r1 r1 b;
+            sll r1 a  i;
+            or  r1 r1 b;
             r1: u16
         }
     }
@@ -39,22 +39,22 @@ impl u32 {
             r1,
         ) {
             aloc size;
-            move ptr hp;
+            move ptr  hp;
 
-            and r1 input off;
-            sb ptr r1 i0;
+            and  r1   input off;
+            sb   ptr  r1    i0;
 
-            srl r1 input i;
-            and r1 r1 off;
-            sb ptr r1 i1;
+            srl  r1   input i;
+            and  r1   r1    off;
+            sb   ptr  r1    i1;
 
-            srl r1 input j;
-            and r1 r1 off;
-            sb ptr r1 i2;
+            srl  r1   input j;
+            and  r1   r1    off;
+            sb   ptr  r1    i2;
 
-            srl r1 input k;
-            and r1 r1 off;
-            sb ptr r1 i3;
+            srl  r1   input k;
+            and  r1   r1    off;
+            sb   ptr  r1    i3;
 
             ptr: raw_ptr
         };
@@ -101,12 +101,12 @@ impl u32 {
         let d = (ptr.add_uint_offset(3)).read_byte();
 
         asm(a: a, b: b, c: c, d: d, i: 0x8, j: 0x10, k: 0x18, r1, r2, r3) {
-            sll r1 c j;
-            sll r2 d k;
-            or r3 r1 r2;
-            sll r1 b i;
-            or r2 a r1;
-            or r1 r2 r3;
+            sll r1 c  j;
+            sll r2 d  k;
+            or  r3 r1 r2;
+            sll r1 b  i;
+            or  r2 a  r1;
+            or  r1 r2 r3;
             r1: u32
         }
     }
@@ -144,22 +144,22 @@ impl u32 {
             r1,
         ) {
             aloc size;
-            move ptr hp;
+            move ptr  hp;
 
-            srl r1 input k;
-            and r1 r1 off;
-            sb ptr r1 i0;
+            srl  r1   input k;
+            and  r1   r1    off;
+            sb   ptr  r1    i0;
 
-            srl r1 input j;
-            and r1 r1 off;
-            sb ptr r1 i1;
+            srl  r1   input j;
+            and  r1   r1    off;
+            sb   ptr  r1    i1;
 
-            srl r1 input i;
-            and r1 r1 off;
-            sb ptr r1 i2;
+            srl  r1   input i;
+            and  r1   r1    off;
+            sb   ptr  r1    i2;
 
-            and r1 input off;
-            sb ptr r1 i3;
+            and  r1   input off;
+            sb   ptr  r1    i3;
 
             ptr: raw_ptr
         };
@@ -206,12 +206,12 @@ impl u32 {
         let d = (ptr.add_uint_offset(3)).read_byte();
 
         asm(a: a, b: b, c: c, d: d, i: 0x8, j: 0x10, k: 0x18, r1, r2, r3) {
-            sll r1 a k;
-            sll r2 b j;
-            or r3 r1 r2;
-            sll r1 c i;
-            or r2 r3 r1;
-            or r1 r2 d;
+            sll r1 a  k;
+            sll r2 b  j;
+            or  r3 r1 r2;
+            sll r1 c  i;
+            or  r2 r3 r1;
+            or  r1 r2 d;
             r1: u32
         }
     }
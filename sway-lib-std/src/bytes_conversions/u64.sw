@@ -47,38 +47,38 @@ impl u64 {
             r1,
         ) {
             aloc size;
-            move ptr hp;
+            move ptr  hp;
 
-            and r1 input off;
-            sb ptr r1 i0;
+            and  r1   input off;
+            sb   ptr  r1    i0;
 
-            srl r1 input i;
-            and r1 r1 off;
-            sb ptr r1 i1;
+            srl  r1   input i;
+            and  r1   r1    off;
+            sb   ptr  r1    i1;
 
-            srl r1 input j;
-            and r1 r1 off;
-            sb ptr r1 i2;
+            srl  r1   input j;
+            and  r1   r1    off;
+            sb   ptr  r1    i2;
 
-            srl r1 input k;
-            and r1 r1 off;
-            sb ptr r1 i3;
+            srl  r1   input k;
+            and  r1   r1    off;
+            sb   ptr  r1    i3;
 
-            srl r1 input l;
-            and r1 r1 off;
-            sb ptr r1 i4;
+            srl  r1   input l;
+            and  r1   r1    off;
+            sb   ptr  r1    i4;
 
-            srl r1 input m;
-            and r1 r1 off;
-            sb ptr r1 i5;
+            srl  r1   input m;
+            and  r1   r1    off;
+            sb   ptr  r1    i5;
 
-            srl r1 input n;
-            and r1 r1 off;
-            sb ptr r1 i6;
+            srl  r1   input n;
+            and  r1   r1    off;
+            sb   ptr  r1    i6;
 
-            srl r1 input o;
-            and r1 r1 off;
-            sb ptr r1 i7;
+            srl  r1   input o;
+            and  r1   r1    off;
+            sb   ptr  r1    i7;
 
             ptr: raw_ptr
         };
@@ -136,20 +136,20 @@ impl u64 {
             a: a, b: b, c: c, d: d, e: e, f: f, g: g, h: h, i: 0x8, j: 0x10, k: 0x18,
             l: 0x20, m: 0x28, n: 0x30, o: 0x38, r1, r2, r3,
         ) {
-            sll r1 h o;
-            sll r2 g n;
-            or r3 r1 r2;
-            sll r1 f m;
-            or r2 r3 r1;
-            sll r3 e l;
-            or r1 r2 r3;
-            sll r2 d k;
-            or r3 r1 r2;
-            sll r1 c j;
-            or r2 r3 r1;
-            sll r3 b i;
-            or r1 r2 r3;
-            or r2 r1 a;
+            sll r1 h  o;
+            sll r2 g  n;
+            or  r3 r1 r2;
+            sll r1 f  m;
+            or  r2 r3 r1;
+            sll r3 e  l;
+            or  r1 r2 r3;
+            sll r2 d  k;
+            or  r3 r1 r2;
+            sll r1 c  j;
+            or  r2 r3 r1;
+            sll r3 b  i;
+            or  r1 r2 r3;
+            or  r2 r1 a;
 
             r2: u64
         }
@@ -196,38 +196,38 @@ impl u64 {
             r1,
         ) {
             aloc size;
-            move ptr hp;
+            move ptr  hp;
 
-            and r1 input off;
-            sb ptr r1 i7;
+            and  r1   input off;
+            sb   ptr  r1    i7;
 
-            srl r1 input i;
-            and r1 r1 off;
-            sb ptr r1 i6;
+            srl  r1   input i;
+            and  r1   r1    off;
+            sb   ptr  r1    i6;
 
-            srl r1 input j;
-            and r1 r1 off;
-            sb ptr r1 i5;
+            srl  r1   input j;
+            and  r1   r1    off;
+            sb   ptr  r1    i5;
 
-            srl r1 input k;
-            and r1 r1 off;
-            sb ptr r1 i4;
+            srl  r1   input k;
+            and  r1   r1    off;
+            sb   ptr  r1    i4;
 
-            srl r1 input l;
-            and r1 r1 off;
-            sb ptr r1 i3;
+            srl  r1   input l;
+            and  r1   r1    off;
+            sb   ptr  r1    i3;
 
-            srl r1 input m;
-            and r1 r1 off;
-            sb ptr r1 i2;
+            srl  r1   input m;
+            and  r1   r1    off;
+            sb   ptr  r1    i2;
 
-            srl r1 input n;
-            and r1 r1 off;
-            sb ptr r1 i1;
+            srl  r1   input n;
+            and  r1   r1    off;
+            sb   ptr  r1    i1;
 
-            srl r1 input o;
-            and r1 r1 off;
-            sb ptr r1 i0;
+            srl  r1   input o;
+            and  r1   r1    off;
+            sb   ptr  r1    i0;
 
             ptr: raw_ptr
         };
@@ -285,20 +285,20 @@ impl u64 {
             a: a, b: b, c: c, d: d, e: e, f: f, g: g, h: h, i: 0x8, j: 0x10, k: 0x18,
             l: 0x20, m: 0x28, n: 0x30, o: 0x38, r1, r2, r3,
         ) {
-            sll r1 h o;
-            sll r2 g n;
-            or r3 r1 r2;
-            sll r1 f m;
-            or r2 r3 r1;
-            sll r3 e l;
-            or r1 r2 r3;
-            sll r2 d k;
-            or r3 r1 r2;
-            sll r1 c j;
-            or r2 r3 r1;
-            sll r3 b i;
-            or r1 r2 r3;
-            or r2 r1 a;
+            sll r1 h  o;
+            sll r2 g  n;
+            or  r3 r1 r2;
+            sll r1 f  m;
+            or  r2 r3 r1;
+            sll r3 e  l;
+            or  r1 r2 r3;
+            sll r2 d  k;
+            or  r3 r1 r2;
+            sll r1 c  j;
+            or  r2 r3 r1;
+            sll r3 b  i;
+            or  r1 r2 r3;
+            or  r2 r1 a;
 
             r2: u64
         }
@@ -6,3 +6,48 @@ pub trait Clone {
     /// Clone self into a new value of the same type.
     fn clone(self) -> Self;
 }
+
+// Primitive types are copied on every use, so cloning them is a copy as well.
+// These implementations allow `Clone` to be derived for types containing them.
+
+impl Clone for bool {
+    fn clone(self) -> Self {
+        self
+    }
+}
+
+impl Clone for u8 {
+    fn clone(self) -> Self {
+        self
+    }
+}
+
+impl Clone for u16 {
+    fn clone(self) -> Self {
+        self
+    }
+}
+
+impl Clone for u32 {
+    fn clone(self) -> Self {
+        self
+    }
+}
+
+impl Clone for u64 {
+    fn clone(self) -> Self {
+        self
+    }
+}
+
+impl Clone for u256 {
+    fn clone(self) -> Self {
+        self
+    }
+}
+
+impl Clone for b256 {
+    fn clone(self) -> Self {
+        self
+    }
+}
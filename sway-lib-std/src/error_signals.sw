@@ -42,3 +42,11 @@ pub const FAILED_ASSERT_NE_SIGNAL = 0xffff_ffff_ffff_0005;
 ///
 /// The value is: 18446744073709486086
 pub const REVERT_WITH_LOG_SIGNAL = 0xffff_ffff_ffff_0006;
+
+/// A revert with this value signals that it was caused by indexing an array or a slice with an out of bounds range,
+/// like in `&array[start..end]`.
+///
+/// # Additional Information
+///
+/// The value is: 18446744073709486087
+pub const RANGE_OUT_OF_BOUNDS_SIGNAL = 0xffff_ffff_ffff_0007;
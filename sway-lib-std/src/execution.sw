@@ -19,14 +19,14 @@ pub fn run_external(load_target: ContractId) -> ! {
         ssp_saved,
         cur_stack_size,
     ) {
-        csiz length load_target;
-        move ssp_saved ssp;
-        sub cur_stack_size sp ssp;
-        cfs cur_stack_size;
-        ldc load_target zero length i0;
-        addi word zero i64;
+        csiz length         load_target;
+        move ssp_saved      ssp;
+        sub  cur_stack_size sp          ssp;
+        cfs  cur_stack_size;
+        ldc  load_target    zero        length i0;
+        addi word           zero        i64;
         aloc word;
-        sw hp ssp_saved i0;
+        sw   hp             ssp_saved   i0;
     }
     __jmp_mem()
 }
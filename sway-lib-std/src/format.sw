@@ -0,0 +1,255 @@
+//! Formatting of values into strings, as done by the `format!` macro.
+library;
+
+use ::bytes::Bytes;
+use ::convert::TryFrom;
+use ::option::Option::{self, *};
+use ::primitive_conversions::u64::*;
+use ::string::String;
+
+/// Builds a `String` out of text and formatted values.
+///
+/// # Additional Information
+///
+/// The `format!` macro expands to the calls of a `Formatter`, so it rarely needs to be used directly.
+pub struct Formatter {
+    /// The bytes written so far.
+    bytes: Bytes,
+}
+
+impl Formatter {
+    /// Constructs a new `Formatter` with an empty buffer.
+    ///
+    /// # Returns
+    ///
+    /// * [Formatter] - A new `Formatter`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::format::Formatter;
+    ///
+    /// fn foo() {
+    ///     let f = Formatter::new();
+    ///     assert(f.finish().is_empty());
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            bytes: Bytes::new(),
+        }
+    }
+
+    /// Constructs a new `Formatter` whose buffer can hold `capacity` bytes without reallocating.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity`: [u64] - The amount of bytes to allocate for the buffer.
+    ///
+    /// # Returns
+    ///
+    /// * [Formatter] - A new `Formatter`.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::format::Formatter;
+    ///
+    /// fn foo() {
+    ///     let mut f = Formatter::with_capacity(4);
+    ///     f.write_str("fuel"); // This will not reallocate
+    /// }
+    /// ```
+    pub fn with_capacity(capacity: u64) -> Self {
+        Self {
+            bytes: Bytes::with_capacity(capacity),
+        }
+    }
+
+    /// Appends the bytes of `s` to the buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `s`: [str] - The string to append.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::{format::Formatter, string::String};
+    ///
+    /// fn foo() {
+    ///     let mut f = Formatter::new();
+    ///     f.write_str("fuel");
+    ///     assert(f.finish() == String::from_ascii_str("fuel"));
+    /// }
+    /// ```
+    pub fn write_str(ref mut self, s: str) {
+        let mut bytes = Bytes::from(raw_slice::from_parts::<u8>(s.as_ptr(), s.len()));
+        self.bytes.append(bytes);
+    }
+
+    /// Appends the textual representation of `value` to the buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: [T] - The value to format.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::{format::Formatter, string::String};
+    ///
+    /// fn foo() {
+    ///     let mut f = Formatter::new();
+    ///     f.write(42);
+    ///     f.write(true);
+    ///     assert(f.finish() == String::from_ascii_str("42true"));
+    /// }
+    /// ```
+    pub fn write<T>(ref mut self, value: T)
+    where
+        T: Format,
+    {
+        value.fmt(self);
+    }
+
+    /// Returns the `String` written so far.
+    ///
+    /// # Returns
+    ///
+    /// * [String] - The formatted string.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use std::{format::Formatter, string::String};
+    ///
+    /// fn foo() {
+    ///     let mut f = Formatter::new();
+    ///     f.write_str("x = ");
+    ///     f.write(1u8);
+    ///     assert(f.finish() == String::from_ascii_str("x = 1"));
+    /// }
+    /// ```
+    pub fn finish(self) -> String {
+        String::from(self.bytes)
+    }
+}
+
+/// A value that can be written as text by a `Formatter`, and thus used as an argument of `format!`.
+pub trait Format {
+    /// Appends the textual representation of `self` to `f`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: [Formatter] - The `Formatter` to write to.
+    fn fmt(self, ref mut f: Formatter);
+}
+
+impl Format for str {
+    fn fmt(self, ref mut f: Formatter) {
+        f.write_str(self);
+    }
+}
+
+impl Format for String {
+    fn fmt(self, ref mut f: Formatter) {
+        let mut bytes = self.as_bytes();
+        f.bytes.append(bytes);
+    }
+}
+
+impl Format for bool {
+    fn fmt(self, ref mut f: Formatter) {
+        if self {
+            f.write_str("true");
+        } else {
+            f.write_str("false");
+        }
+    }
+}
+
+impl Format for u8 {
+    fn fmt(self, ref mut f: Formatter) {
+        write_decimal_u64(f, self.as_u64());
+    }
+}
+
+impl Format for u16 {
+    fn fmt(self, ref mut f: Formatter) {
+        write_decimal_u64(f, self.as_u64());
+    }
+}
+
+impl Format for u32 {
+    fn fmt(self, ref mut f: Formatter) {
+        write_decimal_u64(f, self.as_u64());
+    }
+}
+
+impl Format for u64 {
+    fn fmt(self, ref mut f: Formatter) {
+        write_decimal_u64(f, self);
+    }
+}
+
+impl Format for u256 {
+    fn fmt(self, ref mut f: Formatter) {
+        // Values which fit into a `u64` avoid the more expensive `u256` arithmetic.
+        match u64::try_from(self) {
+            Some(value) => write_decimal_u64(f, value),
+            None => {
+                let mut divisor = 1u256;
+                while self / divisor >= 10 {
+                    divisor *= 10;
+                }
+                while divisor > 0 {
+                    write_digit(f, u64::try_from((self / divisor) % 10).unwrap());
+                    divisor /= 10;
+                }
+            }
+        }
+    }
+}
+
+impl Format for b256 {
+    fn fmt(self, ref mut f: Formatter) {
+        let (word0, word1, word2, word3) = asm(r1: self) {
+            r1: (u64, u64, u64, u64)
+        };
+        f.write_str("0x");
+        write_hex_u64(f, word0);
+        write_hex_u64(f, word1);
+        write_hex_u64(f, word2);
+        write_hex_u64(f, word3);
+    }
+}
+
+/// Writes the decimal digits of `value`, from the most significant one.
+fn write_decimal_u64(ref mut f: Formatter, value: u64) {
+    let mut divisor = 1;
+    while value / divisor >= 10 {
+        divisor *= 10;
+    }
+    while divisor > 0 {
+        write_digit(f, (value / divisor) % 10);
+        divisor /= 10;
+    }
+}
+
+/// Writes all 16 hexadecimal digits of `value`, including the leading zeros.
+fn write_hex_u64(ref mut f: Formatter, value: u64) {
+    let mut shift = 64;
+    while shift > 0 {
+        shift -= 4;
+        write_digit(f, (value >> shift) & 0xf);
+    }
+}
+
+/// Writes a single digit, using `a` to `f` for the digits from 10 to 15.
+fn write_digit(ref mut f: Formatter, digit: u64) {
+    let ascii = if digit < 10 { digit + 48 } else { digit + 87 };
+    f.bytes.push(asm(r1: ascii) {
+        r1: u8
+    });
+}
@@ -80,8 +80,8 @@ impl Hash for u16 {
     fn hash(self, ref mut state: Hasher) {
         let ptr = alloc_bytes(8); // one word capacity
         asm(ptr: ptr, val: self, r1) {
-            slli r1 val i48;
-            sw ptr r1 i0;
+            slli r1  val i48;
+            sw   ptr r1  i0;
         };
 
         state.write(Bytes::from(raw_slice::from_parts::<u8>(ptr, 2)));
@@ -92,8 +92,8 @@ impl Hash for u32 {
     fn hash(self, ref mut state: Hasher) {
         let ptr = alloc_bytes(8); // one word capacity
         asm(ptr: ptr, val: self, r1) {
-            slli r1 val i32;
-            sw ptr r1 i0;
+            slli r1  val i32;
+            sw   ptr r1  i0;
         };
 
         state.write(Bytes::from(raw_slice::from_parts::<u8>(ptr, 4)));
@@ -405,6 +405,41 @@ where
     hasher.sha256()
 }
 
+/// Returns the `SHA-2-256` hash of the 32 bytes of `value`.
+///
+/// # Additional Information
+///
+/// The result is the same as the one of `sha256(value)`, but the bytes are hashed in place
+/// instead of being copied into a `Hasher` first.
+/// The compiler evaluates the hash at compile time if `value` is known at compile time, which
+/// makes this the preferred way of deriving storage keys from other keys.
+///
+/// # Arguments
+///
+/// * `value`: [b256] - The value to be hashed.
+///
+/// # Returns
+///
+/// * [b256] - The sha-256 hash of the value.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::hash::*;
+///
+/// fn foo() {
+///     let result = sha256_b256(b256::zero());
+///     assert(result == 0x66687aadf862bd776c8fc18b8e9f8e20089714856ee233b3902a591d0d5f2925);
+/// }
+/// ```
+pub fn sha256_b256(value: b256) -> b256 {
+    let mut result_buffer = b256::min();
+    asm(hash: result_buffer, ptr: value, bytes: 32) {
+        s256 hash ptr bytes;
+        hash: b256
+    }
+}
+
 /// Returns the `SHA-2-256` hash of `param`.
 /// This function is specific for string arrays
 ///
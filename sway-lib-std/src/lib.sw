@@ -6,6 +6,7 @@ pub mod logging;
 pub mod revert;
 pub mod result;
 pub mod option;
+pub mod ops;
 pub mod assert;
 pub mod convert;
 pub mod intrinsics;
@@ -45,3 +46,4 @@ pub mod low_level_call;
 pub mod array_conversions;
 pub mod bytes_conversions;
 pub mod clone;
+pub mod format;
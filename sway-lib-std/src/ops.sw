@@ -0,0 +1,68 @@
+//! Traits for propagating failures with the `?` operator.
+library;
+
+use ::option::Option::{self, *};
+use ::result::Result::{self, *};
+
+/// Tells the `?` operator whether to continue with a value or to exit early.
+pub enum ControlFlow<B, C> {
+    /// Continue with the value.
+    Continue: C,
+    /// Exit early with the residual of a failure.
+    Break: B,
+}
+
+/// Types whose failure can be propagated with the `?` operator.
+///
+/// # Additional Information
+///
+/// `value?` evaluates to the value held by `ControlFlow::Continue` if `value.branch()` continues.
+/// Otherwise, it returns `R::from_residual(residual)` from the enclosing function, where `R` is
+/// the return type of the function.
+///
+/// The residual holds the failure without the type of the value, so that the failure can be
+/// propagated to functions returning values of a different type. The residual of `Option<T>` is
+/// `Option<!>`, and the residual of `Result<T, E>` is `Result<!, E>`.
+pub trait Try<T, R> {
+    /// Splits `self` into the value of type `T`, or the residual of type `R` of its failure.
+    fn branch(self) -> ControlFlow<R, T>;
+}
+
+/// Types that a failure with the residual of type `R` can be propagated to with the `?` operator.
+pub trait FromResidual<R> {
+    /// Creates the failure from its `residual`.
+    fn from_residual(residual: R) -> Self;
+}
+
+impl<T> Try<T, Option<!>> for Option<T> {
+    fn branch(self) -> ControlFlow<Option<!>, T> {
+        match self {
+            Some(value) => ControlFlow::Continue(value),
+            None => ControlFlow::Break(None),
+        }
+    }
+}
+
+impl<T> FromResidual<Option<!>> for Option<T> {
+    fn from_residual(_residual: Option<!>) -> Self {
+        None
+    }
+}
+
+impl<T, E> Try<T, Result<!, E>> for Result<T, E> {
+    fn branch(self) -> ControlFlow<Result<!, E>, T> {
+        match self {
+            Ok(value) => ControlFlow::Continue(value),
+            Err(error) => ControlFlow::Break(Err(error)),
+        }
+    }
+}
+
+impl<T, E> FromResidual<Result<!, E>> for Result<T, E> {
+    fn from_residual(residual: Result<!, E>) -> Self {
+        match residual {
+            Ok(never) => never,
+            Err(error) => Err(error),
+        }
+    }
+}
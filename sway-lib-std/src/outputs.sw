@@ -193,7 +193,7 @@ pub fn output_amount(index: u64) -> Option<u64> {
             Some(
                 asm(r1, r2, r3: ptr) {
                     addi r2 r3 i40;
-                    lw r1 r2 i0;
+                    lw   r1 r2 i0;
                     r1: u64
                 },
             )
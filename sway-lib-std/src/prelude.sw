@@ -21,7 +21,8 @@ pub use ::vec::{Vec, VecIter};
 pub use ::assert::{assert, assert_eq, assert_ne};
 pub use ::option::Option::{self, *};
 pub use ::result::Result::{self, *};
-pub use ::revert::{require, revert, revert_with_log};
+pub use ::ops::{FromResidual, Try};
+pub use ::revert::{require, revert, revert_with_error, revert_with_log};
 
 // Convert
 pub use ::convert::From;
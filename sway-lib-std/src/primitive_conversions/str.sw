@@ -30,9 +30,9 @@ impl str {
                 source: source,
                 dest,
             ) {
-                move dest sp;
-                cfe tmp_alloc_size;
-                mcp dest source str_size;
+                move dest           sp;
+                cfe  tmp_alloc_size;
+                mcp  dest           source str_size;
                 dest: S
             };
             asm(tmp_alloc_size: tmp_alloc_size) {
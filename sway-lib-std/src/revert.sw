@@ -103,3 +103,50 @@ where
     log(value);
     revert(REVERT_WITH_LOG_SIGNAL)
 }
+
+/// Logs `error` and reverts with its error code.
+///
+/// # Additional Information
+///
+/// The error code of an enum annotated with `#[error_type]` only depends on the path of the enum
+/// and the name of the variant, so SDKs can decode the revert code to a typed error.
+///
+/// # Arguments
+///
+/// * `error`: [E] - The error to log and revert with.
+///
+/// # Reverts
+///
+/// * Reverts unconditionally with `error.error_code()`.
+///
+/// # Examples
+///
+/// ```sway
+/// #[error_type]
+/// enum Error {
+///     NotOwner: (),
+/// }
+///
+/// fn foo() {
+///     revert_with_error(Error::NotOwner);
+/// }
+/// ```
+#[cfg(experimental_new_encoding = false)]
+pub fn revert_with_error<E>(error: E) -> !
+where
+    E: ErrorType,
+{
+    let code = error.error_code();
+    log(error);
+    revert(code)
+}
+
+#[cfg(experimental_new_encoding = true)]
+pub fn revert_with_error<E>(error: E) -> !
+where
+    E: ErrorType + AbiEncode,
+{
+    let code = error.error_code();
+    log(error);
+    revert(code)
+}
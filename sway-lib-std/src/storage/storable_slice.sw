@@ -41,7 +41,7 @@ pub fn write_slice(key: b256, slice: raw_slice) {
     ptr = realloc_bytes(ptr, number_of_bytes, number_of_slots * 32);
 
     // Store `number_of_slots * 32` bytes starting at storage slot `key`.
-    let _ = __state_store_quad(sha256(key), ptr, number_of_slots);
+    let _ = __state_store_quad(sha256_b256(key), ptr, number_of_slots);
 
     // Store the length of the bytes
     write(key, 0, number_of_bytes);
@@ -85,7 +85,7 @@ pub fn read_slice(key: b256) -> Option<raw_slice> {
             let number_of_slots = (len + 31) >> 5;
             let ptr = alloc_bytes(number_of_slots * 32);
             // Load the stored slice into the pointer.
-            let _ = __state_load_quad(sha256(key), ptr, number_of_slots);
+            let _ = __state_load_quad(sha256_b256(key), ptr, number_of_slots);
             Some(asm(ptr: (ptr, len)) {
                 ptr: raw_slice
             })
@@ -128,9 +128,9 @@ pub fn clear_slice(key: b256) -> bool {
     let len = read::<u64>(key, 0).unwrap_or(0);
     let number_of_slots = (len + 31) >> 5;
 
-    // Clear length and `number_of_slots` bytes starting at storage slot `sha256(key)`
+    // Clear length and `number_of_slots` bytes starting at storage slot `sha256_b256(key)`
     let _ = __state_clear(key, 1);
-    __state_clear(sha256(key), number_of_slots)
+    __state_clear(sha256_b256(key), number_of_slots)
 }
 
 /// A general way to persistently store heap types.
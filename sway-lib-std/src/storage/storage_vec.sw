@@ -3,6 +3,7 @@ library;
 use ::alloc::{alloc_bytes, realloc_bytes};
 use ::assert::assert;
 use ::hash::*;
+use ::iterator::*;
 use ::option::Option::{self, *};
 use ::storage::storage_api::*;
 use ::storage::storage_key::*;
@@ -43,7 +44,7 @@ impl<V> StorageKey<StorageVec<V>> {
         let len = read::<u64>(self.field_id(), 0).unwrap_or(0);
 
         // Storing the value at the current length index (if this is the first item, starts off at 0)
-        let key = sha256(self.field_id());
+        let key = sha256_b256(self.field_id());
         let offset = offset_calculator::<V>(len);
         write::<V>(key, offset, value);
 
@@ -92,7 +93,7 @@ impl<V> StorageKey<StorageVec<V>> {
         // reduces len by 1, effectively removing the last item in the vec
         write(self.field_id(), 0, len - 1);
 
-        let key = sha256(self.field_id());
+        let key = sha256_b256(self.field_id());
         let offset = offset_calculator::<V>(len - 1);
         read::<V>(key, offset)
     }
@@ -137,7 +138,7 @@ impl<V> StorageKey<StorageVec<V>> {
             return None;
         }
 
-        let key = sha256(self.field_id());
+        let key = sha256_b256(self.field_id());
         let offset = offset_calculator::<V>(index);
         // This StorageKey can be read by the standard storage api.
         // Field Id must be unique such that nested storage vecs work as they have a 
@@ -195,7 +196,7 @@ impl<V> StorageKey<StorageVec<V>> {
         assert(index < len);
 
         // gets the element before removing it, so it can be returned
-        let key = sha256(self.field_id());
+        let key = sha256_b256(self.field_id());
         let removed_offset = offset_calculator::<V>(index);
         let removed_element = read::<V>(key, removed_offset).unwrap();
 
@@ -264,7 +265,7 @@ impl<V> StorageKey<StorageVec<V>> {
         // if the index is larger or equal to len, there is no item to remove
         assert(index < len);
 
-        let key = sha256(self.field_id());
+        let key = sha256_b256(self.field_id());
         // gets the element before removing it, so it can be returned
         let element_offset = offset_calculator::<V>(index);
         let element_to_be_removed = read::<V>(key, element_offset).unwrap();
@@ -322,7 +323,7 @@ impl<V> StorageKey<StorageVec<V>> {
         // if the index is higher than or equal len, there is no element to set
         assert(index < len);
 
-        let key = sha256(self.field_id());
+        let key = sha256_b256(self.field_id());
         let offset = offset_calculator::<V>(index);
         write::<V>(key, offset, value);
     }
@@ -376,7 +377,7 @@ impl<V> StorageKey<StorageVec<V>> {
         assert(index <= len);
 
         // if len is 0, index must also be 0 due to above check
-        let key = sha256(self.field_id());
+        let key = sha256_b256(self.field_id());
         if len == index {
             let offset = offset_calculator::<V>(index);
             write::<V>(key, offset, value);
@@ -524,7 +525,7 @@ impl<V> StorageKey<StorageVec<V>> {
             return;
         }
 
-        let key = sha256(self.field_id());
+        let key = sha256_b256(self.field_id());
         let element1_offset = offset_calculator::<V>(element1_index);
         let element2_offset = offset_calculator::<V>(element2_index);
 
@@ -567,7 +568,7 @@ impl<V> StorageKey<StorageVec<V>> {
     /// ```
     #[storage(read)]
     pub fn first(self) -> Option<StorageKey<V>> {
-        let key = sha256(self.field_id());
+        let key = sha256_b256(self.field_id());
         match read::<u64>(self.field_id(), 0).unwrap_or(0) {
             0 => None,
             _ => Some(StorageKey::<V>::new(key, 0, sha256((0, key)))),
@@ -603,7 +604,7 @@ impl<V> StorageKey<StorageVec<V>> {
     /// ```
     #[storage(read)]
     pub fn last(self) -> Option<StorageKey<V>> {
-        let key = sha256(self.field_id());
+        let key = sha256_b256(self.field_id());
         match read::<u64>(self.field_id(), 0).unwrap_or(0) {
             0 => None,
             len => {
@@ -646,7 +647,7 @@ impl<V> StorageKey<StorageVec<V>> {
             return;
         }
 
-        let key = sha256(self.field_id());
+        let key = sha256_b256(self.field_id());
         let mid = len / 2;
         let mut i = 0;
         while i < mid {
@@ -695,7 +696,7 @@ impl<V> StorageKey<StorageVec<V>> {
     pub fn fill(self, value: V) {
         let len = read::<u64>(self.field_id(), 0).unwrap_or(0);
 
-        let key = sha256(self.field_id());
+        let key = sha256_b256(self.field_id());
         let mut i = 0;
         while i < len {
             let offset = offset_calculator::<V>(i);
@@ -750,7 +751,7 @@ impl<V> StorageKey<StorageVec<V>> {
     #[storage(read, write)]
     pub fn resize(self, new_len: u64, value: V) {
         let mut len = read::<u64>(self.field_id(), 0).unwrap_or(0);
-        let key = sha256(self.field_id());
+        let key = sha256_b256(self.field_id());
         while len < new_len {
             let offset = offset_calculator::<V>(len);
             write::<V>(key, offset, value);
@@ -842,7 +843,7 @@ impl<V> StorageKey<StorageVec<V>> {
         ptr = realloc_bytes(ptr, number_of_bytes, number_of_slots * 32);
 
         // Store `number_of_slots * 32` bytes starting at storage slot `key`.
-        let _ = __state_store_quad(sha256(self.field_id()), ptr, number_of_slots);
+        let _ = __state_store_quad(sha256_b256(self.field_id()), ptr, number_of_slots);
 
         // Store the length, NOT the bytes. 
         // This differs from the existing `write_slice()` function to be compatible with `StorageVec`.
@@ -891,51 +892,132 @@ impl<V> StorageKey<StorageVec<V>> {
     #[storage(read)]
     pub fn load_vec(self) -> Vec<V> {
         // Get the length of the slice that is stored.
-        match read::<u64>(self.field_id(), 0).unwrap_or(0) {
-            0 => Vec::new(),
-            len => {
-                // Get the number of storage slots needed based on the size.
-                let size_V_bytes = __size_of::<V>();
-
-                assert(size_V_bytes != 0);
-
-                let bytes = if size_V_bytes < 8 {
-                    // Len * size_of_word
-                    len * 8
-                } else {
-                    len * size_V_bytes
-                };
-
-                let number_of_slots = (bytes + 31) >> 5;
-                let ptr = alloc_bytes(number_of_slots * 32);
-                // Load the stored slice into the pointer.
-                let _ = __state_load_quad(sha256(self.field_id()), ptr, number_of_slots);
-
-                if size_V_bytes < 8 {
-                    let len_bytes = len * size_V_bytes;
-                    let new_vec = alloc_bytes(len_bytes);
-                    let mut i = 0;
-                    while i < len {
-                        // The stored vec is offset with 1 word per element, remove the padding for elements less than the size of a word
-                        // (size_of_word * element)
-                        ptr
-                            .add_uint_offset((8 * i))
-                            .copy_bytes_to(new_vec.add::<V>(i), size_V_bytes);
-                        i += 1;
-                    }
-
-                    Vec::from(
-                        asm(ptr: (new_vec, len_bytes)) {
-                            ptr: raw_slice
-                        },
-                    )
-                } else {
-                    Vec::from(
-                        asm(ptr: (ptr, bytes)) {
-                            ptr: raw_slice
-                        },
-                    )
+        let len = read::<u64>(self.field_id(), 0).unwrap_or(0);
+        load_elements::<V>(sha256_b256(self.field_id()), len)
+    }
+
+    /// Returns an iterator over the first `bound` elements of the vector, or all of them if it
+    /// is shorter.
+    ///
+    /// # Additional Information
+    ///
+    /// The elements are loaded when the iterator is created, by reading all the consecutive
+    /// storage slots they occupy at once. The compiler warns about `for` loops over the
+    /// iterator if `bound` is not a constant, as the cost of the loop is then unbounded.
+    ///
+    /// # Arguments
+    ///
+    /// * `bound`: [u64] - The maximum number of elements to iterate over.
+    ///
+    /// # Returns
+    ///
+    /// * [StorageVecIter<V>] - The iterator over the elements.
+    ///
+    /// # Reverts
+    ///
+    /// * If the size of type `V` is 0.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads - `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// storage {
+    ///     vec: StorageVec<u64> = StorageVec {},
+    /// }
+    ///
+    /// fn foo() {
+    ///     storage.vec.push(5);
+    ///     storage.vec.push(10);
+    ///     storage.vec.push(15);
+    ///
+    ///     let mut sum = 0;
+    ///     for value in storage.vec.iter(2) {
+    ///         sum += value;
+    ///     }
+    ///     assert(sum == 15);
+    /// }
+    /// ```
+    #[iteration_bound(bound)]
+    #[storage(read)]
+    pub fn iter(self, bound: u64) -> StorageVecIter<V> {
+        let len = read::<u64>(self.field_id(), 0).unwrap_or(0);
+        let len = if len < bound { len } else { bound };
+        StorageVecIter {
+            values: load_elements::<V>(sha256_b256(self.field_id()), len),
+            index: 0,
+        }
+    }
+}
+
+/// An iterator over the elements of a [StorageVec], created by [StorageVec::iter].
+pub struct StorageVecIter<V> {
+    values: Vec<V>,
+    index: u64,
+}
+
+impl<V> Iterator for StorageVecIter<V> {
+    type Item = V;
+    fn next(ref mut self) -> Option<Self::Item> {
+        if self.index >= self.values.len() {
+            return None
+        }
+
+        self.index += 1;
+        self.values.get(self.index - 1)
+    }
+}
+
+// Load the first `len` elements of a vector stored from `key` onwards, using a single read of
+// the consecutive storage slots they occupy.
+#[storage(read)]
+fn load_elements<V>(key: b256, len: u64) -> Vec<V> {
+    match len {
+        0 => Vec::new(),
+        len => {
+            // Get the number of storage slots needed based on the size.
+            let size_V_bytes = __size_of::<V>();
+
+            assert(size_V_bytes != 0);
+
+            let bytes = if size_V_bytes < 8 {
+                // Len * size_of_word
+                len * 8
+            } else {
+                len * size_V_bytes
+            };
+
+            let number_of_slots = (bytes + 31) >> 5;
+            let ptr = alloc_bytes(number_of_slots * 32);
+            // Load the stored slice into the pointer.
+            let _ = __state_load_quad(key, ptr, number_of_slots);
+
+            if size_V_bytes < 8 {
+                let len_bytes = len * size_V_bytes;
+                let new_vec = alloc_bytes(len_bytes);
+                let mut i = 0;
+                while i < len {
+                    // The stored vec is offset with 1 word per element, remove the padding for elements less than the size of a word
+                    // (size_of_word * element)
+                    ptr
+                        .add_uint_offset((8 * i))
+                        .copy_bytes_to(new_vec.add::<V>(i), size_V_bytes);
+                    i += 1;
                 }
+
+                Vec::from(
+                    asm(ptr: (new_vec, len_bytes)) {
+                        ptr: raw_slice
+                    },
+                )
+            } else {
+                Vec::from(
+                    asm(ptr: (ptr, bytes)) {
+                        ptr: raw_slice
+                    },
+                )
             }
         }
     }
@@ -199,10 +199,10 @@ impl u64 {
         };
 
         asm(sum, overflow, left: self, right: right, result_ptr: result) {
-            add sum left right;
-            move overflow of;
-            sw result_ptr overflow i0;
-            sw result_ptr sum i1;
+            add  sum        left     right;
+            move overflow   of;
+            sw   result_ptr overflow i0;
+            sw   result_ptr sum      i1;
         };
 
         set_flags(prior_flags);
@@ -252,10 +252,10 @@ impl u64 {
             right: right,
             result_ptr: result,
         ) {
-            mul product left right;
-            move overflow of;
-            sw result_ptr overflow i0;
-            sw result_ptr product i1;
+            mul  product    left     right;
+            move overflow   of;
+            sw   result_ptr overflow i0;
+            sw   result_ptr product  i1;
         };
 
         set_flags(prior_flags);
@@ -3,11 +3,14 @@ use crate::{
         code_actions::{CodeActionContext, CODE_ACTION_IMPORT_TITLE},
         diagnostic::DiagnosticData,
     },
-    core::token::{get_range_from_span, ParsedAstToken, SymbolKind, TypedAstToken},
+    core::{
+        token::{get_range_from_span, ParsedAstToken, SymbolKind, TypedAstToken},
+        token_map::TokenMap,
+    },
 };
 use lsp_types::{
     CodeAction as LspCodeAction, CodeActionKind, CodeActionOrCommand, Position, Range, TextEdit,
-    WorkspaceEdit,
+    Url, WorkspaceEdit,
 };
 use serde_json::Value;
 use std::{
@@ -36,32 +39,16 @@ pub(crate) fn import_code_action(
     let call_paths = get_call_paths_for_name(ctx, &symbol_name)?;
 
     // Collect the tokens we need to determine where to insert the import statement.
-    let mut use_statements = Vec::<TyUseStatement>::new();
-    let mut include_statements = Vec::<TyIncludeStatement>::new();
-    let mut program_type_keyword = None;
-
-    ctx.tokens.tokens_for_file(ctx.temp_uri).for_each(|item| {
-        if let Some(TypedAstToken::TypedUseStatement(use_stmt)) = &item.value().as_typed() {
-            use_statements.push(use_stmt.clone());
-        } else if let Some(TypedAstToken::TypedIncludeStatement(include_stmt)) =
-            &item.value().as_typed()
-        {
-            include_statements.push(include_stmt.clone());
-        } else if item.value().kind == SymbolKind::ProgramTypeKeyword {
-            if let Some(ParsedAstToken::Keyword(ident)) = &item.value().as_parsed() {
-                program_type_keyword = Some(ident.clone());
-            }
-        }
-    });
+    let file_imports = FileImports::new(ctx.tokens, ctx.temp_uri);
 
     // Create a list of code actions, one for each potential call path.
     let actions = call_paths
         .map(|call_path| {
             let text_edit = get_text_edit(
                 &call_path,
-                &use_statements,
-                &include_statements,
-                &program_type_keyword,
+                &file_imports.use_statements,
+                &file_imports.include_statements,
+                &file_imports.program_type_keyword,
             );
             let changes = HashMap::from([(ctx.uri.clone(), vec![text_edit])]);
 
@@ -85,6 +72,74 @@ pub(crate) fn import_code_action(
     None
 }
 
+/// Returns a [TextEdit] to insert an import statement for the given [CallPath] in the file, or [None]
+/// if the file already imports it.
+pub(crate) fn import_text_edit(
+    tokens: &TokenMap,
+    uri: &Url,
+    call_path: &CallPath,
+) -> Option<TextEdit> {
+    let file_imports = FileImports::new(tokens, uri);
+    if file_imports.imports(call_path) {
+        return None;
+    }
+    Some(get_text_edit(
+        call_path,
+        &file_imports.use_statements,
+        &file_imports.include_statements,
+        &file_imports.program_type_keyword,
+    ))
+}
+
+/// The tokens of a file which determine where an import statement is inserted.
+struct FileImports {
+    use_statements: Vec<TyUseStatement>,
+    include_statements: Vec<TyIncludeStatement>,
+    program_type_keyword: Option<Ident>,
+}
+
+impl FileImports {
+    fn new(tokens: &TokenMap, uri: &Url) -> Self {
+        let mut file_imports = FileImports {
+            use_statements: vec![],
+            include_statements: vec![],
+            program_type_keyword: None,
+        };
+        tokens.tokens_for_file(uri).for_each(|item| {
+            if let Some(TypedAstToken::TypedUseStatement(use_stmt)) = &item.value().as_typed() {
+                file_imports.use_statements.push(use_stmt.clone());
+            } else if let Some(TypedAstToken::TypedIncludeStatement(include_stmt)) =
+                &item.value().as_typed()
+            {
+                file_imports.include_statements.push(include_stmt.clone());
+            } else if item.value().kind == SymbolKind::ProgramTypeKeyword {
+                if let Some(ParsedAstToken::Keyword(ident)) = &item.value().as_parsed() {
+                    file_imports.program_type_keyword = Some(ident.clone());
+                }
+            }
+        });
+        file_imports
+    }
+
+    /// Returns true if one of the use statements imports the item at the given [CallPath], either
+    /// by name or with a glob import of its module.
+    fn imports(&self, call_path: &CallPath) -> bool {
+        self.use_statements.iter().any(|use_stmt| {
+            use_stmt.call_path.len() == call_path.prefixes.len()
+                && use_stmt
+                    .call_path
+                    .iter()
+                    .zip(call_path.prefixes.iter())
+                    .all(|(stmt_prefix, prefix)| stmt_prefix.as_str() == prefix.as_str())
+                && match &use_stmt.import_type {
+                    ImportType::Star => true,
+                    ImportType::Item(ident) => ident.as_str() == call_path.suffix.as_str(),
+                    ImportType::SelfImport(_) => false,
+                }
+        })
+    }
+}
+
 /// Returns an [Iterator] of [CallPath]s that match the given symbol name. The [CallPath]s are sorted
 /// alphabetically.
 pub(crate) fn get_call_paths_for_name<'s>(
@@ -1,6 +1,8 @@
 mod auto_import;
 mod qualify;
 
+pub(crate) use self::auto_import::import_text_edit;
+
 use crate::capabilities::{code_actions::CodeActionContext, diagnostic::DiagnosticData};
 use lsp_types::CodeActionOrCommand;
 
@@ -9,6 +9,7 @@ pub mod storage_field;
 pub mod struct_decl;
 pub mod struct_field;
 pub mod trait_fn;
+pub mod use_statement;
 
 use crate::core::{
     session::Session,
@@ -30,6 +31,7 @@ pub(crate) const CODE_ACTION_NEW_TITLE: &str = "Generate `new`";
 pub(crate) const CODE_ACTION_DOC_TITLE: &str = "Generate a documentation template";
 pub(crate) const CODE_ACTION_IMPORT_TITLE: &str = "Import";
 pub(crate) const CODE_ACTION_QUALIFY_TITLE: &str = "Qualify as";
+pub(crate) const CODE_ACTION_EXPAND_GLOB_TITLE: &str = "Expand glob import";
 
 #[derive(Clone)]
 pub(crate) struct CodeActionContext<'a> {
@@ -90,6 +92,7 @@ pub fn code_actions(
             TypedAstToken::TypedEnumVariant(decl) => enum_variant::code_actions(decl, &ctx),
             TypedAstToken::TypedStructField(decl) => struct_field::code_actions(decl, &ctx),
             TypedAstToken::TypedTraitFn(decl) => trait_fn::code_actions(decl, &ctx),
+            TypedAstToken::TypedUseStatement(decl) => use_statement::code_actions(decl, &ctx),
             _ => Vec::new(),
         })
         .unwrap_or_default();
@@ -0,0 +1,120 @@
+use super::glob_import_items;
+use crate::{
+    capabilities::code_actions::{CodeAction, CodeActionContext, CODE_ACTION_EXPAND_GLOB_TITLE},
+    core::token::{get_range_from_span, TokenAstNode, TokenIdent, TypedAstToken},
+};
+use lsp_types::{CodeActionDisabled, Range, Url};
+use std::collections::BTreeSet;
+use sway_core::language::ty::TyUseStatement;
+use sway_types::Span;
+
+/// Rewrites `use foo::*` into an import of the items of `foo` that are used in the file.
+pub(crate) struct ExpandGlobCodeAction<'a> {
+    decl: &'a TyUseStatement,
+    uri: &'a Url,
+    used_items: Vec<String>,
+    glob_span: Option<Span>,
+}
+
+impl<'a> CodeAction<'a, TyUseStatement> for ExpandGlobCodeAction<'a> {
+    fn new(ctx: &CodeActionContext<'a>, decl: &'a TyUseStatement) -> Self {
+        let items = ctx
+            .namespace
+            .as_ref()
+            .map(|namespace| glob_import_items(ctx.engines, namespace.root_module(), decl))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|ident| TokenIdent::new(&ident, ctx.engines.se()))
+            .collect::<Vec<_>>();
+
+        // An item is used if a token outside of the use statements of the file refers to its
+        // declaration. Tokens that only share the name of the item, like fields, are ignored.
+        let used_items = ctx
+            .tokens
+            .tokens_for_file(ctx.temp_uri)
+            .filter_map(|item| {
+                let token = item.value();
+                if matches!(
+                    token.ast_node,
+                    TokenAstNode::Typed(TypedAstToken::TypedUseStatement(_))
+                ) {
+                    return None;
+                }
+                let declared = token.declared_token_ident(ctx.engines)?;
+                items
+                    .iter()
+                    .find(|ident| **ident == declared)
+                    .map(|ident| ident.name.clone())
+            })
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        Self {
+            decl,
+            uri: ctx.uri,
+            used_items,
+            glob_span: glob_span(decl),
+        }
+    }
+
+    fn new_text(&self) -> String {
+        match self.used_items.as_slice() {
+            [item] => item.clone(),
+            items => format!("{{{}}}", items.join(", ")),
+        }
+    }
+
+    fn title(&self) -> String {
+        CODE_ACTION_EXPAND_GLOB_TITLE.to_string()
+    }
+
+    fn decl(&self) -> &TyUseStatement {
+        self.decl
+    }
+
+    fn uri(&self) -> &Url {
+        self.uri
+    }
+
+    fn disabled(&self) -> Option<CodeActionDisabled> {
+        if self.glob_span.is_none() {
+            return Some(CodeActionDisabled {
+                reason: "Only single glob imports can be expanded".to_string(),
+            });
+        }
+        if self.used_items.is_empty() {
+            return Some(CodeActionDisabled {
+                reason: "No items of the glob import are used".to_string(),
+            });
+        }
+        None
+    }
+
+    fn range(&self) -> Range {
+        self.glob_span
+            .as_ref()
+            .map(get_range_from_span)
+            .unwrap_or_default()
+    }
+}
+
+/// Returns the span of the `*` of the glob import, if it is the only one in the use statement.
+///
+/// All the imports of a use statement share its span, so the `*` belonging to `decl` can only be
+/// found unambiguously if there is a single one.
+fn glob_span(decl: &TyUseStatement) -> Option<Span> {
+    let span = &decl.span;
+    let mut globs = span.as_str().match_indices('*');
+    let (offset, _) = globs.next()?;
+    if globs.next().is_some() {
+        return None;
+    }
+    let start = span.start() + offset;
+    Span::new(
+        span.src().clone(),
+        start,
+        start + 1,
+        span.source_id().cloned(),
+    )
+}
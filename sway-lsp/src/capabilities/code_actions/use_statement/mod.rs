@@ -0,0 +1,81 @@
+pub(crate) mod expand_glob;
+
+use self::expand_glob::ExpandGlobCodeAction;
+use crate::capabilities::code_actions::{CodeAction, CodeActionContext};
+use lsp_types::CodeActionOrCommand;
+use sway_core::{
+    language::{parsed::ImportType, ty},
+    namespace::Module,
+    Engines,
+};
+use sway_types::Ident;
+
+pub(crate) fn code_actions(
+    use_stmt: &ty::TyUseStatement,
+    ctx: &CodeActionContext,
+) -> Vec<CodeActionOrCommand> {
+    if !matches!(use_stmt.import_type, ImportType::Star) {
+        return Vec::new();
+    }
+    vec![ExpandGlobCodeAction::new(ctx, use_stmt).code_action()]
+}
+
+/// Returns the declared names of the items brought in by the glob import `use_stmt`, sorted
+/// alphabetically.
+///
+/// The imported module is looked up the same way the compiler resolves the import: absolute paths
+/// and paths into external libraries from the root module, other paths from the module
+/// containing `use_stmt`.
+pub(crate) fn glob_import_items(
+    engines: &Engines,
+    root: &Module,
+    use_stmt: &ty::TyUseStatement,
+) -> Vec<Ident> {
+    let current = containing_module(root, use_stmt).unwrap_or(root);
+    let is_external = use_stmt
+        .call_path
+        .first()
+        .and_then(|first| current.submodule(engines, &[first.clone()]))
+        .is_some_and(|module| module.is_external);
+    let path = if is_external || use_stmt.is_absolute {
+        use_stmt.call_path.clone()
+    } else {
+        current
+            .mod_path()
+            .iter()
+            .chain(use_stmt.call_path.iter())
+            .cloned()
+            .collect()
+    };
+    let Some(module) = root.submodule(engines, &path) else {
+        return Vec::new();
+    };
+    let mut items = module
+        .current_items()
+        .symbols()
+        .keys()
+        .cloned()
+        .collect::<Vec<_>>();
+    items.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+    items
+}
+
+/// Returns the submodule of `root` implemented in the file of `use_stmt`.
+fn containing_module<'a>(root: &'a Module, use_stmt: &ty::TyUseStatement) -> Option<&'a Module> {
+    let source_id = use_stmt.span.source_id()?;
+    root.submodules()
+        .values()
+        .filter(|module| !module.is_external)
+        .find_map(|module| {
+            let in_file = module
+                .span()
+                .as_ref()
+                .and_then(|span| span.source_id())
+                .is_some_and(|id| id == source_id);
+            if in_file {
+                Some(module)
+            } else {
+                containing_module(module, use_stmt)
+            }
+        })
+}
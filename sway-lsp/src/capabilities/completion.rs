@@ -1,34 +1,139 @@
-use crate::core::token::TokenIdent;
+use crate::{
+    capabilities::code_actions::diagnostic::import_text_edit,
+    core::{
+        token::{TokenIdent, TypedAstToken},
+        token_map::TokenMap,
+    },
+};
 use lsp_types::{
     CompletionItem, CompletionItemKind, CompletionItemLabelDetails, CompletionTextEdit, Position,
-    Range, TextEdit,
+    Range, TextEdit, Url,
 };
 use sway_core::{
-    language::ty::{TyAstNodeContent, TyDecl, TyFunctionDecl, TyFunctionParameter},
-    Engines, Namespace, TypeId, TypeInfo,
+    language::ty::{
+        AbiDecl, StorageDecl, TyAstNodeContent, TyDecl, TyFunctionDecl, TyFunctionParameter,
+        TyProgram, TyTraitInterfaceItem,
+    },
+    AbiName, Engines, TypeArgument, TypeId, TypeInfo,
 };
+use sway_types::Spanned;
+
+/// The state of the compiled program needed to build completion items.
+pub(crate) struct CompletionContext<'a> {
+    pub(crate) program: &'a TyProgram,
+    pub(crate) engines: &'a Engines,
+    pub(crate) tokens: &'a TokenMap,
+    pub(crate) uri: &'a Url,
+}
 
 pub(crate) fn to_completion_items(
-    namespace: &Namespace,
-    engines: &Engines,
+    ctx: &CompletionContext,
     ident_to_complete: &TokenIdent,
     fn_decl: &TyFunctionDecl,
     position: Position,
+    trigger_char: &str,
 ) -> Vec<CompletionItem> {
-    type_id_of_raw_ident(engines, namespace, &ident_to_complete.name, fn_decl)
-        .map(|type_id| completion_items_for_type_id(engines, namespace, type_id, position))
+    // Completion was invoked explicitly instead of by typing `.`, so offer the values in scope.
+    if trigger_char.is_empty() {
+        return configurable_completion_items(ctx);
+    }
+    if let Some(namespace_names) = storage_namespace_names(&ident_to_complete.name) {
+        return storage_completion_items(ctx, &namespace_names);
+    }
+    type_id_of_raw_ident(ctx, &ident_to_complete.name, fn_decl)
+        .map(|type_id| completion_items_for_type_id(ctx, type_id, position))
         .unwrap_or_default()
 }
 
-/// Gathers the given [`TypeId`] struct's fields and methods and builds completion items.
+/// Builds completion items for the configurables of the program.
+fn configurable_completion_items(ctx: &CompletionContext) -> Vec<CompletionItem> {
+    ctx.program
+        .configurables
+        .iter()
+        .map(|configurable| CompletionItem {
+            kind: Some(CompletionItemKind::CONSTANT),
+            label: configurable.call_path.suffix.as_str().to_string(),
+            label_details: Some(CompletionItemLabelDetails {
+                description: Some(configurable.type_ascription.span.clone().str()),
+                detail: None,
+            }),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// If the ident is the `storage` keyword, optionally followed by a storage namespace path like
+/// `storage::ns1::ns2`, returns the names of the namespaces.
+fn storage_namespace_names(ident_name: &str) -> Option<Vec<&str>> {
+    let namespace_path = ident_name.strip_prefix("storage")?;
+    if namespace_path.is_empty() {
+        return Some(vec![]);
+    }
+    let names = namespace_path
+        .strip_prefix("::")?
+        .split("::")
+        .collect::<Vec<_>>();
+    names.iter().all(|name| !name.is_empty()).then_some(names)
+}
+
+/// Builds completion items for the fields of the contract storage declared within the given
+/// storage namespace.
+fn storage_completion_items(
+    ctx: &CompletionContext,
+    namespace_names: &[&str],
+) -> Vec<CompletionItem> {
+    let Some(storage_decl) = ctx.program.declarations.iter().find_map(|decl| match decl {
+        TyDecl::StorageDecl(StorageDecl { decl_id }) => Some(ctx.engines.de().get_storage(decl_id)),
+        _ => None,
+    }) else {
+        return vec![];
+    };
+    storage_decl
+        .fields
+        .iter()
+        .filter(|field| {
+            field.namespace_names.len() == namespace_names.len()
+                && field
+                    .namespace_names
+                    .iter()
+                    .zip(namespace_names)
+                    .all(|(field_namespace, name)| field_namespace.as_str() == *name)
+        })
+        .map(|field| CompletionItem {
+            kind: Some(CompletionItemKind::FIELD),
+            label: field.name.as_str().to_string(),
+            label_details: Some(CompletionItemLabelDetails {
+                description: Some(field.type_argument.span.clone().str()),
+                detail: None,
+            }),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Gathers the given [`TypeId`] struct's fields and methods, or the methods of the ABI of a contract
+/// caller, and builds completion items.
 fn completion_items_for_type_id(
-    engines: &Engines,
-    namespace: &Namespace,
+    ctx: &CompletionContext,
     type_id: TypeId,
     position: Position,
 ) -> Vec<CompletionItem> {
+    let engines = ctx.engines;
+    let namespace = &ctx.program.root.namespace;
     let mut completion_items = vec![];
     let type_info = engines.te().get(type_id);
+    if let TypeInfo::ContractCaller {
+        abi_name: AbiName::Known(abi_name),
+        ..
+    } = &*type_info
+    {
+        completion_items.extend(abi_method_completion_items(
+            ctx,
+            abi_name.suffix.as_str(),
+            type_id,
+            position,
+        ));
+    }
     if let TypeInfo::Struct(decl_id) = &*type_info {
         let struct_decl = engines.de().get_struct(&decl_id.clone());
         for field in &struct_decl.fields {
@@ -55,37 +160,12 @@ fn completion_items_for_type_id(
 
         // Only show methods that take `self` as the first parameter.
         if params.first().is_some_and(TyFunctionParameter::is_self) {
-            let params_short = if params.is_empty() {
-                "()".to_string()
-            } else {
-                "(…)".to_string()
-            };
-            let params_edit_str = params
-                .iter()
-                .filter_map(|p| {
-                    if p.is_self() {
-                        return None;
-                    }
-                    Some(p.name.as_str())
-                })
-                .collect::<Vec<&str>>()
-                .join(", ");
-            let item = CompletionItem {
-                kind: Some(CompletionItemKind::METHOD),
-                label: format!("{}{}", method.name().clone().as_str(), params_short),
-                text_edit: Some(CompletionTextEdit::Edit(TextEdit {
-                    range: Range {
-                        start: position,
-                        end: position,
-                    },
-                    new_text: format!("{}({})", method.name().clone().as_str(), params_edit_str),
-                })),
-                label_details: Some(CompletionItemLabelDetails {
-                    description: Some(fn_signature_string(engines, &fn_decl, &type_id)),
-                    detail: None,
-                }),
-                ..Default::default()
-            };
+            let item = method_completion_item(
+                method.name().clone().as_str(),
+                params,
+                fn_signature_string(engines, params, &fn_decl.return_type, &type_id),
+                position,
+            );
             completion_items.push(item);
         }
     }
@@ -93,14 +173,144 @@ fn completion_items_for_type_id(
     completion_items
 }
 
+/// Builds completion items for the methods of the ABI with the given name. The types of the
+/// parameters declared in other modules are imported by additional text edits, as calling the
+/// methods requires constructing them.
+fn abi_method_completion_items(
+    ctx: &CompletionContext,
+    abi_name: &str,
+    type_id: TypeId,
+    position: Position,
+) -> Vec<CompletionItem> {
+    let engines = ctx.engines;
+    let Some(abi_decl) = ctx
+        .tokens
+        .tokens_for_name(&abi_name.to_string())
+        .find_map(|item| match item.value().as_typed() {
+            Some(TypedAstToken::TypedDeclaration(TyDecl::AbiDecl(AbiDecl { decl_id }))) => {
+                Some(engines.de().get_abi(decl_id))
+            }
+            _ => None,
+        })
+    else {
+        return vec![];
+    };
+
+    abi_decl
+        .interface_surface
+        .iter()
+        .filter_map(|item| match item {
+            TyTraitInterfaceItem::TraitFn(decl_ref) => Some(engines.de().get_trait_fn(decl_ref)),
+            _ => None,
+        })
+        .map(|trait_fn| {
+            let mut item = method_completion_item(
+                trait_fn.name.as_str(),
+                &trait_fn.parameters,
+                fn_signature_string(
+                    engines,
+                    &trait_fn.parameters,
+                    &trait_fn.return_type,
+                    &type_id,
+                ),
+                position,
+            );
+            let import_edits = import_edits_for_params(ctx, &trait_fn.parameters);
+            if !import_edits.is_empty() {
+                item.additional_text_edits = Some(import_edits);
+            }
+            item
+        })
+        .collect()
+}
+
+/// Returns the [TextEdit]s importing the structs and enums used as the types of the parameters
+/// which are neither declared in, nor imported into, the file being edited.
+fn import_edits_for_params(
+    ctx: &CompletionContext,
+    params: &[TyFunctionParameter],
+) -> Vec<TextEdit> {
+    let engines = ctx.engines;
+    let current_path = ctx.uri.to_file_path().ok();
+    let mut text_edits: Vec<TextEdit> = vec![];
+    for param in params {
+        let (call_path, span) = match &*engines.te().get(param.type_argument.type_id) {
+            TypeInfo::Struct(decl_id) => {
+                let decl = engines.de().get_struct(decl_id);
+                (decl.call_path.clone(), decl.span())
+            }
+            TypeInfo::Enum(decl_id) => {
+                let decl = engines.de().get_enum(decl_id);
+                (decl.call_path.clone(), decl.span())
+            }
+            _ => continue,
+        };
+        let declared_in_file = span
+            .source_id()
+            .map(|source_id| engines.se().get_path(source_id))
+            .is_some_and(|path| Some(path) == current_path);
+        if declared_in_file {
+            continue;
+        }
+        let call_path = call_path.to_import_path(engines, &ctx.program.root.namespace);
+        if let Some(text_edit) = import_text_edit(ctx.tokens, ctx.uri, &call_path) {
+            // Edits of the same use statement would overlap, so only the first one is kept.
+            if text_edits.iter().all(|edit| edit.range != text_edit.range) {
+                text_edits.push(text_edit);
+            }
+        }
+    }
+    text_edits
+}
+
+/// Builds the completion item of a method, leaving out the `self` parameter from the inserted call.
+fn method_completion_item(
+    name: &str,
+    params: &[TyFunctionParameter],
+    signature: String,
+    position: Position,
+) -> CompletionItem {
+    let params_short = if params.is_empty() {
+        "()".to_string()
+    } else {
+        "(…)".to_string()
+    };
+    let params_edit_str = params
+        .iter()
+        .filter_map(|p| {
+            if p.is_self() {
+                return None;
+            }
+            Some(p.name.as_str())
+        })
+        .collect::<Vec<&str>>()
+        .join(", ");
+    CompletionItem {
+        kind: Some(CompletionItemKind::METHOD),
+        label: format!("{}{}", name, params_short),
+        text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+            range: Range {
+                start: position,
+                end: position,
+            },
+            new_text: format!("{}({})", name, params_edit_str),
+        })),
+        label_details: Some(CompletionItemLabelDetails {
+            description: Some(signature),
+            detail: None,
+        }),
+        ..Default::default()
+    }
+}
+
 /// Returns the [String] of the shortened function signature to display in the completion item's label details.
 fn fn_signature_string(
     engines: &Engines,
-    fn_decl: &TyFunctionDecl,
+    params: &[TyFunctionParameter],
+    return_type: &TypeArgument,
     parent_type_id: &TypeId,
 ) -> String {
-    let params_str = fn_decl
-        .parameters
+    let params_str = params
         .iter()
         .map(|p| {
             replace_self_with_type_str(engines, p.type_argument.clone().span.str(), parent_type_id)
@@ -110,11 +320,7 @@ fn fn_signature_string(
     format!(
         "fn({}) -> {}",
         params_str,
-        replace_self_with_type_str(
-            engines,
-            fn_decl.return_type.clone().span.str(),
-            parent_type_id
-        )
+        replace_self_with_type_str(engines, return_type.clone().span.str(), parent_type_id)
     )
 }
 
@@ -132,22 +338,28 @@ fn replace_self_with_type_str(
 
 /// Returns the [TypeId] of an ident that may include field accesses and may be incomplete.
 /// For the first part of the ident, it looks for instantiation in the scope of the given
-/// [`TyFunctionDecl`]. For example, given `a.b.c`, it will return the type ID of `c`
-/// if it can resolve `a` in the given function.
+/// [`TyFunctionDecl`], or among the configurables of the program. For example, given `a.b.c`,
+/// it will return the type ID of `c` if it can resolve `a` in the given function.
 fn type_id_of_raw_ident(
-    engines: &Engines,
-    namespace: &Namespace,
+    ctx: &CompletionContext,
     ident_name: &str,
     fn_decl: &TyFunctionDecl,
 ) -> Option<TypeId> {
+    let engines = ctx.engines;
+    let namespace = &ctx.program.root.namespace;
+    let type_id_of_ident = |ident_name: &str| {
+        type_id_of_local_ident(ident_name, fn_decl)
+            .or_else(|| type_id_of_configurable(ctx.program, ident_name))
+    };
+
     // If this ident has no field accesses or chained methods, look for it in the local function scope.
     if !ident_name.contains('.') {
-        return type_id_of_local_ident(ident_name, fn_decl);
+        return type_id_of_ident(ident_name);
     }
 
     // Otherwise, start with the first part of the ident and follow the subsequent types.
     let parts = ident_name.split('.').collect::<Vec<&str>>();
-    let mut curr_type_id = type_id_of_local_ident(parts[0], fn_decl);
+    let mut curr_type_id = type_id_of_ident(parts[0]);
     let mut i = 1;
 
     while (i < parts.len()) && curr_type_id.is_some() {
@@ -210,3 +422,12 @@ fn type_id_of_local_ident(ident_name: &str, fn_decl: &TyFunctionDecl) -> Option<
             })
         })
 }
+
+/// Returns the [TypeId] of the configurable with the given name, if the program declares one.
+fn type_id_of_configurable(program: &TyProgram, ident_name: &str) -> Option<TypeId> {
+    program
+        .configurables
+        .iter()
+        .find(|configurable| configurable.call_path.suffix.as_str() == ident_name)
+        .map(|configurable| configurable.return_type)
+}
@@ -0,0 +1,69 @@
+use crate::{core::session::Session, lsp_ext::LaunchConfiguration};
+use lsp_types::Url;
+use std::sync::Arc;
+
+/// The debugger type `forc-debug` is registered as by the Sway VSCode extension.
+const DEBUGGER_TYPE: &str = "sway";
+
+/// Generate a launch configuration for every test in the package of the session, and one for all
+/// the tests of each file containing tests.
+///
+/// The configurations are sorted by file and by the position of the tests within their file.
+pub fn launch_configurations(
+    session: &Arc<Session>,
+    debug_server: Option<u16>,
+) -> Vec<LaunchConfiguration> {
+    let _p = tracing::trace_span!("launch_configurations").entered();
+    let mut tests = session
+        .runnables
+        .iter()
+        .flat_map(|entry| {
+            // Runnables are collected from the temp workspace, the configurations refer to the
+            // sources in the user's workspace.
+            let program = Url::from_file_path(entry.key())
+                .ok()
+                .and_then(|url| session.sync.temp_to_workspace_url(&url).ok())
+                .and_then(|url| url.to_file_path().ok());
+            entry
+                .value()
+                .iter()
+                .filter_map(|runnable| {
+                    let test_name = runnable.test_name()?.to_string();
+                    Some((program.clone()?, runnable.range().start, test_name))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    tests.sort_by(|(a_program, a_start, _), (b_program, b_start, _)| {
+        (a_program, a_start).cmp(&(b_program, b_start))
+    });
+
+    let launch_configuration = |name, program, test_name| LaunchConfiguration {
+        debugger_type: DEBUGGER_TYPE.to_string(),
+        request: "launch".to_string(),
+        name,
+        program,
+        test_name,
+        debug_server,
+    };
+    let mut configurations = Vec::new();
+    for (i, (program, _, test_name)) in tests.iter().enumerate() {
+        let file_name = program
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        if i == 0 || tests[i - 1].0 != *program {
+            configurations.push(launch_configuration(
+                format!("Debug all tests in {file_name}"),
+                program.clone(),
+                None,
+            ));
+        }
+        configurations.push(launch_configuration(
+            format!("Debug test {test_name} ({file_name})"),
+            program.clone(),
+            Some(test_name.clone()),
+        ));
+    }
+    configurations
+}
@@ -3,8 +3,8 @@ use crate::{
     error::{DocumentError, LanguageServerError},
 };
 use lsp_types::{Position, Range, TextEdit, Url};
-use std::sync::Arc;
-use swayfmt::Formatter;
+use std::{path::Path, sync::Arc};
+use swayfmt::{config::manifest::Config, on_type, Formatter};
 
 pub fn format_text(documents: &Documents, url: &Url) -> Result<Vec<TextEdit>, LanguageServerError> {
     let _p = tracing::trace_span!("format_text").entered();
@@ -18,6 +18,51 @@ pub fn format_text(documents: &Documents, url: &Url) -> Result<Vec<TextEdit>, La
         .map(|page_text_edit| vec![page_text_edit])
 }
 
+/// Formats the line at the `position`, after `ch` was typed there.
+///
+/// The formatting follows the `swayfmt.toml` found in the `workspace_dir` or its parents, or the
+/// default config if there is none.
+pub fn format_on_type(
+    documents: &Documents,
+    url: &Url,
+    workspace_dir: &Path,
+    position: Position,
+    ch: &str,
+) -> Result<Vec<TextEdit>, LanguageServerError> {
+    let _p = tracing::trace_span!("format_on_type").entered();
+    let document = documents.try_get(url.path()).try_unwrap().ok_or_else(|| {
+        DocumentError::DocumentNotFound {
+            path: url.path().to_string(),
+        }
+    })?;
+    let Some(typed) = ch.chars().next() else {
+        return Ok(vec![]);
+    };
+
+    let config = Formatter::from_dir(workspace_dir)
+        .map(|formatter| formatter.config)
+        .unwrap_or_else(|err| {
+            tracing::warn!("Using the default formatting config: {err}");
+            Config::default()
+        });
+
+    let edits =
+        on_type::format_on_type(document.get_text(), position.line as usize, typed, &config)
+            .into_iter()
+            .map(|edit| {
+                let line = edit.line as u32;
+                TextEdit {
+                    range: Range::new(
+                        Position::new(line, edit.start as u32),
+                        Position::new(line, edit.end as u32),
+                    ),
+                    new_text: edit.new_text,
+                }
+            })
+            .collect();
+    Ok(edits)
+}
+
 pub fn get_page_text_edit(
     text: Arc<str>,
     formatter: &mut Formatter,
@@ -1,6 +1,14 @@
-use crate::core::session::Session;
-use lsp_types::{DocumentHighlight, Position, Url};
+use crate::core::{
+    session::Session,
+    token::{get_range_from_span, TypedAstToken},
+};
+use lsp_types::{DocumentHighlight, Position, Range, Url};
 use std::sync::Arc;
+use sway_core::{
+    language::ty::{self, TyAstNodeContent, TyCodeBlock, TyExpression, TyExpressionVariant},
+    Engines, TypeInfo,
+};
+use sway_types::{Span, Spanned};
 
 pub fn get_highlights(
     session: Arc<Session>,
@@ -8,10 +16,117 @@ pub fn get_highlights(
     position: Position,
 ) -> Option<Vec<DocumentHighlight>> {
     let _p = tracing::trace_span!("get_highlights").entered();
-    session.token_ranges(url, position).map(|ranges| {
+    let ranges =
+        fn_exit_points(&session, url, position).or_else(|| session.token_ranges(url, position))?;
+    Some(
         ranges
             .into_iter()
             .map(|range| DocumentHighlight { range, kind: None })
-            .collect()
-    })
+            .collect(),
+    )
+}
+
+/// If `position` is on the `fn` keyword of a function, get the ranges of the keyword and of all
+/// the points the function may exit at: the implicit returns of its body and the `return`s and
+/// calls to `revert` found in its control flow graph.
+fn fn_exit_points(session: &Session, url: &Url, position: Position) -> Option<Vec<Range>> {
+    let engines = session.engines.read();
+    let fn_tokens = session
+        .token_map
+        .tokens_at_position(&engines, url, position, Some(true));
+    let (fn_decl, fn_keyword) = fn_tokens.iter().find_map(|item| {
+        let Some(TypedAstToken::TypedFunctionDeclaration(fn_decl)) = item.value().as_typed() else {
+            return None;
+        };
+        let fn_keyword = fn_keyword_range(fn_decl)?;
+        (position >= fn_keyword.start && position <= fn_keyword.end)
+            .then_some((fn_decl, fn_keyword))
+    })?;
+
+    let mut exit_points = fn_decl.explicit_exit_points(&engines);
+    collect_tail_exit_points(&engines, &fn_decl.body, &fn_decl.span, &mut exit_points);
+
+    let mut ranges: Vec<Range> = exit_points.iter().map(get_range_from_span).collect();
+    ranges.sort_by(|a, b| (a.start, a.end).cmp(&(b.start, b.end)));
+    ranges.dedup();
+    ranges.insert(0, fn_keyword);
+    Some(ranges)
+}
+
+/// Get the range of the `fn` keyword preceding the name of the function.
+fn fn_keyword_range(fn_decl: &ty::TyFunctionDecl) -> Option<Range> {
+    let decl_span = &fn_decl.span;
+    let name_start = fn_decl.name.span().start();
+    let prefix = decl_span.src().get(decl_span.start()..name_start)?;
+    let start = decl_span.start() + prefix.rfind("fn")?;
+    let span = Span::new(
+        decl_span.src().clone(),
+        start,
+        start + 2,
+        decl_span.source_id().cloned(),
+    )?;
+    Some(get_range_from_span(&span))
+}
+
+/// Collect the expressions whose values the block evaluates to. If the block doesn't end in an
+/// expression, its closing brace, taken from the end of `block_span`, is the exit point.
+fn collect_tail_exit_points(
+    engines: &Engines,
+    block: &TyCodeBlock,
+    block_span: &Span,
+    exit_points: &mut Vec<Span>,
+) {
+    if let Some(TyAstNodeContent::Expression(TyExpression {
+        expression: TyExpressionVariant::ImplicitReturn(expr),
+        ..
+    })) = block.contents.last().map(|node| &node.content)
+    {
+        collect_tail_expr_exit_points(engines, expr, exit_points);
+        return;
+    }
+    let end = block_span.end();
+    if let Some(closing_brace) = Span::new(
+        block_span.src().clone(),
+        end.saturating_sub(1),
+        end,
+        block_span.source_id().cloned(),
+    ) {
+        exit_points.push(closing_brace);
+    }
+}
+
+/// Collect the innermost expressions `expr` evaluates to, descending into blocks and branches.
+fn collect_tail_expr_exit_points(
+    engines: &Engines,
+    expr: &TyExpression,
+    exit_points: &mut Vec<Span>,
+) {
+    match &expr.expression {
+        TyExpressionVariant::ImplicitReturn(expr) => {
+            collect_tail_expr_exit_points(engines, expr, exit_points)
+        }
+        TyExpressionVariant::CodeBlock(block) => {
+            collect_tail_exit_points(engines, block, &expr.span, exit_points)
+        }
+        TyExpressionVariant::IfExp {
+            then,
+            r#else: Some(r#else),
+            ..
+        } => {
+            collect_tail_expr_exit_points(engines, then, exit_points);
+            collect_tail_expr_exit_points(engines, r#else, exit_points);
+        }
+        TyExpressionVariant::MatchExp { desugared, .. } => {
+            collect_tail_expr_exit_points(engines, desugared, exit_points)
+        }
+        // Explicit exits are found by `TyFunctionDecl::explicit_exit_points`.
+        TyExpressionVariant::Return(_) => (),
+        _ if is_never(engines, expr) => (),
+        _ => exit_points.push(expr.span.clone()),
+    }
+}
+
+/// Does `expr` never return, e.g., is it a call to `revert`?
+fn is_never(engines: &Engines, expr: &TyExpression) -> bool {
+    matches!(*engines.te().get(expr.return_type), TypeInfo::Never)
 }
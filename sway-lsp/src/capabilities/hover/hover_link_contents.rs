@@ -90,26 +90,14 @@ impl<'a> HoverLinkContents<'a> {
 
     /// Adds all implementations of the given [`TyTraitDecl`] to the list of implementations.
     pub fn add_implementations_for_trait(&mut self, trait_decl: &TyTraitDecl) {
-        if let Some(namespace) = self.session.namespace() {
-            let call_path =
-                CallPath::from(trait_decl.name.clone()).to_fullpath(self.engines, &namespace);
-            let impl_spans = namespace
-                .module(self.engines)
-                .current_items()
-                .get_impl_spans_for_trait_name(&call_path);
-            self.add_implementations(&trait_decl.span(), impl_spans);
-        }
+        let impl_spans = self.session.impl_spans_for_trait(trait_decl);
+        self.add_implementations(&trait_decl.span(), impl_spans);
     }
 
     /// Adds implementations of the given type to the list of implementations using the [`TyDecl`].
     pub fn add_implementations_for_decl(&mut self, ty_decl: &TyDecl) {
-        if let Some(namespace) = self.session.namespace() {
-            let impl_spans = namespace
-                .module(self.engines)
-                .current_items()
-                .get_impl_spans_for_decl(self.engines, ty_decl);
-            self.add_implementations(&ty_decl.span(self.engines), impl_spans);
-        }
+        let impl_spans = self.session.impl_spans_for_decl(self.engines, ty_decl);
+        self.add_implementations(&ty_decl.span(self.engines), impl_spans);
     }
 
     /// Adds implementations of the given type to the list of implementations using the [`TypeId`].
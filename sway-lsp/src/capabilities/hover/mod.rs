@@ -3,6 +3,7 @@ pub(crate) mod hover_link_contents;
 use self::hover_link_contents::HoverLinkContents;
 use crate::config::LspClient;
 use crate::{
+    capabilities::code_actions::use_statement::glob_import_items,
     core::{
         session::Session,
         token::{SymbolKind, Token, TypedAstToken},
@@ -14,8 +15,9 @@ use crate::{
 use lsp_types::{self, Position, Url};
 use std::sync::Arc;
 use sway_core::{
-    language::{ty, Visibility},
-    Engines, TypeId,
+    ir_generation::layout::TypeLayout,
+    language::{parsed::ImportType, ty, Visibility},
+    Engines, TypeId, TypeInfo,
 };
 use sway_types::{Span, Spanned};
 
@@ -51,6 +53,19 @@ pub fn hover_data(
         });
     }
 
+    // Hovering the module of a glob import lists the items it brings in.
+    if let Some(TypedAstToken::TypedUseStatement(use_stmt)) = token.as_typed() {
+        if matches!(use_stmt.import_type, ImportType::Star)
+            && use_stmt.call_path.last().map(|ident| ident.as_str()) == Some(ident.name.as_str())
+        {
+            let contents = glob_import_hover(&session, use_stmt)?;
+            return Some(lsp_types::Hover {
+                contents,
+                range: Some(range),
+            });
+        }
+    }
+
     let contents = match &token.declared_token_ident(&session.engines.read()) {
         Some(decl_ident) => {
             let t = session.token_map().try_get(decl_ident).try_unwrap()?;
@@ -80,6 +95,33 @@ pub fn hover_data(
     })
 }
 
+/// Lists the items brought in by the glob import `use_stmt`.
+fn glob_import_hover(
+    session: &Session,
+    use_stmt: &ty::TyUseStatement,
+) -> Option<lsp_types::HoverContents> {
+    let engines = session.engines.read();
+    let namespace = session.namespace()?;
+    let items = glob_import_items(&engines, namespace.root_module(), use_stmt)
+        .iter()
+        .map(|ident| ident.as_str().to_string())
+        .collect::<Vec<_>>();
+    let path = use_stmt
+        .call_path
+        .iter()
+        .map(|ident| ident.as_str())
+        .collect::<Vec<_>>()
+        .join("::");
+    let content = Markup::new()
+        .maybe_add_sway_block(Some(format!("use {path}::*")))
+        .text(&format!(
+            "Imports {} items: {}",
+            items.len(),
+            items.join(", ")
+        ));
+    Some(lsp_types::HoverContents::Markup(markup_content(&content)))
+}
+
 fn visibility_as_str(visibility: Visibility) -> &'static str {
     match visibility {
         Visibility::Private => "",
@@ -118,6 +160,27 @@ fn format_variable_hover(is_mutable: bool, type_name: &str, token_name: &str) ->
     format!("let{mutability} {token_name}: {type_name}")
 }
 
+/// Formats the memory layout of a type, along with the size of its ABI encoding.
+fn format_type_layout(layout: &TypeLayout) -> String {
+    let mut text = format!(
+        "Size: {} bytes, alignment: {} bytes",
+        layout.size_in_bytes, layout.alignment_in_bytes
+    );
+    if let Some(abi_encoded_size) = &layout.abi_encoded_size {
+        text.push_str(&format!("\n\nABI encoded size: {abi_encoded_size}"));
+    }
+    if !layout.fields.is_empty() {
+        text.push_str("\n\n| Field | Offset | Size |\n| --- | --- | --- |");
+        for field in &layout.fields {
+            text.push_str(&format!(
+                "\n| `{}` | {} | {} |",
+                field.name, field.offset_in_bytes, field.size_in_bytes
+            ));
+        }
+    }
+    text
+}
+
 fn markup_content(markup: &Markup) -> lsp_types::MarkupContent {
     let kind = lsp_types::MarkupKind::Markdown;
     let value = markdown::format_docs(markup.as_str());
@@ -142,6 +205,9 @@ fn hover_format(
     // Used to collect all the information we need to generate links for the hover component.
     let mut hover_link_contents = HoverLinkContents::new(session, engines);
 
+    // The memory layout of the hovered type declaration, if it has one.
+    let mut type_layout = None;
+
     let sway_block = token
         .as_typed()
         .as_ref()
@@ -160,6 +226,7 @@ fn hover_format(
                 ty::TyDecl::StructDecl(ty::StructDecl { decl_id, .. }) => {
                     let struct_decl = decl_engine.get_struct(decl_id);
                     hover_link_contents.add_implementations_for_decl(decl);
+                    type_layout = TypeLayout::new(engines, &TypeInfo::Struct(*decl_id));
                     Some(format_visibility_hover(
                         struct_decl.visibility,
                         decl.friendly_type_name(),
@@ -178,12 +245,24 @@ fn hover_format(
                 ty::TyDecl::EnumDecl(ty::EnumDecl { decl_id, .. }) => {
                     let enum_decl = decl_engine.get_enum(decl_id);
                     hover_link_contents.add_implementations_for_decl(decl);
+                    type_layout = TypeLayout::new(engines, &TypeInfo::Enum(*decl_id));
                     Some(format_visibility_hover(
                         enum_decl.visibility,
                         decl.friendly_type_name(),
                         ident_name,
                     ))
                 }
+                ty::TyDecl::TypeAliasDecl(ty::TypeAliasDecl { decl_id, .. }) => {
+                    let type_alias_decl = decl_engine.get_type_alias(decl_id);
+                    let aliased_type_id = type_alias_decl.ty.type_id;
+                    hover_link_contents.add_related_types(&aliased_type_id);
+                    type_layout = TypeLayout::new(engines, &engines.te().get(aliased_type_id));
+                    Some(format!(
+                        "{}type {ident_name} = {}",
+                        visibility_as_str(type_alias_decl.visibility),
+                        engines.help_out(aliased_type_id)
+                    ))
+                }
                 ty::TyDecl::AbiDecl(ty::AbiDecl { .. }) => {
                     hover_link_contents.add_implementations_for_decl(decl);
                     Some(format!("{} {}", decl.friendly_type_name(), &ident_name))
@@ -220,15 +299,18 @@ fn hover_format(
             _ => None,
         });
 
-    let content = Markup::new()
+    let mut content = Markup::new()
         .maybe_add_sway_block(sway_block)
-        .text(&doc_comment)
-        .maybe_add_links(
-            engines.se(),
-            &hover_link_contents.related_types,
-            &hover_link_contents.implementations,
-            client_config,
-        );
+        .text(&doc_comment);
+    if let Some(type_layout) = &type_layout {
+        content = content.text(&format_type_layout(type_layout));
+    }
+    let content = content.maybe_add_links(
+        engines.se(),
+        &hover_link_contents.related_types,
+        &hover_link_contents.implementations,
+        client_config,
+    );
 
     lsp_types::HoverContents::Markup(markup_content(&content))
 }
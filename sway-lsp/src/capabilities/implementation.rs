@@ -0,0 +1,152 @@
+use crate::{
+    core::{
+        session::Session,
+        token::{get_range_from_span, TypedAstToken},
+    },
+    utils::document::get_url_from_span,
+};
+use lsp_types::{request::GotoImplementationResponse, Location, Position, Url};
+use std::sync::Arc;
+use sway_core::{decl_engine::InterfaceDeclId, language::ty, Engines};
+use sway_types::{Ident, Span, Spanned};
+
+/// Returns the locations of the implementations of the item at the given position.
+///
+/// - For a trait, these are all impls of the trait.
+/// - For a struct or an enum, these are all impls for the type.
+/// - For a trait method, or a method implemented in an impl of a trait, these are the
+///   declaration of the method in the trait followed by its definitions in all impls of the trait.
+pub fn get_implementations(
+    session: Arc<Session>,
+    url: &Url,
+    position: Position,
+) -> Option<GotoImplementationResponse> {
+    let _p = tracing::trace_span!("get_implementations").entered();
+    let engines = session.engines.read();
+    let token = session
+        .token_map()
+        .token_at_position(url, position)?
+        .value()
+        .clone();
+    // Resolve the token to its declaration, so that requests from any usage behave the same.
+    let decl_token = token
+        .declared_token_ident(&engines)
+        .and_then(|ident| session.token_map().try_get(&ident).try_unwrap())
+        .map(|item| item.value().clone())
+        .unwrap_or(token);
+
+    let spans = match decl_token.as_typed()? {
+        TypedAstToken::TypedDeclaration(ty::TyDecl::TraitDecl(ty::TraitDecl { decl_id })) => {
+            let trait_decl = engines.de().get_trait(decl_id);
+            session.impl_spans_for_trait(&trait_decl)
+        }
+        TypedAstToken::TypedDeclaration(
+            decl @ (ty::TyDecl::StructDecl(_) | ty::TyDecl::EnumDecl(_)),
+        ) => session.impl_spans_for_decl(&engines, decl),
+        TypedAstToken::TypedTraitFn(trait_fn) => {
+            let trait_decl = trait_decl_of_trait_fn(&session, &engines, trait_fn)?;
+            trait_method_spans(&session, &engines, &trait_decl, &trait_fn.name)
+        }
+        TypedAstToken::TypedFunctionDeclaration(fn_decl) => {
+            let Some(ty::TyDecl::ImplSelfOrTrait(ty::ImplSelfOrTrait { decl_id })) =
+                &fn_decl.implementing_type
+            else {
+                return None;
+            };
+            let impl_decl = engines.de().get_impl_self_or_trait(decl_id);
+            let InterfaceDeclId::Trait(trait_decl_id) = impl_decl.trait_decl_ref.as_ref()?.id()
+            else {
+                return None;
+            };
+            let trait_decl = engines.de().get_trait(trait_decl_id);
+            trait_method_spans(&session, &engines, &trait_decl, &fn_decl.name)
+        }
+        _ => return None,
+    };
+
+    // The spans are already sorted and deduplicated, so the locations are unique.
+    let locations = spans
+        .iter()
+        .filter_map(|span| span_to_location(&session, &engines, span))
+        .collect::<Vec<_>>();
+    Some(GotoImplementationResponse::Array(locations))
+}
+
+/// Returns the spans of the declaration of the method `method_name` in the given trait,
+/// followed by the spans of its definitions in all impls of the trait.
+fn trait_method_spans(
+    session: &Session,
+    engines: &Engines,
+    trait_decl: &ty::TyTraitDecl,
+    method_name: &Ident,
+) -> Vec<Span> {
+    let mut spans = trait_decl
+        .interface_surface
+        .iter()
+        .filter_map(|item| match item {
+            ty::TyTraitInterfaceItem::TraitFn(decl_ref) => {
+                let trait_fn = engines.de().get_trait_fn(decl_ref);
+                (trait_fn.name == *method_name).then(|| trait_fn.name.span())
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let impl_spans = session.impl_spans_for_trait(trait_decl);
+    let mut method_spans = session
+        .token_map()
+        .iter()
+        .filter_map(|item| match item.value().as_typed() {
+            Some(TypedAstToken::TypedFunctionDeclaration(fn_decl))
+                if fn_decl.name == *method_name
+                    && impl_spans
+                        .iter()
+                        .any(|impl_span| span_contains(impl_span, &fn_decl.span)) =>
+            {
+                Some(fn_decl.name.span())
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    // The token map is unordered, sort the definitions to keep the response stable.
+    method_spans.sort_by_key(|span| (span.source_id().copied(), span.start(), span.end()));
+    method_spans.dedup();
+    spans.append(&mut method_spans);
+    spans
+}
+
+/// Finds the trait that declares the given trait method.
+fn trait_decl_of_trait_fn(
+    session: &Session,
+    engines: &Engines,
+    trait_fn: &ty::TyTraitFn,
+) -> Option<Arc<ty::TyTraitDecl>> {
+    let trait_fn_span = trait_fn.name.span();
+    session.token_map().iter().find_map(|item| {
+        let Some(TypedAstToken::TypedDeclaration(ty::TyDecl::TraitDecl(ty::TraitDecl {
+            decl_id,
+        }))) = item.value().as_typed()
+        else {
+            return None;
+        };
+        let trait_decl = engines.de().get_trait(decl_id);
+        span_contains(&trait_decl.span, &trait_fn_span).then_some(trait_decl)
+    })
+}
+
+/// Returns true if `inner` lies within `outer` in the same source file.
+fn span_contains(outer: &Span, inner: &Span) -> bool {
+    outer.source_id() == inner.source_id()
+        && outer.start() <= inner.start()
+        && inner.end() <= outer.end()
+}
+
+/// Converts a span to a [Location] in the user's workspace.
+fn span_to_location(session: &Session, engines: &Engines, span: &Span) -> Option<Location> {
+    let span = session
+        .sync
+        .temp_to_workspace_span(engines.se(), span)
+        .ok()?;
+    let url = get_url_from_span(engines.se(), &span).ok()?;
+    Some(Location::new(url, get_range_from_span(&span)))
+}
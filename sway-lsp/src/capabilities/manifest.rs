@@ -0,0 +1,138 @@
+//! Diagnostics and quick fixes for the `Forc.toml` manifest of the workspace.
+
+use crate::{
+    capabilities::diagnostic::DiagnosticData,
+    core::{token::TokenIdent, token_map::TokenMap},
+};
+use forc_pkg::{manifest::GenericManifestFile, PackageManifestFile};
+use lsp_types::{
+    CodeAction as LspCodeAction, CodeActionKind, CodeActionOrCommand, CodeActionResponse,
+    Diagnostic, DiagnosticSeverity, DiagnosticTag, Position, Range, TextEdit, Url, WorkspaceEdit,
+};
+use std::{collections::HashMap, path::Path};
+
+pub(crate) const CODE_ACTION_REMOVE_DEPENDENCY_TITLE: &str = "Remove unused dependency";
+
+/// Returns a warning for each dependency declared in the `[dependencies]` table of the manifest at
+/// `manifest_path` that isn't referred to by any source file in `src_dir`.
+///
+/// A dependency is used if any token in the package has its name, which errs on the side of
+/// keeping dependencies whose name is shadowed by a local item.
+pub(crate) fn unused_dependency_diagnostics(
+    manifest_path: &Path,
+    src_dir: &Path,
+    tokens: &TokenMap,
+) -> Vec<Diagnostic> {
+    let Ok(manifest) = PackageManifestFile::from_file(manifest_path) else {
+        return vec![];
+    };
+    let Ok(manifest_text) = std::fs::read_to_string(manifest_path) else {
+        return vec![];
+    };
+
+    let is_used = |dep_name: &str| {
+        let name = dep_name.replace('-', "_");
+        tokens.tokens_for_name(&name).any(|item| {
+            let TokenIdent { path, .. } = item.key();
+            path.as_ref().is_some_and(|path| path.starts_with(src_dir))
+        })
+    };
+
+    manifest
+        .deps()
+        .filter(|(name, _)| !is_used(name))
+        .filter_map(|(name, _)| {
+            let line = dependency_line(&manifest_text, name)?;
+            let data = DiagnosticData {
+                unused_dependency_name: Some(name.clone()),
+                ..Default::default()
+            };
+            Some(Diagnostic {
+                range: Range {
+                    start: Position::new(line, 0),
+                    end: Position::new(line, name.len() as u32),
+                },
+                severity: Some(DiagnosticSeverity::WARNING),
+                message: format!("Dependency `{name}` is never used."),
+                tags: Some(vec![DiagnosticTag::UNNECESSARY]),
+                data: serde_json::to_value(data).ok(),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+/// Returns the quick fixes removing the unused dependencies reported in `diagnostics`.
+pub(crate) fn code_actions(uri: &Url, diagnostics: &[Diagnostic]) -> Option<CodeActionResponse> {
+    let actions = diagnostics
+        .iter()
+        .filter_map(|diag| {
+            let data = serde_json::from_value::<DiagnosticData>(diag.data.clone()?).ok()?;
+            let name = data.unused_dependency_name?;
+            let line = diag.range.start.line;
+            let text_edit = TextEdit {
+                range: Range {
+                    start: Position::new(line, 0),
+                    end: Position::new(line + 1, 0),
+                },
+                new_text: String::new(),
+            };
+            let changes = HashMap::from([(uri.clone(), vec![text_edit])]);
+            Some(CodeActionOrCommand::CodeAction(LspCodeAction {
+                title: format!("{CODE_ACTION_REMOVE_DEPENDENCY_TITLE} `{name}`"),
+                kind: Some(CodeActionKind::QUICKFIX),
+                diagnostics: Some(vec![diag.clone()]),
+                edit: Some(WorkspaceEdit {
+                    changes: Some(changes),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }))
+        })
+        .collect::<Vec<_>>();
+
+    (!actions.is_empty()).then_some(actions)
+}
+
+/// Returns the line of the `name = ...` entry in the `[dependencies]` table of the manifest.
+fn dependency_line(manifest_text: &str, name: &str) -> Option<u32> {
+    let mut in_dependencies = false;
+    for (index, line) in manifest_text.lines().enumerate() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_dependencies = line == "[dependencies]";
+            continue;
+        }
+        if !in_dependencies {
+            continue;
+        }
+        let key = line.split('=').next().map(str::trim);
+        if key.map(|key| key.trim_matches('"')) == Some(name) {
+            return Some(index as u32);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dependency_line_in_dependencies_table() {
+        let manifest = r#"[project]
+name = "foo"
+
+[dependencies]
+std = { path = "../std" }
+"my-lib" = { path = "../my-lib" }
+
+[contract-dependencies]
+other = { path = "../other" }
+"#;
+        assert_eq!(dependency_line(manifest, "std"), Some(4));
+        assert_eq!(dependency_line(manifest, "my-lib"), Some(5));
+        assert_eq!(dependency_line(manifest, "name"), None);
+        assert_eq!(dependency_line(manifest, "other"), None);
+    }
+}
@@ -1,12 +1,15 @@
 pub mod code_actions;
 pub mod code_lens;
 pub mod completion;
+pub mod debug_launch;
 pub mod diagnostic;
 pub mod document_symbol;
 pub mod formatting;
 pub mod highlight;
 pub mod hover;
+pub mod implementation;
 pub mod inlay_hints;
+pub mod manifest;
 pub mod on_enter;
 pub mod rename;
 pub mod runnable;
@@ -38,6 +38,10 @@ pub trait Runnable: core::fmt::Debug + Send + Sync + 'static {
     fn arguments(&self) -> Option<Vec<Value>>;
     /// The range in the file where the runnable button should be displayed.
     fn range(&self) -> &Range;
+    /// The name of the test, if the runnable is a test function.
+    fn test_name(&self) -> Option<&str> {
+        None
+    }
 }
 
 impl Runnable for RunnableMainFn {
@@ -70,4 +74,7 @@ impl Runnable for RunnableTestFn {
     fn range(&self) -> &Range {
         &self.range
     }
+    fn test_name(&self) -> Option<&str> {
+        self.test_name.as_deref()
+    }
 }
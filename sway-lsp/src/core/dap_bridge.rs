@@ -0,0 +1,78 @@
+//! A bridge between editors and the `forc-debug` Debug Adapter Protocol (DAP) server.
+//!
+//! `forc-debug --serve` speaks DAP over its stdio, which requires editors to know how to locate
+//! and launch it. The bridge instead listens on a local TCP port, so that launch configurations
+//! only need to point their `debugServer` at it. Every connection spawns a new `forc-debug`
+//! process and forwards the DAP messages between the connection and the process unchanged.
+
+use std::{net::Ipv4Addr, process::Stdio};
+use tokio::{
+    io::{self, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    process::Command,
+};
+
+/// The executable of the DAP server the requests are forwarded to.
+pub const FORC_DEBUG_COMMAND: &str = "forc-debug";
+
+#[derive(Debug)]
+pub struct DapBridge {
+    /// The local port the bridge accepts DAP connections on.
+    pub port: u16,
+}
+
+impl DapBridge {
+    /// Bind the bridge to a free local port and start accepting connections in the background.
+    pub async fn start() -> io::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let port = listener.local_addr()?.port();
+        tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, _)) => {
+                        tokio::spawn(async move {
+                            if let Err(err) = forward_to_forc_debug(stream).await {
+                                tracing::error!("Debug adapter bridge failed: {err}");
+                            }
+                        });
+                    }
+                    Err(err) => {
+                        tracing::error!(
+                            "Debug adapter bridge stopped accepting connections: {err}"
+                        );
+                        break;
+                    }
+                }
+            }
+        });
+        Ok(DapBridge { port })
+    }
+}
+
+/// Spawn a `forc-debug` DAP server and forward the DAP messages between it and `stream` until
+/// either side closes the connection.
+async fn forward_to_forc_debug(stream: TcpStream) -> io::Result<()> {
+    let mut child = Command::new(FORC_DEBUG_COMMAND)
+        .arg("--serve")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()?;
+    let mut adapter_in = child.stdin.take().expect("stdin of forc-debug is piped");
+    let mut adapter_out = child.stdout.take().expect("stdout of forc-debug is piped");
+    let (mut client_in, mut client_out) = stream.into_split();
+
+    let requests = async {
+        io::copy(&mut client_in, &mut adapter_in).await?;
+        adapter_in.shutdown().await
+    };
+    let responses = async {
+        io::copy(&mut adapter_out, &mut client_out).await?;
+        client_out.shutdown().await
+    };
+    // Dropping `child` kills the DAP server once either side is done.
+    tokio::select! {
+        result = requests => result,
+        result = responses => result,
+    }
+}
@@ -1,3 +1,4 @@
+pub mod dap_bridge;
 pub mod document;
 pub mod session;
 pub(crate) mod sync;
@@ -43,7 +43,7 @@ use sway_core::{
     BuildTarget, Engines, LspConfig, Namespace, Programs,
 };
 use sway_error::{error::CompileError, handler::Handler, warning::CompileWarning};
-use sway_types::{ProgramId, SourceEngine, Spanned};
+use sway_types::{ProgramId, SourceEngine, Span, Spanned};
 use sway_utils::{helpers::get_sway_files, PerformanceData};
 
 pub type RunnableMap = DashMap<PathBuf, Vec<Box<dyn Runnable>>>;
@@ -230,12 +230,18 @@ impl Session {
         if let Some(TypedAstToken::TypedFunctionDeclaration(fn_decl)) = fn_token.as_typed() {
             let program = compiled_program.typed.clone()?;
             let engines = self.engines.read();
+            let ctx = capabilities::completion::CompletionContext {
+                program: &program,
+                engines: &engines,
+                tokens: &self.token_map,
+                uri,
+            };
             return Some(capabilities::completion::to_completion_items(
-                &program.root.namespace,
-                &engines,
+                &ctx,
                 ident_to_complete,
                 fn_decl,
                 position,
+                trigger_char,
             ));
         }
         None
@@ -248,6 +254,31 @@ impl Session {
         Some(program.root.namespace)
     }
 
+    /// Returns the spans of all impls of the given trait found in any module of the compiled
+    /// program, including the modules of its dependencies, sorted by their location.
+    pub fn impl_spans_for_trait(&self, trait_decl: &ty::TyTraitDecl) -> Vec<Span> {
+        self.namespace()
+            .map(|namespace| {
+                namespace
+                    .root_module()
+                    .get_all_impl_spans_for_trait_name(&trait_decl.call_path)
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the spans of all impls for the type declared by the given [ty::TyDecl] found in
+    /// any module of the compiled program, including the modules of its dependencies, sorted by
+    /// their location.
+    pub fn impl_spans_for_decl(&self, engines: &Engines, ty_decl: &ty::TyDecl) -> Vec<Span> {
+        self.namespace()
+            .map(|namespace| {
+                namespace
+                    .root_module()
+                    .get_all_impl_spans_for_decl(engines, ty_decl)
+            })
+            .unwrap_or_default()
+    }
+
     /// Generate hierarchical document symbols for the given file.
     pub fn document_symbols(&self, url: &Url) -> Option<Vec<DocumentSymbol>> {
         let _p = tracing::trace_span!("document_symbols").entered();
@@ -478,8 +509,21 @@ pub fn parse_project(
         // Only write the diagnostics results on didSave or didOpen.
         if !config.optimized_build {
             if let Some((errors, warnings)) = &diagnostics {
-                *session.diagnostics.write() =
+                let mut diagnostics =
                     capabilities::diagnostic::get_diagnostics(warnings, errors, engines.se());
+                if let (Some(manifest_path), Ok(temp_dir)) =
+                    (session.sync.manifest_path(), session.sync.temp_dir())
+                {
+                    diagnostics
+                        .entry(manifest_path.clone())
+                        .or_default()
+                        .warnings = capabilities::manifest::unused_dependency_diagnostics(
+                        &manifest_path,
+                        &temp_dir,
+                        &session.token_map,
+                    );
+                }
+                *session.diagnostics.write() = diagnostics;
             }
         }
     }
@@ -23,6 +23,8 @@ pub enum LanguageServerError {
     FailedToParse,
     #[error("Error formatting document: {0}")]
     FormatError(FormatterError),
+    #[error("Failed to start the debug adapter bridge. {0}")]
+    DapBridgeFailed(std::io::Error),
     #[error("No Programs were returned from the compiler")]
     ProgramsIsNone,
     #[error("Unable to acquire a semaphore permit for parsing")]
@@ -6,9 +6,10 @@ use crate::{
 };
 use forc_tracing::{tracing_subscriber, FmtSpan, StdioTracingWriter, TracingWriterMode};
 use lsp_types::{
-    CodeLens, CompletionResponse, DocumentFormattingParams, DocumentSymbolResponse,
-    InitializeResult, InlayHint, InlayHintParams, PrepareRenameResponse, RenameParams,
-    SemanticTokensParams, SemanticTokensRangeParams, SemanticTokensRangeResult,
+    request::{GotoImplementationParams, GotoImplementationResponse},
+    CodeLens, CompletionResponse, DocumentFormattingParams, DocumentOnTypeFormattingParams,
+    DocumentSymbolResponse, InitializeResult, InlayHint, InlayHintParams, PrepareRenameResponse,
+    RenameParams, SemanticTokensParams, SemanticTokensRangeParams, SemanticTokensRangeResult,
     SemanticTokensResult, TextDocumentIdentifier, Url, WorkspaceEdit,
 };
 use std::{
@@ -17,7 +18,7 @@ use std::{
     path::{Path, PathBuf},
 };
 use sway_types::{Ident, Spanned};
-use sway_utils::PerformanceData;
+use sway_utils::{constants::MANIFEST_FILE_NAME, PerformanceData};
 use tower_lsp::jsonrpc::Result;
 use tracing::metadata::LevelFilter;
 
@@ -95,6 +96,28 @@ pub async fn handle_goto_definition(
     }
 }
 
+pub async fn handle_goto_implementation(
+    state: &ServerState,
+    params: GotoImplementationParams,
+) -> Result<Option<GotoImplementationResponse>> {
+    let _ = state.wait_for_parsing().await;
+    match state
+        .uri_and_session_from_workspace(&params.text_document_position_params.text_document.uri)
+        .await
+    {
+        Ok((uri, session)) => {
+            let position = params.text_document_position_params.position;
+            Ok(capabilities::implementation::get_implementations(
+                session, &uri, position,
+            ))
+        }
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        }
+    }
+}
+
 pub async fn handle_completion(
     state: &ServerState,
     params: lsp_types::CompletionParams,
@@ -253,11 +276,48 @@ pub async fn handle_formatting(
         })
 }
 
+pub async fn handle_on_type_formatting(
+    state: &ServerState,
+    params: DocumentOnTypeFormattingParams,
+) -> Result<Option<Vec<lsp_types::TextEdit>>> {
+    // On-type formatting doesn't need the parsed program, so there is no need to wait for it.
+    let position = params.text_document_position;
+    state
+        .uri_and_session_from_workspace(&position.text_document.uri)
+        .await
+        .and_then(|(uri, session)| {
+            let workspace_dir = session.sync.manifest_dir()?;
+            capabilities::formatting::format_on_type(
+                &state.documents,
+                &uri,
+                &workspace_dir,
+                position.position,
+                &params.ch,
+            )
+            .map(Some)
+        })
+        .or_else(|err| {
+            tracing::error!("{}", err.to_string());
+            Ok(None)
+        })
+}
+
 pub async fn handle_code_action(
     state: &ServerState,
     params: lsp_types::CodeActionParams,
 ) -> Result<Option<lsp_types::CodeActionResponse>> {
     let _ = state.wait_for_parsing().await;
+    if params
+        .text_document
+        .uri
+        .path()
+        .ends_with(MANIFEST_FILE_NAME)
+    {
+        return Ok(capabilities::manifest::code_actions(
+            &params.text_document.uri,
+            &params.context.diagnostics,
+        ));
+    }
     match state
         .uri_and_session_from_workspace(&params.text_document.uri)
         .await
@@ -509,6 +569,36 @@ pub fn handle_visualize(
     }
 }
 
+/// Generate the launch configurations for debugging the tests of the package containing the
+/// document. The configurations connect to the debug adapter bridge, which is started if needed.
+pub async fn handle_debug_launch_configurations(
+    state: &ServerState,
+    params: lsp_ext::DebugLaunchConfigurationsParams,
+) -> Result<Option<Vec<lsp_ext::LaunchConfiguration>>> {
+    let _ = state.wait_for_parsing().await;
+    let session = match state
+        .uri_and_session_from_workspace(&params.text_document.uri)
+        .await
+    {
+        Ok((_, session)) => session,
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            return Ok(None);
+        }
+    };
+    let debug_server = match state.dap_bridge().await {
+        Ok(bridge) => Some(bridge.port),
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            None
+        }
+    };
+    Ok(Some(capabilities::debug_launch::launch_configurations(
+        &session,
+        debug_server,
+    )))
+}
+
 /// This method is triggered by the test suite to request the latest compilation metrics.
 pub(crate) async fn metrics(
     state: &ServerState,
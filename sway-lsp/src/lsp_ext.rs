@@ -2,6 +2,7 @@
 
 use lsp_types::{TextDocumentContentChangeEvent, TextDocumentIdentifier, Url};
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -31,3 +32,27 @@ pub struct VisualizeParams {
 pub struct MetricsParams {
     pub text_document: TextDocumentIdentifier,
 }
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DebugLaunchConfigurationsParams {
+    pub text_document: TextDocumentIdentifier,
+}
+
+/// A VSCode launch configuration debugging Sway tests with `forc-debug`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchConfiguration {
+    #[serde(rename = "type")]
+    pub debugger_type: String,
+    pub request: String,
+    pub name: String,
+    /// The source file containing the tests.
+    pub program: PathBuf,
+    /// The test to debug. All the tests in `program` are debugged if `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_name: Option<String>,
+    /// The port of the debug adapter bridge forwarding the DAP requests to `forc-debug`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub debug_server: Option<u16>,
+}
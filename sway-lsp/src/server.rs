@@ -3,16 +3,21 @@
 
 use crate::{
     handlers::{notification, request},
-    lsp_ext::{MetricsParams, OnEnterParams, ShowAstParams, VisualizeParams},
+    lsp_ext::{
+        DebugLaunchConfigurationsParams, LaunchConfiguration, MetricsParams, OnEnterParams,
+        ShowAstParams, VisualizeParams,
+    },
     server_state::ServerState,
 };
 use lsp_types::{
+    request::{GotoImplementationParams, GotoImplementationResponse},
     CodeActionParams, CodeActionResponse, CodeLens, CodeLensParams, CompletionParams,
     CompletionResponse, DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
     DidCloseTextDocumentParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
-    DocumentFormattingParams, DocumentHighlight, DocumentHighlightParams, DocumentSymbolParams,
-    DocumentSymbolResponse, GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverParams,
-    InitializeParams, InitializeResult, InitializedParams, InlayHint, InlayHintParams, Location,
+    DocumentFormattingParams, DocumentHighlight, DocumentHighlightParams,
+    DocumentOnTypeFormattingParams, DocumentSymbolParams, DocumentSymbolResponse,
+    GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverParams, InitializeParams,
+    InitializeResult, InitializedParams, InlayHint, InlayHintParams, Location,
     PrepareRenameResponse, ReferenceParams, RenameParams, SemanticTokensParams,
     SemanticTokensRangeParams, SemanticTokensRangeResult, SemanticTokensResult,
     TextDocumentIdentifier, TextDocumentPositionParams, TextEdit, WorkspaceEdit,
@@ -119,10 +124,24 @@ impl LanguageServer for ServerState {
         request::handle_goto_definition(self, params).await
     }
 
+    async fn goto_implementation(
+        &self,
+        params: GotoImplementationParams,
+    ) -> Result<Option<GotoImplementationResponse>> {
+        request::handle_goto_implementation(self, params).await
+    }
+
     async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
         request::handle_formatting(self, params).await
     }
 
+    async fn on_type_formatting(
+        &self,
+        params: DocumentOnTypeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        request::handle_on_type_formatting(self, params).await
+    }
+
     async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
         request::handle_rename(self, params).await
     }
@@ -163,4 +182,11 @@ impl ServerState {
     ) -> Result<Option<Vec<(String, PerformanceData)>>> {
         request::metrics(self, params).await
     }
+
+    pub async fn debug_launch_configurations(
+        &self,
+        params: DebugLaunchConfigurationsParams,
+    ) -> Result<Option<Vec<LaunchConfiguration>>> {
+        request::handle_debug_launch_configurations(self, params).await
+    }
 }
@@ -3,6 +3,7 @@
 use crate::{
     config::{Config, GarbageCollectionConfig, Warnings},
     core::{
+        dap_bridge::DapBridge,
         document::{Documents, PidLockedFiles},
         session::{self, Session},
     },
@@ -28,7 +29,7 @@ use std::{
     },
 };
 use sway_core::LspConfig;
-use tokio::sync::Notify;
+use tokio::sync::{Notify, OnceCell};
 use tower_lsp::{jsonrpc, Client};
 
 const DEFAULT_SESSION_CACHE_CAPACITY: usize = 4;
@@ -52,6 +53,8 @@ pub struct ServerState {
     pub(crate) pid_locked_files: PidLockedFiles,
     manifest_cache: DashMap<Url, Arc<PathBuf>>,
     last_compilation_state: Arc<RwLock<LastCompilationState>>,
+    /// The bridge forwarding DAP requests to `forc-debug`, started on first use.
+    dap_bridge: Arc<OnceCell<DapBridge>>,
 }
 
 impl Default for ServerState {
@@ -71,6 +74,7 @@ impl Default for ServerState {
             pid_locked_files: PidLockedFiles::new(),
             manifest_cache: DashMap::new(),
             last_compilation_state: Arc::new(RwLock::new(LastCompilationState::Uninitialized)),
+            dap_bridge: Arc::new(OnceCell::new()),
         };
         // Spawn a new thread dedicated to handling compilation tasks
         state.spawn_compilation_thread();
@@ -299,6 +303,22 @@ impl ServerState {
             client
                 .publish_diagnostics(workspace_uri.clone(), diagnostics, None)
                 .await;
+
+            // The unused dependencies of the package are reported on its manifest.
+            if let Some(manifest_path) = session.sync.manifest_path() {
+                if let Ok(manifest_uri) = Url::from_file_path(&manifest_path) {
+                    let manifest_diagnostics = session
+                        .diagnostics
+                        .read()
+                        .get(&manifest_path)
+                        .filter(|_| self.config.read().diagnostic.show_warnings)
+                        .map(|diagnostics| diagnostics.warnings.clone())
+                        .unwrap_or_default();
+                    client
+                        .publish_diagnostics(manifest_uri, manifest_diagnostics, None)
+                        .await;
+                }
+            }
         }
     }
 
@@ -333,6 +353,14 @@ impl ServerState {
         diagnostics_to_publish
     }
 
+    /// Get the debug adapter bridge, starting it if it isn't running yet.
+    pub(crate) async fn dap_bridge(&self) -> Result<&DapBridge, LanguageServerError> {
+        self.dap_bridge
+            .get_or_try_init(DapBridge::start)
+            .await
+            .map_err(LanguageServerError::DapBridgeFailed)
+    }
+
     /// Constructs and returns a tuple of `(Url, Arc<Session>)` from a given workspace URI.
     /// The returned URL represents the temp directory workspace.
     pub async fn uri_and_session_from_workspace(
@@ -179,7 +179,7 @@ impl Parse for Expr {
                 args.get()
                     .into_iter()
                     .par_bridge()
-                    .for_each(|expr| expr.parse(ctx));
+                    .for_each(|arg| arg.value.parse(ctx));
             }
             Expr::Index { target, arg } => {
                 target.parse(ctx);
@@ -202,7 +202,7 @@ impl Parse for Expr {
                 args.get()
                     .into_iter()
                     .par_bridge()
-                    .for_each(|expr| expr.parse(ctx));
+                    .for_each(|arg| arg.value.parse(ctx));
             }
             Expr::FieldProjection { target, .. } => {
                 target.parse(ctx);
@@ -210,6 +210,9 @@ impl Parse for Expr {
             Expr::TupleFieldProjection { target, .. } => {
                 target.parse(ctx);
             }
+            Expr::Try { expr, .. } => {
+                expr.parse(ctx);
+            }
             Expr::Ref {
                 mut_token, expr, ..
             } => {
@@ -224,6 +227,13 @@ impl Parse for Expr {
             Expr::Not { expr, .. } => {
                 expr.parse(ctx);
             }
+            Expr::Cast {
+                expr, as_token, ty, ..
+            } => {
+                expr.parse(ctx);
+                insert_keyword(ctx, as_token.span());
+                ty.parse(ctx);
+            }
             Expr::Mul { lhs, rhs, .. }
             | Expr::Div { lhs, rhs, .. }
             | Expr::Pow { lhs, rhs, .. }
@@ -246,16 +256,34 @@ impl Parse for Expr {
                 lhs.parse(ctx);
                 rhs.parse(ctx);
             }
+            Expr::Range { start, end, .. } => {
+                if let Some(start) = start {
+                    start.parse(ctx);
+                }
+                if let Some(end) = end {
+                    end.parse(ctx);
+                }
+            }
             Expr::Reassignment {
                 assignable, expr, ..
             } => {
                 assignable.parse(ctx);
                 expr.parse(ctx);
             }
-            Expr::Break { break_token } => {
+            Expr::Labeled { expr, .. } => {
+                expr.parse(ctx);
+            }
+            Expr::Break {
+                break_token,
+                value_opt,
+                ..
+            } => {
                 insert_keyword(ctx, break_token.span());
+                if let Some(value) = value_opt {
+                    value.parse(ctx);
+                }
             }
-            Expr::Continue { continue_token } => {
+            Expr::Continue { continue_token, .. } => {
                 insert_keyword(ctx, continue_token.span());
             }
             _ => {}
@@ -574,6 +602,9 @@ impl Parse for FnArg {
     fn parse(&self, ctx: &ParseContext) {
         self.pattern.parse(ctx);
         self.ty.parse(ctx);
+        if let Some((_eq_token, default)) = &self.default_opt {
+            default.parse(ctx);
+        }
     }
 }
 
@@ -613,6 +644,10 @@ impl Parse for StatementLet {
             ty.parse(ctx);
         }
         self.expr.parse(ctx);
+        if let Some((else_token, block)) = &self.else_opt {
+            insert_keyword(ctx, else_token.span());
+            block.get().parse(ctx);
+        }
     }
 }
 
@@ -662,11 +697,15 @@ impl Parse for IfCondition {
                 let_token,
                 lhs,
                 rhs,
+                chain,
                 ..
             } => {
                 insert_keyword(ctx, let_token.span());
                 lhs.parse(ctx);
                 rhs.parse(ctx);
+                for (_, condition) in chain {
+                    condition.parse(ctx);
+                }
             }
         }
     }
@@ -691,6 +730,13 @@ impl Parse for Pattern {
                     .par_bridge()
                     .for_each(|pattern| pattern.parse(ctx));
             }
+            Pattern::Slice(elems) => {
+                elems
+                    .get()
+                    .into_iter()
+                    .par_bridge()
+                    .for_each(|pattern| pattern.parse(ctx));
+            }
             Pattern::Struct { fields, .. } => {
                 fields
                     .get()
@@ -13,26 +13,26 @@ use crate::{
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use sway_core::{
     compiler_generated::{
-        is_generated_any_match_expression_var_name, is_generated_destructured_struct_var_name,
-        is_generated_tuple_var_name,
+        is_generated_any_match_expression_var_name, is_generated_destructured_array_var_name,
+        is_generated_destructured_struct_var_name, is_generated_tuple_var_name,
     },
     decl_engine::parsed_id::ParsedDeclId,
     language::{
         parsed::{
             AbiCastExpression, AbiDeclaration, AmbiguousPathExpression, ArrayExpression,
-            ArrayIndexExpression, AstNode, AstNodeContent, ConfigurableDeclaration,
-            ConstantDeclaration, Declaration, DelineatedPathExpression, EnumDeclaration,
-            EnumVariant, Expression, ExpressionKind, ForLoopExpression,
+            ArrayIndexExpression, AstNode, AstNodeContent, BreakExpression,
+            ConfigurableDeclaration, ConstantDeclaration, Declaration, DelineatedPathExpression,
+            EnumDeclaration, EnumVariant, Expression, ExpressionKind, ForLoopExpression,
             FunctionApplicationExpression, FunctionDeclaration, FunctionParameter, IfExpression,
             ImplItem, ImplSelfOrTrait, ImportType, IncludeStatement, IntrinsicFunctionExpression,
-            LazyOperatorExpression, MatchExpression, MethodApplicationExpression, MethodName,
-            ParseModule, ParseProgram, ParseSubmodule, QualifiedPathType, ReassignmentExpression,
-            ReassignmentTarget, RefExpression, Scrutinee, StorageAccessExpression,
-            StorageDeclaration, StorageEntry, StorageField, StorageNamespace, StructDeclaration,
-            StructExpression, StructExpressionField, StructField, StructScrutineeField,
-            SubfieldExpression, Supertrait, TraitDeclaration, TraitFn, TraitItem,
-            TraitTypeDeclaration, TupleIndexExpression, TypeAliasDeclaration, UseStatement,
-            VariableDeclaration, WhileLoopExpression,
+            LabeledBlockExpression, LazyOperatorExpression, MatchExpression,
+            MethodApplicationExpression, MethodName, ParseModule, ParseProgram, ParseSubmodule,
+            QualifiedPathType, ReassignmentExpression, ReassignmentTarget, RefExpression,
+            Scrutinee, StorageAccessExpression, StorageDeclaration, StorageEntry, StorageField,
+            StorageNamespace, StructDeclaration, StructExpression, StructExpressionField,
+            StructField, StructScrutineeField, SubfieldExpression, Supertrait, TraitDeclaration,
+            TraitFn, TraitItem, TraitTypeDeclaration, TryExpression, TupleIndexExpression,
+            TypeAliasDeclaration, UseStatement, VariableDeclaration, WhileLoopExpression,
         },
         CallPathTree, HasSubmodules, Literal,
     },
@@ -225,6 +225,7 @@ impl Parse for Expression {
             }
             ExpressionKind::Variable(name) => {
                 if !(is_generated_tuple_var_name(name.as_str())
+                    || is_generated_destructured_array_var_name(name.as_str())
                     || is_generated_any_match_expression_var_name(name.as_str()))
                 {
                     let symbol_kind = if is_generated_destructured_struct_var_name(name.as_str()) {
@@ -366,17 +367,29 @@ impl Parse for Expression {
             ExpressionKind::ForLoop(ForLoopExpression { desugared }) => {
                 desugared.parse(ctx);
             }
+            ExpressionKind::LabeledBlock(LabeledBlockExpression { block, .. }) => {
+                adaptive_iter(&block.contents, |node| node.parse(ctx));
+            }
+            ExpressionKind::Break(BreakExpression {
+                value: Some(value), ..
+            }) => {
+                value.parse(ctx);
+            }
             ExpressionKind::Reassignment(reassignment) => {
                 reassignment.parse(ctx);
             }
             ExpressionKind::ImplicitReturn(expr)
             | ExpressionKind::Return(expr)
             | ExpressionKind::Ref(RefExpression { value: expr, .. })
-            | ExpressionKind::Deref(expr) => {
+            | ExpressionKind::Deref(expr)
+            | ExpressionKind::Try(TryExpression { expr, .. }) => {
                 expr.parse(ctx);
             }
+            ExpressionKind::NamedArgument(named_arg) => {
+                named_arg.value.parse(ctx);
+            }
             // We are collecting these tokens in the lexed phase.
-            ExpressionKind::Break | ExpressionKind::Continue => {}
+            ExpressionKind::Break(_) | ExpressionKind::Continue(_) => {}
         }
     }
 }
@@ -704,6 +717,7 @@ impl Parse for ParsedDeclId<VariableDeclaration> {
         // Don't collect tokens if the idents are generated tuple or match desugaring names.
         // The individual elements are handled in the subsequent VariableDeclaration's.
         if !(is_generated_tuple_var_name(var_decl.name.as_str())
+            || is_generated_destructured_array_var_name(var_decl.name.as_str())
             || is_generated_any_match_expression_var_name(var_decl.name.as_str()))
         {
             let symbol_kind = if is_generated_destructured_struct_var_name(var_decl.name.as_str()) {
@@ -1028,6 +1042,9 @@ impl Parse for FunctionParameter {
             ),
         );
         self.type_argument.parse(ctx);
+        if let Some(default_value) = &self.default_value {
+            default_value.parse(ctx);
+        }
     }
 }
 
@@ -591,7 +591,15 @@ impl Parse for ty::TyExpression {
             ty::TyExpressionVariant::ForLoop { desugared, .. } => {
                 desugared.parse(ctx);
             }
-            ty::TyExpressionVariant::Break | ty::TyExpressionVariant::Continue => (),
+            ty::TyExpressionVariant::LabeledBlock { block, .. } => {
+                adaptive_iter(&block.contents, |node| node.parse(ctx));
+            }
+            ty::TyExpressionVariant::Break { value, .. } => {
+                if let Some(value) = value {
+                    value.parse(ctx);
+                }
+            }
+            ty::TyExpressionVariant::Continue { .. } => (),
             ty::TyExpressionVariant::Reassignment(reassignment) => {
                 reassignment.parse(ctx);
             }
@@ -905,6 +913,9 @@ impl Parse for ty::TyFunctionParameter {
             token.type_def = Some(TypeDefinition::Ident(self.name.clone()));
         }
         collect_type_argument(ctx, &self.type_argument);
+        if let Some(default_value) = &self.default_value {
+            default_value.parse(ctx);
+        }
     }
 }
 
@@ -0,0 +1,11 @@
+contract;
+
+storage {
+    balance: u64 = 0,
+    paused: bool = false,
+}
+
+#[storage(read)]
+fn read_balance() {
+    storage.
+}
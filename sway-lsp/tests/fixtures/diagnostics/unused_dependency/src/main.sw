@@ -0,0 +1,7 @@
+script;
+
+use used_lib::ANSWER;
+
+fn main() -> u64 {
+    ANSWER
+}
@@ -0,0 +1,3 @@
+library;
+
+pub const UNUSED: u64 = 0;
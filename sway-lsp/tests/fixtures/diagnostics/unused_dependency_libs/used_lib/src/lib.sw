@@ -0,0 +1,3 @@
+library;
+
+pub const ANSWER: u64 = 42;
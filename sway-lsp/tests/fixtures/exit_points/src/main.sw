@@ -0,0 +1,22 @@
+script;
+
+fn check(value: u64) -> u64 {
+    if value == 0 {
+        return 0;
+    }
+    require(value < 100, 1);
+    let doubled = if value > 50 {
+        revert(1)
+    } else {
+        value * 2
+    };
+    if doubled > 10 {
+        doubled
+    } else {
+        10
+    }
+}
+
+fn main() {
+    let _ = check(1);
+}
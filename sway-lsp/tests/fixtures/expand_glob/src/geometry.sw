@@ -0,0 +1,11 @@
+library;
+
+mod units;
+
+use units::*;
+use ::shapes::*;
+
+pub fn scaled(side: u64) -> u64 {
+    let _ = side;
+    SCALE
+}
@@ -0,0 +1,5 @@
+library;
+
+pub const SCALE: u64 = 10;
+
+pub const UNUSED: u64 = 0;
@@ -0,0 +1,12 @@
+script;
+
+mod shapes;
+mod geometry;
+
+use shapes::*;
+
+fn main() {
+    let square = Square { side: 2 };
+    let _ = square.side;
+    let _ = geometry::scaled(square.side);
+}
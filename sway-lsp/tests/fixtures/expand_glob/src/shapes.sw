@@ -0,0 +1,13 @@
+library;
+
+pub struct Square {
+    side: u64,
+}
+
+pub struct Circle {
+    radius: u64,
+}
+
+pub fn side(square: Square) -> u64 {
+    square.side
+}
@@ -0,0 +1,11 @@
+library;
+
+fn foo(x: u64) -> u64 {
+let y = 1;
+  match x {
+    0 => {
+      y
+        }
+    _ => x,
+  }
+}
@@ -0,0 +1,13 @@
+library;
+
+use ::shape::Shape;
+
+pub struct Circle {
+    pub radius: u64,
+}
+
+impl Shape for Circle {
+    fn area(self) -> u64 {
+        3 * self.radius * self.radius
+    }
+}
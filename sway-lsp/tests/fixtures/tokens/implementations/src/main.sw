@@ -0,0 +1,38 @@
+contract;
+
+mod shape;
+mod circle;
+
+use shape::Shape;
+use circle::Circle;
+
+struct Square {
+    side: u64,
+}
+
+struct Rectangle {
+    width: u64,
+    height: u64,
+}
+
+impl Shape for Square {
+    fn area(self) -> u64 {
+        self.side * self.side
+    }
+}
+
+impl Shape for Rectangle {
+    fn area(self) -> u64 {
+        self.width * self.height
+    }
+}
+
+impl Square {
+    fn double(self) -> Square {
+        Square { side: self.side * 2 }
+    }
+}
+
+fn total_area(square: Square, rectangle: Rectangle, circle: Circle) -> u64 {
+    square.area() + rectangle.area() + circle.area()
+}
@@ -0,0 +1,5 @@
+library;
+
+pub trait Shape {
+    fn area(self) -> u64;
+}
@@ -507,6 +507,7 @@ pub(crate) async fn code_action_auto_import_struct_request(server: &ServerState,
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("EvmAddress".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -550,6 +551,7 @@ pub(crate) async fn code_action_auto_import_struct_request(server: &ServerState,
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("DeepStruct".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -597,6 +599,7 @@ pub(crate) async fn code_action_auto_import_enum_request(server: &ServerState, u
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("AuthError".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -640,6 +643,7 @@ pub(crate) async fn code_action_auto_import_enum_request(server: &ServerState, u
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("DeepEnum".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -688,6 +692,7 @@ pub(crate) async fn code_action_auto_import_function_request(server: &ServerStat
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("deep_fun".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -736,6 +741,7 @@ pub(crate) async fn code_action_auto_import_constant_request(server: &ServerStat
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("TEST_CONST".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -781,6 +787,7 @@ pub(crate) async fn code_action_auto_import_trait_request(server: &ServerState,
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("TryFrom".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -824,6 +831,7 @@ pub(crate) async fn code_action_auto_import_trait_request(server: &ServerState,
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("DeepTrait".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -870,6 +878,7 @@ pub(crate) async fn code_action_auto_import_alias_request(server: &ServerState,
             range,
             DiagnosticData {
                 unknown_symbol_name: Some("A".to_string()),
+                ..Default::default()
             },
         ),
     );
@@ -909,3 +918,150 @@ pub(crate) async fn code_action_auto_import_alias_request(server: &ServerState,
     let actual = send_request(server, &params).await;
     assert_eq!(expected, actual);
 }
+
+/// Requests the expand glob import code action for the `use` statement at `line`, whose last module
+/// starts at `module_char`, and returns it along with the range of the `*`.
+async fn send_expand_glob_request(
+    server: &ServerState,
+    uri: &Url,
+    line: u32,
+    module_char: u32,
+    glob_char: u32,
+) -> (Vec<CodeActionOrCommand>, Range) {
+    let params = create_code_action_params(
+        uri.clone(),
+        Range {
+            start: Position {
+                line,
+                character: module_char,
+            },
+            end: Position {
+                line,
+                character: module_char,
+            },
+        },
+        None,
+    );
+    let glob_range = Range {
+        start: Position {
+            line,
+            character: glob_char,
+        },
+        end: Position {
+            line,
+            character: glob_char + 1,
+        },
+    };
+    (send_request(server, &params).await, glob_range)
+}
+
+pub(crate) async fn code_action_expand_glob_request(server: &ServerState, uri: &Url) {
+    // `use shapes::*;` brings in `Square`, `Circle` and `side`. Only `Square` is used, as
+    // `square.side` refers to the field and not to the function with the same name.
+    let (actual, glob_range) = send_expand_glob_request(server, uri, 5, 4, 12).await;
+    let expected = vec![create_code_action(
+        uri.clone(),
+        "Expand glob import".to_string(),
+        create_changes_map(uri, glob_range, "Square"),
+        None,
+        Some(CodeActionKind::REFACTOR),
+    )];
+    assert_eq!(expected, actual);
+}
+
+pub(crate) async fn code_action_expand_glob_relative_request(server: &ServerState, uri: &Url) {
+    // `use units::*;` in `geometry` is relative to `geometry`, not to the root module.
+    let (actual, glob_range) = send_expand_glob_request(server, uri, 4, 4, 11).await;
+    let expected = vec![create_code_action(
+        uri.clone(),
+        "Expand glob import".to_string(),
+        create_changes_map(uri, glob_range, "SCALE"),
+        None,
+        Some(CodeActionKind::REFACTOR),
+    )];
+    assert_eq!(expected, actual);
+}
+
+pub(crate) async fn code_action_expand_glob_unused_request(server: &ServerState, uri: &Url) {
+    // No item of `use ::shapes::*;` is used in `geometry`. The `side` parameter only shares the
+    // name of the `side` function.
+    let (actual, glob_range) = send_expand_glob_request(server, uri, 5, 6, 14).await;
+    let expected = vec![create_code_action(
+        uri.clone(),
+        "Expand glob import".to_string(),
+        create_changes_map(uri, glob_range, "{}"),
+        Some(CodeActionDisabled {
+            reason: "No items of the glob import are used".to_string(),
+        }),
+        Some(CodeActionKind::REFACTOR),
+    )];
+    assert_eq!(expected, actual);
+}
+
+pub(crate) async fn code_action_remove_unused_dependency_request(server: &ServerState, uri: &Url) {
+    let manifest_uri = uri.join("../Forc.toml").unwrap();
+    let range = Range {
+        start: Position {
+            line: 8,
+            character: 0,
+        },
+        end: Position {
+            line: 8,
+            character: 10,
+        },
+    };
+    let diagnostic = Diagnostic {
+        range,
+        severity: Some(DiagnosticSeverity::WARNING),
+        message: "Dependency `unused_lib` is never used.".to_string(),
+        tags: Some(vec![DiagnosticTag::UNNECESSARY]),
+        data: Some(json!(DiagnosticData {
+            unused_dependency_name: Some("unused_lib".to_string()),
+            ..Default::default()
+        })),
+        ..Default::default()
+    };
+    let params = CodeActionParams {
+        text_document: TextDocumentIdentifier {
+            uri: manifest_uri.clone(),
+        },
+        range,
+        context: CodeActionContext {
+            diagnostics: vec![diagnostic.clone()],
+            only: None,
+            trigger_kind: Some(CodeActionTriggerKind::AUTOMATIC),
+        },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+
+    // The whole line of the dependency is removed.
+    let changes = create_changes_map(
+        &manifest_uri,
+        Range {
+            start: Position {
+                line: 8,
+                character: 0,
+            },
+            end: Position {
+                line: 9,
+                character: 0,
+            },
+        },
+        "",
+    );
+    let expected = vec![CodeActionOrCommand::CodeAction(CodeAction {
+        title: "Remove unused dependency `unused_lib`".to_string(),
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        ..Default::default()
+    })];
+
+    let actual = send_request(server, &params).await;
+    assert_eq!(expected, actual);
+}
@@ -9,14 +9,19 @@ use serde_json::json;
 use std::{borrow::Cow, path::Path};
 use sway_lsp::{
     handlers::request,
-    lsp_ext::{ShowAstParams, VisualizeParams},
+    lsp_ext::{
+        DebugLaunchConfigurationsParams, LaunchConfiguration, ShowAstParams, VisualizeParams,
+    },
     server_state::ServerState,
 };
 use sway_utils::PerformanceData;
 use tower::{Service, ServiceExt};
 use tower_lsp::{
     jsonrpc::{Id, Request, Response},
-    lsp_types::*,
+    lsp_types::{
+        request::{GotoImplementationParams, GotoImplementationResponse},
+        *,
+    },
     ExitedError, LspService,
 };
 
@@ -329,6 +334,63 @@ pub(crate) async fn format_request(server: &ServerState, uri: &Url) {
     assert!(!response.unwrap().is_empty());
 }
 
+pub(crate) async fn on_type_format_request(server: &ServerState, uri: &Url) {
+    let params = DocumentOnTypeFormattingParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position: Position {
+                line: 13,
+                character: 1,
+            },
+        },
+        ch: "}".to_string(),
+        options: FormattingOptions {
+            tab_size: 4,
+            insert_spaces: true,
+            ..Default::default()
+        },
+    };
+    // The closing brace of the enum is already aligned with its declaration.
+    let response = request::handle_on_type_formatting(server, params)
+        .await
+        .unwrap();
+    assert_eq!(response, Some(vec![]));
+}
+
+pub(crate) async fn on_type_format_with_config_request(server: &ServerState, uri: &Url) {
+    let params = |line, ch: &str| DocumentOnTypeFormattingParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position: Position { line, character: 1 },
+        },
+        ch: ch.to_string(),
+        options: FormattingOptions {
+            tab_size: 4,
+            insert_spaces: true,
+            ..Default::default()
+        },
+    };
+    let edit = |line, start, end, new_text: &str| TextEdit {
+        range: Range::new(Position::new(line, start), Position::new(line, end)),
+        new_text: new_text.to_string(),
+    };
+
+    // The fixture's `swayfmt.toml` sets `tab_spaces = 2`.
+    let response = request::handle_on_type_formatting(server, params(3, ";"))
+        .await
+        .unwrap();
+    assert_eq!(response, Some(vec![edit(3, 0, 0, "  ")]));
+
+    // It also sets `match_block_trailing_comma = true`.
+    let response = request::handle_on_type_formatting(server, params(7, "}"))
+        .await
+        .unwrap();
+    assert_eq!(
+        response,
+        Some(vec![edit(7, 0, 8, "    "), edit(7, 9, 9, ",")])
+    );
+}
+
 pub(crate) async fn highlight_request(server: &ServerState, uri: &Url) {
     let params = DocumentHighlightParams {
         text_document_position_params: TextDocumentPositionParams {
@@ -375,6 +437,48 @@ pub(crate) async fn highlight_request(server: &ServerState, uri: &Url) {
     assert_eq!(expected, response.unwrap());
 }
 
+pub(crate) async fn highlight_exit_points_request(server: &ServerState, uri: &Url) {
+    let params = DocumentHighlightParams {
+        text_document_position_params: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position: Position {
+                line: 2,
+                character: 1,
+            },
+        },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    let response = request::handle_document_highlight(server, params)
+        .await
+        .unwrap();
+    let highlight = |line, start, end| DocumentHighlight {
+        range: Range {
+            start: Position {
+                line,
+                character: start,
+            },
+            end: Position {
+                line,
+                character: end,
+            },
+        },
+        kind: None,
+    };
+    let expected = vec![
+        // `fn`
+        highlight(2, 0, 2),
+        // `return 0`
+        highlight(4, 8, 16),
+        // `revert(1)`
+        highlight(8, 8, 17),
+        // The implicit returns `doubled` and `10`.
+        highlight(13, 8, 15),
+        highlight(15, 8, 10),
+    ];
+    assert_eq!(expected, response.unwrap());
+}
+
 pub(crate) async fn references_request(server: &ServerState, uri: &Url) {
     let params = ReferenceParams {
         text_document_position: TextDocumentPositionParams {
@@ -425,6 +529,85 @@ pub(crate) async fn references_request(server: &ServerState, uri: &Url) {
     assert_eq!(expected, response);
 }
 
+pub(crate) async fn implementation_request(server: &ServerState, uri: &Url) {
+    let params = |line, character| GotoImplementationParams {
+        text_document_position_params: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position: Position { line, character },
+        },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    let shape_uri = uri.join("shape.sw").unwrap();
+    let circle_uri = uri.join("circle.sw").unwrap();
+    let create_location = |uri: &Url, start: (u32, u32), end: (u32, u32)| -> Location {
+        Location {
+            uri: uri.clone(),
+            range: Range {
+                start: Position::new(start.0, start.1),
+                end: Position::new(end.0, end.1),
+            },
+        }
+    };
+    // The order of the files in a response depends on the order in which they were compiled.
+    let sort_locations = |locations: &mut [Location]| {
+        locations.sort_by(|a, b| (&a.uri, a.range.start).cmp(&(&b.uri, b.range.start)))
+    };
+    let implementations = |response: Option<GotoImplementationResponse>| match response {
+        Some(GotoImplementationResponse::Array(locations)) => locations,
+        _ => panic!("expected a list of implementation locations"),
+    };
+
+    // The impls of a trait declared in a submodule, in the root module and in another submodule.
+    let mut response = implementations(
+        request::handle_goto_implementation(server, params(17, 6))
+            .await
+            .unwrap(),
+    );
+    sort_locations(&mut response);
+    let mut expected = vec![
+        create_location(uri, (17, 0), (21, 1)),
+        create_location(uri, (23, 0), (27, 1)),
+        create_location(&circle_uri, (8, 0), (12, 1)),
+    ];
+    sort_locations(&mut expected);
+    assert_eq!(response, expected);
+
+    // The impls for a struct, including the inherent impl.
+    let response = implementations(
+        request::handle_goto_implementation(server, params(8, 8))
+            .await
+            .unwrap(),
+    );
+    assert_eq!(
+        response,
+        vec![
+            create_location(uri, (17, 0), (21, 1)),
+            create_location(uri, (29, 0), (33, 1)),
+        ]
+    );
+
+    // The trait method declaration followed by its definitions in all impls of the trait,
+    // requested from a definition and from a method call.
+    let mut expected = vec![
+        create_location(uri, (18, 7), (18, 11)),
+        create_location(uri, (24, 7), (24, 11)),
+        create_location(&circle_uri, (9, 7), (9, 11)),
+    ];
+    sort_locations(&mut expected);
+    expected.insert(0, create_location(&shape_uri, (3, 7), (3, 11)));
+    for (line, character) in [(18, 8), (36, 12)] {
+        let mut response = implementations(
+            request::handle_goto_implementation(server, params(line, character))
+                .await
+                .unwrap(),
+        );
+        assert_eq!(response.first(), expected.first());
+        sort_locations(&mut response[1..]);
+        assert_eq!(response, expected);
+    }
+}
+
 pub(crate) async fn code_lens_empty_request(server: &ServerState, uri: &Url) {
     let params = CodeLensParams {
         text_document: TextDocumentIdentifier { uri: uri.clone() },
@@ -435,6 +618,33 @@ pub(crate) async fn code_lens_empty_request(server: &ServerState, uri: &Url) {
     assert_eq!(response.unwrap().len(), 0);
 }
 
+pub(crate) async fn debug_launch_configurations_request(server: &ServerState, uri: &Url) {
+    let params = DebugLaunchConfigurationsParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+    };
+    let response = request::handle_debug_launch_configurations(server, params)
+        .await
+        .unwrap()
+        .unwrap();
+    let debug_server = response[0].debug_server;
+    assert!(debug_server.is_some());
+    let program = uri.to_file_path().unwrap();
+    let launch_configuration = |name: &str, test_name: Option<&str>| LaunchConfiguration {
+        debugger_type: "sway".to_string(),
+        request: "launch".to_string(),
+        name: name.to_string(),
+        program: program.clone(),
+        test_name: test_name.map(str::to_string),
+        debug_server,
+    };
+    let expected = vec![
+        launch_configuration("Debug all tests in main.sw", None),
+        launch_configuration("Debug test test_foo (main.sw)", Some("test_foo")),
+        launch_configuration("Debug test test_bar (main.sw)", Some("test_bar")),
+    ];
+    assert_eq!(response, expected);
+}
+
 pub(crate) async fn code_lens_request(server: &ServerState, uri: &Url) {
     let params = CodeLensParams {
         text_document: TextDocumentIdentifier { uri: uri.clone() },
@@ -558,6 +768,46 @@ pub(crate) async fn completion_request(server: &ServerState, uri: &Url) {
     assert_eq!(expected, res.unwrap());
 }
 
+pub(crate) async fn completion_storage_request(server: &ServerState, uri: &Url) {
+    let params = CompletionParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position: Position {
+                line: 9,
+                character: 12,
+            },
+        },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+        context: Some(CompletionContext {
+            trigger_kind: CompletionTriggerKind::TRIGGER_CHARACTER,
+            trigger_character: Some(".".to_string()),
+        }),
+    };
+    let res = request::handle_completion(server, params).await.unwrap();
+    let expected = CompletionResponse::Array(vec![
+        CompletionItem {
+            label: "balance".to_string(),
+            kind: Some(CompletionItemKind::FIELD),
+            label_details: Some(CompletionItemLabelDetails {
+                detail: None,
+                description: Some("u64".to_string()),
+            }),
+            ..Default::default()
+        },
+        CompletionItem {
+            label: "paused".to_string(),
+            kind: Some(CompletionItemKind::FIELD),
+            label_details: Some(CompletionItemLabelDetails {
+                detail: None,
+                description: Some("bool".to_string()),
+            }),
+            ..Default::default()
+        },
+    ]);
+    assert_eq!(expected, res.unwrap());
+}
+
 pub(crate) async fn definition_check<'a>(server: &ServerState, go_to: &'a GotoDefinition<'a>) {
     let params = GotoDefinitionParams {
         text_document_position_params: TextDocumentPositionParams {
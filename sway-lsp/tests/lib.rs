@@ -1640,6 +1640,31 @@ fn hover_docs_for_structs() {
     });
 }
 
+#[test]
+fn hover_type_layout_for_structs() {
+    run_async!({
+        let server = ServerState::default();
+        let uri = open(
+            &server,
+            test_fixtures_dir().join("tokens/structs/src/main.sw"),
+        )
+        .await;
+
+        let hover = HoverDocumentation {
+            req_uri: &uri,
+            req_line: 19,
+            req_char: 8,
+            documentation: vec![
+                "Size: 8 bytes, alignment: 8 bytes",
+                "ABI encoded size: 1 bytes",
+                "| `x` | 0 | 1 |",
+            ],
+        };
+        lsp::hover_request(&server, &hover).await;
+        let _ = server.shutdown_server();
+    });
+}
+
 #[test]
 fn hover_docs_for_enums() {
     run_async!({
@@ -1945,6 +1970,25 @@ fn publish_diagnostics_multi_file() {
     });
 }
 
+#[test]
+fn publish_diagnostics_unused_dependency() {
+    run_async!({
+        let (mut service, socket) = LspService::new(ServerState::new);
+        let fixture_dir = test_fixtures_dir().join("diagnostics/unused_dependency");
+        // The diagnostics of the opened file are followed by the ones of the manifest.
+        let expected_requests = vec![
+            get_fixture(fixture_dir.join("expected.json")),
+            get_fixture(fixture_dir.join("expected_manifest.json")),
+        ];
+        let socket_handle = assert_server_requests(socket, expected_requests).await;
+        let _ = init_and_open(&mut service, fixture_dir.join("src/main.sw")).await;
+        socket_handle
+            .await
+            .unwrap_or_else(|e| panic!("Test failed: {e:?}"));
+        shutdown_and_exit(&mut service).await;
+    });
+}
+
 lsp_capability_test!(
     semantic_tokens,
     lsp::semantic_tokens_request,
@@ -1960,16 +2004,36 @@ lsp_capability_test!(
     lsp::format_request,
     doc_comments_dir().join("src/main.sw")
 );
+lsp_capability_test!(
+    on_type_format,
+    lsp::on_type_format_request,
+    doc_comments_dir().join("src/main.sw")
+);
+lsp_capability_test!(
+    on_type_format_with_config,
+    lsp::on_type_format_with_config_request,
+    test_fixtures_dir().join("on_type_formatting/src/main.sw")
+);
 lsp_capability_test!(
     highlight,
     lsp::highlight_request,
     doc_comments_dir().join("src/main.sw")
 );
+lsp_capability_test!(
+    highlight_exit_points,
+    lsp::highlight_exit_points_request,
+    test_fixtures_dir().join("exit_points/src/main.sw")
+);
 lsp_capability_test!(
     references,
     lsp::references_request,
     test_fixtures_dir().join("tokens/structs/src/main.sw")
 );
+lsp_capability_test!(
+    implementation,
+    lsp::implementation_request,
+    test_fixtures_dir().join("tokens/implementations/src/main.sw")
+);
 lsp_capability_test!(
     code_action_abi,
     code_actions::code_action_abi_request,
@@ -2030,6 +2094,26 @@ lsp_capability_test!(
     code_actions::code_action_auto_import_alias_request,
     test_fixtures_dir().join("auto_import/src/main.sw")
 );
+lsp_capability_test!(
+    code_action_remove_unused_dependency,
+    code_actions::code_action_remove_unused_dependency_request,
+    test_fixtures_dir().join("diagnostics/unused_dependency/src/main.sw")
+);
+lsp_capability_test!(
+    code_action_expand_glob,
+    code_actions::code_action_expand_glob_request,
+    test_fixtures_dir().join("expand_glob/src/main.sw")
+);
+lsp_capability_test!(
+    code_action_expand_glob_relative,
+    code_actions::code_action_expand_glob_relative_request,
+    test_fixtures_dir().join("expand_glob/src/geometry.sw")
+);
+lsp_capability_test!(
+    code_action_expand_glob_unused,
+    code_actions::code_action_expand_glob_unused_request,
+    test_fixtures_dir().join("expand_glob/src/geometry.sw")
+);
 lsp_capability_test!(
     code_lens,
     lsp::code_lens_request,
@@ -2040,11 +2124,21 @@ lsp_capability_test!(
     lsp::code_lens_empty_request,
     runnables_test_dir().join("src/other.sw")
 );
+lsp_capability_test!(
+    debug_launch_configurations,
+    lsp::debug_launch_configurations_request,
+    runnables_test_dir().join("src/main.sw")
+);
 lsp_capability_test!(
     completion,
     lsp::completion_request,
     test_fixtures_dir().join("completion/src/main.sw")
 );
+lsp_capability_test!(
+    completion_storage,
+    lsp::completion_storage_request,
+    test_fixtures_dir().join("completion_storage/src/main.sw")
+);
 lsp_capability_test!(
     inlay_hints_function_params,
     lsp::inlay_hints_request,
@@ -3,16 +3,17 @@ use crate::{Parse, ParseBracket, ParseResult, ParseToEnd, Parser, ParserConsumed
 use sway_ast::brackets::{Braces, Parens, SquareBrackets};
 use sway_ast::expr::{LoopControlFlow, ReassignmentOp, ReassignmentOpVariant};
 use sway_ast::keywords::{
-    AbiToken, AddEqToken, AmpersandToken, AsmToken, CommaToken, ConfigurableToken, ConstToken,
-    DivEqToken, DoubleColonToken, EnumToken, EqToken, FalseToken, FnToken, IfToken, ImplToken,
-    LetToken, MutToken, OpenAngleBracketToken, PubToken, SemicolonToken, ShlEqToken, ShrEqToken,
-    StarEqToken, StorageToken, StructToken, SubEqToken, TraitToken, TrueToken, TypeToken, UseToken,
+    AbiToken, AddEqToken, AmpersandToken, ApostropheToken, AsmToken, ColonToken, CommaToken,
+    ConfigurableToken, ConstToken, DivEqToken, DoubleColonToken, DoubleDotToken, EnumToken,
+    EqToken, FalseToken, FnToken, ForToken, IfToken, ImplToken, LetToken, MutToken,
+    OpenAngleBracketToken, PubToken, SemicolonToken, ShlEqToken, ShrEqToken, StarEqToken,
+    StorageToken, StructToken, SubEqToken, TraitToken, TrueToken, TypeToken, UseToken, WhileToken,
 };
 use sway_ast::literal::{LitBool, LitBoolType};
 use sway_ast::punctuated::Punctuated;
 use sway_ast::{
-    AbiCastArgs, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
-    ExprTupleDescriptor, GenericArgs, IfCondition, IfExpr, LitInt, Literal, MatchBranch,
+    AbiCastArgs, CallArg, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
+    ExprTupleDescriptor, GenericArgs, IfCondition, IfExpr, Label, LitInt, Literal, MatchBranch,
     MatchBranchKind, PathExpr, PathExprSegment, Statement, StatementLet,
 };
 use sway_error::parser_error::ParseErrorKind;
@@ -68,16 +69,19 @@ impl Parse for IfExpr {
 
 impl Parse for IfCondition {
     fn parse(parser: &mut Parser) -> ParseResult<IfCondition> {
-        if let Some(let_token) = parser.take() {
-            let lhs = parser.parse()?;
-            let eq_token = parser.parse()?;
-            let rhs = Box::new(parse_condition(parser)?);
-            Ok(IfCondition::Let {
-                let_token,
-                lhs,
-                eq_token,
-                rhs,
-            })
+        if parser.peek::<LetToken>().is_some() {
+            let mut condition = parse_let_condition(parser)?;
+            if let IfCondition::Let { chain, .. } = &mut condition {
+                while let Some(double_ampersand_token) = parser.take() {
+                    let link = if parser.peek::<LetToken>().is_some() {
+                        parse_let_condition(parser)?
+                    } else {
+                        IfCondition::Expr(Box::new(parse_chained_condition(parser)?))
+                    };
+                    chain.push((double_ampersand_token, link));
+                }
+            }
+            Ok(condition)
         } else {
             let expr = Box::new(parse_condition(parser)?);
             Ok(IfCondition::Expr(expr))
@@ -85,6 +89,30 @@ impl Parse for IfCondition {
     }
 }
 
+/// Parses `let pattern = expr`, without the conditions chained to it.
+fn parse_let_condition(parser: &mut Parser) -> ParseResult<IfCondition> {
+    let let_token = parser.parse()?;
+    let lhs = parser.parse()?;
+    let eq_token = parser.parse()?;
+    let rhs = Box::new(parse_chained_condition(parser)?);
+    Ok(IfCondition::Let {
+        let_token,
+        lhs,
+        eq_token,
+        rhs,
+        chain: Vec::new(),
+    })
+}
+
+/// Parses an operand of a chain of `let` conditions, which binds tighter than `&&`.
+fn parse_chained_condition(parser: &mut Parser) -> ParseResult<Expr> {
+    let ctx = ParseExprCtx {
+        parsing_conditional: true,
+        at_start_of_statement: false,
+    };
+    parse_comparison(parser, ctx)
+}
+
 impl Parse for Expr {
     fn parse(parser: &mut Parser) -> ParseResult<Expr> {
         parse_reassignment(parser, ParseExprCtx::default())
@@ -112,6 +140,10 @@ impl Parse for StatementLet {
         };
         let eq_token: EqToken = parser.try_parse(true)?;
         let expr = parser.try_parse(true)?;
+        let else_opt = match parser.take() {
+            Some(else_token) => Some((else_token, parser.parse()?)),
+            None => None,
+        };
 
         // Recover on missing semicolon.
         let semicolon_token = parser.try_parse(true)?;
@@ -122,6 +154,7 @@ impl Parse for StatementLet {
             ty_opt,
             eq_token,
             expr,
+            else_opt,
             semicolon_token,
         })
     }
@@ -280,7 +313,7 @@ fn take_reassignment_op(parser: &mut Parser) -> Option<ReassignmentOp> {
 }
 
 fn parse_reassignment(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
-    let expr = parse_logical_or(parser, ctx)?;
+    let expr = parse_range(parser, ctx)?;
     let expr_span = expr.span();
 
     if let Some(reassignment_op) = take_reassignment_op(parser) {
@@ -335,6 +368,44 @@ fn parse_binary<O: Peek>(
     Ok(expr)
 }
 
+fn parse_range(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
+    if let Some(double_dot_token) = parser.take() {
+        return parse_range_end(parser, ctx, None, double_dot_token);
+    }
+    let start = parse_logical_or(parser, ctx)?;
+    if start.is_control_flow() && ctx.at_start_of_statement {
+        return Ok(start);
+    }
+    match parser.take() {
+        Some(double_dot_token) => {
+            parse_range_end(parser, ctx, Some(Box::new(start)), double_dot_token)
+        }
+        None => Ok(start),
+    }
+}
+
+/// Parses the optional end of a range, after its `..`.
+fn parse_range_end(
+    parser: &mut Parser,
+    ctx: ParseExprCtx,
+    start: Option<Box<Expr>>,
+    double_dot_token: DoubleDotToken,
+) -> ParseResult<Expr> {
+    let end = if parser.is_empty()
+        || parser.peek::<SemicolonToken>().is_some()
+        || parser.peek::<CommaToken>().is_some()
+    {
+        None
+    } else {
+        Some(Box::new(parse_logical_or(parser, ctx.not_statement())?))
+    };
+    Ok(Expr::Range {
+        start,
+        double_dot_token,
+        end,
+    })
+}
+
 fn parse_logical_or(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
     let combine = |lhs, rhs, double_pipe_token| Expr::LogicalOr {
         lhs,
@@ -475,31 +546,30 @@ fn parse_add(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
 }
 
 fn parse_mul(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
-    let mut expr = parse_unary_op(parser, ctx)?;
+    let mut expr = parse_cast(parser, ctx)?;
     if expr.is_control_flow() && ctx.at_start_of_statement {
         return Ok(expr);
     }
     loop {
-        expr = if let Some((double_star_token, rhs)) = parse_op_rhs(parser, ctx, parse_unary_op)? {
+        expr = if let Some((double_star_token, rhs)) = parse_op_rhs(parser, ctx, parse_cast)? {
             Expr::Pow {
                 lhs: Box::new(expr),
                 double_star_token,
                 rhs,
             }
-        } else if let Some((star_token, rhs)) = parse_op_rhs(parser, ctx, parse_unary_op)? {
+        } else if let Some((star_token, rhs)) = parse_op_rhs(parser, ctx, parse_cast)? {
             Expr::Mul {
                 lhs: Box::new(expr),
                 star_token,
                 rhs,
             }
-        } else if let Some((forward_slash_token, rhs)) = parse_op_rhs(parser, ctx, parse_unary_op)?
-        {
+        } else if let Some((forward_slash_token, rhs)) = parse_op_rhs(parser, ctx, parse_cast)? {
             Expr::Div {
                 lhs: Box::new(expr),
                 forward_slash_token,
                 rhs,
             }
-        } else if let Some((percent_token, rhs)) = parse_op_rhs(parser, ctx, parse_unary_op)? {
+        } else if let Some((percent_token, rhs)) = parse_op_rhs(parser, ctx, parse_cast)? {
             Expr::Modulo {
                 lhs: Box::new(expr),
                 percent_token,
@@ -511,6 +581,22 @@ fn parse_mul(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
     }
 }
 
+fn parse_cast(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
+    let mut expr = parse_unary_op(parser, ctx)?;
+    if expr.is_control_flow() && ctx.at_start_of_statement {
+        return Ok(expr);
+    }
+    while let Some(as_token) = parser.take() {
+        let ty = parser.parse()?;
+        expr = Expr::Cast {
+            expr: Box::new(expr),
+            as_token,
+            ty,
+        };
+    }
+    Ok(expr)
+}
+
 fn parse_unary_op(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
     if let Some((ampersand_token, mut_token, expr)) = parse_referencing(parser, ctx)? {
         return Ok(Expr::Ref {
@@ -551,6 +637,17 @@ fn parse_projection(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr>
             expr = Expr::Index { target, arg };
             continue;
         }
+        if let Some(question_mark_token) = parser.take() {
+            expr = Expr::Try {
+                expr: Box::new(expr),
+                question_mark_token,
+            };
+            continue;
+        }
+        // The `.` of a `..` range is not a projection.
+        if parser.peek::<DoubleDotToken>().is_some() {
+            return Ok(expr);
+        }
         if let Some(dot_token) = parser.take() {
             let target = Box::new(expr);
 
@@ -692,10 +789,48 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
         return Ok(Expr::Asm(asm_block));
     }
     if let Some(break_token) = parser.take() {
-        return Ok(Expr::Break { break_token });
+        let label_opt: Option<Label> = parser.guarded_parse::<ApostropheToken, _>()?;
+        // Only a labeled `break` can carry a value, which then becomes the value of the
+        // labeled block.
+        if label_opt.is_none()
+            || parser.is_empty()
+            || parser.peek::<CommaToken>().is_some()
+            || parser.peek::<SemicolonToken>().is_some()
+        {
+            return Ok(Expr::Break {
+                break_token,
+                label_opt,
+                value_opt: None,
+            });
+        }
+        let value = parser.parse()?;
+        return Ok(Expr::Break {
+            break_token,
+            label_opt,
+            value_opt: Some(value),
+        });
     }
     if let Some(continue_token) = parser.take() {
-        return Ok(Expr::Continue { continue_token });
+        let label_opt = parser.guarded_parse::<ApostropheToken, _>()?;
+        return Ok(Expr::Continue {
+            continue_token,
+            label_opt,
+        });
+    }
+    if let Some(label) = parser.guarded_parse::<ApostropheToken, Label>()? {
+        let colon_token = parser.parse()?;
+        let expr = if let Some(block) = Braces::try_parse(parser)? {
+            Expr::Block(block)
+        } else if parser.peek::<WhileToken>().is_some() || parser.peek::<ForToken>().is_some() {
+            parse_atom(parser, ctx)?
+        } else {
+            return Err(parser.emit_error(ParseErrorKind::ExpectedLabeledBlockOrLoop));
+        };
+        return Ok(Expr::Labeled {
+            label,
+            colon_token,
+            expr: Box::new(expr),
+        });
     }
     if let Some(abi_token) = parser.take() {
         let args = parser.parse()?;
@@ -779,6 +914,17 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
     Err(parser.emit_error(ParseErrorKind::ExpectedExpression))
 }
 
+impl Parse for Label {
+    fn parse(parser: &mut Parser) -> ParseResult<Label> {
+        let apostrophe_token = parser.parse()?;
+        let name = parser.parse()?;
+        Ok(Label {
+            apostrophe_token,
+            name,
+        })
+    }
+}
+
 impl Parse for ExprStructField {
     fn parse(parser: &mut Parser) -> ParseResult<ExprStructField> {
         let field_name = parser.parse()?;
@@ -796,6 +942,17 @@ impl Parse for ExprStructField {
     }
 }
 
+impl Parse for CallArg {
+    fn parse(parser: &mut Parser) -> ParseResult<CallArg> {
+        let name_opt = match parser.peek::<(Ident, ColonToken)>() {
+            Some(_) => Some((parser.parse()?, parser.parse()?)),
+            None => None,
+        };
+        let value = parser.parse()?;
+        Ok(CallArg { name_opt, value })
+    }
+}
+
 impl ParseToEnd for ExprArrayDescriptor {
     fn parse_to_end<'a, 'e>(
         mut parser: Parser<'a, '_>,
@@ -840,10 +997,12 @@ impl ParseToEnd for ExprArrayDescriptor {
 
 impl Parse for MatchBranch {
     fn parse(parser: &mut Parser) -> ParseResult<MatchBranch> {
+        let pipe_token_opt = parser.take();
         let pattern = parser.parse()?;
         let fat_right_arrow_token = parser.parse()?;
         let kind = parser.parse()?;
         Ok(MatchBranch {
+            pipe_token_opt,
             pattern,
             fat_right_arrow_token,
             kind,
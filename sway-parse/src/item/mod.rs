@@ -1,9 +1,9 @@
 use crate::{Parse, ParseResult, ParseToEnd, Parser, ParserConsumed};
 
 use sway_ast::keywords::{
-    AbiToken, ClassToken, ColonToken, ConfigurableToken, ConstToken, EnumToken, FnToken, ImplToken,
-    ModToken, MutToken, OpenAngleBracketToken, RefToken, SelfToken, SemicolonToken, StorageToken,
-    StructToken, TraitToken, TypeToken, UseToken, WhereToken,
+    AbiToken, ClassToken, ColonToken, ConfigurableToken, ConstToken, EnumToken, EqToken, FnToken,
+    ImplToken, ModToken, MutToken, OpenAngleBracketToken, RefToken, SelfToken, SemicolonToken,
+    StorageToken, StructToken, TraitToken, TypeToken, UseToken, WhereToken,
 };
 use sway_ast::{
     FnArg, FnArgs, FnSignature, ItemConst, ItemEnum, ItemFn, ItemKind, ItemStruct, ItemTrait,
@@ -163,6 +163,10 @@ impl Parse for FnArg {
             pattern: parser.parse()?,
             colon_token: parser.parse()?,
             ty: parser.parse()?,
+            default_opt: match parser.take::<EqToken>() {
+                Some(eq_token) => Some((eq_token, parser.parse()?)),
+                None => None,
+            },
         })
     }
 }
@@ -191,6 +195,7 @@ impl Parse for TraitType {
     fn parse(parser: &mut Parser) -> ParseResult<TraitType> {
         let type_token = parser.parse()?;
         let name = parser.parse()?;
+        let generics = parser.guarded_parse::<OpenAngleBracketToken, _>()?;
         let eq_token_opt = parser.take();
         let ty_opt = match &eq_token_opt {
             Some(_eq) => Some(parser.parse()?),
@@ -200,6 +205,7 @@ impl Parse for TraitType {
         Ok(TraitType {
             type_token,
             name,
+            generics,
             eq_token_opt,
             ty_opt,
             semicolon_token,
@@ -214,6 +220,7 @@ mod tests {
     use super::*;
     use crate::test_utils::parse;
     use sway_ast::{AttributeDecl, Item, ItemTraitItem};
+    use sway_types::Spanned;
 
     // Attribute name and its list of parameters
     type ParameterizedAttr<'a> = (&'a str, Option<Vec<&'a str>>);
@@ -655,4 +662,60 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn parse_generic_trait_type() {
+        let item = parse::<Item>(
+            r#"
+            trait T {
+                type Output<A, B>;
+                type Plain;
+            }
+            "#,
+        );
+
+        if let ItemKind::Trait(item_trait) = item.value {
+            let mut items = item_trait.trait_items.get().iter();
+            let ItemTraitItem::Type(output, _) = &items.next().unwrap().value else {
+                panic!("Expected an associated type.");
+            };
+            let params = &output.generics.as_ref().unwrap().parameters.inner;
+            assert_eq!(output.name.as_str(), "Output");
+            assert_eq!(params.into_iter().count(), 2);
+
+            let ItemTraitItem::Type(plain, _) = &items.next().unwrap().value else {
+                panic!("Expected an associated type.");
+            };
+            assert!(plain.generics.is_none());
+        } else {
+            panic!("Parsed trait is not a trait.");
+        }
+    }
+
+    #[test]
+    fn parse_fn_arg_default_value() {
+        let item = parse::<Item>(
+            r#"
+            fn f(a: u64, b: u64 = 1, c: bool = a == b) {}
+            "#,
+        );
+
+        let ItemKind::Fn(item_fn) = item.value else {
+            panic!("Parsed item is not a function.");
+        };
+        let FnArgs::Static(args) = item_fn.fn_signature.arguments.inner else {
+            panic!("Expected static function arguments.");
+        };
+        let defaults = args
+            .into_iter()
+            .map(|arg| {
+                arg.default_opt
+                    .map(|(_eq_token, default)| default.span().as_str().to_string())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            defaults,
+            vec![None, Some("1".to_string()), Some("a == b".to_string())]
+        );
+    }
 }
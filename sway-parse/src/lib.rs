@@ -5,6 +5,7 @@ mod generics;
 mod item;
 mod keywords;
 mod literal;
+mod macros;
 mod module;
 mod parse;
 mod parser;
@@ -22,6 +23,7 @@ mod where_clause;
 use crate::priv_prelude::*;
 pub use crate::{
     keywords::RESERVED_KEYWORDS,
+    macros::{expand_macros, reject_macros},
     parse::Parse,
     parser::Parser,
     token::{lex, lex_commented, parse_int_suffix},
@@ -47,6 +49,31 @@ pub fn parse_file(
     Ok(m)
 }
 
+/// Parses a file like [parse_file], after expanding the `macro_rules!` macros within it.
+pub fn parse_file_expanding_macros(
+    handler: &Handler,
+    src: Arc<str>,
+    source_id: Option<SourceId>,
+) -> Result<Annotated<Module>, ErrorEmitted> {
+    let ts = lex(handler, &src, 0, src.len(), source_id)?;
+    let ts = expand_macros(handler, ts)?;
+    let (m, _) = Parser::new(handler, &ts).parse_to_end()?;
+    Ok(m)
+}
+
+/// Parses a file like [parse_file], reporting an error if it defines or invokes macros, which
+/// are only available with the experimental `declarative_macros` feature.
+pub fn parse_file_rejecting_macros(
+    handler: &Handler,
+    src: Arc<str>,
+    source_id: Option<SourceId>,
+) -> Result<Annotated<Module>, ErrorEmitted> {
+    let ts = lex(handler, &src, 0, src.len(), source_id)?;
+    reject_macros(handler, &ts)?;
+    let (m, _) = Parser::new(handler, &ts).parse_to_end()?;
+    Ok(m)
+}
+
 pub fn parse_module_kind(
     handler: &Handler,
     src: Arc<str>,
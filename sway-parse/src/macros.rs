@@ -0,0 +1,1250 @@
+//! Expansion of `macro_rules!` declarative macros.
+//!
+//! Macros are expanded on the token trees of a file before the file is parsed, so the rest of the
+//! compiler only ever sees the expanded code. A macro is defined at the top level of a module and
+//! can be invoked anywhere after its definition within the same file:
+//!
+//! ```sway
+//! macro_rules! square {
+//!     ($x:expr) => { $x * $x };
+//! }
+//! ```
+//!
+//! Invocations at the top level of a module expand to items. All other invocations expand to a
+//! code block, so that they can be used as expressions or statements.
+//!
+//! Names bound by `let` within the body of a macro are renamed on every expansion, so that they
+//! can neither shadow nor be shadowed by the names used in the arguments of the invocation.
+//!
+//! The built-in `format!` macro splits its format string at compile time, and expands to the
+//! calls of a `std::format::Formatter` writing the pieces of the string and the arguments.
+
+use crate::{Parse, Parser};
+use std::collections::HashMap;
+use sway_ast::{
+    literal::{LitInt, LitString},
+    token::{Group, Punct, Spacing, TokenStream, TokenTree},
+    Expr, Literal, Ty,
+};
+use sway_error::{
+    error::CompileError,
+    handler::{ErrorEmitted, Handler},
+    parser_error::{ParseError, ParseErrorKind},
+};
+use sway_types::{
+    ast::{Delimiter, PunctKind},
+    Ident, Span, Spanned,
+};
+use unicode_xid::UnicodeXID;
+
+const MACRO_RULES: &str = "macro_rules";
+const FORMAT: &str = "format";
+
+/// The maximum depth of macro invocations within the expansions of other invocations.
+const RECURSION_LIMIT: usize = 64;
+
+type Result<T> = core::result::Result<T, ErrorEmitted>;
+
+/// An error found while transcribing, emitted by the [Expander].
+type TranscribeResult<T> = core::result::Result<T, (ParseErrorKind, Span)>;
+
+/// Expands all `macro_rules!` definitions and invocations within the tokens of a module.
+pub fn expand_macros(handler: &Handler, token_stream: TokenStream) -> Result<TokenStream> {
+    let mut expander = Expander {
+        handler,
+        macros: HashMap::new(),
+        next_expansion: 0,
+    };
+    let full_span = token_stream.span();
+    let token_trees = expander.expand(token_stream.token_trees(), true, 0)?;
+    Ok(TokenStream::new(token_trees, full_span))
+}
+
+/// Reports the first `macro_rules!` definition or `format!` invocation within the tokens of a
+/// module, for modules compiled without the experimental `declarative_macros` feature.
+pub fn reject_macros(handler: &Handler, token_stream: &TokenStream) -> Result<()> {
+    match find_macro(token_stream.token_trees()) {
+        Some(name) => {
+            let error = ParseError {
+                span: name.span(),
+                kind: ParseErrorKind::DeclarativeMacrosNotEnabled { name: name.clone() },
+            };
+            Err(handler.emit_err(CompileError::Parse { error }))
+        }
+        None => Ok(()),
+    }
+}
+
+fn find_macro(token_trees: &[TokenTree]) -> Option<&Ident> {
+    token_trees
+        .iter()
+        .enumerate()
+        .find_map(|(index, token_tree)| match &token_trees[index..] {
+            [TokenTree::Ident(name), TokenTree::Punct(bang), ..]
+                if bang.kind == PunctKind::Bang && name.as_str() == MACRO_RULES =>
+            {
+                Some(name)
+            }
+            [TokenTree::Ident(name), TokenTree::Punct(bang), TokenTree::Group(_), ..]
+                if bang.kind == PunctKind::Bang && name.as_str() == FORMAT =>
+            {
+                Some(name)
+            }
+            _ => match token_tree {
+                TokenTree::Group(group) => find_macro(group.token_stream.token_trees()),
+                _ => None,
+            },
+        })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FragmentKind {
+    Ident,
+    Expr,
+    Ty,
+    Literal,
+    Tt,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RepetitionKind {
+    /// `$(...)*`
+    ZeroOrMore,
+    /// `$(...)+`
+    OneOrMore,
+}
+
+#[derive(Clone, Debug)]
+enum Matcher {
+    Token(TokenTree),
+    Group {
+        delimiter: Delimiter,
+        matchers: Vec<Matcher>,
+    },
+    Fragment {
+        name: Ident,
+        kind: FragmentKind,
+    },
+    Repetition {
+        matchers: Vec<Matcher>,
+        separator: Option<TokenTree>,
+        kind: RepetitionKind,
+    },
+}
+
+#[derive(Clone, Debug)]
+enum Transcriber {
+    Token(TokenTree),
+    Group {
+        delimiter: Delimiter,
+        span: Span,
+        transcribers: Vec<Transcriber>,
+    },
+    Variable(Ident),
+    Repetition {
+        transcribers: Vec<Transcriber>,
+        separator: Option<TokenTree>,
+        span: Span,
+    },
+}
+
+#[derive(Clone, Debug)]
+struct MacroRule {
+    matchers: Vec<Matcher>,
+    transcribers: Vec<Transcriber>,
+    /// The names bound by `let` within the transcribers, renamed on every expansion.
+    local_names: Vec<Ident>,
+}
+
+/// The tokens matched by a macro variable.
+#[derive(Clone, Debug)]
+enum Binding {
+    Fragment {
+        token_trees: Vec<TokenTree>,
+        kind: FragmentKind,
+    },
+    /// The bindings of each repetition of a variable declared within `$(...)`.
+    Repeated(Vec<Binding>),
+}
+
+type Bindings = HashMap<Ident, Binding>;
+
+struct Expander<'h> {
+    handler: &'h Handler,
+    macros: HashMap<Ident, Vec<MacroRule>>,
+    /// Distinguishes the renamed local names of different expansions.
+    next_expansion: usize,
+}
+
+impl Expander<'_> {
+    fn emit_error(&self, kind: ParseErrorKind, span: Span) -> ErrorEmitted {
+        let error = ParseError { span, kind };
+        self.handler.emit_err(CompileError::Parse { error })
+    }
+
+    /// Expands the macro definitions and invocations within `token_trees`.
+    ///
+    /// Macros can only be defined, and expand to items, at the top level of a module.
+    fn expand(
+        &mut self,
+        token_trees: &[TokenTree],
+        is_top_level: bool,
+        depth: usize,
+    ) -> Result<Vec<TokenTree>> {
+        let mut expanded = Vec::with_capacity(token_trees.len());
+        let mut index = 0;
+        while let Some(token_tree) = token_trees.get(index) {
+            match &token_trees[index..] {
+                [TokenTree::Ident(keyword), TokenTree::Punct(bang), ..]
+                    if is_top_level
+                        && keyword.as_str() == MACRO_RULES
+                        && bang.kind == PunctKind::Bang =>
+                {
+                    index += self.define_macro(&token_trees[index..])?;
+                }
+                [TokenTree::Ident(name), TokenTree::Punct(bang), TokenTree::Group(args), ..]
+                    if bang.kind == PunctKind::Bang && self.macros.contains_key(name) =>
+                {
+                    index += 3;
+                    let span = Span::join(name.span(), &args.span);
+                    if depth >= RECURSION_LIMIT {
+                        return Err(self.emit_error(
+                            ParseErrorKind::MacroRecursionLimitReached {
+                                name: name.clone(),
+                                limit: RECURSION_LIMIT,
+                            },
+                            span,
+                        ));
+                    }
+                    // Invocations within the arguments are expanded first, so that they can be
+                    // matched as expressions.
+                    let args_token_trees =
+                        self.expand(args.token_stream.token_trees(), false, depth + 1)?;
+                    let transcribed = self.invoke(name, &args_token_trees, span.clone())?;
+                    let expansion = self.expand(&transcribed, is_top_level, depth + 1)?;
+                    if is_top_level {
+                        // Item invocations may be terminated by a semicolon, e.g. `impls!(...);`.
+                        if is_punct(token_trees.get(index), PunctKind::Semicolon) {
+                            index += 1;
+                        }
+                        expanded.extend(expansion);
+                    } else {
+                        expanded.push(TokenTree::Group(Group {
+                            delimiter: Delimiter::Brace,
+                            token_stream: TokenStream::new(expansion, span.clone()),
+                            span,
+                        }));
+                    }
+                }
+                [TokenTree::Ident(name), TokenTree::Punct(bang), TokenTree::Group(args), ..]
+                    if !is_top_level && bang.kind == PunctKind::Bang && name.as_str() == FORMAT =>
+                {
+                    index += 3;
+                    let span = Span::join(name.span(), &args.span);
+                    let args_token_trees =
+                        self.expand(args.token_stream.token_trees(), false, depth + 1)?;
+                    let expansion = self.expand_format(&args_token_trees, span.clone())?;
+                    expanded.push(TokenTree::Group(Group {
+                        delimiter: Delimiter::Brace,
+                        token_stream: TokenStream::new(expansion, span.clone()),
+                        span,
+                    }));
+                }
+                [TokenTree::Group(group), ..] => {
+                    index += 1;
+                    let expansion = self.expand(group.token_stream.token_trees(), false, depth)?;
+                    expanded.push(TokenTree::Group(Group {
+                        delimiter: group.delimiter,
+                        token_stream: TokenStream::new(expansion, group.token_stream.span()),
+                        span: group.span.clone(),
+                    }));
+                }
+                _ => {
+                    index += 1;
+                    expanded.push(token_tree.clone());
+                }
+            }
+        }
+        Ok(expanded)
+    }
+
+    /// Parses the `macro_rules! name { ... }` definition at the start of `token_trees`.
+    ///
+    /// Returns the number of token trees of the definition.
+    fn define_macro(&mut self, token_trees: &[TokenTree]) -> Result<usize> {
+        let [_, _, TokenTree::Ident(name), TokenTree::Group(body), ..] = token_trees else {
+            let span = Span::join_all(token_trees.iter().take(4).map(Spanned::span));
+            return Err(self.emit_error(ParseErrorKind::InvalidMacroDefinition, span));
+        };
+        let rules = self.parse_rules(body)?;
+        self.macros.insert(name.clone(), rules);
+        match is_punct(token_trees.get(4), PunctKind::Semicolon) {
+            true => Ok(5),
+            false => Ok(4),
+        }
+    }
+
+    /// Parses the `(matcher) => { transcriber };` rules within the body of a macro definition.
+    fn parse_rules(&self, body: &Group) -> Result<Vec<MacroRule>> {
+        let mut rules = vec![];
+        let mut token_trees = body.token_stream.token_trees();
+        loop {
+            match token_trees {
+                [] => break,
+                [TokenTree::Group(matcher), TokenTree::Punct(eq), TokenTree::Punct(gt), TokenTree::Group(transcriber), rest @ ..]
+                    if eq.kind == PunctKind::Equals && gt.kind == PunctKind::GreaterThan =>
+                {
+                    let matchers = self.parse_matchers(matcher.token_stream.token_trees())?;
+                    let transcribers =
+                        self.parse_transcribers(transcriber.token_stream.token_trees())?;
+                    let mut local_names = vec![];
+                    collect_local_names(&transcribers, &mut local_names);
+                    rules.push(MacroRule {
+                        matchers,
+                        transcribers,
+                        local_names,
+                    });
+                    token_trees = match rest {
+                        [semicolon, rest @ ..]
+                            if is_punct(Some(semicolon), PunctKind::Semicolon) =>
+                        {
+                            rest
+                        }
+                        [] => rest,
+                        [token_tree, ..] => {
+                            return Err(self.emit_error(
+                                ParseErrorKind::ExpectedPunct {
+                                    kinds: vec![PunctKind::Semicolon],
+                                },
+                                token_tree.span(),
+                            ))
+                        }
+                    };
+                }
+                [token_tree, ..] => {
+                    return Err(
+                        self.emit_error(ParseErrorKind::InvalidMacroDefinition, token_tree.span())
+                    )
+                }
+            }
+        }
+        if rules.is_empty() {
+            return Err(self.emit_error(ParseErrorKind::InvalidMacroDefinition, body.span()));
+        }
+        Ok(rules)
+    }
+
+    fn parse_matchers(&self, mut token_trees: &[TokenTree]) -> Result<Vec<Matcher>> {
+        let mut matchers = vec![];
+        loop {
+            match token_trees {
+                [] => break,
+                [TokenTree::Punct(dollar), TokenTree::Ident(name), TokenTree::Punct(colon), TokenTree::Ident(kind), rest @ ..]
+                    if dollar.kind == PunctKind::Dollar && colon.kind == PunctKind::Colon =>
+                {
+                    matchers.push(Matcher::Fragment {
+                        name: name.clone(),
+                        kind: self.fragment_kind(kind)?,
+                    });
+                    token_trees = rest;
+                }
+                [TokenTree::Punct(dollar), TokenTree::Group(group), rest @ ..]
+                    if dollar.kind == PunctKind::Dollar
+                        && group.delimiter == Delimiter::Parenthesis =>
+                {
+                    let (separator, kind, rest) = self.parse_repetition_suffix(group, rest)?;
+                    matchers.push(Matcher::Repetition {
+                        matchers: self.parse_matchers(group.token_stream.token_trees())?,
+                        separator,
+                        kind,
+                    });
+                    token_trees = rest;
+                }
+                [TokenTree::Punct(dollar), ..] if dollar.kind == PunctKind::Dollar => {
+                    return Err(
+                        self.emit_error(ParseErrorKind::InvalidMacroDefinition, dollar.span())
+                    )
+                }
+                [TokenTree::Group(group), rest @ ..] => {
+                    matchers.push(Matcher::Group {
+                        delimiter: group.delimiter,
+                        matchers: self.parse_matchers(group.token_stream.token_trees())?,
+                    });
+                    token_trees = rest;
+                }
+                [token_tree, rest @ ..] => {
+                    matchers.push(Matcher::Token(token_tree.clone()));
+                    token_trees = rest;
+                }
+            }
+        }
+        Ok(matchers)
+    }
+
+    fn parse_transcribers(&self, mut token_trees: &[TokenTree]) -> Result<Vec<Transcriber>> {
+        let mut transcribers = vec![];
+        loop {
+            match token_trees {
+                [] => break,
+                [TokenTree::Punct(dollar), TokenTree::Ident(name), rest @ ..]
+                    if dollar.kind == PunctKind::Dollar =>
+                {
+                    transcribers.push(Transcriber::Variable(name.clone()));
+                    token_trees = rest;
+                }
+                [TokenTree::Punct(dollar), TokenTree::Group(group), rest @ ..]
+                    if dollar.kind == PunctKind::Dollar
+                        && group.delimiter == Delimiter::Parenthesis =>
+                {
+                    let (separator, _, rest) = self.parse_repetition_suffix(group, rest)?;
+                    transcribers.push(Transcriber::Repetition {
+                        transcribers: self.parse_transcribers(group.token_stream.token_trees())?,
+                        separator,
+                        span: group.span(),
+                    });
+                    token_trees = rest;
+                }
+                [TokenTree::Punct(dollar), ..] if dollar.kind == PunctKind::Dollar => {
+                    return Err(
+                        self.emit_error(ParseErrorKind::InvalidMacroDefinition, dollar.span())
+                    )
+                }
+                [TokenTree::Group(group), rest @ ..] => {
+                    transcribers.push(Transcriber::Group {
+                        delimiter: group.delimiter,
+                        span: group.span(),
+                        transcribers: self.parse_transcribers(group.token_stream.token_trees())?,
+                    });
+                    token_trees = rest;
+                }
+                [token_tree, rest @ ..] => {
+                    transcribers.push(Transcriber::Token(token_tree.clone()));
+                    token_trees = rest;
+                }
+            }
+        }
+        Ok(transcribers)
+    }
+
+    /// Parses the optional separator and the `*` or `+` following the `$(...)` of a repetition.
+    fn parse_repetition_suffix<'t>(
+        &self,
+        group: &Group,
+        token_trees: &'t [TokenTree],
+    ) -> Result<(Option<TokenTree>, RepetitionKind, &'t [TokenTree])> {
+        match token_trees {
+            [op, rest @ ..] if repetition_kind(op).is_some() => {
+                Ok((None, repetition_kind(op).unwrap(), rest))
+            }
+            [separator, op, rest @ ..]
+                if repetition_kind(op).is_some() && !matches!(separator, TokenTree::Group(_)) =>
+            {
+                Ok((Some(separator.clone()), repetition_kind(op).unwrap(), rest))
+            }
+            _ => Err(self.emit_error(ParseErrorKind::InvalidMacroDefinition, group.span())),
+        }
+    }
+
+    fn fragment_kind(&self, kind: &Ident) -> Result<FragmentKind> {
+        match kind.as_str() {
+            "ident" => Ok(FragmentKind::Ident),
+            "expr" => Ok(FragmentKind::Expr),
+            "ty" => Ok(FragmentKind::Ty),
+            "literal" => Ok(FragmentKind::Literal),
+            "tt" => Ok(FragmentKind::Tt),
+            _ => Err(self.emit_error(
+                ParseErrorKind::UnknownMacroFragmentSpecifier { kind: kind.clone() },
+                kind.span(),
+            )),
+        }
+    }
+
+    /// Transcribes the first rule of the macro `name` that matches the `args` of the invocation.
+    fn invoke(&mut self, name: &Ident, args: &[TokenTree], span: Span) -> Result<Vec<TokenTree>> {
+        let expansion = self.next_expansion;
+        self.next_expansion += 1;
+        for rule in &self.macros[name] {
+            let mut bindings = Bindings::new();
+            if !match_all(&rule.matchers, args, &mut bindings) {
+                continue;
+            }
+            let renames = rule
+                .local_names
+                .iter()
+                .map(|local_name| {
+                    (
+                        local_name.clone(),
+                        format!("{local_name}__macro{expansion}"),
+                    )
+                })
+                .collect();
+            let mut transcribed = vec![];
+            transcribe(
+                &rule.transcribers,
+                &bindings,
+                &renames,
+                &mut vec![],
+                &mut transcribed,
+            )
+            .map_err(|(kind, span)| self.emit_error(kind, span))?;
+            return Ok(transcribed);
+        }
+        Err(self.emit_error(
+            ParseErrorKind::NoMatchingMacroRule { name: name.clone() },
+            span,
+        ))
+    }
+
+    /// Expands an invocation of the built-in `format!` macro with the given `args`.
+    ///
+    /// The arguments are bound to locals first, so that each of them is evaluated exactly once
+    /// and in order. E.g. `format!("x = {}", x)` expands to:
+    ///
+    /// ```sway
+    /// let format_arg0 = x;
+    /// let mut formatter = std::format::Formatter::with_capacity(4);
+    /// formatter.write_str("x = ");
+    /// formatter.write(format_arg0);
+    /// formatter.finish()
+    /// ```
+    fn expand_format(&mut self, args: &[TokenTree], span: Span) -> Result<Vec<TokenTree>> {
+        let expansion = self.next_expansion;
+        self.next_expansion += 1;
+
+        let [TokenTree::Literal(Literal::String(format_string)), mut rest @ ..] = args else {
+            let span = args.first().map_or(span, Spanned::span);
+            return Err(self.emit_error(ParseErrorKind::ExpectedFormatString, span));
+        };
+        let mut arguments = vec![];
+        while let [comma, tail @ ..] = rest {
+            if !is_punct(Some(comma), PunctKind::Comma) {
+                return Err(self.emit_error(
+                    ParseErrorKind::ExpectedPunct {
+                        kinds: vec![PunctKind::Comma],
+                    },
+                    comma.span(),
+                ));
+            }
+            let Some(first) = tail.first() else {
+                break;
+            };
+            let Some(len) = match_fragment(FragmentKind::Expr, tail) else {
+                return Err(self.emit_error(ParseErrorKind::ExpectedExpression, first.span()));
+            };
+            arguments.push(&tail[..len]);
+            rest = &tail[len..];
+        }
+
+        let pieces = parse_format_string(format_string)
+            .map_err(|(kind, span)| self.emit_error(kind, span))?;
+        let mut used = vec![false; arguments.len()];
+        for piece in &pieces {
+            if let FormatPiece::Argument { index, span } = piece {
+                match used.get_mut(*index) {
+                    Some(used) => *used = true,
+                    None => {
+                        return Err(self.emit_error(
+                            ParseErrorKind::MissingFormatArgument {
+                                index: *index,
+                                count: arguments.len(),
+                            },
+                            span.clone(),
+                        ))
+                    }
+                }
+            }
+        }
+        if let Some(unused) = used.iter().position(|used| !used) {
+            let span = Span::join_all(arguments[unused].iter().map(Spanned::span));
+            return Err(self.emit_error(ParseErrorKind::UnusedFormatArgument, span));
+        }
+
+        let formatter = format!("formatter__macro{expansion}");
+        let argument_name = |index: usize| format!("format_arg{index}__macro{expansion}");
+        let mut output = vec![];
+        for (index, argument) in arguments.iter().enumerate() {
+            let argument_span = Span::join_all(argument.iter().map(Spanned::span));
+            output.push(ident("let", &span));
+            output.push(ident(&argument_name(index), &argument_span));
+            output.push(punct(PunctKind::Equals, Spacing::Alone, &span));
+            output.extend(argument.iter().cloned());
+            output.push(punct(PunctKind::Semicolon, Spacing::Alone, &span));
+        }
+
+        let capacity = pieces
+            .iter()
+            .map(|piece| match piece {
+                FormatPiece::Text(text) => text.parsed.len(),
+                FormatPiece::Argument { .. } | FormatPiece::Named(_) => 0,
+            })
+            .sum::<usize>();
+        output.extend([
+            ident("let", &span),
+            ident("mut", &span),
+            ident(&formatter, &span),
+            punct(PunctKind::Equals, Spacing::Alone, &span),
+        ]);
+        for (index, path_segment) in ["std", "format", "Formatter"].into_iter().enumerate() {
+            if index > 0 {
+                output.push(punct(PunctKind::Colon, Spacing::Joint, &span));
+                output.push(punct(PunctKind::Colon, Spacing::Alone, &span));
+            }
+            output.push(ident(path_segment, &span));
+        }
+        output.extend([
+            punct(PunctKind::Colon, Spacing::Joint, &span),
+            punct(PunctKind::Colon, Spacing::Alone, &span),
+            ident("with_capacity", &span),
+            group(
+                Delimiter::Parenthesis,
+                vec![TokenTree::Literal(Literal::Int(LitInt {
+                    span: format_string.span.clone(),
+                    parsed: capacity.into(),
+                    ty_opt: None,
+                }))],
+                &span,
+            ),
+            punct(PunctKind::Semicolon, Spacing::Alone, &span),
+        ]);
+
+        for piece in pieces {
+            let (method, argument, argument_span) = match piece {
+                FormatPiece::Text(text) => {
+                    let text_span = text.span.clone();
+                    (
+                        "write_str",
+                        TokenTree::Literal(Literal::String(text)),
+                        text_span,
+                    )
+                }
+                FormatPiece::Argument { index, span } => {
+                    ("write", ident(&argument_name(index), &span), span)
+                }
+                FormatPiece::Named(name) => {
+                    let name_span = name.span();
+                    ("write", TokenTree::Ident(name), name_span)
+                }
+            };
+            output.extend([
+                ident(&formatter, &span),
+                punct(PunctKind::Dot, Spacing::Alone, &span),
+                ident(method, &argument_span),
+                group(Delimiter::Parenthesis, vec![argument], &argument_span),
+                punct(PunctKind::Semicolon, Spacing::Alone, &span),
+            ]);
+        }
+        output.extend([
+            ident(&formatter, &span),
+            punct(PunctKind::Dot, Spacing::Alone, &span),
+            ident("finish", &span),
+            group(Delimiter::Parenthesis, vec![], &span),
+        ]);
+        Ok(output)
+    }
+}
+
+/// A piece of the format string of a `format!` invocation.
+enum FormatPiece {
+    /// Text written as is, e.g. `x = ` in `"x = {}"`.
+    Text(LitString),
+    /// A placeholder for a positional argument, i.e. `{}` or `{0}`.
+    Argument { index: usize, span: Span },
+    /// A placeholder for a variable in scope, e.g. `{x}`.
+    Named(Ident),
+}
+
+/// Splits a format string into its pieces, e.g. `"{x} = {}"` into `{x}`, ` = ` and `{}`.
+fn parse_format_string(format_string: &LitString) -> TranscribeResult<Vec<FormatPiece>> {
+    let full_span = &format_string.span;
+    let full_str = full_span.as_str();
+    let (raw_prefix_len, hashes) = match full_str.strip_prefix('r') {
+        Some(rest) => (1, rest.len() - rest.trim_start_matches('#').len()),
+        None => (0, 0),
+    };
+    let start = full_span.start() + raw_prefix_len + hashes + 1;
+    let end = full_span.end() - hashes - 1;
+    let content = &full_span.src()[start..end];
+    let source_span = |from: usize, to: usize| {
+        Span::new(
+            full_span.src().clone(),
+            from,
+            to,
+            full_span.source_id().copied(),
+        )
+        .unwrap()
+    };
+    let subspan = |from: usize, to: usize| source_span(start + from, start + to);
+
+    let mut pieces = vec![];
+    // The text pieces keep the character before and after them in their spans, which the
+    // compiler strips from string literals in place of the quotes.
+    let push_text = |pieces: &mut Vec<FormatPiece>, from: usize, to: usize| {
+        if from < to {
+            pieces.push(FormatPiece::Text(LitString {
+                span: source_span(start + from - 1, start + to + 1),
+                parsed: content[from..to].to_string(),
+            }));
+        }
+    };
+    let invalid = |at: usize| (ParseErrorKind::InvalidFormatString, subspan(at, at + 1));
+    let mut next_index = 0;
+    let mut text_start = 0;
+    let mut chars = content.char_indices().peekable();
+    while let Some((position, character)) = chars.next() {
+        match character {
+            '{' | '}' if chars.next_if(|(_, next)| *next == character).is_some() => {
+                push_text(&mut pieces, text_start, position + 1);
+                text_start = position + 2;
+            }
+            '}' => return Err(invalid(position)),
+            '{' => {
+                push_text(&mut pieces, text_start, position);
+                let Some(close) = content[position..].find('}').map(|close| position + close)
+                else {
+                    return Err(invalid(position));
+                };
+                let placeholder = &content[position + 1..close];
+                let span = subspan(position, close + 1);
+                if placeholder.is_empty() {
+                    pieces.push(FormatPiece::Argument {
+                        index: next_index,
+                        span,
+                    });
+                    next_index += 1;
+                } else if let Ok(index) = placeholder.parse::<usize>() {
+                    pieces.push(FormatPiece::Argument { index, span });
+                } else if placeholder.starts_with(|c: char| c.is_xid_start() || c == '_')
+                    && placeholder.chars().all(|c: char| c.is_xid_continue())
+                {
+                    pieces.push(FormatPiece::Named(Ident::new(subspan(position + 1, close))));
+                } else {
+                    return Err(invalid(position));
+                }
+                while chars
+                    .next_if(|(next_position, _)| *next_position <= close)
+                    .is_some()
+                {}
+                text_start = close + 1;
+            }
+            _ => {}
+        }
+    }
+    push_text(&mut pieces, text_start, content.len());
+    Ok(pieces)
+}
+
+fn ident(name: &str, span: &Span) -> TokenTree {
+    TokenTree::Ident(Ident::new_with_override(name.to_string(), span.clone()))
+}
+
+fn punct(kind: PunctKind, spacing: Spacing, span: &Span) -> TokenTree {
+    TokenTree::Punct(Punct {
+        span: span.clone(),
+        kind,
+        spacing,
+    })
+}
+
+fn group(delimiter: Delimiter, token_trees: Vec<TokenTree>, span: &Span) -> TokenTree {
+    TokenTree::Group(Group {
+        delimiter,
+        token_stream: TokenStream::new(token_trees, span.clone()),
+        span: span.clone(),
+    })
+}
+
+/// Matches `matchers` against all of the `token_trees`.
+fn match_all(matchers: &[Matcher], token_trees: &[TokenTree], bindings: &mut Bindings) -> bool {
+    match_prefix(matchers, token_trees, bindings) == Some(token_trees.len())
+}
+
+/// Matches `matchers` against the start of `token_trees`.
+///
+/// Returns the number of matched token trees.
+fn match_prefix(
+    matchers: &[Matcher],
+    token_trees: &[TokenTree],
+    bindings: &mut Bindings,
+) -> Option<usize> {
+    let mut position = 0;
+    for matcher in matchers {
+        let rest = &token_trees[position..];
+        match matcher {
+            Matcher::Token(expected) => {
+                if !rest
+                    .first()
+                    .is_some_and(|token_tree| token_trees_eq(token_tree, expected))
+                {
+                    return None;
+                }
+                position += 1;
+            }
+            Matcher::Group {
+                delimiter,
+                matchers,
+            } => {
+                let Some(TokenTree::Group(group)) = rest.first() else {
+                    return None;
+                };
+                if group.delimiter != *delimiter
+                    || !match_all(matchers, group.token_stream.token_trees(), bindings)
+                {
+                    return None;
+                }
+                position += 1;
+            }
+            Matcher::Fragment { name, kind } => {
+                let len = match_fragment(*kind, rest)?;
+                bindings.insert(
+                    name.clone(),
+                    Binding::Fragment {
+                        token_trees: rest[..len].to_vec(),
+                        kind: *kind,
+                    },
+                );
+                position += len;
+            }
+            Matcher::Repetition {
+                matchers,
+                separator,
+                kind,
+            } => {
+                let mut iterations = vec![];
+                loop {
+                    let mut start = position;
+                    if let (false, Some(separator)) = (iterations.is_empty(), separator) {
+                        match token_trees.get(position) {
+                            Some(token_tree) if token_trees_eq(token_tree, separator) => start += 1,
+                            _ => break,
+                        }
+                    }
+                    let mut iteration = Bindings::new();
+                    match match_prefix(matchers, &token_trees[start..], &mut iteration) {
+                        Some(len) if len > 0 => {
+                            position = start + len;
+                            iterations.push(iteration);
+                        }
+                        _ => break,
+                    }
+                }
+                if *kind == RepetitionKind::OneOrMore && iterations.is_empty() {
+                    return None;
+                }
+                let mut names = vec![];
+                collect_matched_variables(matchers, &mut names);
+                for name in names {
+                    let repeated = iterations
+                        .iter_mut()
+                        .filter_map(|iteration| iteration.remove(&name))
+                        .collect();
+                    bindings.insert(name, Binding::Repeated(repeated));
+                }
+            }
+        }
+    }
+    Some(position)
+}
+
+/// Matches a fragment against the start of `token_trees`.
+///
+/// Returns the number of matched token trees.
+fn match_fragment(kind: FragmentKind, token_trees: &[TokenTree]) -> Option<usize> {
+    match kind {
+        FragmentKind::Ident => {
+            matches!(token_trees.first(), Some(TokenTree::Ident(_))).then_some(1)
+        }
+        FragmentKind::Literal => match token_trees.first() {
+            Some(TokenTree::Literal(_)) => Some(1),
+            Some(TokenTree::Ident(ident)) if matches!(ident.as_str(), "true" | "false") => Some(1),
+            _ => None,
+        },
+        FragmentKind::Tt => (!token_trees.is_empty()).then_some(1),
+        FragmentKind::Expr => parse_fragment::<Expr>(token_trees),
+        FragmentKind::Ty => parse_fragment::<Ty>(token_trees),
+    }
+}
+
+/// Parses a `T` from the start of `token_trees`, returning the number of parsed token trees.
+fn parse_fragment<T: Parse>(token_trees: &[TokenTree]) -> Option<usize> {
+    let full_span = Span::join(token_trees.first()?.span(), &token_trees.last()?.span());
+    let handler = Handler::default();
+    let mut parser = Parser::from_token_trees(&handler, token_trees, full_span);
+    if parser.parse::<T>().is_err() || handler.has_errors() {
+        return None;
+    }
+    Some(token_trees.len() - parser.remaining_token_trees().len())
+}
+
+fn transcribe(
+    transcribers: &[Transcriber],
+    bindings: &Bindings,
+    renames: &HashMap<Ident, String>,
+    repetition: &mut Vec<usize>,
+    output: &mut Vec<TokenTree>,
+) -> TranscribeResult<()> {
+    for (index, transcriber) in transcribers.iter().enumerate() {
+        match transcriber {
+            Transcriber::Token(TokenTree::Ident(ident))
+                if renames.contains_key(ident) && !follows_dot(transcribers, index) =>
+            {
+                output.push(TokenTree::Ident(Ident::new_with_override(
+                    renames[ident].clone(),
+                    ident.span(),
+                )));
+            }
+            Transcriber::Token(token_tree) => output.push(token_tree.clone()),
+            Transcriber::Group {
+                delimiter,
+                span,
+                transcribers,
+            } => {
+                let mut token_trees = vec![];
+                transcribe(
+                    transcribers,
+                    bindings,
+                    renames,
+                    repetition,
+                    &mut token_trees,
+                )?;
+                output.push(TokenTree::Group(Group {
+                    delimiter: *delimiter,
+                    token_stream: TokenStream::new(token_trees, span.clone()),
+                    span: span.clone(),
+                }));
+            }
+            Transcriber::Variable(name) => match lookup(bindings, name, repetition)? {
+                Binding::Fragment {
+                    token_trees,
+                    kind: FragmentKind::Expr,
+                } if token_trees.len() > 1 => {
+                    // Keep the precedence of the expression, e.g. `$x * 2` where `$x` is `1 + 1`.
+                    let span = Span::join_all(token_trees.iter().map(Spanned::span));
+                    output.push(TokenTree::Group(Group {
+                        delimiter: Delimiter::Parenthesis,
+                        token_stream: TokenStream::new(token_trees.clone(), span.clone()),
+                        span,
+                    }));
+                }
+                Binding::Fragment { token_trees, .. } => output.extend(token_trees.iter().cloned()),
+                Binding::Repeated(_) => {
+                    return Err((
+                        ParseErrorKind::MacroVariableStillRepeating { name: name.clone() },
+                        name.span(),
+                    ))
+                }
+            },
+            Transcriber::Repetition {
+                transcribers,
+                separator,
+                span,
+            } => {
+                let mut names = vec![];
+                collect_transcribed_variables(transcribers, &mut names);
+                let mut count = None;
+                for name in &names {
+                    if let Binding::Repeated(repeated) = lookup(bindings, name, repetition)? {
+                        match count {
+                            Some(count) if count != repeated.len() => {
+                                return Err((
+                                    ParseErrorKind::MacroRepetitionMismatch { name: name.clone() },
+                                    name.span(),
+                                ))
+                            }
+                            _ => count = Some(repeated.len()),
+                        }
+                    }
+                }
+                let Some(count) = count else {
+                    return Err((
+                        ParseErrorKind::MacroRepetitionWithoutVariables,
+                        span.clone(),
+                    ));
+                };
+                for iteration in 0..count {
+                    if let (true, Some(separator)) = (iteration > 0, separator) {
+                        output.push(separator.clone());
+                    }
+                    repetition.push(iteration);
+                    transcribe(transcribers, bindings, renames, repetition, output)?;
+                    repetition.pop();
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Finds the binding of the variable `name` within the current `repetition`.
+fn lookup<'b>(
+    bindings: &'b Bindings,
+    name: &Ident,
+    repetition: &[usize],
+) -> TranscribeResult<&'b Binding> {
+    let mut binding = bindings.get(name).ok_or_else(|| {
+        (
+            ParseErrorKind::UnknownMacroVariable { name: name.clone() },
+            name.span(),
+        )
+    })?;
+    for iteration in repetition {
+        match binding {
+            Binding::Repeated(repeated) => binding = &repeated[*iteration],
+            Binding::Fragment { .. } => break,
+        }
+    }
+    Ok(binding)
+}
+
+fn collect_matched_variables(matchers: &[Matcher], names: &mut Vec<Ident>) {
+    for matcher in matchers {
+        match matcher {
+            Matcher::Token(_) => {}
+            Matcher::Fragment { name, .. } => names.push(name.clone()),
+            Matcher::Group { matchers, .. } | Matcher::Repetition { matchers, .. } => {
+                collect_matched_variables(matchers, names)
+            }
+        }
+    }
+}
+
+fn collect_transcribed_variables(transcribers: &[Transcriber], names: &mut Vec<Ident>) {
+    for transcriber in transcribers {
+        match transcriber {
+            Transcriber::Token(_) => {}
+            Transcriber::Variable(name) => names.push(name.clone()),
+            Transcriber::Group { transcribers, .. }
+            | Transcriber::Repetition { transcribers, .. } => {
+                collect_transcribed_variables(transcribers, names)
+            }
+        }
+    }
+}
+
+/// Collects the names bound by `let name` and `let mut name` within the `transcribers`.
+fn collect_local_names(transcribers: &[Transcriber], names: &mut Vec<Ident>) {
+    for (index, transcriber) in transcribers.iter().enumerate() {
+        match transcriber {
+            Transcriber::Token(TokenTree::Ident(keyword)) if keyword.as_str() == "let" => {
+                let name = match &transcribers[index + 1..] {
+                    [Transcriber::Token(TokenTree::Ident(mut_keyword)), Transcriber::Token(TokenTree::Ident(name)), ..]
+                        if mut_keyword.as_str() == "mut" =>
+                    {
+                        name
+                    }
+                    [Transcriber::Token(TokenTree::Ident(name)), ..] => name,
+                    _ => continue,
+                };
+                if !names.contains(name) {
+                    names.push(name.clone());
+                }
+            }
+            Transcriber::Group { transcribers, .. }
+            | Transcriber::Repetition { transcribers, .. } => {
+                collect_local_names(transcribers, names)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// True if the transcriber at `index` is preceded by a `.`, i.e. it is a field or method name.
+fn follows_dot(transcribers: &[Transcriber], index: usize) -> bool {
+    index > 0
+        && matches!(
+            &transcribers[index - 1],
+            Transcriber::Token(TokenTree::Punct(punct)) if punct.kind == PunctKind::Dot
+        )
+}
+
+fn repetition_kind(token_tree: &TokenTree) -> Option<RepetitionKind> {
+    match token_tree {
+        TokenTree::Punct(punct) if punct.kind == PunctKind::Star => {
+            Some(RepetitionKind::ZeroOrMore)
+        }
+        TokenTree::Punct(punct) if punct.kind == PunctKind::Add => Some(RepetitionKind::OneOrMore),
+        _ => None,
+    }
+}
+
+fn is_punct(token_tree: Option<&TokenTree>, kind: PunctKind) -> bool {
+    matches!(token_tree, Some(TokenTree::Punct(punct)) if punct.kind == kind)
+}
+
+/// Compares two token trees, ignoring their spans.
+fn token_trees_eq(lhs: &TokenTree, rhs: &TokenTree) -> bool {
+    match (lhs, rhs) {
+        (TokenTree::Punct(lhs), TokenTree::Punct(rhs)) => lhs.kind == rhs.kind,
+        (TokenTree::Ident(lhs), TokenTree::Ident(rhs)) => lhs == rhs,
+        (TokenTree::Literal(lhs), TokenTree::Literal(rhs)) => {
+            lhs.span().as_str() == rhs.span().as_str()
+        }
+        (TokenTree::Group(lhs), TokenTree::Group(rhs)) => {
+            let (lhs_trees, rhs_trees) = (
+                lhs.token_stream.token_trees(),
+                rhs.token_stream.token_trees(),
+            );
+            lhs.delimiter == rhs.delimiter
+                && lhs_trees.len() == rhs_trees.len()
+                && lhs_trees
+                    .iter()
+                    .zip(rhs_trees)
+                    .all(|(lhs, rhs)| token_trees_eq(lhs, rhs))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lex;
+    use std::sync::Arc;
+
+    /// Expands the macros within `src` and returns the expanded tokens as source code.
+    fn expand(src: &str) -> String {
+        let handler = Handler::default();
+        let src: Arc<str> = Arc::from(src);
+        let token_stream = lex(&handler, &src, 0, src.len(), None).unwrap();
+        let expanded = expand_macros(&handler, token_stream).unwrap();
+        let mut output = String::new();
+        write_token_trees(expanded.token_trees(), &mut output);
+        output.trim_end().to_string()
+    }
+
+    fn write_token_trees(token_trees: &[TokenTree], output: &mut String) {
+        for token_tree in token_trees {
+            match token_tree {
+                TokenTree::Ident(ident) => output.push_str(ident.as_str()),
+                TokenTree::Group(group) => {
+                    let (open, close) = match group.delimiter {
+                        Delimiter::Parenthesis => ('(', ')'),
+                        Delimiter::Brace => ('{', '}'),
+                        Delimiter::Bracket => ('[', ']'),
+                    };
+                    output.push(open);
+                    output.push(' ');
+                    write_token_trees(group.token_stream.token_trees(), output);
+                    output.push(close);
+                }
+                // The literals generated by `format!` have the spans of the format string.
+                TokenTree::Literal(Literal::String(lit_string)) => {
+                    output.push_str(&format!("{:?}", lit_string.parsed))
+                }
+                TokenTree::Literal(Literal::Int(lit_int)) => {
+                    output.push_str(&lit_int.parsed.to_string())
+                }
+                token_tree => output.push_str(token_tree.span().as_str()),
+            }
+            output.push(' ');
+        }
+    }
+
+    #[test]
+    fn expands_expressions_in_blocks() {
+        let expanded = expand(
+            r#"
+            macro_rules! double { ($x:expr) => { $x * 2 }; }
+            fn f() -> u64 { double!(1 + 1) }
+            "#,
+        );
+        assert_eq!(expanded, "fn f ( ) - > u64 { { ( 1 + 1 ) * 2 } }");
+    }
+
+    #[test]
+    fn expands_repetitions_to_items() {
+        let expanded = expand(
+            r#"
+            macro_rules! consts {
+                ($($name:ident = $value:literal),*) => { $(const $name: u64 = $value;)* };
+            }
+            consts!(A = 1, B = 2);
+            "#,
+        );
+        assert_eq!(expanded, "const A : u64 = 1 ; const B : u64 = 2 ;");
+    }
+
+    #[test]
+    fn renames_local_names() {
+        let expanded = expand(
+            r#"
+            macro_rules! add_one { ($x:expr) => { let tmp = 1; tmp + $x }; }
+            fn f(tmp: u64) -> u64 { add_one!(tmp) }
+            "#,
+        );
+        assert_eq!(
+            expanded,
+            "fn f ( tmp : u64 ) - > u64 { { let tmp__macro0 = 1 ; tmp__macro0 + tmp } }"
+        );
+    }
+
+    #[test]
+    fn reports_unmatched_invocations() {
+        let handler = Handler::default();
+        let src: Arc<str> = Arc::from("macro_rules! m { () => {}; } fn f() { m!(1) }");
+        let token_stream = lex(&handler, &src, 0, src.len(), None).unwrap();
+        assert!(expand_macros(&handler, token_stream).is_err());
+        let (errors, _) = handler.consume();
+        assert!(matches!(
+            &errors[..],
+            [CompileError::Parse { error }]
+                if matches!(error.kind, ParseErrorKind::NoMatchingMacroRule { .. })
+        ));
+    }
+
+    #[test]
+    fn expands_format() {
+        let expanded = expand(
+            r#"
+            fn f(x: u64) -> String { format!("x = {}, {{{x}}}", x + 1) }
+            "#,
+        );
+        assert_eq!(
+            expanded,
+            "fn f ( x : u64 ) - > String { { \
+            let format_arg0__macro0 = x + 1 ; \
+            let mut formatter__macro0 = std : : format : : Formatter : : with_capacity ( 8 ) ; \
+            formatter__macro0 . write_str ( \"x = \" ) ; \
+            formatter__macro0 . write ( format_arg0__macro0 ) ; \
+            formatter__macro0 . write_str ( \", {\" ) ; \
+            formatter__macro0 . write ( x ) ; \
+            formatter__macro0 . write_str ( \"}\" ) ; \
+            formatter__macro0 . finish ( ) } }"
+        );
+    }
+
+    #[test]
+    fn rejects_macros_when_not_enabled() {
+        let handler = Handler::default();
+        let src: Arc<str> =
+            Arc::from(r#"fn f() -> bool { if true { format!("{}", 1) }; x != (y) }"#);
+        let token_stream = lex(&handler, &src, 0, src.len(), None).unwrap();
+        assert!(reject_macros(&handler, &token_stream).is_err());
+        let (errors, _) = handler.consume();
+        assert!(matches!(
+            &errors[..],
+            [CompileError::Parse { error }]
+                if matches!(error.kind, ParseErrorKind::DeclarativeMacrosNotEnabled { .. })
+                    && error.span.as_str() == "format"
+        ));
+
+        let handler = Handler::default();
+        let src: Arc<str> = Arc::from("fn f(format: bool) -> bool { !format != (true) }");
+        let token_stream = lex(&handler, &src, 0, src.len(), None).unwrap();
+        assert!(reject_macros(&handler, &token_stream).is_ok());
+    }
+
+    #[test]
+    fn reports_unused_format_arguments() {
+        let handler = Handler::default();
+        let src: Arc<str> = Arc::from(r#"fn f() { format!("{}", 1, 2) }"#);
+        let token_stream = lex(&handler, &src, 0, src.len(), None).unwrap();
+        assert!(expand_macros(&handler, token_stream).is_err());
+        let (errors, _) = handler.consume();
+        assert!(matches!(
+            &errors[..],
+            [CompileError::Parse { error }]
+                if matches!(error.kind, ParseErrorKind::UnusedFormatArgument)
+                    && error.span.as_str() == "2"
+        ));
+    }
+}
@@ -32,6 +32,20 @@ impl<'a, 'e> Parser<'a, 'e> {
         }
     }
 
+    /// Creates a parser over the given token trees, which all lie within the `full_span`.
+    pub(crate) fn from_token_trees(
+        handler: &'e Handler,
+        token_trees: &'a [TokenTree],
+        full_span: Span,
+    ) -> Parser<'a, 'e> {
+        Parser {
+            token_trees,
+            full_span,
+            handler,
+            check_double_underscore: true,
+        }
+    }
+
     pub fn emit_error(&mut self, kind: ParseErrorKind) -> ErrorEmitted {
         let span = match self.token_trees {
             [token_tree, ..] => token_tree.span(),
@@ -288,6 +302,11 @@ impl<'a, 'e> Parser<'a, 'e> {
             .then_some(ParserConsumed { _priv: PhantomData })
     }
 
+    /// The token trees that are yet to be parsed.
+    pub(crate) fn remaining_token_trees(&self) -> &'a [TokenTree] {
+        self.token_trees
+    }
+
     pub fn debug_tokens(&self) -> &[TokenTree] {
         let len = std::cmp::min(5, self.token_trees.len());
         &self.token_trees[..len]
@@ -1,6 +1,6 @@
 use crate::{Parse, ParseBracket, ParseResult, Parser, Peek};
 
-use sway_ast::brackets::{Braces, Parens};
+use sway_ast::brackets::{Braces, Parens, SquareBrackets};
 use sway_ast::keywords::{DoubleDotToken, FalseToken, TrueToken};
 use sway_ast::literal::{LitBool, LitBoolType};
 use sway_ast::punctuated::Punctuated;
@@ -69,6 +69,12 @@ fn parse_atomic_pattern(parser: &mut Parser) -> ParseResult<Pattern> {
         if let Some(tuple) = Parens::try_parse(parser)? {
             return Ok(Pattern::Tuple(tuple));
         }
+        if let Some(slice) = SquareBrackets::try_parse(parser)? {
+            return Ok(Pattern::Slice(slice));
+        }
+        if let Some(token) = parser.take::<DoubleDotToken>() {
+            return Ok(Pattern::Rest { token });
+        }
         if let Some(underscore_token) = parser.take() {
             return Ok(Pattern::Wildcard { underscore_token });
         }
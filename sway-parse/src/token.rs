@@ -60,11 +60,15 @@ impl CharExt for char {
             '|' => Some(PunctKind::Pipe),
             '_' => Some(PunctKind::Underscore),
             '#' => Some(PunctKind::Sharp),
+            '$' => Some(PunctKind::Dollar),
+            '?' => Some(PunctKind::QuestionMark),
+            '\'' => Some(PunctKind::Apostrophe),
             _ => None,
         }
     }
 }
 
+#[derive(Clone)]
 struct CharIndicesInner<'a> {
     src: &'a str,
     position: usize,
@@ -200,6 +204,10 @@ pub fn lex_commented(
             gather_module_docs = false;
         }
 
+        if let Some(token) = lex_raw_string(&mut l, index, character)? {
+            token_trees.push(token);
+            continue;
+        }
         if character.is_xid_start() || character == '_' {
             // Raw identifier, e.g., `r#foo`? Then mark as such, stripping the prefix `r#`.
             let is_raw_ident = character == 'r' && matches!(l.stream.peek(), Some((_, '#')));
@@ -505,6 +513,73 @@ fn lex_string(
     Ok(Some(CommentedTokenTree::Tree(literal.into())))
 }
 
+/// Lexes a raw string literal, e.g., `r"C:\path"` or `r#"{"key": "value"}"#`.
+///
+/// The content of a raw string is taken verbatim, without processing escape codes.
+/// It ends at the first `"` followed by as many `#`s as there were after the leading `r`.
+fn lex_raw_string(
+    l: &mut Lexer<'_>,
+    index: usize,
+    character: char,
+) -> Result<Option<CommentedTokenTree>> {
+    if character != 'r' {
+        return Ok(None);
+    }
+
+    // Look ahead without consuming, so that e.g., raw identifiers like `r#foo`
+    // are left to be lexed as identifiers.
+    let mut lookahead = l.stream.clone();
+    let mut hashes = 0;
+    while lookahead.next_if(|(_, c)| *c == '#').is_some() {
+        hashes += 1;
+    }
+    if lookahead.next_if(|(_, c)| *c == '"').is_none() {
+        return Ok(None);
+    }
+    *l.stream = lookahead;
+
+    let mut parsed = String::new();
+    loop {
+        let Some((next_index, next_character)) = l.stream.next() else {
+            // last character may not be a unicode boundary
+            let mut end = l.src.len() - 1;
+            while !l.src.is_char_boundary(end) {
+                end -= 1;
+            }
+            return Err(error(
+                l.handler,
+                LexError {
+                    kind: LexErrorKind::UnclosedStringLiteral { position: index },
+                    span: span(l, index, end),
+                },
+            ));
+        };
+        match next_character {
+            '"' => {
+                let mut closing = l.stream.clone();
+                if (0..hashes).all(|_| closing.next_if(|(_, c)| *c == '#').is_some()) {
+                    *l.stream = closing;
+                    break;
+                }
+                parsed.push(next_character);
+            }
+            // do not allow text direction codepoints
+            ALM | FSI | LRE | LRI | LRM | LRO | PDF | PDI | RLE | RLI | RLM | RLO => {
+                let kind = LexErrorKind::UnicodeTextDirInLiteral {
+                    position: next_index,
+                    character: next_character,
+                };
+                let span = span_one(l, next_index, next_character);
+                error(l.handler, LexError { span, kind });
+            }
+            _ => parsed.push(next_character),
+        }
+    }
+    let span = span_until(l, index);
+    let literal = Literal::String(LitString { span, parsed });
+    Ok(Some(CommentedTokenTree::Tree(literal.into())))
+}
+
 fn lex_char(
     l: &mut Lexer<'_>,
     index: usize,
@@ -515,6 +590,20 @@ fn lex_char(
         return Ok(None);
     }
 
+    // A quote followed by an identifier that is not closed by another quote, like in
+    // `'outer: while`, is the start of a label. It is lexed as the `'` punctuation followed
+    // by the identifier.
+    let mut lookahead = l.stream.clone();
+    if lookahead
+        .next_if(|(_, c)| c.is_xid_start() || *c == '_')
+        .is_some()
+    {
+        while lookahead.next_if(|(_, c)| c.is_xid_continue()).is_some() {}
+        if !matches!(lookahead.peek(), Some((_, next)) if is_quote(*next)) {
+            return Ok(None);
+        }
+    }
+
     let unclosed_char_lit = |l: &Lexer<'_>| {
         let err = LexError {
             kind: LexErrorKind::UnclosedCharLiteral { position: index },
@@ -844,7 +933,7 @@ mod tests {
     use assert_matches::assert_matches;
     use std::sync::Arc;
     use sway_ast::{
-        literal::{LitChar, Literal},
+        literal::{LitChar, LitString, Literal},
         token::{
             Comment, CommentKind, CommentedTokenTree, CommentedTree, DocComment, DocStyle,
             TokenTree,
@@ -1095,4 +1184,96 @@ mod tests {
         );
         assert_eq!(tts.next(), None);
     }
+
+    #[test]
+    fn lex_labels() {
+        let input = r"
+        'outer: 'a'
+        ";
+        let handler = Handler::default();
+        let stream = lex(&handler, &Arc::from(input), 0, input.len(), None).unwrap();
+        assert!(handler.consume().0.is_empty());
+        let mut tts = stream.token_trees().iter();
+        assert_matches!(
+            tts.next(),
+            Some(TokenTree::Punct(Punct {
+                kind: PunctKind::Apostrophe,
+                spacing: Spacing::Alone,
+                ..
+            }))
+        );
+        assert_eq!(tts.next().unwrap().span().as_str(), "outer");
+        assert_eq!(tts.next().unwrap().span().as_str(), ":");
+        assert_matches!(
+            tts.next(),
+            Some(TokenTree::Literal(Literal::Char(LitChar {
+                parsed: 'a',
+                ..
+            })))
+        );
+        assert_eq!(tts.next(), None);
+    }
+
+    #[test]
+    fn lex_raw_strings() {
+        let input = r###"
+        r"C:\path\n" r#"{"key": "value"}"# r##"a "# b"## r#foo
+        "###;
+        let handler = Handler::default();
+        let stream = lex(&handler, &Arc::from(input), 0, input.len(), None).unwrap();
+        assert!(handler.consume().0.is_empty());
+        let mut tts = stream.token_trees().iter();
+        for (expected_span, expected_parsed) in [
+            (r#"r"C:\path\n""#, r"C:\path\n"),
+            (r##"r#"{"key": "value"}"#"##, r#"{"key": "value"}"#),
+            (r###"r##"a "# b"##"###, r##"a "# b"##),
+        ] {
+            assert_matches!(
+                tts.next(),
+                Some(TokenTree::Literal(Literal::String(LitString {
+                    span,
+                    parsed,
+                }))) if span.as_str() == expected_span && parsed == expected_parsed
+            );
+        }
+        assert_matches!(
+            tts.next(),
+            Some(TokenTree::Ident(ident)) if ident.is_raw_ident() && ident.as_str() == "foo"
+        );
+        assert_eq!(tts.next(), None);
+    }
+
+    #[test]
+    fn lex_multiline_string() {
+        let input = "\"first\n    second\"";
+        let handler = Handler::default();
+        let stream = lex(&handler, &Arc::from(input), 0, input.len(), None).unwrap();
+        assert!(handler.consume().0.is_empty());
+        let mut tts = stream.token_trees().iter();
+        assert_matches!(
+            tts.next(),
+            Some(TokenTree::Literal(Literal::String(LitString {
+                parsed,
+                ..
+            }))) if parsed == "first\n    second"
+        );
+        assert_eq!(tts.next(), None);
+    }
+
+    #[test]
+    fn lex_unclosed_raw_string() {
+        let input = r##"r#"never closed""##;
+        let handler = Handler::default();
+        let _stream = lex(&handler, &Arc::from(input), 0, input.len(), None);
+        let (errors, _) = handler.consume();
+        assert_matches!(
+            errors.as_slice(),
+            [CompileError::Lex {
+                error: LexError {
+                    kind: LexErrorKind::UnclosedStringLiteral { position: 0 },
+                    ..
+                }
+            }]
+        );
+    }
 }
@@ -42,6 +42,9 @@ pub enum PunctKind {
     Pipe,
     Underscore,
     Sharp,
+    Dollar,
+    QuestionMark,
+    Apostrophe,
 }
 
 impl PunctKind {
@@ -65,6 +68,9 @@ impl PunctKind {
             PunctKind::Pipe => '|',
             PunctKind::Underscore => '_',
             PunctKind::Sharp => '#',
+            PunctKind::Dollar => '$',
+            PunctKind::QuestionMark => '?',
+            PunctKind::Apostrophe => '\'',
         }
     }
 }
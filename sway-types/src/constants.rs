@@ -37,6 +37,12 @@ pub const DOC_COMMENT_ATTRIBUTE_NAME: &str = "doc-comment";
 /// The attribute used for Sway in-language unit tests.
 pub const TEST_ATTRIBUTE_NAME: &str = "test";
 
+/// The valid attribute strings related to mocking external contract calls in tests.
+pub const MOCK_ATTRIBUTE_NAME: &str = "mock";
+pub const MOCK_CONTRACT_ARG_NAME: &str = "contract";
+pub const MOCK_METHOD_ARG_NAME: &str = "method";
+pub const MOCK_RETURNS_ARG_NAME: &str = "returns";
+
 /// The valid attribute string used for payable functions.
 pub const PAYABLE_ATTRIBUTE_NAME: &str = "payable";
 
@@ -54,6 +60,42 @@ pub const DEPRECATED_ATTRIBUTE_NAME: &str = "deprecated";
 
 pub const FALLBACK_ATTRIBUTE_NAME: &str = "fallback";
 
+/// The valid attribute string used for enums whose variants are published as error codes.
+pub const ERROR_TYPE_ATTRIBUTE_NAME: &str = "error_type";
+
+/// The valid attribute strings related to structs logged as events.
+pub const EVENT_ATTRIBUTE_NAME: &str = "event";
+pub const INDEXED_ATTRIBUTE_NAME: &str = "indexed";
+
+/// The valid attribute string used to derive trait implementations, and the derivable traits.
+pub const DERIVE_ATTRIBUTE_NAME: &str = "derive";
+pub const DERIVE_EQ_NAME: &str = "Eq";
+pub const DERIVE_PARTIAL_EQ_NAME: &str = "PartialEq";
+pub const DERIVE_CLONE_NAME: &str = "Clone";
+pub const DERIVE_HASH_NAME: &str = "Hash";
+pub const DERIVE_ABI_ENCODE_NAME: &str = "AbiEncode";
+pub const DERIVE_ABI_DECODE_NAME: &str = "AbiDecode";
+
+/// The valid attribute string used for functions that validate external inputs.
+pub const SANITIZER_ATTRIBUTE_NAME: &str = "sanitizer";
+
+/// The valid attribute string used for functions that prevent reentrancy.
+pub const REENTRANCY_GUARD_ATTRIBUTE_NAME: &str = "reentrancy_guard";
+
+/// The valid attribute string used for functions returning iterators bounded by one of their
+/// arguments.
+pub const ITERATION_BOUND_ATTRIBUTE_NAME: &str = "iteration_bound";
+
+/// The valid attribute strings used to pin the selector and the method name contract calls use to
+/// identify an ABI method.
+pub const SELECTOR_ATTRIBUTE_NAME: &str = "selector";
+pub const METHOD_NAME_ATTRIBUTE_NAME: &str = "method_name";
+pub const ABI_METHOD_OVERRIDE_VALUE_ARG_NAME: &str = "value";
+
+/// The valid attribute strings related to the formatter.
+pub const FMT_ATTRIBUTE_NAME: &str = "fmt";
+pub const FMT_SKIP_NAME: &str = "skip";
+
 /// The list of valid attributes.
 pub const VALID_ATTRIBUTE_NAMES: &[&str] = &[
     STORAGE_PURITY_ATTRIBUTE_NAME,
@@ -66,6 +108,17 @@ pub const VALID_ATTRIBUTE_NAMES: &[&str] = &[
     CFG_ATTRIBUTE_NAME,
     DEPRECATED_ATTRIBUTE_NAME,
     FALLBACK_ATTRIBUTE_NAME,
+    FMT_ATTRIBUTE_NAME,
+    ERROR_TYPE_ATTRIBUTE_NAME,
+    EVENT_ATTRIBUTE_NAME,
+    INDEXED_ATTRIBUTE_NAME,
+    DERIVE_ATTRIBUTE_NAME,
+    MOCK_ATTRIBUTE_NAME,
+    SANITIZER_ATTRIBUTE_NAME,
+    REENTRANCY_GUARD_ATTRIBUTE_NAME,
+    ITERATION_BOUND_ATTRIBUTE_NAME,
+    SELECTOR_ATTRIBUTE_NAME,
+    METHOD_NAME_ATTRIBUTE_NAME,
 ];
 
 pub const CORE: &str = "core";
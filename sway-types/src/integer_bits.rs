@@ -27,6 +27,16 @@ impl IntegerBits {
 
         needed_bits > bits
     }
+
+    /// Returns `v` truncated to the lowest `self` bits.
+    pub fn truncate(&self, v: u64) -> u64 {
+        match self {
+            IntegerBits::Eight => v as u8 as u64,
+            IntegerBits::Sixteen => v as u16 as u64,
+            IntegerBits::ThirtyTwo => v as u32 as u64,
+            IntegerBits::SixtyFour | IntegerBits::V256 => v,
+        }
+    }
 }
 
 #[test]
@@ -45,6 +55,20 @@ fn would_overflow_tests() {
     assert!(!IntegerBits::SixtyFour.would_overflow(0xFFFFFFFFFFFFFFFF));
 }
 
+#[test]
+fn truncate_tests() {
+    assert_eq!(IntegerBits::Eight.truncate(0xFF), 0xFF);
+    assert_eq!(IntegerBits::Eight.truncate(0x1FF), 0xFF);
+    assert_eq!(IntegerBits::Eight.truncate(0x100), 0);
+
+    assert_eq!(IntegerBits::Sixteen.truncate(0x12345), 0x2345);
+    assert_eq!(IntegerBits::ThirtyTwo.truncate(0x123456789), 0x23456789);
+    assert_eq!(
+        IntegerBits::SixtyFour.truncate(0xFFFFFFFFFFFFFFFF),
+        0xFFFFFFFFFFFFFFFF
+    );
+}
+
 impl fmt::Display for IntegerBits {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use IntegerBits::{Eight, Sixteen, SixtyFour, ThirtyTwo, V256};
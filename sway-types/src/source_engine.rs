@@ -142,6 +142,54 @@ impl SourceEngine {
         v
     }
 
+    /// Returns the ids of all the programs known to the source engine, in ascending order.
+    pub fn all_program_ids(&self) -> Vec<ProgramId> {
+        let mut program_ids = self
+            .module_to_sources_map
+            .read()
+            .keys()
+            .copied()
+            .collect::<Vec<_>>();
+        program_ids.sort();
+        program_ids
+    }
+
+    /// Returns an estimate of the memory held by the source engine, in bytes.
+    pub fn memory_usage(&self) -> usize {
+        let paths = self
+            .source_to_path_map
+            .read()
+            .values()
+            .map(|path| path.as_os_str().len())
+            .sum::<usize>();
+        let entry_size = std::mem::size_of::<(SourceId, PathBuf)>();
+        // Every path is stored in both directions.
+        2 * (paths + self.source_to_path_map.read().len() * entry_size)
+            + self
+                .manifest_path_to_program_map
+                .read()
+                .keys()
+                .map(|path| path.as_os_str().len() + std::mem::size_of::<(PathBuf, ProgramId)>())
+                .sum::<usize>()
+    }
+
+    /// Removes the program `program_id` and the sources it contains from the source engine.
+    pub fn clear_program(&self, program_id: &ProgramId) {
+        let Some(source_ids) = self.module_to_sources_map.write().remove(program_id) else {
+            return;
+        };
+        let mut source_map = self.path_to_source_map.write();
+        let mut path_map = self.source_to_path_map.write();
+        for source_id in &source_ids {
+            if let Some(path) = path_map.remove(source_id) {
+                source_map.remove(&path);
+            }
+        }
+        self.manifest_path_to_program_map
+            .write()
+            .retain(|_, id| id != program_id);
+    }
+
     pub fn get_source_ids_from_program_id(
         &self,
         program_id: ProgramId,
@@ -15,5 +15,6 @@ pub const STRUCT_FIELD_SEPARATOR: &str = ".";
 pub const LIB_ENTRY: &str = "lib.sw";
 pub const MAIN_ENTRY: &str = "main.sw";
 pub const FORC_INIT_MANIFEST_AUTHOR: &str = "FORC_INIT_MANIFEST_AUTHOR";
+pub const FORC_GIT_CHECKOUTS_DIR: &str = "FORC_GIT_CHECKOUTS_DIR";
 // TODO: Once Fuel has its own IPFS node, default should be that node for performance reasons.
 pub const DEFAULT_IPFS_GATEWAY_URL: &str = "https://ipfs.io";
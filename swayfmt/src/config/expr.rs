@@ -13,6 +13,8 @@ pub struct Expressions {
     pub space_before_colon: bool,
     /// Leave a space after the colon.
     pub space_after_colon: bool,
+    /// Trailing comma after the last argument of function calls and declarations.
+    pub fn_args_trailing_comma: TrailingComma,
 
     // OPERATORS
     /// Determines if `+` or `=` are wrapped in spaces in the punctuation of types.
@@ -25,6 +27,10 @@ pub struct Expressions {
     pub match_block_trailing_comma: bool,
     /// Determines whether leading pipes are emitted on match arms.
     pub match_arm_leading_pipe: MatchArmLeadingPipe,
+    /// Trailing comma after the last arm of a match, if it is a block based arm (non-block arms
+    /// always end with a comma). As arms are always on separate lines, `Vertical` keeps the
+    /// comma as written.
+    pub match_arms_trailing_comma: TrailingComma,
 
     // FUNCTIONS
     /// Force multiline closure bodies and match arms to be wrapped in a block.
@@ -33,6 +39,13 @@ pub struct Expressions {
     pub fn_args_layout: ItemsLayout,
     /// Put single-expression functions on a single line.
     pub fn_single_line: bool,
+    /// Rewrite the values returned at the end of function bodies into a consistent style.
+    pub return_style: ReturnStyle,
+
+    // ASM
+    /// Align the opcodes and operands of `asm` blocks into columns.
+    /// If false, `asm` blocks are kept as written.
+    pub format_asm: bool,
 }
 
 impl Default for Expressions {
@@ -42,13 +55,17 @@ impl Default for Expressions {
             trailing_semicolon: true,
             space_before_colon: false,
             space_after_colon: false,
+            fn_args_trailing_comma: Default::default(),
             type_combinator_layout: Default::default(),
             spaces_around_ranges: false,
             match_block_trailing_comma: false,
             match_arm_leading_pipe: Default::default(),
+            match_arms_trailing_comma: Default::default(),
             force_multiline_blocks: false,
             fn_args_layout: Default::default(),
             fn_single_line: false,
+            return_style: Default::default(),
+            format_asm: true,
         }
     }
 }
@@ -65,6 +82,9 @@ impl Expressions {
                 .space_before_colon
                 .unwrap_or(default.space_before_colon),
             space_after_colon: opts.space_after_colon.unwrap_or(default.space_after_colon),
+            fn_args_trailing_comma: opts
+                .fn_args_trailing_comma
+                .unwrap_or(default.fn_args_trailing_comma),
             type_combinator_layout: opts
                 .type_combinator_layout
                 .unwrap_or(default.type_combinator_layout),
@@ -77,11 +97,16 @@ impl Expressions {
             match_arm_leading_pipe: opts
                 .match_arm_leading_pipe
                 .unwrap_or(default.match_arm_leading_pipe),
+            match_arms_trailing_comma: opts
+                .match_arms_trailing_comma
+                .unwrap_or(default.match_arms_trailing_comma),
             force_multiline_blocks: opts
                 .force_multiline_blocks
                 .unwrap_or(default.force_multiline_blocks),
             fn_args_layout: opts.fn_args_layout.unwrap_or(default.fn_args_layout),
             fn_single_line: opts.fn_single_line.unwrap_or(default.fn_single_line),
+            return_style: opts.return_style.unwrap_or(default.return_style),
+            format_asm: opts.format_asm.unwrap_or(default.format_asm),
         }
     }
 }
@@ -101,6 +126,19 @@ pub enum ExprBraceStyle {
     AlwaysNextLine,
 }
 
+/// When to put a trailing comma after the last element of a comma separated list.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TrailingComma {
+    /// Always put a trailing comma, even if the list is on a single line.
+    Always,
+    /// Never put a trailing comma, even if the list is split over multiple lines.
+    Never,
+    /// Put a trailing comma only if the list is split over multiple lines.
+    #[default]
+    Vertical,
+}
+
 /// Spacing around type combinators.
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, Default)]
 pub enum TypeCombinatorLayout {
@@ -124,3 +162,18 @@ pub enum MatchArmLeadingPipe {
     /// Preserve any existing leading pipes
     Preserve,
 }
+
+/////FUNCTIONS/////
+
+/// How values are returned at the end of function bodies.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ReturnStyle {
+    /// Return values as tail expressions, rewriting a trailing `return x;` into `x`.
+    Implicit,
+    /// Return values with `return`, rewriting a tail expression `x` into `return x;`.
+    Explicit,
+    /// Preserve the existing style.
+    #[default]
+    Preserve,
+}
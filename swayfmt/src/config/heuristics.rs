@@ -16,6 +16,12 @@ pub struct Heuristics {
     pub heuristics_pref: HeuristicsPreferences,
     /// Whether to use different formatting for items and expressions if they satisfy a heuristic notion of 'small'
     pub use_small_heuristics: bool,
+    /// Maximum width in the body of a struct literal before falling back to vertical formatting.
+    /// Overrides the width derived from `heuristics_pref`.
+    pub struct_lit_width: Option<usize>,
+    /// Maximum line length for single line if-else expressions. Overrides the width derived
+    /// from `heuristics_pref`.
+    pub single_line_if_else_max_width: Option<usize>,
 }
 
 impl Default for Heuristics {
@@ -23,6 +29,8 @@ impl Default for Heuristics {
         Self {
             heuristics_pref: Default::default(),
             use_small_heuristics: true,
+            struct_lit_width: None,
+            single_line_if_else_max_width: None,
         }
     }
 }
@@ -35,8 +43,25 @@ impl Heuristics {
             use_small_heuristics: opts
                 .use_small_heuristics
                 .unwrap_or(default.use_small_heuristics),
+            struct_lit_width: opts.struct_lit_width.or(default.struct_lit_width),
+            single_line_if_else_max_width: opts
+                .single_line_if_else_max_width
+                .or(default.single_line_if_else_max_width),
         }
     }
+
+    /// The [WidthHeuristics] derived from `heuristics_pref`, with any granular widths that were
+    /// set explicitly taking precedence.
+    pub fn to_width_heuristics(&self, max_width: usize) -> WidthHeuristics {
+        let mut width_heuristics = self.heuristics_pref.to_width_heuristics(max_width);
+        if let Some(struct_lit_width) = self.struct_lit_width {
+            width_heuristics.structure_lit_width = struct_lit_width;
+        }
+        if let Some(single_line_if_else_max_width) = self.single_line_if_else_max_width {
+            width_heuristics.single_line_if_else_max_width = single_line_if_else_max_width;
+        }
+        width_heuristics
+    }
 }
 
 /// Heuristic settings that can be used to simplify
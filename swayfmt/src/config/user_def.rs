@@ -1,5 +1,5 @@
 //! Configuration options related to formatting user-defined structures.
-use crate::config::user_opts::StructuresOptions;
+use crate::config::{expr::TrailingComma, user_opts::StructuresOptions};
 use serde::{Deserialize, Serialize};
 
 /// Styling preferences for user-defined structures like `struct`s or `enum`s.
@@ -9,6 +9,10 @@ pub struct Structures {
     pub field_alignment: FieldAlignment,
     /// Put small user-defined structure literals on a single line.
     pub small_structures_single_line: bool,
+    /// Trailing comma after the last field of user-defined structure literals.
+    pub struct_lit_trailing_comma: TrailingComma,
+    /// Trailing comma after the last variant of `enum`s.
+    pub enum_variants_trailing_comma: TrailingComma,
 }
 
 impl Default for Structures {
@@ -16,6 +20,8 @@ impl Default for Structures {
         Self {
             field_alignment: Default::default(),
             small_structures_single_line: true,
+            struct_lit_trailing_comma: Default::default(),
+            enum_variants_trailing_comma: Default::default(),
         }
     }
 }
@@ -28,6 +34,12 @@ impl Structures {
             small_structures_single_line: opts
                 .struct_lit_single_line
                 .unwrap_or(default.small_structures_single_line),
+            struct_lit_trailing_comma: opts
+                .struct_lit_trailing_comma
+                .unwrap_or(default.struct_lit_trailing_comma),
+            enum_variants_trailing_comma: opts
+                .enum_variants_trailing_comma
+                .unwrap_or(default.enum_variants_trailing_comma),
         }
     }
 }
@@ -1,6 +1,6 @@
 //! All of the user-facing configuration options stored in [ConfigOptions].
 use crate::config::{
-    expr::{ExprBraceStyle, MatchArmLeadingPipe, TypeCombinatorLayout},
+    expr::{ExprBraceStyle, MatchArmLeadingPipe, ReturnStyle, TrailingComma, TypeCombinatorLayout},
     heuristics::HeuristicsPreferences,
     imports::{GroupImports, ImportGranularity},
     items::{ItemBraceStyle, ItemsLayout},
@@ -54,25 +54,33 @@ pub struct ExpressionsOptions {
     pub trailing_semicolon: Option<bool>,
     pub space_before_colon: Option<bool>,
     pub space_after_colon: Option<bool>,
+    pub fn_args_trailing_comma: Option<TrailingComma>,
     pub type_combinator_layout: Option<TypeCombinatorLayout>,
     pub spaces_around_ranges: Option<bool>,
     pub match_block_trailing_comma: Option<bool>,
     pub match_arm_leading_pipe: Option<MatchArmLeadingPipe>,
+    pub match_arms_trailing_comma: Option<TrailingComma>,
     pub force_multiline_blocks: Option<bool>,
     pub fn_args_layout: Option<ItemsLayout>,
     pub fn_single_line: Option<bool>,
+    pub return_style: Option<ReturnStyle>,
+    pub format_asm: Option<bool>,
 }
 /// See parent struct [Heuristics].
 #[derive(Serialize, Deserialize, Debug, Copy, Clone)]
 pub struct HeuristicsOptions {
     pub heuristics_pref: Option<HeuristicsPreferences>,
     pub use_small_heuristics: Option<bool>,
+    pub struct_lit_width: Option<usize>,
+    pub single_line_if_else_max_width: Option<usize>,
 }
 /// See parent struct [Structures].
 #[derive(Serialize, Deserialize, Debug, Copy, Clone)]
 pub struct StructuresOptions {
     pub field_alignment: Option<FieldAlignment>,
     pub struct_lit_single_line: Option<bool>,
+    pub struct_lit_trailing_comma: Option<TrailingComma>,
+    pub enum_variants_trailing_comma: Option<TrailingComma>,
 }
 /// See parent struct [Comments].
 #[derive(Serialize, Deserialize, Debug, Copy, Clone)]
@@ -1,8 +1,11 @@
 use self::shape::Shape;
 use crate::comments::{write_comments, CommentsContext};
+use crate::module::apply_blank_lines_lower_bound;
 use crate::parse::parse_file;
 use crate::utils::map::comments::CommentMap;
-use crate::utils::map::{newline::handle_newlines, newline_style::apply_newline_style};
+use crate::utils::map::{
+    newline::handle_newlines, newline_style::apply_newline_style, return_style::apply_return_style,
+};
 pub use crate::{
     config::manifest::Config,
     error::{ConfigError, FormatterError},
@@ -89,12 +92,17 @@ impl Formatter {
         self.shape.apply_width_heuristics(
             self.config
                 .heuristics
-                .heuristics_pref
                 .to_width_heuristics(self.config.whitespace.max_width),
         );
-        let src = src.trim();
-
         let path = build_config.map(|build_config| build_config.canonical_root_module());
+        let src = apply_return_style(
+            &self.source_engine,
+            Arc::from(src.trim()),
+            path.clone(),
+            self.config.expressions.return_style,
+        )?;
+        let src = &*src;
+
         // Formatted code will be pushed here with raw newline style.
         // Which means newlines are not converted into system-specific versions until `apply_newline_style()`.
         // Use the length of src as a hint of the memory size needed for `raw_formatted_code`,
@@ -121,19 +129,26 @@ impl Formatter {
             Arc::from(src),
             &annotated_module.value,
             Arc::from(formatted_code.clone()),
-            path,
+            path.clone(),
             &mut formatted_code,
             self,
         )?;
-        // Replace newlines with specified `NewlineStyle`
-        apply_newline_style(
-            self.config.whitespace.newline_style,
+        apply_blank_lines_lower_bound(
+            &self.source_engine,
             &mut formatted_code,
-            &raw_formatted_code,
+            path,
+            self.config.items.blank_lines_lower_bound,
         )?;
         if !formatted_code.ends_with('\n') {
             writeln!(formatted_code)?;
         }
+        // Replace newlines with specified `NewlineStyle`, detecting the existing one from the
+        // unformatted code
+        apply_newline_style(
+            self.config.whitespace.newline_style,
+            &mut formatted_code,
+            src,
+        )?;
 
         Ok(formatted_code)
     }
@@ -1,11 +1,12 @@
 use crate::{
     comments::rewrite_with_comments,
-    config::user_def::FieldAlignment,
+    config::{expr::TrailingComma, user_def::FieldAlignment},
     formatter::{
         shape::{ExprKind, LineStyle},
         *,
     },
     utils::{
+        language::punctuated::format_punctuated,
         map::byte_span::{ByteSpan, LeafSpans},
         CurlyBrace,
     },
@@ -46,6 +47,7 @@ impl Format for ItemEnum {
                 }
 
                 let fields = self.fields.get();
+                let trailing_comma = formatter.config.structures.enum_variants_trailing_comma;
 
                 formatter.shape.code_line.update_expr_new_line(true);
 
@@ -102,20 +104,29 @@ impl Format for ItemEnum {
                                 type_field.colon_token.span().as_str(),
                             )?;
                             type_field.ty.format(formatted_code, formatter)?;
-                            writeln!(formatted_code, "{}", comma_token.span().as_str())?;
+                            let is_last = var_index + 1 == value_pairs.len()
+                                && fields.final_value_opt.is_none();
+                            if is_last && matches!(trailing_comma, TrailingComma::Never) {
+                                writeln!(formatted_code)?;
+                            } else {
+                                writeln!(formatted_code, "{}", comma_token.span().as_str())?;
+                            }
                         }
                         if let Some(final_value) = &fields.final_value_opt {
                             // TODO: Handle annotation
                             let final_value = &final_value.value;
-                            writeln!(
-                                formatted_code,
-                                "{}{}",
-                                final_value.span().as_str(),
-                                PunctKind::Comma.as_char(),
-                            )?;
+                            write!(formatted_code, "{}", final_value.span().as_str())?;
+                            match trailing_comma {
+                                TrailingComma::Never => writeln!(formatted_code)?,
+                                TrailingComma::Always | TrailingComma::Vertical => {
+                                    writeln!(formatted_code, "{}", PunctKind::Comma.as_char())?
+                                }
+                            }
                         }
                     }
-                    FieldAlignment::Off => fields.format(formatted_code, formatter)?,
+                    FieldAlignment::Off => {
+                        format_punctuated(fields, trailing_comma, formatted_code, formatter)?
+                    }
                 }
                 // Handle closing brace
                 Self::close_curly_brace(formatted_code, formatter)?;
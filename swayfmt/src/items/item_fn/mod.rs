@@ -1,10 +1,12 @@
 use crate::{
     comments::{has_comments_in_formatter, rewrite_with_comments, write_comments},
+    constants::NEW_LINE,
     formatter::{
         shape::{ExprKind, LineStyle},
         *,
     },
     utils::{
+        language::punctuated::format_punctuated,
         map::byte_span::{ByteSpan, LeafSpans},
         {CurlyBrace, Parenthesis},
     },
@@ -35,7 +37,11 @@ impl Format for ItemFn {
             |formatter| -> Result<(), FormatterError> {
                 self.fn_signature.format(formatted_code, formatter)?;
                 let body = self.body.get();
-                if !body.statements.is_empty() || body.final_expr_opt.is_some() {
+                if formatter.config.expressions.fn_single_line
+                    && write_single_line_body(self, formatted_code, formatter)?
+                {
+                    // The body was written on the same line as the signature.
+                } else if !body.statements.is_empty() || body.final_expr_opt.is_some() {
                     Self::open_curly_brace(formatted_code, formatter)?;
                     formatter.indent();
                     body.format(formatted_code, formatter)?;
@@ -75,6 +81,44 @@ impl Format for ItemFn {
     }
 }
 
+/// Writes the body of a function consisting of a single expression on the same line as its
+/// signature, e.g. `fn foo() -> u64 { 42 }`, if it fits within `max_width`.
+///
+/// Returns `false` without writing anything if the body cannot be put on a single line.
+fn write_single_line_body(
+    item_fn: &ItemFn,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<bool, FormatterError> {
+    let body = item_fn.body.get();
+    let Some(final_expr) = body.final_expr_opt.as_ref() else {
+        return Ok(false);
+    };
+    if !body.statements.is_empty()
+        || formatter.shape.code_line.has_where_clause
+        || has_comments_in_formatter(formatter, &item_fn.body.span().into())
+    {
+        return Ok(false);
+    }
+
+    let mut expr_code = FormattedCode::new();
+    final_expr.format(&mut expr_code, &mut formatter.clone())?;
+    let line_width = formatted_code
+        .rsplit(NEW_LINE)
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .count()
+        + expr_code.chars().count()
+        + " {  }".len();
+    if expr_code.contains(NEW_LINE) || line_width > formatter.config.whitespace.max_width {
+        return Ok(false);
+    }
+
+    write!(formatted_code, " {{ {expr_code} }}")?;
+    Ok(true)
+}
+
 impl CurlyBrace for ItemFn {
     fn open_curly_brace(
         line: &mut FormattedCode,
@@ -192,18 +236,19 @@ fn format_fn_args(
     formatted_code: &mut FormattedCode,
     formatter: &mut Formatter,
 ) -> Result<(), FormatterError> {
+    let trailing_comma = formatter.config.expressions.fn_args_trailing_comma;
     match fn_args {
         FnArgs::Static(args) => match formatter.shape.code_line.line_style {
             LineStyle::Multiline => {
                 formatter.shape.code_line.update_expr_new_line(true);
                 if !args.value_separator_pairs.is_empty() || args.final_value_opt.is_some() {
                     formatter.indent();
-                    args.format(formatted_code, formatter)?;
+                    format_punctuated(args, trailing_comma, formatted_code, formatter)?;
                     formatter.unindent();
                     write!(formatted_code, "{}", formatter.indent_to_str()?)?;
                 }
             }
-            _ => args.format(formatted_code, formatter)?,
+            _ => format_punctuated(args, trailing_comma, formatted_code, formatter)?,
         },
         FnArgs::NonStatic {
             self_token,
@@ -222,7 +267,7 @@ fn format_fn_args(
                         // `, `
                         write!(formatted_code, "{}", comma.ident().as_str())?;
                         // `Punctuated<FnArg, CommaToken>`
-                        args.format(formatted_code, formatter)?;
+                        format_punctuated(args, trailing_comma, formatted_code, formatter)?;
                     }
                 }
                 _ => {
@@ -232,7 +277,7 @@ fn format_fn_args(
                         // `, `
                         write!(formatted_code, "{} ", comma.ident().as_str())?;
                         // `Punctuated<FnArg, CommaToken>`
-                        args.format(formatted_code, formatter)?;
+                        format_punctuated(args, trailing_comma, formatted_code, formatter)?;
                     }
                 }
             }
@@ -298,6 +343,11 @@ impl Format for FnArg {
         )?;
         // `Ty`
         self.ty.format(formatted_code, formatter)?;
+        // ` = default`
+        if let Some((eq_token, default)) = &self.default_opt {
+            write!(formatted_code, " {} ", eq_token.span().as_str())?;
+            default.format(formatted_code, formatter)?;
+        }
 
         Ok(())
     }
@@ -369,6 +419,10 @@ impl LeafSpans for FnArg {
         collected_spans.append(&mut self.pattern.leaf_spans());
         collected_spans.push(ByteSpan::from(self.colon_token.span()));
         collected_spans.push(ByteSpan::from(self.ty.span()));
+        if let Some((eq_token, default)) = &self.default_opt {
+            collected_spans.push(ByteSpan::from(eq_token.span()));
+            collected_spans.append(&mut default.leaf_spans());
+        }
         collected_spans
     }
 }
@@ -89,6 +89,42 @@ fmt_test_item!(  fn_nested_if_lets
 }"
 );
 
+fmt_test_item!(  fn_let_else
+"fn has_let_else() -> u64 {
+    let Option::Some(x) = x else {
+        return 0;
+    };
+    x
+}",
+            intermediate_whitespace
+"fn has_let_else() -> u64 {
+    let   Option::Some(x)   =  x   else   {   return   0 ;   }  ;
+    x
+}"
+);
+
+fmt_test_item!(  fn_labeled_blocks_and_loops
+"fn has_labels() -> u64 {
+    'outer: while true {
+        if x {
+            continue 'outer;
+        }
+        break 'outer;
+    }
+    'block: {
+        break 'block 1;
+    }
+}",
+            intermediate_whitespace
+"fn has_labels() -> u64 {
+    'outer  :   while   true {
+        if x {   continue   'outer ;   }
+        break    'outer  ;
+    }
+    'block :{   break   'block   1 ;   }
+}"
+);
+
 fmt_test_item!(  fn_conditional_with_comment
 "fn conditional_with_comment() {
     if true {
@@ -132,3 +168,9 @@ intermediate_whitespace
     let val = 1;      // this is a normal comment
 }"
 );
+
+fmt_test_item!(  fn_args_default_values
+"fn transfer(amount: u64, fee: u64 = 10, memo: (u64, bool) = (0, false)) {}",
+            intermediate_whitespace
+"fn transfer(amount : u64,fee:u64=10  ,  memo: (u64,bool)   =   (0 , false)) {}"
+);
@@ -22,6 +22,11 @@ impl Format for TraitType {
         // Add name of the const
         self.name.format(formatted_code, formatter)?;
 
+        // Format `GenericParams`, if any
+        if let Some(generics) = &self.generics {
+            generics.format(formatted_code, formatter)?;
+        }
+
         // Check if ` = ` exists
         if let Some(eq_token) = &self.eq_token_opt {
             write!(formatted_code, " {} ", eq_token.ident().as_str())?;
@@ -50,6 +55,9 @@ impl LeafSpans for TraitType {
         let mut collected_spans = Vec::new();
         collected_spans.push(ByteSpan::from(self.type_token.span()));
         collected_spans.push(ByteSpan::from(self.name.span()));
+        if let Some(generics) = &self.generics {
+            collected_spans.push(ByteSpan::from(generics.parameters.span()));
+        }
         if let Some(eq_token) = &self.eq_token_opt {
             collected_spans.push(ByteSpan::from(eq_token.span()));
         }
@@ -11,6 +11,7 @@ mod error;
 mod formatter;
 mod items;
 mod module;
+pub mod on_type;
 pub mod parse;
 mod utils;
 
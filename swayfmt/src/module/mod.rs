@@ -1,11 +1,13 @@
 use crate::{
     comments::write_comments,
+    constants::UNIX_NEWLINE,
     formatter::*,
+    parse::parse_file,
     utils::map::byte_span::{self, ByteSpan, LeafSpans},
 };
-use std::fmt::Write;
+use std::{fmt::Write, path::PathBuf, sync::Arc};
 use sway_ast::{Item, ItemKind, Module, ModuleKind};
-use sway_types::Spanned;
+use sway_types::{SourceEngine, Spanned};
 
 pub(crate) mod item;
 pub(crate) mod submodule;
@@ -62,6 +64,59 @@ impl Format for Module {
     }
 }
 
+/// Puts at least `lower_bound` blank lines between the items of the module in `formatted_code`.
+///
+/// The blank lines are put right after the previous item, before any comments that precede the
+/// next one. The upper bound is already enforced when the newlines of the unformatted code are
+/// added to the `formatted_code`.
+pub(crate) fn apply_blank_lines_lower_bound(
+    source_engine: &SourceEngine,
+    formatted_code: &mut FormattedCode,
+    path: Option<Arc<PathBuf>>,
+    lower_bound: usize,
+) -> Result<(), FormatterError> {
+    if lower_bound == 0 {
+        return Ok(());
+    }
+    let module = parse_file(source_engine, Arc::from(formatted_code.as_str()), path)?.value;
+
+    let mut bounded_code = FormattedCode::with_capacity(formatted_code.len());
+    let mut last_written = 0;
+    for (prev_item, item) in module.items.iter().zip(module.items.iter().skip(1)) {
+        let between = prev_item.span().end()..item.span().start();
+        bounded_code.push_str(&formatted_code[last_written..between.start]);
+        bounded_code.push_str(&with_blank_lines_lower_bound(
+            &formatted_code[between.clone()],
+            lower_bound,
+        ));
+        last_written = between.end;
+    }
+    bounded_code.push_str(&formatted_code[last_written..]);
+
+    *formatted_code = bounded_code;
+    Ok(())
+}
+
+/// Adds blank lines to the beginning of the whitespace and comments between two items, so that
+/// there are at least `lower_bound` of them.
+fn with_blank_lines_lower_bound(between_items: &str, lower_bound: usize) -> String {
+    // The newline style is applied only after the whole module is formatted.
+    let mut lines = between_items.split(UNIX_NEWLINE).collect::<Vec<_>>();
+    // The first line is the rest of the line of the previous item, e.g. a trailing comment, and
+    // the last one is the indentation of the next item.
+    if lines.len() < 2 {
+        return between_items.to_string();
+    }
+    let blank_lines = lines[1..lines.len() - 1]
+        .iter()
+        .take_while(|line| line.trim().is_empty())
+        .count();
+    if blank_lines < lower_bound {
+        lines.splice(1..1, std::iter::repeat("").take(lower_bound - blank_lines));
+    }
+    lines.join(UNIX_NEWLINE)
+}
+
 impl Format for ModuleKind {
     fn format(
         &self,
@@ -0,0 +1,279 @@
+//! Formatting of the line being edited, as the user types.
+//!
+//! Unlike [crate::Formatter::format], this does not parse the file. It only scans the text
+//! before the edited line for unclosed delimiters, so it works on incomplete code and is cheap
+//! enough to run on every keystroke.
+use crate::config::manifest::Config;
+
+/// A replacement of the text between the `start` and `end` columns of a `line`.
+///
+/// The columns are byte offsets within the line. Edits only touch the indentation of a line and
+/// the text right after its leading closing brace, so they are the same as character offsets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineEdit {
+    pub line: usize,
+    pub start: usize,
+    pub end: usize,
+    pub new_text: String,
+}
+
+/// Returns the edits to apply after `typed` was typed on the `line` of the `text`.
+///
+/// - `}` reindents the line it closes to the indentation of the line of the matching `{`. If it
+///   closes the block of a match arm, a trailing comma is added, if configured.
+/// - `;` reindents the statement it ends, if the statement fits on a single line.
+/// - A newline continues a doc comment (`///` or `//!`) from the previous line.
+pub fn format_on_type(text: &str, line: usize, typed: char, config: &Config) -> Vec<LineEdit> {
+    let lines = text
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .collect::<Vec<_>>();
+    if line >= lines.len() {
+        return vec![];
+    }
+
+    match typed {
+        '}' => on_close_brace(&lines, line, config),
+        ';' => on_semicolon(&lines, line, config).into_iter().collect(),
+        '\n' => on_newline(&lines, line).into_iter().collect(),
+        _ => vec![],
+    }
+}
+
+fn on_close_brace(lines: &[&str], line: usize, config: &Config) -> Vec<LineEdit> {
+    let current = lines[line];
+    let indent_len = indentation(current).len();
+    if !current[indent_len..].starts_with('}') {
+        return vec![];
+    }
+    let Some(&(_, open_line)) = unclosed_delimiters(&lines[..line]).last() else {
+        return vec![];
+    };
+
+    let opening = lines[open_line];
+    let mut edits = reindent(lines, line, indentation(opening).to_string())
+        .into_iter()
+        .collect::<Vec<_>>();
+    let is_match_arm_block = opening.contains("=>") && opening.trim_end().ends_with('{');
+    if config.expressions.match_block_trailing_comma
+        && is_match_arm_block
+        && current[indent_len + 1..].trim().is_empty()
+    {
+        edits.push(LineEdit {
+            line,
+            start: indent_len + 1,
+            end: current.len(),
+            new_text: ",".to_string(),
+        });
+    }
+    edits
+}
+
+fn on_semicolon(lines: &[&str], line: usize, config: &Config) -> Option<LineEdit> {
+    // A statement that continues a previous line is indented relative to that line, which can't
+    // be determined without parsing it.
+    let starts_statement = lines[..line]
+        .iter()
+        .rev()
+        .map(|line| line.trim())
+        .find(|line| !line.is_empty())
+        .map_or(true, |previous| {
+            previous.ends_with([';', '{', '}']) || previous.starts_with("//")
+        });
+    if !starts_statement {
+        return None;
+    }
+
+    let indent = match unclosed_delimiters(&lines[..line]).last() {
+        Some(&(_, open_line)) => {
+            format!("{}{}", indentation(lines[open_line]), indent_unit(config))
+        }
+        None => String::new(),
+    };
+    reindent(lines, line, indent)
+}
+
+fn on_newline(lines: &[&str], line: usize) -> Option<LineEdit> {
+    let previous = lines[line.checked_sub(1)?];
+    let previous_indent = indentation(previous);
+    let prefix = ["///", "//!"]
+        .into_iter()
+        .find(|prefix| previous[previous_indent.len()..].starts_with(prefix))?;
+
+    let current = lines[line];
+    let indent_len = indentation(current).len();
+    if current[indent_len..].starts_with(prefix) {
+        return None;
+    }
+    Some(LineEdit {
+        line,
+        start: 0,
+        end: indent_len,
+        new_text: format!("{previous_indent}{prefix} "),
+    })
+}
+
+/// Returns the edit replacing the indentation of the `line` with `indent`, if they differ.
+fn reindent(lines: &[&str], line: usize, indent: String) -> Option<LineEdit> {
+    let current = indentation(lines[line]);
+    (current != indent).then(|| LineEdit {
+        line,
+        start: 0,
+        end: current.len(),
+        new_text: indent,
+    })
+}
+
+fn indentation(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
+
+fn indent_unit(config: &Config) -> String {
+    if config.whitespace.hard_tabs {
+        "\t".to_string()
+    } else {
+        " ".repeat(config.whitespace.tab_spaces)
+    }
+}
+
+/// Returns the delimiters that are opened but not closed in the `lines`, along with the index of
+/// the line they are on, from the outermost to the innermost.
+///
+/// Delimiters within comments and string literals are ignored.
+fn unclosed_delimiters(lines: &[&str]) -> Vec<(char, usize)> {
+    let mut unclosed = vec![];
+    let mut block_comment_depth = 0;
+    let mut in_string = false;
+    for (line_index, line) in lines.iter().enumerate() {
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            if block_comment_depth > 0 {
+                match (c, chars.peek()) {
+                    ('*', Some('/')) => {
+                        chars.next();
+                        block_comment_depth -= 1;
+                    }
+                    ('/', Some('*')) => {
+                        chars.next();
+                        block_comment_depth += 1;
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+            if in_string {
+                match c {
+                    '\\' => {
+                        chars.next();
+                    }
+                    '"' => in_string = false,
+                    _ => {}
+                }
+                continue;
+            }
+            match (c, chars.peek()) {
+                ('/', Some('/')) => break,
+                ('/', Some('*')) => {
+                    chars.next();
+                    block_comment_depth += 1;
+                }
+                ('"', _) => in_string = true,
+                ('{' | '(' | '[', _) => unclosed.push((c, line_index)),
+                ('}' | ')' | ']', _) => {
+                    unclosed.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+    unclosed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edits(text: &str, line: usize, typed: char) -> Vec<LineEdit> {
+        format_on_type(text, line, typed, &Config::default())
+    }
+
+    #[test]
+    fn close_brace_aligns_with_opening_line() {
+        let text = "fn main() {\n    if true {\n        foo();\n        }\n}";
+        assert_eq!(
+            edits(text, 3, '}'),
+            vec![LineEdit {
+                line: 3,
+                start: 0,
+                end: 8,
+                new_text: "    ".to_string(),
+            }]
+        );
+        assert!(edits(text, 4, '}').is_empty());
+    }
+
+    #[test]
+    fn close_brace_ignores_delimiters_in_strings_and_comments() {
+        let text = "fn main() {\n    let s = \"{\"; // {\n    /* { */\n  }";
+        assert_eq!(
+            edits(text, 3, '}'),
+            vec![LineEdit {
+                line: 3,
+                start: 0,
+                end: 2,
+                new_text: String::new(),
+            }]
+        );
+    }
+
+    #[test]
+    fn close_brace_of_match_arm_adds_trailing_comma() {
+        let text = "match x {\n    1 => {\n        foo();\n    }\n}";
+        assert!(edits(text, 3, '}').is_empty());
+
+        let mut config = Config::default();
+        config.expressions.match_block_trailing_comma = true;
+        assert_eq!(
+            format_on_type(text, 3, '}', &config),
+            vec![LineEdit {
+                line: 3,
+                start: 5,
+                end: 5,
+                new_text: ",".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn semicolon_indents_statement() {
+        let text = "fn main() {\n    let a = 1;\nlet b = 2;\n}";
+        assert_eq!(
+            edits(text, 2, ';'),
+            vec![LineEdit {
+                line: 2,
+                start: 0,
+                end: 0,
+                new_text: "    ".to_string(),
+            }]
+        );
+
+        // Continuation lines are left as they are.
+        let text = "fn main() {\n    let a = foo\n            .bar();\n}";
+        assert!(edits(text, 2, ';').is_empty());
+    }
+
+    #[test]
+    fn newline_continues_doc_comment() {
+        let text = "    /// Docs.\n    ";
+        assert_eq!(
+            edits(text, 1, '\n'),
+            vec![LineEdit {
+                line: 1,
+                start: 0,
+                end: 4,
+                new_text: "    /// ".to_string(),
+            }]
+        );
+        assert!(edits("// Comment.\n", 1, '\n').is_empty());
+    }
+}
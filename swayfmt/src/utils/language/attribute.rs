@@ -11,10 +11,21 @@ use std::fmt::Write;
 use sway_ast::attribute::{Annotated, Attribute, AttributeArg, AttributeDecl, AttributeHashKind};
 use sway_types::{
     ast::{Delimiter, PunctKind},
-    constants::DOC_COMMENT_ATTRIBUTE_NAME,
+    constants::{DOC_COMMENT_ATTRIBUTE_NAME, FMT_ATTRIBUTE_NAME, FMT_SKIP_NAME},
     Spanned,
 };
 
+/// Whether the attributes contain `#[fmt(skip)]`, in which case the annotated value is left
+/// exactly as written.
+pub(crate) fn has_skip_attribute(attribute_list: &[AttributeDecl]) -> bool {
+    attribute_list
+        .iter()
+        .flat_map(|attr_decl| attr_decl.attribute.get())
+        .filter(|attr| attr.name.as_str() == FMT_ATTRIBUTE_NAME)
+        .flat_map(|attr| attr.args.iter().flat_map(|args| args.get()))
+        .any(|arg| arg.name.as_str() == FMT_SKIP_NAME)
+}
+
 impl<T: Format + Spanned + std::fmt::Debug> Format for Annotated<T> {
     fn format(
         &self,
@@ -46,7 +57,19 @@ impl<T: Format + Spanned + std::fmt::Debug> Format for Annotated<T> {
         }
         // format `ItemKind`
         formatter.write_indent_into_buffer(formatted_code)?;
-        self.value.format(formatted_code, formatter)?;
+        if has_skip_attribute(&self.attribute_list) {
+            let span = self.value.span();
+            write!(formatted_code, "{}", span.as_str())?;
+            // Comments within the value are already part of its source, so they must not be
+            // written again.
+            let range = span.start()..span.end();
+            formatter
+                .comments_context
+                .map
+                .retain(|bs, _| !bs.contained_within(&range));
+        } else {
+            self.value.format(formatted_code, formatter)?;
+        }
 
         Ok(())
     }
@@ -11,7 +11,7 @@ use sway_ast::{
     expr::asm::{AsmBlock, AsmBlockContents, AsmFinalExpr, AsmRegisterDeclaration},
     Instruction,
 };
-use sway_types::{ast::Delimiter, Spanned};
+use sway_types::{ast::Delimiter, Ident, Spanned};
 
 impl Format for AsmBlock {
     fn format(
@@ -19,6 +19,18 @@ impl Format for AsmBlock {
         formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
+        if !formatter.config.expressions.format_asm {
+            // Hand-tuned asm blocks are kept as written, along with their comments.
+            let span = self.span();
+            write!(formatted_code, "{}", span.as_str())?;
+            let range = span.start()..span.end();
+            formatter
+                .comments_context
+                .map
+                .retain(|bs, _| !bs.contained_within(&range));
+            return Ok(());
+        }
+
         // Required for comment formatting
         let start_len = formatted_code.len();
 
@@ -206,9 +218,10 @@ impl Format for AsmBlockContents {
         formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
+        let columns = InstructionColumns::new(self.instructions.iter().map(|(i, _)| i));
         for (instruction, semicolon_token) in self.instructions.iter() {
             write!(formatted_code, "{}", formatter.indent_to_str()?)?;
-            instruction.format(formatted_code, formatter)?;
+            columns.write(instruction, formatted_code)?;
             writeln!(formatted_code, "{}", semicolon_token.span().as_str())?
         }
         if let Some(final_expr) = &self.final_expr_opt {
@@ -225,6 +238,73 @@ impl Format for AsmBlockContents {
     }
 }
 
+/// The widths of the opcode and operand columns of the instructions in an `asm` block.
+///
+/// Instructions are aligned so that their opcodes and operands at the same position
+/// start in the same column, e.g.:
+///
+/// ```ignore
+/// addi r3 zero i32;
+/// meq  r4 r1   r2 r3;
+/// ```
+struct InstructionColumns {
+    op_code: usize,
+    operands: Vec<usize>,
+}
+
+impl InstructionColumns {
+    fn new<'a>(instructions: impl Iterator<Item = &'a Instruction>) -> Self {
+        let mut columns = Self {
+            op_code: 0,
+            operands: vec![],
+        };
+        for instruction in instructions {
+            columns.op_code = columns
+                .op_code
+                .max(instruction.op_code_ident().as_str().len());
+            for (i, operand) in instruction_operands(instruction).iter().enumerate() {
+                match columns.operands.get_mut(i) {
+                    Some(width) => *width = (*width).max(operand.as_str().len()),
+                    None => columns.operands.push(operand.as_str().len()),
+                }
+            }
+        }
+        columns
+    }
+
+    /// Writes the `instruction` padded to the column widths, without trailing whitespace.
+    fn write(
+        &self,
+        instruction: &Instruction,
+        formatted_code: &mut FormattedCode,
+    ) -> Result<(), FormatterError> {
+        let op_code = instruction.op_code_ident();
+        let operands = instruction_operands(instruction);
+        let Some((last, operands)) = operands.split_last() else {
+            write!(formatted_code, "{}", op_code.as_str())?;
+            return Ok(());
+        };
+        write!(
+            formatted_code,
+            "{:width$}",
+            op_code.as_str(),
+            width = self.op_code
+        )?;
+        for (operand, &width) in operands.iter().zip(&self.operands) {
+            write!(formatted_code, " {:width$}", operand.as_str())?;
+        }
+        write!(formatted_code, " {}", last.as_str())?;
+        Ok(())
+    }
+}
+
+/// Returns the register arguments of the `instruction`, followed by its immediate values.
+fn instruction_operands(instruction: &Instruction) -> Vec<Ident> {
+    let mut operands = instruction.register_arg_idents();
+    operands.append(&mut instruction.immediate_idents());
+    operands
+}
+
 impl Format for AsmFinalExpr {
     fn format(
         &self,
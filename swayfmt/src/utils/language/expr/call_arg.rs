@@ -0,0 +1,37 @@
+use crate::{
+    formatter::*,
+    utils::map::byte_span::{ByteSpan, LeafSpans},
+};
+use std::fmt::Write;
+use sway_ast::CallArg;
+use sway_types::Spanned;
+
+impl Format for CallArg {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        if let Some((name, colon_token)) = &self.name_opt {
+            write!(
+                formatted_code,
+                "{}{} ",
+                name.span().as_str(),
+                colon_token.span().as_str()
+            )?;
+        }
+        self.value.format(formatted_code, formatter)
+    }
+}
+
+impl LeafSpans for CallArg {
+    fn leaf_spans(&self) -> Vec<ByteSpan> {
+        let mut collected_spans = vec![];
+        if let Some((name, colon_token)) = &self.name_opt {
+            collected_spans.push(ByteSpan::from(name.span()));
+            collected_spans.push(ByteSpan::from(colon_token.span()));
+        }
+        collected_spans.append(&mut self.value.leaf_spans());
+        collected_spans
+    }
+}
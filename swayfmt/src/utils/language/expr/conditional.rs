@@ -1,5 +1,6 @@
 use crate::{
     comments::write_comments,
+    config::expr::MatchArmLeadingPipe,
     formatter::{
         shape::{ExprKind, LineStyle},
         *,
@@ -267,11 +268,20 @@ impl Format for IfCondition {
                 lhs,
                 eq_token,
                 rhs,
+                chain,
             } => {
                 write!(formatted_code, "{} ", let_token.span().as_str())?;
                 lhs.format(formatted_code, formatter)?;
                 write!(formatted_code, " {} ", eq_token.span().as_str())?;
                 rhs.format(formatted_code, formatter)?;
+                for (double_ampersand_token, condition) in chain {
+                    write!(
+                        formatted_code,
+                        " {} ",
+                        double_ampersand_token.span().as_str()
+                    )?;
+                    condition.format(formatted_code, formatter)?;
+                }
             }
         }
 
@@ -285,6 +295,14 @@ impl Format for MatchBranch {
         formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
+        let leading_pipe = match formatter.config.expressions.match_arm_leading_pipe {
+            MatchArmLeadingPipe::Always => true,
+            MatchArmLeadingPipe::Never => false,
+            MatchArmLeadingPipe::Preserve => self.pipe_token_opt.is_some(),
+        };
+        if leading_pipe {
+            write!(formatted_code, "| ")?;
+        }
         self.pattern.format(formatted_code, formatter)?;
         write!(
             formatted_code,
@@ -406,11 +424,16 @@ impl LeafSpans for IfCondition {
                 lhs,
                 eq_token,
                 rhs,
+                chain,
             } => {
                 let mut collected_spans = vec![ByteSpan::from(let_token.span())];
                 collected_spans.append(&mut lhs.leaf_spans());
                 collected_spans.push(ByteSpan::from(eq_token.span()));
                 collected_spans.append(&mut rhs.leaf_spans());
+                for (double_ampersand_token, condition) in chain {
+                    collected_spans.push(ByteSpan::from(double_ampersand_token.span()));
+                    collected_spans.append(&mut condition.leaf_spans());
+                }
                 collected_spans
             }
         }
@@ -419,6 +442,7 @@ impl LeafSpans for IfCondition {
 
 impl LeafSpans for MatchBranch {
     fn leaf_spans(&self) -> Vec<ByteSpan> {
+        // The leading pipe is left out, as formatting can add or remove it.
         let mut collected_spans = Vec::new();
         collected_spans.append(&mut self.pattern.leaf_spans());
         collected_spans.push(ByteSpan::from(self.fat_right_arrow_token.span()));
@@ -436,8 +460,12 @@ impl LeafSpans for MatchBranchKind {
                 comma_token_opt,
             } => {
                 collected_spans.append(&mut block.leaf_spans());
-                if let Some(comma_token) = comma_token_opt {
-                    collected_spans.push(ByteSpan::from(comma_token.span()));
+                // The optional comma is merged into the closing brace, as formatting can add or
+                // remove it after the last arm.
+                if let (Some(comma_token), Some(closing_brace)) =
+                    (comma_token_opt, collected_spans.last_mut())
+                {
+                    closing_brace.end = comma_token.span().end();
                 }
             }
             MatchBranchKind::Expr { expr, comma_token } => {
@@ -1,9 +1,11 @@
 use crate::{
+    config::expr::TrailingComma,
     formatter::{
         shape::{ExprKind, LineStyle},
         *,
     },
     utils::{
+        language::punctuated::format_punctuated,
         map::byte_span::{ByteSpan, LeafSpans},
         {CurlyBrace, Parenthesis, SquareBracket},
     },
@@ -13,14 +15,18 @@ use sway_ast::{
     brackets::Parens,
     keywords::{CommaToken, DotToken},
     punctuated::Punctuated,
-    Braces, CodeBlockContents, Expr, ExprStructField, IfExpr, MatchBranch, PathExpr,
-    PathExprSegment,
+    Braces, CallArg, CodeBlockContents, Expr, ExprStructField, IfExpr, MatchBranch,
+    MatchBranchKind, PathExpr, PathExprSegment,
+};
+use sway_types::{
+    ast::{Delimiter, PunctKind},
+    Spanned,
 };
-use sway_types::{ast::Delimiter, Spanned};
 
 pub(crate) mod abi_cast;
 pub(crate) mod asm_block;
 pub(crate) mod assignable;
+pub(crate) mod call_arg;
 pub(crate) mod code_block;
 pub(crate) mod collections;
 pub(crate) mod conditional;
@@ -239,9 +245,12 @@ impl Format for Expr {
                 if !branches.get().is_empty() {
                     MatchBranch::open_curly_brace(formatted_code, formatter)?;
                     let branches = branches.get();
-                    for match_branch in branches.iter() {
+                    for (index, match_branch) in branches.iter().enumerate() {
                         write!(formatted_code, "{}", formatter.indent_to_str()?)?;
                         match_branch.format(formatted_code, formatter)?;
+                        if index + 1 == branches.len() {
+                            format_last_match_branch_comma(match_branch, formatted_code, formatter);
+                        }
                         writeln!(formatted_code)?;
                     }
                     MatchBranch::close_curly_brace(formatted_code, formatter)?;
@@ -421,6 +430,13 @@ impl Format for Expr {
                     field_span.as_str(),
                 )?;
             }
+            Self::Try {
+                expr,
+                question_mark_token,
+            } => {
+                expr.format(formatted_code, formatter)?;
+                write!(formatted_code, "{}", question_mark_token.span().as_str())?;
+            }
             Self::Ref {
                 ampersand_token,
                 mut_token,
@@ -441,6 +457,11 @@ impl Format for Expr {
                 write!(formatted_code, "{}", bang_token.span().as_str())?;
                 expr.format(formatted_code, formatter)?;
             }
+            Self::Cast { expr, as_token, ty } => {
+                expr.format(formatted_code, formatter)?;
+                write!(formatted_code, " {} ", as_token.span().as_str())?;
+                ty.format(formatted_code, formatter)?;
+            }
             Self::Pow {
                 lhs,
                 double_star_token,
@@ -660,6 +681,19 @@ impl Format for Expr {
                     formatter,
                 )?;
             }
+            Self::Range {
+                start,
+                double_dot_token,
+                end,
+            } => {
+                if let Some(start) = start {
+                    start.format(formatted_code, formatter)?;
+                }
+                write!(formatted_code, "{}", double_dot_token.span().as_str())?;
+                if let Some(end) = end {
+                    end.format(formatted_code, formatter)?;
+                }
+            }
             Self::Reassignment {
                 assignable,
                 reassignment_op,
@@ -669,11 +703,41 @@ impl Format for Expr {
                 reassignment_op.format(formatted_code, formatter)?;
                 expr.format(formatted_code, formatter)?;
             }
-            Self::Break { break_token } => {
+            Self::Labeled {
+                label,
+                colon_token,
+                expr,
+            } => {
+                write!(
+                    formatted_code,
+                    "{}{} ",
+                    label.span().as_str(),
+                    colon_token.span().as_str()
+                )?;
+                expr.format(formatted_code, formatter)?;
+            }
+            Self::Break {
+                break_token,
+                label_opt,
+                value_opt,
+            } => {
                 write!(formatted_code, "{}", break_token.span().as_str())?;
+                if let Some(label) = label_opt {
+                    write!(formatted_code, " {}", label.span().as_str())?;
+                }
+                if let Some(value) = value_opt {
+                    write!(formatted_code, " ")?;
+                    value.format(formatted_code, formatter)?;
+                }
             }
-            Self::Continue { continue_token } => {
+            Self::Continue {
+                continue_token,
+                label_opt,
+            } => {
                 write!(formatted_code, "{}", continue_token.span().as_str())?;
+                if let Some(label) = label_opt {
+                    write!(formatted_code, " {}", label.span().as_str())?;
+                }
             }
         }
 
@@ -730,6 +794,32 @@ pub(super) fn debug_expr(
     println!("{:?}\n", formatter.shape.width_heuristics);
 }
 
+/// Adds or removes the optional comma after the last match arm, if it is a block based arm,
+/// according to `match_arms_trailing_comma`.
+fn format_last_match_branch_comma(
+    match_branch: &MatchBranch,
+    formatted_code: &mut FormattedCode,
+    formatter: &Formatter,
+) {
+    let MatchBranchKind::Block {
+        comma_token_opt, ..
+    } = &match_branch.kind
+    else {
+        return;
+    };
+    match (
+        formatter.config.expressions.match_arms_trailing_comma,
+        comma_token_opt,
+    ) {
+        (TrailingComma::Always, None) => formatted_code.push(PunctKind::Comma.as_char()),
+        // The comma is the last thing written when formatting the arm.
+        (TrailingComma::Never, Some(_)) => {
+            formatted_code.pop();
+        }
+        _ => {}
+    }
+}
+
 fn format_expr_struct(
     path: &PathExpr,
     fields: &Braces<Punctuated<ExprStructField, CommaToken>>,
@@ -739,10 +829,11 @@ fn format_expr_struct(
     path.format(formatted_code, formatter)?;
     ExprStructField::open_curly_brace(formatted_code, formatter)?;
     let fields = &fields.get();
+    let trailing_comma = formatter.config.structures.struct_lit_trailing_comma;
     match formatter.shape.code_line.line_style {
-        LineStyle::Inline => fields.format(formatted_code, formatter)?,
+        LineStyle::Inline => format_punctuated(fields, trailing_comma, formatted_code, formatter)?,
         // TODO: add field alignment
-        _ => fields.format(formatted_code, formatter)?,
+        _ => format_punctuated(fields, trailing_comma, formatted_code, formatter)?,
     }
     ExprStructField::close_curly_brace(formatted_code, formatter)?;
 
@@ -771,33 +862,34 @@ pub fn should_write_multiline(code: &str, formatter: &Formatter) -> bool {
     }
 }
 
-/// Whether this expression can be inlined if it is the sole argument of a
+/// Whether this argument can be inlined if it is the sole argument of a
 /// function/method call
 #[inline]
-fn same_line_if_only_argument(expr: &Expr) -> bool {
-    matches!(
-        expr,
-        Expr::Struct { path: _, fields: _ }
-            | Expr::Tuple(_)
-            | Expr::Array(_)
-            | Expr::Parens(_)
-            | Expr::Not {
-                bang_token: _,
-                expr: _
-            }
-            | Expr::Path(_)
-            | Expr::FuncApp { func: _, args: _ }
-            | Expr::Match {
-                match_token: _,
-                value: _,
-                branches: _
-            }
-    )
+fn same_line_if_only_argument(arg: &CallArg) -> bool {
+    arg.name_opt.is_none()
+        && matches!(
+            &arg.value,
+            Expr::Struct { path: _, fields: _ }
+                | Expr::Tuple(_)
+                | Expr::Array(_)
+                | Expr::Parens(_)
+                | Expr::Not {
+                    bang_token: _,
+                    expr: _
+                }
+                | Expr::Path(_)
+                | Expr::FuncApp { func: _, args: _ }
+                | Expr::Match {
+                    match_token: _,
+                    value: _,
+                    branches: _
+                }
+        )
 }
 
 #[inline]
 pub(crate) fn is_single_argument_and_can_be_inline<P>(
-    args: &Punctuated<Expr, P>,
+    args: &Punctuated<CallArg, P>,
     formatter: &mut Formatter,
 ) -> bool
 where
@@ -833,7 +925,7 @@ where
 /// methods and functions and how to organize their arguments.
 #[inline]
 pub fn write_function_call_arguments<P>(
-    args: &Punctuated<Expr, P>,
+    args: &Punctuated<CallArg, P>,
     formatter: &mut Formatter,
 ) -> Result<(bool, String), FormatterError>
 where
@@ -841,6 +933,7 @@ where
 {
     let has_single_argument_and_can_be_inlined =
         is_single_argument_and_can_be_inline(args, formatter);
+    let trailing_comma = formatter.config.expressions.fn_args_trailing_comma;
 
     formatter.with_shape(
         formatter
@@ -848,7 +941,7 @@ where
             .with_code_line_from(LineStyle::Normal, ExprKind::Function),
         |formatter| -> Result<(bool, String), FormatterError> {
             let mut buf = FormattedCode::new();
-            args.format(&mut buf, formatter)?;
+            format_punctuated(args, trailing_comma, &mut buf, formatter)?;
 
             Ok(if has_single_argument_and_can_be_inlined {
                 (true, buf.trim().to_owned())
@@ -873,7 +966,7 @@ where
                         // should be rewritten to a multi-line
                         let mut formatted_code = FormattedCode::new();
                         let mut buf = FormattedCode::new();
-                        args.format(&mut buf, formatter)?;
+                        format_punctuated(args, trailing_comma, &mut buf, formatter)?;
                         formatter.unindent();
                         writeln!(formatted_code, "{}", buf.trim_end())?;
                         formatter.write_indent_into_buffer(&mut formatted_code)?;
@@ -891,7 +984,7 @@ fn format_method_call(
     dot_token: &DotToken,
     path_seg: &PathExprSegment,
     contract_args_opt: &Option<Braces<Punctuated<ExprStructField, CommaToken>>>,
-    args: &Parens<Punctuated<Expr, CommaToken>>,
+    args: &Parens<Punctuated<CallArg, CommaToken>>,
     formatted_code: &mut FormattedCode,
     formatter: &mut Formatter,
 ) -> Result<(usize, bool), FormatterError> {
@@ -1101,6 +1194,14 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.push(ByteSpan::from(field_span.clone()));
             collected_spans
         }
+        Expr::Try {
+            expr,
+            question_mark_token,
+        } => {
+            let mut collected_spans = expr.leaf_spans();
+            collected_spans.push(ByteSpan::from(question_mark_token.span()));
+            collected_spans
+        }
         Expr::Ref {
             ampersand_token,
             mut_token,
@@ -1123,6 +1224,12 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.append(&mut expr.leaf_spans());
             collected_spans
         }
+        Expr::Cast { expr, as_token, ty } => {
+            let mut collected_spans = expr.leaf_spans();
+            collected_spans.push(ByteSpan::from(as_token.span()));
+            collected_spans.append(&mut ty.leaf_spans());
+            collected_spans
+        }
         Expr::Pow {
             lhs,
             double_star_token,
@@ -1313,6 +1420,21 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.append(&mut rhs.leaf_spans());
             collected_spans
         }
+        Expr::Range {
+            start,
+            double_dot_token,
+            end,
+        } => {
+            let mut collected_spans = Vec::new();
+            if let Some(start) = start {
+                collected_spans.append(&mut start.leaf_spans());
+            }
+            collected_spans.push(ByteSpan::from(double_dot_token.span()));
+            if let Some(end) = end {
+                collected_spans.append(&mut end.leaf_spans());
+            }
+            collected_spans
+        }
         Expr::Reassignment {
             assignable,
             reassignment_op,
@@ -1323,11 +1445,41 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.append(&mut expr.leaf_spans());
             collected_spans
         }
-        Expr::Break { break_token } => {
-            vec![ByteSpan::from(break_token.span())]
+        Expr::Labeled {
+            label,
+            colon_token,
+            expr,
+        } => {
+            let mut collected_spans = vec![
+                ByteSpan::from(label.span()),
+                ByteSpan::from(colon_token.span()),
+            ];
+            collected_spans.append(&mut expr.leaf_spans());
+            collected_spans
         }
-        Expr::Continue { continue_token } => {
-            vec![ByteSpan::from(continue_token.span())]
+        Expr::Break {
+            break_token,
+            label_opt,
+            value_opt,
+        } => {
+            let mut collected_spans = vec![ByteSpan::from(break_token.span())];
+            if let Some(label) = label_opt {
+                collected_spans.push(ByteSpan::from(label.span()));
+            }
+            if let Some(value) = value_opt {
+                collected_spans.append(&mut value.leaf_spans());
+            }
+            collected_spans
+        }
+        Expr::Continue {
+            continue_token,
+            label_opt,
+        } => {
+            let mut collected_spans = vec![ByteSpan::from(continue_token.span())];
+            if let Some(label) = label_opt {
+                collected_spans.push(ByteSpan::from(label.span()));
+            }
+            collected_spans
         }
     }
 }
@@ -22,6 +22,27 @@ fmt_test_expr!(  abi_cast                "abi(MyAbi, 0x1111111111111111111111111
                                   )  "
 );
 
+fmt_test_expr!(  int_cast                "(a * b) as u8 + c as u8",
+            intermediate_whitespace " ( a  *  b )   as   u8  +  c   as
+              u8 "
+);
+
+fmt_test_expr!(  range_index             "&a[1..n + 1]",
+            intermediate_whitespace " & a [ 1 .. n  +  1 ] "
+);
+
+fmt_test_expr!(  range_index_open        "&mut a[..]",
+            intermediate_whitespace " & mut  a [ .. ] "
+);
+
+fmt_test_expr!(  named_arguments         "transfer(amount: 5, to: recipient).log(message: msg)",
+            intermediate_whitespace " transfer ( amount :  5 , to :recipient ) . log ( message : msg ) "
+);
+
+fmt_test_expr!(  try_propagation         "foo()?.bar[0]?.baz()?",
+            intermediate_whitespace " foo ( ) ? . bar [ 0 ] ?  . baz ( )  ? "
+);
+
 fmt_test_expr!(  basic_func_app          "foo()",
             intermediate_whitespace " foo (
 
@@ -119,6 +140,10 @@ fmt_test_expr!(  small_if_let "if let Result::Ok(x) = x { 100 } else { 1 }",
             intermediate_whitespace "if    let    Result   ::   Ok( x ) =    x {     100 }   else  {    1 }"
 );
 
+fmt_test_expr!(  small_if_let_chain "if let Option::Some(x) = x && x > 1 { x } else { 1 }",
+            intermediate_whitespace "if    let    Option   ::   Some( x ) =    x   &&   x>1 {     x }   else  {    1 }"
+);
+
 fmt_test_expr!(  match_nested_conditional
 "match foo {
     Foo::foo => {
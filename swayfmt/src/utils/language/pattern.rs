@@ -125,6 +125,12 @@ impl Format for Pattern {
                     },
                 )?;
             }
+            Self::Slice(elems) => {
+                write!(formatted_code, "{}", Delimiter::Bracket.as_open_char())?;
+                elems.get().format(formatted_code, formatter)?;
+                write!(formatted_code, "{}", Delimiter::Bracket.as_close_char())?;
+            }
+            Self::Rest { token } => formatted_code.push_str(token.span().as_str()),
             Self::Error(..) => {
                 return Err(FormatterError::SyntaxError);
             }
@@ -307,6 +313,12 @@ impl LeafSpans for Pattern {
             Pattern::Tuple(tuple) => {
                 collected_spans.append(&mut tuple.leaf_spans());
             }
+            Pattern::Slice(slice) => {
+                collected_spans.append(&mut slice.leaf_spans());
+            }
+            Pattern::Rest { token } => {
+                collected_spans.push(ByteSpan::from(token.span()));
+            }
             Pattern::Error(spans, _) => {
                 let mut leaf_spans = spans.iter().map(|s| ByteSpan::from(s.clone())).collect();
                 collected_spans.append(&mut leaf_spans)
@@ -1,4 +1,5 @@
 use crate::{
+    config::expr::TrailingComma,
     constants::RAW_MODIFIER,
     formatter::{shape::LineStyle, *},
     utils::{
@@ -27,90 +28,118 @@ where
         formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
-        if !self.value_separator_pairs.is_empty() || self.final_value_opt.is_some() {
-            match formatter.shape.code_line.line_style {
-                LineStyle::Normal => {
-                    write!(
-                        formatted_code,
-                        "{}",
-                        format_generic_pair(
-                            &self.value_separator_pairs,
-                            &self.final_value_opt,
-                            formatter
-                        )?
-                    )?;
-                }
-                LineStyle::Inline => {
-                    write!(
-                        formatted_code,
-                        " {} ",
-                        format_generic_pair(
-                            &self.value_separator_pairs,
-                            &self.final_value_opt,
-                            formatter
-                        )?
-                    )?;
+        format_punctuated(self, TrailingComma::Vertical, formatted_code, formatter)
+    }
+}
+
+/// Formats `punctuated`, putting a trailing comma after the last value according to
+/// `trailing_comma`.
+pub(crate) fn format_punctuated<T, P>(
+    punctuated: &Punctuated<T, P>,
+    trailing_comma: TrailingComma,
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+) -> Result<(), FormatterError>
+where
+    T: Format,
+    P: Format,
+{
+    if !punctuated.value_separator_pairs.is_empty() || punctuated.final_value_opt.is_some() {
+        let single_line_trailing_comma = match trailing_comma {
+            TrailingComma::Always => PunctKind::Comma.as_char().to_string(),
+            TrailingComma::Never | TrailingComma::Vertical => String::new(),
+        };
+        match formatter.shape.code_line.line_style {
+            LineStyle::Normal => {
+                write!(
+                    formatted_code,
+                    "{}{}",
+                    format_generic_pair(
+                        &punctuated.value_separator_pairs,
+                        &punctuated.final_value_opt,
+                        formatter
+                    )?,
+                    single_line_trailing_comma
+                )?;
+            }
+            LineStyle::Inline => {
+                write!(
+                    formatted_code,
+                    " {}{} ",
+                    format_generic_pair(
+                        &punctuated.value_separator_pairs,
+                        &punctuated.final_value_opt,
+                        formatter
+                    )?,
+                    single_line_trailing_comma
+                )?;
+            }
+            LineStyle::Multiline => {
+                if !formatted_code.ends_with('\n') {
+                    writeln!(formatted_code)?;
                 }
-                LineStyle::Multiline => {
-                    if !formatted_code.ends_with('\n') {
-                        writeln!(formatted_code)?;
-                    }
-                    if !self.value_separator_pairs.is_empty() || self.final_value_opt.is_some() {
-                        formatter.write_indent_into_buffer(formatted_code)?;
-                    }
+                formatter.write_indent_into_buffer(formatted_code)?;
 
-                    let mut is_value_too_long = false;
-                    let value_separator_pairs = formatter.with_shape(
-                        formatter.shape.with_default_code_line(),
-                        |formatter| -> Result<Vec<(String, String)>, FormatterError> {
-                            self.value_separator_pairs
-                                .iter()
-                                .map(|(type_field, comma_token)| {
-                                    let mut field = FormattedCode::new();
-                                    let mut comma = FormattedCode::new();
-                                    type_field.format(&mut field, formatter)?;
-                                    comma_token.format(&mut comma, formatter)?;
-                                    if field.len()
-                                        > formatter.shape.width_heuristics.short_array_element_width
-                                    {
-                                        is_value_too_long = true;
-                                    }
-                                    Ok((
-                                        field.trim_start().to_owned(),
-                                        comma.trim_start().to_owned(),
-                                    ))
-                                })
-                                .collect()
-                        },
-                    )?;
+                let mut is_value_too_long = false;
+                let value_separator_pairs = formatter.with_shape(
+                    formatter.shape.with_default_code_line(),
+                    |formatter| -> Result<Vec<(String, String)>, FormatterError> {
+                        punctuated
+                            .value_separator_pairs
+                            .iter()
+                            .map(|(type_field, comma_token)| {
+                                let mut field = FormattedCode::new();
+                                let mut comma = FormattedCode::new();
+                                type_field.format(&mut field, formatter)?;
+                                comma_token.format(&mut comma, formatter)?;
+                                if field.len()
+                                    > formatter.shape.width_heuristics.short_array_element_width
+                                {
+                                    is_value_too_long = true;
+                                }
+                                Ok((field.trim_start().to_owned(), comma.trim_start().to_owned()))
+                            })
+                            .collect()
+                    },
+                )?;
 
-                    let mut iter = value_separator_pairs.iter().peekable();
+                let mut iter = value_separator_pairs.iter().peekable();
 
-                    while let Some((type_field, comma_token)) = iter.next() {
-                        write!(formatted_code, "{}{}", type_field, comma_token)?;
-                        if iter.peek().is_none() && self.final_value_opt.is_none() {
-                            break;
+                while let Some((type_field, comma_token)) = iter.next() {
+                    if iter.peek().is_none() && punctuated.final_value_opt.is_none() {
+                        match trailing_comma {
+                            TrailingComma::Never => write!(formatted_code, "{type_field}")?,
+                            TrailingComma::Always | TrailingComma::Vertical => {
+                                write!(formatted_code, "{type_field}{comma_token}")?
+                            }
                         }
-                        if is_value_too_long || should_write_multiline(formatted_code, formatter) {
-                            writeln!(formatted_code)?;
-                            formatter.write_indent_into_buffer(formatted_code)?;
-                        } else {
-                            write!(formatted_code, " ")?;
-                        }
-                    }
-                    if let Some(final_value) = &self.final_value_opt {
-                        final_value.format(formatted_code, formatter)?;
-                        write!(formatted_code, "{}", PunctKind::Comma.as_char())?;
+                        break;
                     }
-                    if !formatted_code.ends_with('\n') {
+                    write!(formatted_code, "{}{}", type_field, comma_token)?;
+                    if is_value_too_long || should_write_multiline(formatted_code, formatter) {
                         writeln!(formatted_code)?;
+                        formatter.write_indent_into_buffer(formatted_code)?;
+                    } else {
+                        write!(formatted_code, " ")?;
+                    }
+                }
+                if let Some(final_value) = &punctuated.final_value_opt {
+                    final_value.format(formatted_code, formatter)?;
+                    match trailing_comma {
+                        TrailingComma::Never => {}
+                        TrailingComma::Always | TrailingComma::Vertical => {
+                            write!(formatted_code, "{}", PunctKind::Comma.as_char())?
+                        }
                     }
                 }
+                if !formatted_code.ends_with('\n') {
+                    writeln!(formatted_code)?;
+                }
             }
         }
-
-        Ok(())
     }
+
+    Ok(())
 }
 
 fn format_generic_pair<T, P>(
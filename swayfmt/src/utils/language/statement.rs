@@ -1,9 +1,12 @@
 use crate::{
     formatter::{shape::LineStyle, *},
-    utils::map::byte_span::{ByteSpan, LeafSpans},
+    utils::{
+        map::byte_span::{ByteSpan, LeafSpans},
+        CurlyBrace,
+    },
 };
 use std::fmt::Write;
-use sway_ast::{Expr, Parens, Punctuated, Statement, StatementLet};
+use sway_ast::{CodeBlockContents, Expr, Parens, Punctuated, Statement, StatementLet};
 use sway_types::{Span, Spanned};
 
 impl Format for Statement {
@@ -95,6 +98,7 @@ fn format_statement(
                             condition: _,
                             block: _
                         }
+                        | Expr::Labeled { .. }
                 ) {
                     // Method calls, If, While should not tamper with the
                     // expr_new_line because that would be inherited for all
@@ -152,6 +156,17 @@ impl Format for StatementLet {
         write!(formatted_code, " {} ", self.eq_token.span().as_str())?;
         // expr
         self.expr.format(formatted_code, formatter)?;
+        // ` else { ... }`
+        if let Some((else_token, block)) = &self.else_opt {
+            write!(formatted_code, " {} ", else_token.span().as_str())?;
+            if !block.get().statements.is_empty() || block.get().final_expr_opt.is_some() {
+                CodeBlockContents::open_curly_brace(formatted_code, formatter)?;
+                block.get().format(formatted_code, formatter)?;
+                CodeBlockContents::close_curly_brace(formatted_code, formatter)?;
+            } else {
+                write!(formatted_code, "{{}}")?;
+            }
+        }
         if formatter.shape.code_line.line_style == LineStyle::Inline {
             // `;`
             write!(formatted_code, "{}", self.semicolon_token.span().as_str())?;
@@ -201,6 +216,11 @@ impl LeafSpans for StatementLet {
         collected_spans.push(ByteSpan::from(self.eq_token.span()));
         // Add Expr's ByteSpan
         collected_spans.append(&mut self.expr.leaf_spans());
+        // Add else token's and else block's ByteSpans if they exist
+        if let Some((else_token, block)) = &self.else_opt {
+            collected_spans.push(ByteSpan::from(else_token.span()));
+            collected_spans.append(&mut block.leaf_spans());
+        }
         collected_spans.push(ByteSpan::from(self.semicolon_token.span()));
         collected_spans
     }
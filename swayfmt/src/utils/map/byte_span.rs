@@ -1,3 +1,4 @@
+use crate::utils::language::attribute::has_skip_attribute;
 use std::{cmp::Ordering, ops::Range};
 use sway_ast::{
     attribute::Annotated,
@@ -155,11 +156,17 @@ where
 }
 impl<T> LeafSpans for Annotated<T>
 where
-    T: LeafSpans + Parse,
+    T: LeafSpans + Parse + Spanned,
 {
     fn leaf_spans(&self) -> Vec<ByteSpan> {
         let mut collected_spans = self.attribute_list.leaf_spans();
-        collected_spans.append(&mut self.value.leaf_spans());
+        if has_skip_attribute(&self.attribute_list) {
+            // A skipped value is kept as written, so it is treated as a single leaf to keep
+            // comments and newlines from being inserted into it.
+            collected_spans.push(ByteSpan::from(self.value.span()));
+        } else {
+            collected_spans.append(&mut self.value.leaf_spans());
+        }
         collected_spans
     }
 }
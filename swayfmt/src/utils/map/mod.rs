@@ -2,3 +2,4 @@ pub(crate) mod byte_span;
 pub(crate) mod comments;
 pub(crate) mod newline;
 pub(crate) mod newline_style;
+pub(crate) mod return_style;
@@ -1,8 +1,13 @@
 use anyhow::Result;
 use ropey::{str_utils::byte_to_char_idx, Rope};
-use std::{collections::BTreeMap, fmt::Display, path::PathBuf, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Display,
+    path::PathBuf,
+    sync::Arc,
+};
 use sway_ast::Module;
-use sway_types::SourceEngine;
+use sway_types::{SourceEngine, Spanned};
 
 use crate::{
     constants::NEW_LINE,
@@ -116,6 +121,8 @@ pub fn handle_newlines(
 ) -> Result<(), FormatterError> {
     // Get newline threshold from config
     let newline_threshold = formatter.config.whitespace.newline_threshold;
+    // Blank lines between the items of the module are bounded by their own threshold.
+    let item_threshold = formatter.config.items.blank_lines_upper_bound;
     // Collect ByteSpan -> NewlineSequence mapping from unformatted input.
     //
     // We remove the extra whitespace the beginning of a file before creating a map of newlines.
@@ -132,6 +139,7 @@ pub fn handle_newlines(
         formatted_code,
         unformatted_input,
         newline_threshold,
+        item_threshold,
     )?;
     Ok(())
 }
@@ -157,6 +165,9 @@ fn calculate_offset(base: usize, offset: i64) -> usize {
 /// This requires both the unformatted_code itself and the parsed version of it, because
 /// unformatted_code is used for context lookups and unformatted_module is required for actual
 /// traversal.
+///
+/// Newline sequences that follow an item of the module are bounded by `item_threshold` instead
+/// of `newline_threshold`.
 fn add_newlines(
     newline_map: NewlineMap,
     unformatted_module: &Module,
@@ -164,7 +175,13 @@ fn add_newlines(
     formatted_code: &mut FormattedCode,
     unformatted_code: Arc<str>,
     newline_threshold: usize,
+    item_threshold: usize,
 ) -> Result<(), FormatterError> {
+    let item_ends = unformatted_module
+        .items
+        .iter()
+        .map(|item| item.span().end())
+        .collect::<HashSet<_>>();
     let mut unformatted_newline_spans = unformatted_module.leaf_spans();
     let mut formatted_newline_spans = formatted_module.leaf_spans();
     // Adding end of file to both spans so that last newline sequence(s) after an item would also be
@@ -193,6 +210,11 @@ fn add_newlines(
         .zip(formatted_newline_spans.iter().skip(1))
     {
         if previous_unformatted_newline_span.end < unformatted_newline_span.start {
+            let newline_threshold = if item_ends.contains(&previous_unformatted_newline_span.end) {
+                item_threshold
+            } else {
+                newline_threshold
+            };
             // At its core, the spaces between leaf spans are nothing more than just whitespace characters,
             // and sometimes comments, since they are not considered valid AST nodes. We are interested in
             // these spaces (with comments, if any)
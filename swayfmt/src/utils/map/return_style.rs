@@ -0,0 +1,155 @@
+//! Rewriting of the values returned at the end of function bodies into the user defined
+//! [ReturnStyle].
+//!
+//! The rewrite is applied to the source before it is formatted, so that the formatted code and
+//! the unformatted code it is compared against, when placing comments and newlines, have the
+//! same tokens.
+use crate::{config::expr::ReturnStyle, parse::parse_file, FormatterError};
+use std::{ops::Range, path::PathBuf, sync::Arc};
+use sway_ast::{CodeBlockContents, Expr, ItemFn, ItemImplItem, ItemKind, Module, Statement};
+use sway_types::{SourceEngine, Spanned};
+
+/// Tokens which, at the beginning of an expression, would continue a preceding control flow
+/// expression which is not terminated by a semicolon, e.g. as a binary operator.
+const CONTINUING_TOKENS: &[char] = &['(', '[', '-', '*', '&', '|'];
+
+/// Rewrites the trailing `return x;` of function bodies into implicit tail expressions `x`, or
+/// the tail expressions into `return x;`, depending on `return_style`.
+///
+/// Only the end of function bodies is rewritten, where both forms return from the function.
+/// Control flow tail expressions, like `if` and `match`, are never made explicit.
+pub(crate) fn apply_return_style(
+    source_engine: &SourceEngine,
+    src: Arc<str>,
+    path: Option<Arc<PathBuf>>,
+    return_style: ReturnStyle,
+) -> Result<Arc<str>, FormatterError> {
+    if matches!(return_style, ReturnStyle::Preserve) {
+        return Ok(src);
+    }
+    let module = parse_file(source_engine, src.clone(), path)?.value;
+    let mut edits = vec![];
+    for body in fn_bodies(&module) {
+        match return_style {
+            ReturnStyle::Implicit => make_implicit(&src, body, &mut edits),
+            ReturnStyle::Explicit => make_explicit(body, &mut edits),
+            ReturnStyle::Preserve => {}
+        }
+    }
+    if edits.is_empty() {
+        return Ok(src);
+    }
+
+    edits.sort_by_key(|(range, _)| range.start);
+    let mut rewritten = String::with_capacity(src.len());
+    let mut last_end = 0;
+    for (range, replacement) in edits {
+        rewritten.push_str(&src[last_end..range.start]);
+        rewritten.push_str(&replacement);
+        last_end = range.end;
+    }
+    rewritten.push_str(&src[last_end..]);
+    Ok(Arc::from(rewritten))
+}
+
+fn fn_bodies(module: &Module) -> Vec<&CodeBlockContents> {
+    let body = |item_fn: &ItemFn| item_fn.body.get();
+    module
+        .items
+        .iter()
+        .flat_map(|item| match &item.value {
+            ItemKind::Fn(item_fn) => vec![body(item_fn)],
+            ItemKind::Impl(item_impl) => item_impl
+                .contents
+                .get()
+                .iter()
+                .filter_map(|item| match &item.value {
+                    ItemImplItem::Fn(item_fn) => Some(body(item_fn)),
+                    ItemImplItem::Const(_) | ItemImplItem::Type(_) => None,
+                })
+                .collect(),
+            ItemKind::Trait(item_trait) => item_trait
+                .trait_defs_opt
+                .iter()
+                .flat_map(|defs| defs.get().iter().map(|item_fn| body(&item_fn.value)))
+                .collect(),
+            ItemKind::Abi(item_abi) => item_abi
+                .abi_defs_opt
+                .iter()
+                .flat_map(|defs| defs.get().iter().map(|item_fn| body(&item_fn.value)))
+                .collect(),
+            _ => vec![],
+        })
+        .collect()
+}
+
+/// `return x;` becomes `x`, and a trailing `return;` is removed.
+fn make_implicit(src: &str, body: &CodeBlockContents, edits: &mut Vec<(Range<usize>, String)>) {
+    let (return_expr, semicolon_span) = match (&body.final_expr_opt, body.statements.last()) {
+        (Some(final_expr), _) => (&**final_expr, None),
+        (
+            None,
+            Some(Statement::Expr {
+                expr,
+                semicolon_token_opt,
+            }),
+        ) => (expr, semicolon_token_opt.as_ref().map(|token| token.span())),
+        (None, _) => return,
+    };
+    let Expr::Return {
+        return_token,
+        expr_opt,
+    } = return_expr
+    else {
+        return;
+    };
+
+    let Some(value) = expr_opt else {
+        // A trailing `return;` can only end a function returning `()`.
+        let end = semicolon_span.map_or(return_token.span().end(), |span| span.end());
+        edits.push((return_token.span().start()..end, String::new()));
+        return;
+    };
+
+    let value_start = value.span().start();
+    let previous_expr = match body.statements.len().checked_sub(2) {
+        Some(index) if body.final_expr_opt.is_none() => body.statements.get(index),
+        _ => body.statements.last(),
+    };
+    if let Some(Statement::Expr {
+        expr,
+        semicolon_token_opt: None,
+    }) = previous_expr
+    {
+        if expr.is_control_flow()
+            && src[value_start..].starts_with(|c| CONTINUING_TOKENS.contains(&c))
+        {
+            return;
+        }
+    }
+
+    // Keep any comment between the `return` keyword and the value.
+    let return_end = return_token.span().end();
+    let removed_end = if src[return_end..value_start].trim().is_empty() {
+        value_start
+    } else {
+        return_end
+    };
+    edits.push((return_token.span().start()..removed_end, String::new()));
+    if let Some(semicolon_span) = semicolon_span {
+        edits.push((semicolon_span.start()..semicolon_span.end(), String::new()));
+    }
+}
+
+/// `x` becomes `return x;`.
+fn make_explicit(body: &CodeBlockContents, edits: &mut Vec<(Range<usize>, String)>) {
+    let Some(final_expr) = &body.final_expr_opt else {
+        return;
+    };
+    if final_expr.is_control_flow() || matches!(**final_expr, Expr::Return { .. }) {
+        return;
+    }
+    let span = final_expr.span();
+    edits.push((span.start()..span.start(), "return ".to_string()));
+    edits.push((span.end()..span.end(), ";".to_string()));
+}
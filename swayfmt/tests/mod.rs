@@ -1,6 +1,12 @@
 use indoc::indoc;
 use std::sync::Arc;
-use swayfmt::{config::user_def::FieldAlignment, Formatter};
+use swayfmt::{
+    config::{
+        expr::{MatchArmLeadingPipe, ReturnStyle, TrailingComma},
+        user_def::FieldAlignment,
+    },
+    Formatter,
+};
 use test_macros::assert_eq_pretty;
 
 /// Takes a configured formatter as input and formats a given input and checks the actual output against an
@@ -1627,11 +1633,72 @@ fn asm_block() {
         fn foo() {
             asm(r1: self, r2: other, r3, r4) {
                 addi r3 zero i32;
-                meq r4 r1 r2 r3;
+                meq  r4 r1   r2  r3;
+                r4: bool
+            }
+        }
+        "#},
+    );
+}
+
+#[test]
+fn asm_block_aligns_operands() {
+    check(
+        indoc! {r#"
+        library;
+
+        fn foo() {
+            asm(ptr: ptr,   len: len, result) {
+                aloc   len;
+                    mcp hp ptr len;
+                lw result hp i0; // Load the first word.
+                result: u64
+            }
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn foo() {
+            asm(ptr: ptr, len: len, result) {
+                aloc len;
+                mcp  hp     ptr len;
+                lw   result hp  i0; // Load the first word.
+                result: u64
+            }
+        }
+        "#},
+    );
+}
+
+#[test]
+fn asm_block_format_asm_disabled() {
+    let mut formatter = Formatter::default();
+    formatter.config.expressions.format_asm = false;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        fn foo()   {
+            asm(r1: self, r2: other, r3, r4) {
+                addi r3   zero i32; // Hand-tuned.
+                meq  r4   r1   r2   r3;
+                r4: bool
+            }
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn foo() {
+            asm(r1: self, r2: other, r3, r4) {
+                addi r3   zero i32; // Hand-tuned.
+                meq  r4   r1   r2   r3;
                 r4: bool
             }
         }
         "#},
+        &mut formatter,
     );
 }
 
@@ -2731,7 +2798,7 @@ fn long_doc_break_new_line() {
         pub fn alloc_bytes(count: u64) -> raw_ptr {
             asm(size: count, ptr) {
                 aloc size;
-                move ptr hp;
+                move ptr  hp;
                 ptr: raw_ptr
             }
         }
@@ -3155,3 +3222,555 @@ fn retain_in_keyword() {
         "#},
     );
 }
+
+#[test]
+fn skip_attribute_preserves_items() {
+    check(
+        indoc! {r#"
+        library;
+
+        #[fmt(skip)]
+        const TABLE: [u64; 4] = [
+            1,    20,
+            300,  4,
+        ];
+
+        impl Foo {
+            #[fmt(skip)]
+            fn   aligned() -> u64 {   1   }
+            fn   other() -> u64 {   2   }
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        #[fmt(skip)]
+        const TABLE: [u64; 4] = [
+            1,    20,
+            300,  4,
+        ];
+
+        impl Foo {
+            #[fmt(skip)]
+            fn   aligned() -> u64 {   1   }
+            fn other() -> u64 {
+                2
+            }
+        }
+        "#},
+    );
+}
+
+#[test]
+fn blank_lines_upper_bound() {
+    let mut formatter = Formatter::default();
+    formatter.config.items.blank_lines_upper_bound = 2;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        fn foo() {}
+
+
+
+        fn bar() {}
+
+
+        fn baz() {
+            let x = 1;
+
+
+
+            let y = 2;
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn foo() {}
+
+
+        fn bar() {}
+
+
+        fn baz() {
+            let x = 1;
+
+            let y = 2;
+        }
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn blank_lines_lower_bound() {
+    let mut formatter = Formatter::default();
+    formatter.config.items.blank_lines_lower_bound = 1;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        const X: u64 = 1;
+        const Y: u64 = 2;
+        fn foo() {}
+
+        fn bar() {}
+        "#},
+        indoc! {r#"
+        library;
+
+        const X: u64 = 1;
+
+        const Y: u64 = 2;
+
+        fn foo() {}
+
+        fn bar() {}
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn fn_single_line() {
+    let mut formatter = Formatter::default();
+    formatter.config.expressions.fn_single_line = true;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        fn answer() -> u64 {
+            42
+        }
+
+        fn add(a: u64, b: u64) -> u64 { a+b }
+
+        fn with_statement() -> u64 { let a = 1; a }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn answer() -> u64 { 42 }
+
+        fn add(a: u64, b: u64) -> u64 { a + b }
+
+        fn with_statement() -> u64 {
+            let a = 1;
+            a
+        }
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn match_arm_leading_pipe() {
+    let mut formatter = Formatter::default();
+    formatter.config.expressions.match_arm_leading_pipe = MatchArmLeadingPipe::Always;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        fn foo(x: u64) -> u64 {
+            match x {
+                0 | 1 => 1,
+                | 2 => 2,
+                _ => 3,
+            }
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn foo(x: u64) -> u64 {
+            match x {
+                | 0 | 1 => 1,
+                | 2 => 2,
+                | _ => 3,
+            }
+        }
+        "#},
+        &mut formatter,
+    );
+
+    formatter.config.expressions.match_arm_leading_pipe = MatchArmLeadingPipe::Never;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        fn foo(x: u64) -> u64 {
+            match x {
+                | 0 | 1 => 1,
+                _ => 3,
+            }
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn foo(x: u64) -> u64 {
+            match x {
+                0 | 1 => 1,
+                _ => 3,
+            }
+        }
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn struct_lit_width() {
+    let mut formatter = Formatter::default();
+    formatter.config.heuristics.struct_lit_width = Some(5);
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        fn foo() {
+            let p = Point { x: 1, y: 2 };
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn foo() {
+            let p = Point {
+                x: 1,
+                y: 2,
+            };
+        }
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn raw_and_multiline_strings_are_preserved() {
+    check(
+        indoc! {r####"
+        library;
+
+        fn foo() {
+            let json  =   r#"{"key": "value", "list": [1, 2]}"#;
+            let nested = r##"contains "# in the middle"##;
+            let text = "first line
+          second line,   unevenly indented";
+        }
+        "####},
+        indoc! {r####"
+        library;
+
+        fn foo() {
+            let json = r#"{"key": "value", "list": [1, 2]}"#;
+            let nested = r##"contains "# in the middle"##;
+            let text = "first line
+          second line,   unevenly indented";
+        }
+        "####},
+    );
+}
+
+#[test]
+fn return_style_implicit() {
+    let mut formatter = Formatter::default();
+    formatter.config.expressions.return_style = ReturnStyle::Implicit;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        fn foo(x: u64) -> u64 {
+            if x == 0 {
+                return 1;
+            }
+            let y = x * 2;
+            return y + 1;
+        }
+
+        fn bar() {
+            baz();
+            return;
+        }
+
+        impl Foo for Bar {
+            fn foo(self) -> u64 {
+                return self.x;
+            }
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn foo(x: u64) -> u64 {
+            if x == 0 {
+                return 1;
+            }
+            let y = x * 2;
+            y + 1
+        }
+
+        fn bar() {
+            baz();
+        }
+
+        impl Foo for Bar {
+            fn foo(self) -> u64 {
+                self.x
+            }
+        }
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn return_style_implicit_after_control_flow() {
+    let mut formatter = Formatter::default();
+    formatter.config.expressions.return_style = ReturnStyle::Implicit;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        fn foo(x: u64) -> u64 {
+            if x == 0 {
+                log(x);
+            }
+            return (x + 1) * 2;
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn foo(x: u64) -> u64 {
+            if x == 0 {
+                log(x);
+            }
+            return (x + 1) * 2;
+        }
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn return_style_explicit() {
+    let mut formatter = Formatter::default();
+    formatter.config.expressions.return_style = ReturnStyle::Explicit;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        fn foo(x: u64) -> u64 {
+            let y = x * 2;
+            y + 1
+        }
+
+        fn bar(x: bool) -> u64 {
+            if x { 1 } else { 2 }
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn foo(x: u64) -> u64 {
+            let y = x * 2;
+            return y + 1;
+        }
+
+        fn bar(x: bool) -> u64 {
+            if x { 1 } else { 2 }
+        }
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn fn_args_trailing_comma() {
+    let mut formatter = Formatter::default();
+    formatter.config.expressions.fn_args_trailing_comma = TrailingComma::Always;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        fn foo(a: u64, b: u64) -> u64 {
+            bar(a, b)
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn foo(a: u64, b: u64,) -> u64 {
+            bar(a, b,)
+        }
+        "#},
+        &mut formatter,
+    );
+
+    formatter.config.expressions.fn_args_trailing_comma = TrailingComma::Never;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        fn access_control_with_identity() {
+            let sender = msg_sender().unwrap();
+            require(sender == storage.owner.read(), MyError::UnauthorizedUser(sender));
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn access_control_with_identity() {
+            let sender = msg_sender().unwrap();
+            require(
+                sender == storage
+                    .owner
+                    .read(),
+                MyError::UnauthorizedUser(sender)
+            );
+        }
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn struct_lit_trailing_comma() {
+    let mut formatter = Formatter::default();
+    formatter.config.structures.struct_lit_trailing_comma = TrailingComma::Always;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        fn foo() {
+            let point = Point { x: 0, y: 0 };
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn foo() {
+            let point = Point { x: 0, y: 0, };
+        }
+        "#},
+        &mut formatter,
+    );
+
+    formatter.config.structures.struct_lit_trailing_comma = TrailingComma::Never;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        fn foo() {
+            let point = Point { x: 18446744073709551615, y: 18446744073709551615};
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn foo() {
+            let point = Point {
+                x: 18446744073709551615,
+                y: 18446744073709551615
+            };
+        }
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn enum_variants_trailing_comma() {
+    let mut formatter = Formatter::default();
+    formatter.config.structures.enum_variants_trailing_comma = TrailingComma::Never;
+    check_with_formatter(
+        indoc! {r#"
+        contract;
+
+        enum Color {
+            Blue: (), Green: (),
+            Grey: (), }
+        "#},
+        indoc! {r#"
+        contract;
+
+        enum Color {
+            Blue: (),
+            Green: (),
+            Grey: ()
+        }
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn match_arms_trailing_comma() {
+    let mut formatter = Formatter::default();
+    formatter.config.expressions.match_arms_trailing_comma = TrailingComma::Always;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        fn foo(x: u64) {
+            match x {
+                0 => {
+                    bar();
+                }
+                _ => {
+                    baz();
+                }
+            }
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn foo(x: u64) {
+            match x {
+                0 => {
+                    bar();
+                }
+                _ => {
+                    baz();
+                },
+            }
+        }
+        "#},
+        &mut formatter,
+    );
+
+    formatter.config.expressions.match_arms_trailing_comma = TrailingComma::Never;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        fn foo(x: u64) {
+            match x {
+                0 => {
+                    bar();
+                },
+                _ => {
+                    baz();
+                },
+            }
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn foo(x: u64) {
+            match x {
+                0 => {
+                    bar();
+                },
+                _ => {
+                    baz();
+                }
+            }
+        }
+        "#},
+        &mut formatter,
+    );
+}
@@ -222,34 +222,29 @@ pub(crate) fn runs_in_vm(
                 .with_clear_env()
                 .build();
 
-            // Transaction to create the smart contract
+            // Transaction to create the smart contract, running its constructor.
+            evm.tx_mut().data = script.bytecode.bytes.into();
+            evm.tx_mut().transact_to = revm::interpreter::primitives::TransactTo::Create;
             let result = evm
                 .transact_commit()
                 .map_err(|e| anyhow::anyhow!("Could not create smart contract on EVM: {e:?}"))?;
 
-            match result {
-                revm::primitives::ExecutionResult::Revert { .. }
-                | revm::primitives::ExecutionResult::Halt { .. } => todo!(),
-                revm::primitives::ExecutionResult::Success { ref output, .. } => match output {
-                    revm::primitives::result::Output::Call(_) => todo!(),
-                    revm::primitives::result::Output::Create(_bytes, address_opt) => {
-                        match address_opt {
-                            None => todo!(),
-                            Some(address) => {
-                                evm.tx_mut().data = script.bytecode.bytes.into();
-                                evm.tx_mut().transact_to =
-                                    revm::interpreter::primitives::TransactTo::Call(*address);
-
-                                let result = evm
-                                    .transact_commit()
-                                    .map_err(|e| anyhow::anyhow!("Failed call on EVM: {e:?}"))?;
-
-                                Ok(VMExecutionResult::Evm(result))
-                            }
-                        }
-                    }
-                },
-            }
+            let address = match result {
+                revm::primitives::ExecutionResult::Success {
+                    output: revm::primitives::result::Output::Create(_, Some(address)),
+                    ..
+                } => address,
+                result => bail!("Could not create smart contract on EVM: {result:?}"),
+            };
+
+            // Call the deployed code, passing the script data as calldata.
+            evm.tx_mut().data = script_data.unwrap_or_default().into();
+            evm.tx_mut().transact_to = revm::interpreter::primitives::TransactTo::Call(address);
+            let result = evm
+                .transact_commit()
+                .map_err(|e| anyhow::anyhow!("Failed call on EVM: {e:?}"))?;
+
+            Ok(VMExecutionResult::Evm(result))
         }
     }
 }
@@ -272,6 +267,7 @@ pub(crate) async fn compile_to_bytes(file_name: &str, run_config: &RunConfig) ->
             bytecode: run_config.print_bytecode,
             bytecode_spans: run_config.print_bytecode,
             ir: run_config.print_ir.clone(),
+            verify_ir: Default::default(),
             reverse_order: false,
         },
         pkg: forc_pkg::PkgOpts {
@@ -333,7 +329,12 @@ pub(crate) async fn compile_and_run_unit_tests(
         }) {
             Ok(Ok(built_tests)) => {
                 let test_filter = None;
-                let tested = built_tests.run(forc_test::TestRunnerCount::Auto, test_filter)?;
+                let tested = built_tests.run(
+                    forc_test::TestRunnerCount::Auto,
+                    test_filter,
+                    forc_test::TestLimits::default(),
+                    &forc_test::invariant::InvariantOpts::default(),
+                )?;
                 match tested {
                     forc_test::Tested::Package(tested_pkg) => Ok(vec![*tested_pkg]),
                     forc_test::Tested::Workspace(tested_pkgs) => Ok(tested_pkgs),
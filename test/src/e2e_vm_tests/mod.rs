@@ -428,12 +428,16 @@ impl TestContext {
                         }
                     }
                     harness::VMExecutionResult::Evm(state) => match state {
-                        revm::primitives::ExecutionResult::Success { reason, .. } => match reason {
-                            revm::primitives::SuccessReason::Stop => TestResult::Result(0),
-                            revm::primitives::SuccessReason::Return => todo!(),
-                            revm::primitives::SuccessReason::SelfDestruct => todo!(),
-                            revm::primitives::SuccessReason::EofReturnContract => todo!(),
-                        },
+                        revm::primitives::ExecutionResult::Success { reason, output, .. } => {
+                            match reason {
+                                revm::primitives::SuccessReason::Stop => TestResult::Result(0),
+                                revm::primitives::SuccessReason::Return => {
+                                    TestResult::ReturnData(output.data().to_vec())
+                                }
+                                revm::primitives::SuccessReason::SelfDestruct => todo!(),
+                                revm::primitives::SuccessReason::EofReturnContract => todo!(),
+                            }
+                        }
                         revm::primitives::ExecutionResult::Revert { .. } => TestResult::Result(0),
                         revm::primitives::ExecutionResult::Halt { reason, .. } => {
                             panic!("EVM exited with unhandled reason: {:?}", reason);
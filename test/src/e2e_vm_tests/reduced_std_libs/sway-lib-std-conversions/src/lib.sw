@@ -6,6 +6,7 @@ pub mod logging;
 pub mod revert;
 pub mod result;
 pub mod option;
+pub mod ops;
 pub mod assert;
 pub mod convert;
 pub mod intrinsics;
@@ -7,6 +7,7 @@ library;
 pub use ::assert::{assert, assert_eq, assert_ne};
 pub use ::option::Option::{self, *};
 pub use ::result::Result::{self, *};
+pub use ::ops::{FromResidual, Try};
 pub use ::revert::{require, revert};
 
 // Logging
@@ -10,6 +10,7 @@ pub use ::vec::{Vec, VecIter};
 pub use ::assert::{assert, assert_eq, assert_ne};
 pub use ::option::Option::{self, *};
 pub use ::result::Result::{self, *};
+pub use ::ops::{FromResidual, Try};
 pub use ::revert::{require, revert};
 
 // Convert
@@ -0,0 +1,15 @@
+// Inheritance graph:
+//          MySuperAbi
+//              |
+//            MyAbi
+
+contract;
+
+abi MySuperAbi {
+    fn method();
+}
+
+abi MyAbi : MySuperAbi {
+    #[method_name(value = "method")]
+    fn other_method();
+}
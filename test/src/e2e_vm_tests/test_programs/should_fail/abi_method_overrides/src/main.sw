@@ -0,0 +1,26 @@
+contract;
+
+abi MyAbi {
+    #[selector(value = 0x100000000)]
+    fn too_large();
+
+    #[method_name(value = "")]
+    fn empty_name();
+
+    #[selector(value = 0x12345678)]
+    fn pinned();
+
+    #[selector(value = 0x12345678)]
+    fn pinned_again();
+}
+
+impl MyAbi for Contract {
+    fn too_large() {}
+
+    fn empty_name() {}
+
+    #[selector(value = 0x87654321)]
+    fn pinned() {}
+
+    fn pinned_again() {}
+}
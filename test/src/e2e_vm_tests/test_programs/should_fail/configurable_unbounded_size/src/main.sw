@@ -0,0 +1,7 @@
+script;
+
+configurable {
+    X: raw_slice = asm(s: (0, 0)) { s: raw_slice },
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+script;
+
+fn not_trailing(a: u64 = 1, b: u64) -> u64 {
+    b
+}
+
+fn main() {}
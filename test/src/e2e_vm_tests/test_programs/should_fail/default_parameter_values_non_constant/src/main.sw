@@ -0,0 +1,13 @@
+script;
+
+fn one() -> u64 {
+    1
+}
+
+fn add(a: u64, b: u64 = one()) -> u64 {
+    b
+}
+
+fn main() {
+    let _ = add(1);
+}
@@ -0,0 +1,21 @@
+script;
+
+#[error_type]
+struct NotAnEnum {
+    code: u64,
+}
+
+#[error_type]
+fn not_an_enum() {}
+
+#[error_type]
+enum Error {
+    #[error_type]
+    NotOwner: (),
+}
+
+fn main() {
+    let _ = NotAnEnum { code: 0 };
+    not_an_enum();
+    let _ = Error::NotOwner;
+}
@@ -0,0 +1,5 @@
+script;
+
+fn main() {
+    let _ = format!("{} and {}", 1);
+}
@@ -0,0 +1,23 @@
+script;
+
+trait Container {
+    type Item<T>;
+}
+
+trait Combine {
+    type Output<T>;
+}
+
+struct Struct {}
+
+impl Container for Struct {
+  type Item<T, U> = u64;
+}
+
+impl Combine for Struct {
+  type Output = u64;
+}
+
+fn main() -> u32 {
+  0
+}
@@ -0,0 +1,52 @@
+script;
+
+trait Numeric {
+    fn number(self) -> u64;
+}
+
+trait Flag {
+    fn flag(self) -> bool;
+}
+
+impl Numeric for u64 {
+    fn number(self) -> u64 {
+        self
+    }
+}
+
+impl Flag for u64 {
+    fn flag(self) -> bool {
+        true
+    }
+}
+
+struct Wrapper<T> {
+    inner: T,
+}
+
+trait Describe {
+    fn describe(self) -> u64;
+}
+
+impl<T> Describe for Wrapper<T>
+where
+    T: Numeric,
+{
+    fn describe(self) -> u64 {
+        self.inner.number()
+    }
+}
+
+impl<T> Describe for Wrapper<T>
+where
+    T: Flag,
+{
+    fn describe(self) -> u64 {
+        if self.inner.flag() { 1 } else { 0 }
+    }
+}
+
+fn main() -> u64 {
+    let _v = Wrapper { inner: 42u64 }.describe();
+    0
+}
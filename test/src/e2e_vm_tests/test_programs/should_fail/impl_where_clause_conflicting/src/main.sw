@@ -0,0 +1,40 @@
+script;
+
+trait First {}
+
+trait Second {}
+
+impl First for u64 {}
+
+impl Second for u64 {}
+
+struct Wrapper<T> {
+    inner: T,
+}
+
+trait Describe {
+    fn describe(self) -> u64;
+}
+
+impl<T> Describe for Wrapper<T>
+where
+    T: First + Second,
+{
+    fn describe(self) -> u64 {
+        1
+    }
+}
+
+// The same constraints in a different order do not make this a distinct impl.
+impl<T> Describe for Wrapper<T>
+where
+    T: Second + First,
+{
+    fn describe(self) -> u64 {
+        2
+    }
+}
+
+fn main() -> u64 {
+    Wrapper { inner: 42u64 }.describe()
+}
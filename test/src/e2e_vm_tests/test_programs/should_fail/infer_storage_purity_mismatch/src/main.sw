@@ -0,0 +1,45 @@
+contract;
+
+abi MyContract {
+    #[storage(read)]
+    fn read_through_private() -> u64;
+
+    #[storage(read)]
+    fn write_through_private() -> bool;
+
+    fn pure_through_private() -> u64;
+}
+
+impl MyContract for Contract {
+    #[storage(read)]
+    fn read_through_private() -> u64 {
+        load()
+    }
+
+    #[storage(read)]
+    fn write_through_private() -> bool {
+        store()
+    }
+
+    fn pure_through_private() -> u64 {
+        load()
+    }
+}
+
+// The purity of the private functions below is inferred from their bodies and callees.
+fn load() -> u64 {
+    load_word()
+}
+
+fn load_word() -> u64 {
+    __state_load_word(b256::zero())
+}
+
+fn store() -> bool {
+    store_word()
+}
+
+fn store_word() -> bool {
+    let _ = __state_store_word(b256::zero(), 0);
+    true
+}
@@ -0,0 +1,28 @@
+script;
+
+struct Transfer {
+    #[indexed]
+    from: b256,
+    amount: u64,
+}
+
+#[event]
+struct Deposit {
+    #[indexed]
+    account: b256,
+    amount: u64,
+    #[indexed]
+    asset: b256,
+}
+
+#[event]
+struct Memo {
+    #[indexed]
+    text: str[4],
+}
+
+fn main() {
+    let _ = Transfer { from: b256::zero(), amount: 0 };
+    let _ = Deposit { account: b256::zero(), amount: 0, asset: b256::zero() };
+    let _ = Memo { text: __to_str_array("memo") };
+}
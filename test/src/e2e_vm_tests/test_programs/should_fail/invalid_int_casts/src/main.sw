@@ -0,0 +1,12 @@
+script;
+
+fn main() {
+    let b = true;
+    let _ = b as u64;
+
+    let x = 1u64;
+    let _ = x as u256;
+
+    let y = 1u256;
+    let _ = y as u64;
+}
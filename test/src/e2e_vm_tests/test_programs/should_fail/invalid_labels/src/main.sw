@@ -0,0 +1,19 @@
+library;
+
+fn unknown_label() {
+    'outer: while true {
+        break 'missing;
+    }
+}
+
+fn break_loop_with_value() {
+    'outer: while true {
+        break 'outer 42;
+    }
+}
+
+fn continue_labeled_block() {
+    'block: {
+        continue 'block;
+    }
+}
@@ -0,0 +1,15 @@
+library;
+
+fn not_diverging(value: Option<u64>) -> u64 {
+    let Some(n) = value else {
+        log(0);
+    };
+    n
+}
+
+fn diverging(value: Option<u64>) -> u64 {
+    let Some(n) = value else {
+        return 0;
+    };
+    n
+}
@@ -0,0 +1,11 @@
+script;
+
+fn add(a: u64, b: u64) -> u64 {
+    a + b
+}
+
+fn main() {
+    let _ = add(b: 1, a: 2);
+    let _ = add(a: 1, c: 2);
+    let _ = add(a: 1, a: 2);
+}
@@ -0,0 +1,9 @@
+script;
+
+fn sub(minuend: u64, subtrahend: u64) -> u64 {
+    minuend
+}
+
+fn main() {
+    let _ = sub(10, subtrahend: 3);
+}
@@ -0,0 +1,21 @@
+script;
+
+fn multiple_rest_patterns(array: [u64; 5]) {
+    let [_a, .., _b, .., _c] = array;
+}
+
+fn range_index_not_referenced(array: [u64; 5]) {
+    let _ = array[1..3];
+}
+
+fn range_outside_of_index() {
+    let _ = 1..3;
+}
+
+fn slice_pattern_in_match(array: [u64; 5]) {
+    match array {
+        [_a, ..] => {},
+    }
+}
+
+fn main() {}
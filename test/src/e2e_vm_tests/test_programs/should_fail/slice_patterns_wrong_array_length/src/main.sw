@@ -0,0 +1,13 @@
+script;
+
+fn main() {
+    let array = [1u64, 2, 3];
+
+    let [_a, _b] = array;
+
+    let [_c, _d, .., _e, _f] = array;
+
+    let _ = &array[2..1];
+
+    let _ = &array[1..4];
+}
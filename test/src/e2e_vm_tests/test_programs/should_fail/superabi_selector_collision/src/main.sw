@@ -0,0 +1,15 @@
+// Inheritance graph:
+//          MySuperAbi
+//              |
+//            MyAbi
+
+contract;
+
+abi MySuperAbi {
+    fn method_67725();
+}
+
+// The selectors of `method_67725()` and `method_139276()` are both 0xda33a98e.
+abi MyAbi : MySuperAbi {
+    fn method_139276();
+}
@@ -0,0 +1,27 @@
+script;
+
+trait Double {
+    fn double(self) -> Self;
+}
+
+trait Container<T> {
+    fn get(self) -> T;
+} {
+    fn doubled(self, value: T) -> T where T: Double {
+        value.double()
+    }
+}
+
+struct NotDouble {}
+
+struct Struct {}
+
+impl Container<NotDouble> for Struct {
+    fn get(self) -> NotDouble {
+        NotDouble {}
+    }
+}
+
+fn main() -> u32 {
+  0
+}
@@ -0,0 +1,42 @@
+library;
+
+enum MyError {
+    A: (),
+}
+
+enum OtherError {
+    B: (),
+}
+
+fn number() -> u64 {
+    42
+}
+
+fn maybe_number() -> Option<u64> {
+    Some(42)
+}
+
+fn result_number() -> Result<u64, MyError> {
+    Ok(42)
+}
+
+fn unsupported_type() -> Option<u64> {
+    let n = number()?;
+    Some(n)
+}
+
+fn option_in_function_returning_u64() -> u64 {
+    maybe_number()? + 1
+}
+
+fn option_in_function_returning_result() -> Result<u64, MyError> {
+    Ok(maybe_number()?)
+}
+
+fn result_in_function_returning_option() -> Option<u64> {
+    Some(result_number()?)
+}
+
+fn result_with_other_error_type() -> Result<u64, OtherError> {
+    Ok(result_number()?)
+}
@@ -0,0 +1,27 @@
+contract;
+
+abi Counter {
+    #[storage(read, write)]
+    fn increment(amount: u64) -> u64;
+}
+
+const COUNTER_KEY: b256 = 0x0000000000000000000000000000000000000000000000000000000000000001;
+
+fn triangular(n: u64) -> u64 {
+    let mut i = 0;
+    let mut sum = 0;
+    while i < n {
+        i += 1;
+        sum += i;
+    }
+    sum
+}
+
+impl Counter for Contract {
+    #[storage(read, write)]
+    fn increment(amount: u64) -> u64 {
+        let value = __state_load_word(COUNTER_KEY) + triangular(amount);
+        let _ = __state_store_word(COUNTER_KEY, value);
+        __state_load_word(COUNTER_KEY)
+    }
+}
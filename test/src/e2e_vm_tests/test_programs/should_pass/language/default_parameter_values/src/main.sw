@@ -0,0 +1,34 @@
+script;
+
+const FEE: u64 = 10;
+
+fn transfer(amount: u64, fee: u64 = FEE, count: u64 = 2) -> u64 {
+    amount * count + fee
+}
+
+struct Wallet {
+    balance: u64,
+}
+
+impl Wallet {
+    fn withdraw(self, amount: u64, fee: u64 = 1) -> u64 {
+        self.balance - amount - fee
+    }
+}
+
+fn main() -> u64 {
+    let wallet = Wallet { balance: 100 };
+
+    // 5 * 2 + 10
+    let a = transfer(5);
+    // 5 * 2 + 0
+    let b = transfer(5, 0);
+    // 1 * 3 + 10
+    let c = transfer(amount: 1, count: 3);
+    // 100 - 50 - 1
+    let d = wallet.withdraw(50);
+    // 100 - 50 - 9
+    let e = wallet.withdraw(amount: 50, fee: 9);
+
+    a + b + c + d + e
+}
@@ -0,0 +1,53 @@
+script;
+
+use std::{clone::Clone, hash::{Hash, sha256}};
+
+#[derive(Eq, Clone, Hash, AbiEncode, AbiDecode)]
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+#[derive(PartialEq, Clone, Hash)]
+enum Shape {
+    Empty: (),
+    Dot: Point,
+    Circle: u64,
+}
+
+#[derive(Eq, Clone)]
+struct Wrapper<T> {
+    value: T,
+}
+
+#[derive(Eq)]
+struct Empty {}
+
+fn main() -> bool {
+    let p = Point { x: 1, y: 2 };
+    let q = Point { x: 2, y: 1 };
+
+    // Eq
+    assert(p == Point { x: 1, y: 2 });
+    assert(p != q);
+    assert(Shape::Empty == Shape::Empty);
+    assert(Shape::Dot(p) == Shape::Dot(p));
+    assert(Shape::Dot(p) != Shape::Dot(q));
+    assert(Shape::Empty != Shape::Circle(0));
+    assert(Empty {} == Empty {});
+
+    // Clone
+    assert(p.clone() == p);
+    assert(Shape::Circle(3).clone() == Shape::Circle(3));
+    assert(Wrapper { value: p }.clone() == Wrapper { value: p });
+
+    // Hash
+    assert(sha256(p) == sha256(Point { x: 1, y: 2 }));
+    assert(sha256(p) != sha256(q));
+    assert(sha256(Shape::Circle(1)) != sha256(Shape::Dot(Point { x: 1, y: 0 })));
+
+    // AbiEncode and AbiDecode
+    assert(abi_decode::<Point>(encode(p)) == p);
+
+    true
+}
@@ -0,0 +1,7 @@
+library;
+
+#[error_type]
+pub enum Error {
+    NotOwner: (),
+    InsufficientBalance: u64,
+}
@@ -0,0 +1,13 @@
+script;
+
+mod errors;
+
+use std::revert::revert_with_error;
+use errors::Error;
+
+fn main() {
+    // sha256("error_type_revert::errors::Error::NotOwner")[0..8]
+    assert(Error::NotOwner.error_code() == 18118490125210031297);
+
+    revert_with_error(Error::InsufficientBalance(42));
+}
@@ -0,0 +1,59 @@
+script;
+
+struct Wrapper<T> {
+  inner: T,
+}
+
+struct Pair<A, B> {
+  first: A,
+  second: B,
+}
+
+trait Container {
+    type Item<T>;
+
+    fn wrap_u64(self, value: u64) -> Self::Item<u64>;
+    fn wrap_bool(self, value: bool) -> Self::Item<bool>;
+}
+
+struct WrapperFamily {}
+
+impl Container for WrapperFamily {
+  type Item<T> = Wrapper<T>;
+
+  fn wrap_u64(self, value: u64) -> Self::Item<u64> {
+    Wrapper { inner: value }
+  }
+
+  fn wrap_bool(self, value: bool) -> Self::Item<bool> {
+    Wrapper { inner: value }
+  }
+}
+
+trait Combine {
+    type Output<A, B>;
+
+    fn combine(self, a: u64, b: bool) -> Self::Output<u64, bool>;
+}
+
+struct PairFamily {}
+
+impl Combine for PairFamily {
+  type Output<A, B> = Pair<A, B>;
+
+  fn combine(self, a: u64, b: bool) -> Self::Output<u64, bool> {
+    Pair { first: a, second: b }
+  }
+}
+
+fn main() -> u64 {
+  let wrapped_u64: Wrapper<u64> = WrapperFamily {}.wrap_u64(40);
+  let wrapped_bool: Wrapper<bool> = WrapperFamily {}.wrap_bool(true);
+  let pair: Pair<u64, bool> = PairFamily {}.combine(2, wrapped_bool.inner);
+
+  if pair.second {
+    wrapped_u64.inner + pair.first
+  } else {
+    0
+  }
+}
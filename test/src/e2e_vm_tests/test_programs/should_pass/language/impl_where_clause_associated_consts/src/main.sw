@@ -0,0 +1,49 @@
+script;
+
+trait Numeric {
+    fn number(self) -> u64;
+}
+
+trait Flag {
+    fn flag(self) -> bool;
+}
+
+impl Numeric for u64 {
+    fn number(self) -> u64 {
+        self
+    }
+}
+
+impl Flag for bool {
+    fn flag(self) -> bool {
+        self
+    }
+}
+
+struct Wrapper<T> {
+    inner: T,
+}
+
+trait Kind {
+    const KIND: u64;
+}
+
+impl<T> Kind for Wrapper<T>
+where
+    T: Numeric,
+{
+    const KIND: u64 = 1;
+}
+
+impl<T> Kind for Wrapper<T>
+where
+    T: Flag,
+{
+    const KIND: u64 = 2;
+}
+
+fn main() -> u64 {
+    let numeric_kind = Wrapper::<u64>::KIND;
+    let flag_kind = Wrapper::<bool>::KIND;
+    numeric_kind * 10 + flag_kind
+}
@@ -0,0 +1,53 @@
+script;
+
+trait Numeric {
+    fn number(self) -> u64;
+}
+
+trait Flag {
+    fn flag(self) -> bool;
+}
+
+impl Numeric for u64 {
+    fn number(self) -> u64 {
+        self
+    }
+}
+
+impl Flag for bool {
+    fn flag(self) -> bool {
+        self
+    }
+}
+
+struct Wrapper<T> {
+    inner: T,
+}
+
+trait Describe {
+    fn describe(self) -> u64;
+}
+
+impl<T> Describe for Wrapper<T>
+where
+    T: Numeric,
+{
+    fn describe(self) -> u64 {
+        self.inner.number()
+    }
+}
+
+impl<T> Describe for Wrapper<T>
+where
+    T: Flag,
+{
+    fn describe(self) -> u64 {
+        if self.inner.flag() { 101 } else { 100 }
+    }
+}
+
+fn main() -> u64 {
+    let number = Wrapper { inner: 40u64 }.describe();
+    let flag = Wrapper { inner: true }.describe();
+    number + flag
+}
@@ -0,0 +1,42 @@
+script;
+
+const WIDE: u32 = 0x12345678;
+const NARROW: u8 = WIDE as u8;
+
+fn narrow(x: u64) -> u8 {
+    x as u8
+}
+
+fn main() -> bool {
+    // Widening casts keep the value.
+    let a = 255u8;
+    assert(a as u16 == 255u16);
+    assert(a as u32 == 255u32);
+    assert(a as u64 == 255);
+
+    // Narrowing casts truncate the value.
+    assert(narrow(256) == 0u8);
+    assert(narrow(511) == 255u8);
+    let b = 0x12345678u32;
+    assert(b as u16 == 0x5678u16);
+    assert(b as u8 == 0x78u8);
+    let c = 0x1234567890abcdef;
+    assert(c as u32 == 0x90abcdefu32);
+
+    // `as` binds tighter than binary operators.
+    assert(c as u8 + 1u8 == 0xf0u8);
+
+    // `as` is lowered to the `__cast` intrinsic.
+    assert(__cast::<u16>(c) == 0xcdefu16);
+
+    // Casts are evaluated in constants.
+    assert(NARROW == 0x78u8);
+
+    // Warning: the literal is truncated.
+    assert(300 as u8 == 44u8);
+
+    // Warning: the cast is redundant.
+    assert(a as u8 == 255u8);
+
+    true
+}
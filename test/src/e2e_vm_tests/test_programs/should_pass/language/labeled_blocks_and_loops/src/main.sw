@@ -0,0 +1,100 @@
+library;
+
+fn classify(n: u64) -> u64 {
+    'classification: {
+        if n == 0 {
+            break 'classification 0;
+        }
+        if n < 10 {
+            break 'classification 1;
+        }
+        2
+    }
+}
+
+fn first_pair_with_sum(values: [u64; 5], sum: u64) -> Option<(u64, u64)> {
+    let mut found = None;
+    let mut i = 0;
+    'outer: while i < 5 {
+        let mut j = i + 1;
+        while j < 5 {
+            if values[i] + values[j] == sum {
+                found = Some((i, j));
+                break 'outer;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    found
+}
+
+fn count_skipping_rows(rows: u64, columns: u64, skipped_row: u64) -> u64 {
+    let mut count = 0;
+    let mut row = 0;
+    'rows: while row < rows {
+        row += 1;
+        let mut column = 0;
+        while column < columns {
+            column += 1;
+            if row - 1 == skipped_row {
+                continue 'rows;
+            }
+            count += 1;
+        }
+    }
+    count
+}
+
+fn unit_labeled_block(flag: bool) -> u64 {
+    let mut result = 1;
+    'block: {
+        if flag {
+            break 'block;
+        }
+        result = 2;
+    }
+    result
+}
+
+fn labeled_block_exited_from_loop(limit: u64) -> u64 {
+    let value: u64 = 'search: {
+        let mut i = 0;
+        while i < 100 {
+            if i * i >= limit {
+                break 'search i;
+            }
+            i += 1;
+        }
+        100
+    };
+    value
+}
+
+#[test]
+fn labeled_blocks_evaluate_to_break_values() {
+    assert(classify(0) == 0);
+    assert(classify(5) == 1);
+    assert(classify(50) == 2);
+
+    assert(unit_labeled_block(true) == 1);
+    assert(unit_labeled_block(false) == 2);
+
+    assert(labeled_block_exited_from_loop(10) == 4);
+    assert(labeled_block_exited_from_loop(20000) == 100);
+}
+
+#[test]
+fn labeled_loops_break_and_continue_outer_loops() {
+    match first_pair_with_sum([1, 3, 5, 7, 9], 12) {
+        Some((i, j)) => {
+            assert(i == 1);
+            assert(j == 4);
+        },
+        None => assert(false),
+    }
+    assert(first_pair_with_sum([1, 3, 5, 7, 9], 100).is_none());
+
+    assert(count_skipping_rows(3, 4, 1) == 8);
+    assert(count_skipping_rows(3, 4, 5) == 12);
+}
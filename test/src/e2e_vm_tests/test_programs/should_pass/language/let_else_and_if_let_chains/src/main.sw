@@ -0,0 +1,108 @@
+library;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+enum Shape {
+    Dot: Point,
+    Line: (Point, Point),
+}
+
+fn first_or_zero(values: Option<(u64, u64)>) -> u64 {
+    let Some((first, _)) = values else {
+        return 0;
+    };
+    first
+}
+
+fn line_length_x(shape: Shape) -> u64 {
+    let Shape::Line((from, to)) = shape else {
+        return 0;
+    };
+    let Point { x: from_x, .. } = from;
+    let Point { x: to_x, .. } = to;
+    if to_x > from_x {
+        to_x - from_x
+    } else {
+        from_x - to_x
+    }
+}
+
+fn sum_of_somes(a: Option<u64>, b: Option<u64>) -> u64 {
+    let Some(a) = a else {
+        return 0;
+    };
+    let Some(b) = b else {
+        return a;
+    };
+    a + b
+}
+
+fn typed_let_else(value: Option<u64>) -> u64 {
+    let Some(n): Option<u64> = value else {
+        return 1;
+    };
+    n * 2
+}
+
+fn large_dot_x(shape: Shape) -> u64 {
+    if let Shape::Dot(point) = shape && point.x > 10 {
+        point.x
+    } else {
+        0
+    }
+}
+
+fn both_somes(a: Option<u64>, b: Option<u64>) -> u64 {
+    if let Some(a) = a && a > 1 && let Some(b) = b && b > a {
+        b - a
+    } else if let Some(a) = a {
+        a
+    } else {
+        42
+    }
+}
+
+fn count_with_chain(value: Option<u64>) -> u64 {
+    let mut count = 0;
+    if let Some(n) = value && n != 0 {
+        count = n;
+    }
+    count
+}
+
+#[test]
+fn let_else_binds_pattern_variables() {
+    assert_eq(first_or_zero(Some((3, 4))), 3);
+    assert_eq(first_or_zero(None), 0);
+
+    let line = Shape::Line((Point { x: 1, y: 2 }, Point { x: 7, y: 2 }));
+    assert_eq(line_length_x(line), 6);
+    assert_eq(line_length_x(Shape::Dot(Point { x: 1, y: 2 })), 0);
+
+    assert_eq(sum_of_somes(Some(1), Some(2)), 3);
+    assert_eq(sum_of_somes(Some(1), None), 1);
+    assert_eq(sum_of_somes(None, Some(2)), 0);
+
+    assert_eq(typed_let_else(Some(21)), 42);
+    assert_eq(typed_let_else(None), 1);
+}
+
+#[test]
+fn if_let_chains_check_all_conditions() {
+    assert_eq(large_dot_x(Shape::Dot(Point { x: 11, y: 0 })), 11);
+    assert_eq(large_dot_x(Shape::Dot(Point { x: 10, y: 0 })), 0);
+    assert_eq(large_dot_x(Shape::Line((Point { x: 11, y: 0 }, Point { x: 11, y: 0 }))), 0);
+
+    assert_eq(both_somes(Some(2), Some(5)), 3);
+    assert_eq(both_somes(Some(2), Some(1)), 2);
+    assert_eq(both_somes(Some(1), Some(5)), 1);
+    assert_eq(both_somes(Some(2), None), 2);
+    assert_eq(both_somes(None, Some(5)), 42);
+
+    assert_eq(count_with_chain(Some(3)), 3);
+    assert_eq(count_with_chain(Some(0)), 0);
+    assert_eq(count_with_chain(None), 0);
+}
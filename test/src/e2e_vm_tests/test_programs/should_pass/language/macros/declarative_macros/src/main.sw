@@ -0,0 +1,41 @@
+script;
+
+macro_rules! square {
+    ($x:expr) => { $x * $x };
+}
+
+macro_rules! sum {
+    ($x:expr) => { $x };
+    ($x:expr, $($rest:expr),+) => { $x + sum!($($rest),+) };
+}
+
+macro_rules! add_first {
+    ($x:expr, $y:expr) => {
+        let tmp = $x;
+        tmp + $y
+    };
+}
+
+trait Arity {
+    fn arity(self) -> u64;
+}
+
+macro_rules! impl_arity {
+    ($($n:literal => ($($t:ident),+)),*) => {
+        $(impl<$($t),+> Arity for ($($t),+) {
+            fn arity(self) -> u64 { $n }
+        })*
+    };
+}
+
+impl_arity!(2 => (A, B), 3 => (A, B, C), 4 => (A, B, C, D));
+
+fn main() -> u64 {
+    // (2 + 1) * (2 + 1)
+    let squared = square!(2 + 1);
+    let summed = sum!(1, 2, 3, 4);
+    let tmp = 10;
+    let added = add_first!(1, tmp);
+    let arities = (1, 2).arity() + (1, true, 3).arity() + (1, 2, 3, 4).arity();
+    squared + summed + added + arities
+}
@@ -0,0 +1,42 @@
+script;
+
+use std::{format::{Format, Formatter}, string::String};
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+impl Format for Point {
+    fn fmt(self, ref mut f: Formatter) {
+        f.write_str("(");
+        f.write(self.x);
+        f.write_str(", ");
+        f.write(self.y);
+        f.write_str(")");
+    }
+}
+
+fn next(ref mut counter: u64) -> u64 {
+    counter += 1;
+    counter
+}
+
+fn main() -> bool {
+    assert(format!("fuel") == String::from_ascii_str("fuel"));
+
+    let x = 42u8;
+    assert(format!("x = {}", x) == String::from_ascii_str("x = 42"));
+    assert(format!("x = {x}") == String::from_ascii_str("x = 42"));
+    assert(format!("{1} {0} {1}", "a", true) == String::from_ascii_str("true a true"));
+    assert(format!("{{{}}}", 7u64,) == String::from_ascii_str("{7}"));
+    assert(format!("{}", Point { x: 1, y: 2 }) == String::from_ascii_str("(1, 2)"));
+    assert(format!(r#"x = "{x}""#) == String::from_ascii_str(r#"x = "42""#));
+
+    // Arguments are evaluated once and in order.
+    let mut counter = 0;
+    assert(format!("{1}{0}{1}", next(counter), next(counter)) == String::from_ascii_str("212"));
+    assert(counter == 2);
+
+    true
+}
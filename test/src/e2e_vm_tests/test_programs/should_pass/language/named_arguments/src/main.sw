@@ -0,0 +1,35 @@
+script;
+
+fn sub(minuend: u64, subtrahend: u64) -> u64 {
+    minuend - subtrahend
+}
+
+fn digits(hundreds: u64, tens: u64, ones: u64) -> u64 {
+    hundreds * 100 + tens * 10 + ones
+}
+
+struct Account {
+    balance: u64,
+}
+
+impl Account {
+    fn new(balance: u64) -> Self {
+        Account { balance }
+    }
+
+    fn transfer(self, amount: u64, fee: u64) -> u64 {
+        self.balance - amount - fee * 2
+    }
+}
+
+fn main() -> u64 {
+    // 10 - 3
+    let a = sub(subtrahend: 3, minuend: 10);
+    // 1 * 100 + 2 * 10 + 3
+    let b = digits(ones: 3, hundreds: 1, tens: 2);
+    let account = Account::new(balance: 100);
+    // 100 - 20 - 5 * 2
+    let c = account.transfer(fee: 5, amount: 20);
+
+    a + b + c
+}
@@ -0,0 +1,55 @@
+script;
+
+fn sum(values: &__slice[u64]) -> u64 {
+    let mut total = 0;
+    let mut i = 0;
+    while i < values.len() {
+        total += *__elem_at(values, i);
+        i += 1;
+    }
+    total
+}
+
+fn main() -> bool {
+    let array = [1, 2, 3, 4, 5];
+
+    // Slice patterns.
+    let [first, .., last] = array;
+    assert(first == 1);
+    assert(last == 5);
+
+    let [a, b, ..] = array;
+    assert(a == 1 && b == 2);
+
+    let [.., y, z] = array;
+    assert(y == 4 && z == 5);
+
+    let [_, second, _, _, fifth] = array;
+    assert(second == 2 && fifth == 5);
+
+    let [(p, q), ..]: [(u64, bool); 2] = [(7, true), (8, false)];
+    assert(p == 7 && q);
+
+    // Indexing with ranges.
+    assert(sum(&array[1..3]) == 5);
+    assert(sum(&array[..2]) == 3);
+    assert(sum(&array[3..]) == 9);
+    assert(sum(&array[..]) == 15);
+    assert(sum(&array[2..2]) == 0);
+
+    let start = 1;
+    let end = 4;
+    let middle = &array[start..end];
+    assert(sum(middle) == 9);
+
+    // Slicing a slice.
+    assert(sum(&middle[1..]) == 7);
+    assert(sum(&middle[..1]) == 2);
+
+    let mut bytes = [0u8; 4];
+    let elem = __elem_at(&mut bytes[1..3], 1);
+    *elem = 42;
+    assert(bytes[2] == 42);
+
+    true
+}
@@ -0,0 +1,31 @@
+script;
+
+fn main() -> bool {
+    // Raw strings without special characters are the same as regular strings.
+    assert(r"fuel" == "fuel");
+    assert(r#"fuel"# == "fuel");
+
+    // Quotes and backslashes are taken verbatim.
+    let json = r#"{"key": "value"}"#;
+    assert(json.len() == 16);
+    let path = r"C:\fuel\n";
+    assert(path.len() == 9);
+
+    // The closing delimiter needs the same number of `#`s.
+    let nested = r##"a "# b"##;
+    assert(nested.len() == 6);
+
+    // Raw identifiers are still lexed as identifiers.
+    let r#raw = 1;
+    assert(r#raw == 1);
+
+    // Raw and regular strings can span multiple lines.
+    let multiline = "a
+b";
+    assert(multiline.len() == 3);
+    let raw_multiline = r#"a
+"b""#;
+    assert(raw_multiline.len() == 5);
+
+    true
+}
@@ -0,0 +1,50 @@
+script;
+
+trait Double {
+    fn double(self) -> u64;
+}
+
+impl Double for u64 {
+    fn double(self) -> u64 {
+        self + self
+    }
+}
+
+trait Counter<T> {
+    fn count(self) -> u64;
+    fn value(self) -> T;
+} {
+    // Calls a default method declared after it.
+    fn count_plus_one(self) -> u64 {
+        self.count_plus(1)
+    }
+
+    fn count_plus(self, n: u64) -> u64 {
+        self.count() + n
+    }
+
+    // Requires an additional bound on the trait's type parameter.
+    fn doubled(self, value: T) -> u64 where T: Double {
+        value.double()
+    }
+}
+
+struct Items {
+    count: u64,
+    value: u64,
+}
+
+impl Counter<u64> for Items {
+    fn count(self) -> u64 {
+        self.count
+    }
+
+    fn value(self) -> u64 {
+        self.value
+    }
+}
+
+fn main() -> u64 {
+    let items = Items { count: 9, value: 16 };
+    items.count_plus_one() + items.doubled(items.value())
+}
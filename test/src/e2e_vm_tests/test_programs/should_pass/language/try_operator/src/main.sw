@@ -0,0 +1,141 @@
+library;
+
+use std::ops::ControlFlow;
+
+enum Error {
+    NotEven: u64,
+    TooLarge: u64,
+}
+
+impl Eq for Error {
+    fn eq(self, other: Self) -> bool {
+        match (self, other) {
+            (Error::NotEven(a), Error::NotEven(b)) => a == b,
+            (Error::TooLarge(a), Error::TooLarge(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+fn half(n: u64) -> Result<u64, Error> {
+    if n % 2 == 0 {
+        Ok(n / 2)
+    } else {
+        Err(Error::NotEven(n))
+    }
+}
+
+fn below_ten(n: u64) -> Result<u64, Error> {
+    if n < 10 {
+        Ok(n)
+    } else {
+        Err(Error::TooLarge(n))
+    }
+}
+
+fn quarter_below_ten(n: u64) -> Result<u64, Error> {
+    let quarter = half(half(n)?)?;
+    Ok(below_ten(quarter)?)
+}
+
+fn first_even(a: Option<u64>, b: Option<u64>) -> Option<u64> {
+    let a = a?;
+    if a % 2 == 0 {
+        return Some(a);
+    }
+    let b = b?;
+    if b % 2 == 0 {
+        Some(b)
+    } else {
+        None
+    }
+}
+
+struct Pair {
+    first: Option<u64>,
+    second: Option<u64>,
+}
+
+fn sum(pair: Option<Pair>) -> Option<u64> {
+    Some(pair?.first? + pair?.second?)
+}
+
+enum Parsed {
+    Value: u64,
+    Invalid: (),
+}
+
+struct InvalidDigit {}
+
+impl Try<u64, InvalidDigit> for Parsed {
+    fn branch(self) -> ControlFlow<InvalidDigit, u64> {
+        match self {
+            Parsed::Value(value) => ControlFlow::Continue(value),
+            Parsed::Invalid => ControlFlow::Break(InvalidDigit {}),
+        }
+    }
+}
+
+impl FromResidual<InvalidDigit> for Parsed {
+    fn from_residual(_residual: InvalidDigit) -> Self {
+        Parsed::Invalid
+    }
+}
+
+fn parse_digit(n: u64) -> Parsed {
+    if n < 10 {
+        Parsed::Value(n)
+    } else {
+        Parsed::Invalid
+    }
+}
+
+fn parse_pair(a: u64, b: u64) -> Parsed {
+    Parsed::Value(parse_digit(a)? * 10 + parse_digit(b)?)
+}
+
+fn is_value(parsed: Parsed, expected: u64) -> bool {
+    match parsed {
+        Parsed::Value(value) => value == expected,
+        Parsed::Invalid => false,
+    }
+}
+
+#[test]
+fn propagates_results() {
+    assert(quarter_below_ten(36) == Ok(9));
+    assert(quarter_below_ten(6) == Err(Error::NotEven(3)));
+    assert(quarter_below_ten(7) == Err(Error::NotEven(7)));
+    assert(quarter_below_ten(40) == Err(Error::TooLarge(10)));
+}
+
+#[test]
+fn propagates_options() {
+    assert(first_even(Some(2), None) == Some(2));
+    assert(first_even(Some(1), Some(4)) == Some(4));
+    assert(first_even(Some(1), Some(3)).is_none());
+    assert(first_even(Some(1), None).is_none());
+    assert(first_even(None, Some(4)).is_none());
+}
+
+#[test]
+fn propagates_in_chains() {
+    let pair = Pair {
+        first: Some(1),
+        second: Some(2),
+    };
+    assert(sum(Some(pair)) == Some(3));
+    assert(sum(None).is_none());
+    let pair = Pair {
+        first: Some(1),
+        second: None,
+    };
+    assert(sum(Some(pair)).is_none());
+}
+
+#[test]
+fn propagates_custom_types() {
+    assert(is_value(parse_pair(4, 2), 42));
+    assert(!is_value(parse_pair(12, 2), 122));
+    assert(!is_value(parse_pair(4, 20), 60));
+}
@@ -0,0 +1,46 @@
+contract;
+
+use std::asset::{burn, mint, transfer};
+
+abi TestAbi {
+    fn withdraw();
+    fn mint_reward();
+    fn burn_deposit();
+    fn transfer_before_interaction();
+}
+
+const OTHER_CONTRACT: b256 = 0x3dba0a4455b598b7655a7fb430883d96c9527ef275b49739e7b0ad12f8280eae;
+const SUB_ID: SubId = 0x0000000000000000000000000000000000000000000000000000000000000000;
+
+impl TestAbi for Contract {
+    fn withdraw() {
+        let other_contract = abi(TestAbi, OTHER_CONTRACT);
+        other_contract.withdraw();
+        // The transfer happens in a called function.
+        pay_out(10);
+    }
+
+    fn mint_reward() {
+        let other_contract = abi(TestAbi, OTHER_CONTRACT);
+        other_contract.mint_reward();
+        mint(SUB_ID, 10);
+    }
+
+    fn burn_deposit() {
+        let other_contract = abi(TestAbi, OTHER_CONTRACT);
+        other_contract.burn_deposit();
+        burn(SUB_ID, 10);
+    }
+
+    // Transferring before the interaction does not violate CEI.
+    fn transfer_before_interaction() {
+        pay_out(10);
+        let other_contract = abi(TestAbi, OTHER_CONTRACT);
+        other_contract.transfer_before_interaction();
+    }
+}
+
+fn pay_out(amount: u64) {
+    let address = 0x0000000000000000000000000000000000000000000000000000000000000001;
+    transfer(Identity::ContractId(ContractId::from(address)), AssetId::from(address), amount);
+}
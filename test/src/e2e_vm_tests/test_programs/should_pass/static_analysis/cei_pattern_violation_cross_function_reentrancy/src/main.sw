@@ -0,0 +1,64 @@
+contract;
+
+storage {
+    balance: u64 = 0,
+    guarded_balance: u64 = 0,
+    locked: bool = false,
+}
+
+abi Vault {
+    #[storage(read)]
+    fn withdraw();
+
+    #[storage(write)]
+    fn set_balance(amount: u64);
+
+    #[storage(read, write)]
+    fn guarded_withdraw();
+
+    #[storage(read, write)]
+    fn guarded_set_balance(amount: u64);
+}
+
+#[reentrancy_guard]
+#[storage(read, write)]
+fn lock() {
+    require(!storage.locked.read(), 0);
+    storage.locked.write(true);
+}
+
+#[storage(write)]
+fn unlock() {
+    storage.locked.write(false);
+}
+
+impl Vault for Contract {
+    #[storage(read)]
+    fn withdraw() {
+        let other_contract = abi(Vault, 0x3dba0a4455b598b7655a7fb430883d96c9527ef275b49739e7b0ad12f8280eae);
+        // the balance can be changed by re-entering `set_balance` during the call
+        let amount = storage.balance.read();
+        other_contract.set_balance(amount);
+    }
+
+    #[storage(write)]
+    fn set_balance(amount: u64) {
+        storage.balance.write(amount);
+    }
+
+    #[storage(read, write)]
+    fn guarded_withdraw() {
+        lock();
+        let other_contract = abi(Vault, 0x3dba0a4455b598b7655a7fb430883d96c9527ef275b49739e7b0ad12f8280eae);
+        let amount = storage.guarded_balance.read();
+        other_contract.guarded_set_balance(amount);
+        unlock();
+    }
+
+    #[storage(read, write)]
+    fn guarded_set_balance(amount: u64) {
+        lock();
+        storage.guarded_balance.write(amount);
+        unlock();
+    }
+}
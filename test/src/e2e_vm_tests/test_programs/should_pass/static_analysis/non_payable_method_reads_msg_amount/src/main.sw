@@ -0,0 +1,36 @@
+contract;
+
+abi TestAbi {
+    #[payable]
+    fn deposit() -> u64;
+
+    fn withdraw() -> u64;
+
+    fn interact() -> u64;
+}
+
+impl TestAbi for Contract {
+    #[payable]
+    fn deposit() -> u64 {
+        forwarded_amount()
+    }
+
+    // Reads the forwarded amount via a called function, but is not payable.
+    fn withdraw() -> u64 {
+        forwarded_amount()
+    }
+
+    // Reading the forwarded amount after an interaction does not violate CEI.
+    #[payable]
+    fn interact() -> u64 {
+        let other_contract = abi(TestAbi, 0x3dba0a4455b598b7655a7fb430883d96c9527ef275b49739e7b0ad12f8280eae);
+        let _ = other_contract.withdraw();
+        forwarded_amount()
+    }
+}
+
+fn forwarded_amount() -> u64 {
+    asm() {
+        bal
+    }
+}
@@ -0,0 +1,49 @@
+contract;
+
+use std::storage::storage_vec::*;
+
+const MAX_VALUES: u64 = 10;
+
+storage {
+    values: StorageVec<u64> = StorageVec {},
+}
+
+abi Sum {
+    #[storage(read)]
+    fn sum_constant_bound() -> u64;
+
+    #[storage(read)]
+    fn sum_literal_bound() -> u64;
+
+    #[storage(read)]
+    fn sum_argument_bound(bound: u64) -> u64;
+}
+
+impl Sum for Contract {
+    #[storage(read)]
+    fn sum_constant_bound() -> u64 {
+        let mut sum = 0;
+        for value in storage.values.iter(MAX_VALUES) {
+            sum += value;
+        }
+        sum
+    }
+
+    #[storage(read)]
+    fn sum_literal_bound() -> u64 {
+        let mut sum = 0;
+        for value in storage.values.iter(5) {
+            sum += value;
+        }
+        sum
+    }
+
+    #[storage(read)]
+    fn sum_argument_bound(bound: u64) -> u64 {
+        let mut sum = 0;
+        for value in storage.values.iter(bound) {
+            sum += value;
+        }
+        sum
+    }
+}
@@ -0,0 +1,47 @@
+contract;
+
+abi Vault {
+    #[storage(write)]
+    fn set_limit(limit: u64);
+
+    #[storage(write)]
+    fn set_checked_limit(limit: u64);
+
+    fn send(amount: u64);
+}
+
+const LIMIT_KEY: b256 = 0x0000000000000000000000000000000000000000000000000000000000000001;
+const RECIPIENT: b256 = 0x3dba0a4455b598b7655a7fb430883d96c9527ef275b49739e7b0ad12f8280eae;
+const ASSET: b256 = 0x0000000000000000000000000000000000000000000000000000000000000000;
+
+impl Vault for Contract {
+    // The argument reaches the storage write through a variable and a called function.
+    #[storage(write)]
+    fn set_limit(limit: u64) {
+        let doubled = limit * 2;
+        store(doubled);
+    }
+
+    // The sanitizer removes the taint of the argument.
+    #[storage(write)]
+    fn set_checked_limit(limit: u64) {
+        let limit = checked_limit(limit);
+        store(limit);
+    }
+
+    fn send(amount: u64) {
+        asm(recipient: RECIPIENT, amount: amount, asset: ASSET) {
+            tr recipient amount asset;
+        }
+    }
+}
+
+#[sanitizer]
+fn checked_limit(limit: u64) -> u64 {
+    if limit > 100 { 100 } else { limit }
+}
+
+#[storage(write)]
+fn store(value: u64) {
+    let _ = __state_store_word(LIMIT_KEY, value);
+}
@@ -0,0 +1,100 @@
+contract;
+
+use std::storage::storage_vec::*;
+
+storage {
+    words: StorageVec<u64> = StorageVec {},
+    bytes: StorageVec<u8> = StorageVec {},
+    hashes: StorageVec<b256> = StorageVec {},
+}
+
+abi StorageVecIter {
+    #[storage(read, write)]
+    fn push(value: u64);
+
+    #[storage(read)]
+    fn sum_words_two() -> u64;
+
+    #[storage(read)]
+    fn sum_words_ten() -> u64;
+
+    #[storage(read)]
+    fn sum_bytes_ten() -> u64;
+
+    #[storage(read)]
+    fn count_hashes_ten() -> u64;
+}
+
+impl StorageVecIter for Contract {
+    #[storage(read, write)]
+    fn push(value: u64) {
+        storage.words.push(value);
+        storage.bytes.push(value.try_as_u8().unwrap());
+        storage.hashes.push(b256::from(value.as_u256()));
+    }
+
+    #[storage(read)]
+    fn sum_words_two() -> u64 {
+        let mut sum = 0;
+        for value in storage.words.iter(2) {
+            sum += value;
+        }
+        sum
+    }
+
+    #[storage(read)]
+    fn sum_words_ten() -> u64 {
+        let mut sum = 0;
+        for value in storage.words.iter(10) {
+            sum += value;
+        }
+        sum
+    }
+
+    #[storage(read)]
+    fn sum_bytes_ten() -> u64 {
+        let mut sum = 0;
+        for value in storage.bytes.iter(10) {
+            sum += value.as_u64();
+        }
+        sum
+    }
+
+    #[storage(read)]
+    fn count_hashes_ten() -> u64 {
+        let mut count = 0;
+        for value in storage.hashes.iter(10) {
+            count += 1;
+            assert(value == b256::from(count.as_u256()));
+        }
+        count
+    }
+}
+
+#[test]
+fn iter_over_empty_vec() {
+    let caller = abi(StorageVecIter, CONTRACT_ID);
+    assert(caller.sum_words_ten() == 0);
+    assert(caller.sum_bytes_ten() == 0);
+    assert(caller.count_hashes_ten() == 0);
+}
+
+#[test]
+fn iter_stops_at_bound() {
+    let caller = abi(StorageVecIter, CONTRACT_ID);
+    caller.push(5);
+    caller.push(10);
+    caller.push(15);
+    assert(caller.sum_words_two() == 15);
+}
+
+#[test]
+fn iter_stops_at_len() {
+    let caller = abi(StorageVecIter, CONTRACT_ID);
+    caller.push(1);
+    caller.push(2);
+    caller.push(3);
+    assert(caller.sum_words_ten() == 6);
+    assert(caller.sum_bytes_ten() == 6);
+    assert(caller.count_hashes_ten() == 3);
+}
@@ -0,0 +1,13 @@
+library;
+
+#[test(should_panic = "not enough funds")]
+fn should_panic_with_message() {
+  let balance = 10;
+  require(balance >= 42, "not enough funds");
+}
+
+#[test(should_panic = "enough")]
+fn should_panic_with_message_substring() {
+  let balance = 10;
+  require(balance >= 42, "not enough funds");
+}
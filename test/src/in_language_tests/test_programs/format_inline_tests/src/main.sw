@@ -0,0 +1,68 @@
+library;
+
+use std::{format::Formatter, string::String};
+
+fn formatted<T>(value: T) -> String
+where
+    T: std::format::Format,
+{
+    let mut f = Formatter::new();
+    f.write(value);
+    f.finish()
+}
+
+#[test]
+fn format_write_str() {
+    let mut f = Formatter::with_capacity(8);
+    f.write_str("fuel");
+    f.write_str("");
+    f.write_str(" vm");
+    assert(f.finish() == String::from_ascii_str("fuel vm"));
+}
+
+#[test]
+fn format_empty() {
+    let f = Formatter::new();
+    assert(f.finish().is_empty());
+}
+
+#[test]
+fn format_bool() {
+    assert(formatted(true) == String::from_ascii_str("true"));
+    assert(formatted(false) == String::from_ascii_str("false"));
+}
+
+#[test]
+fn format_unsigned_integers() {
+    assert(formatted(0u8) == String::from_ascii_str("0"));
+    assert(formatted(255u8) == String::from_ascii_str("255"));
+    assert(formatted(1000u16) == String::from_ascii_str("1000"));
+    assert(formatted(u32::max()) == String::from_ascii_str("4294967295"));
+    assert(formatted(10u64) == String::from_ascii_str("10"));
+    assert(formatted(u64::max()) == String::from_ascii_str("18446744073709551615"));
+}
+
+#[test]
+fn format_u256() {
+    assert(formatted(0u256) == String::from_ascii_str("0"));
+    assert(formatted(42u256) == String::from_ascii_str("42"));
+    assert(formatted(u64::max().as_u256() + 1) == String::from_ascii_str("18446744073709551616"));
+    assert(
+        formatted(u256::max()) == String::from_ascii_str(
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        ),
+    );
+}
+
+#[test]
+fn format_b256() {
+    assert(
+        formatted(0x00000000000000000000000000000000000000000000000000000000000000ff) == String::from_ascii_str("0x00000000000000000000000000000000000000000000000000000000000000ff"),
+    );
+}
+
+#[test]
+fn format_strings() {
+    assert(formatted("fuel") == String::from_ascii_str("fuel"));
+    assert(formatted(String::from_ascii_str("fuel")) == String::from_ascii_str("fuel"));
+}
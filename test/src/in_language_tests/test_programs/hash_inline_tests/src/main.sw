@@ -447,6 +447,17 @@ fn hash_sha256_str_array() {
     assert(digest == 0x4a3cd7c8b44dbf7941e55179425f746adeaa97fe2d99b571fffee78e9b41743c);
 }
 
+#[test()]
+fn hash_sha256_b256() {
+    let value = 0x2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b2b;
+    let digest = sha256_b256(value);
+    assert(digest == 0xeebd6ae7ed7a0885341392eb992ae3a531817127919e489d06e067f429db120f);
+    assert(digest == sha256(value));
+
+    let digest = sha256_b256(b256::zero());
+    assert(digest == 0x66687aadf862bd776c8fc18b8e9f8e20089714856ee233b3902a591d0d5f2925);
+}
+
 #[test()]
 fn hash_keccak256() {
     let digest = keccak256(0_u64);
@@ -539,6 +539,8 @@ fn compile_core(
         offline_mode: true,
         terse_mode: true,
         disable_tests: false,
+        tests: false,
+        all_targets: false,
         locked: false,
         ipfs_node: None,
         experimental: run_config.experimental.clone(),
@@ -186,5 +186,9 @@ fn clean_output(output: &str) -> String {
     let r = Regex::new("(Finished release \\[.*?\\] target\\(s\\) \\[.*?\\] in )(.*?s)").unwrap();
     let result = r.replace(&result, "$1???");
 
+    // Remove build duration from JSON messages
+    let r = Regex::new("(\"duration_secs\":)[0-9.eE+-]+").unwrap();
+    let result = r.replace_all(&result, "$1???");
+
     result.to_string()
 }